@@ -0,0 +1,78 @@
+use avalanche_types::{
+    hash,
+    ids::short,
+    key::secp256k1::{self, batch, ReadOnly},
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ring::digest::{digest, SHA256};
+
+const SIZES: [usize; 2] = [100, 1000];
+
+fn bench_hash(c: &mut Criterion) {
+    let mut g = c.benchmark_group("sha256_ripemd160");
+    for n in SIZES {
+        // compressed public keys
+        let bs: Vec<Vec<u8>> = (0..n).map(|i| vec![(i % 256) as u8; 33]).collect();
+        g.throughput(Throughput::Elements(n as u64));
+        g.bench_with_input(BenchmarkId::new("loop", n), &bs, |b, bs| {
+            b.iter(|| {
+                bs.iter()
+                    .map(|b| hash::sha256_ripemd160(b).unwrap())
+                    .collect::<Vec<_>>()
+            })
+        });
+        g.bench_with_input(BenchmarkId::new("batch", n), &bs, |b, bs| {
+            b.iter(|| hash::sha256_ripemd160_batch(bs).unwrap())
+        });
+    }
+    g.finish();
+}
+
+fn bench_verify(c: &mut Criterion) {
+    let keys = &secp256k1::TEST_KEYS;
+    let mut g = c.benchmark_group("verify");
+    for n in SIZES {
+        let digests: Vec<Vec<u8>> = (0..n)
+            .map(|i| digest(&SHA256, &i.to_be_bytes()).as_ref().to_vec())
+            .collect();
+        let sigs: Vec<Vec<u8>> = digests
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                let k = &keys[i % keys.len()];
+                k.sign_digest(d).unwrap().to_bytes().to_vec()
+            })
+            .collect();
+        let signers: Vec<short::Id> = (0..n)
+            .map(|i| keys[i % keys.len()].short_address().unwrap())
+            .collect();
+        let items: Vec<batch::Item> = (0..n)
+            .map(|i| batch::Item {
+                digest: &digests[i],
+                sig: &sigs[i],
+                signer: &signers[i],
+            })
+            .collect();
+
+        g.throughput(Throughput::Elements(n as u64));
+        g.bench_with_input(BenchmarkId::new("loop", n), &items, |b, items| {
+            b.iter(|| {
+                items
+                    .iter()
+                    .map(|item| {
+                        batch::recover_signer(item.digest, item.sig)
+                            .map(|signer| signer == *item.signer)
+                            .unwrap_or(false)
+                    })
+                    .collect::<Vec<_>>()
+            })
+        });
+        g.bench_with_input(BenchmarkId::new("batch", n), &items, |b, items| {
+            b.iter(|| batch::verify(items))
+        });
+    }
+    g.finish();
+}
+
+criterion_group!(benches, bench_hash, bench_verify);
+criterion_main!(benches);
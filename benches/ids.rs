@@ -0,0 +1,59 @@
+use std::collections::{HashMap, HashSet};
+
+use avalanche_types::ids;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+const SIZES: [usize; 2] = [1000, 100000];
+
+fn bench_set(c: &mut Criterion) {
+    let mut g = c.benchmark_group("ids_set_contains");
+    for n in SIZES {
+        let keys: Vec<ids::Id> = (0..n as u64)
+            .map(|i| ids::Id::sha256(i.to_be_bytes()))
+            .collect();
+        let std_set: HashSet<ids::Id> = keys.iter().copied().collect();
+        let set: ids::set::Set = keys.iter().copied().collect();
+
+        g.throughput(Throughput::Elements(n as u64));
+        g.bench_with_input(BenchmarkId::new("siphash", n), &keys, |b, keys| {
+            b.iter(|| keys.iter().filter(|k| std_set.contains(*k)).count())
+        });
+        g.bench_with_input(BenchmarkId::new("id_hasher", n), &keys, |b, keys| {
+            b.iter(|| keys.iter().filter(|k| set.contains(*k)).count())
+        });
+    }
+    g.finish();
+}
+
+fn bench_map(c: &mut Criterion) {
+    let mut g = c.benchmark_group("ids_map_insert");
+    for n in SIZES {
+        let keys: Vec<ids::Id> = (0..n as u64)
+            .map(|i| ids::Id::sha256(i.to_be_bytes()))
+            .collect();
+
+        g.throughput(Throughput::Elements(n as u64));
+        g.bench_with_input(BenchmarkId::new("siphash", n), &keys, |b, keys| {
+            b.iter(|| {
+                let mut m = HashMap::with_capacity(keys.len());
+                for (i, k) in keys.iter().enumerate() {
+                    m.insert(*k, i);
+                }
+                m
+            })
+        });
+        g.bench_with_input(BenchmarkId::new("id_hasher", n), &keys, |b, keys| {
+            b.iter(|| {
+                let mut m = ids::map::new(keys.len());
+                for (i, k) in keys.iter().enumerate() {
+                    m.insert(*k, i);
+                }
+                m
+            })
+        });
+    }
+    g.finish();
+}
+
+criterion_group!(benches, bench_set, bench_map);
+criterion_main!(benches);
@@ -0,0 +1,39 @@
+//! Regenerates the gRPC stubs in "src/proto/pb" from the avalanchego proto
+//! release pinned by "proto::PROTOCOL_VERSION", only when built with the
+//! "proto_codegen" feature. Regular builds use the checked-in stubs, so
+//! they need neither "buf" nor the protoc plugins.
+//!
+//! e.g.,
+//! cargo build --features proto_codegen
+//!
+//! ref. scripts/protobuf_codegen.sh
+
+use std::{env, path::Path, process::Command};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=CARGO_FEATURE_PROTO_CODEGEN");
+    if env::var_os("CARGO_FEATURE_PROTO_CODEGEN").is_none() {
+        return;
+    }
+
+    let root = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR not set");
+    let script = Path::new("scripts").join("protobuf_codegen.sh");
+    if !Path::new(&root).join(&script).exists() {
+        panic!(
+            "'proto_codegen' requires the repository checkout ({} not found)",
+            script.display()
+        );
+    }
+    println!("cargo:rerun-if-changed=src/proto/mod.rs");
+    println!("cargo:rerun-if-changed=src/proto/buf.gen.yaml");
+
+    let status = Command::new("bash")
+        .arg(&script)
+        .current_dir(&root)
+        .status()
+        .expect("failed to run protobuf codegen");
+    if !status.success() {
+        panic!("protobuf codegen failed with {}", status);
+    }
+}
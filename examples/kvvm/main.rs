@@ -0,0 +1,30 @@
+//! Runs the key-value chain VM as an avalanchego plugin.
+//!
+//! cargo build --example kvvm --features subnet_vm
+//! cp target/debug/examples/kvvm [avalanchego plugins dir]/[VM ID]
+//!
+//! Once the chain is created, put and get the entries with:
+//! curl -X POST --data '{"jsonrpc":"2.0","id":1,"method":"kvvm.put","params":{"key":"foo","value":"bar"}}' \
+//!   -H 'content-type:application/json;' [node URI]/ext/bc/[chain ID]/rpc
+//! curl -X POST --data '{"jsonrpc":"2.0","id":1,"method":"kvvm.get","params":{"key":"foo"}}' \
+//!   -H 'content-type:application/json;' [node URI]/ext/bc/[chain ID]/rpc
+
+mod vm;
+
+use std::io;
+
+use avalanche_types::subnet::rpc::{plugin, vm::server::Server};
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    // ref. https://github.com/env-logger-rs/env_logger/issues/47
+    env_logger::init_from_env(
+        env_logger::Env::default().filter_or(env_logger::DEFAULT_FILTER_ENV, "info"),
+    );
+    log::info!("starting kvvm {}", vm::VERSION);
+
+    let (stop_ch_tx, stop_ch_rx): (Sender<()>, Receiver<()>) = broadcast::channel(1);
+    let vm_server = Server::new(Box::new(vm::Vm::new()), stop_ch_tx);
+    plugin::serve(vm_server, stop_ch_rx).await
+}
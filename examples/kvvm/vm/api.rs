@@ -0,0 +1,90 @@
+//! JSON-RPC handlers of the chain, served at "/ext/bc/[chain ID]/rpc".
+
+use std::io;
+
+use jsonrpc_core::{Error, ErrorCode, IoHandler, Params, Value};
+use serde::{Deserialize, Serialize};
+
+use super::{tx::Tx, Vm};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PutArgs {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetArgs {
+    pub key: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LastAcceptedResponse {
+    pub id: String,
+    pub parent_id: String,
+    pub height: u64,
+    pub timestamp: u64,
+}
+
+fn internal_error(e: io::Error) -> Error {
+    Error {
+        code: ErrorCode::InternalError,
+        message: e.to_string(),
+        data: None,
+    }
+}
+
+fn to_value<T: Serialize>(v: T) -> jsonrpc_core::Result<Value> {
+    serde_json::to_value(v).map_err(|e| Error {
+        code: ErrorCode::InternalError,
+        message: e.to_string(),
+        data: None,
+    })
+}
+
+/// Creates the handler of the chain methods:
+/// "kvvm.put" issues a put and returns the tx ID,
+/// "kvvm.get" returns the accepted value of the key (null if not set),
+/// and "kvvm.lastAccepted" returns the last accepted block.
+pub fn new_handler(vm: Vm) -> IoHandler {
+    let mut handler = IoHandler::new();
+
+    let put_vm = vm.clone();
+    handler.add_method("kvvm.put", move |params: Params| {
+        let vm = put_vm.clone();
+        async move {
+            let args: PutArgs = params.parse()?;
+            let tx_id = vm
+                .issue(Tx::new(&args.key, &args.value))
+                .await
+                .map_err(internal_error)?;
+            Ok(Value::String(tx_id.to_string()))
+        }
+    });
+
+    let get_vm = vm.clone();
+    handler.add_method("kvvm.get", move |params: Params| {
+        let vm = get_vm.clone();
+        async move {
+            let args: GetArgs = params.parse()?;
+            let value = vm.get_value(&args.key).await.map_err(internal_error)?;
+            to_value(value)
+        }
+    });
+
+    handler.add_method("kvvm.lastAccepted", move |_params: Params| {
+        let vm = vm.clone();
+        async move {
+            let blk = vm.last_accepted_block().await.map_err(internal_error)?;
+            to_value(LastAcceptedResponse {
+                id: blk.id().to_string(),
+                parent_id: blk.parent_id().to_string(),
+                height: blk.height(),
+                timestamp: blk.timestamp(),
+            })
+        }
+    });
+
+    handler
+}
@@ -0,0 +1,270 @@
+use std::{
+    collections::HashSet,
+    io::{self, Error, ErrorKind},
+};
+
+use avalanche_types::{
+    choices::status::Status,
+    ids,
+    subnet::rpc::{consensus::snowman, mempool::Tx as _},
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::{state::State, tx::Tx};
+
+/// How far ahead of the local clock a block timestamp may be.
+pub const MAX_FUTURE_SECS: u64 = 10;
+
+/// Represents a block of puts, chained by the parent ID.
+/// Only the header fields and the transactions are serialized,
+/// and the ID is the SHA256 digest of the serialized bytes.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Block {
+    parent_id: ids::Id,
+    height: u64,
+    /// Unix timestamp in seconds.
+    timestamp: u64,
+    txs: Vec<Tx>,
+
+    #[serde(skip)]
+    status: Status,
+    #[serde(skip)]
+    bytes: Vec<u8>,
+    #[serde(skip)]
+    id: ids::Id,
+
+    /// Set once the block is built, parsed or loaded by the VM.
+    #[serde(skip)]
+    state: Option<State>,
+}
+
+impl Block {
+    pub fn new(
+        parent_id: ids::Id,
+        height: u64,
+        timestamp: u64,
+        txs: Vec<Tx>,
+        status: Status,
+    ) -> io::Result<Self> {
+        let mut b = Self {
+            parent_id,
+            height,
+            timestamp,
+            txs,
+            status,
+            bytes: Vec::new(),
+            id: ids::Id::empty(),
+            state: None,
+        };
+        b.bytes = serde_json::to_vec(&b).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to serialize block '{}'", e),
+            )
+        })?;
+        b.id = ids::Id::sha256(&b.bytes);
+        Ok(b)
+    }
+
+    pub fn from_slice(d: &[u8], status: Status) -> io::Result<Self> {
+        let mut b: Self = serde_json::from_slice(d).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to deserialize block '{}'", e),
+            )
+        })?;
+        for tx in b.txs.iter() {
+            tx.verify()?;
+        }
+        b.status = status;
+        b.bytes = d.to_vec();
+        b.id = ids::Id::sha256(d);
+        Ok(b)
+    }
+
+    pub fn set_state(&mut self, state: State) {
+        self.state = Some(state);
+    }
+
+    pub fn id(&self) -> ids::Id {
+        self.id
+    }
+
+    pub fn status(&self) -> Status {
+        self.status.clone()
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn height(&self) -> u64 {
+        self.height
+    }
+
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    pub fn parent_id(&self) -> ids::Id {
+        self.parent_id
+    }
+
+    fn state(&self) -> io::Result<State> {
+        self.state.clone().ok_or_else(|| {
+            Error::new(
+                ErrorKind::Other,
+                format!("block {} not bound to the VM state", self.id),
+            )
+        })
+    }
+
+    /// Applies the puts and persists the block as the last accepted.
+    /// Also used for the genesis block, which is never verified.
+    pub async fn accept_block(&mut self) -> io::Result<()> {
+        let mut state = self.state()?;
+        for tx in self.txs.iter() {
+            state.put_value(&tx.key, &tx.value).await?;
+            state.put_tx(&tx.id(), &self.id).await?;
+        }
+
+        self.status = Status::Accepted;
+        state.put_block(self).await?;
+        state.set_last_accepted(&self.id).await?;
+        state.remove_verified(&self.id).await;
+
+        log::info!("accepted block {} at height {}", self.id, self.height);
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl snowman::Block for Block {
+    async fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    async fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        Ok(self.bytes.clone())
+    }
+
+    async fn height(&self) -> u64 {
+        self.height
+    }
+
+    async fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    async fn parent(&self) -> ids::Id {
+        self.parent_id
+    }
+
+    /// Checks the block against its parent, and keeps it in memory
+    /// until the engine decides it.
+    async fn verify(&mut self) -> io::Result<()> {
+        let state = self.state()?;
+        if self.height == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "genesis block cannot be verified",
+            ));
+        }
+        if self.txs.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("block {} has no tx", self.id),
+            ));
+        }
+
+        let parent = state.get_block(&self.parent_id).await?;
+        if parent.status == Status::Rejected {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("parent block {} is rejected", self.parent_id),
+            ));
+        }
+        if self.height != parent.height + 1 {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "block height {} does not follow the parent height {}",
+                    self.height, parent.height
+                ),
+            ));
+        }
+        if self.timestamp < parent.timestamp {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "block timestamp {} before the parent timestamp {}",
+                    self.timestamp, parent.timestamp
+                ),
+            ));
+        }
+        let now = Utc::now().timestamp() as u64;
+        if self.timestamp > now + MAX_FUTURE_SECS {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("block timestamp {} too far in the future", self.timestamp),
+            ));
+        }
+
+        let mut tx_ids = HashSet::new();
+        for tx in self.txs.iter() {
+            let tx_id = tx.id();
+            if !tx_ids.insert(tx_id) || state.has_tx(&tx_id).await? {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("block {} includes duplicate tx {}", self.id, tx_id),
+                ));
+            }
+        }
+
+        state.add_verified(self).await;
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl snowman::Decidable for Block {
+    async fn id(&self) -> ids::Id {
+        self.id
+    }
+
+    async fn status(&self) -> Status {
+        self.status.clone()
+    }
+
+    async fn accept(&mut self) -> io::Result<()> {
+        self.accept_block().await
+    }
+
+    async fn reject(&mut self) -> io::Result<()> {
+        let mut state = self.state()?;
+        self.status = Status::Rejected;
+        state.put_block(self).await?;
+        state.remove_verified(&self.id).await;
+
+        log::info!("rejected block {} at height {}", self.id, self.height);
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl snowman::Initializer for Block {
+    async fn init(&mut self, bytes: &[u8], status: Status) -> io::Result<()> {
+        let state = self.state.take();
+        *self = Self::from_slice(bytes, status)?;
+        self.state = state;
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl snowman::StatusWriter for Block {
+    async fn set_status(&mut self, status: Status) {
+        self.status = status;
+    }
+}
@@ -0,0 +1,396 @@
+//! Key-value chain VM: each block carries a batch of puts ("key" = "value"),
+//! timestamped and chained like the timestampvm, so that the example covers
+//! the whole "subnet::rpc" stack (blocks, persisted state, JSON-RPC handlers,
+//! mempool and gossip) that a custom VM plugs into.
+//! ref. https://github.com/ava-labs/timestampvm-rs
+
+pub mod api;
+pub mod block;
+pub mod state;
+pub mod tx;
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
+
+use avalanche_types::{
+    choices::status::Status,
+    ids,
+    subnet::rpc::{
+        common::{
+            apphandler::AppHandler,
+            appsender::AppSender,
+            http_handler::HttpHandler,
+            message::Message,
+            vm::{Connector, Fx},
+        },
+        consensus::snowman,
+        context::Context,
+        database::manager::Manager,
+        health::Checkable,
+        mempool::{self, Tx as _},
+        snow,
+        snowman::block::{ChainVm, Getter, Parser},
+    },
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc::Sender, RwLock};
+
+use self::{block::Block, state::State, tx::Tx};
+
+pub const VERSION: &str = "0.0.1";
+
+/// Maximum total size of the puts in a block.
+pub const MAX_BLOCK_TXS_BYTES: usize = 256 * 1024;
+
+/// Initial entries of the chain, set by the genesis block.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Genesis {
+    /// Unix timestamp of the genesis block in seconds.
+    #[serde(default)]
+    pub timestamp: u64,
+    #[serde(default)]
+    pub entries: BTreeMap<String, String>,
+}
+
+impl Genesis {
+    /// Returns the default genesis for empty bytes.
+    pub fn from_slice(d: &[u8]) -> io::Result<Self> {
+        if d.is_empty() {
+            return Ok(Self::default());
+        }
+        serde_json::from_slice(d).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to deserialize genesis '{}'", e),
+            )
+        })
+    }
+}
+
+#[derive(Default)]
+struct Inner {
+    state: Option<State>,
+    bootstrapped: bool,
+    preferred: ids::Id,
+    mempool: mempool::Mempool<Tx>,
+    to_engine: Option<Sender<Message>>,
+    app_sender: Option<Box<dyn AppSender + Send + Sync>>,
+}
+
+/// Implements the VM traits of "subnet::rpc".
+/// Clones share the state, so that the handlers can issue puts.
+#[derive(Clone, Default)]
+pub struct Vm {
+    inner: Arc<RwLock<Inner>>,
+}
+
+fn not_initialized() -> Error {
+    Error::new(ErrorKind::Other, "vm not initialized")
+}
+
+impl Vm {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn state(&self) -> io::Result<State> {
+        self.inner
+            .read()
+            .await
+            .state
+            .clone()
+            .ok_or_else(not_initialized)
+    }
+
+    /// Adds the put to the mempool and gossips it to the peers.
+    /// Returns the tx ID.
+    pub async fn issue(&self, tx: Tx) -> io::Result<ids::Id> {
+        tx.verify()?;
+        let tx_id = tx.id();
+
+        let mut inner = self.inner.write().await;
+        let state = inner.state.clone().ok_or_else(not_initialized)?;
+        if !inner.bootstrapped {
+            return Err(Error::new(
+                ErrorKind::Other,
+                "vm not bootstrapped, cannot issue tx",
+            ));
+        }
+        if state.has_tx(&tx_id).await? {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!("tx {} already accepted", tx_id),
+            ));
+        }
+        inner.mempool.add(tx)?;
+
+        let txs = inner.mempool.take_pending_gossip();
+        if let Some(app_sender) = &inner.app_sender {
+            if !txs.is_empty() {
+                app_sender.send_app_gossip(tx::encode_gossip(&txs)?).await?;
+            }
+        }
+        Ok(tx_id)
+    }
+
+    /// Returns the value of the key in the last accepted state.
+    pub async fn get_value(&self, key: &str) -> io::Result<Option<String>> {
+        self.state().await?.get_value(key).await
+    }
+
+    pub async fn last_accepted_block(&self) -> io::Result<Block> {
+        let state = self.state().await?;
+        let id = state
+            .get_last_accepted()
+            .await?
+            .ok_or_else(not_initialized)?;
+        state.get_block(&id).await
+    }
+}
+
+#[tonic::async_trait]
+impl avalanche_types::subnet::rpc::common::vm::Vm for Vm {
+    async fn initialize(
+        &mut self,
+        _ctx: Option<Context>,
+        db_manager: Box<dyn Manager + Send + Sync>,
+        genesis_bytes: &[u8],
+        _upgrade_bytes: &[u8],
+        _config_bytes: &[u8],
+        to_engine: Sender<Message>,
+        _fxs: &[Fx],
+        app_sender: Box<dyn AppSender + Send + Sync>,
+    ) -> io::Result<()> {
+        let db = db_manager.current().await?.db;
+        let state = State::new(db);
+
+        let last_accepted = match state.get_last_accepted().await? {
+            Some(id) => id,
+            None => {
+                let genesis = Genesis::from_slice(genesis_bytes)?;
+                let txs = genesis.entries.iter().map(|(k, v)| Tx::new(k, v)).collect();
+                let mut blk = Block::new(
+                    ids::Id::empty(),
+                    0,
+                    genesis.timestamp,
+                    txs,
+                    Status::Processing,
+                )?;
+                blk.set_state(state.clone());
+                blk.accept_block().await?;
+                log::info!("initialized genesis block {}", blk.id());
+                blk.id()
+            }
+        };
+
+        let mut inner = self.inner.write().await;
+        inner.state = Some(state);
+        inner.preferred = last_accepted;
+        inner.mempool = mempool::Mempool::default().to_engine(to_engine.clone());
+        inner.to_engine = Some(to_engine);
+        inner.app_sender = Some(app_sender);
+        Ok(())
+    }
+
+    async fn set_state(&self, state: snow::State) -> io::Result<()> {
+        let mut inner = self.inner.write().await;
+        match state {
+            snow::State::Bootstrapping => inner.bootstrapped = false,
+            snow::State::NormalOp => inner.bootstrapped = true,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    format!("unsupported vm state '{}'", state.as_str()),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    async fn shutdown(&self) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn version(&self) -> io::Result<String> {
+        Ok(VERSION.to_string())
+    }
+
+    async fn create_static_handlers(&mut self) -> io::Result<HashMap<String, HttpHandler>> {
+        Ok(HashMap::new())
+    }
+
+    async fn create_handlers(&mut self) -> io::Result<HashMap<String, HttpHandler>> {
+        let handler = HttpHandler::new_from_u8(0, api::new_handler(self.clone()))
+            .map_err(|e| Error::new(ErrorKind::Other, e))?;
+        Ok(HashMap::from([("/rpc".to_string(), handler)]))
+    }
+}
+
+#[tonic::async_trait]
+impl ChainVm for Vm {
+    /// Builds a block on the preferred block out of the mempool,
+    /// skipping the puts that have been accepted since they were issued.
+    async fn build_block(&self) -> io::Result<Box<dyn snowman::Block + Send + Sync>> {
+        let mut inner = self.inner.write().await;
+        let state = inner.state.clone().ok_or_else(not_initialized)?;
+        let parent = state.get_block(&inner.preferred).await?;
+
+        let mut txs = Vec::new();
+        for tx in inner.mempool.pop_up_to(MAX_BLOCK_TXS_BYTES) {
+            if !state.has_tx(&tx.id()).await? {
+                txs.push(tx);
+            }
+        }
+        if txs.is_empty() {
+            return Err(Error::new(ErrorKind::Other, "no pending tx to build"));
+        }
+
+        // the rest goes into the next block
+        if !inner.mempool.is_empty() {
+            if let Some(to_engine) = &inner.to_engine {
+                if let Err(e) = to_engine.try_send(Message::PendingTxs) {
+                    log::warn!("failed to notify engine of pending txs: {}", e);
+                }
+            }
+        }
+
+        let timestamp = (Utc::now().timestamp() as u64).max(parent.timestamp());
+        let mut blk = Block::new(
+            parent.id(),
+            parent.height() + 1,
+            timestamp,
+            txs,
+            Status::Processing,
+        )?;
+        blk.set_state(state);
+        log::info!("built block {} at height {}", blk.id(), blk.height());
+        Ok(Box::new(blk))
+    }
+
+    async fn issue_tx(&self) -> io::Result<Box<dyn snowman::Block + Send + Sync>> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "issue_tx not implemented, use the \"kvvm.put\" handler",
+        ))
+    }
+
+    async fn set_preference(&self, id: ids::Id) -> io::Result<()> {
+        self.inner.write().await.preferred = id;
+        Ok(())
+    }
+
+    async fn last_accepted(&self) -> io::Result<ids::Id> {
+        self.state()
+            .await?
+            .get_last_accepted()
+            .await?
+            .ok_or_else(not_initialized)
+    }
+}
+
+#[tonic::async_trait]
+impl Getter for Vm {
+    async fn get_block(&self, id: ids::Id) -> io::Result<Box<dyn snowman::Block + Send + Sync>> {
+        let blk = self.state().await?.get_block(&id).await?;
+        Ok(Box::new(blk))
+    }
+}
+
+#[tonic::async_trait]
+impl Parser for Vm {
+    /// Returns the known block with its status, or the new block as processing.
+    async fn parse_block(&self, bytes: &[u8]) -> io::Result<Box<dyn snowman::Block + Send + Sync>> {
+        let state = self.state().await?;
+        let mut blk = Block::from_slice(bytes, Status::Processing)?;
+        match state.get_block(&blk.id()).await {
+            Ok(known) => Ok(Box::new(known)),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                blk.set_state(state);
+                Ok(Box::new(blk))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl avalanche_types::subnet::rpc::vm::Vm for Vm {}
+
+#[tonic::async_trait]
+impl AppHandler for Vm {
+    async fn app_request(
+        &self,
+        _node_id: &ids::node::Id,
+        _request_id: u32,
+        _deadline: DateTime<Utc>,
+        _request: &[u8],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn app_request_failed(
+        &self,
+        _node_id: &ids::node::Id,
+        _request_id: u32,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    async fn app_response(
+        &self,
+        _node_id: &ids::node::Id,
+        _request_id: u32,
+        _response: &[u8],
+    ) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Adds the gossiped puts to the mempool, without gossiping them again.
+    async fn app_gossip(&self, node_id: &ids::node::Id, msg: &[u8]) -> io::Result<()> {
+        let txs = tx::decode_gossip(msg)?;
+        log::debug!("received {} gossiped txs from {}", txs.len(), node_id);
+
+        let mut inner = self.inner.write().await;
+        let state = inner.state.clone().ok_or_else(not_initialized)?;
+        for tx in txs {
+            if state.has_tx(&tx.id()).await? {
+                continue;
+            }
+            if let Err(e) = inner.mempool.add_gossiped(tx) {
+                log::warn!("dropping gossiped tx from {} '{}'", node_id, e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl Connector for Vm {
+    async fn connected(&self, node_id: &ids::node::Id) -> io::Result<()> {
+        log::debug!("connected to {}", node_id);
+        Ok(())
+    }
+
+    async fn disconnected(&self, node_id: &ids::node::Id) -> io::Result<()> {
+        log::debug!("disconnected from {}", node_id);
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl Checkable for Vm {
+    async fn health_check(&self) -> io::Result<Vec<u8>> {
+        let blk = self.last_accepted_block().await?;
+        let pending = self.inner.read().await.mempool.len();
+        Ok(serde_json::json!({
+            "lastAccepted": blk.id().to_string(),
+            "height": blk.height(),
+            "pendingTxs": pending,
+        })
+        .to_string()
+        .into_bytes())
+    }
+}
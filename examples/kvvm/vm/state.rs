@@ -0,0 +1,141 @@
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
+
+use avalanche_types::{
+    choices::status::Status,
+    ids,
+    subnet::rpc::database::{self, errors},
+};
+use tokio::sync::RwLock;
+
+use super::block::Block;
+
+const LAST_ACCEPTED_KEY: &[u8] = b"last_accepted";
+const BLOCK_PREFIX: &[u8] = b"block/";
+const STATUS_PREFIX: &[u8] = b"status/";
+const TX_PREFIX: &[u8] = b"tx/";
+const VALUE_PREFIX: &[u8] = b"kv/";
+
+/// Persists the accepted and rejected blocks and the key-value entries,
+/// and keeps the verified blocks in memory until they are decided.
+/// Clones share the database and the verified blocks.
+#[derive(Clone)]
+pub struct State {
+    db: Box<dyn database::Database + Send + Sync>,
+    verified_blocks: Arc<RwLock<HashMap<ids::Id, Block>>>,
+}
+
+fn prefixed(prefix: &[u8], key: &[u8]) -> Vec<u8> {
+    [prefix, key].concat()
+}
+
+/// Returns None if the key is not found.
+async fn get_opt(
+    db: &(dyn database::Database + Send + Sync),
+    key: &[u8],
+) -> io::Result<Option<Vec<u8>>> {
+    match db.get(key).await {
+        Ok(v) => Ok(Some(v)),
+        Err(e) if errors::is_not_found(&e) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+impl State {
+    pub fn new(db: Box<dyn database::Database + Send + Sync>) -> Self {
+        Self {
+            db,
+            verified_blocks: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Returns None before the genesis block is accepted.
+    pub async fn get_last_accepted(&self) -> io::Result<Option<ids::Id>> {
+        let v = get_opt(self.db.as_ref(), LAST_ACCEPTED_KEY).await?;
+        Ok(v.map(|d| ids::Id::from_slice(&d)))
+    }
+
+    pub async fn set_last_accepted(&mut self, id: &ids::Id) -> io::Result<()> {
+        self.db.put(LAST_ACCEPTED_KEY, id.as_ref()).await
+    }
+
+    /// Returns the verified block, or the decided block from the database.
+    /// Fails with "not found" for an unknown block.
+    pub async fn get_block(&self, id: &ids::Id) -> io::Result<Block> {
+        if let Some(blk) = self.verified_blocks.read().await.get(id) {
+            return Ok(blk.clone());
+        }
+
+        let bytes = self.db.get(&prefixed(BLOCK_PREFIX, id.as_ref())).await?;
+        let status = self.db.get(&prefixed(STATUS_PREFIX, id.as_ref())).await?;
+        let status = String::from_utf8(status).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid status of block {} '{}'", id, e),
+            )
+        })?;
+
+        let mut blk = Block::from_slice(&bytes, Status::from(status.as_str()))?;
+        blk.set_state(self.clone());
+        Ok(blk)
+    }
+
+    /// Writes the decided block with its status.
+    pub async fn put_block(&mut self, blk: &Block) -> io::Result<()> {
+        let id = blk.id();
+        self.db
+            .put(&prefixed(BLOCK_PREFIX, id.as_ref()), blk.bytes())
+            .await?;
+        self.db
+            .put(
+                &prefixed(STATUS_PREFIX, id.as_ref()),
+                blk.status().as_str().as_bytes(),
+            )
+            .await
+    }
+
+    pub async fn add_verified(&self, blk: &Block) {
+        self.verified_blocks
+            .write()
+            .await
+            .insert(blk.id(), blk.clone());
+    }
+
+    pub async fn remove_verified(&self, id: &ids::Id) {
+        self.verified_blocks.write().await.remove(id);
+    }
+
+    /// Returns true if the transaction is in an accepted block.
+    pub async fn has_tx(&self, tx_id: &ids::Id) -> io::Result<bool> {
+        self.db.has(&prefixed(TX_PREFIX, tx_id.as_ref())).await
+    }
+
+    /// Indexes the accepted transaction by the block that includes it.
+    pub async fn put_tx(&mut self, tx_id: &ids::Id, blk_id: &ids::Id) -> io::Result<()> {
+        self.db
+            .put(&prefixed(TX_PREFIX, tx_id.as_ref()), blk_id.as_ref())
+            .await
+    }
+
+    pub async fn get_value(&self, key: &str) -> io::Result<Option<String>> {
+        let v = get_opt(self.db.as_ref(), &prefixed(VALUE_PREFIX, key.as_bytes())).await?;
+        v.map(|d| {
+            String::from_utf8(d).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid value of '{}' '{}'", key, e),
+                )
+            })
+        })
+        .transpose()
+    }
+
+    pub async fn put_value(&mut self, key: &str, value: &str) -> io::Result<()> {
+        self.db
+            .put(&prefixed(VALUE_PREFIX, key.as_bytes()), value.as_bytes())
+            .await
+    }
+}
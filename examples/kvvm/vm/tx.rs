@@ -0,0 +1,88 @@
+use std::io::{self, Error, ErrorKind};
+
+use avalanche_types::{ids, subnet::rpc::mempool};
+use serde::{Deserialize, Serialize};
+
+/// Maximum size of a key or a value in bytes.
+pub const MAX_ENTRY_SIZE: usize = 1024;
+
+/// Sets the key to the value once the block that includes it is accepted.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Tx {
+    pub key: String,
+    pub value: String,
+}
+
+impl Tx {
+    pub fn new(key: &str, value: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            value: value.to_string(),
+        }
+    }
+
+    pub fn to_bytes(&self) -> io::Result<Vec<u8>> {
+        serde_json::to_vec(self).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to serialize tx '{}'", e),
+            )
+        })
+    }
+
+    /// Checks the sizes, since the keys and the values are kept forever.
+    pub fn verify(&self) -> io::Result<()> {
+        if self.key.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "empty key"));
+        }
+        if self.key.len() > MAX_ENTRY_SIZE || self.value.len() > MAX_ENTRY_SIZE {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "key or value of '{}' exceeds {} bytes",
+                    self.key, MAX_ENTRY_SIZE
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl mempool::Tx for Tx {
+    fn id(&self) -> ids::Id {
+        ids::Id::sha256(self.to_bytes().expect("tx serializes"))
+    }
+
+    /// Puts are free, so the mempool keeps them in the arrival order.
+    fn fee(&self) -> u64 {
+        0
+    }
+
+    fn size(&self) -> usize {
+        self.key.len() + self.value.len()
+    }
+}
+
+/// Encodes the transactions of a gossip message.
+pub fn encode_gossip(txs: &[Tx]) -> io::Result<Vec<u8>> {
+    serde_json::to_vec(txs).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed to serialize gossip '{}'", e),
+        )
+    })
+}
+
+/// Decodes the transactions of a gossip message.
+pub fn decode_gossip(d: &[u8]) -> io::Result<Vec<Tx>> {
+    let txs: Vec<Tx> = serde_json::from_slice(d).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("failed to deserialize gossip '{}'", e),
+        )
+    })?;
+    for tx in txs.iter() {
+        tx.verify()?;
+    }
+    Ok(txs)
+}
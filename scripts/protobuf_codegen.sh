@@ -1,15 +1,20 @@
 #!/usr/bin/env bash
 
-# protocol version is the version of the gRPC proto definitions
-# as defined by the avalanchego rpcchainvm.
-# ref. https://github.com/ava-labs/avalanchego/blob/v1.9.2/version/constants.go#L15-L17
-PROTOCOL_VERSION='19'
-
 if ! [[ "$0" =~ scripts/protobuf_codegen.sh ]]; then
   echo "must be run from repository root"
   exit 255
 fi
 
+# protocol version is the version of the gRPC proto definitions
+# as defined by the avalanchego rpcchainvm, pinned by "proto::PROTOCOL_VERSION"
+# so that the generated stubs and the advertised version never diverge.
+# ref. https://github.com/ava-labs/avalanchego/blob/v1.9.2/version/constants.go#L15-L17
+PROTOCOL_VERSION=$(grep -E '^pub const PROTOCOL_VERSION' ./src/proto/mod.rs | cut -d'"' -f2)
+if [[ -z "${PROTOCOL_VERSION}" ]]; then
+  echo "could not find PROTOCOL_VERSION in src/proto/mod.rs"
+  exit 255
+fi
+
 # ref. https://docs.buf.build/installation
 BUF_VERSION='1.9.0'
 if [[ $(buf --version | cut -f2 -d' ') != "${BUF_VERSION}" ]]; then
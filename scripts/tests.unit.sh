@@ -17,8 +17,11 @@ RUST_LOG=debug cargo test --workspace \
 --features avalanche-types/kms_aws \
 --features avalanche-types/libsecp256k1 \
 --features avalanche-types/message_compress_gzip \
+--features avalanche-types/message_compress_zstd \
 --features avalanche-types/mnemonic \
 --features avalanche-types/subnet_evm \
+--features avalanche-types/testnet \
+--features avalanche-types/vanity \
 -- --show-output
 
 echo "ALL SUCCESS!"
@@ -0,0 +1,146 @@
+//! Project files of avalanche-cli, so that the Rust deployment tooling can
+//! read (and update) the subnets created and deployed with avalanche-cli.
+//! ref. https://github.com/ava-labs/avalanche-cli
+
+pub mod sidecar;
+pub mod subnet_config;
+
+use std::{
+    fs,
+    io::{self, Error, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+/// Base directory of avalanche-cli under the home directory.
+pub const BASE_DIR_NAME: &str = ".avalanche-cli";
+
+/// Directory of the subnets under the base directory,
+/// with one "[subnet name]" directory per subnet.
+pub const SUBNETS_DIR_NAME: &str = "subnets";
+
+pub const SIDECAR_FILE_NAME: &str = "sidecar.json";
+pub const GENESIS_FILE_NAME: &str = "genesis.json";
+pub const CHAIN_CONFIG_FILE_NAME: &str = "chain.json";
+pub const SUBNET_CONFIG_FILE_NAME: &str = "subnet.json";
+
+/// Returns "[base dir]/subnets/[subnet name]", where the base dir
+/// is "~/.avalanche-cli" unless avalanche-cli runs with "--config".
+pub fn subnet_dir(base_dir: impl AsRef<Path>, subnet_name: &str) -> PathBuf {
+    base_dir.as_ref().join(SUBNETS_DIR_NAME).join(subnet_name)
+}
+
+/// Files of a subnet directory.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Subnet {
+    pub sidecar: sidecar::Sidecar,
+    /// VM genesis, as passed to "CreateChainTx".
+    pub genesis: Vec<u8>,
+    /// Chain config of the VM, if any (e.g., the subnet-evm "config.json").
+    pub chain_config: Option<Vec<u8>>,
+    pub subnet_config: Option<subnet_config::SubnetConfig>,
+}
+
+impl Subnet {
+    /// Loads the subnet directory, where the sidecar and the genesis are required.
+    pub fn load(dir: impl AsRef<Path>) -> io::Result<Self> {
+        let dir = dir.as_ref();
+        log::info!("loading avalanche-cli subnet from {}", dir.display());
+
+        let path_str = |name: &str| -> io::Result<String> {
+            dir.join(name).to_str().map(String::from).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("non-UTF-8 path {}", dir.display()),
+                )
+            })
+        };
+
+        let sidecar = sidecar::Sidecar::load(&path_str(SIDECAR_FILE_NAME)?)?;
+        let genesis = fs::read(dir.join(GENESIS_FILE_NAME)).map_err(|e| {
+            Error::new(
+                e.kind(),
+                format!(
+                    "failed to read {} in {} ({})",
+                    GENESIS_FILE_NAME,
+                    dir.display(),
+                    e
+                ),
+            )
+        })?;
+
+        let chain_config_path = dir.join(CHAIN_CONFIG_FILE_NAME);
+        let chain_config = if chain_config_path.exists() {
+            Some(fs::read(chain_config_path)?)
+        } else {
+            None
+        };
+
+        let subnet_config_path = path_str(SUBNET_CONFIG_FILE_NAME)?;
+        let subnet_config = if Path::new(&subnet_config_path).exists() {
+            Some(subnet_config::SubnetConfig::load(&subnet_config_path)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            sidecar,
+            genesis,
+            chain_config,
+            subnet_config,
+        })
+    }
+
+    /// Writes the files to the subnet directory, overwriting the existing ones.
+    pub fn sync(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let path_str = |name: &str| -> io::Result<String> {
+            dir.join(name).to_str().map(String::from).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("non-UTF-8 path {}", dir.display()),
+                )
+            })
+        };
+
+        self.sidecar.sync(&path_str(SIDECAR_FILE_NAME)?)?;
+        fs::write(dir.join(GENESIS_FILE_NAME), &self.genesis)?;
+        if let Some(chain_config) = &self.chain_config {
+            fs::write(dir.join(CHAIN_CONFIG_FILE_NAME), chain_config)?;
+        }
+        if let Some(subnet_config) = &self.subnet_config {
+            subnet_config.sync(&path_str(SUBNET_CONFIG_FILE_NAME)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanche_cli::test_subnet --exact --show-output
+#[test]
+fn test_subnet() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let base_dir = tempfile::tempdir().unwrap();
+    let dir = subnet_dir(base_dir.path(), "mysubnet");
+    assert!(dir.ends_with("subnets/mysubnet"));
+    assert!(Subnet::load(&dir).is_err());
+
+    let subnet = Subnet {
+        sidecar: sidecar::Sidecar {
+            name: "mysubnet".to_string(),
+            vm: sidecar::VmType::SubnetEvm,
+            token_name: "TEST".to_string(),
+            ..Default::default()
+        },
+        genesis: br#"{"config":{"chainId":43214}}"#.to_vec(),
+        chain_config: None,
+        subnet_config: Some(subnet_config::SubnetConfig {
+            validator_only: Some(true),
+            ..Default::default()
+        }),
+    };
+    subnet.sync(&dir).unwrap();
+    assert!(!dir.join(CHAIN_CONFIG_FILE_NAME).exists());
+    assert_eq!(Subnet::load(&dir).unwrap(), subnet);
+}
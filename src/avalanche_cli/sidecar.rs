@@ -0,0 +1,246 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, Error, ErrorKind, Write},
+    path::Path,
+};
+
+use crate::ids;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// VM of the subnet, as named by avalanche-cli.
+/// Any other name (e.g., from a newer avalanche-cli) is kept as "Unknown".
+/// ref. https://github.com/ava-labs/avalanche-cli/blob/v1.0.5/pkg/models/vm.go
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default)]
+pub enum VmType {
+    SubnetEvm,
+    SpacesVm,
+    BlobVm,
+    TimestampVm,
+    #[default]
+    Custom,
+    Unknown(String),
+}
+
+impl std::convert::From<&str> for VmType {
+    fn from(s: &str) -> Self {
+        match s {
+            "Subnet-EVM" => VmType::SubnetEvm,
+            "Spaces VM" => VmType::SpacesVm,
+            "Blob VM" => VmType::BlobVm,
+            "Timestamp VM" => VmType::TimestampVm,
+            "Custom" => VmType::Custom,
+            other => VmType::Unknown(other.to_owned()),
+        }
+    }
+}
+
+impl VmType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            VmType::SubnetEvm => "Subnet-EVM",
+            VmType::SpacesVm => "Spaces VM",
+            VmType::BlobVm => "Blob VM",
+            VmType::TimestampVm => "Timestamp VM",
+            VmType::Custom => "Custom",
+            VmType::Unknown(s) => s,
+        }
+    }
+}
+
+impl Serialize for VmType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for VmType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(VmType::from(s.as_str()))
+    }
+}
+
+/// Subnet and blockchain created on a network (e.g., "Fuji").
+/// ref. https://github.com/ava-labs/avalanche-cli/blob/v1.0.5/pkg/models/sidecar.go
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct NetworkData {
+    #[serde(rename = "SubnetID", default)]
+    pub subnet_id: ids::Id,
+    #[serde(rename = "BlockchainID", default)]
+    pub blockchain_id: ids::Id,
+}
+
+/// Project file "sidecar.json" that avalanche-cli keeps for each subnet,
+/// as "~/.avalanche-cli/subnets/[subnet name]/sidecar.json".
+/// The field names follow the Go struct (no JSON tags), the fields
+/// missing in the files of older avalanche-cli versions default to empty,
+/// and the unknown fields are preserved in "extra".
+/// ref. https://github.com/ava-labs/avalanche-cli/blob/v1.0.5/pkg/models/sidecar.go
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct Sidecar {
+    #[serde(rename = "Name", default)]
+    pub name: String,
+    #[serde(rename = "VM", default)]
+    pub vm: VmType,
+    #[serde(rename = "VMVersion", default)]
+    pub vm_version: String,
+    /// Plugin protocol version of the VM binary.
+    #[serde(rename = "RPCVersion", default)]
+    pub rpc_version: u32,
+    #[serde(rename = "Subnet", default)]
+    pub subnet: String,
+    #[serde(rename = "TokenName", default)]
+    pub token_name: String,
+    /// EVM chain ID (e.g., "43214"), empty for non-EVM subnets.
+    #[serde(rename = "ChainID", default)]
+    pub chain_id: String,
+    /// Version of the sidecar file format (e.g., "1.4.0").
+    #[serde(rename = "Version", default)]
+    pub version: String,
+    /// Keyed by the network name: "Local Network", "Fuji" or "Mainnet".
+    #[serde(rename = "Networks", default)]
+    pub networks: BTreeMap<String, NetworkData>,
+    #[serde(rename = "ImportedFromAPM", default)]
+    pub imported_from_apm: bool,
+    #[serde(rename = "ImportedVMID", default)]
+    pub imported_vm_id: String,
+
+    /// Fields of the newer avalanche-cli versions not modeled here,
+    /// kept so that "sync" writes them back unchanged.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Network names of the "Networks" entries.
+pub const NETWORK_LOCAL: &str = "Local Network";
+pub const NETWORK_FUJI: &str = "Fuji";
+pub const NETWORK_MAINNET: &str = "Mainnet";
+
+impl Sidecar {
+    /// Returns the subnet and blockchain IDs on the network, if deployed.
+    pub fn network(&self, name: &str) -> Option<&NetworkData> {
+        self.networks.get(name)
+    }
+
+    /// Returns the network name of the network ID, as used in "Networks".
+    pub fn network_name(network_id: u32) -> &'static str {
+        match network_id {
+            1 => NETWORK_MAINNET,
+            5 => NETWORK_FUJI,
+            _ => NETWORK_LOCAL,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string_pretty(&self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize to JSON {}", e),
+            )
+        })
+    }
+
+    /// Saves the sidecar to disk and overwrites the file.
+    pub fn sync(&self, file_path: &str) -> io::Result<()> {
+        log::info!("syncing sidecar to '{}'", file_path);
+        let path = Path::new(file_path);
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        let d = self.encode_json()?;
+        let mut f = File::create(file_path)?;
+        f.write_all(d.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        log::info!("loading sidecar from {}", file_path);
+
+        if !Path::new(file_path).exists() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("file {} does not exists", file_path),
+            ));
+        }
+
+        // read to memory first, since the IDs only deserialize from borrowed strings
+        let d = fs::read(file_path).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to read {} ({})", file_path, e),
+            )
+        })?;
+        serde_json::from_slice(&d)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid JSON: {}", e)))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanche_cli::sidecar::test_sidecar --exact --show-output
+#[test]
+fn test_sidecar() {
+    let _ = env_logger::builder().is_test(true).try_init();
+
+    let d = r#"{
+    "Name": "mysubnet",
+    "VM": "Subnet-EVM",
+    "VMVersion": "v0.4.3",
+    "RPCVersion": 19,
+    "Subnet": "mysubnet",
+    "TokenName": "TEST",
+    "ChainID": "43214",
+    "Version": "1.4.0",
+    "Networks": {
+        "Fuji": {
+            "SubnetID": "TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES",
+            "BlockchainID": "g25v3qDyAaHfR7kBev8tLUHouSgN5BJuZjy1BYS1oiHd2vres"
+        }
+    },
+    "ImportedFromAPM": false,
+    "ImportedVMID": ""
+}"#;
+    let sc: Sidecar = serde_json::from_str(d).unwrap();
+    assert_eq!(sc.vm, VmType::SubnetEvm);
+    assert_eq!(sc.rpc_version, 19);
+    assert_eq!(sc.token_name, "TEST");
+    let fuji = sc.network(Sidecar::network_name(5)).unwrap();
+    assert_eq!(
+        fuji.subnet_id.to_string(),
+        "TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES"
+    );
+    assert!(sc.network(NETWORK_MAINNET).is_none());
+    assert!(sc.extra.is_empty());
+
+    let tmp_path = random_manager::tmp_path(10, Some(".json")).unwrap();
+    sc.sync(&tmp_path).unwrap();
+    assert_eq!(Sidecar::load(&tmp_path).unwrap(), sc);
+    fs::remove_file(&tmp_path).unwrap();
+
+    // older files without the newer fields, and unknown VM names
+    let sc: Sidecar = serde_json::from_str(r#"{"Name":"old","VM":"Hyper VM"}"#).unwrap();
+    assert_eq!(sc.vm, VmType::Unknown("Hyper VM".to_string()));
+    assert_eq!(sc.vm.as_str(), "Hyper VM");
+    assert!(sc.networks.is_empty());
+
+    // unknown fields survive the round trip
+    let sc: Sidecar = serde_json::from_str(
+        r#"{"Name":"new","VM":"Subnet-EVM","TeleporterReady":true,"ValidatorManagement":{"Kind":"PoA"}}"#,
+    )
+    .unwrap();
+    assert_eq!(sc.extra["TeleporterReady"], serde_json::json!(true));
+    let tmp_path = random_manager::tmp_path(10, Some(".json")).unwrap();
+    sc.sync(&tmp_path).unwrap();
+    let loaded = Sidecar::load(&tmp_path).unwrap();
+    fs::remove_file(&tmp_path).unwrap();
+    assert_eq!(loaded, sc);
+    assert_eq!(loaded.extra["ValidatorManagement"]["Kind"], "PoA");
+    assert!(!loaded.extra.contains_key("Name"));
+}
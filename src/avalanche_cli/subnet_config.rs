@@ -0,0 +1,146 @@
+use std::{
+    fs::{self, File},
+    io::{self, Error, ErrorKind, Write},
+    path::Path,
+};
+
+use crate::ids;
+use serde::{Deserialize, Serialize};
+
+/// Per-subnet config of avalanchego, which avalanche-cli keeps as
+/// "subnet.json" in the subnet directory and copies to the node as
+/// "{subnet-config-dir}/[subnet ID].json".
+/// ref. https://docs.avax.network/nodes/maintain/subnet-configs
+/// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/chains/subnet.go
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubnetConfig {
+    /// If true, the node only connects to the subnet validators.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub validator_only: Option<bool>,
+    /// Non-validators that may connect if "validator_only" is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_nodes: Option<Vec<ids::node::Id>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consensus_parameters: Option<ConsensusParameters>,
+    /// Minimum delay between the proposervm blocks in nanoseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proposer_min_block_delay: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gossip_accepted_frontier_validator_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gossip_accepted_frontier_non_validator_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gossip_accepted_frontier_peer_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gossip_on_accept_validator_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gossip_on_accept_non_validator_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gossip_on_accept_peer_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_gossip_validator_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_gossip_non_validator_size: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_gossip_peer_size: Option<u32>,
+}
+
+/// Consensus parameters of the subnet, the node defaults apply to the unset ones.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/consensus/avalanche#Parameters
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsensusParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alpha: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beta_virtuous: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub beta_rogue: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrent_repolls: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optimal_processing: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_outstanding_items: Option<u32>,
+    /// In nanoseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_item_processing_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parents: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<u32>,
+}
+
+impl SubnetConfig {
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize to JSON {}", e),
+            )
+        })
+    }
+
+    /// Saves the config to disk and overwrites the file.
+    pub fn sync(&self, file_path: &str) -> io::Result<()> {
+        log::info!("syncing subnet config to '{}'", file_path);
+        let path = Path::new(file_path);
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        let d = self.encode_json()?;
+        let mut f = File::create(file_path)?;
+        f.write_all(d.as_bytes())?;
+
+        Ok(())
+    }
+
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        log::info!("loading subnet config from {}", file_path);
+
+        if !Path::new(file_path).exists() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("file {} does not exists", file_path),
+            ));
+        }
+
+        // "node::Id" deserializes from a borrowed string, so not from a reader
+        let d = fs::read(file_path).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to read {} ({})", file_path, e),
+            )
+        })?;
+        serde_json::from_slice(&d)
+            .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid JSON: {}", e)))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanche_cli::subnet_config::test_subnet_config --exact --show-output
+#[test]
+fn test_subnet_config() {
+    let d = r#"{
+    "validatorOnly": true,
+    "allowedNodes": ["NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg"],
+    "consensusParameters": {"k": 25, "alpha": 18},
+    "proposerMinBlockDelay": 0,
+    "appGossipValidatorSize": 10
+}"#;
+    let cfg: SubnetConfig = serde_json::from_str(d).unwrap();
+    assert_eq!(cfg.validator_only, Some(true));
+    assert_eq!(cfg.allowed_nodes.as_ref().unwrap().len(), 1);
+    assert_eq!(cfg.consensus_parameters.as_ref().unwrap().k, Some(25));
+    assert_eq!(cfg.consensus_parameters.as_ref().unwrap().beta_rogue, None);
+    assert_eq!(cfg.app_gossip_validator_size, Some(10));
+
+    // unset fields are omitted so that the node defaults apply
+    let encoded = cfg.encode_json().unwrap();
+    assert!(!encoded.contains("betaRogue"));
+    assert_eq!(serde_json::from_str::<SubnetConfig>(&encoded).unwrap(), cfg);
+}
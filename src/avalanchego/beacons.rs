@@ -0,0 +1,314 @@
+//! Bootstrap beacons of the public networks, and the parsers of the
+//! "--bootstrap-ips" and "--bootstrap-ids" flags.
+//!
+//! The built-in lists are the defaults shipped with avalanchego and may go
+//! stale, so they can be replaced at runtime (e.g., with the latest
+//! "bootstrappers.json" of the avalanchego release in use).
+//! ref. https://github.com/ava-labs/avalanchego/blob/master/genesis/bootstrappers.json
+
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+    net::SocketAddr,
+    str::FromStr,
+    sync::RwLock,
+};
+
+use crate::{constants, ids::node};
+use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+
+/// Node that new nodes connect to first to bootstrap the network.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Hash)]
+pub struct Beacon {
+    pub id: node::Id,
+    pub ip: SocketAddr,
+}
+
+/// Mainnet beacons as of avalanchego v1.9.
+const MAINNET: &[(&str, &str)] = &[
+    (
+        "NodeID-A6onFGyJjA37EZ7kYHANMR1PFRT8NmXrF",
+        "54.94.43.49:9651",
+    ),
+    (
+        "NodeID-6SwnPJLH8cWfrJ162JjZekbmzaFpjPcf",
+        "52.79.47.77:9651",
+    ),
+    (
+        "NodeID-GSgaA47umS1px2ohVjodW9621Ks63xDxD",
+        "18.229.206.191:9651",
+    ),
+    (
+        "NodeID-BQEo5Fy1FRKLbX51ejqDd14cuSXJKArH2",
+        "3.34.221.73:9651",
+    ),
+    (
+        "NodeID-Drv1Qh7iJvW3zGBBeRnYfCzk56VCRM2GQ",
+        "13.244.155.170:9651",
+    ),
+    (
+        "NodeID-DAtCoXfLT6Y83dgJ7FmQg8eR53hz37J79",
+        "13.244.47.224:9651",
+    ),
+    (
+        "NodeID-FGRoKnyYKFWYFMb6Xbocf4hKuyCBENgWM",
+        "122.248.200.212:9651",
+    ),
+    (
+        "NodeID-Dw7tuwxpAmcpvVGp9JzaHAR3REPoJ8f2R",
+        "52.30.9.211:9651",
+    ),
+    (
+        "NodeID-4kCLS16Wy73nt1Zm54jFZsL7Msrv3UCeJ",
+        "122.248.199.127:9651",
+    ),
+    (
+        "NodeID-9T7NXBFpp8LWCyc58YdKNoowDipdVKAWz",
+        "18.202.190.40:9651",
+    ),
+    (
+        "NodeID-6ghBh6yof5ouMCya2n9fHzhpWouiZFVVj",
+        "15.206.182.45:9651",
+    ),
+    (
+        "NodeID-HiFv1DpKXkAAfJ1NHWVqQoojjznibZXHP",
+        "15.207.11.193:9651",
+    ),
+    (
+        "NodeID-Fv3t2shrpkmvLnvNzcv1rqRKbDAYFnUor",
+        "44.226.118.72:9651",
+    ),
+    (
+        "NodeID-AaxT2P4uuPAHb7vAD8mNvjQ3jgyaV7tu9",
+        "54.185.87.50:9651",
+    ),
+    (
+        "NodeID-kZNuQMHhydefgnwjYX1fhHMpRNAs9my1",
+        "18.158.15.12:9651",
+    ),
+    (
+        "NodeID-A7GwTSd47AcDVqpTVj7YtxtjHREM33EJw",
+        "3.21.38.33:9651",
+    ),
+    (
+        "NodeID-Hr78Fy8uDYiRYocRYHXp4eLCYeb8x5UuM",
+        "54.93.182.129:9651",
+    ),
+    (
+        "NodeID-9CkG9MBNavnw7EVSRsuFr7ws9gascDQy3",
+        "3.128.138.36:9651",
+    ),
+    (
+        "NodeID-A8jypu63CWp76STwKdqP6e9hjL675kdiG",
+        "3.104.107.241:9651",
+    ),
+    (
+        "NodeID-HsBEx3L71EHWSXaE6gvk2VsNntFEZsxqc",
+        "3.106.25.139:9651",
+    ),
+    (
+        "NodeID-Nr584bLpGgbCUbZFSBaBz3Xum5wpca9Ym",
+        "18.162.129.129:9651",
+    ),
+    (
+        "NodeID-QKGoUvqcgormCoMj6yPw9isY7DX9H4mdd",
+        "18.162.161.230:9651",
+    ),
+    (
+        "NodeID-HCw7S2TVbFPDWNBo1GnFWqJ47f9rDJtt1",
+        "52.47.181.114:9651",
+    ),
+    (
+        "NodeID-FYv1Lb29SqMpywYXH7yNkcFAzRF2jvm3K",
+        "15.188.9.42:9651",
+    ),
+];
+
+/// Fuji beacons as of avalanchego v1.9.
+const FUJI: &[(&str, &str)] = &[
+    (
+        "NodeID-2m38qc95mhHXtrhjyGbe7r2NhniqHHJRB",
+        "3.214.55.84:9651",
+    ),
+    (
+        "NodeID-JjvzhxnLHLUQ5HjVRkvG827ivbLXPwA9u",
+        "34.207.133.167:9651",
+    ),
+    (
+        "NodeID-LegbVf6qaMKcsXPnLStkdc1JVktmmiDxy",
+        "107.23.241.199:9651",
+    ),
+    (
+        "NodeID-HGZ8ae74J3odT8ESreAdCtdnvWG1J4X5n",
+        "54.197.215.186:9651",
+    ),
+    (
+        "NodeID-CYKruAjwH1BmV3m37sXNuprbr7dGQuJwG",
+        "18.234.153.22:9651",
+    ),
+];
+
+fn load(pairs: &[(&str, &str)]) -> Vec<Beacon> {
+    pairs
+        .iter()
+        .map(|(id, ip)| Beacon {
+            id: node::Id::from_str(id).expect("unexpected invalid built-in beacon ID"),
+            ip: ip.parse().expect("unexpected invalid built-in beacon IP"),
+        })
+        .collect()
+}
+
+fn defaults() -> HashMap<u32, Vec<Beacon>> {
+    HashMap::from([(1, load(MAINNET)), (5, load(FUJI))])
+}
+
+lazy_static! {
+    static ref BEACONS: RwLock<HashMap<u32, Vec<Beacon>>> = RwLock::new(defaults());
+}
+
+/// Returns the beacons of the network, empty for the networks without beacons
+/// (e.g., local networks).
+pub fn get(network_id: u32) -> Vec<Beacon> {
+    BEACONS
+        .read()
+        .expect("unexpected poisoned lock")
+        .get(&network_id)
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// Replaces the beacons of the network.
+pub fn set(network_id: u32, beacons: Vec<Beacon>) {
+    BEACONS
+        .write()
+        .expect("unexpected poisoned lock")
+        .insert(network_id, beacons);
+}
+
+/// Restores the built-in beacons.
+pub fn reset() {
+    *BEACONS.write().expect("unexpected poisoned lock") = defaults();
+}
+
+/// Replaces the beacons with the ones in the avalanchego "bootstrappers.json"
+/// format, keyed by the network name (e.g., "mainnet", "fuji").
+pub fn load_json(d: &str) -> io::Result<()> {
+    let parsed: HashMap<String, Vec<Beacon>> = serde_json::from_str(d)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("invalid JSON: {}", e)))?;
+
+    let mut by_network_id = HashMap::new();
+    for (network_name, beacons) in parsed {
+        let network_id = constants::NETWORK_NAME_TO_NETWORK_ID
+            .get(network_name.as_str())
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("unknown network name '{}'", network_name),
+                )
+            })?;
+        by_network_id.insert(*network_id, beacons);
+    }
+
+    let mut beacons = BEACONS.write().expect("unexpected poisoned lock");
+    for (network_id, b) in by_network_id {
+        beacons.insert(network_id, b);
+    }
+    Ok(())
+}
+
+/// Parses the comma-separated "--bootstrap-ips" value.
+pub fn parse_bootstrap_ips(s: &str) -> io::Result<Vec<SocketAddr>> {
+    split(s)
+        .map(|ip| {
+            ip.parse::<SocketAddr>().map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("invalid bootstrap IP '{}' ({})", ip, e),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Parses the comma-separated "--bootstrap-ids" value.
+pub fn parse_bootstrap_ids(s: &str) -> io::Result<Vec<node::Id>> {
+    split(s)
+        .map(|id| {
+            node::Id::from_str(id).map_err(|e| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("invalid bootstrap ID '{}' ({})", id, e),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Parses the "--bootstrap-ips" and "--bootstrap-ids" pair,
+/// which must list the same number of nodes in the same order.
+pub fn parse(bootstrap_ips: &str, bootstrap_ids: &str) -> io::Result<Vec<Beacon>> {
+    let ips = parse_bootstrap_ips(bootstrap_ips)?;
+    let ids = parse_bootstrap_ids(bootstrap_ids)?;
+    if ips.len() != ids.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "{} bootstrap IPs but {} bootstrap IDs",
+                ips.len(),
+                ids.len()
+            ),
+        ));
+    }
+    Ok(ids
+        .into_iter()
+        .zip(ips)
+        .map(|(id, ip)| Beacon { id, ip })
+        .collect())
+}
+
+/// Returns the "--bootstrap-ips" and "--bootstrap-ids" values of the beacons.
+pub fn to_flags(beacons: &[Beacon]) -> (String, String) {
+    let ips: Vec<String> = beacons.iter().map(|b| b.ip.to_string()).collect();
+    let ids: Vec<String> = beacons.iter().map(|b| b.id.to_string()).collect();
+    (ips.join(","), ids.join(","))
+}
+
+fn split(s: &str) -> impl Iterator<Item = &str> {
+    s.split(',').map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::beacons::test_beacons --exact --show-output
+#[test]
+fn test_beacons() {
+    // built-in lists are valid
+    assert_eq!(load(MAINNET).len(), MAINNET.len());
+    assert_eq!(load(FUJI).len(), FUJI.len());
+    assert!(get(12345).is_empty());
+
+    let (ips, ids) = to_flags(&load(FUJI));
+    assert_eq!(parse(&ips, &ids).unwrap(), load(FUJI));
+
+    let beacons = parse(
+        " 127.0.0.1:9651, 127.0.0.1:9653 ,",
+        "NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg,NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ",
+    )
+    .unwrap();
+    assert_eq!(beacons.len(), 2);
+    assert_eq!(beacons[1].ip, "127.0.0.1:9653".parse().unwrap());
+    assert!(parse("", "").unwrap().is_empty());
+    assert!(parse("127.0.0.1:9651", "").is_err());
+    assert!(parse_bootstrap_ips("127.0.0.1").is_err());
+    assert!(parse_bootstrap_ids("NodeID-invalid").is_err());
+
+    // runtime updates (network ID 4 is not used by the other tests)
+    set(4, beacons.clone());
+    assert_eq!(get(4), beacons);
+    load_json(
+        r#"{"everest":[{"id":"NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg","ip":"10.0.0.1:9651"}]}"#,
+    )
+    .unwrap();
+    assert_eq!(get(4).len(), 1);
+    assert_eq!(get(4)[0].ip, "10.0.0.1:9651".parse().unwrap());
+    assert!(load_json(r#"{"unknown":[]}"#).is_err());
+}
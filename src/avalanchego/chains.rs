@@ -0,0 +1,178 @@
+//! Blockchain IDs of the chains created in the network genesis.
+//!
+//! Each genesis chain is the ID of an unsigned "CreateChainTx" on the
+//! primary network, so it only depends on the network ID and the chain
+//! genesis data. Test tooling can thus predict the X/C-chain IDs of a custom
+//! network before the node is even started.
+//! ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/genesis/genesis.go "FromConfig"
+//! ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/api/static_service.go "BuildGenesis"
+
+use std::{collections::HashMap, io};
+
+use crate::{avalanchego::genesis::Genesis, ids, key, platformvm, txs};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#AVMID
+    pub static ref AVM_ID: ids::Id = ids::Id::from_slice(b"avm");
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#EVMID
+    pub static ref EVM_ID: ids::Id = ids::Id::from_slice(b"evm");
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#ID
+    pub static ref SECP256K1FX_ID: ids::Id = ids::Id::from_slice(b"secp256k1fx");
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/nftfx#ID
+    pub static ref NFTFX_ID: ids::Id = ids::Id::from_slice(b"nftfx");
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/propertyfx#ID
+    pub static ref PROPERTYFX_ID: ids::Id = ids::Id::from_slice(b"propertyfx");
+}
+
+/// Chain created in the network genesis.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GenesisChain {
+    pub name: String,
+    pub vm_id: ids::Id,
+    pub fx_ids: Option<Vec<ids::Id>>,
+    pub genesis_data: Vec<u8>,
+}
+
+impl GenesisChain {
+    /// X-chain with the "avm" genesis bytes (e.g., "avm.buildGenesis").
+    pub fn x_chain(genesis_data: Vec<u8>) -> Self {
+        Self {
+            name: String::from("X-Chain"),
+            vm_id: *AVM_ID,
+            fx_ids: Some(vec![*SECP256K1FX_ID, *NFTFX_ID, *PROPERTYFX_ID]),
+            genesis_data,
+        }
+    }
+
+    /// C-chain with the "cChainGenesis" string of the genesis file,
+    /// byte for byte as the node reads it.
+    pub fn c_chain(c_chain_genesis: &str) -> Self {
+        Self {
+            name: String::from("C-Chain"),
+            vm_id: *EVM_ID,
+            fx_ids: None,
+            genesis_data: c_chain_genesis.as_bytes().to_vec(),
+        }
+    }
+
+    /// Computes the blockchain ID on the network.
+    pub async fn chain_id(&self, network_id: u32) -> io::Result<ids::Id> {
+        let mut tx = platformvm::txs::create_chain::Tx {
+            base_tx: txs::Tx {
+                network_id,
+                // the P-chain ID
+                blockchain_id: ids::Id::empty(),
+                ..Default::default()
+            },
+            // the primary network ID
+            subnet_id: ids::Id::empty(),
+            chain_name: self.name.clone(),
+            vm_id: self.vm_id,
+            fx_ids: self.fx_ids.clone(),
+            genesis_data: self.genesis_data.clone(),
+            ..Default::default()
+        };
+
+        // genesis transactions carry no credentials
+        let no_signers: Vec<Vec<key::secp256k1::private_key::Key>> = Vec::new();
+        tx.sign(no_signers).await?;
+        Ok(tx.tx_id())
+    }
+}
+
+/// Predicted blockchain IDs of a network, keyed by the chain alias.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChainIds {
+    pub network_id: u32,
+    pub ids: HashMap<String, ids::Id>,
+}
+
+impl ChainIds {
+    /// Predicts the C-chain ID from the genesis, as written by "Genesis::sync".
+    /// The X-chain ID is predicted as well if its "avm" genesis bytes are given.
+    pub async fn predict(
+        genesis: &Genesis,
+        x_chain_genesis_data: Option<&[u8]>,
+    ) -> io::Result<Self> {
+        let mut ids = HashMap::new();
+
+        let c_chain_genesis = genesis.c_chain_genesis.encode_json()?;
+        let c_chain_id = GenesisChain::c_chain(&c_chain_genesis)
+            .chain_id(genesis.network_id)
+            .await?;
+        ids.insert(String::from("C"), c_chain_id);
+
+        if let Some(d) = x_chain_genesis_data {
+            let x_chain_id = GenesisChain::x_chain(d.to_vec())
+                .chain_id(genesis.network_id)
+                .await?;
+            ids.insert(String::from("X"), x_chain_id);
+        }
+        log::info!(
+            "predicted chain IDs {:?} for network {}",
+            ids,
+            genesis.network_id
+        );
+
+        Ok(Self {
+            network_id: genesis.network_id,
+            ids,
+        })
+    }
+
+    /// Returns the blockchain ID of the alias (e.g., "C").
+    pub fn get(&self, alias: &str) -> Option<ids::Id> {
+        self.ids.get(alias).copied()
+    }
+
+    /// Returns the alias of the blockchain ID, if known.
+    pub fn alias(&self, chain_id: &ids::Id) -> Option<&str> {
+        self.ids
+            .iter()
+            .find(|(_, id)| *id == chain_id)
+            .map(|(alias, _)| alias.as_str())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::chains::test_chain_ids --exact --show-output
+#[test]
+fn test_chain_ids() {
+    assert_eq!(
+        AVM_ID.to_string(),
+        "jvYyfQTxGMJLuGWa55kdP2p2zSUYsQ5Raupu4TW34ZAUBAbtq"
+    );
+
+    let genesis = Genesis::default();
+    let predicted = tokio_test::block_on(ChainIds::predict(&genesis, Some(&[0_u8, 1, 2]))).unwrap();
+    let c_chain_id = predicted.get("C").unwrap();
+    let x_chain_id = predicted.get("X").unwrap();
+    assert_ne!(c_chain_id, ids::Id::empty());
+    assert_ne!(c_chain_id, x_chain_id);
+    assert_eq!(predicted.alias(&c_chain_id), Some("C"));
+    assert_eq!(predicted.alias(&ids::Id::empty()), None);
+
+    // deterministic
+    let again = tokio_test::block_on(ChainIds::predict(&genesis, Some(&[0_u8, 1, 2]))).unwrap();
+    assert_eq!(predicted, again);
+
+    // depends on the network ID and the genesis data
+    let mut other = genesis.clone();
+    other.network_id += 1;
+    let other = tokio_test::block_on(ChainIds::predict(&other, None)).unwrap();
+    assert_ne!(other.get("C").unwrap(), c_chain_id);
+    assert!(other.get("X").is_none());
+    let x = tokio_test::block_on(GenesisChain::x_chain(vec![0, 1, 3]).chain_id(genesis.network_id))
+        .unwrap();
+    assert_ne!(x, x_chain_id);
+
+    // golden vector: "cChainGenesis" of the local network (network ID 12345)
+    // ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/genesis/genesis_local.json
+    let c_chain_genesis = r#"{"config":{"chainId":43112,"homesteadBlock":0,"daoForkBlock":0,"daoForkSupport":true,"eip150Block":0,"eip150Hash":"0x2086799aeebeae135c246c65021c82b4e15a2c451340993aacfd2751886514f0","eip155Block":0,"eip158Block":0,"byzantiumBlock":0,"constantinopleBlock":0,"petersburgBlock":0,"istanbulBlock":0,"muirGlacierBlock":0,"apricotPhase1BlockTimestamp":0,"apricotPhase2BlockTimestamp":0},"nonce":"0x0","timestamp":"0x0","extraData":"0x00","gasLimit":"0x5f5e100","difficulty":"0x0","mixHash":"0x0000000000000000000000000000000000000000000000000000000000000000","coinbase":"0x0000000000000000000000000000000000000000","alloc":{"8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC":{"balance":"0x295BE96E64066972000000"}},"number":"0x0","gasUsed":"0x0","parentHash":"0x0000000000000000000000000000000000000000000000000000000000000000"}"#;
+    let c_chain_id =
+        tokio_test::block_on(GenesisChain::c_chain(c_chain_genesis).chain_id(12345)).unwrap();
+    assert_eq!(
+        c_chain_id.to_string(),
+        "2CA6j5zYzasynPsFeNoqWkmTCt3VScMvXUZHbfDJ8k3oGzAPtU"
+    );
+}
@@ -3,11 +3,12 @@ use std::{
     fs::{self, File},
     io::{self, Error, ErrorKind, Write},
     path::Path,
+    str::FromStr,
     time::SystemTime,
     u64,
 };
 
-use crate::{constants, coreth::genesis as coreth_genesis, key};
+use crate::{constants, coreth::genesis as coreth_genesis, ids::node, key};
 use serde::{Deserialize, Serialize};
 
 /// Represents Avalanche network genesis configuration.
@@ -186,6 +187,86 @@ impl Genesis {
         })
     }
 
+    /// Creates a new Genesis object that funds each key with its target balances,
+    /// and makes the nodes the initial stakers.
+    /// The first key funds the initial stakes with its locked P-chain amount,
+    /// and receives the staking rewards.
+    pub fn new_with_allocations<T: key::secp256k1::ReadOnly>(
+        network_id: u32,
+        funded_keys: &[(T, Funding)],
+        initial_staker_node_ids: &[node::Id],
+    ) -> io::Result<Self> {
+        if funded_keys.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "no key to allocate funds to",
+            ));
+        }
+
+        let mut xp_allocs: Vec<Allocation> = Vec::new();
+        let mut c_allocs = BTreeMap::new();
+        for (k, funding) in funded_keys.iter() {
+            let eth_addr = k.eth_address();
+            xp_allocs.push(Allocation {
+                eth_addr: Some(eth_addr.clone()),
+                avax_addr: Some(k.hrp_address(network_id, "X")?),
+                initial_amount: Some(funding.x_chain_amount),
+                unlock_schedule: Some(vec![LockedAmount {
+                    amount: Some(funding.p_chain_locked_amount),
+                    locktime: None,
+                }]),
+            });
+
+            if !funding.c_chain_amount.is_zero() {
+                c_allocs.insert(
+                    eth_addr.trim_start_matches("0x").to_string(),
+                    coreth_genesis::AllocAccount {
+                        balance: funding.c_chain_amount,
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+
+        let (staking_key, staking_funding) = &funded_keys[0];
+        let staking_addr = staking_key.hrp_address(network_id, "X")?;
+        let initial_stakers = if initial_staker_node_ids.is_empty() {
+            None
+        } else {
+            if staking_funding.p_chain_locked_amount == 0 {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "initial staked funds address {} has no P-chain locked amount",
+                        staking_addr
+                    ),
+                ));
+            }
+            Some(
+                initial_staker_node_ids
+                    .iter()
+                    .map(|node_id| Staker {
+                        node_id: Some(node_id.to_string()),
+                        reward_address: Some(staking_addr.clone()),
+                        ..Default::default()
+                    })
+                    .collect(),
+            )
+        };
+
+        let mut c_chain_genesis = coreth_genesis::Genesis::default();
+        c_chain_genesis.alloc = Some(c_allocs);
+
+        Ok(Self {
+            network_id,
+            allocations: Some(xp_allocs),
+            initial_staked_funds: Some(vec![staking_addr]),
+            initial_stakers,
+            c_chain_genesis,
+            ..Default::default()
+        })
+    }
+
     /// Saves the current configuration to disk
     /// and overwrites the file.
     pub fn sync(&self, file_path: &str) -> io::Result<()> {
@@ -271,6 +352,37 @@ impl Genesis {
     }
 }
 
+/// Target balances of a pre-funded key in the generated genesis.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Funding {
+    /// Unlocked amount on the X-chain, in nAVAX.
+    pub x_chain_amount: u64,
+    /// Locked amount on the P-chain, in nAVAX, that can be staked.
+    pub p_chain_locked_amount: u64,
+    /// Balance on the C-chain, in wei.
+    pub c_chain_amount: primitive_types::U256,
+}
+
+impl Default for Funding {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Funding {
+    pub fn default() -> Self {
+        Self {
+            x_chain_amount: DEFAULT_INITIAL_AMOUNT_X_CHAIN,
+            p_chain_locked_amount: DEFAULT_LOCKED_AMOUNT_P_CHAIN,
+            c_chain_amount: primitive_types::U256::from_str_radix(
+                coreth_genesis::DEFAULT_INITIAL_AMOUNT,
+                16,
+            )
+            .unwrap(),
+        }
+    }
+}
+
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#Allocation
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Allocation {
@@ -390,6 +502,242 @@ impl Staker {
     }
 }
 
+/// Minimum stake of a validator in nAVAX.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#GetStakingConfig
+pub fn min_validator_stake(network_id: u32) -> u64 {
+    match network_id {
+        // 1 AVAX on fuji
+        5 => 1_000_000_000,
+        // 2,000 AVAX on mainnet and local/custom networks
+        _ => 2_000_000_000_000,
+    }
+}
+
+/// Denominator of the delegation fee (e.g., 62500 is 6.25%).
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#PercentDenominator
+pub const PERCENT_DENOMINATOR: u32 = 1_000_000;
+
+/// Checks the genesis for the misconfigurations that make avalanchego
+/// reject it or the network fail to start, and returns all the
+/// problems found in a single error.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#FromConfig
+pub fn validate(genesis: &Genesis) -> io::Result<()> {
+    let mut problems: Vec<String> = Vec::new();
+
+    if genesis.network_id == 0 {
+        problems.push("network ID must be non-zero".to_string());
+    }
+
+    // allocation sums, with the locked amounts per address for the staked funds
+    let mut locked_by_addr: BTreeMap<String, u64> = BTreeMap::new();
+    let mut total: u64 = 0;
+    for (i, alloc) in genesis
+        .allocations
+        .clone()
+        .unwrap_or_default()
+        .iter()
+        .enumerate()
+    {
+        let addr = match &alloc.avax_addr {
+            Some(addr) if !addr.is_empty() => addr.clone(),
+            _ => {
+                problems.push(format!("allocations[{}] has no avaxAddr", i));
+                continue;
+            }
+        };
+        let mut locked: u64 = 0;
+        let mut amounts = vec![alloc.initial_amount.unwrap_or(0)];
+        for unlock in alloc.unlock_schedule.clone().unwrap_or_default() {
+            let amount = unlock.amount.unwrap_or(0);
+            locked = locked.saturating_add(amount);
+            amounts.push(amount);
+        }
+        for amount in amounts {
+            match total.checked_add(amount) {
+                Some(t) => total = t,
+                None => {
+                    problems.push(format!("allocations[{}] overflows the total supply", i));
+                    total = u64::MAX;
+                }
+            }
+        }
+        let entry = locked_by_addr.entry(addr).or_insert(0);
+        *entry = entry.saturating_add(locked);
+    }
+    if total == 0 {
+        problems.push("allocations are empty".to_string());
+    }
+
+    if genesis.start_time.is_none() {
+        problems.push("startTime is not set".to_string());
+    }
+    let duration = genesis.initial_stake_duration.unwrap_or(0);
+    if duration == 0 {
+        problems.push("initialStakeDuration must be non-zero".to_string());
+    }
+
+    // stakers
+    let stakers = genesis.initial_stakers.clone().unwrap_or_default();
+    if stakers.is_empty() {
+        problems.push("initialStakers are empty".to_string());
+    }
+    let mut node_ids = std::collections::HashSet::new();
+    for (i, staker) in stakers.iter().enumerate() {
+        match staker.node_id.as_deref().map(node::Id::from_str) {
+            Some(Ok(node_id)) => {
+                if !node_ids.insert(node_id) {
+                    problems.push(format!("duplicate staker node ID {}", node_id));
+                }
+            }
+            Some(Err(e)) => {
+                problems.push(format!("initialStakers[{}] has invalid nodeID ({})", i, e))
+            }
+            None => problems.push(format!("initialStakers[{}] has no nodeID", i)),
+        }
+        if staker.reward_address.is_none() {
+            problems.push(format!("initialStakers[{}] has no rewardAddress", i));
+        }
+        if staker.delegation_fee.unwrap_or(0) > PERCENT_DENOMINATOR {
+            problems.push(format!(
+                "initialStakers[{}] delegation fee {} exceeds {}",
+                i,
+                staker.delegation_fee.unwrap_or(0),
+                PERCENT_DENOMINATOR
+            ));
+        }
+    }
+
+    // the staked funds are split among the stakers
+    let staked_funds = genesis.initial_staked_funds.clone().unwrap_or_default();
+    if !stakers.is_empty() && staked_funds.is_empty() {
+        problems.push("initialStakedFunds are empty".to_string());
+    }
+    let mut staked_total: u64 = 0;
+    let mut seen = std::collections::HashSet::new();
+    for addr in staked_funds.iter() {
+        if !seen.insert(addr) {
+            problems.push(format!("duplicate staked funds address {}", addr));
+            continue;
+        }
+        match locked_by_addr.get(addr) {
+            Some(locked) => staked_total = staked_total.saturating_add(*locked),
+            None => problems.push(format!("staked funds address {} has no allocation", addr)),
+        }
+    }
+    if !stakers.is_empty() && !staked_funds.is_empty() {
+        let per_staker = staked_total / stakers.len() as u64;
+        let min = min_validator_stake(genesis.network_id);
+        if per_staker < min {
+            problems.push(format!(
+                "stake per initial staker {} is below the minimum validator stake {}",
+                per_staker, min
+            ));
+        }
+
+        // each staker ends "offset" earlier than the previous one
+        let offset = genesis.initial_stake_duration_offset.unwrap_or(0);
+        let last_offset = offset.saturating_mul(stakers.len() as u64 - 1);
+        if duration > 0 && last_offset >= duration {
+            problems.push(format!(
+                "initialStakeDuration {} does not cover the offset {} of the last staker (end must be after start)",
+                duration, last_offset
+            ));
+        }
+    }
+
+    // C-chain genesis
+    let c_chain = &genesis.c_chain_genesis;
+    match &c_chain.config {
+        Some(cfg) if cfg.chain_id.is_some() => {}
+        _ => problems.push("cChainGenesis has no config.chainId".to_string()),
+    }
+    if c_chain.gas_limit.is_zero() {
+        problems.push("cChainGenesis gasLimit must be non-zero".to_string());
+    }
+    for addr in c_chain.alloc.clone().unwrap_or_default().keys() {
+        let hex = addr.trim_start_matches("0x");
+        if hex.len() != 40 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            problems.push(format!(
+                "cChainGenesis alloc has invalid address '{}'",
+                addr
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(Error::new(
+        ErrorKind::InvalidInput,
+        format!("invalid genesis: {}", problems.join("; ")),
+    ))
+}
+
+/// Field that differs between two genesis configurations.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Change {
+    /// JSON path of the field (e.g., "allocations[1].initialAmount").
+    pub path: String,
+    /// None if the field was added.
+    pub before: Option<serde_json::Value>,
+    /// None if the field was removed.
+    pub after: Option<serde_json::Value>,
+}
+
+/// Returns the fields that differ from "before" to "after", in path order.
+pub fn diff(before: &Genesis, after: &Genesis) -> io::Result<Vec<Change>> {
+    let to_value = |g: &Genesis| {
+        serde_json::to_value(g).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize genesis to JSON {}", e),
+            )
+        })
+    };
+    let mut changes = Vec::new();
+    diff_values(
+        "",
+        Some(&to_value(before)?),
+        Some(&to_value(after)?),
+        &mut changes,
+    );
+    Ok(changes)
+}
+
+fn diff_values(
+    path: &str,
+    before: Option<&serde_json::Value>,
+    after: Option<&serde_json::Value>,
+    changes: &mut Vec<Change>,
+) {
+    use serde_json::Value;
+
+    match (before, after) {
+        (Some(Value::Object(a)), Some(Value::Object(b))) => {
+            let keys: std::collections::BTreeSet<&String> = a.keys().chain(b.keys()).collect();
+            for k in keys {
+                let p = if path.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{}.{}", path, k)
+                };
+                diff_values(&p, a.get(k), b.get(k), changes);
+            }
+        }
+        (Some(Value::Array(a)), Some(Value::Array(b))) => {
+            for i in 0..a.len().max(b.len()) {
+                diff_values(&format!("{}[{}]", path, i), a.get(i), b.get(i), changes);
+            }
+        }
+        (a, b) if a != b => changes.push(Change {
+            path: path.to_string(),
+            before: a.cloned(),
+            after: b.cloned(),
+        }),
+        _ => {}
+    }
+}
+
 #[test]
 fn test_genesis() {
     let _ = env_logger::builder()
@@ -570,3 +918,118 @@ fn test_genesis() {
     let d = fs::read_to_string(&p).unwrap();
     log::info!("{}", d);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_genesis_with_allocations --exact --show-output
+#[test]
+fn test_genesis_with_allocations() {
+    let k1 = key::secp256k1::private_key::Key::generate().unwrap();
+    let k2 = key::secp256k1::private_key::Key::generate().unwrap();
+    let node_ids = vec![
+        node::Id::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap(),
+        node::Id::from_str("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ").unwrap(),
+    ];
+
+    let genesis = Genesis::new_with_allocations(
+        1337,
+        &[
+            (k1.clone(), Funding::default()),
+            (
+                k2.clone(),
+                Funding {
+                    x_chain_amount: 1000,
+                    p_chain_locked_amount: 0,
+                    c_chain_amount: primitive_types::U256::zero(),
+                },
+            ),
+        ],
+        &node_ids,
+    )
+    .unwrap();
+
+    let k1_addr = k1.to_public_key().hrp_address(1337, "X").unwrap();
+    let allocs = genesis.allocations.clone().unwrap();
+    assert_eq!(allocs.len(), 2);
+    assert_eq!(allocs[0].avax_addr, Some(k1_addr.clone()));
+    assert_eq!(allocs[1].initial_amount, Some(1000));
+    assert_eq!(genesis.initial_staked_funds, Some(vec![k1_addr.clone()]));
+
+    let stakers = genesis.initial_stakers.clone().unwrap();
+    assert_eq!(stakers.len(), 2);
+    assert_eq!(stakers[1].node_id, Some(node_ids[1].to_string()));
+    assert_eq!(stakers[0].reward_address, Some(k1_addr));
+
+    // zero C-chain balances are left out of the alloc
+    let c_allocs = genesis.c_chain_genesis.alloc.clone().unwrap();
+    assert_eq!(c_allocs.len(), 1);
+    assert!(c_allocs.contains_key(k1.to_public_key().eth_address().trim_start_matches("0x")));
+
+    let p = random_manager::tmp_path(10, Some(".json")).unwrap();
+    genesis.sync(&p).unwrap();
+    assert_eq!(Genesis::load(&p).unwrap(), genesis);
+
+    // staked funds must be locked on the P-chain
+    assert!(Genesis::new_with_allocations(
+        1337,
+        &[(
+            k2,
+            Funding {
+                p_chain_locked_amount: 0,
+                ..Default::default()
+            }
+        )],
+        &node_ids,
+    )
+    .is_err());
+    assert!(
+        Genesis::new_with_allocations::<key::secp256k1::private_key::Key>(1337, &[], &[]).is_err()
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avalanchego::genesis::test_validate_and_diff --exact --show-output
+#[test]
+fn test_validate_and_diff() {
+    let k1 = key::secp256k1::private_key::Key::generate().unwrap();
+    let node_ids = vec![
+        node::Id::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap(),
+        node::Id::from_str("NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ").unwrap(),
+    ];
+    let genesis =
+        Genesis::new_with_allocations(1337, &[(k1, Funding::default())], &node_ids).unwrap();
+    validate(&genesis).unwrap();
+
+    // duplicate node ID, stake below the minimum, and offsets past the duration
+    let mut bad = genesis.clone();
+    let mut stakers = bad.initial_stakers.clone().unwrap();
+    stakers.push(stakers[0].clone());
+    bad.initial_stakers = Some(stakers);
+    bad.allocations.as_mut().unwrap()[0].unlock_schedule = Some(vec![LockedAmount {
+        amount: Some(1),
+        locktime: None,
+    }]);
+    bad.initial_stake_duration = Some(100);
+    bad.initial_stake_duration_offset = Some(60);
+    bad.c_chain_genesis.config = None;
+    let err = validate(&bad).unwrap_err().to_string();
+    assert!(err.contains("duplicate staker node ID"));
+    assert!(err.contains("below the minimum validator stake"));
+    assert!(err.contains("end must be after start"));
+    assert!(err.contains("chainId"));
+
+    let mut overflow = genesis.clone();
+    overflow.allocations.as_mut().unwrap()[0].initial_amount = Some(u64::MAX);
+    assert!(validate(&overflow)
+        .unwrap_err()
+        .to_string()
+        .contains("overflows"));
+
+    assert!(diff(&genesis, &genesis).unwrap().is_empty());
+    let mut changed = genesis.clone();
+    changed.network_id = 1338;
+    changed.initial_stakers.as_mut().unwrap().pop();
+    let changes = diff(&genesis, &changed).unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].path, "initialStakers[1]");
+    assert!(changes[0].after.is_none());
+    assert_eq!(changes[1].path, "networkID");
+    assert_eq!(changes[1].after, Some(serde_json::json!(1338)));
+}
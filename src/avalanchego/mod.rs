@@ -1,2 +1,4 @@
+pub mod beacons;
+pub mod chains;
 pub mod config;
 pub mod genesis;
@@ -0,0 +1,101 @@
+//! X-chain block parser for the linearized chain (post-Cortina).
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/blocks
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{codec, ids, packer::Packer};
+
+/// Maximum size of an X-chain block.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#DefaultMaxMessageSize
+pub const MAX_BLOCK_SIZE: usize = 2 * 1024 * 1024;
+
+/// Codec type ID of "StandardBlock", registered after the 5 tx types
+/// and the 15 types of the secp256k1fx, nftfx, and propertyfx.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/blocks#NewParser
+pub const STANDARD_BLOCK_TYPE_ID: u32 = 20;
+
+/// Parsed X-chain standard block.
+/// The transactions are kept in their signed bytes, concatenated,
+/// since their boundaries are only known by decoding each tx.
+/// Use the "json" encoding of "avm.getBlock" to read the decoded txs.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Block {
+    /// SHA256 hash of the block bytes.
+    pub id: ids::Id,
+    /// Codec version that the block is marshaled with.
+    pub codec_version: u16,
+    pub parent_id: ids::Id,
+    pub height: u64,
+    /// Unix timestamp.
+    pub timestamp: u64,
+    pub merkle_root: ids::Id,
+    pub tx_count: u32,
+    pub txs_bytes: Vec<u8>,
+}
+
+impl Block {
+    /// Parses the block from its codec bytes.
+    pub fn unpack(d: &[u8]) -> io::Result<Self> {
+        let packer = Packer::load_bytes_for_unpack(MAX_BLOCK_SIZE, d);
+
+        let codec_version = packer.unpack_u16()?;
+        codec::REGISTRY.check_version(codec::Vm::X, codec_version)?;
+        let type_id = packer.unpack_u32()?;
+        if type_id != STANDARD_BLOCK_TYPE_ID {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown X-chain block type ID {}", type_id),
+            ));
+        }
+
+        let parent_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let height = packer.unpack_u64()?;
+        let timestamp = packer.unpack_u64()?;
+        let merkle_root = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let tx_count = packer.unpack_u32()?;
+        let txs_bytes = packer.unpack_bytes(d.len() - packer.get_offset())?;
+
+        Ok(Self {
+            id: ids::Id::sha256(d),
+            codec_version,
+            parent_id,
+            height,
+            timestamp,
+            merkle_root,
+            tx_count,
+            txs_bytes,
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- avm::block::test_unpack --exact --show-output
+#[test]
+fn test_unpack() {
+    let parent_id = ids::Id::sha256(b"parent");
+
+    let packer = Packer::new(MAX_BLOCK_SIZE, 128);
+    packer.pack_u16(codec::VERSION).unwrap();
+    packer.pack_u32(STANDARD_BLOCK_TYPE_ID).unwrap();
+    packer.pack_bytes(parent_id.as_ref()).unwrap();
+    packer.pack_u64(3).unwrap();
+    packer.pack_u64(1_680_000_000).unwrap();
+    packer.pack_bytes(ids::Id::empty().as_ref()).unwrap();
+    packer.pack_u32(1).unwrap();
+    packer.pack_bytes(&[0x00, 0x00, 0x00]).unwrap();
+    let b = packer.take_bytes();
+
+    let blk = Block::unpack(&b).unwrap();
+    assert_eq!(blk.id, ids::Id::sha256(&b));
+    assert_eq!(blk.codec_version, codec::VERSION);
+    assert_eq!(blk.parent_id, parent_id);
+    assert_eq!(blk.height, 3);
+    assert_eq!(blk.timestamp, 1_680_000_000);
+    assert_eq!(blk.merkle_root, ids::Id::empty());
+    assert_eq!(blk.tx_count, 1);
+    assert_eq!(blk.txs_bytes, vec![0x00, 0x00, 0x00]);
+
+    let mut wrong_type = b.to_vec();
+    wrong_type[5] = 0x00;
+    assert!(Block::unpack(&wrong_type).is_err());
+    assert!(Block::unpack(&b[..20]).is_err());
+}
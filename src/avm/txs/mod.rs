@@ -3,10 +3,12 @@ pub mod fx;
 pub mod import;
 pub mod vertex;
 
-use std::io::{self, Error, ErrorKind};
+use std::io;
 
-use crate::{codec, ids, key, txs};
-use ring::digest::{digest, SHA256};
+use crate::{
+    codec, ids, key, packer,
+    txs::{self, sign::Signable, verify::Verifiable},
+};
 use serde::{Deserialize, Serialize};
 
 /// Base transaction.
@@ -77,77 +79,41 @@ impl Tx {
         &mut self,
         signers: Vec<Vec<T>>,
     ) -> io::Result<()> {
-        // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
-
-        // "avalanchego" marshals the whole struct again for signed bytes
-        // even when the underlying "unsigned_tx" is already once marshaled
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx
-        //
-        // reuse the underlying packer to avoid marshaling the unsigned tx twice
-        // just marshal the next fields in the struct and pack them all together
-        // in the existing packer
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "avm.Tx" unsigned tx
-        // not other fields -- only hash "avm.Tx.UnsignedTx" but not "avm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm#Tx
-        let tx_bytes_hash: Vec<u8> = digest(&SHA256, &tx_bytes_with_no_signature).as_ref().into();
-
-        // number of of credentials
-        let fx_creds_len = signers.len() as u32;
-        // pack the second field in the struct
-        packer.pack_u32(fx_creds_len)?;
+        txs::sign::sign_with_codec_version(self, codec::VERSION, signers).await
+    }
+}
 
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.fx_creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await.map_err(|e| {
-                    Error::new(ErrorKind::Other, format!("failed sign_digest {}", e))
-                })?;
-                sigs.push(Vec::from(sig));
-            }
+impl Verifiable for Tx {
+    const VM: codec::Vm = codec::Vm::X;
 
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
+    fn pack_unsigned(&self, codec_version: u16) -> io::Result<packer::Packer> {
+        // marshal "unsigned tx" with the codec version
+        let type_id = codec::REGISTRY.type_id(Self::VM, codec_version, &Self::type_name())?;
+        self.base_tx.pack(Self::VM, codec_version, type_id)
+    }
 
-            let mut fx_cred = fx::Credential::default();
-            fx_cred.cred = cred;
+    fn signed_inputs(&self) -> Vec<txs::transferable::Input> {
+        self.base_tx.transferable_inputs.clone().unwrap_or_default()
+    }
 
-            // add a new credential to "Tx"
-            self.fx_creds.push(fx_cred);
-        }
-        if fx_creds_len > 0 {
-            // pack each "fx_cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for fx_cred in self.fx_creds.iter() {
-                packer.pack_u32(cred_type_id)?;
-                packer.pack_u32(fx_cred.cred.signatures.len() as u32)?;
-                for sig in fx_cred.cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id: Vec<u8> = digest(&SHA256, &tx_bytes_with_signatures).as_ref().into();
+    fn credentials(&self) -> Vec<key::secp256k1::txs::Credential> {
+        self.fx_creds.iter().map(|c| c.cred.clone()).collect()
+    }
+}
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/avm.Tx.SignSECP256K1Fx"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+impl Signable for Tx {
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
 
-        Ok(())
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.fx_creds = creds
+            .into_iter()
+            .map(|cred| fx::Credential {
+                cred,
+                ..fx::Credential::default()
+            })
+            .collect();
     }
 }
 
@@ -0,0 +1,118 @@
+//! Shared runtime and wallet calls of the foreign bindings
+//! (C ABI in "ffi", pyo3 in "python"), so that both expose the same behavior.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    client::{
+        p as client_p,
+        wallet::{self, signed::Signed},
+        x as client_x,
+    },
+    ids::{self, short},
+    key::secp256k1::{self, private_key::Key},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Runtime of the blocking calls from the foreign callers.
+    pub static ref RUNTIME: tokio::runtime::Runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("avalanche-types-bindings")
+        .build()
+        .expect("failed to build tokio runtime");
+}
+
+/// Loads the private key in hex ("0x...") or CB58 ("PrivateKey-...").
+pub fn parse_key(s: &str) -> io::Result<Key> {
+    let s = s.trim();
+    if s.starts_with(secp256k1::private_key::HEX_ENCODE_PREFIX) {
+        Key::from_hex(s)
+    } else {
+        Key::from_cb58(s)
+    }
+}
+
+pub async fn build_wallet(http_rpc: &str, key: &Key) -> io::Result<wallet::Wallet<Key>> {
+    wallet::Builder::new(key)
+        .http_rpc(http_rpc.to_string())
+        .build()
+        .await
+}
+
+/// Issues the signed transaction in hex to the "X" or "P" chain,
+/// and returns the transaction Id.
+pub async fn issue_tx(http_rpc: &str, chain_alias: &str, tx_hex: &str) -> io::Result<ids::Id> {
+    match chain_alias {
+        "X" => Ok(client_x::issue_tx(http_rpc, tx_hex)
+            .await?
+            .into_result()?
+            .tx_id),
+        "P" => Ok(client_p::issue_tx(http_rpc, tx_hex)
+            .await?
+            .into_result()?
+            .tx_id),
+        _ => Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("unsupported chain alias '{}'", chain_alias),
+        )),
+    }
+}
+
+/// Builds and signs the X-chain AVAX transfer to the receiver address
+/// (e.g., "X-avax1..."), and issues it if "issue" is true.
+pub async fn x_transfer(
+    http_rpc: &str,
+    key: &Key,
+    receiver: &str,
+    amount: u64,
+    issue: bool,
+    check_acceptance: bool,
+) -> io::Result<Signed> {
+    let (_, receiver) = secp256k1::address::avax_address_to_short_bytes("X", receiver)
+        .map_err(|e| Error::new(ErrorKind::InvalidInput, format!("invalid receiver ({})", e)))?;
+    let receiver = short::Id::from_slice(&receiver);
+
+    let w = build_wallet(http_rpc, key).await?;
+    let tx = w
+        .x()
+        .transfer()
+        .receiver(receiver)
+        .amount(amount)
+        .check_acceptance(check_acceptance);
+    if issue {
+        tx.issue_signed().await
+    } else {
+        tx.sign().await
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- bindings::test_parse_key --exact --show-output
+#[test]
+fn test_parse_key() {
+    let key = Key::generate().unwrap();
+    assert_eq!(parse_key(&key.to_hex()).unwrap().to_cb58(), key.to_cb58());
+    assert_eq!(
+        parse_key(&format!(" {}\n", key.to_cb58()))
+            .unwrap()
+            .to_hex(),
+        key.to_hex()
+    );
+    assert!(parse_key("PrivateKey-invalid").is_err());
+
+    let err = RUNTIME
+        .block_on(issue_tx("http://localhost:9650", "C", "0x00"))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    let err = RUNTIME
+        .block_on(x_transfer(
+            "http://localhost:9650",
+            &key,
+            "X-invalid",
+            1,
+            false,
+            false,
+        ))
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidInput);
+}
@@ -1,3 +1,5 @@
+use std::io;
+
 use crate::errors::Result;
 use crate::{choices::status::Status, ids::Id};
 
@@ -14,3 +16,100 @@ pub trait Decidable {
     /// Rejects this element.
     fn reject(&mut self) -> Result<()>;
 }
+
+/// Async variant of "Decidable", as required by the VM server
+/// (see "subnet::rpc::consensus::snowman::Block").
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/choices#Decidable
+#[async_trait::async_trait]
+pub trait AsyncDecidable {
+    /// Returns the ID of this element.
+    async fn id(&self) -> Id;
+
+    /// Returns the current status.
+    async fn status(&self) -> Status;
+
+    /// Accepts this element.
+    async fn accept(&mut self) -> io::Result<()>;
+
+    /// Rejects this element.
+    async fn reject(&mut self) -> io::Result<()>;
+}
+
+/// Wraps a "Decidable" to implement "AsyncDecidable", so that one element
+/// type serves both the local consensus helpers and the VM server.
+#[derive(Clone, Debug)]
+pub struct AsyncAdapter<T>(pub T);
+
+impl<T> AsyncAdapter<T> {
+    pub fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for AsyncAdapter<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> std::ops::DerefMut for AsyncAdapter<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+#[async_trait::async_trait]
+impl<T> AsyncDecidable for AsyncAdapter<T>
+where
+    T: Decidable + Send + Sync,
+{
+    async fn id(&self) -> Id {
+        Decidable::id(&self.0)
+    }
+
+    async fn status(&self) -> Status {
+        Decidable::status(&self.0)
+    }
+
+    async fn accept(&mut self) -> io::Result<()> {
+        Decidable::accept(&mut self.0).map_err(io::Error::from)
+    }
+
+    async fn reject(&mut self) -> io::Result<()> {
+        Decidable::reject(&mut self.0).map_err(io::Error::from)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- choices::decidable::test_async_adapter --exact --show-output
+#[test]
+fn test_async_adapter() {
+    use crate::{choices::test_decidable::TestDecidable, errors::Error};
+
+    let id = Id::from_slice(&[1, 2, 3]);
+    let mut d = AsyncAdapter::new(TestDecidable::new(id, Status::Processing));
+    assert_eq!(tokio_test::block_on(AsyncDecidable::id(&d)), id);
+    tokio_test::block_on(AsyncDecidable::accept(&mut d)).unwrap();
+    assert_eq!(
+        tokio_test::block_on(AsyncDecidable::status(&d)),
+        Status::Accepted
+    );
+    assert_eq!(d.into_inner().status(), Status::Accepted);
+
+    let mut d = AsyncAdapter::new(TestDecidable::new(id, Status::Processing));
+    d.set_reject_result(Err(Error::Other {
+        message: "test error".to_string(),
+        is_retryable: false,
+    }));
+    let e = tokio_test::block_on(AsyncDecidable::reject(&mut d)).unwrap_err();
+    assert!(e.to_string().contains("test error"));
+    assert_eq!(
+        tokio_test::block_on(AsyncDecidable::status(&d)),
+        Status::Processing
+    );
+}
@@ -1,15 +1,15 @@
-use std::io;
+use std::io::{self, Error, ErrorKind};
 
 use crate::packer::Packer;
 use bytes::Bytes;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Defines possible status values.
+/// Serialized as the strings that the avalanchego APIs return
+/// (e.g., "Accepted"), with any other string kept as "Unknown".
 ///
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/choices#Status
 #[derive(
-    Deserialize,
-    Serialize,
     std::clone::Clone,
     std::cmp::Eq,
     std::cmp::Ord,
@@ -49,6 +49,25 @@ impl std::convert::From<&str> for Status {
     }
 }
 
+impl Serialize for Status {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(Status::from(s.as_str()))
+    }
+}
+
 impl std::str::FromStr for Status {
     type Err = std::convert::Infallible;
 
@@ -86,6 +105,35 @@ impl Status {
         matches!(self, Status::Rejected | Status::Accepted)
     }
 
+    /// Returns "true" if the status has been decided, which is final.
+    pub fn is_decided(&self) -> bool {
+        self.decided()
+    }
+
+    /// Returns "true" if the status may change to the next status.
+    /// Only a processing operation may be decided, and an unknown operation
+    /// may only start processing (e.g., once issued or fetched).
+    pub fn can_transition_to(&self, next: &Status) -> bool {
+        matches!(
+            (self, next),
+            (Status::Processing, Status::Accepted | Status::Rejected)
+                | (Status::Unknown(_), Status::Processing)
+        )
+    }
+
+    /// Changes the status to the next status, failing on an invalid
+    /// transition (e.g., from "Rejected" to "Accepted").
+    pub fn transition(&mut self, next: Status) -> io::Result<()> {
+        if !self.can_transition_to(&next) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid status transition from '{}' to '{}'", self, next),
+            ));
+        }
+        *self = next;
+        Ok(())
+    }
+
     /// Returns "true" if the status has been set.
     pub fn fetched(&self) -> bool {
         match self {
@@ -119,6 +167,21 @@ impl Status {
         iota
     }
 
+    /// Returns the status of the numeric code used by the VM protobufs
+    /// (see "to_u32"), where zero is "Unknown".
+    pub fn from_u32(n: u32) -> io::Result<Self> {
+        match n {
+            0 => Ok(Status::Unknown("Unknown".to_owned())),
+            1 => Ok(Status::Processing),
+            2 => Ok(Status::Rejected),
+            3 => Ok(Status::Accepted),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown status code {}", n),
+            )),
+        }
+    }
+
     /// Returns native endian value from a slice if u8s.
     pub fn u32_from_slice(bytes: &[u8]) -> u32 {
         assert!(bytes.len() <= 4);
@@ -157,3 +220,42 @@ fn test_to_u32() {
     assert_eq!(Status::Rejected.to_u32(), 2);
     assert_eq!(Status::Accepted.to_u32(), 3);
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- choices::status::test_transition --exact --show-output
+#[test]
+fn test_transition() {
+    let mut status = Status::default();
+    assert!(!status.is_decided());
+    assert!(status.transition(Status::Accepted).is_err());
+
+    status.transition(Status::Processing).unwrap();
+    assert!(!status.is_decided());
+    assert!(status.can_transition_to(&Status::Rejected));
+    status.transition(Status::Accepted).unwrap();
+    assert!(status.is_decided());
+
+    // decided is final
+    assert!(status.transition(Status::Rejected).is_err());
+    assert!(status.transition(Status::Processing).is_err());
+    assert!(status.transition(Status::Accepted).is_err());
+    assert_eq!(status, Status::Accepted);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- choices::status::test_serde --exact --show-output
+#[test]
+fn test_serde() {
+    assert_eq!(
+        serde_json::to_string(&Status::Accepted).unwrap(),
+        "\"Accepted\""
+    );
+    let status: Status = serde_json::from_str("\"Processing\"").unwrap();
+    assert_eq!(status, Status::Processing);
+    let status: Status = serde_json::from_str("\"Dropped\"").unwrap();
+    assert_eq!(status, Status::Unknown("Dropped".to_string()));
+    assert_eq!(serde_json::to_string(&status).unwrap(), "\"Dropped\"");
+
+    for n in 0..4 {
+        assert_eq!(Status::from_u32(n).unwrap().to_u32(), n);
+    }
+    assert!(Status::from_u32(4).is_err());
+}
@@ -0,0 +1,45 @@
+use std::{collections::HashMap, io};
+
+use crate::{
+    client::transport,
+    jsonrpc::{self, admin},
+};
+
+/// e.g., "admin.loadVMs" that loads the newly installed VM plugins.
+/// Requires the node to run with "--api-admin-enabled".
+/// ref. https://docs.avax.network/apis/avalanchego/apis/admin#adminloadvms
+pub async fn load_vms(http_rpc: &str) -> io::Result<admin::LoadVmsResponse> {
+    log::info!("loading VMs for {}", http_rpc);
+
+    let call = jsonrpc::Call::new("admin.loadVMs");
+    let data = call.request();
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "ext/admin", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "admin.getLoggerLevel" that returns the log and display levels
+/// of the logger, or of every logger if None.
+/// Requires the node to run with "--api-admin-enabled".
+/// ref. https://docs.avax.network/apis/avalanchego/apis/admin#admingetloggerlevel
+pub async fn get_logger_level(
+    http_rpc: &str,
+    logger_name: Option<&str>,
+) -> io::Result<admin::GetLoggerLevelResponse> {
+    log::info!("getting logger level for {}", http_rpc);
+
+    let call = jsonrpc::Call::new("admin.getLoggerLevel");
+
+    let mut params = HashMap::new();
+    if let Some(logger_name) = logger_name {
+        params.insert(String::from("loggerName"), String::from(logger_name));
+    }
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "ext/admin", &d).await?;
+
+    call.decode(&rb)
+}
@@ -0,0 +1,342 @@
+//! Progress and completion-time estimation for a bootstrapping node.
+//!
+//! The monitor periodically samples "info.getNodeVersion", "health", and
+//! "info.isBootstrapped" of each chain, and estimates the remaining time
+//! from the progress observed over the recent samples. When a reference
+//! endpoint is set (e.g., a public API node), the C-chain height of the
+//! node relative to the reference height is used as the C-chain progress.
+//!
+//! ```
+//! # async fn run() {
+//! use std::time::Duration;
+//! use avalanche_types::client::bootstrap::Monitor;
+//! use futures::StreamExt;
+//!
+//! let snapshots = Monitor::new("http://localhost:9650")
+//!     .interval(Duration::from_secs(30))
+//!     .target_http_rpc("https://api.avax.network")
+//!     .stream();
+//! futures::pin_mut!(snapshots);
+//! while let Some(s) = snapshots.next().await {
+//!     println!("{:.1}% (ETA {:?})", s.progress * 100.0, s.eta);
+//! }
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::client::{
+    cancel, evm as client_evm, health as client_health, info as client_info, time::Instant,
+};
+
+/// Chains of the primary network.
+pub const DEFAULT_CHAINS: [&str; 3] = ["P", "X", "C"];
+
+/// Default wait between samples.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Default number of recent samples to estimate the progress rate.
+pub const DEFAULT_WINDOW: usize = 10;
+
+/// Bootstrap progress of the node at a point in time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snapshot {
+    /// Time since the monitoring started.
+    pub elapsed: Duration,
+    /// None if the node is not serving the info API yet.
+    pub node_version: Option<String>,
+    pub healthy: bool,
+    /// Bootstrapped status of each monitored chain.
+    pub chains: BTreeMap<String, bool>,
+    pub c_chain_height: Option<u64>,
+    /// C-chain height of the reference endpoint.
+    pub target_height: Option<u64>,
+    /// Estimated progress in [0, 1].
+    pub progress: f64,
+    /// Estimated remaining time, None until the progress rate is known.
+    pub eta: Option<Duration>,
+}
+
+impl Snapshot {
+    pub fn is_bootstrapped(&self) -> bool {
+        !self.chains.is_empty() && self.chains.values().all(|b| *b)
+    }
+
+    /// Returns the progress of the chains, where a bootstrapped chain counts
+    /// as 1, and the C-chain counts as its height relative to the target
+    /// height if known.
+    fn compute_progress(&self) -> f64 {
+        if self.chains.is_empty() {
+            return 0.0;
+        }
+        let sum: f64 = self
+            .chains
+            .iter()
+            .map(|(chain, bootstrapped)| {
+                if *bootstrapped {
+                    return 1.0;
+                }
+                match (chain.as_str(), self.c_chain_height, self.target_height) {
+                    ("C", Some(h), Some(t)) if t > 0 => (h as f64 / t as f64).min(1.0),
+                    _ => 0.0,
+                }
+            })
+            .sum();
+        sum / self.chains.len() as f64
+    }
+}
+
+/// Estimates the remaining time from the progress rate
+/// over a sliding window of samples.
+#[derive(Debug, Clone)]
+pub struct Estimator {
+    window: usize,
+    samples: VecDeque<(Duration, f64)>,
+}
+
+impl Estimator {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(2),
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Records the progress at the elapsed time, and returns the estimated
+    /// remaining time. Returns None if no progress was made in the window.
+    pub fn observe(&mut self, elapsed: Duration, progress: f64) -> Option<Duration> {
+        if self.samples.len() == self.window {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((elapsed, progress));
+        if progress >= 1.0 {
+            return Some(Duration::ZERO);
+        }
+
+        let (first_elapsed, first_progress) = *self.samples.front()?;
+        let took = elapsed.checked_sub(first_elapsed)?.as_secs_f64();
+        let made = progress - first_progress;
+        if took <= 0.0 || made <= 0.0 {
+            return None;
+        }
+        Some(Duration::from_secs_f64((1.0 - progress) * took / made))
+    }
+}
+
+/// Samples the bootstrap progress of a node.
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    pub http_rpc: String,
+    pub chains: Vec<String>,
+    pub interval: Duration,
+    /// Reference endpoint for the C-chain target height.
+    pub target_http_rpc: Option<String>,
+    pub window: usize,
+}
+
+impl Monitor {
+    pub fn new(http_rpc: &str) -> Self {
+        Self {
+            http_rpc: http_rpc.to_string(),
+            chains: DEFAULT_CHAINS.iter().map(|c| c.to_string()).collect(),
+            interval: DEFAULT_INTERVAL,
+            target_http_rpc: None,
+            window: DEFAULT_WINDOW,
+        }
+    }
+
+    /// Sets the chains to monitor.
+    #[must_use]
+    pub fn chains(mut self, chains: Vec<String>) -> Self {
+        self.chains = chains;
+        self
+    }
+
+    /// Sets the wait between samples.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Sets the reference endpoint for the C-chain target height.
+    #[must_use]
+    pub fn target_http_rpc(mut self, target_http_rpc: &str) -> Self {
+        self.target_http_rpc = Some(target_http_rpc.to_string());
+        self
+    }
+
+    /// Sets the number of recent samples to estimate the progress rate.
+    #[must_use]
+    pub fn window(mut self, window: usize) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// Samples the node once. The node may not be serving the APIs yet,
+    /// so the failed calls are recorded as not ready rather than errors.
+    pub async fn sample(&self, elapsed: Duration) -> Snapshot {
+        let node_version = client_info::get_node_version(&self.http_rpc)
+            .await
+            .and_then(|resp| resp.into_result())
+            .ok()
+            .map(|r| r.version);
+        let healthy = client_health::check(Arc::new(self.http_rpc.clone()), false)
+            .await
+            .map(|resp| resp.healthy)
+            .unwrap_or(false);
+
+        let mut chains = BTreeMap::new();
+        for chain in self.chains.iter() {
+            let bootstrapped = client_info::is_chain_bootstrapped(&self.http_rpc, chain)
+                .await
+                .and_then(|resp| resp.into_result())
+                .map(|r| r.is_bootstrapped)
+                .unwrap_or(false);
+            chains.insert(chain.clone(), bootstrapped);
+        }
+
+        let (mut c_chain_height, mut target_height) = (None, None);
+        if let (Some(target), Some(false)) = (&self.target_http_rpc, chains.get("C")) {
+            c_chain_height = client_evm::block_number(&self.http_rpc, "C")
+                .await
+                .and_then(|resp| resp.into_result())
+                .ok()
+                .map(|h| h.as_u64());
+            target_height = client_evm::block_number(target, "C")
+                .await
+                .and_then(|resp| resp.into_result())
+                .ok()
+                .map(|h| h.as_u64());
+        }
+
+        let mut snapshot = Snapshot {
+            elapsed,
+            node_version,
+            healthy,
+            chains,
+            c_chain_height,
+            target_height,
+            progress: 0.0,
+            eta: None,
+        };
+        snapshot.progress = snapshot.compute_progress();
+        snapshot
+    }
+
+    /// Returns the stream of snapshots, one per interval, that ends
+    /// after the first snapshot with all chains bootstrapped,
+    /// or when the "cancel::scope" is cancelled.
+    pub fn stream(self) -> impl futures::Stream<Item = Snapshot> {
+        let estimator = Estimator::new(self.window);
+        futures::stream::unfold(
+            (self, estimator, Instant::now(), true, false),
+            |(monitor, mut estimator, start, first, done)| async move {
+                if done {
+                    return None;
+                }
+                if !first && cancel::sleep(monitor.interval).await.is_err() {
+                    return None;
+                }
+
+                let mut snapshot = monitor.sample(start.elapsed()).await;
+                snapshot.eta = estimator.observe(snapshot.elapsed, snapshot.progress);
+                let done = snapshot.is_bootstrapped();
+                log::info!(
+                    "bootstrap progress {:.1}% (ETA {:?}, chains {:?})",
+                    snapshot.progress * 100.0,
+                    snapshot.eta,
+                    snapshot.chains
+                );
+                Some((snapshot, (monitor, estimator, start, false, done)))
+            },
+        )
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::bootstrap::test_monitor --exact --show-output
+#[tokio::test]
+async fn test_monitor() {
+    use crate::client::transport::{self, Transport};
+    use futures::StreamExt;
+    use std::{
+        io,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+
+    let mut estimator = Estimator::new(3);
+    assert_eq!(estimator.observe(Duration::from_secs(0), 0.0), None);
+    assert_eq!(
+        estimator.observe(Duration::from_secs(10), 0.25),
+        Some(Duration::from_secs(30))
+    );
+    assert_eq!(
+        estimator.observe(Duration::from_secs(20), 0.25),
+        Some(Duration::from_secs(60))
+    );
+    // the first sample is out of the window
+    assert_eq!(estimator.observe(Duration::from_secs(30), 0.25), None);
+    assert_eq!(
+        estimator.observe(Duration::from_secs(40), 1.0),
+        Some(Duration::ZERO)
+    );
+
+    /// Node C-chain advances by 50 blocks per height query, up to the
+    /// target height 200 of the reference node.
+    #[derive(Default)]
+    struct Fake {
+        height: AtomicU64,
+    }
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, http_rpc: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            let result = if body.contains("eth_blockNumber") {
+                let height = if http_rpc.contains("reference") {
+                    200
+                } else {
+                    self.height.fetch_add(50, Ordering::SeqCst) + 50
+                };
+                format!(r#""0x{:x}""#, height)
+            } else if body.contains("isBootstrapped") {
+                format!(
+                    r#"{{"isBootstrapped":{}}}"#,
+                    self.height.load(Ordering::SeqCst) >= 200
+                )
+            } else {
+                r#"{"version":"avalanche/1.9.4","databaseVersion":"v1.4.5","gitCommit":"","vmVersions":{"avm":"","evm":"","platform":""}}"#.to_string()
+            };
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            Ok(format!(r#"{{"jsonrpc":"2.0","id":{},"result":{}}}"#, id, result).into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            Ok(br#"{"healthy":false}"#.to_vec())
+        }
+    }
+
+    let snapshots: Vec<Snapshot> = transport::scope(
+        Arc::new(Fake::default()),
+        Monitor::new("http://node")
+            .chains(vec!["C".to_string()])
+            .interval(Duration::from_millis(1))
+            .target_http_rpc("http://reference")
+            .stream()
+            .collect(),
+    )
+    .await;
+    let progress: Vec<f64> = snapshots.iter().map(|s| s.progress).collect();
+    // caught up with the target height before reporting bootstrapped
+    assert_eq!(progress, vec![0.25, 0.5, 0.75, 1.0, 1.0]);
+    assert!(!snapshots[3].is_bootstrapped());
+    assert_eq!(
+        snapshots[0].node_version.as_deref(),
+        Some("avalanche/1.9.4")
+    );
+    assert_eq!(snapshots[0].eta, None);
+    assert!(snapshots[1].eta.is_some());
+    assert!(snapshots[4].is_bootstrapped());
+    assert_eq!(snapshots[4].eta, Some(Duration::ZERO));
+}
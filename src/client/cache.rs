@@ -0,0 +1,345 @@
+//! Response cache for the calls whose results never change once they
+//! succeed (e.g., a transaction by its ID), so that indexers resolving
+//! the same IDs over and over do not hit the node every time.
+//!
+//! ```
+//! # async fn run() {
+//! use std::{sync::Arc, time::Duration};
+//! use avalanche_types::client::{cache::Cache, p, transport};
+//!
+//! let cache = Arc::new(
+//!     Cache::new(Arc::new(transport::Http))
+//!         .ttl(Duration::from_secs(600))
+//!         .max_entries(10_000),
+//! );
+//! let tx_id = "11111111111111111111111111111111LpoYY";
+//! let resp = transport::scope(cache.clone(), p::get_tx("http://localhost:9650", tx_id)).await;
+//! # }
+//! ```
+
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crate::client::{
+    cassette::{echo_id, normalize_request},
+    time::{Duration, Instant},
+    transport::Transport,
+};
+use async_trait::async_trait;
+
+/// Methods whose successful results are immutable.
+pub const DEFAULT_METHODS: &[&str] = &[
+    "platform.getTx",
+    "platform.getBlock",
+    "avm.getTx",
+    "avm.getBlock",
+    "avm.getAssetDescription",
+    "info.getBlockchainID",
+];
+
+pub const DEFAULT_TTL: Duration = Duration::from_secs(10 * 60);
+pub const DEFAULT_MAX_ENTRIES: usize = 4096;
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Clone)]
+struct Key {
+    http_rpc: String,
+    url_path: String,
+    /// Request without the JSON-RPC ID.
+    request: String,
+}
+
+#[derive(Debug, Default)]
+struct Entries {
+    responses: HashMap<Key, (Instant, String)>,
+    /// Insertion order, to evict the oldest entry first.
+    order: VecDeque<Key>,
+}
+
+/// Forwards the requests to the inner transport, and serves the
+/// responses of the cached methods from memory until they expire.
+/// Only the responses with a result are cached, never the errors
+/// (e.g., a transaction that is not yet known to the node).
+pub struct Cache {
+    inner: Arc<dyn Transport>,
+    ttl: Duration,
+    max_entries: usize,
+    methods: HashSet<String>,
+
+    entries: Mutex<Entries>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl Cache {
+    /// Creates the cache of the "DEFAULT_METHODS" responses.
+    pub fn new(inner: Arc<dyn Transport>) -> Self {
+        Self {
+            inner,
+            ttl: DEFAULT_TTL,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            methods: DEFAULT_METHODS.iter().map(|m| m.to_string()).collect(),
+            entries: Mutex::new(Entries::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    #[must_use]
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets the number of responses kept, evicting the oldest beyond it.
+    #[must_use]
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries;
+        self
+    }
+
+    /// Also caches the method, which must only return immutable results.
+    #[must_use]
+    pub fn method(mut self, method: &str) -> Self {
+        self.methods.insert(method.to_string());
+        self
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            evictions: self.evictions.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .responses
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        let mut entries = self.entries.lock().expect("unexpected poisoned lock");
+        entries.responses.clear();
+        entries.order.clear();
+    }
+
+    /// Returns the key if the request calls one of the cached methods.
+    fn key(&self, http_rpc: &str, url_path: &str, body: &str) -> Option<Key> {
+        let v: serde_json::Value = serde_json::from_str(body).ok()?;
+        let method = v.get("method")?.as_str()?;
+        if !self.methods.contains(method) {
+            return None;
+        }
+        Some(Key {
+            http_rpc: http_rpc.to_string(),
+            url_path: url_path.trim_start_matches('/').to_string(),
+            request: normalize_request(body),
+        })
+    }
+
+    fn get(&self, key: &Key) -> Option<String> {
+        let mut entries = self.entries.lock().expect("unexpected poisoned lock");
+        match entries.responses.get(key) {
+            Some((at, response)) if at.elapsed() < self.ttl => Some(response.clone()),
+            Some(_) => {
+                entries.responses.remove(key);
+                entries.order.retain(|k| k != key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn insert(&self, key: Key, response: String) {
+        if self.max_entries == 0 {
+            return;
+        }
+        let mut entries = self.entries.lock().expect("unexpected poisoned lock");
+        if entries
+            .responses
+            .insert(key.clone(), (Instant::now(), response))
+            .is_none()
+        {
+            entries.order.push_back(key);
+        }
+        while entries.responses.len() > self.max_entries {
+            match entries.order.pop_front() {
+                Some(oldest) => {
+                    entries.responses.remove(&oldest);
+                    self.evictions.fetch_add(1, Ordering::Relaxed);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+/// Returns true if the JSON-RPC response has a result and no error.
+fn is_result(response: &str) -> bool {
+    match serde_json::from_str::<serde_json::Value>(response) {
+        Ok(v) => {
+            v.get("error").map_or(true, |e| e.is_null())
+                && v.get("result").map_or(false, |r| !r.is_null())
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for Cache {
+    async fn post_non_tls(
+        &self,
+        http_rpc: &str,
+        url_path: &str,
+        body: &str,
+    ) -> io::Result<Vec<u8>> {
+        let key = match self.key(http_rpc, url_path, body) {
+            Some(key) => key,
+            None => return self.inner.post_non_tls(http_rpc, url_path, body).await,
+        };
+        if let Some(response) = self.get(&key) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(echo_id(&response, Some(body)).into_bytes());
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let rb = self.inner.post_non_tls(http_rpc, url_path, body).await?;
+        let response = String::from_utf8_lossy(&rb);
+        if is_result(&response) {
+            self.insert(key, response.to_string());
+        }
+        Ok(rb)
+    }
+
+    async fn get_non_tls(&self, http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+        self.inner.get_non_tls(http_rpc, url_path).await
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::cache::test_cache --exact --show-output
+#[tokio::test]
+async fn test_cache() {
+    use crate::client::{info, transport};
+
+    /// Returns a new blockchain ID on every call, and fails the unknown aliases.
+    struct Counter(AtomicU64);
+    #[async_trait]
+    impl Transport for Counter {
+        async fn post_non_tls(&self, _: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            let n = self.0.fetch_add(1, Ordering::SeqCst);
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            if body.contains("unknown") {
+                return Ok(format!(
+                    r#"{{"jsonrpc":"2.0","error":{{"code":-32000,"message":"unknown alias"}},"id":{}}}"#,
+                    id
+                )
+                .into_bytes());
+            }
+            if body.contains("getNetworkID") {
+                return Ok(format!(
+                    r#"{{"jsonrpc":"2.0","result":{{"networkID":"{}"}},"id":{}}}"#,
+                    n, id
+                )
+                .into_bytes());
+            }
+            let blockchain_id = crate::ids::Id::from_slice(&[n as u8; 32]);
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","result":{{"blockchainID":"{}"}},"id":{}}}"#,
+                blockchain_id, id
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let blockchain_id = |http_rpc: &'static str, alias: &'static str| async move {
+        info::get_blockchain_id(http_rpc, alias)
+            .await
+            .unwrap()
+            .result
+            .unwrap()
+            .blockchain_id
+    };
+
+    let counter = Arc::new(Counter(AtomicU64::new(1)));
+    let cache = Arc::new(Cache::new(counter.clone()).max_entries(2));
+    transport::scope(cache.clone(), async {
+        let first = blockchain_id("http://a", "X").await;
+        assert_eq!(blockchain_id("http://a", "X").await, first);
+        assert_ne!(blockchain_id("http://b", "X").await, first);
+        assert_eq!(
+            cache.stats(),
+            Stats {
+                hits: 1,
+                misses: 2,
+                evictions: 0
+            }
+        );
+
+        // the errors and the uncached methods always go to the node
+        for _ in 0..2 {
+            assert!(info::get_blockchain_id("http://a", "unknown")
+                .await
+                .unwrap()
+                .error
+                .is_some());
+        }
+        assert_eq!(cache.stats().misses, 4);
+        let n = info::get_network_id("http://a")
+            .await
+            .unwrap()
+            .result
+            .unwrap();
+        let m = info::get_network_id("http://a")
+            .await
+            .unwrap()
+            .result
+            .unwrap();
+        assert_ne!(n.network_id, m.network_id);
+        assert_eq!(cache.len(), 2);
+
+        // evicts the oldest beyond the bound
+        blockchain_id("http://a", "C").await;
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.stats().evictions, 1);
+        assert_ne!(blockchain_id("http://a", "X").await, first);
+    })
+    .await;
+
+    // expired
+    let cache = Arc::new(Cache::new(counter).ttl(Duration::from_millis(10)));
+    transport::scope(cache.clone(), async {
+        let first = blockchain_id("http://a", "X").await;
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert_ne!(blockchain_id("http://a", "X").await, first);
+        assert_eq!(cache.stats().hits, 0);
+        cache.clear();
+        assert!(cache.is_empty());
+    })
+    .await;
+}
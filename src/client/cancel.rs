@@ -0,0 +1,223 @@
+//! Cancellation and deadlines of the client calls.
+//!
+//! Like the transport (see "transport::scope"), a cancellation context is
+//! installed for all calls made within a future with "scope", so that the
+//! RPC calls and the wallet polls (e.g., waiting for the acceptance of a
+//! transfer) stop as soon as the application cancels the token (e.g., on
+//! shutdown) or the deadline passes, instead of running as orphaned tasks.
+//!
+//! A cancelled call fails with "ErrorKind::Interrupted", and a call past
+//! the deadline with "ErrorKind::TimedOut".
+
+use std::{
+    future::Future,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::client::time::{self, Duration, Instant};
+pub use tokio_util::sync::CancellationToken;
+
+/// Cancellation tokens and the deadline of the calls within a scope.
+#[derive(Debug, Clone, Default)]
+pub struct Cancel {
+    tokens: Vec<CancellationToken>,
+    deadline: Option<Instant>,
+}
+
+impl Cancel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the calls when the token is cancelled.
+    #[must_use]
+    pub fn token(mut self, token: CancellationToken) -> Self {
+        self.tokens.push(token);
+        self
+    }
+
+    /// Fails the calls that have not completed by the deadline.
+    /// The earliest deadline applies if set more than once.
+    #[must_use]
+    pub fn deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(match self.deadline {
+            Some(d) => d.min(deadline),
+            None => deadline,
+        });
+        self
+    }
+
+    /// Sets the deadline to the timeout from now.
+    #[must_use]
+    pub fn timeout(self, timeout: Duration) -> Self {
+        self.deadline(Instant::now() + timeout)
+    }
+
+    pub fn get_deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.tokens.iter().any(|t| t.is_cancelled())
+    }
+
+    /// Returns an error if any token was cancelled or the deadline has passed.
+    pub fn check(&self) -> io::Result<()> {
+        if self.is_cancelled() {
+            return Err(Error::new(ErrorKind::Interrupted, "client call cancelled"));
+        }
+        if let Some(d) = self.deadline {
+            if Instant::now() >= d {
+                return Err(deadline_exceeded());
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the future until it completes, any token is cancelled,
+    /// or the deadline passes, whichever comes first.
+    pub async fn run<F, T>(&self, f: F) -> io::Result<T>
+    where
+        F: Future<Output = io::Result<T>>,
+    {
+        self.check()?;
+
+        let cancelled = async {
+            if self.tokens.is_empty() {
+                futures::future::pending::<()>().await;
+            }
+            let tokens = self.tokens.iter().map(|t| Box::pin(t.cancelled()));
+            futures::future::select_all(tokens).await;
+        };
+        let expired = async {
+            match self.deadline {
+                Some(d) => time::sleep_until(d).await,
+                None => futures::future::pending::<()>().await,
+            }
+        };
+
+        tokio::select! {
+            biased;
+            r = f => r,
+            _ = cancelled => Err(Error::new(ErrorKind::Interrupted, "client call cancelled")),
+            _ = expired => Err(deadline_exceeded()),
+        }
+    }
+
+    /// Merges the tokens and the earliest deadline of both.
+    fn merge(mut self, other: &Cancel) -> Self {
+        self.tokens.extend(other.tokens.iter().cloned());
+        if let Some(d) = other.deadline {
+            self = self.deadline(d);
+        }
+        self
+    }
+}
+
+fn deadline_exceeded() -> Error {
+    Error::new(ErrorKind::TimedOut, "client call deadline exceeded")
+}
+
+tokio::task_local! {
+    static CANCEL: Cancel;
+}
+
+/// Runs the future with all client calls bound to the cancellation context.
+/// A nested scope also honors the tokens and the deadline of the outer one.
+pub async fn scope<F>(cancel: Cancel, f: F) -> F::Output
+where
+    F: Future,
+{
+    let cancel = match current() {
+        Some(outer) => cancel.merge(&outer),
+        None => cancel,
+    };
+    CANCEL.scope(cancel, f).await
+}
+
+/// Returns the cancellation context of the current scope, if any.
+pub fn current() -> Option<Cancel> {
+    CANCEL.try_with(|c| c.clone()).ok()
+}
+
+/// Runs the future with the cancellation context of the current scope, if any.
+pub async fn run<F, T>(f: F) -> io::Result<T>
+where
+    F: Future<Output = io::Result<T>>,
+{
+    match current() {
+        Some(c) => c.run(f).await,
+        None => f.await,
+    }
+}
+
+/// Sleeps between the polls, returning early with an error
+/// if the current scope is cancelled or its deadline passes.
+pub async fn sleep(d: Duration) -> io::Result<()> {
+    run(async {
+        time::sleep(d).await;
+        Ok(())
+    })
+    .await
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::cancel::test_cancel --exact --show-output
+#[tokio::test]
+async fn test_cancel() {
+    // no scope
+    assert!(current().is_none());
+    sleep(Duration::from_millis(1)).await.unwrap();
+
+    let token = CancellationToken::new();
+    let cancel = Cancel::new().token(token.clone());
+    let child = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        child.cancel();
+    });
+    let e = scope(cancel, async { sleep(Duration::from_secs(60)).await })
+        .await
+        .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Interrupted);
+
+    // already cancelled
+    let e = scope(Cancel::new().token(token), async {
+        run(async { Ok(()) }).await
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::Interrupted);
+
+    let e = scope(Cancel::new().timeout(Duration::from_millis(20)), async {
+        sleep(Duration::from_secs(60)).await
+    })
+    .await
+    .unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::TimedOut);
+
+    // nested scope keeps the earlier outer deadline and the outer token
+    let outer = CancellationToken::new();
+    let deadline = Instant::now() + Duration::from_secs(1);
+    scope(
+        Cancel::new().token(outer.clone()).deadline(deadline),
+        async {
+            scope(Cancel::new().timeout(Duration::from_secs(60)), async {
+                let c = current().unwrap();
+                assert_eq!(c.get_deadline(), Some(deadline));
+                assert!(!c.is_cancelled());
+                outer.cancel();
+                assert!(c.is_cancelled());
+            })
+            .await
+        },
+    )
+    .await;
+
+    // completes before the deadline
+    let v = scope(Cancel::new().timeout(Duration::from_secs(60)), async {
+        run(async { Ok(1) }).await
+    })
+    .await
+    .unwrap();
+    assert_eq!(v, 1);
+}
@@ -0,0 +1,370 @@
+//! Records the client responses into fixture files ("cassettes"),
+//! and replays them deterministically in tests without the network.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use avalanche_types::client::{cassette, info, transport};
+//!
+//! // records from fuji on the first run, and replays afterwards
+//! let t = cassette::open("tests/fixtures/fuji_network_id.json", cassette::Mode::Auto)?;
+//! let resp = transport::scope(t, info::get_network_id("https://api.avax-test.network")).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fs::{self, File},
+    io::{self, Error, ErrorKind, Write},
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use crate::client::transport::{self, Transport};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// Recorded request and its response.
+/// The endpoint is not recorded, so the fixtures replay against any endpoint.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Interaction {
+    /// "GET" or "POST".
+    pub method: String,
+    pub url_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request: Option<String>,
+    pub response: String,
+}
+
+impl Interaction {
+    fn matches(&self, method: &str, url_path: &str, request: Option<&str>) -> bool {
+        self.method == method
+            && normalize_path(&self.url_path) == normalize_path(url_path)
+            && self.request.as_deref().map(normalize_request) == request.map(normalize_request)
+    }
+}
+
+/// Sequence of recorded interactions, persisted in JSON.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct Cassette {
+    pub interactions: Vec<Interaction>,
+}
+
+impl Cassette {
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        let f = File::open(file_path).map_err(|e| {
+            Error::new(
+                e.kind(),
+                format!("failed to open cassette {} ({})", file_path, e),
+            )
+        })?;
+        serde_json::from_reader(f)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("invalid JSON: {}", e)))
+    }
+
+    /// Saves the cassette to disk and overwrites the file.
+    pub fn sync(&self, file_path: &str) -> io::Result<()> {
+        if let Some(parent_dir) = Path::new(file_path).parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+        let d = serde_json::to_vec_pretty(self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize cassette to JSON {}", e),
+            )
+        })?;
+        let mut f = File::create(file_path)?;
+        f.write_all(&d)
+    }
+}
+
+/// Forwards the requests to the inner transport,
+/// and persists every interaction to the cassette file.
+pub struct Recorder {
+    inner: Arc<dyn Transport>,
+    file_path: String,
+    cassette: Mutex<Cassette>,
+}
+
+impl Recorder {
+    /// Starts a new cassette, overwriting the existing file on the first request.
+    pub fn new(inner: Arc<dyn Transport>, file_path: &str) -> Self {
+        Self {
+            inner,
+            file_path: file_path.to_string(),
+            cassette: Mutex::new(Cassette::default()),
+        }
+    }
+
+    pub fn cassette(&self) -> Cassette {
+        self.cassette
+            .lock()
+            .expect("unexpected poisoned lock")
+            .clone()
+    }
+
+    fn record(&self, interaction: Interaction) -> io::Result<()> {
+        let mut cassette = self.cassette.lock().expect("unexpected poisoned lock");
+        cassette.interactions.push(interaction);
+        cassette.sync(&self.file_path)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for Recorder {
+    async fn post_non_tls(
+        &self,
+        http_rpc: &str,
+        url_path: &str,
+        body: &str,
+    ) -> io::Result<Vec<u8>> {
+        let rb = self.inner.post_non_tls(http_rpc, url_path, body).await?;
+        self.record(Interaction {
+            method: String::from("POST"),
+            url_path: url_path.to_string(),
+            request: Some(body.to_string()),
+            response: String::from_utf8_lossy(&rb).to_string(),
+        })?;
+        Ok(rb)
+    }
+
+    async fn get_non_tls(&self, http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+        let rb = self.inner.get_non_tls(http_rpc, url_path).await?;
+        self.record(Interaction {
+            method: String::from("GET"),
+            url_path: url_path.to_string(),
+            request: None,
+            response: String::from_utf8_lossy(&rb).to_string(),
+        })?;
+        Ok(rb)
+    }
+}
+
+/// Serves the recorded responses without the network.
+/// Each interaction is replayed once, in the recorded order among
+/// identical requests (e.g., polling the same status returns the
+/// recorded sequence of statuses).
+pub struct Replayer {
+    interactions: Vec<Interaction>,
+    used: Mutex<Vec<bool>>,
+}
+
+impl Replayer {
+    pub fn new(cassette: Cassette) -> Self {
+        let used = vec![false; cassette.interactions.len()];
+        Self {
+            interactions: cassette.interactions,
+            used: Mutex::new(used),
+        }
+    }
+
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        Ok(Self::new(Cassette::load(file_path)?))
+    }
+
+    /// Returns true if every recorded interaction has been replayed.
+    pub fn is_exhausted(&self) -> bool {
+        self.used
+            .lock()
+            .expect("unexpected poisoned lock")
+            .iter()
+            .all(|u| *u)
+    }
+
+    fn replay(&self, method: &str, url_path: &str, request: Option<&str>) -> io::Result<Vec<u8>> {
+        let mut used = self.used.lock().expect("unexpected poisoned lock");
+        for (i, interaction) in self.interactions.iter().enumerate() {
+            if !used[i] && interaction.matches(method, url_path, request) {
+                used[i] = true;
+                return Ok(echo_id(&interaction.response, request).into_bytes());
+            }
+        }
+        Err(Error::new(
+            ErrorKind::NotFound,
+            format!(
+                "no recorded interaction for {} '{}' (request {:?})",
+                method, url_path, request
+            ),
+        ))
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for Replayer {
+    async fn post_non_tls(
+        &self,
+        _http_rpc: &str,
+        url_path: &str,
+        body: &str,
+    ) -> io::Result<Vec<u8>> {
+        self.replay("POST", url_path, Some(body))
+    }
+
+    async fn get_non_tls(&self, _http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+        self.replay("GET", url_path, None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Mode {
+    /// Always sends the requests and re-records the cassette.
+    Record,
+    /// Only replays the existing cassette.
+    Replay,
+    /// Replays if the cassette exists, otherwise records it.
+    Auto,
+}
+
+/// Opens the cassette in the mode, recording over the network with
+/// the default transport.
+pub fn open(file_path: &str, mode: Mode) -> io::Result<Arc<dyn Transport>> {
+    let record = match mode {
+        Mode::Record => true,
+        Mode::Replay => false,
+        Mode::Auto => !Path::new(file_path).exists(),
+    };
+    if record {
+        log::info!("recording cassette {}", file_path);
+        Ok(Arc::new(Recorder::new(
+            transport::default_transport(),
+            file_path,
+        )))
+    } else {
+        log::info!("replaying cassette {}", file_path);
+        Ok(Arc::new(Replayer::load(file_path)?))
+    }
+}
+
+fn normalize_path(p: &str) -> &str {
+    p.trim_start_matches('/')
+}
+
+/// Drops the JSON-RPC request "id", and re-encodes the JSON with sorted keys.
+pub(crate) fn normalize_request(body: &str) -> String {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(mut v) => {
+            if let Some(m) = v.as_object_mut() {
+                m.remove("id");
+            }
+            v.to_string()
+        }
+        Err(_) => body.to_string(),
+    }
+}
+
+/// Replaces the recorded response ID with the ID of the replayed request,
+/// since the clients check that the response ID matches.
+pub(crate) fn echo_id(response: &str, request: Option<&str>) -> String {
+    let id = match request.and_then(crate::jsonrpc::request_id) {
+        Some(id) => id,
+        None => return response.to_string(),
+    };
+    match serde_json::from_str::<serde_json::Value>(response) {
+        Ok(mut v) => match v.as_object_mut() {
+            Some(m) if m.contains_key("id") => {
+                m.insert(String::from("id"), serde_json::Value::from(id));
+                v.to_string()
+            }
+            _ => response.to_string(),
+        },
+        Err(_) => response.to_string(),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::cassette::test_record_replay --exact --show-output
+#[tokio::test]
+async fn test_record_replay() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::client::info;
+
+    /// Returns a different network ID on every call.
+    struct Counter(AtomicU32);
+    #[async_trait]
+    impl Transport for Counter {
+        async fn post_non_tls(&self, _: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            let n = self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(format!(
+                "{{\"jsonrpc\":\"2.0\",\"result\":{{\"networkID\":\"{}\"}},\"id\":{}}}",
+                n,
+                crate::jsonrpc::request_id(body).unwrap_or_default()
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            Ok(b"{\"healthy\":true}".to_vec())
+        }
+    }
+
+    let dir = tempfile::tempdir().unwrap();
+    let p = dir.path().join("cassette.json");
+    let p = p.to_str().unwrap();
+
+    let recorder = Arc::new(Recorder::new(Arc::new(Counter(AtomicU32::new(5))), p));
+    transport::scope(recorder.clone(), async {
+        assert_eq!(
+            info::get_network_id("http://a")
+                .await
+                .unwrap()
+                .result
+                .unwrap()
+                .network_id,
+            5
+        );
+        assert_eq!(
+            info::get_network_id("http://a")
+                .await
+                .unwrap()
+                .result
+                .unwrap()
+                .network_id,
+            6
+        );
+    })
+    .await;
+    assert_eq!(recorder.cassette().interactions.len(), 2);
+    assert_eq!(Cassette::load(p).unwrap(), recorder.cassette());
+
+    // replays in order against any endpoint, then runs out
+    let replayer = Arc::new(Replayer::load(p).unwrap());
+    transport::scope(replayer.clone(), async {
+        assert_eq!(
+            info::get_network_id("http://b")
+                .await
+                .unwrap()
+                .result
+                .unwrap()
+                .network_id,
+            5
+        );
+        assert!(!replayer.is_exhausted());
+        assert_eq!(
+            info::get_network_id("http://b")
+                .await
+                .unwrap()
+                .result
+                .unwrap()
+                .network_id,
+            6
+        );
+        assert!(replayer.is_exhausted());
+        let err = info::get_network_id("http://b").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        assert!(info::get_network_name("http://b").await.is_err());
+    })
+    .await;
+
+    assert!(open(p, Mode::Auto).is_ok());
+    assert!(open(
+        dir.path().join("missing.json").to_str().unwrap(),
+        Mode::Replay
+    )
+    .is_err());
+
+    assert_eq!(
+        normalize_request("{\"method\":\"m\",\"id\":7,\"jsonrpc\":\"2.0\"}"),
+        normalize_request("{\"jsonrpc\":\"2.0\",\"id\":1,\"method\":\"m\"}")
+    );
+}
@@ -0,0 +1,306 @@
+//! Fault-injecting transport for resilience testing.
+//!
+//! Wraps another transport and, with the configured probabilities,
+//! delays the request, times it out, rejects it with 429/503,
+//! or corrupts the JSON response.
+//!
+//! ```
+//! # async fn run() {
+//! use std::{sync::Arc, time::Duration};
+//! use avalanche_types::client::{chaos::Chaos, info, transport};
+//!
+//! let chaos = Chaos::new(Arc::new(transport::Http))
+//!     .seed(7)
+//!     .latency(Duration::from_millis(500), 0.2)
+//!     .too_many_requests(0.1)
+//!     .malformed_json(0.05);
+//! let resp = transport::scope(Arc::new(chaos), info::get_network_id("http://localhost:9650")).await;
+//! # }
+//! ```
+
+use std::{
+    io::{self, Error, ErrorKind},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crate::client::{
+    time::{self, Duration},
+    transport::Transport,
+};
+use async_trait::async_trait;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Number of injected faults by type.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub delayed: u64,
+    pub timed_out: u64,
+    pub too_many_requests: u64,
+    pub service_unavailable: u64,
+    pub malformed_json: u64,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    delayed: AtomicU64,
+    timed_out: AtomicU64,
+    too_many_requests: AtomicU64,
+    service_unavailable: AtomicU64,
+    malformed_json: AtomicU64,
+}
+
+pub struct Chaos {
+    inner: Arc<dyn Transport>,
+    rng: Mutex<StdRng>,
+
+    latency: Duration,
+    latency_probability: f64,
+    timeout: Duration,
+    timeout_probability: f64,
+    too_many_requests_probability: f64,
+    service_unavailable_probability: f64,
+    malformed_json_probability: f64,
+
+    counters: Counters,
+}
+
+/// Faults drawn for a single request.
+#[derive(Debug, Default)]
+struct Draw {
+    delay: bool,
+    time_out: bool,
+    too_many_requests: bool,
+    service_unavailable: bool,
+    malformed_json: bool,
+}
+
+impl Chaos {
+    /// Creates the transport that forwards to "inner" without faults,
+    /// until the probabilities are set.
+    pub fn new(inner: Arc<dyn Transport>) -> Self {
+        Self {
+            inner,
+            rng: Mutex::new(StdRng::from_entropy()),
+            latency: Duration::ZERO,
+            latency_probability: 0.0,
+            timeout: Duration::ZERO,
+            timeout_probability: 0.0,
+            too_many_requests_probability: 0.0,
+            service_unavailable_probability: 0.0,
+            malformed_json_probability: 0.0,
+            counters: Counters::default(),
+        }
+    }
+
+    /// Sets the seed so that the same faults are injected in every run.
+    #[must_use]
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        self
+    }
+
+    /// Sets the extra latency added before the request is sent.
+    #[must_use]
+    pub fn latency(mut self, latency: Duration, probability: f64) -> Self {
+        self.latency = latency;
+        self.latency_probability = probability;
+        self
+    }
+
+    /// Sets the timeout, which waits for the duration and
+    /// fails with "TimedOut" without sending the request.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration, probability: f64) -> Self {
+        self.timeout = timeout;
+        self.timeout_probability = probability;
+        self
+    }
+
+    /// Sets the probability of failing with "429 Too Many Requests".
+    #[must_use]
+    pub fn too_many_requests(mut self, probability: f64) -> Self {
+        self.too_many_requests_probability = probability;
+        self
+    }
+
+    /// Sets the probability of failing with "503 Service Unavailable".
+    #[must_use]
+    pub fn service_unavailable(mut self, probability: f64) -> Self {
+        self.service_unavailable_probability = probability;
+        self
+    }
+
+    /// Sets the probability of truncating the response body,
+    /// so that it no longer decodes as JSON.
+    #[must_use]
+    pub fn malformed_json(mut self, probability: f64) -> Self {
+        self.malformed_json_probability = probability;
+        self
+    }
+
+    /// Returns the number of faults injected so far.
+    pub fn stats(&self) -> Stats {
+        Stats {
+            delayed: self.counters.delayed.load(Ordering::Relaxed),
+            timed_out: self.counters.timed_out.load(Ordering::Relaxed),
+            too_many_requests: self.counters.too_many_requests.load(Ordering::Relaxed),
+            service_unavailable: self.counters.service_unavailable.load(Ordering::Relaxed),
+            malformed_json: self.counters.malformed_json.load(Ordering::Relaxed),
+        }
+    }
+
+    fn draw(&self) -> Draw {
+        let mut rng = self.rng.lock().expect("unexpected poisoned lock");
+        Draw {
+            delay: rng.gen_bool(self.latency_probability.clamp(0.0, 1.0)),
+            time_out: rng.gen_bool(self.timeout_probability.clamp(0.0, 1.0)),
+            too_many_requests: rng.gen_bool(self.too_many_requests_probability.clamp(0.0, 1.0)),
+            service_unavailable: rng.gen_bool(self.service_unavailable_probability.clamp(0.0, 1.0)),
+            malformed_json: rng.gen_bool(self.malformed_json_probability.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Applies the faults that happen before the request is sent.
+    async fn before(&self, draw: &Draw, url_path: &str) -> io::Result<()> {
+        if draw.delay {
+            self.counters.delayed.fetch_add(1, Ordering::Relaxed);
+            time::sleep(self.latency).await;
+        }
+        if draw.time_out {
+            self.counters.timed_out.fetch_add(1, Ordering::Relaxed);
+            time::sleep(self.timeout).await;
+            return Err(Error::new(
+                ErrorKind::TimedOut,
+                format!("injected timeout for '{}'", url_path),
+            ));
+        }
+        if draw.too_many_requests {
+            self.counters
+                .too_many_requests
+                .fetch_add(1, Ordering::Relaxed);
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("injected status 429 Too Many Requests for '{}'", url_path),
+            ));
+        }
+        if draw.service_unavailable {
+            self.counters
+                .service_unavailable
+                .fetch_add(1, Ordering::Relaxed);
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("injected status 503 Service Unavailable for '{}'", url_path),
+            ));
+        }
+        Ok(())
+    }
+
+    fn after(&self, draw: &Draw, mut rb: Vec<u8>) -> Vec<u8> {
+        if draw.malformed_json {
+            self.counters.malformed_json.fetch_add(1, Ordering::Relaxed);
+            rb.truncate(rb.len() / 2);
+            rb.extend_from_slice(b"<!-- injected -->");
+        }
+        rb
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transport for Chaos {
+    async fn post_non_tls(
+        &self,
+        http_rpc: &str,
+        url_path: &str,
+        body: &str,
+    ) -> io::Result<Vec<u8>> {
+        let draw = self.draw();
+        self.before(&draw, url_path).await?;
+        let rb = self.inner.post_non_tls(http_rpc, url_path, body).await?;
+        Ok(self.after(&draw, rb))
+    }
+
+    async fn get_non_tls(&self, http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+        let draw = self.draw();
+        self.before(&draw, url_path).await?;
+        let rb = self.inner.get_non_tls(http_rpc, url_path).await?;
+        Ok(self.after(&draw, rb))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::chaos::test_chaos --exact --show-output
+#[tokio::test]
+async fn test_chaos() {
+    use crate::client::{info, transport};
+
+    struct Fixed;
+    #[async_trait]
+    impl Transport for Fixed {
+        async fn post_non_tls(&self, _: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","result":{{"networkID":"1"}},"id":{}}}"#,
+                id
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            Ok(b"{}".to_vec())
+        }
+    }
+
+    // no faults by default
+    let chaos = Arc::new(Chaos::new(Arc::new(Fixed)));
+    let resp = transport::scope(chaos.clone(), info::get_network_id("http://test"))
+        .await
+        .unwrap();
+    assert_eq!(resp.result.unwrap().network_id, 1);
+    assert_eq!(chaos.stats(), Stats::default());
+
+    let chaos = Arc::new(Chaos::new(Arc::new(Fixed)).too_many_requests(1.0));
+    let err = transport::scope(chaos.clone(), info::get_network_id("http://test"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("429"));
+    assert_eq!(chaos.stats().too_many_requests, 1);
+
+    let chaos = Arc::new(Chaos::new(Arc::new(Fixed)).malformed_json(1.0));
+    assert!(
+        transport::scope(chaos.clone(), info::get_network_id("http://test"))
+            .await
+            .is_err()
+    );
+    assert_eq!(chaos.stats().malformed_json, 1);
+
+    let chaos = Arc::new(Chaos::new(Arc::new(Fixed)).timeout(Duration::from_millis(10), 1.0));
+    let err = transport::scope(chaos.clone(), info::get_network_id("http://test"))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+    // same seed, same faults
+    let run = |seed| async move {
+        let chaos = Arc::new(
+            Chaos::new(Arc::new(Fixed))
+                .seed(seed)
+                .service_unavailable(0.5),
+        );
+        let mut failed = Vec::new();
+        for _ in 0..20 {
+            failed.push(
+                transport::scope(chaos.clone(), info::get_network_id("http://test"))
+                    .await
+                    .is_err(),
+            );
+        }
+        (failed, chaos.stats())
+    };
+    let (failed_1, stats_1) = run(42).await;
+    let (failed_2, stats_2) = run(42).await;
+    assert_eq!(failed_1, failed_2);
+    assert_eq!(stats_1, stats_2);
+    assert!(stats_1.service_unavailable > 0 && stats_1.service_unavailable < 20);
+}
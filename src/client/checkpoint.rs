@@ -0,0 +1,340 @@
+//! Durable checkpoints for the index subscriptions.
+//!
+//! An indexer saves the index of each container once processed, and
+//! resumes the subscription from the saved index after a restart (see
+//! "index::Subscription::resume"), so that no container is skipped or
+//! processed twice across restarts. The store is pluggable: "File" keeps
+//! one file per key, "Memory" is for tests, and the "checkpoint_sled" and
+//! "checkpoint_postgres" features add the "Sled" and "Postgres" stores.
+//!
+//! ```
+//! # async fn run() -> std::io::Result<()> {
+//! use avalanche_types::{
+//!     client::{checkpoint::{self, Checkpoint}, index},
+//!     ids,
+//! };
+//! use futures::StreamExt;
+//!
+//! let cp = checkpoint::File::new("/var/lib/indexer");
+//! let chain_id = ids::Id::from_slice(&[0x2c; 32]);
+//! let key = checkpoint::key(1, &chain_id, index::C_BLOCK);
+//! let accepted = index::Subscription::new("http://localhost:9650", index::C_BLOCK, 0)
+//!     .resume(&cp, &key)
+//!     .await?
+//!     .stream();
+//! futures::pin_mut!(accepted);
+//! while let Some(c) = accepted.next().await {
+//!     let c = c?;
+//!     // process the container, then record it
+//!     cp.save(&key, c.index).await?;
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, Error, ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::ids;
+use async_trait::async_trait;
+
+/// Returns the checkpoint key "[network ID]/[chain ID]/[index]"
+/// (e.g., "1/2q9e4r6Mu.../C/block"). The chain ID keeps apart the chains
+/// that share a name or an alias (e.g., the same VM on two subnets).
+pub fn key(network_id: u32, chain_id: &ids::Id, index: &str) -> String {
+    format!("{}/{}/{}", network_id, chain_id, index)
+}
+
+/// Stores the last processed index of each subscription by key
+/// (see "key").
+#[async_trait]
+pub trait Checkpoint: Send + Sync {
+    /// Returns the last processed index, or None if nothing was processed.
+    async fn load(&self, key: &str) -> io::Result<Option<u64>>;
+
+    /// Records the index as processed, durably once returned.
+    async fn save(&self, key: &str, index: u64) -> io::Result<()>;
+}
+
+/// Returns the index to resume from, the one after the last processed,
+/// or "from_index" if nothing was processed yet.
+pub async fn resume_index(cp: &dyn Checkpoint, key: &str, from_index: u64) -> io::Result<u64> {
+    Ok(match cp.load(key).await? {
+        Some(last) => last.checked_add(1).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("checkpoint '{}' at the maximum index", key),
+            )
+        })?,
+        None => from_index,
+    })
+}
+
+/// Keeps the checkpoints in memory, e.g., for tests.
+#[derive(Debug, Default)]
+pub struct Memory {
+    indexes: Mutex<HashMap<String, u64>>,
+}
+
+impl Memory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Checkpoint for Memory {
+    async fn load(&self, key: &str) -> io::Result<Option<u64>> {
+        let indexes = self.indexes.lock().expect("unexpected poisoned lock");
+        Ok(indexes.get(key).copied())
+    }
+
+    async fn save(&self, key: &str, index: u64) -> io::Result<()> {
+        let mut indexes = self.indexes.lock().expect("unexpected poisoned lock");
+        indexes.insert(key.to_string(), index);
+        Ok(())
+    }
+}
+
+/// Keeps each checkpoint as a file in the directory, named after the key
+/// with any character other than ASCII alphanumerics, "-" and "." escaped
+/// as "_[hex byte]", so that distinct keys never share a file. Saves write
+/// a temporary file, rename it over the previous one and sync the directory,
+/// so a crash leaves either checkpoint.
+#[derive(Debug, Clone)]
+pub struct File {
+    pub dir: PathBuf,
+}
+
+impl File {
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        let mut name = String::with_capacity(key.len());
+        for b in key.bytes() {
+            if b.is_ascii_alphanumeric() || b == b'-' || b == b'.' {
+                name.push(b as char);
+            } else {
+                name.push_str(&format!("_{:02x}", b));
+            }
+        }
+        self.dir.join(format!("{}.checkpoint", name))
+    }
+}
+
+#[async_trait]
+impl Checkpoint for File {
+    async fn load(&self, key: &str) -> io::Result<Option<u64>> {
+        let path = self.path(key);
+        let d = match fs::read_to_string(&path) {
+            Ok(d) => d,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        d.trim().parse::<u64>().map(Some).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid checkpoint {} ({})", path.display(), e),
+            )
+        })
+    }
+
+    async fn save(&self, key: &str, index: u64) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(key);
+        let tmp_path = path.with_extension("checkpoint.tmp");
+
+        let mut f = fs::File::create(&tmp_path)?;
+        f.write_all(index.to_string().as_bytes())?;
+        f.sync_all()?;
+        fs::rename(&tmp_path, &path)?;
+
+        // the rename is only durable once the directory entry is synced
+        #[cfg(unix)]
+        fs::File::open(&self.dir)?.sync_all()?;
+        Ok(())
+    }
+}
+
+/// Keeps the checkpoints in a sled tree, keyed by the key bytes
+/// with the big-endian index as the value.
+#[cfg(feature = "checkpoint_sled")]
+#[derive(Debug, Clone)]
+pub struct Sled {
+    tree: sled::Tree,
+}
+
+#[cfg(feature = "checkpoint_sled")]
+impl Sled {
+    pub const TREE_NAME: &'static str = "checkpoints";
+
+    /// Opens the "checkpoints" tree of the database,
+    /// which may be shared with the indexed data.
+    pub fn new(db: &sled::Db) -> io::Result<Self> {
+        let tree = db
+            .open_tree(Self::TREE_NAME)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed to open tree '{}'", e)))?;
+        Ok(Self { tree })
+    }
+}
+
+#[cfg(feature = "checkpoint_sled")]
+#[async_trait]
+impl Checkpoint for Sled {
+    async fn load(&self, key: &str) -> io::Result<Option<u64>> {
+        let v = self
+            .tree
+            .get(key.as_bytes())
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed sled get '{}'", e)))?;
+        v.map(|v| {
+            let b: [u8; 8] = v.as_ref().try_into().map_err(|_| {
+                Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid checkpoint '{}' length {}", key, v.len()),
+                )
+            })?;
+            Ok(u64::from_be_bytes(b))
+        })
+        .transpose()
+    }
+
+    async fn save(&self, key: &str, index: u64) -> io::Result<()> {
+        self.tree
+            .insert(key.as_bytes(), &index.to_be_bytes())
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed sled insert '{}'", e)))?;
+        self.tree
+            .flush_async()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed sled flush '{}'", e)))?;
+        Ok(())
+    }
+}
+
+/// Keeps the checkpoints in a Postgres table
+/// "(key TEXT PRIMARY KEY, last_index BIGINT NOT NULL)".
+#[cfg(feature = "checkpoint_postgres")]
+pub struct Postgres {
+    client: tokio_postgres::Client,
+    table: String,
+}
+
+#[cfg(feature = "checkpoint_postgres")]
+impl Postgres {
+    pub const DEFAULT_TABLE: &'static str = "avalanche_checkpoints";
+
+    /// Creates the table if it does not exist. The client must be connected,
+    /// with its connection task running (see "tokio_postgres::connect").
+    /// The table name is interpolated as is, so it must be trusted.
+    pub async fn new(client: tokio_postgres::Client, table: &str) -> io::Result<Self> {
+        client
+            .batch_execute(&format!(
+                "CREATE TABLE IF NOT EXISTS {} (key TEXT PRIMARY KEY, last_index BIGINT NOT NULL)",
+                table
+            ))
+            .await
+            .map_err(postgres_err)?;
+        Ok(Self {
+            client,
+            table: table.to_string(),
+        })
+    }
+}
+
+#[cfg(feature = "checkpoint_postgres")]
+fn postgres_err(e: tokio_postgres::Error) -> Error {
+    Error::new(ErrorKind::Other, format!("failed postgres '{}'", e))
+}
+
+#[cfg(feature = "checkpoint_postgres")]
+#[async_trait]
+impl Checkpoint for Postgres {
+    async fn load(&self, key: &str) -> io::Result<Option<u64>> {
+        let row = self
+            .client
+            .query_opt(
+                &format!("SELECT last_index FROM {} WHERE key = $1", self.table),
+                &[&key],
+            )
+            .await
+            .map_err(postgres_err)?;
+        // BIGINT is signed, and the index is stored as its bit pattern
+        Ok(row.map(|r| r.get::<_, i64>(0) as u64))
+    }
+
+    async fn save(&self, key: &str, index: u64) -> io::Result<()> {
+        self.client
+            .execute(
+                &format!(
+                    "INSERT INTO {} (key, last_index) VALUES ($1, $2) ON CONFLICT (key) DO UPDATE SET last_index = EXCLUDED.last_index",
+                    self.table
+                ),
+                &[&key, &(index as i64)],
+            )
+            .await
+            .map_err(postgres_err)?;
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::checkpoint::test_checkpoint --exact --show-output
+#[tokio::test]
+async fn test_checkpoint() {
+    let dir = tempfile::tempdir().unwrap();
+    #[allow(unused_mut)]
+    let mut stores: Vec<Box<dyn Checkpoint>> =
+        vec![Box::new(Memory::new()), Box::new(File::new(dir.path()))];
+    #[cfg(feature = "checkpoint_sled")]
+    {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        stores.push(Box::new(Sled::new(&db).unwrap()));
+    }
+    for cp in stores.iter() {
+        let cp = cp.as_ref();
+        assert_eq!(cp.load("mainnet/C/block").await.unwrap(), None);
+        assert_eq!(resume_index(cp, "mainnet/C/block", 7).await.unwrap(), 7);
+
+        cp.save("mainnet/C/block", 0).await.unwrap();
+        cp.save("mainnet/C/block", 41).await.unwrap();
+        cp.save("mainnet/P/block", 3).await.unwrap();
+        assert_eq!(cp.load("mainnet/C/block").await.unwrap(), Some(41));
+        assert_eq!(resume_index(cp, "mainnet/C/block", 7).await.unwrap(), 42);
+        assert_eq!(cp.load("mainnet/P/block").await.unwrap(), Some(3));
+
+        cp.save("max", u64::MAX).await.unwrap();
+        assert!(resume_index(cp, "max", 0).await.is_err());
+    }
+
+    // survives the restart
+    let cp = File::new(dir.path());
+    assert_eq!(cp.load("mainnet/C/block").await.unwrap(), Some(41));
+
+    // the keys that only differ in the escaped characters do not collide
+    assert_ne!(cp.path("mainnet/C/block"), cp.path("mainnet_C_block"));
+    assert_eq!(cp.load("mainnet_C_block").await.unwrap(), None);
+    assert!(cp.path("1/C/block").ends_with("1_2fC_2fblock.checkpoint"));
+
+    // the chains of the same name on two subnets do not share a key
+    let (a, b) = (
+        key(1, &ids::Id::from_slice(&[1; 32]), "subnetevm/block"),
+        key(1, &ids::Id::from_slice(&[2; 32]), "subnetevm/block"),
+    );
+    assert_ne!(a, b);
+    cp.save(&a, 10).await.unwrap();
+    cp.save(&b, 20).await.unwrap();
+    assert_eq!(cp.load(&a).await.unwrap(), Some(10));
+    assert_eq!(cp.load(&b).await.unwrap(), Some(20));
+    fs::write(cp.path("corrupt"), "x").unwrap();
+    assert_eq!(
+        cp.load("corrupt").await.unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
@@ -0,0 +1,128 @@
+//! Network time from the P-chain timestamps of multiple nodes.
+//!
+//! The P-chain timestamp is the time of the last accepted block, which the
+//! validators only accept within the sync bound of their clocks, so a
+//! timestamp ahead of the local clock means that the local clock is behind.
+//! It trails the wall clock when no block is produced, so a local clock
+//! ahead of the chain time is not corrected (see "sync").
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    client::p as client_p,
+    clock::{self, Sample, Skew, SyncedClock},
+};
+use chrono::{Duration, Utc};
+
+/// Samples the P-chain timestamp of the node.
+pub async fn sample(http_rpc: &str) -> io::Result<Sample> {
+    let sent_at = Utc::now();
+    let resp = client_p::get_timestamp(http_rpc).await?;
+    let received_at = Utc::now();
+    Ok(Sample {
+        source: http_rpc.to_string(),
+        sent_at,
+        received_at,
+        remote: resp.into_result()?.timestamp,
+    })
+}
+
+/// Samples the nodes concurrently and estimates the skew from the nodes
+/// that responded, which must be at least "min_samples".
+pub async fn estimate_skew(http_rpcs: &[String], min_samples: usize) -> io::Result<Skew> {
+    let results = futures::future::join_all(http_rpcs.iter().map(|u| sample(u))).await;
+
+    let mut samples = Vec::with_capacity(results.len());
+    for (http_rpc, res) in http_rpcs.iter().zip(results) {
+        match res {
+            Ok(s) => samples.push(s),
+            Err(e) => log::warn!("failed to sample timestamp from {} '{}'", http_rpc, e),
+        }
+    }
+    if samples.len() < min_samples.max(1) {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "only {} of {} nodes sampled (min {})",
+                samples.len(),
+                http_rpcs.len(),
+                min_samples
+            ),
+        ));
+    }
+    clock::estimate(&samples)
+}
+
+/// Estimates the skew and moves the clock forward by it if the local clock
+/// is behind the network, or resets the offset otherwise.
+pub async fn sync(c: &SyncedClock, http_rpcs: &[String], min_samples: usize) -> io::Result<Skew> {
+    let skew = estimate_skew(http_rpcs, min_samples).await?;
+    let offset = skew.offset.max(Duration::zero());
+    log::info!(
+        "clock offset {}ms from {} nodes (spread {}ms)",
+        offset.num_milliseconds(),
+        skew.samples,
+        skew.spread().num_milliseconds()
+    );
+    c.set_offset(offset);
+    Ok(skew)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::clock::test_sync --exact --show-output
+#[tokio::test]
+async fn test_sync() {
+    use crate::client::transport::{self, Transport};
+    use std::sync::Arc;
+
+    /// Returns the P-chain timestamp offset by the seconds in the endpoint.
+    struct Fake;
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, http_rpc: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            if http_rpc.contains("down") {
+                return Err(Error::new(ErrorKind::ConnectionRefused, "down"));
+            }
+            let secs: i64 = http_rpc.rsplit('/').next().unwrap().parse().unwrap();
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            let timestamp = Utc::now() + Duration::seconds(secs);
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":{{"timestamp":"{}"}}}}"#,
+                id,
+                timestamp.to_rfc3339()
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let endpoints = |offsets: &[&str]| -> Vec<String> {
+        offsets.iter().map(|o| format!("http://{}", o)).collect()
+    };
+
+    // the local clock is 30 seconds behind
+    let c = SyncedClock::default();
+    let skew = transport::scope(
+        Arc::new(Fake),
+        sync(&c, &endpoints(&["a/30", "b/30", "down/0", "c/-3600"]), 2),
+    )
+    .await
+    .unwrap();
+    assert_eq!(skew.samples, 3);
+    assert!((c.offset() - Duration::seconds(30)).num_seconds().abs() <= 1);
+
+    // idle chain, the chain time trails the local clock
+    let skew = transport::scope(Arc::new(Fake), sync(&c, &endpoints(&["a/-60", "b/-60"]), 2))
+        .await
+        .unwrap();
+    assert!(skew.offset < Duration::zero());
+    assert_eq!(c.offset(), Duration::zero());
+
+    assert!(transport::scope(
+        Arc::new(Fake),
+        estimate_skew(&endpoints(&["a/0", "down/0"]), 2)
+    )
+    .await
+    .is_err());
+}
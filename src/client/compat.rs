@@ -0,0 +1,212 @@
+//! Compatibility of the avalanchego node with this crate.
+//!
+//! The client APIs follow the avalanchego releases from "MIN_NODE_VERSION",
+//! and a VM built on "subnet::rpc" only completes the plugin handshake with
+//! the releases that speak the same plugin protocol ("PLUGIN_PROTOCOL_VERSION").
+//! Checking the node version first lets the deployment tooling fail fast,
+//! instead of on a missing RPC method or a plugin that never registers.
+
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{client::info, jsonrpc, version::ApplicationVersion};
+
+/// avalanchego release as (major, minor, patch).
+pub type Release = (u32, u32, u32);
+
+/// Oldest avalanchego release with all the RPC methods of the client.
+pub const MIN_NODE_VERSION: Release = (1, 9, 0);
+
+/// Plugin protocol version of the VM server (see "proto::PROTOCOL_VERSION").
+pub const PLUGIN_PROTOCOL_VERSION: u32 = 19;
+
+/// Plugin protocol versions and the avalanchego releases that speak them,
+/// for the nodes that do not report "rpcProtocolVersion".
+/// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/version/compatibility.json
+pub const PLUGIN_PROTOCOL_COMPATIBILITY: &[(u32, &[Release])] = &[
+    (17, &[(1, 9, 0)]),
+    (18, &[(1, 9, 1)]),
+    (19, &[(1, 9, 2), (1, 9, 3)]),
+    (20, &[(1, 9, 4)]),
+];
+
+/// Returns the plugin protocol version of the avalanchego release, if known.
+pub fn plugin_protocol_of(major: u32, minor: u32, patch: u32) -> Option<u32> {
+    PLUGIN_PROTOCOL_COMPATIBILITY
+        .iter()
+        .find(|(_, versions)| versions.contains(&(major, minor, patch)))
+        .map(|(protocol, _)| *protocol)
+}
+
+/// Result of the node version check.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Verdict {
+    /// Both the client APIs and the plugins of this crate work with the node.
+    Compatible { node: ApplicationVersion },
+
+    /// The node predates "MIN_NODE_VERSION",
+    /// so some of the client RPC methods may not exist.
+    TooOld { node: ApplicationVersion },
+
+    /// The client APIs work, but the node speaks another plugin protocol,
+    /// so a VM built with this crate fails the handshake.
+    PluginProtocolMismatch {
+        node: ApplicationVersion,
+        node_protocol: u32,
+    },
+
+    /// The client APIs work, but the plugin protocol of the node is unknown
+    /// (a release newer than the compatibility matrix that does not report it).
+    PluginProtocolUnknown { node: ApplicationVersion },
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (min_major, min_minor, min_patch) = MIN_NODE_VERSION;
+        match self {
+            Verdict::Compatible { node } => write!(f, "node {} is compatible", node),
+            Verdict::TooOld { node } => write!(
+                f,
+                "node {} is older than the minimum supported v{}.{}.{}",
+                node, min_major, min_minor, min_patch
+            ),
+            Verdict::PluginProtocolMismatch {
+                node,
+                node_protocol,
+            } => write!(
+                f,
+                "node {} speaks plugin protocol {} but this crate speaks {}",
+                node, node_protocol, PLUGIN_PROTOCOL_VERSION
+            ),
+            Verdict::PluginProtocolUnknown { node } => write!(
+                f,
+                "node {} has an unknown plugin protocol (this crate speaks {})",
+                node, PLUGIN_PROTOCOL_VERSION
+            ),
+        }
+    }
+}
+
+impl Verdict {
+    pub fn node(&self) -> &ApplicationVersion {
+        match self {
+            Verdict::Compatible { node }
+            | Verdict::TooOld { node }
+            | Verdict::PluginProtocolMismatch { node, .. }
+            | Verdict::PluginProtocolUnknown { node } => node,
+        }
+    }
+
+    /// Returns true if the client RPC methods are available.
+    pub fn is_rpc_compatible(&self) -> bool {
+        !matches!(self, Verdict::TooOld { .. })
+    }
+
+    /// Returns true if the node can load the VMs built with this crate.
+    pub fn is_plugin_compatible(&self) -> bool {
+        matches!(self, Verdict::Compatible { .. })
+    }
+
+    /// Fails with "ErrorKind::Unsupported" unless the client RPC methods are available.
+    pub fn check_rpc(&self) -> io::Result<()> {
+        if self.is_rpc_compatible() {
+            return Ok(());
+        }
+        Err(Error::new(ErrorKind::Unsupported, self.to_string()))
+    }
+
+    /// Fails with "ErrorKind::Unsupported" unless the node can load the VMs
+    /// built with this crate, e.g., before registering a plugin.
+    pub fn check_plugin(&self) -> io::Result<()> {
+        if self.is_plugin_compatible() {
+            return Ok(());
+        }
+        Err(Error::new(ErrorKind::Unsupported, self.to_string()))
+    }
+}
+
+/// Returns the verdict for the "info.getNodeVersion" result.
+/// The plugin protocol reported by the node takes precedence over
+/// the compatibility matrix.
+pub fn check(resp: &jsonrpc::info::GetNodeVersionResult) -> io::Result<Verdict> {
+    let node: ApplicationVersion = resp.version.parse()?;
+    if (node.major, node.minor, node.patch) < MIN_NODE_VERSION {
+        return Ok(Verdict::TooOld { node });
+    }
+
+    let node_protocol = match &resp.rpc_protocol_version {
+        Some(v) => Some(v.trim().parse::<u32>().map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid rpcProtocolVersion '{}' ({})", v, e),
+            )
+        })?),
+        None => plugin_protocol_of(node.major, node.minor, node.patch),
+    };
+    Ok(match node_protocol {
+        Some(p) if p == PLUGIN_PROTOCOL_VERSION => Verdict::Compatible { node },
+        Some(node_protocol) => Verdict::PluginProtocolMismatch {
+            node,
+            node_protocol,
+        },
+        None => Verdict::PluginProtocolUnknown { node },
+    })
+}
+
+/// Queries "info.getNodeVersion" and checks the node against the versions
+/// that this crate supports.
+pub async fn check_node_version(http_rpc: &str) -> io::Result<Verdict> {
+    let resp = info::get_node_version(http_rpc).await?.into_result()?;
+    let verdict = check(&resp)?;
+    log::info!("{} ({})", verdict, http_rpc);
+    Ok(verdict)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::compat::test_check --exact --show-output
+#[test]
+fn test_check() {
+    #[cfg(feature = "proto")]
+    assert_eq!(
+        PLUGIN_PROTOCOL_VERSION.to_string(),
+        crate::proto::PROTOCOL_VERSION
+    );
+
+    let resp = |version: &str, protocol: Option<&str>| jsonrpc::info::GetNodeVersionResult {
+        version: version.to_string(),
+        rpc_protocol_version: protocol.map(|p| p.to_string()),
+        ..Default::default()
+    };
+
+    let v = check(&resp("avalanche/1.9.3", None)).unwrap();
+    assert!(matches!(v, Verdict::Compatible { .. }));
+    assert_eq!(v.node().to_string(), "avalanche/1.9.3");
+    v.check_plugin().unwrap();
+
+    let v = check(&resp("avalanche/1.8.6", None)).unwrap();
+    assert!(matches!(v, Verdict::TooOld { .. }));
+    assert_eq!(v.check_rpc().unwrap_err().kind(), ErrorKind::Unsupported);
+
+    let v = check(&resp("avalanche/1.9.4", None)).unwrap();
+    assert_eq!(
+        v,
+        Verdict::PluginProtocolMismatch {
+            node: "avalanche/1.9.4".parse().unwrap(),
+            node_protocol: 20,
+        }
+    );
+    v.check_rpc().unwrap();
+    assert!(v.check_plugin().is_err());
+
+    let v = check(&resp("avalanche/1.10.0", None)).unwrap();
+    assert!(matches!(v, Verdict::PluginProtocolUnknown { .. }));
+    assert!(v.is_rpc_compatible() && !v.is_plugin_compatible());
+
+    // reported protocol overrides the matrix
+    let v = check(&resp("avalanche/1.10.0", Some("19"))).unwrap();
+    assert!(v.is_plugin_compatible());
+
+    assert!(check(&resp("avalanche/1.9.3", Some("x"))).is_err());
+    assert!(check(&resp("unknown", None)).is_err());
+}
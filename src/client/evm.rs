@@ -1,6 +1,10 @@
-use std::io::{self, Error, ErrorKind};
+use std::io;
 
-use crate::jsonrpc::{self, evm};
+use crate::{
+    client::transport,
+    ids,
+    jsonrpc::{self, evm},
+};
 
 pub async fn block_number(
     http_rpc: &str,
@@ -9,28 +13,26 @@ pub async fn block_number(
     let chain_rpc_url_path = format!("/ext/bc/{}/rpc", chain_id_alias);
     log::info!("getting block number via {} {}", http_rpc, chain_id_alias,);
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("eth_blockNumber");
+    let call = jsonrpc::Call::new("eth_blockNumber");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed eth_blockNumber '{}'", e)))
+    call.decode(&rb)
 }
 
 pub async fn chain_id(http_rpc: &str, chain_id_alias: &str) -> io::Result<evm::ChainIdResponse> {
     let chain_rpc_url_path = format!("/ext/bc/{}/rpc", chain_id_alias);
     log::info!("getting chain id via {} {}", http_rpc, chain_id_alias,);
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("eth_chainId");
+    let call = jsonrpc::Call::new("eth_chainId");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed eth_chainId '{}'", e)))
+    call.decode(&rb)
 }
 
 /// Fetches the balance.
@@ -50,17 +52,15 @@ pub async fn get_balance(
         chain_rpc_url_path
     );
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("eth_getBalance");
+    let call = jsonrpc::Call::new("eth_getBalance");
 
     let params = vec![String::from(eth_addr), "latest".to_string()];
-    data.params = Some(params);
+    let data = call.request_with_params_array(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed eth_getBalance '{}'", e)))
+    call.decode(&rb)
 }
 
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactioncount
@@ -77,21 +77,15 @@ pub async fn get_latest_transaction_count(
         chain_rpc_url_path
     );
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("eth_getTransactionCount");
+    let call = jsonrpc::Call::new("eth_getTransactionCount");
 
     let params = vec![String::from(eth_addr), "latest".to_string()];
-    data.params = Some(params);
+    let data = call.request_with_params_array(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed eth_getTransactionCount '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
 
 /// Get transaction receipt.
@@ -109,21 +103,15 @@ pub async fn get_transaction_receipt(
         chain_rpc_url_path
     );
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("eth_getTransactionReceipt");
+    let call = jsonrpc::Call::new("eth_getTransactionReceipt");
 
     let params = vec![String::from(tx_hash)];
-    data.params = Some(params);
+    let data = call.request_with_params_array(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed eth_getTransactionReceipt '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
 
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_signtransaction
@@ -137,19 +125,46 @@ pub async fn send_raw_transaction(
     let chain_rpc_url_path = format!("/ext/bc/{}/rpc", chain_id_alias);
     log::info!("sending raw tx via {} {}", http_rpc, chain_id_alias,);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("eth_sendRawTransaction");
+    let call = jsonrpc::Call::new("eth_sendRawTransaction");
 
     let params = vec![tx_bytes_signed_hex.to_string()];
-    data.params = Some(params);
+    let data = call.request_with_params_array(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+
+    call.decode(&rb)
+}
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed eth_sendRawTransaction '{}'", e),
-        )
+/// Fetches the signed warp message, aggregating the BLS signatures of the
+/// source subnet validators until "quorum_num" percent of the stake signed.
+/// "chain_id_alias" is the subnet-evm chain Id that sent the message.
+/// ref. https://github.com/ava-labs/subnet-evm/blob/master/warp/service.go
+pub async fn get_message_aggregate_signature(
+    http_rpc: &str,
+    chain_id_alias: &str,
+    message_id: &ids::Id,
+    quorum_num: u64,
+) -> io::Result<evm::GetMessageAggregateSignatureResponse> {
+    let chain_rpc_url_path = format!("/ext/bc/{}/rpc", chain_id_alias);
+    log::info!(
+        "getting aggregate signature for warp message {} via {} {}",
+        message_id,
+        http_rpc,
+        chain_rpc_url_path
+    );
+
+    // the quorum numerator must be encoded as a number,
+    // which "RequestWithParamsArray" cannot carry
+    let call = jsonrpc::Call::new("warp_getMessageAggregateSignature");
+    let d = serde_json::json!({
+        "jsonrpc": jsonrpc::DEFAULT_VERSION,
+        "id": call.id,
+        "method": call.method,
+        "params": [message_id.to_string(), quorum_num],
     })
+    .to_string();
+    let rb = transport::post_non_tls(http_rpc, &chain_rpc_url_path, &d).await?;
+
+    call.decode(&rb)
 }
@@ -3,7 +3,10 @@ use std::{
     sync::Arc,
 };
 
-use crate::jsonrpc::health;
+use crate::{
+    client::transport,
+    jsonrpc::{self, health},
+};
 
 /// "If a single piece of data must be accessible from more than one task
 /// concurrently, then it must be shared using synchronization primitives such as Arc."
@@ -16,18 +19,32 @@ pub async fn check(http_rpc: Arc<String>, liveness: bool) -> io::Result<health::
             "ext/health"
         }
     };
-    let joined = http_manager::join_uri(http_rpc.as_str(), url_path)?;
+    let joined = transport::join_uri(http_rpc.as_str(), url_path)?;
     log::info!("checking for {:?}", joined);
 
-    let rb = http_manager::get_non_tls(http_rpc.as_str(), url_path).await?;
+    let rb = transport::get_non_tls(http_rpc.as_str(), url_path).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed health '{}'", e)))
+    jsonrpc::decode(&rb).map_err(|e| Error::new(ErrorKind::Other, format!("failed health '{}'", e)))
 }
 
+/// Runs the check in a separate task. On wasm32, where the client futures
+/// are not "Send", the check runs in the current task instead.
 pub async fn spawn_check(http_rpc: &str, liveness: bool) -> io::Result<health::Response> {
     let ep_arc = Arc::new(http_rpc.to_string());
-    tokio::spawn(async move { check(ep_arc, liveness).await })
+
+    #[cfg(target_arch = "wasm32")]
+    return check(ep_arc, liveness).await;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let t = transport::current();
+        tokio::spawn(async move {
+            match t {
+                Some(t) => transport::scope(t, check(ep_arc, liveness)).await,
+                None => check(ep_arc, liveness).await,
+            }
+        })
         .await
         .expect("failed spawn await")
+    }
 }
@@ -0,0 +1,411 @@
+//! Subscription to the accepted containers over the index API.
+//!
+//! The index API of the node (with "--index-enabled") is pull-only, so the
+//! subscription polls "index.getContainerRange" from the next index, and
+//! yields the decoded containers in the order of acceptance. The polls are
+//! back to back while catching up, and back off up to the maximum interval
+//! while the chain is idle or the node is unreachable. A container out of
+//! order (e.g., the node was re-indexed) ends the stream with an error,
+//! so that the indexer can resume from the last index it processed.
+//!
+//! ```
+//! # async fn run() {
+//! use avalanche_types::client::index;
+//! use futures::StreamExt;
+//!
+//! let accepted = index::stream_accepted("http://localhost:9650", index::C_BLOCK, 0);
+//! futures::pin_mut!(accepted);
+//! while let Some(Ok(c)) = accepted.next().await {
+//!     println!("{} {} ({} bytes)", c.index, c.id, c.bytes.len());
+//! }
+//! # }
+//! ```
+
+use std::{
+    collections::VecDeque,
+    io::{self, Error, ErrorKind},
+    time::Duration,
+};
+
+use crate::{
+    client::{cancel, checkpoint, transport},
+    ids,
+    jsonrpc::{self, index},
+};
+use chrono::{DateTime, Utc};
+
+/// Indexes of the primary network, as "[chain]/[container type]".
+/// ref. https://docs.avax.network/apis/avalanchego/apis/index-api
+pub const X_TX: &str = "X/tx";
+pub const X_VTX: &str = "X/vtx";
+pub const P_BLOCK: &str = "P/block";
+pub const C_BLOCK: &str = "C/block";
+
+/// Default number of containers per poll.
+pub const DEFAULT_PAGE_SIZE: u64 = 100;
+
+/// Default wait after a poll that returned new containers.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Default maximum wait, while no container is accepted.
+pub const DEFAULT_MAX_INTERVAL: Duration = Duration::from_secs(30);
+
+/// e.g., "index.getContainerRange" on "ext/index/C/block".
+/// ref. https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange
+pub async fn get_container_range(
+    http_rpc: &str,
+    chain: &str,
+    start_index: u64,
+    num_to_fetch: u64,
+) -> io::Result<index::GetContainerRangeResponse> {
+    log::debug!(
+        "getting {} container(s) from {} in {} for {}",
+        num_to_fetch,
+        start_index,
+        chain,
+        http_rpc
+    );
+
+    let call = jsonrpc::Call::new("index.getContainerRange");
+    let mut data = index::GetContainerRangeRequest::default();
+    data.id = call.id;
+    data.method = call.method.clone();
+    data.params = Some(index::GetContainerRangeParams {
+        start_index,
+        num_to_fetch,
+        encoding: String::from("hex"),
+    });
+
+    let d = data.encode_json()?;
+    let url_path = format!("ext/index/{}", chain);
+    let rb = transport::post_non_tls(http_rpc, &url_path, &d).await?;
+
+    call.decode(&rb)
+}
+
+/// Returns true if the error means that no container is accepted
+/// at the start index yet, rather than a failed call.
+/// ref. "indexer.index.GetContainerRange"
+fn is_not_accepted_yet(e: &jsonrpc::ResponseError) -> bool {
+    e.message.contains("> last accepted index")
+        || e.message.contains("no containers have been accepted")
+}
+
+/// Accepted container with the decoded bytes.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Container {
+    pub index: u64,
+    pub id: ids::Id,
+    /// Acceptance time on the node.
+    pub timestamp: DateTime<Utc>,
+    pub bytes: Vec<u8>,
+}
+
+impl TryFrom<index::Container> for Container {
+    type Error = Error;
+
+    fn try_from(c: index::Container) -> io::Result<Self> {
+        let bytes = c.decode_bytes()?;
+        Ok(Self {
+            index: c.index,
+            id: c.id,
+            timestamp: c.timestamp,
+            bytes,
+        })
+    }
+}
+
+/// Polls an index of the node from a start index.
+#[derive(Debug, Clone)]
+pub struct Subscription {
+    pub http_rpc: String,
+    /// e.g., "C/block" (see "C_BLOCK").
+    pub chain: String,
+    /// Index of the first container to yield.
+    pub from_index: u64,
+    pub page_size: u64,
+    pub min_interval: Duration,
+    pub max_interval: Duration,
+}
+
+impl Subscription {
+    pub fn new(http_rpc: &str, chain: &str, from_index: u64) -> Self {
+        Self {
+            http_rpc: http_rpc.to_string(),
+            chain: chain.to_string(),
+            from_index,
+            page_size: DEFAULT_PAGE_SIZE,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            max_interval: DEFAULT_MAX_INTERVAL,
+        }
+    }
+
+    /// Sets the number of containers per poll,
+    /// capped at "index::MAX_FETCHED_BY_RANGE".
+    #[must_use]
+    pub fn page_size(mut self, page_size: u64) -> Self {
+        self.page_size = page_size.clamp(1, index::MAX_FETCHED_BY_RANGE);
+        self
+    }
+
+    /// Sets the wait after a poll that returned new containers.
+    #[must_use]
+    pub fn min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    /// Sets the maximum wait, while no container is accepted.
+    #[must_use]
+    pub fn max_interval(mut self, max_interval: Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    /// Resumes from the container after the last one recorded under the key
+    /// (see "checkpoint::key"), or from "from_index" if none is recorded.
+    pub async fn resume(mut self, cp: &dyn checkpoint::Checkpoint, key: &str) -> io::Result<Self> {
+        self.from_index = checkpoint::resume_index(cp, key, self.from_index).await?;
+        log::info!(
+            "resuming {} of {} from {} ('{}')",
+            self.chain,
+            self.http_rpc,
+            self.from_index,
+            key
+        );
+        Ok(self)
+    }
+
+    /// Returns the stream of accepted containers from "from_index", which
+    /// ends after an error (an out-of-order or undecodable container),
+    /// or when the "cancel::scope" is cancelled. The failed polls are
+    /// logged and retried.
+    pub fn stream(self) -> impl futures::Stream<Item = io::Result<Container>> {
+        let poller = Poller {
+            next_index: self.from_index,
+            interval: self.min_interval,
+            wait: Duration::ZERO,
+            buffered: VecDeque::new(),
+            failed: None,
+            subscription: self,
+        };
+        futures::stream::unfold(Some(poller), |poller| async move {
+            let mut poller = poller?;
+            let next = poller.next().await?;
+            let done = next.is_err();
+            Some((next, if done { None } else { Some(poller) }))
+        })
+    }
+}
+
+/// Returns the stream of the containers accepted in the index of the chain
+/// (e.g., "C/block"), from "from_index" with the default poll intervals.
+pub fn stream_accepted(
+    http_rpc: &str,
+    chain: &str,
+    from_index: u64,
+) -> impl futures::Stream<Item = io::Result<Container>> {
+    Subscription::new(http_rpc, chain, from_index).stream()
+}
+
+struct Poller {
+    subscription: Subscription,
+    next_index: u64,
+    /// Current backoff, from "min_interval" to "max_interval".
+    interval: Duration,
+    /// Wait before the next poll, zero while catching up.
+    wait: Duration,
+    buffered: VecDeque<Container>,
+    /// Yielded after the buffered containers.
+    failed: Option<Error>,
+}
+
+impl Poller {
+    /// Returns the next container, polling until one is accepted.
+    /// Returns None if cancelled.
+    async fn next(&mut self) -> Option<io::Result<Container>> {
+        loop {
+            if let Some(c) = self.buffered.pop_front() {
+                return Some(Ok(c));
+            }
+            if let Some(e) = self.failed.take() {
+                return Some(Err(e));
+            }
+
+            if !self.wait.is_zero() && cancel::sleep(self.wait).await.is_err() {
+                return None;
+            }
+            match self.poll().await {
+                Ok(0) => self.back_off(),
+                Ok(n) => {
+                    self.interval = self.subscription.min_interval;
+                    self.wait = if n as u64 == self.subscription.page_size {
+                        Duration::ZERO
+                    } else {
+                        self.interval
+                    };
+                }
+                Err(e) => {
+                    log::warn!(
+                        "failed to poll {} of {} from {} '{}'",
+                        self.subscription.chain,
+                        self.subscription.http_rpc,
+                        self.next_index,
+                        e
+                    );
+                    self.back_off();
+                }
+            }
+        }
+    }
+
+    fn back_off(&mut self) {
+        self.wait = self.interval;
+        self.interval = (self.interval * 2).min(self.subscription.max_interval);
+    }
+
+    /// Fetches the next page into the buffer, and returns the number
+    /// of the containers in the page.
+    async fn poll(&mut self) -> io::Result<usize> {
+        let resp = get_container_range(
+            &self.subscription.http_rpc,
+            &self.subscription.chain,
+            self.next_index,
+            self.subscription.page_size,
+        )
+        .await?;
+        if let Some(e) = &resp.error {
+            if is_not_accepted_yet(e) {
+                return Ok(0);
+            }
+        }
+        let containers = resp.into_result()?.containers;
+
+        let n = containers.len();
+        for c in containers {
+            if c.index != self.next_index {
+                self.failed = Some(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "gap in {} of {}, expected index {} but got {} ({})",
+                        self.subscription.chain,
+                        self.subscription.http_rpc,
+                        self.next_index,
+                        c.index,
+                        c.id
+                    ),
+                ));
+                break;
+            }
+            match Container::try_from(c) {
+                Ok(c) => self.buffered.push_back(c),
+                Err(e) => {
+                    self.failed = Some(Error::new(
+                        e.kind(),
+                        format!("failed to decode container {} '{}'", self.next_index, e),
+                    ));
+                    break;
+                }
+            }
+            self.next_index += 1;
+        }
+        Ok(n)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::index::test_stream_accepted --exact --show-output
+#[tokio::test]
+async fn test_stream_accepted() {
+    use crate::{
+        client::transport::{scope, Transport},
+        formatting,
+    };
+    use futures::StreamExt;
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    };
+
+    /// Accepts one more container per poll up to "total", and skips
+    /// the index "skip" if set.
+    struct Fake {
+        total: u64,
+        skip: Option<u64>,
+        accepted: AtomicU64,
+    }
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, _: &str, url_path: &str, body: &str) -> io::Result<Vec<u8>> {
+            assert_eq!(url_path, "ext/index/C/block");
+            let req: index::GetContainerRangeRequest = serde_json::from_str(body).unwrap();
+            let params = req.params.unwrap();
+            let accepted = (self.accepted.fetch_add(1, Ordering::SeqCst) + 1).min(self.total);
+
+            let body = if accepted == 0 || params.start_index >= accepted {
+                format!(
+                    r#""error":{{"code":-32000,"message":"start index ({}) > last accepted index ({})"}}"#,
+                    params.start_index,
+                    accepted as i64 - 1
+                )
+            } else {
+                let end = accepted.min(params.start_index + params.num_to_fetch);
+                let containers: Vec<String> = (params.start_index..end)
+                    .map(|i| if Some(i) == self.skip { i + 1 } else { i })
+                    .map(|i| {
+                        format!(
+                            r#"{{"id":"{}","bytes":"0x{}","timestamp":"2022-12-01T00:00:00Z","encoding":"hex","index":"{}"}}"#,
+                            ids::Id::from_slice(&[i as u8]),
+                            formatting::encode_hex_with_checksum(&[i as u8]),
+                            i
+                        )
+                    })
+                    .collect();
+                format!(r#""result":{{"containers":[{}]}}"#, containers.join(","))
+            };
+            Ok(format!(r#"{{"jsonrpc":"2.0","id":{},{}}}"#, req.id, body).into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let fake = Arc::new(Fake {
+        total: 7,
+        skip: None,
+        accepted: AtomicU64::new(0),
+    });
+    let containers: Vec<Container> = scope(
+        fake.clone(),
+        Subscription::new("http://node", C_BLOCK, 2)
+            .page_size(3)
+            .min_interval(Duration::from_millis(1))
+            .max_interval(Duration::from_millis(4))
+            .stream()
+            .take(5)
+            .map(|c| c.unwrap())
+            .collect(),
+    )
+    .await;
+    let indexes: Vec<u64> = containers.iter().map(|c| c.index).collect();
+    assert_eq!(indexes, vec![2, 3, 4, 5, 6]);
+    assert_eq!(containers[0].bytes, vec![2]);
+    assert_eq!(containers[4].id, ids::Id::from_slice(&[6]));
+
+    // yields the containers before the gap, then ends with the error
+    let fake = Arc::new(Fake {
+        total: 5,
+        skip: Some(3),
+        accepted: AtomicU64::new(5),
+    });
+    let results: Vec<io::Result<Container>> = scope(
+        fake,
+        stream_accepted("http://node", C_BLOCK, 1).collect::<Vec<_>>(),
+    )
+    .await;
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[1].as_ref().unwrap().index, 2);
+    assert_eq!(
+        results[2].as_ref().unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
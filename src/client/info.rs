@@ -1,27 +1,23 @@
-use std::{
-    collections::HashMap,
-    io::{self, Error, ErrorKind},
-};
+use std::{collections::HashMap, io};
 
-use crate::jsonrpc::{self, info};
+use crate::{
+    client::transport,
+    ids::node,
+    jsonrpc::{self, info},
+};
 
 /// e.g., "info.getNetworkName".
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkname
 pub async fn get_network_name(http_rpc: &str) -> io::Result<info::GetNetworkNameResponse> {
     log::info!("getting network name for {}", http_rpc);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.getNetworkName");
+    let call = jsonrpc::Call::new("info.getNetworkName");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
-
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed info.getNetworkName '{}'", e),
-        )
-    })
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "info.getNetworkID".
@@ -29,18 +25,13 @@ pub async fn get_network_name(http_rpc: &str) -> io::Result<info::GetNetworkName
 pub async fn get_network_id(http_rpc: &str) -> io::Result<info::GetNetworkIdResponse> {
     log::info!("getting network ID for {}", http_rpc);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.getNetworkID");
+    let call = jsonrpc::Call::new("info.getNetworkID");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
-
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed info.getNetworkID '{}'", e),
-        )
-    })
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "info.getBlockchainID".
@@ -51,22 +42,16 @@ pub async fn get_blockchain_id(
 ) -> io::Result<info::GetBlockchainIdResponse> {
     log::info!("getting blockchain ID for {} and {}", http_rpc, chain_alias);
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("info.getBlockchainID");
+    let call = jsonrpc::Call::new("info.getBlockchainID");
 
     let mut params = HashMap::new();
     params.insert(String::from("alias"), String::from(chain_alias));
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
-
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed info.getBlockchainID '{}'", e),
-        )
-    })
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "info.getNodeID".
@@ -74,34 +59,28 @@ pub async fn get_blockchain_id(
 pub async fn get_node_id(http_rpc: &str) -> io::Result<info::GetNodeIdResponse> {
     log::info!("getting node ID for {}", http_rpc);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.getNodeID");
+    let call = jsonrpc::Call::new("info.getNodeID");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed info.getNodeID '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "info.getNodeVersion".
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnodeversion
 pub async fn get_node_version(http_rpc: &str) -> io::Result<info::GetNodeVersionResponse> {
-    let joined = http_manager::join_uri(http_rpc, "ext/info")?;
+    let joined = transport::join_uri(http_rpc, "ext/info")?;
     log::info!("getting node version for {}", joined.as_str());
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.getNodeVersion");
+    let call = jsonrpc::Call::new("info.getNodeVersion");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
-
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed info.getNodeVersion '{}'", e),
-        )
-    })
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "info.getVMs".
@@ -109,14 +88,13 @@ pub async fn get_node_version(http_rpc: &str) -> io::Result<info::GetNodeVersion
 pub async fn get_vms(http_rpc: &str) -> io::Result<info::GetVmsResponse> {
     log::info!("getting VMs for {}", http_rpc);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.getVMs");
+    let call = jsonrpc::Call::new("info.getVMs");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed info.getVMs '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "info.isBootstrapped".
@@ -124,18 +102,33 @@ pub async fn get_vms(http_rpc: &str) -> io::Result<info::GetVmsResponse> {
 pub async fn is_bootstrapped(http_rpc: &str) -> io::Result<info::IsBootstrappedResponse> {
     log::info!("getting bootstrapped for {}", http_rpc);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.isBootstrapped");
+    let call = jsonrpc::Call::new("info.isBootstrapped");
+    let data = call.request();
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "info.isBootstrapped" for a single chain (e.g., "X", "P", "C").
+/// ref. https://docs.avax.network/build/avalanchego-apis/info/#infoisbootstrapped
+pub async fn is_chain_bootstrapped(
+    http_rpc: &str,
+    chain_alias: &str,
+) -> io::Result<info::IsBootstrappedResponse> {
+    log::info!("getting bootstrapped for {} and {}", http_rpc, chain_alias);
+
+    let call = jsonrpc::Call::new("info.isBootstrapped");
+
+    let mut params = HashMap::new();
+    params.insert(String::from("chain"), String::from(chain_alias));
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
-
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed info.isBootstrapped '{}'", e),
-        )
-    })
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "info.getTxFee".
@@ -143,12 +136,45 @@ pub async fn is_bootstrapped(http_rpc: &str) -> io::Result<info::IsBootstrappedR
 pub async fn get_tx_fee(http_rpc: &str) -> io::Result<info::GetTxFeeResponse> {
     log::info!("getting node ID for {}", http_rpc);
 
-    let mut data = jsonrpc::RequestWithParamsArray::default();
-    data.method = String::from("info.getTxFee");
+    let call = jsonrpc::Call::new("info.getTxFee");
+    let data = call.request();
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "info.peers" for the specified nodes, or for all the connected
+/// peers if "node_ids" is empty.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/info#infopeers
+pub async fn peers(http_rpc: &str, node_ids: &[node::Id]) -> io::Result<info::PeersResponse> {
+    log::info!("getting {} peer(s) for {}", node_ids.len(), http_rpc);
+
+    let call = jsonrpc::Call::new("info.peers");
+    let mut data = info::PeersRequest::default();
+    data.id = call.id;
+    data.method = call.method.clone();
+    data.params = Some(info::PeersParams {
+        node_ids: node_ids.to_vec(),
+    });
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "info.uptime" of the node as observed by the other validators.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/info#infouptime
+pub async fn uptime(http_rpc: &str) -> io::Result<info::UptimeResponse> {
+    log::info!("getting uptime for {}", http_rpc);
+
+    let call = jsonrpc::Call::new("info.uptime");
+    let data = call.request();
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/info", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "ext/info", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed info.getTxFee '{}'", e)))
+    call.decode(&rb)
 }
@@ -0,0 +1,325 @@
+//! Scraper of the node metrics, exposed at "/ext/metrics" in the
+//! Prometheus text exposition format, parsed without a Prometheus crate.
+//! ref. https://docs.avax.network/apis/avalanchego/apis/metrics
+//! ref. https://prometheus.io/docs/instrumenting/exposition_formats/#text-based-format
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::client::transport;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MetricType {
+    Counter,
+    Gauge,
+    Histogram,
+    Summary,
+    Untyped,
+}
+
+impl MetricType {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "counter" => MetricType::Counter,
+            "gauge" => MetricType::Gauge,
+            "histogram" => MetricType::Histogram,
+            "summary" => MetricType::Summary,
+            _ => MetricType::Untyped,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sample {
+    /// Name of the sample, which has the "_bucket", "_sum" or "_count" suffix
+    /// in the histograms and the summaries.
+    pub name: String,
+    pub labels: BTreeMap<String, String>,
+    pub value: f64,
+    /// Milliseconds since the Unix epoch, if set.
+    pub timestamp_ms: Option<i64>,
+}
+
+impl Sample {
+    /// Returns true if the sample has every label.
+    pub fn matches(&self, labels: &[(&str, &str)]) -> bool {
+        labels
+            .iter()
+            .all(|(k, v)| self.labels.get(*k).map_or(false, |l| l == v))
+    }
+}
+
+/// Metric family: the samples of one metric under all its labels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Family {
+    pub name: String,
+    pub help: String,
+    pub metric_type: MetricType,
+    pub samples: Vec<Sample>,
+}
+
+impl Family {
+    fn new(name: &str, metric_type: MetricType) -> Self {
+        Self {
+            name: name.to_string(),
+            help: String::new(),
+            metric_type,
+            samples: Vec::new(),
+        }
+    }
+
+    /// Returns the value of the first sample with every label
+    /// (e.g., the counter or the gauge value).
+    pub fn value(&self, labels: &[(&str, &str)]) -> Option<f64> {
+        self.samples
+            .iter()
+            .find(|s| s.name == self.name && s.matches(labels))
+            .map(|s| s.value)
+    }
+
+    /// Returns the sum of the samples with every label, across the other labels.
+    pub fn sum(&self, labels: &[(&str, &str)]) -> f64 {
+        self.samples
+            .iter()
+            .filter(|s| s.name == self.name && s.matches(labels))
+            .map(|s| s.value)
+            .sum()
+    }
+
+    /// Returns the "_sum" and "_count" samples of the histogram or the summary.
+    pub fn sum_and_count(&self, labels: &[(&str, &str)]) -> Option<(f64, f64)> {
+        let find = |suffix: &str| {
+            let name = format!("{}{}", self.name, suffix);
+            self.samples
+                .iter()
+                .find(|s| s.name == name && s.matches(labels))
+                .map(|s| s.value)
+        };
+        Some((find("_sum")?, find("_count")?))
+    }
+}
+
+/// Fetches and parses the metrics of the node.
+pub async fn scrape(http_rpc: &str) -> io::Result<BTreeMap<String, Family>> {
+    log::info!("scraping metrics from {}", http_rpc);
+    let rb = transport::get_non_tls(http_rpc, "ext/metrics").await?;
+    let text = String::from_utf8(rb).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("metrics are not UTF-8 '{}'", e),
+        )
+    })?;
+    parse(&text)
+}
+
+/// Parses the text exposition format into the families by name.
+/// The samples without the "# TYPE" line are kept as untyped families.
+pub fn parse(text: &str) -> io::Result<BTreeMap<String, Family>> {
+    let mut families: BTreeMap<String, Family> = BTreeMap::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(comment) = line.strip_prefix('#') {
+            let mut parts = comment.trim_start().splitn(3, char::is_whitespace);
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("HELP"), Some(name), help) => {
+                    families
+                        .entry(name.to_string())
+                        .or_insert_with(|| Family::new(name, MetricType::Untyped))
+                        .help = unescape(help.unwrap_or_default().trim());
+                }
+                (Some("TYPE"), Some(name), Some(t)) => {
+                    families
+                        .entry(name.to_string())
+                        .or_insert_with(|| Family::new(name, MetricType::Untyped))
+                        .metric_type = MetricType::from_str(t.trim());
+                }
+                _ => {}
+            }
+            continue;
+        }
+
+        let sample = parse_sample(line)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("line {}: {}", i + 1, e)))?;
+        let family_name = family_of(&families, &sample.name);
+        families
+            .entry(family_name.clone())
+            .or_insert_with(|| Family::new(&family_name, MetricType::Untyped))
+            .samples
+            .push(sample);
+    }
+    Ok(families)
+}
+
+/// Returns the family of the sample, which drops the suffix of
+/// the histogram and summary samples.
+fn family_of(families: &BTreeMap<String, Family>, sample_name: &str) -> String {
+    for suffix in ["_bucket", "_sum", "_count"] {
+        if let Some(base) = sample_name.strip_suffix(suffix) {
+            if let Some(f) = families.get(base) {
+                if matches!(f.metric_type, MetricType::Histogram | MetricType::Summary) {
+                    return base.to_string();
+                }
+            }
+        }
+    }
+    sample_name.to_string()
+}
+
+fn parse_sample(line: &str) -> io::Result<Sample> {
+    let invalid = |msg: &str| Error::new(ErrorKind::InvalidData, format!("{} in '{}'", msg, line));
+
+    let name_end = line
+        .find(|c: char| c == '{' || c.is_whitespace())
+        .ok_or_else(|| invalid("no value"))?;
+    let name = &line[..name_end];
+    let mut rest = &line[name_end..];
+
+    let mut labels = BTreeMap::new();
+    if let Some(after) = rest.strip_prefix('{') {
+        let (parsed, end) = parse_labels(after).map_err(invalid)?;
+        labels = parsed;
+        rest = &after[end..];
+    }
+
+    let mut fields = rest.split_whitespace();
+    let value = fields.next().ok_or_else(|| invalid("no value"))?;
+    let value = value.parse::<f64>().map_err(|_| invalid("invalid value"))?;
+    let timestamp_ms = match fields.next() {
+        Some(ts) => Some(
+            ts.parse::<i64>()
+                .map_err(|_| invalid("invalid timestamp"))?,
+        ),
+        None => None,
+    };
+
+    Ok(Sample {
+        name: name.to_string(),
+        labels,
+        value,
+        timestamp_ms,
+    })
+}
+
+/// Parses the labels after "{", and returns them with the length up to "}".
+fn parse_labels(s: &str) -> Result<(BTreeMap<String, String>, usize), &'static str> {
+    let mut labels = BTreeMap::new();
+    let mut chars = s.char_indices().peekable();
+    loop {
+        while let Some((_, c)) = chars.peek() {
+            if !c.is_whitespace() && *c != ',' {
+                break;
+            }
+            chars.next();
+        }
+        let (start, c) = chars.next().ok_or("unterminated labels")?;
+        if c == '}' {
+            return Ok((labels, start + 1));
+        }
+
+        let mut key = String::from(c);
+        loop {
+            match chars.next() {
+                Some((_, '=')) => break,
+                Some((_, c)) => key.push(c),
+                None => return Err("label without value"),
+            }
+        }
+        if !matches!(chars.next(), Some((_, '"'))) {
+            return Err("unquoted label value");
+        }
+        let mut value = String::new();
+        loop {
+            match chars.next() {
+                Some((_, '\\')) => match chars.next() {
+                    Some((_, 'n')) => value.push('\n'),
+                    Some((_, c)) => value.push(c),
+                    None => return Err("unterminated label value"),
+                },
+                Some((_, '"')) => break,
+                Some((_, c)) => value.push(c),
+                None => return Err("unterminated label value"),
+            }
+        }
+        labels.insert(key.trim().to_string(), value);
+    }
+}
+
+/// Unescapes "\\" and "\n" of the help text.
+fn unescape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('\\') => out.push('\\'),
+            Some(c) => {
+                out.push('\\');
+                out.push(c);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::metrics::test_parse --exact --show-output
+#[test]
+fn test_parse() {
+    let text = r#"
+# HELP avalanche_network_peers Number of network peers
+# TYPE avalanche_network_peers gauge
+avalanche_network_peers 42
+# HELP avalanche_P_vm_blks_accepted Number of accepted blocks
+# TYPE avalanche_P_vm_blks_accepted counter
+avalanche_P_vm_blks_accepted{chain="P",kind="standard"} 100
+avalanche_P_vm_blks_accepted{chain="P",kind="proposal"} 5 1700000000000
+# TYPE avalanche_requests_latency histogram
+avalanche_requests_latency_bucket{op="get",le="0.1"} 3
+avalanche_requests_latency_bucket{op="get",le="+Inf"} 4
+avalanche_requests_latency_sum{op="get"} 0.75
+avalanche_requests_latency_count{op="get"} 4
+go_goroutines 120
+weird{path="a\"b\\c",empty=""} NaN
+neg -Inf
+"#;
+    let families = parse(text).unwrap();
+    assert_eq!(families.len(), 6);
+    assert_eq!(families["neg"].value(&[]), Some(f64::NEG_INFINITY));
+
+    let peers = &families["avalanche_network_peers"];
+    assert_eq!(peers.metric_type, MetricType::Gauge);
+    assert_eq!(peers.help, "Number of network peers");
+    assert_eq!(peers.value(&[]), Some(42.0));
+
+    let accepted = &families["avalanche_P_vm_blks_accepted"];
+    assert_eq!(accepted.metric_type, MetricType::Counter);
+    assert_eq!(accepted.value(&[("kind", "proposal")]), Some(5.0));
+    assert_eq!(accepted.samples[1].timestamp_ms, Some(1700000000000));
+    assert_eq!(accepted.sum(&[("chain", "P")]), 105.0);
+    assert_eq!(accepted.value(&[("kind", "atomic")]), None);
+
+    let latency = &families["avalanche_requests_latency"];
+    assert_eq!(latency.metric_type, MetricType::Histogram);
+    assert_eq!(latency.samples.len(), 4);
+    assert_eq!(latency.samples[1].labels["le"], "+Inf");
+    assert_eq!(latency.sum_and_count(&[("op", "get")]), Some((0.75, 4.0)));
+
+    assert_eq!(families["go_goroutines"].metric_type, MetricType::Untyped);
+    let weird = &families["weird"].samples[0];
+    assert_eq!(weird.labels["path"], r#"a"b\c"#);
+    assert_eq!(weird.labels["empty"], "");
+    assert!(weird.value.is_nan());
+
+    assert!(parse("no_value").is_err());
+    assert!(parse(r#"bad{a="b} 1"#).is_err());
+    assert!(parse("bad one").is_err());
+}
@@ -1,6 +1,30 @@
+#[cfg(all(target_arch = "wasm32", not(feature = "wasm")))]
+compile_error!("the client on wasm32 requires the \"wasm\" feature (the \"fetch\" transport)");
+
+pub mod admin;
+pub mod bootstrap;
+pub mod cache;
+pub mod cancel;
+pub mod cassette;
+pub mod chaos;
+pub mod checkpoint;
+pub mod clock;
+pub mod compat;
+pub mod debug;
 pub mod evm;
 pub mod health;
+pub mod index;
 pub mod info;
+pub mod metrics;
+pub mod multi;
 pub mod p;
+pub mod plugin;
+pub mod quorum;
+pub mod time;
+pub mod transport;
+pub mod txs;
+pub mod validator_monitor;
+#[cfg(feature = "wallet")]
 pub mod wallet;
+pub mod watch;
 pub mod x;
@@ -0,0 +1,302 @@
+//! Fan-out of the same query across chains or endpoints, with bounded
+//! concurrency and typed, joined results.
+//!
+//! ```
+//! # async fn run() -> std::io::Result<()> {
+//! use avalanche_types::client::multi::Fanout;
+//!
+//! let endpoints = vec![
+//!     "http://node-1:9650".to_string(),
+//!     "http://node-2:9650".to_string(),
+//! ];
+//! let joined = Fanout::new(4).x_tx_status(&endpoints, "tx-id").await;
+//! for r in joined.responses.iter() {
+//!     println!("{}: {:?}", r.key, r.result);
+//! }
+//! let statuses = joined.into_result()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    future::Future,
+    io::{self, Error},
+};
+
+use crate::{
+    client::{evm as client_evm, p as client_p, x as client_x},
+    jsonrpc::{avm, platformvm},
+};
+use futures::{stream, StreamExt};
+
+/// Default number of in-flight queries.
+pub const DEFAULT_CONCURRENCY: usize = 8;
+
+/// Response for a single key (e.g., endpoint or address).
+#[derive(Debug)]
+pub struct Response<K, T> {
+    pub key: K,
+    pub result: io::Result<T>,
+}
+
+/// Joined responses, in the order of the keys.
+#[derive(Debug)]
+pub struct Joined<K, T> {
+    pub responses: Vec<Response<K, T>>,
+}
+
+impl<K, T> Joined<K, T> {
+    /// Returns true if every query succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.responses.iter().all(|r| r.result.is_ok())
+    }
+
+    /// Returns the successful results with their keys.
+    pub fn oks(&self) -> Vec<(&K, &T)> {
+        self.responses
+            .iter()
+            .filter_map(|r| r.result.as_ref().ok().map(|v| (&r.key, v)))
+            .collect()
+    }
+
+    /// Returns the failed queries with their keys.
+    pub fn errors(&self) -> Vec<(&K, &Error)> {
+        self.responses
+            .iter()
+            .filter_map(|r| r.result.as_ref().err().map(|e| (&r.key, e)))
+            .collect()
+    }
+
+    /// Returns all results with their keys, or the first error.
+    pub fn into_result(self) -> io::Result<Vec<(K, T)>>
+    where
+        K: std::fmt::Display,
+    {
+        let mut rs = Vec::with_capacity(self.responses.len());
+        for r in self.responses {
+            match r.result {
+                Ok(v) => rs.push((r.key, v)),
+                Err(e) => {
+                    return Err(Error::new(
+                        e.kind(),
+                        format!("query for {} failed '{}'", r.key, e),
+                    ))
+                }
+            }
+        }
+        Ok(rs)
+    }
+}
+
+/// Runs the same query for each key, with at most "concurrency"
+/// queries in flight.
+#[derive(Debug, Clone, Copy)]
+pub struct Fanout {
+    /// Maximum number of in-flight queries, unbounded if zero.
+    pub concurrency: usize,
+}
+
+impl Default for Fanout {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Fanout {
+    pub fn default() -> Self {
+        Self::new(DEFAULT_CONCURRENCY)
+    }
+
+    pub fn new(concurrency: usize) -> Self {
+        Self { concurrency }
+    }
+
+    /// Runs the query for each key, and joins the results in the order
+    /// of the keys. A failed query does not cancel the others.
+    pub async fn run<K, T, I, F, Fut>(&self, keys: I, f: F) -> Joined<K, T>
+    where
+        K: Clone,
+        I: IntoIterator<Item = K>,
+        F: Fn(K) -> Fut,
+        Fut: Future<Output = io::Result<T>>,
+    {
+        let keys: Vec<K> = keys.into_iter().collect();
+        let limit = if self.concurrency == 0 {
+            keys.len().max(1)
+        } else {
+            self.concurrency
+        };
+        let responses = stream::iter(keys)
+            .map(|key| {
+                let fut = f(key.clone());
+                async move {
+                    let result = fut.await;
+                    Response { key, result }
+                }
+            })
+            .buffered(limit)
+            .collect()
+            .await;
+        Joined { responses }
+    }
+
+    /// Fetches "avm.getTxStatus" of the transaction from each endpoint.
+    pub async fn x_tx_status(
+        &self,
+        http_rpcs: &[String],
+        tx_id: &str,
+    ) -> Joined<String, avm::GetTxStatusResult> {
+        self.run(http_rpcs.iter().cloned(), |http_rpc| async move {
+            client_x::get_tx_status(&http_rpc, tx_id)
+                .await?
+                .into_result()
+        })
+        .await
+    }
+
+    /// Fetches "platform.getTxStatus" of the transaction from each endpoint.
+    pub async fn p_tx_status(
+        &self,
+        http_rpcs: &[String],
+        tx_id: &str,
+    ) -> Joined<String, platformvm::GetTxStatusResult> {
+        self.run(http_rpcs.iter().cloned(), |http_rpc| async move {
+            client_p::get_tx_status(&http_rpc, tx_id)
+                .await?
+                .into_result()
+        })
+        .await
+    }
+
+    /// Fetches "avm.getUTXOs" for each address (e.g., "X-avax1...").
+    pub async fn x_utxos(
+        &self,
+        http_rpc: &str,
+        xaddrs: &[String],
+    ) -> Joined<String, avm::GetUtxosResult> {
+        self.run(xaddrs.iter().cloned(), |xaddr| async move {
+            client_x::get_utxos(http_rpc, &xaddr).await?.into_result()
+        })
+        .await
+    }
+
+    /// Fetches "platform.getUTXOs" for each address (e.g., "P-avax1...").
+    pub async fn p_utxos(
+        &self,
+        http_rpc: &str,
+        paddrs: &[String],
+    ) -> Joined<String, platformvm::GetUtxosResult> {
+        self.run(paddrs.iter().cloned(), |paddr| async move {
+            client_p::get_utxos(http_rpc, &paddr).await?.into_result()
+        })
+        .await
+    }
+}
+
+/// AVAX balances of the same key on the X/P/C-chain.
+#[derive(Debug)]
+pub struct ChainBalances {
+    pub x: io::Result<u64>,
+    pub p: io::Result<u64>,
+    /// In wei.
+    pub c: io::Result<primitive_types::U256>,
+}
+
+/// Fetches the balances of the addresses on the X/P/C-chain concurrently.
+/// "xaddr" and "paddr" are bech32 (e.g., "X-avax1..."), and "eth_addr"
+/// is the hex address on the C-chain.
+pub async fn balances(http_rpc: &str, xaddr: &str, paddr: &str, eth_addr: &str) -> ChainBalances {
+    let (x, p, c) = futures::join!(
+        async {
+            let r = client_x::get_balance(http_rpc, xaddr)
+                .await?
+                .into_result()?;
+            Ok(r.balance)
+        },
+        async {
+            let r = client_p::get_balance(http_rpc, paddr)
+                .await?
+                .into_result()?;
+            Ok(r.balance)
+        },
+        async {
+            client_evm::get_balance(http_rpc, "C", eth_addr)
+                .await?
+                .into_result()
+        },
+    );
+    ChainBalances { x, p, c }
+}
+
+/// UTXOs of the same key on the X and P-chain.
+#[derive(Debug)]
+pub struct ChainUtxos {
+    pub x: io::Result<avm::GetUtxosResult>,
+    pub p: io::Result<platformvm::GetUtxosResult>,
+}
+
+/// Fetches the UTXOs of the addresses on the X and P-chain concurrently.
+pub async fn utxos(http_rpc: &str, xaddr: &str, paddr: &str) -> ChainUtxos {
+    let (x, p) = futures::join!(
+        async { client_x::get_utxos(http_rpc, xaddr).await?.into_result() },
+        async { client_p::get_utxos(http_rpc, paddr).await?.into_result() },
+    );
+    ChainUtxos { x, p }
+}
+
+impl ChainUtxos {
+    /// Returns both results, or the first error.
+    pub fn into_result(self) -> io::Result<(avm::GetUtxosResult, platformvm::GetUtxosResult)> {
+        let x = self
+            .x
+            .map_err(|e| Error::new(e.kind(), format!("X-chain UTXOs failed '{}'", e)))?;
+        let p = self
+            .p
+            .map_err(|e| Error::new(e.kind(), format!("P-chain UTXOs failed '{}'", e)))?;
+        Ok((x, p))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::multi::test_fanout --exact --show-output
+#[tokio::test]
+async fn test_fanout() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let in_flight = Arc::new(AtomicUsize::new(0));
+    let peak = Arc::new(AtomicUsize::new(0));
+    let joined = Fanout::new(2)
+        .run(0..6_u64, |i| {
+            let in_flight = in_flight.clone();
+            let peak = peak.clone();
+            async move {
+                let n = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(n, Ordering::SeqCst);
+                // finish out of order
+                tokio::time::sleep(std::time::Duration::from_millis(10 * (6 - i))).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                if i == 3 {
+                    return Err(Error::new(io::ErrorKind::Other, "down"));
+                }
+                Ok(i * 10)
+            }
+        })
+        .await;
+    assert!(peak.load(Ordering::SeqCst) <= 2);
+
+    // joined in the order of the keys
+    let keys: Vec<u64> = joined.responses.iter().map(|r| r.key).collect();
+    assert_eq!(keys, vec![0, 1, 2, 3, 4, 5]);
+    assert!(!joined.is_ok());
+    assert_eq!(joined.oks().len(), 5);
+    assert_eq!(joined.errors().len(), 1);
+    assert_eq!(*joined.errors()[0].0, 3);
+    assert!(joined.into_result().is_err());
+
+    let joined = Fanout::new(0)
+        .run(vec!["a", "b"], |k| async move { Ok(k.len()) })
+        .await;
+    assert_eq!(joined.into_result().unwrap(), vec![("a", 1), ("b", 1)]);
+}
@@ -1,51 +1,44 @@
-use std::{
-    collections::HashMap,
-    io::{self, Error, ErrorKind},
-};
+use std::{collections::HashMap, io};
 
-use crate::jsonrpc::{self, platformvm};
+use crate::{
+    client::transport,
+    ids,
+    jsonrpc::{self, platformvm},
+};
 
 /// e.g., "platform.issueTx" on "http://[ADDR]:9650" and "/ext/P" path.
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators
 pub async fn issue_tx(http_rpc: &str, tx: &str) -> io::Result<platformvm::IssueTxResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("issuing a transaction via {:?}", joined.as_str());
 
-    let mut data = platformvm::IssueTxRequest::default();
-    data.method = String::from("platform.issueTx");
-
-    let params = platformvm::IssueTxParams {
-        tx: prefix_manager::prepend_0x(tx),
-        encoding: String::from("hex"), // don't use "cb58"
-    };
-    data.params = Some(params);
+    let mut data = jsonrpc::issue::new_platformvm_issue_tx(tx);
+    let call = jsonrpc::Call::new(&data.method);
+    data.id = call.id;
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed platform.issueTx '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getTx" on "http://[ADDR]:9650" and "/ext/P" path.
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettx
 pub async fn get_tx(http_rpc: &str, tx_id: &str) -> io::Result<platformvm::GetTxResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("getting tx via {}", joined.as_str());
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getTx");
+    let call = jsonrpc::Call::new("platform.getTx");
 
     let mut params = HashMap::new();
     params.insert(String::from("txID"), String::from(tx_id));
     params.insert(String::from("encoding"), String::from("json")); // TODO: use "hex"?
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("failed to decode '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getTxStatus" on "http://[ADDR]:9650" and "/ext/P" path.
@@ -54,48 +47,192 @@ pub async fn get_tx_status(
     http_rpc: &str,
     tx_id: &str,
 ) -> io::Result<platformvm::GetTxStatusResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("getting tx status via {}", joined.as_str());
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getTxStatus");
+    let call = jsonrpc::Call::new("platform.getTxStatus");
 
     let mut params = HashMap::new();
     params.insert(String::from("txID"), String::from(tx_id));
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed platform.getTxStatus '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getHeight" on "http://[ADDR]:9650" and "/ext/P" path.
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetheight
 pub async fn get_height(http_rpc: &str) -> io::Result<platformvm::GetHeightResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("getting height for {:?}", joined);
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getHeight");
+    let call = jsonrpc::Call::new("platform.getHeight");
 
     let params = HashMap::new();
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed platform.getHeight '{}'", e),
-        )
-    })
+    call.decode(&rb)
+}
+
+/// e.g., "platform.getTimestamp" on "http://[ADDR]:9650" and "/ext/P" path.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettimestamp
+pub async fn get_timestamp(http_rpc: &str) -> io::Result<platformvm::GetTimestampResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!("getting timestamp for {:?}", joined);
+
+    let call = jsonrpc::Call::new("platform.getTimestamp");
+
+    let params = HashMap::new();
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "platform.getTotalStake" on "http://[ADDR]:9650" and "/ext/P" path.
+/// Returns the total stake of the primary network if "subnet_id" is None.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake
+pub async fn get_total_stake(
+    http_rpc: &str,
+    subnet_id: Option<&str>,
+) -> io::Result<platformvm::GetTotalStakeResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!(
+        "getting total stake of subnet {:?} via {}",
+        subnet_id,
+        joined.as_str()
+    );
+
+    let call = jsonrpc::Call::new("platform.getTotalStake");
+
+    let mut params = HashMap::new();
+    // the primary network subnet Id is the empty Id
+    let subnet_id = subnet_id.map_or_else(|| ids::Id::empty().to_string(), String::from);
+    params.insert(String::from("subnetID"), subnet_id);
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "platform.getCurrentSupply" on "http://[ADDR]:9650" and "/ext/P" path.
+/// Returns the supply of the primary network if "subnet_id" is None.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply
+pub async fn get_current_supply(
+    http_rpc: &str,
+    subnet_id: Option<&str>,
+) -> io::Result<platformvm::GetCurrentSupplyResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!(
+        "getting current supply of subnet {:?} via {}",
+        subnet_id,
+        joined.as_str()
+    );
+
+    let call = jsonrpc::Call::new("platform.getCurrentSupply");
+
+    let mut params = HashMap::new();
+    if let Some(subnet_id) = subnet_id {
+        params.insert(String::from("subnetID"), String::from(subnet_id));
+    }
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "platform.getMaxStakeAmount" on "http://[ADDR]:9650" and "/ext/P" path.
+/// Returns the maximum amount staked on the validator between the unix
+/// timestamps "start_time" and "end_time" (in seconds).
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetmaxstakeamount
+pub async fn get_max_stake_amount(
+    http_rpc: &str,
+    subnet_id: Option<&str>,
+    node_id: &str,
+    start_time: u64,
+    end_time: u64,
+) -> io::Result<platformvm::GetMaxStakeAmountResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!(
+        "getting max stake amount of {} in subnet {:?} via {}",
+        node_id,
+        subnet_id,
+        joined.as_str()
+    );
+
+    let call = jsonrpc::Call::new("platform.getMaxStakeAmount");
+
+    let mut params = HashMap::new();
+    // the primary network subnet Id is the empty Id
+    let subnet_id = subnet_id.map_or_else(|| ids::Id::empty().to_string(), String::from);
+    params.insert(String::from("subnetID"), subnet_id);
+    params.insert(String::from("nodeID"), String::from(node_id));
+    params.insert(String::from("startTime"), start_time.to_string());
+    params.insert(String::from("endTime"), end_time.to_string());
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "platform.getBlock" on "http://[ADDR]:9650" and "/ext/P" path.
+/// Set "encoding" to "hex" to parse the block with "GetBlockResult::parse".
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblock
+pub async fn get_block(
+    http_rpc: &str,
+    block_id: &str,
+    encoding: &str,
+) -> io::Result<platformvm::GetBlockResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!("getting block {} via {}", block_id, joined.as_str());
+
+    let call = jsonrpc::Call::new("platform.getBlock");
+
+    let mut params = HashMap::new();
+    params.insert(String::from("blockID"), String::from(block_id));
+    params.insert(String::from("encoding"), String::from(encoding));
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "platform.getBlockByHeight" on "http://[ADDR]:9650" and "/ext/P" path.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockbyheight
+pub async fn get_block_by_height(
+    http_rpc: &str,
+    height: u64,
+    encoding: &str,
+) -> io::Result<platformvm::GetBlockResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!("getting block at height {} via {}", height, joined.as_str());
+
+    let call = jsonrpc::Call::new("platform.getBlockByHeight");
+
+    let mut params = HashMap::new();
+    params.insert(String::from("height"), height.to_string());
+    params.insert(String::from("encoding"), String::from(encoding));
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getBalance" on "http://[ADDR]:9650" and "/ext/P" path.
@@ -104,35 +241,58 @@ pub async fn get_balance(
     http_rpc: &str,
     paddr: &str,
 ) -> io::Result<platformvm::GetBalanceResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("getting balances for {} via {:?}", paddr, joined);
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getBalance");
+    let call = jsonrpc::Call::new("platform.getBalance");
 
     let mut params = HashMap::new();
     params.insert(String::from("address"), paddr.to_string());
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
+}
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed platform.getBalance '{}'", e),
-        )
+/// e.g., "platform.getStake" on "http://[ADDR]:9650" and "/ext/P" path.
+/// Returns the amount staked by the addresses in the current and pending
+/// validator sets, which "platform.getBalance" does not include.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake
+pub async fn get_stake(
+    http_rpc: &str,
+    paddrs: &[String],
+) -> io::Result<platformvm::GetStakeResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
+    log::debug!("getting stake for {:?} via {:?}", paddrs, joined);
+
+    let call = jsonrpc::Call::new("platform.getStake");
+    let d = serde_json::json!({
+        "jsonrpc": jsonrpc::DEFAULT_VERSION,
+        "id": call.id,
+        "method": call.method,
+        "params": {
+            "addresses": paddrs,
+            "encoding": jsonrpc::issue::ENCODING_HEX,
+        },
     })
+    .to_string();
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
+
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getUTXOs" on "http://[ADDR]:9650" and "/ext/P" path.
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetutxos
 pub async fn get_utxos(http_rpc: &str, paddr: &str) -> io::Result<platformvm::GetUtxosResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("getting UTXOs for {} via {:?}", paddr, joined);
 
+    let call = jsonrpc::Call::new("platform.getUTXOs");
     let mut data = platformvm::GetUtxosRequest::default();
-    data.method = String::from("platform.getUTXOs");
+    data.id = call.id;
+    data.method = call.method.clone();
 
     let params = platformvm::GetUtxosParams {
         addresses: vec![paddr.to_string()],
@@ -142,14 +302,9 @@ pub async fn get_utxos(http_rpc: &str, paddr: &str) -> io::Result<platformvm::Ge
     data.params = Some(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed platform.getUTXOs '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getCurrentValidators" on "http://[ADDR]:9650" and "/ext/P" path.
@@ -158,24 +313,18 @@ pub async fn get_utxos(http_rpc: &str, paddr: &str) -> io::Result<platformvm::Ge
 pub async fn get_primary_network_validators(
     http_rpc: &str,
 ) -> io::Result<platformvm::GetCurrentValidatorsResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!("getting primary network validators via {}", joined.as_str());
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getCurrentValidators");
+    let call = jsonrpc::Call::new("platform.getCurrentValidators");
 
     let params = HashMap::new();
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed platform.getCurrentValidators '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
 
 /// e.g., "platform.getCurrentValidators" on "http://[ADDR]:9650" and "/ext/P" path.
@@ -185,27 +334,21 @@ pub async fn get_subnet_validators(
     http_rpc: &str,
     subnet_id: &str,
 ) -> io::Result<platformvm::GetCurrentValidatorsResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/P")?;
+    let joined = transport::join_uri(http_rpc, "/ext/P")?;
     log::debug!(
         "getting subnet {} validators via {}",
         subnet_id,
         joined.as_str()
     );
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("platform.getCurrentValidators");
+    let call = jsonrpc::Call::new("platform.getCurrentValidators");
 
     let mut params = HashMap::new();
     params.insert(String::from("subnetID"), subnet_id.to_string());
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/P", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/P", &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed platform.getCurrentValidators '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
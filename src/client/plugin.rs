@@ -0,0 +1,248 @@
+//! VM plugin checks for subnet deployments.
+//!
+//! avalanchego loads a VM from the plugin directory by the file name that
+//! equals the VM Id. When the plugin is missing or misnamed on a validator,
+//! the node never loads the chain and the chain appears stuck in pending.
+
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    client::{admin as client_admin, info as client_info},
+    ids,
+};
+
+/// Returns the plugin file name that avalanchego expects for the VM Id.
+pub fn file_name(vm_id: &ids::Id) -> String {
+    vm_id.to_string()
+}
+
+/// Returns the expected plugin path in the plugin directory.
+pub fn path(plugin_dir: impl AsRef<Path>, vm_id: &ids::Id) -> PathBuf {
+    plugin_dir.as_ref().join(file_name(vm_id))
+}
+
+/// Checks that the plugin binary for the VM Id exists in the local
+/// plugin directory and is executable, and returns its path.
+pub fn check_local(plugin_dir: impl AsRef<Path>, vm_id: &ids::Id) -> io::Result<PathBuf> {
+    let p = path(plugin_dir, vm_id);
+    let metadata = std::fs::metadata(&p).map_err(|e| {
+        Error::new(
+            e.kind(),
+            format!("plugin {} for vm {} not found '{}'", p.display(), vm_id, e),
+        )
+    })?;
+    if !metadata.is_file() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("plugin {} for vm {} is not a file", p.display(), vm_id),
+        ));
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        if metadata.permissions().mode() & 0o111 == 0 {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                format!("plugin {} for vm {} is not executable", p.display(), vm_id),
+            ));
+        }
+    }
+    Ok(p)
+}
+
+/// Result of checking a VM plugin on a node.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Report {
+    pub http_rpc: String,
+    pub vm_id: ids::Id,
+    /// Aliases of the VM if the node has loaded it, otherwise None.
+    pub aliases: Option<Vec<String>>,
+    /// Error from the node when it failed to load the plugin.
+    pub load_error: Option<String>,
+}
+
+impl Report {
+    pub fn is_loaded(&self) -> bool {
+        self.aliases.is_some()
+    }
+}
+
+/// ref. https://doc.rust-lang.org/std/string/trait.ToString.html
+/// ref. https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// Use "Self.to_string()" to directly invoke this
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(aliases) = &self.aliases {
+            return write!(
+                f,
+                "{} loaded vm {} (aliases {:?})",
+                self.http_rpc, self.vm_id, aliases
+            );
+        }
+        if let Some(e) = &self.load_error {
+            return write!(
+                f,
+                "{} failed to load plugin '{}' for vm {} '{}'",
+                self.http_rpc,
+                file_name(&self.vm_id),
+                self.vm_id,
+                e
+            );
+        }
+        write!(
+            f,
+            "{} has not loaded vm {} (expected plugin file '{}' in its plugin directory)",
+            self.http_rpc,
+            self.vm_id,
+            file_name(&self.vm_id)
+        )
+    }
+}
+
+/// Checks whether the node has loaded the VM plugin. With "reload", asks the
+/// node to load the newly installed plugins first, which requires the admin
+/// API; a disabled admin API is logged and the check continues.
+pub async fn verify(http_rpc: &str, vm_id: &ids::Id, reload: bool) -> io::Result<Report> {
+    let vm = vm_id.to_string();
+
+    let mut load_error = None;
+    if reload {
+        match client_admin::load_vms(http_rpc)
+            .await
+            .and_then(|resp| resp.into_result())
+        {
+            Ok(loaded) => {
+                if let Some(failed) = loaded.failed_vms {
+                    load_error = failed.get(&vm).cloned();
+                }
+            }
+            Err(e) => log::warn!("failed to reload VMs on {} '{}'", http_rpc, e),
+        }
+    }
+
+    let vms = client_info::get_vms(http_rpc)
+        .await?
+        .into_result()?
+        .vms
+        .unwrap_or_default();
+    let report = Report {
+        http_rpc: http_rpc.to_string(),
+        vm_id: *vm_id,
+        aliases: vms.get(&vm).cloned(),
+        load_error,
+    };
+    if report.is_loaded() {
+        log::info!("{}", report);
+    } else {
+        log::warn!("{}", report);
+    }
+    Ok(report)
+}
+
+/// Checks the VM plugin on every node, and returns the reports of the nodes
+/// that have not loaded it.
+pub async fn mismatches(http_rpcs: &[String], vm_id: &ids::Id) -> io::Result<Vec<Report>> {
+    let mut reports = Vec::new();
+    for http_rpc in http_rpcs.iter() {
+        let report = verify(http_rpc, vm_id, false).await?;
+        if !report.is_loaded() {
+            reports.push(report);
+        }
+    }
+    Ok(reports)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::plugin::test_verify --exact --show-output
+#[tokio::test]
+async fn test_verify() {
+    use crate::client::transport::{self, Transport};
+    use std::sync::Arc;
+
+    /// "node1" loaded the VM, "node2" failed to load it, "node3" has no admin API.
+    struct Fake;
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, url: &str, path: &str, body: &str) -> io::Result<Vec<u8>> {
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            if body.contains("admin.loadVMs") {
+                assert_eq!(path, "ext/admin");
+                if url.contains("node3") {
+                    return Err(Error::new(ErrorKind::NotFound, "404"));
+                }
+                let failed = if url.contains("node2") {
+                    r#","failedVMs":{"tGas3T58KzdjcJ2iKSyiYsWiqYctRXaPTqBCA11BqEkNg8kPc":"permission denied"}"#
+                } else {
+                    ""
+                };
+                return Ok(format!(
+                    r#"{{"jsonrpc":"2.0","id":{},"result":{{"newVMs":{{}}{}}}}}"#,
+                    id, failed
+                )
+                .into_bytes());
+            }
+            assert!(body.contains("info.getVMs"));
+            let vms = if url.contains("node1") {
+                r#""tGas3T58KzdjcJ2iKSyiYsWiqYctRXaPTqBCA11BqEkNg8kPc":["timestampvm"]"#
+            } else {
+                ""
+            };
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":{{"vms":{{"jvYyfQTxGMJLuGWa55kdP2p2zSUYsQ5Raupu4TW34ZAUBAbtq":["avm"]{}{}}}}}}}"#,
+                id,
+                if vms.is_empty() { "" } else { "," },
+                vms
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let vm_id = ids::Id::from_slice("timestampvm".as_bytes());
+    assert_eq!(
+        path("/plugins", &vm_id),
+        PathBuf::from("/plugins/tGas3T58KzdjcJ2iKSyiYsWiqYctRXaPTqBCA11BqEkNg8kPc")
+    );
+
+    let fake = Arc::new(Fake);
+    let r = transport::scope(fake.clone(), verify("http://node1", &vm_id, true))
+        .await
+        .unwrap();
+    assert!(r.is_loaded());
+    assert_eq!(r.aliases, Some(vec![String::from("timestampvm")]));
+
+    let r = transport::scope(fake.clone(), verify("http://node2", &vm_id, true))
+        .await
+        .unwrap();
+    assert!(!r.is_loaded());
+    assert_eq!(r.load_error.as_deref(), Some("permission denied"));
+    assert!(r.to_string().contains("failed to load plugin"));
+
+    let r = transport::scope(fake.clone(), verify("http://node3", &vm_id, true))
+        .await
+        .unwrap();
+    assert!(!r.is_loaded());
+    assert!(r.load_error.is_none());
+
+    let reports = transport::scope(
+        fake,
+        mismatches(
+            &[
+                String::from("http://node1"),
+                String::from("http://node2"),
+                String::from("http://node3"),
+            ],
+            &vm_id,
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(reports.len(), 2);
+    assert_eq!(reports[0].http_rpc, "http://node2");
+}
@@ -0,0 +1,309 @@
+//! Consistency-checked reads across multiple endpoints.
+//!
+//! The same read is sent to "k" endpoints concurrently and the responses
+//! are compared, so that a stale or misbehaving endpoint (e.g., a third-party
+//! RPC provider lagging behind) is flagged instead of silently trusted.
+//!
+//! ```
+//! # async fn run() -> std::io::Result<()> {
+//! use avalanche_types::client::{p as client_p, quorum::Quorum};
+//!
+//! let quorum = Quorum::new(vec![
+//!     "http://node-1:9650".to_string(),
+//!     "http://node-2:9650".to_string(),
+//!     "https://api.avax.network".to_string(),
+//! ])
+//! .threshold(2);
+//! let reading = quorum
+//!     .read(|http_rpc| async move {
+//!         let resp = client_p::get_tx_status(&http_rpc, "tx-id").await?;
+//!         Ok(resp.result.unwrap_or_default().status)
+//!     })
+//!     .await?;
+//! if reading.is_divergent() {
+//!     println!("divergent endpoints {:?}", reading.divergent_endpoints());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{
+    fmt::Debug,
+    future::Future,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::client::evm as client_evm;
+
+/// Response of a single endpoint.
+#[derive(Debug)]
+pub struct Response<T> {
+    pub http_rpc: String,
+    pub result: io::Result<T>,
+}
+
+/// Outcome of a quorum read.
+#[derive(Debug)]
+pub struct Reading<T> {
+    /// Value returned by the most endpoints.
+    pub value: T,
+    /// Number of endpoints that returned "value".
+    pub agreed: usize,
+    /// Responses in the order of the queried endpoints.
+    pub responses: Vec<Response<T>>,
+}
+
+impl<T> Reading<T>
+where
+    T: PartialEq,
+{
+    /// Returns true if any queried endpoint failed or returned
+    /// a different value than the agreed one.
+    pub fn is_divergent(&self) -> bool {
+        self.agreed != self.responses.len()
+    }
+
+    /// Returns the endpoints that failed or disagreed with the agreed value.
+    pub fn divergent_endpoints(&self) -> Vec<&str> {
+        self.responses
+            .iter()
+            .filter(|r| !matches!(&r.result, Ok(v) if *v == self.value))
+            .map(|r| r.http_rpc.as_str())
+            .collect()
+    }
+}
+
+/// Sends each read to multiple endpoints and compares the responses.
+#[derive(Debug, Clone)]
+pub struct Quorum {
+    pub http_rpcs: Vec<String>,
+    /// Number of endpoints to query, all endpoints if zero.
+    pub k: usize,
+    /// Minimum number of matching responses, a strict majority of
+    /// the queried endpoints if zero.
+    pub threshold: usize,
+}
+
+impl Quorum {
+    pub fn new(http_rpcs: Vec<String>) -> Self {
+        Self {
+            http_rpcs,
+            k: 0,
+            threshold: 0,
+        }
+    }
+
+    /// Sets the number of endpoints to query.
+    #[must_use]
+    pub fn k(mut self, k: usize) -> Self {
+        self.k = k;
+        self
+    }
+
+    /// Sets the minimum number of matching responses.
+    #[must_use]
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Returns the endpoints to query.
+    pub fn endpoints(&self) -> &[String] {
+        if self.k == 0 || self.k >= self.http_rpcs.len() {
+            &self.http_rpcs
+        } else {
+            &self.http_rpcs[..self.k]
+        }
+    }
+
+    fn required(&self, queried: usize) -> usize {
+        if self.threshold == 0 {
+            queried / 2 + 1
+        } else {
+            self.threshold
+        }
+    }
+
+    /// Runs the read against the endpoints concurrently, and returns
+    /// the value that the most endpoints agree on. Fails if fewer than
+    /// the threshold number of endpoints agree.
+    pub async fn read<T, F, Fut>(&self, f: F) -> io::Result<Reading<T>>
+    where
+        T: PartialEq + Clone + Debug,
+        F: Fn(String) -> Fut,
+        Fut: Future<Output = io::Result<T>>,
+    {
+        let endpoints = self.endpoints();
+        if endpoints.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no endpoint to read"));
+        }
+        let required = self.required(endpoints.len());
+        if required > endpoints.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "threshold {} exceeds the {} endpoints",
+                    required,
+                    endpoints.len()
+                ),
+            ));
+        }
+
+        let results =
+            futures::future::join_all(endpoints.iter().map(|http_rpc| f(http_rpc.clone()))).await;
+        let responses: Vec<Response<T>> = endpoints
+            .iter()
+            .cloned()
+            .zip(results)
+            .map(|(http_rpc, result)| Response { http_rpc, result })
+            .collect();
+
+        // count the matching values, in the order first seen
+        let mut tally: Vec<(&T, usize)> = Vec::new();
+        for v in responses.iter().filter_map(|r| r.result.as_ref().ok()) {
+            match tally.iter_mut().find(|(seen, _)| *seen == v) {
+                Some((_, n)) => *n += 1,
+                None => tally.push((v, 1)),
+            }
+        }
+        // ties go to the value first seen
+        let mut best: Option<(&T, usize)> = None;
+        for (v, n) in tally {
+            if best.map_or(true, |(_, m)| n > m) {
+                best = Some((v, n));
+            }
+        }
+        let (value, agreed) = match best {
+            Some((v, n)) => (v.clone(), n),
+            None => {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!(
+                        "all {} endpoints failed (first error '{}')",
+                        responses.len(),
+                        responses[0].result.as_ref().err().unwrap()
+                    ),
+                ))
+            }
+        };
+
+        let reading = Reading {
+            value,
+            agreed,
+            responses,
+        };
+        if reading.is_divergent() {
+            log::warn!(
+                "{} of {} endpoints agree on {:?}, divergent {:?}",
+                agreed,
+                reading.responses.len(),
+                reading.value,
+                reading.divergent_endpoints()
+            );
+        }
+        if agreed < required {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "quorum not reached ({} of {} endpoints agree, {} required)",
+                    agreed,
+                    reading.responses.len(),
+                    required
+                ),
+            ));
+        }
+        Ok(reading)
+    }
+
+    /// Reads "eth_blockNumber" from the endpoints.
+    /// Since endpoints of varying freshness may legitimately differ
+    /// by a few blocks, also returns the highest block number seen.
+    pub async fn block_number(&self, chain_id_alias: &str) -> io::Result<(Reading<u64>, u64)> {
+        let reading = self
+            .read(|http_rpc| async move {
+                let resp = client_evm::block_number(&http_rpc, chain_id_alias).await?;
+                Ok(resp.into_result()?.as_u64())
+            })
+            .await?;
+        let highest = reading
+            .responses
+            .iter()
+            .filter_map(|r| r.result.as_ref().ok())
+            .copied()
+            .max()
+            .unwrap_or(reading.value);
+        Ok((reading, highest))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::quorum::test_quorum --exact --show-output
+#[tokio::test]
+async fn test_quorum() {
+    use crate::client::transport::{self, Transport};
+    use std::sync::Arc;
+
+    /// Returns the block number encoded in the endpoint, fails on "down".
+    struct Fake;
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, http_rpc: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            if http_rpc.contains("down") {
+                return Err(Error::new(ErrorKind::ConnectionRefused, "down"));
+            }
+            let height: u64 = http_rpc.rsplit('/').next().unwrap().parse().unwrap();
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":"0x{:x}"}}"#,
+                id, height
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let endpoints = |heights: &[&str]| -> Vec<String> {
+        heights.iter().map(|h| format!("http://{}", h)).collect()
+    };
+
+    let quorum = Quorum::new(endpoints(&["a/10", "b/10", "c/12"]));
+    let (reading, highest) = transport::scope(Arc::new(Fake), quorum.block_number("C"))
+        .await
+        .unwrap();
+    assert_eq!(reading.value, 10);
+    assert_eq!(reading.agreed, 2);
+    assert_eq!(highest, 12);
+    assert!(reading.is_divergent());
+    assert_eq!(reading.divergent_endpoints(), vec!["http://c/12"]);
+
+    // unanimous only
+    let quorum = quorum.threshold(3);
+    assert!(transport::scope(Arc::new(Fake), quorum.block_number("C"))
+        .await
+        .is_err());
+
+    // only the first endpoints are queried
+    let quorum = Quorum::new(endpoints(&["a/10", "down/0", "c/12"])).k(1);
+    let (reading, _) = transport::scope(Arc::new(Fake), quorum.block_number("C"))
+        .await
+        .unwrap();
+    assert!(!reading.is_divergent());
+
+    // failed endpoints count against the quorum
+    let quorum = Quorum::new(endpoints(&["a/10", "down/0", "down/1"]));
+    assert!(transport::scope(Arc::new(Fake), quorum.block_number("C"))
+        .await
+        .is_err());
+    let reading = quorum
+        .threshold(1)
+        .read(|http_rpc| async move {
+            if http_rpc.contains("down") {
+                return Err(Error::new(ErrorKind::Other, "down"));
+            }
+            Ok(http_rpc)
+        })
+        .await
+        .unwrap();
+    assert_eq!(reading.divergent_endpoints().len(), 2);
+}
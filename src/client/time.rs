@@ -0,0 +1,121 @@
+//! Clocks and timers of the client calls and the wallet polls.
+//!
+//! On wasm32-unknown-unknown, "std::time::Instant", "SystemTime::now" and
+//! the tokio timers panic (there is no OS clock nor a timer driver), so the
+//! client uses these instead: the tokio ones natively, and on wasm32 the
+//! ones backed by "Date.now()" and "setTimeout" of the JavaScript host.
+
+pub use std::time::Duration;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use tokio::time::{sleep, sleep_until, Instant};
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub use wasm::{sleep, sleep_until, Instant};
+
+/// Returns the duration since the Unix epoch.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn unix_now() -> Duration {
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .expect("unexpected None duration_since")
+}
+
+/// Returns the duration since the Unix epoch.
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+pub fn unix_now() -> Duration {
+    wasm::unix_now()
+}
+
+/// Returns the current Unix timestamp in seconds.
+pub fn unix_now_secs() -> u64 {
+    unix_now().as_secs()
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+mod wasm {
+    use std::ops::{Add, AddAssign, Sub};
+
+    use super::Duration;
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    pub(super) fn unix_now() -> Duration {
+        Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+    }
+
+    /// Point in time from the wall clock of the host in milliseconds,
+    /// which is not guaranteed to be monotonic, so the durations saturate
+    /// at zero instead of panicking.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+    pub struct Instant(Duration);
+
+    impl Instant {
+        pub fn now() -> Self {
+            Self(unix_now())
+        }
+
+        pub fn duration_since(&self, earlier: Instant) -> Duration {
+            self.saturating_duration_since(earlier)
+        }
+
+        pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+            self.0.saturating_sub(earlier.0)
+        }
+
+        pub fn elapsed(&self) -> Duration {
+            Self::now().saturating_duration_since(*self)
+        }
+
+        pub fn checked_add(&self, d: Duration) -> Option<Instant> {
+            self.0.checked_add(d).map(Self)
+        }
+    }
+
+    impl Add<Duration> for Instant {
+        type Output = Instant;
+
+        fn add(self, d: Duration) -> Instant {
+            Self(self.0 + d)
+        }
+    }
+
+    impl AddAssign<Duration> for Instant {
+        fn add_assign(&mut self, d: Duration) {
+            self.0 += d;
+        }
+    }
+
+    impl Sub<Instant> for Instant {
+        type Output = Duration;
+
+        fn sub(self, earlier: Instant) -> Duration {
+            self.saturating_duration_since(earlier)
+        }
+    }
+
+    /// Waits for the duration with "setTimeout" of the window,
+    /// or of the worker global scope (e.g., in extensions).
+    pub async fn sleep(d: Duration) {
+        let ms = i32::try_from(d.as_millis()).unwrap_or(i32::MAX);
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            let global = js_sys::global();
+            let scheduled = if let Some(w) = global.dyn_ref::<web_sys::Window>() {
+                w.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            } else if let Some(w) = global.dyn_ref::<web_sys::WorkerGlobalScope>() {
+                w.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, ms)
+            } else {
+                Err(JsValue::from_str("no setTimeout in the global scope"))
+            };
+            if let Err(e) = scheduled {
+                log::warn!("failed to schedule sleep '{:?}', not waiting", e);
+                let _ = resolve.call0(&JsValue::UNDEFINED);
+            }
+        });
+        let _ = JsFuture::from(promise).await;
+    }
+
+    pub async fn sleep_until(deadline: Instant) {
+        sleep(deadline.saturating_duration_since(Instant::now())).await
+    }
+}
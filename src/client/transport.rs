@@ -0,0 +1,204 @@
+//! HTTP transport behind all client calls.
+//!
+//! The client functions send requests through "http_manager" by default,
+//! or on wasm32 with the "wasm" feature, through the "fetch" API of the
+//! browser (or the worker global scope, e.g., in extensions).
+//! A different transport (e.g., one that injects faults, or records
+//! and replays responses) can be installed for the calls made within
+//! a future with "scope", without changing the call sites.
+//! The calls honor the cancellation context of the current
+//! "cancel::scope", if any.
+//! Set "AVALANCHE_RPC_DEBUG" to capture the calls on the default
+//! transport (see "debug").
+
+use std::{future::Future, io, sync::Arc};
+
+use crate::client::{cancel, debug};
+use async_trait::async_trait;
+
+/// The futures are not "Send" on wasm32, since the JavaScript values
+/// that "fetch" returns are bound to the thread.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Transport: Send + Sync {
+    async fn post_non_tls(&self, http_rpc: &str, url_path: &str, body: &str)
+        -> io::Result<Vec<u8>>;
+    async fn get_non_tls(&self, http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>>;
+}
+
+/// Sends the requests over the network with "http_manager".
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Http;
+
+#[cfg(not(target_arch = "wasm32"))]
+#[async_trait]
+impl Transport for Http {
+    async fn post_non_tls(
+        &self,
+        http_rpc: &str,
+        url_path: &str,
+        body: &str,
+    ) -> io::Result<Vec<u8>> {
+        http_manager::post_non_tls(http_rpc, url_path, body).await
+    }
+
+    async fn get_non_tls(&self, http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+        http_manager::get_non_tls(http_rpc, url_path).await
+    }
+}
+
+/// Sends the requests with the "fetch" API of the JavaScript host.
+/// The node must allow the origin of the page via CORS
+/// (e.g., "--http-allowed-origins").
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fetch;
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+#[async_trait(?Send)]
+impl Transport for Fetch {
+    async fn post_non_tls(
+        &self,
+        http_rpc: &str,
+        url_path: &str,
+        body: &str,
+    ) -> io::Result<Vec<u8>> {
+        fetch::send("POST", &join_uri(http_rpc, url_path)?, Some(body)).await
+    }
+
+    async fn get_non_tls(&self, http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+        fetch::send("GET", &join_uri(http_rpc, url_path)?, None).await
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+mod fetch {
+    use std::io::{self, Error, ErrorKind};
+
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+
+    fn js_err(msg: &str, v: JsValue) -> Error {
+        Error::new(ErrorKind::Other, format!("{} '{:?}'", msg, v))
+    }
+
+    pub(super) async fn send(method: &str, url: &str, body: Option<&str>) -> io::Result<Vec<u8>> {
+        let opts = web_sys::RequestInit::new();
+        opts.set_method(method);
+        opts.set_mode(web_sys::RequestMode::Cors);
+        if let Some(b) = body {
+            opts.set_body(&JsValue::from_str(b));
+        }
+
+        let req = web_sys::Request::new_with_str_and_init(url, &opts)
+            .map_err(|e| js_err("failed Request::new", e))?;
+        req.headers()
+            .set("content-type", "application/json")
+            .map_err(|e| js_err("failed Headers::set", e))?;
+
+        // pages have "window", while web workers and extension
+        // service workers only have the worker global scope
+        let global = js_sys::global();
+        let promise = if let Some(w) = global.dyn_ref::<web_sys::Window>() {
+            w.fetch_with_request(&req)
+        } else if let Some(w) = global.dyn_ref::<web_sys::WorkerGlobalScope>() {
+            w.fetch_with_request(&req)
+        } else {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                "no fetch API in the global scope",
+            ));
+        };
+
+        let resp: web_sys::Response = JsFuture::from(promise)
+            .await
+            .map_err(|e| js_err("failed fetch", e))?
+            .dyn_into()
+            .map_err(|e| js_err("unexpected fetch response", e))?;
+        if !resp.ok() {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("{} {} failed with status {}", method, url, resp.status()),
+            ));
+        }
+
+        let buf = JsFuture::from(
+            resp.array_buffer()
+                .map_err(|e| js_err("failed Response::array_buffer", e))?,
+        )
+        .await
+        .map_err(|e| js_err("failed reading response body", e))?;
+        Ok(js_sys::Uint8Array::new(&buf).to_vec())
+    }
+}
+
+/// Joins the endpoint and the URL path (e.g., "/ext/bc/P").
+#[cfg(not(target_arch = "wasm32"))]
+pub fn join_uri(http_rpc: &str, url_path: &str) -> io::Result<String> {
+    http_manager::join_uri(http_rpc, url_path).map(|u| u.to_string())
+}
+
+/// Joins the endpoint and the URL path (e.g., "/ext/bc/P").
+#[cfg(target_arch = "wasm32")]
+pub fn join_uri(http_rpc: &str, url_path: &str) -> io::Result<String> {
+    if !http_rpc.starts_with("http://") && !http_rpc.starts_with("https://") {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid endpoint '{}'", http_rpc),
+        ));
+    }
+    Ok(format!(
+        "{}/{}",
+        http_rpc.trim_end_matches('/'),
+        url_path.trim_start_matches('/')
+    ))
+}
+
+tokio::task_local! {
+    static TRANSPORT: Arc<dyn Transport>;
+}
+
+/// Runs the future with all client calls going through the transport.
+/// Tasks spawned within the future use the default transport
+/// unless they are scoped again (see "current").
+pub async fn scope<F>(transport: Arc<dyn Transport>, f: F) -> F::Output
+where
+    F: Future,
+{
+    TRANSPORT.scope(transport, f).await
+}
+
+/// Returns the transport of the current scope, if any.
+pub fn current() -> Option<Arc<dyn Transport>> {
+    TRANSPORT.try_with(|t| t.clone()).ok()
+}
+
+pub async fn post_non_tls(http_rpc: &str, url_path: &str, body: &str) -> io::Result<Vec<u8>> {
+    let t = current().unwrap_or_else(default_transport);
+    cancel::run(t.post_non_tls(http_rpc, url_path, body)).await
+}
+
+pub async fn get_non_tls(http_rpc: &str, url_path: &str) -> io::Result<Vec<u8>> {
+    let t = current().unwrap_or_else(default_transport);
+    cancel::run(t.get_non_tls(http_rpc, url_path)).await
+}
+
+/// Returns the transport that the client calls use outside of "scope":
+/// "Http", or "Fetch" on wasm32, captured into "debug::global()"
+/// if "AVALANCHE_RPC_DEBUG" is set.
+pub fn default_transport() -> Arc<dyn Transport> {
+    match debug::global() {
+        Some(capture) => capture,
+        None => base_transport(),
+    }
+}
+
+/// Returns "Http", or "Fetch" on wasm32.
+pub fn base_transport() -> Arc<dyn Transport> {
+    #[cfg(not(target_arch = "wasm32"))]
+    let t = Arc::new(Http);
+    #[cfg(all(target_arch = "wasm32", feature = "wasm"))]
+    let t = Arc::new(Fetch);
+    t
+}
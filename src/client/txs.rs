@@ -0,0 +1,338 @@
+//! Chain-agnostic interface over signed X, P, and C-chain transactions,
+//! so generic orchestration code can issue and track them uniformly.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    avm,
+    choices::status::Status,
+    client::{
+        cancel, evm as client_evm, p as client_p,
+        time::{Duration, Instant},
+        x as client_x,
+    },
+    hash, ids, jsonrpc, platformvm, txs,
+};
+use async_trait::async_trait;
+
+/// Represents a signed transaction that can be issued to its chain
+/// and polled for acceptance.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Transaction {
+    /// Returns the transaction Id.
+    /// For EVM chains, the 32-byte transaction hash is returned as an Id.
+    fn id(&self) -> ids::Id;
+
+    /// Returns the signed transaction bytes.
+    fn serialize(&self) -> io::Result<Vec<u8>>;
+
+    /// Issues the signed transaction via the HTTP endpoint
+    /// (e.g., "http://[ADDR]:9650") and returns the transaction Id.
+    async fn issue(&self, http_rpc: &str) -> io::Result<ids::Id>;
+
+    /// Polls the HTTP endpoint until the transaction is accepted,
+    /// or errors if it is rejected or not accepted within the timeout.
+    async fn await_accepted(
+        &self,
+        http_rpc: &str,
+        poll_interval: Duration,
+        poll_timeout: Duration,
+    ) -> io::Result<()>;
+}
+
+/// Returns the signed bytes from the metadata populated by "sign".
+fn signed_bytes(metadata: &Option<txs::Metadata>) -> io::Result<Vec<u8>> {
+    match metadata {
+        Some(m) => {
+            m.verify()?;
+            Ok(m.tx_bytes_with_signatures.clone())
+        }
+        None => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "transaction is not signed (empty metadata)",
+        )),
+    }
+}
+
+/// Polls the X-chain via the HTTP endpoint until the transaction is accepted,
+/// or errors if it is rejected or not accepted within the timeout.
+pub async fn await_x_accepted(
+    http_rpc: &str,
+    tx_id: &ids::Id,
+    poll_interval: Duration,
+    poll_timeout: Duration,
+) -> io::Result<()> {
+    let start = Instant::now();
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed.gt(&poll_timeout) {
+            break;
+        }
+
+        let resp = client_x::get_tx_status(http_rpc, &tx_id.to_string()).await?;
+        if let Some(r) = resp.result {
+            match r.status {
+                Status::Accepted => {
+                    log::info!("{} successfully accepted", tx_id);
+                    return Ok(());
+                }
+                Status::Rejected => {
+                    return Err(Error::new(ErrorKind::Other, format!("{} rejected", tx_id)));
+                }
+                status => {
+                    log::warn!("{} {} (elapsed {:?})", tx_id, status, elapsed);
+                }
+            }
+        }
+        cancel::sleep(poll_interval).await?;
+    }
+    Err(Error::new(
+        ErrorKind::TimedOut,
+        format!("failed to check acceptance of {} in time", tx_id),
+    ))
+}
+
+/// Polls the P-chain via the HTTP endpoint until the transaction is committed,
+/// or errors if it is aborted, dropped, or not committed within the timeout.
+pub async fn await_p_committed(
+    http_rpc: &str,
+    tx_id: &ids::Id,
+    poll_interval: Duration,
+    poll_timeout: Duration,
+) -> io::Result<()> {
+    let start = Instant::now();
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed.gt(&poll_timeout) {
+            break;
+        }
+
+        let resp = client_p::get_tx_status(http_rpc, &tx_id.to_string()).await?;
+        if let Some(r) = resp.result {
+            match r.status {
+                platformvm::txs::status::Status::Committed => {
+                    log::info!("{} successfully committed", tx_id);
+                    return Ok(());
+                }
+                platformvm::txs::status::Status::Aborted
+                | platformvm::txs::status::Status::Dropped => {
+                    return Err(Error::new(
+                        ErrorKind::Other,
+                        format!("{} {}", tx_id, r.status),
+                    ));
+                }
+                status => {
+                    log::warn!("{} {} (elapsed {:?})", tx_id, status, elapsed);
+                }
+            }
+        }
+        cancel::sleep(poll_interval).await?;
+    }
+    Err(Error::new(
+        ErrorKind::TimedOut,
+        format!("failed to check acceptance of {} in time", tx_id),
+    ))
+}
+
+macro_rules! impl_x_transaction {
+    ($($t:ty),+) => {
+        $(
+            #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+            #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+            impl Transaction for $t {
+                fn id(&self) -> ids::Id {
+                    self.tx_id()
+                }
+
+                fn serialize(&self) -> io::Result<Vec<u8>> {
+                    signed_bytes(&self.base_tx.metadata)
+                }
+
+                async fn issue(&self, http_rpc: &str) -> io::Result<ids::Id> {
+                    let hex_tx = jsonrpc::issue::encode_tx_hex(&self.serialize()?);
+                    let resp = client_x::issue_tx(http_rpc, &hex_tx).await?;
+                    match resp.result {
+                        Some(r) => Ok(r.tx_id),
+                        None => Err(Error::new(
+                            ErrorKind::Other,
+                            format!("failed to issue tx {:?}", resp.error),
+                        )),
+                    }
+                }
+
+                async fn await_accepted(
+                    &self,
+                    http_rpc: &str,
+                    poll_interval: Duration,
+                    poll_timeout: Duration,
+                ) -> io::Result<()> {
+                    await_x_accepted(http_rpc, &self.id(), poll_interval, poll_timeout).await
+                }
+            }
+        )+
+    };
+}
+
+impl_x_transaction!(avm::txs::Tx, avm::txs::export::Tx, avm::txs::import::Tx);
+
+macro_rules! impl_p_transaction {
+    ($($t:ty),+) => {
+        $(
+            #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+            #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+            impl Transaction for $t {
+                fn id(&self) -> ids::Id {
+                    self.tx_id()
+                }
+
+                fn serialize(&self) -> io::Result<Vec<u8>> {
+                    signed_bytes(&self.base_tx.metadata)
+                }
+
+                async fn issue(&self, http_rpc: &str) -> io::Result<ids::Id> {
+                    let hex_tx = jsonrpc::issue::encode_tx_hex(&self.serialize()?);
+                    let resp = client_p::issue_tx(http_rpc, &hex_tx).await?;
+                    match resp.result {
+                        Some(r) => Ok(r.tx_id),
+                        None => Err(Error::new(
+                            ErrorKind::Other,
+                            format!("failed to issue tx {:?}", resp.error),
+                        )),
+                    }
+                }
+
+                async fn await_accepted(
+                    &self,
+                    http_rpc: &str,
+                    poll_interval: Duration,
+                    poll_timeout: Duration,
+                ) -> io::Result<()> {
+                    await_p_committed(http_rpc, &self.id(), poll_interval, poll_timeout).await
+                }
+            }
+        )+
+    };
+}
+
+impl_p_transaction!(
+    platformvm::txs::add_subnet_validator::Tx,
+    platformvm::txs::add_validator::Tx,
+    platformvm::txs::create_chain::Tx,
+    platformvm::txs::create_subnet::Tx,
+    platformvm::txs::export::Tx,
+    platformvm::txs::import::Tx
+);
+
+/// Represents a signed RLP-encoded transaction for the C-chain or a subnet-evm chain.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EvmTx {
+    /// Either "C" or subnet_evm chain Id.
+    pub chain_id_alias: String,
+    /// Signed RLP-encoded transaction bytes.
+    pub signed_bytes: Vec<u8>,
+}
+
+impl EvmTx {
+    pub fn new(chain_id_alias: &str, signed_bytes: &[u8]) -> Self {
+        Self {
+            chain_id_alias: chain_id_alias.to_string(),
+            signed_bytes: Vec::from(signed_bytes),
+        }
+    }
+
+    /// Returns the keccak256 transaction hash.
+    pub fn tx_hash(&self) -> primitive_types::H256 {
+        hash::keccak256(&self.signed_bytes)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Transaction for EvmTx {
+    fn id(&self) -> ids::Id {
+        ids::Id::from_slice(self.tx_hash().as_bytes())
+    }
+
+    fn serialize(&self) -> io::Result<Vec<u8>> {
+        Ok(self.signed_bytes.clone())
+    }
+
+    async fn issue(&self, http_rpc: &str) -> io::Result<ids::Id> {
+        let tx_bytes_signed_hex = format!("0x{}", hex::encode(&self.signed_bytes));
+        let resp =
+            client_evm::send_raw_transaction(http_rpc, &self.chain_id_alias, &tx_bytes_signed_hex)
+                .await?;
+        if let Some(r) = &resp.result {
+            if *r != self.tx_hash() {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!("unexpected tx hash {:?} (expected {:?})", r, self.tx_hash()),
+                ));
+            }
+        }
+        Ok(self.id())
+    }
+
+    async fn await_accepted(
+        &self,
+        http_rpc: &str,
+        poll_interval: Duration,
+        poll_timeout: Duration,
+    ) -> io::Result<()> {
+        let tx_hash = format!("0x{:x}", self.tx_hash());
+        let start = Instant::now();
+        loop {
+            let elapsed = start.elapsed();
+            if elapsed.gt(&poll_timeout) {
+                break;
+            }
+
+            let resp =
+                client_evm::get_transaction_receipt(http_rpc, &self.chain_id_alias, &tx_hash)
+                    .await?;
+            if let Some(r) = &resp.result {
+                if r.status.as_u64() == 1 {
+                    log::info!("{} successfully accepted", tx_hash);
+                    return Ok(());
+                }
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!("{} failed with status {}", tx_hash, r.status),
+                ));
+            }
+            cancel::sleep(poll_interval).await?;
+        }
+        Err(Error::new(
+            ErrorKind::TimedOut,
+            format!("failed to check acceptance of {} in time", tx_hash),
+        ))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::txs::test_transaction --exact --show-output
+#[test]
+fn test_transaction() {
+    let tx = avm::txs::Tx::default();
+    assert!(Transaction::serialize(&tx).is_err());
+    assert_eq!(Transaction::id(&tx), ids::Id::empty());
+
+    let mut tx = platformvm::txs::create_subnet::Tx::default();
+    tx.base_tx.metadata = Some(txs::Metadata::new(&[1, 2, 3], &[1, 2, 3, 4]));
+    assert_eq!(Transaction::serialize(&tx).unwrap(), vec![1, 2, 3, 4]);
+    assert_eq!(Transaction::id(&tx), ids::Id::sha256([1, 2, 3, 4]));
+
+    let tx = EvmTx::new("C", &[0x02, 0x01]);
+    assert_eq!(Transaction::serialize(&tx).unwrap(), vec![0x02, 0x01]);
+    assert_eq!(
+        Transaction::id(&tx).to_vec(),
+        hash::keccak256([0x02, 0x01]).as_bytes().to_vec()
+    );
+
+    let all: Vec<Box<dyn Transaction>> = vec![
+        Box::new(avm::txs::Tx::default()),
+        Box::new(platformvm::txs::add_validator::Tx::default()),
+        Box::new(tx),
+    ];
+    assert_eq!(all.len(), 3);
+}
@@ -0,0 +1,216 @@
+//! Uptime self-monitoring for validator operators.
+//!
+//! The monitor periodically samples "info.uptime" of the node, and calls the
+//! alert callback when the percentage of the stake that considers the node
+//! eligible for rewards drops below the threshold. The callback is called
+//! once per drop, and again only after the node recovers above the threshold.
+//!
+//! ```
+//! # async fn run() {
+//! use std::time::Duration;
+//! use avalanche_types::client::validator_monitor::Monitor;
+//!
+//! Monitor::new("http://localhost:9650")
+//!     .threshold(90.0)
+//!     .interval(Duration::from_secs(300))
+//!     .run(|alert| println!("uptime alert {:?}", alert))
+//!     .await;
+//! # }
+//! ```
+
+use std::{io, time::Duration};
+
+use crate::{
+    client::{cancel, info as client_info},
+    jsonrpc::info::UptimeResult,
+};
+
+/// Default minimum rewarding stake percentage, the uptime
+/// that the primary network requires for staking rewards.
+pub const DEFAULT_THRESHOLD: f64 = 80.0;
+
+/// Default wait between samples.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Rewarding stake percentage dropped below the threshold.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alert {
+    pub rewarding_stake_percentage: f64,
+    pub weighted_average_percentage: f64,
+    pub threshold: f64,
+}
+
+/// Tracks whether the node is below the threshold, so that
+/// each drop is reported once.
+#[derive(Debug, Clone)]
+pub struct Detector {
+    pub threshold: f64,
+    below: bool,
+}
+
+impl Detector {
+    pub fn new(threshold: f64) -> Self {
+        Self {
+            threshold,
+            below: false,
+        }
+    }
+
+    /// Returns the alert if the uptime dropped below the threshold
+    /// since the last observation.
+    pub fn observe(&mut self, uptime: &UptimeResult) -> Option<Alert> {
+        let below = uptime.rewarding_stake_percentage < self.threshold;
+        let dropped = below && !self.below;
+        if self.below && !below {
+            log::info!(
+                "rewarding stake percentage recovered to {:.2}% (threshold {:.2}%)",
+                uptime.rewarding_stake_percentage,
+                self.threshold
+            );
+        }
+        self.below = below;
+        if !dropped {
+            return None;
+        }
+        Some(Alert {
+            rewarding_stake_percentage: uptime.rewarding_stake_percentage,
+            weighted_average_percentage: uptime.weighted_average_percentage,
+            threshold: self.threshold,
+        })
+    }
+}
+
+/// Samples the uptime of a validator node.
+#[derive(Debug, Clone)]
+pub struct Monitor {
+    pub http_rpc: String,
+    /// Minimum rewarding stake percentage in [0, 100].
+    pub threshold: f64,
+    pub interval: Duration,
+}
+
+impl Monitor {
+    pub fn new(http_rpc: &str) -> Self {
+        Self {
+            http_rpc: http_rpc.to_string(),
+            threshold: DEFAULT_THRESHOLD,
+            interval: DEFAULT_INTERVAL,
+        }
+    }
+
+    /// Sets the minimum rewarding stake percentage.
+    #[must_use]
+    pub fn threshold(mut self, threshold: f64) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Sets the wait between samples.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Fetches the current uptime of the node.
+    pub async fn sample(&self) -> io::Result<UptimeResult> {
+        client_info::uptime(&self.http_rpc).await?.into_result()
+    }
+
+    /// Samples the node every interval, and calls "on_alert" whenever the
+    /// rewarding stake percentage drops below the threshold. Failed samples
+    /// (e.g., the node is restarting) are logged and skipped. Runs until
+    /// the future is dropped or the "cancel::scope" is cancelled.
+    pub async fn run<F>(&self, mut on_alert: F)
+    where
+        F: FnMut(&Alert),
+    {
+        let mut detector = Detector::new(self.threshold);
+        loop {
+            match self.sample().await {
+                Ok(uptime) => {
+                    log::debug!(
+                        "rewarding stake percentage {:.2}%, weighted average {:.2}%",
+                        uptime.rewarding_stake_percentage,
+                        uptime.weighted_average_percentage
+                    );
+                    if let Some(alert) = detector.observe(&uptime) {
+                        log::warn!(
+                            "rewarding stake percentage {:.2}% below the threshold {:.2}%",
+                            alert.rewarding_stake_percentage,
+                            alert.threshold
+                        );
+                        on_alert(&alert);
+                    }
+                }
+                Err(e) => log::warn!("failed to sample uptime of {} '{}'", self.http_rpc, e),
+            }
+            if cancel::sleep(self.interval).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::validator_monitor::test_monitor --exact --show-output
+#[tokio::test]
+async fn test_monitor() {
+    use crate::client::transport::{self, Transport};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    };
+
+    /// Replays the uptime samples, and fails past the last one.
+    struct Fake {
+        samples: Vec<f64>,
+        next: AtomicUsize,
+    }
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, _: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            assert!(body.contains("info.uptime"));
+            let i = self.next.fetch_add(1, Ordering::SeqCst);
+            let p = self
+                .samples
+                .get(i)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::ConnectionRefused, "down"))?;
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":{{"rewardingStakePercentage":"{:.4}","weightedAveragePercentage":"{:.4}"}}}}"#,
+                crate::jsonrpc::request_id(body).unwrap_or_default(),
+                p,
+                p
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let fake = Arc::new(Fake {
+        samples: vec![100.0, 79.0, 70.0, 95.0, 60.0],
+        next: AtomicUsize::new(0),
+    });
+    let alerts = Arc::new(Mutex::new(Vec::new()));
+    let monitor = Monitor::new("http://node").interval(Duration::from_millis(1));
+    let collected = alerts.clone();
+    let run = monitor.run(move |alert| {
+        collected
+            .lock()
+            .expect("unexpected poisoned lock")
+            .push(alert.rewarding_stake_percentage)
+    });
+    let _ = transport::scope(
+        fake.clone(),
+        tokio::time::timeout(Duration::from_millis(200), run),
+    )
+    .await;
+
+    // one alert per drop, and the failed samples are skipped
+    assert!(fake.next.load(Ordering::SeqCst) > 5);
+    assert_eq!(
+        *alerts.lock().expect("unexpected poisoned lock"),
+        vec![79.0, 60.0]
+    );
+}
@@ -0,0 +1,292 @@
+//! Ordered issuance of dependent transactions.
+//!
+//! Each step is issued only after the previous one is accepted, and the Ids
+//! produced by earlier steps are wired into the later steps that refer to
+//! them (e.g., the subnet Id of a create subnet step for the create chain
+//! and add subnet validator steps).
+//!
+//! ```ignore
+//! let p = wallet.p();
+//! let issued = wallet
+//!     .batch()
+//!     .create_subnet(p.create_subnet())
+//!     .create_chain(p.create_chain().vm_id(vm_id).genesis_data(genesis), Ref::Step(0))
+//!     .add_subnet_validator(p.add_subnet_validator().node_id(node_id), Ref::Step(0))
+//!     .issue()
+//!     .await?;
+//! let (subnet_id, blockchain_id) = (issued[0], issued[1]);
+//! ```
+
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{
+    client::wallet::{p, x},
+    ids, key,
+};
+
+/// Refers to an Id that is either known, or produced by an earlier step.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Ref {
+    Id(ids::Id),
+    /// Index of the earlier create subnet step in the batch.
+    Step(usize),
+}
+
+/// Kind of the batch step.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Kind {
+    CreateSubnet,
+    CreateChain,
+    AddValidator,
+    AddSubnetValidator,
+    Transfer,
+}
+
+/// ref. https://doc.rust-lang.org/std/string/trait.ToString.html
+/// ref. https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// Use "Self.to_string()" to directly invoke this
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Kind::CreateSubnet => "create_subnet",
+            Kind::CreateChain => "create_chain",
+            Kind::AddValidator => "add_validator",
+            Kind::AddSubnetValidator => "add_subnet_validator",
+            Kind::Transfer => "transfer",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Step<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    CreateSubnet(p::create_subnet::Tx<T>),
+    CreateChain(p::create_chain::Tx<T>, Ref),
+    AddValidator(p::add_validator::Tx<T>),
+    AddSubnetValidator(p::add_subnet_validator::Tx<T>, Ref),
+    Transfer(x::transfer::Tx<T>),
+}
+
+impl<T> Step<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn kind(&self) -> Kind {
+        match self {
+            Step::CreateSubnet(_) => Kind::CreateSubnet,
+            Step::CreateChain(..) => Kind::CreateChain,
+            Step::AddValidator(_) => Kind::AddValidator,
+            Step::AddSubnetValidator(..) => Kind::AddSubnetValidator,
+            Step::Transfer(_) => Kind::Transfer,
+        }
+    }
+
+    /// Returns the subnet that the step depends on, if any.
+    pub fn subnet(&self) -> Option<Ref> {
+        match self {
+            Step::CreateChain(_, subnet) | Step::AddSubnetValidator(_, subnet) => Some(*subnet),
+            _ => None,
+        }
+    }
+}
+
+/// Transactions to issue in order, each after the acceptance of the previous.
+#[derive(Clone, Debug)]
+pub struct Batch<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub steps: Vec<Step<T>>,
+}
+
+impl<T> Batch<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
+
+    /// Queues the create subnet transaction, whose Id is the subnet Id.
+    #[must_use]
+    pub fn create_subnet(mut self, tx: p::create_subnet::Tx<T>) -> Self {
+        self.steps.push(Step::CreateSubnet(tx));
+        self
+    }
+
+    /// Queues the create chain transaction on the subnet.
+    #[must_use]
+    pub fn create_chain(mut self, tx: p::create_chain::Tx<T>, subnet: Ref) -> Self {
+        self.steps.push(Step::CreateChain(tx, subnet));
+        self
+    }
+
+    /// Queues the add primary network validator transaction.
+    #[must_use]
+    pub fn add_validator(mut self, tx: p::add_validator::Tx<T>) -> Self {
+        self.steps.push(Step::AddValidator(tx));
+        self
+    }
+
+    /// Queues the add subnet validator transaction on the subnet.
+    #[must_use]
+    pub fn add_subnet_validator(mut self, tx: p::add_subnet_validator::Tx<T>, subnet: Ref) -> Self {
+        self.steps.push(Step::AddSubnetValidator(tx, subnet));
+        self
+    }
+
+    /// Queues the X-chain transfer transaction.
+    #[must_use]
+    pub fn transfer(mut self, tx: x::transfer::Tx<T>) -> Self {
+        self.steps.push(Step::Transfer(tx));
+        self
+    }
+
+    /// Checks that every step refers to an earlier create subnet step.
+    pub fn validate(&self) -> io::Result<()> {
+        let deps: Vec<(Kind, Option<Ref>)> =
+            self.steps.iter().map(|s| (s.kind(), s.subnet())).collect();
+        validate(&deps)
+    }
+
+    /// Issues the steps in order, waiting for the acceptance of each, and
+    /// returns the transaction Id of each step. The add validator steps for
+    /// nodes that already validate return the empty Id.
+    ///
+    /// On failure, the steps issued before the failed one stay on chain,
+    /// and the error lists their transaction Ids.
+    pub async fn issue(&self) -> io::Result<Vec<ids::Id>> {
+        self.validate()?;
+
+        let mut tx_ids: Vec<ids::Id> = Vec::with_capacity(self.steps.len());
+        for (i, step) in self.steps.iter().enumerate() {
+            log::info!("issuing batch step {} ({})", i, step.kind());
+            let subnet_id = step.subnet().map(|r| match r {
+                Ref::Id(id) => id,
+                Ref::Step(j) => tx_ids[j],
+            });
+
+            let res = match step {
+                Step::CreateSubnet(tx) => tx.clone().check_acceptance(true).issue().await,
+                Step::CreateChain(tx, _) => {
+                    tx.clone()
+                        .subnet_id(subnet_id.expect("unexpected None subnet"))
+                        .check_acceptance(true)
+                        .issue()
+                        .await
+                }
+                Step::AddValidator(tx) => tx
+                    .clone()
+                    .check_acceptance(true)
+                    .issue()
+                    .await
+                    .map(|(tx_id, _)| tx_id),
+                Step::AddSubnetValidator(tx, _) => tx
+                    .clone()
+                    .subnet_id(subnet_id.expect("unexpected None subnet"))
+                    .check_acceptance(true)
+                    .issue()
+                    .await
+                    .map(|(tx_id, _)| tx_id),
+                Step::Transfer(tx) => tx.clone().check_acceptance(true).issue().await,
+            };
+            match res {
+                Ok(tx_id) => {
+                    log::info!("batch step {} ({}) accepted {}", i, step.kind(), tx_id);
+                    tx_ids.push(tx_id);
+                }
+                Err(e) => {
+                    return Err(Error::new(
+                        e.kind(),
+                        format!(
+                            "batch step {} ({}) failed after issuing {:?} '{}'",
+                            i,
+                            step.kind(),
+                            tx_ids,
+                            e
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(tx_ids)
+    }
+}
+
+impl<T> Default for Batch<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks that each subnet reference points to an earlier create subnet step.
+fn validate(deps: &[(Kind, Option<Ref>)]) -> io::Result<()> {
+    for (i, (kind, subnet)) in deps.iter().enumerate() {
+        let j = match subnet {
+            Some(Ref::Step(j)) => *j,
+            _ => continue,
+        };
+        if j >= i {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("batch step {} ({}) refers to later step {}", i, kind, j),
+            ));
+        }
+        if deps[j].0 != Kind::CreateSubnet {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "batch step {} ({}) refers to step {} ({}) for its subnet",
+                    i, kind, j, deps[j].0
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::batch::test_validate --exact --show-output
+#[test]
+fn test_validate() {
+    let subnet_id = ids::Id::from_slice(&[1; 32]);
+
+    assert!(validate(&[
+        (Kind::CreateSubnet, None),
+        (Kind::CreateChain, Some(Ref::Step(0))),
+        (Kind::AddValidator, None),
+        (Kind::AddSubnetValidator, Some(Ref::Step(0))),
+        (Kind::CreateChain, Some(Ref::Id(subnet_id))),
+    ])
+    .is_ok());
+
+    // refers to itself
+    assert_eq!(
+        validate(&[(Kind::CreateChain, Some(Ref::Step(0)))])
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidInput
+    );
+
+    // refers to a later step
+    assert!(validate(&[
+        (Kind::CreateChain, Some(Ref::Step(1))),
+        (Kind::CreateSubnet, None),
+    ])
+    .is_err());
+
+    // refers to a step that does not create a subnet
+    assert!(validate(&[
+        (Kind::Transfer, None),
+        (Kind::AddSubnetValidator, Some(Ref::Step(0))),
+    ])
+    .is_err());
+}
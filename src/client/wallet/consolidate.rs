@@ -0,0 +1,218 @@
+//! Planning of UTXO consolidation, which sweeps many small UTXOs into fewer
+//! outputs, and reporting of the dust that is uneconomical to move.
+//!
+//! The X-chain and P-chain charge a flat fee per transaction regardless of
+//! the number of inputs, so a UTXO is dust only when it is worth less than
+//! the fee of spending it on its own. Dust can still be swept as part of a
+//! batch whose total exceeds the fee.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    ids::{self, short},
+    key::{
+        self,
+        secp256k1::keychain::{self, Keychain},
+    },
+    txs,
+    units::NanoAvax,
+};
+
+/// Default maximum number of inputs per consolidation transaction, which
+/// keeps the signed transaction well below the 64 KiB transaction size limit
+/// (each input and its credential take about 160 bytes).
+pub const DEFAULT_MAX_INPUTS: usize = 256;
+
+/// Minimum number of inputs for a consolidation transaction to reduce the
+/// number of UTXOs.
+const MIN_INPUTS: usize = 2;
+
+/// UTXOs to consolidate into a single output in one transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Batch {
+    pub utxos: Vec<txs::utxo::Utxo>,
+    /// Sum of the UTXO amounts.
+    pub total: u64,
+    /// Amount of the consolidated output, the total minus the fee.
+    pub output_amount: u64,
+}
+
+/// UTXOs that are worth less than the fee to spend them on their own.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Dust {
+    pub utxos: Vec<txs::utxo::Utxo>,
+    pub total: u64,
+}
+
+/// Returns the amount of the UTXO if the keychain can spend it at the time,
+/// and it holds the asset. Locked UTXOs are skipped.
+pub fn spendable_amount<T>(
+    keychain: &Keychain<T>,
+    utxo: &txs::utxo::Utxo,
+    asset_id: &ids::Id,
+    now_unix: u64,
+) -> Option<u64>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    if utxo.asset_id != *asset_id {
+        return None;
+    }
+    let out = match (&utxo.transfer_output, &utxo.stakeable_lock_out) {
+        (Some(out), _) => out,
+        (None, Some(locked)) if locked.locktime <= now_unix => &locked.transfer_output,
+        _ => return None,
+    };
+    keychain.spend(out, now_unix).map(|(input, _)| input.amount)
+}
+
+/// Returns the inputs that spend the UTXOs of the batch with their signers,
+/// sorted as the VMs require. The stakeable UTXOs whose lock expired are
+/// spent as the unlocked ones.
+#[allow(clippy::type_complexity)]
+pub fn spend<T>(
+    keychain: &Keychain<T>,
+    batch: &Batch,
+    now_unix: u64,
+) -> io::Result<(Vec<txs::transferable::Input>, Vec<Vec<T>>)>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    let mut inputs: Vec<txs::transferable::Input> = Vec::new();
+    let mut signers: Vec<Vec<T>> = Vec::new();
+    for utxo in batch.utxos.iter() {
+        let out = match (&utxo.transfer_output, &utxo.stakeable_lock_out) {
+            (Some(out), _) => out,
+            (None, Some(locked)) if locked.locktime <= now_unix => &locked.transfer_output,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("UTXO {} has no spendable output", utxo.utxo_id.id),
+                ))
+            }
+        };
+        let (input, keys) = keychain.spend(out, now_unix).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("cannot spend UTXO {}", utxo.utxo_id.id),
+            )
+        })?;
+        keychain::verify_signers(&out.output_owners, &input.sig_indices, &keys)?;
+        inputs.push(txs::transferable::Input {
+            utxo_id: utxo.utxo_id.clone(),
+            asset_id: utxo.asset_id,
+            transfer_input: Some(input),
+            ..Default::default()
+        });
+        signers.push(keys);
+    }
+    keychain::sort_with_signers(&mut inputs, &mut signers);
+    Ok((inputs, signers))
+}
+
+/// Returns the single output of the batch owned by the address.
+pub fn output(batch: &Batch, asset_id: &ids::Id, owner: &short::Id) -> txs::transferable::Output {
+    txs::transferable::Output {
+        asset_id: *asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: batch.output_amount,
+            output_owners: key::secp256k1::txs::OutputOwners {
+                locktime: 0,
+                threshold: 1,
+                addresses: vec![owner.clone()],
+            },
+        }),
+        ..Default::default()
+    }
+}
+
+/// Plans the consolidation of the UTXOs with their amounts, smallest first,
+/// into batches of at most "max_inputs" inputs. Batches with fewer than two
+/// inputs or with a total that does not cover the fee are left out.
+/// Fails if the total of a batch overflows.
+pub fn plan(
+    utxos: Vec<(txs::utxo::Utxo, u64)>,
+    max_inputs: usize,
+    fee: u64,
+) -> io::Result<Vec<Batch>> {
+    let max_inputs = max_inputs.max(MIN_INPUTS);
+
+    let mut utxos = utxos;
+    utxos.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.utxo_id.cmp(&b.0.utxo_id)));
+
+    let mut batches = Vec::new();
+    for chunk in utxos.chunks(max_inputs) {
+        if chunk.len() < MIN_INPUTS {
+            continue;
+        }
+        let total = NanoAvax::checked_sum(chunk.iter().map(|(_, amount)| *amount))?.as_u64();
+        if total <= fee {
+            continue;
+        }
+        batches.push(Batch {
+            utxos: chunk.iter().map(|(u, _)| u.clone()).collect(),
+            total,
+            output_amount: total - fee,
+        });
+    }
+    Ok(batches)
+}
+
+/// Returns the UTXOs worth no more than the fee of spending them on their own.
+/// Fails if their total overflows.
+pub fn dust(utxos: &[(txs::utxo::Utxo, u64)], fee: u64) -> io::Result<Dust> {
+    let mut d = Dust::default();
+    let mut total = NanoAvax::ZERO;
+    for (utxo, amount) in utxos.iter() {
+        if *amount <= fee {
+            d.utxos.push(utxo.clone());
+            total = total.checked_add(NanoAvax(*amount))?;
+        }
+    }
+    d.total = total.as_u64();
+    Ok(d)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::consolidate::test_plan --exact --show-output
+#[test]
+fn test_plan() {
+    let utxos: Vec<(txs::utxo::Utxo, u64)> = [5_u64, 1, 40, 2, 3, 100, 7]
+        .iter()
+        .enumerate()
+        .map(|(i, amount)| {
+            let mut utxo = txs::utxo::Utxo::default();
+            utxo.utxo_id.output_index = i as u32;
+            (utxo, *amount)
+        })
+        .collect();
+
+    // smallest first: [1, 2, 3], [5, 7, 40], [100] left out
+    let batches = plan(utxos.clone(), 3, 4).unwrap();
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].total, 6);
+    assert_eq!(batches[0].output_amount, 2);
+    assert_eq!(batches[0].utxos.len(), 3);
+    assert_eq!(batches[1].total, 52);
+
+    // the first batch does not cover the fee
+    let batches = plan(utxos.clone(), 3, 6).unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].total, 52);
+
+    let batches = plan(utxos.clone(), DEFAULT_MAX_INPUTS, 10).unwrap();
+    assert_eq!(batches.len(), 1);
+    assert_eq!(batches[0].output_amount, 148);
+
+    let d = dust(&utxos, 3).unwrap();
+    assert_eq!(d.utxos.len(), 3);
+    assert_eq!(d.total, 6);
+    assert!(plan(Vec::new(), 3, 0).unwrap().is_empty());
+
+    // the total of the batch overflows
+    let huge: Vec<(txs::utxo::Utxo, u64)> = vec![
+        (utxos[0].0.clone(), u64::MAX),
+        (utxos[1].0.clone(), u64::MAX),
+    ];
+    assert!(plan(huge.clone(), 3, 0).is_err());
+    assert!(dust(&huge, u64::MAX).is_err());
+}
@@ -1,12 +1,14 @@
 use std::io::{self, Error, ErrorKind};
 
 use crate::{
-    client::{self, evm as client_evm},
+    client::{
+        self, cancel, evm as client_evm,
+        time::{Duration, Instant},
+    },
     evm, hash, key,
 };
 use ethers_providers::Middleware;
 use primitive_types::{H160, H256, U256};
-use tokio::time::{sleep, Duration, Instant};
 
 /// Represents an Ethereum transaction.
 /// ref. https://ethereum.org/en/developers/docs/transactions/
@@ -41,6 +43,9 @@ where
     pub value: Option<U256>,
     pub data: Option<Vec<u8>>,
 
+    /// EIP-2930 access list, which the dynamic fee transaction also carries.
+    pub access_list: Option<client::wallet::evm::AccessList>,
+
     /// Set "true" to poll transfer status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -75,6 +80,8 @@ where
             value: None,
             data: None,
 
+            access_list: None,
+
             check_acceptance: false,
 
             poll_initial_wait: Duration::from_millis(500),
@@ -129,6 +136,13 @@ where
         self
     }
 
+    /// Sets the addresses and storage keys that the transaction plans to access.
+    #[must_use]
+    pub fn access_list(mut self, access_list: client::wallet::evm::AccessList) -> Self {
+        self.access_list = Some(access_list);
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -216,6 +230,10 @@ where
         if let Some(data) = &self.data {
             tx_request = tx_request.data(data.clone());
         }
+        if let Some(access_list) = &self.access_list {
+            tx_request =
+                tx_request.access_list(client::wallet::evm::to_ethers_access_list(access_list));
+        }
 
         // ref. "ethers-middleware::signer::SignerMiddleware"
         // ref. "ethers-signers::LocalWallet"
@@ -229,32 +247,40 @@ where
                 .with_chain_id(self.inner.chain_id.as_u64()),
         );
 
-        let pending_tx = signer
-            .send_transaction(tx_request, None)
-            .await
-            .map_err(|e| {
+        let pending_tx = cancel::run(async {
+            signer
+                .send_transaction(tx_request, None)
+                .await
+                .map_err(|e| client::wallet::evm::revert::to_io_error("send_transaction", e))
+        })
+        .await?;
+        self.inner
+            .record_sent(pending_tx.tx_hash(), "eip1559", &picked_http_rpc.1);
+
+        let tx_receipt = cancel::run(async {
+            pending_tx.await.map_err(|e| {
                 Error::new(
                     ErrorKind::Other,
-                    format!("failed to send_transaction '{}'", e),
+                    format!("failed to wait for pending tx '{}'", e),
                 )
-            })?;
-
-        let tx_receipt = pending_tx.await.map_err(|e| {
-            Error::new(
-                ErrorKind::Other,
-                format!("failed to wait for pending tx '{}'", e),
-            )
-        })?;
+            })
+        })
+        .await?;
         if tx_receipt.is_none() {
             return Err(Error::new(ErrorKind::Other, "tx dropped from mempool"));
         }
         let tx_receipt = tx_receipt.unwrap();
         let tx_hash = H256(tx_receipt.transaction_hash.0);
 
-        let tx = signer
-            .get_transaction(tx_receipt.transaction_hash)
-            .await
-            .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e)))?;
+        let tx = cancel::run(async {
+            signer
+                .get_transaction(tx_receipt.transaction_hash)
+                .await
+                .map_err(|e| {
+                    Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e))
+                })
+        })
+        .await?;
 
         // serde_json::to_string(&tx).unwrap()
         if let Some(inner) = &tx {
@@ -351,6 +377,8 @@ where
             log::info!("no result found for eth_sendRawTransaction, use precomputed hash");
         };
         log::info!("transaction hash {}", tx_hash);
+        self.inner
+            .record_sent(tx_hash, "eip1559", &picked_http_rpc.1);
 
         if !self.check_acceptance {
             log::debug!("skipping checking acceptance...");
@@ -359,7 +387,7 @@ where
 
         // enough time for txs processing
         log::info!("initial waiting {:?}", self.poll_initial_wait);
-        sleep(self.poll_initial_wait).await;
+        cancel::sleep(self.poll_initial_wait).await?;
 
         log::info!("polling to confirm transaction");
         let (start, mut success) = (Instant::now(), false);
@@ -385,7 +413,7 @@ where
                 }
             }
 
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
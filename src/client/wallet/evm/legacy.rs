@@ -1,16 +1,21 @@
 use std::io::{self, Error, ErrorKind};
 
 use crate::{
-    client::{self, evm as client_evm},
+    client::{
+        self, cancel, evm as client_evm,
+        time::{Duration, Instant},
+    },
     evm, hash, key,
 };
+use ethers::types::transaction::eip2718::TypedTransaction;
 use ethers_providers::Middleware;
 use primitive_types::{H160, H256, U256};
-use tokio::time::{sleep, Duration, Instant};
 
 pub const DEFAULT_GAS: u64 = 21000;
 
-/// Represents an Ethereum transaction.
+/// Represents an Ethereum transaction, which pays the gas price and is sent
+/// as the legacy type unless set with "tx_type" (e.g., the EIP-2930 type with
+/// an access list).
 /// ref. https://ethereum.org/en/developers/docs/transactions/
 ///
 /// NOTE: The default coreth and subnet-evm will fail this transaction with
@@ -39,6 +44,15 @@ where
     pub value: Option<U256>,
     pub data: Option<Vec<u8>>,
 
+    /// Transaction type, legacy by default.
+    pub tx_type: client::wallet::evm::TxType,
+    /// Addresses and storage keys that the transaction plans to access,
+    /// dropped for the legacy type.
+    pub access_list: client::wallet::evm::AccessList,
+    /// Tip for the dynamic fee type, with "gas_price" as the maximum fee.
+    /// Estimated by the provider if not set.
+    pub max_priority_fee_per_gas: Option<U256>,
+
     /// Set "true" to poll transfer status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -70,6 +84,10 @@ where
             value: None,
             data: None,
 
+            tx_type: client::wallet::evm::TxType::Legacy,
+            access_list: Vec::new(),
+            max_priority_fee_per_gas: None,
+
             check_acceptance: false,
 
             poll_initial_wait: Duration::from_millis(500),
@@ -118,6 +136,27 @@ where
         self
     }
 
+    /// Sets the transaction type.
+    #[must_use]
+    pub fn tx_type(mut self, tx_type: client::wallet::evm::TxType) -> Self {
+        self.tx_type = tx_type;
+        self
+    }
+
+    /// Sets the addresses and storage keys that the transaction plans to access.
+    #[must_use]
+    pub fn access_list(mut self, access_list: client::wallet::evm::AccessList) -> Self {
+        self.access_list = access_list;
+        self
+    }
+
+    /// Sets the tip for the dynamic fee type.
+    #[must_use]
+    pub fn max_priority_fee_per_gas(mut self, max_priority_fee_per_gas: impl Into<U256>) -> Self {
+        self.max_priority_fee_per_gas = Some(max_priority_fee_per_gas.into());
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -154,11 +193,14 @@ where
     }
 
     /// Issues the transaction with "ethers" and returns the transaction Id.
+    /// In dry mode, returns the hash of the locally signed transaction
+    /// without sending it.
     /// ref. "coreth,subnet-evm/internal/ethapi.SubmitTransaction"
     pub async fn submit(&self) -> io::Result<H256> {
         let picked_http_rpc = self.inner.inner.pick_http_rpc();
         log::info!(
-            "issuing ethers transaction [chain Id {}, value {:?}, from {}, to {:?}, http rpc {}, chain RPC {}, gas_price {:?}, gas_limit {:?}]",
+            "issuing ethers transaction [type {:?}, chain Id {}, value {:?}, from {}, to {:?}, http rpc {}, chain RPC {}, gas_price {:?}, gas_limit {:?}, access list {} address(es)]",
+            self.tx_type,
             self.inner.chain_id,
             self.value,
             self.inner.inner.h160_address,
@@ -167,6 +209,7 @@ where
             self.inner.chain_rpc_url_path,
             self.gas_price,
             self.gas_limit,
+            self.access_list.len(),
         );
 
         let signer_nonce = if let Some(signer_nonce) = self.signer_nonce {
@@ -175,29 +218,7 @@ where
             self.inner.latest_nonce().await?
         };
         log::info!("latest signer nonce {}", signer_nonce);
-
-        let mut tx_request = ethers::prelude::TransactionRequest::new()
-            .from(ethers::prelude::H160::from(
-                self.inner.inner.h160_address.as_fixed_bytes(),
-            ))
-            .chain_id(ethers::prelude::U64::from(self.inner.chain_id.as_u64()))
-            .nonce(ethers::prelude::U256::from(signer_nonce.as_u128()));
-
-        if let Some(to) = &self.to {
-            tx_request = tx_request.to(ethers::prelude::H160::from(to.as_fixed_bytes()));
-        }
-        if let Some(value) = &self.value {
-            tx_request = tx_request.value(ethers::prelude::U256::from(value.as_u128()));
-        }
-        if let Some(gas_price) = &self.gas_price {
-            tx_request = tx_request.gas_price(ethers::prelude::U256::from(gas_price.as_u128()));
-        }
-        if let Some(gas_limit) = &self.gas_limit {
-            tx_request = tx_request.gas(ethers::prelude::U256::from(gas_limit.as_u128()));
-        }
-        if let Some(data) = &self.data {
-            tx_request = tx_request.data(data.clone());
-        }
+        let mut typed_tx = self.typed_tx(signer_nonce);
 
         // ref. "ethers-middleware::signer::SignerMiddleware"
         // ref. "ethers-signers::LocalWallet"
@@ -210,32 +231,65 @@ where
                 .with_chain_id(self.inner.chain_id.as_u64()),
         );
 
-        let pending_tx = signer
-            .send_transaction(tx_request, None)
-            .await
-            .map_err(|e| {
+        if self.dry_mode {
+            // fill in the gas price and limit as "send_transaction" does
+            cancel::run(async {
+                signer
+                    .fill_transaction(&mut typed_tx, None)
+                    .await
+                    .map_err(|e| client::wallet::evm::revert::to_io_error("fill_transaction", e))
+            })
+            .await?;
+            let signature = signer
+                .signer()
+                .sign_transaction(&typed_tx)
+                .await
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("failed to sign_transaction '{}'", e),
+                    )
+                })?;
+            let tx_hash = hash::keccak256(typed_tx.rlp_signed(&signature));
+            log::debug!("dry mode... returning {}", tx_hash);
+            return Ok(tx_hash);
+        }
+
+        let tx_type = client::wallet::evm::tx_type_name(&typed_tx);
+        let pending_tx = cancel::run(async {
+            signer
+                .send_transaction(typed_tx, None)
+                .await
+                .map_err(|e| client::wallet::evm::revert::to_io_error("send_transaction", e))
+        })
+        .await?;
+        self.inner
+            .record_sent(pending_tx.tx_hash(), tx_type, &picked_http_rpc.1);
+
+        let tx_receipt = cancel::run(async {
+            pending_tx.await.map_err(|e| {
                 Error::new(
                     ErrorKind::Other,
-                    format!("failed to send_transaction '{}'", e),
+                    format!("failed to wait for pending tx '{}'", e),
                 )
-            })?;
-
-        let tx_receipt = pending_tx.await.map_err(|e| {
-            Error::new(
-                ErrorKind::Other,
-                format!("failed to wait for pending tx '{}'", e),
-            )
-        })?;
+            })
+        })
+        .await?;
         if tx_receipt.is_none() {
             return Err(Error::new(ErrorKind::Other, "tx dropped from mempool"));
         }
         let tx_receipt = tx_receipt.unwrap();
         let tx_hash = H256(tx_receipt.transaction_hash.0);
 
-        let tx = signer
-            .get_transaction(tx_receipt.transaction_hash)
-            .await
-            .map_err(|e| Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e)))?;
+        let tx = cancel::run(async {
+            signer
+                .get_transaction(tx_receipt.transaction_hash)
+                .await
+                .map_err(|e| {
+                    Error::new(ErrorKind::Other, format!("failed get_transaction '{}'", e))
+                })
+        })
+        .await?;
 
         // serde_json::to_string(&tx).unwrap()
         if let Some(inner) = &tx {
@@ -252,6 +306,40 @@ where
 
         Ok(tx_hash)
     }
+
+    /// Returns the request of the transaction type, with the amounts
+    /// and the fees as set (in full 256-bit precision).
+    fn typed_tx(&self, signer_nonce: U256) -> TypedTransaction {
+        let mut tx_request = ethers::prelude::TransactionRequest::new()
+            .from(ethers::prelude::H160::from(
+                self.inner.inner.h160_address.as_fixed_bytes(),
+            ))
+            .chain_id(ethers::prelude::U64::from(self.inner.chain_id.as_u64()))
+            .nonce(signer_nonce);
+
+        if let Some(to) = &self.to {
+            tx_request = tx_request.to(ethers::prelude::H160::from(to.as_fixed_bytes()));
+        }
+        if let Some(value) = self.value {
+            tx_request = tx_request.value(value);
+        }
+        if let Some(gas_price) = self.gas_price {
+            tx_request = tx_request.gas_price(gas_price);
+        }
+        if let Some(gas_limit) = self.gas_limit {
+            tx_request = tx_request.gas(gas_limit);
+        }
+        if let Some(data) = &self.data {
+            tx_request = tx_request.data(data.clone());
+        }
+
+        let mut typed_tx =
+            client::wallet::evm::new_typed_tx(self.tx_type, tx_request, &self.access_list);
+        if let TypedTransaction::Eip1559(req) = &mut typed_tx {
+            req.max_priority_fee_per_gas = self.max_priority_fee_per_gas;
+        }
+        typed_tx
+    }
 }
 
 impl<'a, T, S> Tx<'a, T, S>
@@ -333,7 +421,7 @@ where
 
         // enough time for txs processing
         log::info!("initial waiting {:?}", self.poll_initial_wait);
-        sleep(self.poll_initial_wait).await;
+        cancel::sleep(self.poll_initial_wait).await?;
 
         log::info!("polling to confirm transaction");
         let (start, mut success) = (Instant::now(), false);
@@ -359,7 +447,7 @@ where
                 }
             }
 
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -371,3 +459,77 @@ where
         Ok(tx_hash)
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::legacy::test_typed_tx --exact --show-output
+#[test]
+fn test_typed_tx() {
+    use std::sync::{Arc, Mutex};
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let eth_signer = ethers_signers::LocalWallet::from(k.signing_key());
+    let inner = client::wallet::Wallet {
+        keychain: key::secp256k1::keychain::Keychain::new(vec![k.clone()]),
+        http_rpcs: vec![String::from("http://localhost:9650")],
+        http_rpc_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: String::from("mainnet"),
+        hrp: String::from("avax"),
+        h160_address: k.to_public_key().to_h160(),
+        x_address: String::new(),
+        p_address: String::new(),
+        c_address: String::new(),
+        short_address: k.to_public_key().to_short_id().unwrap(),
+        eth_address: k.to_public_key().eth_address(),
+        blockchain_id_x: crate::ids::Id::empty(),
+        blockchain_id_p: crate::ids::Id::empty(),
+        blockchain_id_c: crate::ids::Id::empty(),
+        chain_id_c: U256::from(43114),
+        avax_asset_id: crate::ids::Id::empty(),
+        tx_fee: 0,
+        add_primary_network_validator_fee: 0,
+        create_subnet_tx_fee: 0,
+        create_blockchain_tx_fee: 0,
+        journal: None,
+        issuance_guard: None,
+        issuance_queue: None,
+    };
+    let evm = inner
+        .evm(&eth_signer, String::from("C"), U256::from(43114))
+        .unwrap();
+
+    // beyond 128 bits, which must not be truncated
+    let value = U256::from(u128::MAX) * 3;
+    let access_list = vec![(H160::from_low_u64_be(1), vec![H256::from_low_u64_be(2)])];
+
+    let tx = evm.legacy().value(value).gas_price(30_u64);
+    let typed_tx = tx.typed_tx(U256::from(7));
+    assert!(matches!(typed_tx, TypedTransaction::Legacy(_)));
+    assert_eq!(typed_tx.value(), Some(&value));
+    assert_eq!(typed_tx.nonce(), Some(&U256::from(7)));
+    assert_eq!(typed_tx.gas(), Some(&U256::from(DEFAULT_GAS)));
+
+    let typed_tx = evm
+        .eip2930()
+        .value(value)
+        .access_list(access_list.clone())
+        .typed_tx(U256::from(7));
+    assert!(matches!(typed_tx, TypedTransaction::Eip2930(_)));
+    assert_eq!(typed_tx.access_list().unwrap().0.len(), 1);
+    assert_eq!(typed_tx.gas(), None);
+
+    let typed_tx = evm
+        .legacy()
+        .tx_type(client::wallet::evm::TxType::DynamicFee)
+        .access_list(access_list)
+        .gas_price(30_u64)
+        .max_priority_fee_per_gas(2_u64)
+        .typed_tx(U256::from(7));
+    match &typed_tx {
+        TypedTransaction::Eip1559(req) => {
+            assert_eq!(req.max_fee_per_gas, Some(U256::from(30)));
+            assert_eq!(req.max_priority_fee_per_gas, Some(U256::from(2)));
+            assert_eq!(req.access_list.0.len(), 1);
+        }
+        _ => panic!("unexpected tx type"),
+    }
+}
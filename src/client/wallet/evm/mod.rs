@@ -1,12 +1,117 @@
 pub mod eip1559;
 pub mod legacy;
+#[cfg(feature = "subnet_evm")]
+pub mod precompile;
+pub mod revert;
+pub mod token;
+#[cfg(feature = "subnet_evm")]
+pub mod warp;
 
-use std::io;
+use std::io::{self, Error, ErrorKind};
 
 use crate::{
-    client::{self, evm as client_evm},
+    client::{
+        self, cancel, evm as client_evm,
+        time::{Duration, Instant},
+        wallet::journal,
+    },
     key,
 };
+use ethers::types::transaction::{eip2718::TypedTransaction, eip2930 as ethers_eip2930};
+use ethers_providers::Middleware;
+use primitive_types::{H160, H256};
+
+/// EIP-2718 transaction type.
+/// ref. https://eips.ethereum.org/EIPS/eip-2718
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TxType {
+    /// Untyped transaction, for the chains that only accept the legacy type.
+    Legacy,
+    /// Type 1 transaction with an access list and a gas price.
+    /// ref. https://eips.ethereum.org/EIPS/eip-2930
+    AccessList,
+    /// Type 2 transaction with an access list and the fee caps.
+    /// ref. https://eips.ethereum.org/EIPS/eip-1559
+    DynamicFee,
+}
+
+/// Addresses and their storage keys that the transaction plans to access,
+/// which are charged the discounted "warm" cost (e.g., the subnet-evm
+/// precompile addresses and the storage slots they read).
+pub type AccessList = Vec<(H160, Vec<H256>)>;
+
+pub fn to_ethers_access_list(access_list: &[(H160, Vec<H256>)]) -> ethers_eip2930::AccessList {
+    ethers_eip2930::AccessList(
+        access_list
+            .iter()
+            .map(|(address, storage_keys)| ethers_eip2930::AccessListItem {
+                address: ethers::prelude::H160::from(address.as_fixed_bytes()),
+                storage_keys: storage_keys
+                    .iter()
+                    .map(|k| ethers::prelude::H256::from(k.as_fixed_bytes()))
+                    .collect(),
+            })
+            .collect(),
+    )
+}
+
+pub fn from_ethers_access_list(access_list: &ethers_eip2930::AccessList) -> AccessList {
+    access_list
+        .0
+        .iter()
+        .map(|item| {
+            (
+                H160::from(item.address.as_fixed_bytes()),
+                item.storage_keys
+                    .iter()
+                    .map(|k| H256::from(k.as_fixed_bytes()))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Converts the request to the transaction type. The access list is dropped
+/// for the legacy type, and the gas price is used as the maximum fee of
+/// the dynamic fee type, whose tip is left to the caller (or to the provider
+/// estimate when the transaction is filled).
+pub fn new_typed_tx(
+    tx_type: TxType,
+    tx_request: ethers::prelude::TransactionRequest,
+    access_list: &[(H160, Vec<H256>)],
+) -> TypedTransaction {
+    match tx_type {
+        TxType::Legacy => TypedTransaction::Legacy(tx_request),
+        TxType::AccessList => {
+            TypedTransaction::Eip2930(ethers_eip2930::Eip2930TransactionRequest::new(
+                tx_request,
+                to_ethers_access_list(access_list),
+            ))
+        }
+        TxType::DynamicFee => {
+            let mut req = ethers::prelude::Eip1559TransactionRequest::new()
+                .access_list(to_ethers_access_list(access_list));
+            req.from = tx_request.from;
+            req.to = tx_request.to;
+            req.gas = tx_request.gas;
+            req.value = tx_request.value;
+            req.data = tx_request.data;
+            req.nonce = tx_request.nonce;
+            req.chain_id = tx_request.chain_id;
+            req.max_fee_per_gas = tx_request.gas_price;
+            TypedTransaction::Eip1559(req)
+        }
+    }
+}
+
+/// Returns the journal name of the transaction type.
+fn tx_type_name(tx: &TypedTransaction) -> &'static str {
+    match tx {
+        TypedTransaction::Legacy(_) => "legacy",
+        TypedTransaction::Eip2930(_) => "eip2930",
+        TypedTransaction::Eip1559(_) => "eip1559",
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct Evm<'a, T, S>
@@ -24,6 +129,19 @@ where
     /// Either "C" or subnet_evm chain Id.
     pub chain_id_alias: String,
     pub chain_rpc_url_path: String,
+
+    /// Wait between each poll intervals for receipts and confirmations.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling receipts and confirmations.
+    pub poll_timeout: Duration,
+
+    /// Transaction type for the transactions built by the wallet
+    /// (e.g., "deploy").
+    pub tx_type: TxType,
+
+    /// Native token of the chain, AVAX unless set with "native_token"
+    /// or fetched with "fetch_native_token" for subnet-evm chains.
+    pub native_token: token::NativeToken,
 }
 
 impl<'a, T, S> Evm<'a, T, S>
@@ -36,8 +154,7 @@ where
     pub async fn balance_with_endpoint(&self, http_rpc: &str) -> io::Result<primitive_types::U256> {
         let resp = client_evm::get_balance(http_rpc, &self.chain_id_alias, &self.inner.eth_address)
             .await?;
-        let cur_balance = resp.result;
-        Ok(cur_balance)
+        resp.into_result()
     }
 
     /// Fetches the current balance of the wallet owner from all endpoints
@@ -57,6 +174,65 @@ where
             .await
     }
 
+    /// Fetches the current balance of the wallet owner, labeled with
+    /// the native token of the chain.
+    pub async fn native_balance(&self) -> io::Result<token::Balance> {
+        let amount = self.balance().await?;
+        Ok(token::Balance {
+            amount,
+            token: self.native_token.clone(),
+        })
+    }
+
+    /// Fetches the symbol and decimals with the ERC-20 "symbol()" and
+    /// "decimals()" calls to the contract that describes the native token
+    /// (e.g., a wrapped native token deployed on the subnet-evm chain),
+    /// and uses them for "native_balance".
+    pub async fn fetch_native_token(
+        mut self,
+        metadata_contract: primitive_types::H160,
+    ) -> io::Result<Evm<'a, T, S>> {
+        let symbol = self
+            .eth_call(metadata_contract, &token::SYMBOL_SELECTOR)
+            .await?;
+        let decimals = self
+            .eth_call(metadata_contract, &token::DECIMALS_SELECTOR)
+            .await?;
+        self.native_token = token::NativeToken::new(
+            token::decode_symbol(&symbol)?,
+            token::decode_decimals(&decimals)?,
+        );
+        log::info!(
+            "native token of chain {} is {:?}",
+            self.chain_id_alias,
+            self.native_token
+        );
+        Ok(self)
+    }
+
+    /// Runs "eth_call" against the latest state and returns the output.
+    async fn eth_call(&self, to: primitive_types::H160, data: &[u8]) -> io::Result<Vec<u8>> {
+        let tx_request = ethers::prelude::TransactionRequest::new()
+            .to(ethers::prelude::H160::from(to.as_fixed_bytes()))
+            .data(data.to_vec());
+        let output = cancel::run(async {
+            self.providers[self.inner.pick_http_rpc().0]
+                .call(&TypedTransaction::Legacy(tx_request), None)
+                .await
+                .map_err(|e| revert::to_io_error("eth_call", e))
+        })
+        .await?;
+        Ok(output.to_vec())
+    }
+
+    /// Sets the native token of the chain (e.g., from the subnet-evm
+    /// chain config of the deployer).
+    #[must_use]
+    pub fn native_token(mut self, native_token: token::NativeToken) -> Self {
+        self.native_token = native_token;
+        self
+    }
+
     /// Fetches the current balance of the wallet owner.
     pub async fn latest_nonce(&self) -> io::Result<primitive_types::U256> {
         let resp = client_evm::get_latest_transaction_count(
@@ -65,7 +241,243 @@ where
             &self.inner.eth_address,
         )
         .await?;
-        Ok(resp.result)
+        resp.into_result()
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the transaction type.
+    #[must_use]
+    pub fn tx_type(mut self, tx_type: TxType) -> Self {
+        self.tx_type = tx_type;
+        self
+    }
+
+    /// Returns the access list that the transaction would use, and the gas
+    /// used with the access list, with "eth_createAccessList".
+    pub async fn create_access_list(
+        &self,
+        tx: &TypedTransaction,
+    ) -> io::Result<(AccessList, primitive_types::U256)> {
+        let resp = cancel::run(async {
+            self.providers[self.inner.pick_http_rpc().0]
+                .create_access_list(tx, None)
+                .await
+                .map_err(|e| revert::to_io_error("create_access_list", e))
+        })
+        .await?;
+        Ok((
+            from_ethers_access_list(&resp.access_list),
+            primitive_types::U256::from(resp.gas_used.as_u128()),
+        ))
+    }
+
+    /// Signs and sends the transaction, and waits for its receipt plus
+    /// "confirmations" number of accepted blocks on top of the receipt block.
+    /// If the receipt block hash changes (or the receipt disappears) while
+    /// waiting, the transaction is treated as reorged and is re-confirmed
+    /// from its new block. Returns the final receipt.
+    pub async fn send_and_confirm(
+        &self,
+        tx: impl Into<ethers::types::transaction::eip2718::TypedTransaction> + Send + Sync,
+        confirmations: u64,
+    ) -> io::Result<ethers::prelude::TransactionReceipt> {
+        self.send_and_confirm_via(self.inner.pick_http_rpc(), tx, confirmations)
+            .await
+    }
+
+    /// Same as "send_and_confirm", but on the endpoint already picked by the
+    /// caller (e.g., the one that estimated the gas).
+    async fn send_and_confirm_via(
+        &self,
+        picked_http_rpc: (usize, String),
+        tx: impl Into<ethers::types::transaction::eip2718::TypedTransaction> + Send + Sync,
+        confirmations: u64,
+    ) -> io::Result<ethers::prelude::TransactionReceipt> {
+        let signer = ethers::prelude::SignerMiddleware::new(
+            self.providers[picked_http_rpc.0].clone(),
+            self.eth_signer
+                .clone()
+                .with_chain_id(self.chain_id.as_u64()),
+        );
+        let tx: TypedTransaction = tx.into();
+        let tx_type = tx_type_name(&tx);
+        let pending_tx = cancel::run(async {
+            signer
+                .send_transaction(tx, None)
+                .await
+                .map_err(|e| revert::to_io_error("send_transaction", e))
+        })
+        .await?;
+        let tx_hash = pending_tx.tx_hash();
+        self.record_sent(tx_hash, tx_type, &picked_http_rpc.1);
+        log::info!(
+            "sent transaction {:?} via {}, waiting for {} confirmation(s)",
+            tx_hash,
+            picked_http_rpc.1,
+            confirmations
+        );
+
+        self.confirm(picked_http_rpc.0, tx_hash, confirmations)
+            .await
+    }
+
+    /// Sends the transaction that was RLP-encoded and signed outside of the
+    /// wallet (e.g., by a hardware wallet or a remote signer), and waits for
+    /// its receipt plus "confirmations" number of accepted blocks, the same
+    /// as "send_and_confirm".
+    pub async fn issue_raw(
+        &self,
+        signed_tx_bytes: &[u8],
+        confirmations: u64,
+    ) -> io::Result<ethers::prelude::TransactionReceipt> {
+        if signed_tx_bytes.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "empty signed transaction bytes",
+            ));
+        }
+
+        let picked_http_rpc = self.inner.pick_http_rpc();
+        let expected_tx_hash =
+            ethers::prelude::H256::from(crate::hash::keccak256(signed_tx_bytes).to_fixed_bytes());
+        let pending_tx = cancel::run(async {
+            self.providers[picked_http_rpc.0]
+                .send_raw_transaction(ethers::prelude::Bytes::from(signed_tx_bytes.to_vec()))
+                .await
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("failed to send_raw_transaction '{}'", e),
+                    )
+                })
+        })
+        .await?;
+        let tx_hash = pending_tx.tx_hash();
+        if tx_hash != expected_tx_hash {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "sent transaction hash {:?} does not match the signed bytes {:?}",
+                    tx_hash, expected_tx_hash
+                ),
+            ));
+        }
+        self.record_sent(tx_hash, "raw", &picked_http_rpc.1);
+        log::info!(
+            "sent raw transaction {:?} via {}, waiting for {} confirmation(s)",
+            tx_hash,
+            picked_http_rpc.1,
+            confirmations
+        );
+
+        self.confirm(picked_http_rpc.0, tx_hash, confirmations)
+            .await
+    }
+
+    /// Records the transaction sent to the endpoint in the journal, if any.
+    /// The amounts are left unknown, as the EVM values are in wei.
+    fn record_sent(&self, tx_hash: H256, tx_type: &str, http_rpc: &str) {
+        self.inner.record_issued(&journal::Entry {
+            tx_id: format!("{:?}", tx_hash),
+            chain: self.chain_id_alias.clone(),
+            tx_type: tx_type.to_string(),
+            http_rpc: http_rpc.to_string(),
+            issued_at: journal::now_unix_millis(),
+            ..Default::default()
+        });
+    }
+
+    /// Polls the provider for the transaction receipt until "confirmations"
+    /// number of blocks are accepted on top of the receipt block.
+    async fn confirm(
+        &self,
+        provider_index: usize,
+        tx_hash: ethers::prelude::H256,
+        confirmations: u64,
+    ) -> io::Result<ethers::prelude::TransactionReceipt> {
+        wait_for_confirmations(
+            &self.providers[provider_index],
+            tx_hash,
+            confirmations,
+            self.poll_interval,
+            self.poll_timeout,
+        )
+        .await
+    }
+
+    /// Deploys a contract with the creation bytecode and the ABI-encoded
+    /// constructor arguments (appended to the bytecode), and returns the
+    /// deployed contract address once the creation receipt is available.
+    /// The gas limit is estimated against the latest state.
+    pub async fn deploy(
+        &self,
+        bytecode: impl AsRef<[u8]>,
+        constructor_args: impl AsRef<[u8]>,
+    ) -> io::Result<primitive_types::H160> {
+        let mut data = bytecode.as_ref().to_vec();
+        data.extend_from_slice(constructor_args.as_ref());
+        if data.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "empty contract bytecode",
+            ));
+        }
+
+        let tx_request = ethers::prelude::TransactionRequest::new()
+            .from(ethers::prelude::H160::from(
+                self.inner.h160_address.as_fixed_bytes(),
+            ))
+            .chain_id(ethers::prelude::U64::from(self.chain_id.as_u64()))
+            .data(data);
+        let mut typed_tx = new_typed_tx(self.tx_type, tx_request, &[]);
+
+        // estimates and sends on the same endpoint, which may be behind the others
+        let picked_http_rpc = self.inner.pick_http_rpc();
+        let estimated_gas = cancel::run(async {
+            self.providers[picked_http_rpc.0]
+                .estimate_gas(&typed_tx, None)
+                .await
+                .map_err(|e| revert::to_io_error("estimate_gas", e))
+        })
+        .await?;
+        log::info!(
+            "deploying contract ({} bytes, {:?}) with estimated gas {}",
+            typed_tx.data().map(|d| d.len()).unwrap_or_default(),
+            self.tx_type,
+            estimated_gas
+        );
+        typed_tx.set_gas(estimated_gas);
+
+        let receipt = self
+            .send_and_confirm_via(picked_http_rpc, typed_tx, 0)
+            .await?;
+        let contract_address = receipt.contract_address.ok_or_else(|| {
+            Error::new(
+                ErrorKind::Other,
+                format!(
+                    "no contract address in receipt for {:?}",
+                    receipt.transaction_hash
+                ),
+            )
+        })?;
+        log::info!("deployed contract at {:?}", contract_address);
+
+        Ok(primitive_types::H160::from(
+            contract_address.as_fixed_bytes(),
+        ))
     }
 
     #[must_use]
@@ -77,4 +489,387 @@ where
     pub fn eip1559(&self) -> eip1559::Tx<'a, T, S> {
         eip1559::Tx::new(self)
     }
+
+    /// Returns the legacy builder set to the EIP-2930 (type 1) transaction,
+    /// which pays the gas price and carries an access list.
+    /// ref. https://eips.ethereum.org/EIPS/eip-2930
+    #[must_use]
+    pub fn eip2930(&self) -> legacy::Tx<'a, T, S> {
+        // the access list costs more than the plain transfer gas
+        let mut tx = legacy::Tx::new(self).tx_type(TxType::AccessList);
+        tx.gas_limit = None;
+        tx
+    }
+}
+
+/// Polls the provider for the transaction receipt until "confirmations"
+/// number of blocks are accepted on top of the receipt block.
+/// If the receipt block hash changes (or the receipt disappears) while
+/// waiting, the confirmations are counted again from the new block.
+async fn wait_for_confirmations<M>(
+    provider: &M,
+    tx_hash: ethers::prelude::H256,
+    confirmations: u64,
+    poll_interval: Duration,
+    poll_timeout: Duration,
+) -> io::Result<ethers::prelude::TransactionReceipt>
+where
+    M: Middleware,
+{
+    let start = Instant::now();
+    let mut last_block_hash: Option<ethers::prelude::H256> = None;
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed.gt(&poll_timeout) {
+            return Err(Error::new(
+                ErrorKind::TimedOut,
+                format!(
+                    "failed to confirm transaction {:?} with {} confirmation(s) in time",
+                    tx_hash, confirmations
+                ),
+            ));
+        }
+
+        let receipt = cancel::run(async {
+            provider
+                .get_transaction_receipt(tx_hash)
+                .await
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Other,
+                        format!("failed get_transaction_receipt '{}'", e),
+                    )
+                })
+        })
+        .await?;
+        let receipt = match receipt {
+            Some(r) if r.block_number.is_some() => r,
+            _ => {
+                if last_block_hash.is_some() {
+                    log::warn!(
+                        "transaction {:?} receipt disappeared (reorged out), waiting for re-inclusion",
+                        tx_hash
+                    );
+                    last_block_hash = None;
+                }
+                log::debug!(
+                    "transaction {:?} not included yet (elapsed {:?})",
+                    tx_hash,
+                    elapsed
+                );
+                cancel::sleep(poll_interval).await?;
+                continue;
+            }
+        };
+
+        if let Some(prev) = last_block_hash {
+            if receipt.block_hash != Some(prev) {
+                log::warn!(
+                    "transaction {:?} reorged from block {:?} to {:?}",
+                    tx_hash,
+                    prev,
+                    receipt.block_hash
+                );
+            }
+        }
+        last_block_hash = receipt.block_hash;
+
+        let receipt_block = receipt.block_number.unwrap().as_u64();
+        let latest_block = cancel::run(async {
+            provider.get_block_number().await.map_err(|e| {
+                Error::new(ErrorKind::Other, format!("failed get_block_number '{}'", e))
+            })
+        })
+        .await?;
+        let latest_block = latest_block.as_u64();
+        if latest_block >= receipt_block + confirmations {
+            if receipt.status != Some(ethers::prelude::U64::from(1)) {
+                return Err(Error::new(
+                    ErrorKind::Other,
+                    format!(
+                        "transaction {:?} failed with status {:?}",
+                        tx_hash, receipt.status
+                    ),
+                ));
+            }
+            log::info!(
+                "transaction {:?} confirmed at block {} (latest {})",
+                tx_hash,
+                receipt_block,
+                latest_block
+            );
+            return Ok(receipt);
+        }
+
+        log::debug!(
+            "transaction {:?} at block {}, latest {} (waiting for {} confirmation(s), elapsed {:?})",
+            tx_hash,
+            receipt_block,
+            latest_block,
+            confirmations,
+            elapsed
+        );
+        cancel::sleep(poll_interval).await?;
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::test_new_typed_tx --exact --show-output
+#[test]
+fn test_new_typed_tx() {
+    let access_list: AccessList = vec![(
+        H160::from_low_u64_be(0x0200000000000000),
+        vec![H256::from_low_u64_be(1), H256::from_low_u64_be(2)],
+    )];
+    assert_eq!(
+        from_ethers_access_list(&to_ethers_access_list(&access_list)),
+        access_list
+    );
+
+    let tx_request = ethers::prelude::TransactionRequest::new()
+        .gas_price(25_000_000_000_u64)
+        .data(vec![1, 2, 3]);
+    let tx = new_typed_tx(TxType::Legacy, tx_request.clone(), &access_list);
+    assert!(matches!(tx, TypedTransaction::Legacy(_)));
+    assert!(tx.access_list().is_none());
+
+    let tx = new_typed_tx(TxType::AccessList, tx_request.clone(), &access_list);
+    assert!(matches!(tx, TypedTransaction::Eip2930(_)));
+    assert_eq!(tx.access_list().unwrap().0.len(), 1);
+
+    let tx = new_typed_tx(TxType::DynamicFee, tx_request, &access_list);
+    match &tx {
+        TypedTransaction::Eip1559(req) => {
+            assert_eq!(req.max_fee_per_gas, Some(25_000_000_000_u64.into()));
+            assert_eq!(req.max_priority_fee_per_gas, None);
+            assert_eq!(req.access_list.0[0].storage_keys.len(), 2);
+        }
+        _ => panic!("unexpected tx type"),
+    }
+    assert_eq!(tx.data().unwrap().to_vec(), vec![1, 2, 3]);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::test_wait_for_confirmations_reorg --exact --show-output
+#[tokio::test]
+async fn test_wait_for_confirmations_reorg() {
+    use ethers::prelude::{TransactionReceipt, U64};
+
+    let tx_hash = ethers::prelude::H256::from_low_u64_be(1);
+    let receipt = |block_number: u64, block_hash: u64| TransactionReceipt {
+        transaction_hash: tx_hash,
+        block_number: Some(U64::from(block_number)),
+        block_hash: Some(ethers::prelude::H256::from_low_u64_be(block_hash)),
+        status: Some(U64::from(1)),
+        ..Default::default()
+    };
+
+    // the mock pops the responses in the reverse order of the pushes
+    let (provider, mock) = ethers_providers::Provider::mocked();
+    // 3. re-included in a different block at height 11, and 12 is accepted
+    mock.push(U64::from(12)).unwrap();
+    mock.push(receipt(11, 0xb)).unwrap();
+    // 2. reorged out
+    mock.push::<Option<TransactionReceipt>, _>(None).unwrap();
+    // 1. included at height 10, with no confirmation yet
+    mock.push(U64::from(10)).unwrap();
+    mock.push(receipt(10, 0xa)).unwrap();
+
+    let confirmed = wait_for_confirmations(
+        &provider,
+        tx_hash,
+        1,
+        Duration::from_millis(1),
+        Duration::from_secs(10),
+    )
+    .await
+    .unwrap();
+    assert_eq!(confirmed.block_number, Some(U64::from(11)));
+    assert_eq!(
+        confirmed.block_hash,
+        Some(ethers::prelude::H256::from_low_u64_be(0xb))
+    );
+
+    // the receipt block hash changes between the polls
+    let (provider, mock) = ethers_providers::Provider::mocked();
+    mock.push(U64::from(13)).unwrap();
+    mock.push(receipt(12, 0xc)).unwrap();
+    mock.push(U64::from(10)).unwrap();
+    mock.push(receipt(10, 0xa)).unwrap();
+    let confirmed = wait_for_confirmations(
+        &provider,
+        tx_hash,
+        1,
+        Duration::from_millis(1),
+        Duration::from_secs(10),
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        confirmed.block_hash,
+        Some(ethers::prelude::H256::from_low_u64_be(0xc))
+    );
+
+    // times out while never confirmed
+    let (provider, mock) = ethers_providers::Provider::mocked();
+    mock.push(U64::from(10)).unwrap();
+    mock.push(receipt(10, 0xa)).unwrap();
+    let err = wait_for_confirmations(
+        &provider,
+        tx_hash,
+        1,
+        Duration::from_millis(1),
+        Duration::from_millis(0),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::test_send_cancel --exact --show-output
+#[tokio::test]
+async fn test_send_cancel() {
+    use std::sync::{Arc, Mutex};
+
+    // accepts the connections but never replies
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let http_rpc = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut conns = Vec::new();
+        while let Ok((conn, _)) = listener.accept().await {
+            conns.push(conn);
+        }
+    });
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let eth_signer = ethers_signers::LocalWallet::from(k.signing_key());
+    let inner = client::wallet::Wallet {
+        keychain: key::secp256k1::keychain::Keychain::new(vec![k.clone()]),
+        http_rpcs: vec![http_rpc.clone()],
+        http_rpc_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: String::from("mainnet"),
+        hrp: String::from("avax"),
+        h160_address: k.to_public_key().to_h160(),
+        x_address: String::new(),
+        p_address: String::new(),
+        c_address: String::new(),
+        short_address: k.to_public_key().to_short_id().unwrap(),
+        eth_address: k.to_public_key().eth_address(),
+        blockchain_id_x: crate::ids::Id::empty(),
+        blockchain_id_p: crate::ids::Id::empty(),
+        blockchain_id_c: crate::ids::Id::empty(),
+        chain_id_c: primitive_types::U256::from(43114),
+        avax_asset_id: crate::ids::Id::empty(),
+        tx_fee: 0,
+        add_primary_network_validator_fee: 0,
+        create_subnet_tx_fee: 0,
+        create_blockchain_tx_fee: 0,
+        journal: None,
+        issuance_guard: None,
+        issuance_queue: None,
+    };
+    let evm = inner
+        .evm(
+            &eth_signer,
+            String::from("C"),
+            primitive_types::U256::from(43114),
+        )
+        .unwrap();
+
+    let token = cancel::CancellationToken::new();
+    let child = token.clone();
+    tokio::spawn(async move {
+        crate::client::time::sleep(Duration::from_millis(20)).await;
+        child.cancel();
+    });
+    let tx = ethers::prelude::TransactionRequest::new()
+        .to(ethers::prelude::H160::zero())
+        .value(1);
+    let err = cancel::scope(
+        cancel::Cancel::new().token(token),
+        evm.send_and_confirm(tx, 0),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Interrupted);
+
+    let err = cancel::scope(
+        cancel::Cancel::new().timeout(Duration::from_millis(20)),
+        evm.issue_raw(&[1, 2, 3], 0),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::test_record_sent --exact --show-output
+#[test]
+fn test_record_sent() {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Debug, Default)]
+    struct Recorder(Mutex<Vec<journal::Entry>>);
+    impl journal::TxJournal for Recorder {
+        fn record(&self, entry: &journal::Entry) -> io::Result<()> {
+            self.0
+                .lock()
+                .expect("unexpected poisoned lock")
+                .push(entry.clone());
+            Ok(())
+        }
+    }
+
+    let recorder = Arc::new(Recorder::default());
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let eth_signer = ethers_signers::LocalWallet::from(k.signing_key());
+    let inner = client::wallet::Wallet {
+        keychain: key::secp256k1::keychain::Keychain::new(vec![k.clone()]),
+        http_rpcs: vec![String::from("http://localhost:9650")],
+        http_rpc_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: String::from("mainnet"),
+        hrp: String::from("avax"),
+        h160_address: k.to_public_key().to_h160(),
+        x_address: String::new(),
+        p_address: String::new(),
+        c_address: String::new(),
+        short_address: k.to_public_key().to_short_id().unwrap(),
+        eth_address: k.to_public_key().eth_address(),
+        blockchain_id_x: crate::ids::Id::empty(),
+        blockchain_id_p: crate::ids::Id::empty(),
+        blockchain_id_c: crate::ids::Id::empty(),
+        chain_id_c: primitive_types::U256::from(43114),
+        avax_asset_id: crate::ids::Id::empty(),
+        tx_fee: 0,
+        add_primary_network_validator_fee: 0,
+        create_subnet_tx_fee: 0,
+        create_blockchain_tx_fee: 0,
+        journal: Some(recorder.clone()),
+        issuance_guard: None,
+        issuance_queue: None,
+    };
+    let evm = inner
+        .evm(
+            &eth_signer,
+            String::from("C"),
+            primitive_types::U256::from(43114),
+        )
+        .unwrap();
+
+    let tx = new_typed_tx(
+        TxType::DynamicFee,
+        ethers::prelude::TransactionRequest::new(),
+        &[],
+    );
+    let tx_hash = H256::from_low_u64_be(0xab);
+    evm.record_sent(tx_hash, tx_type_name(&tx), "http://localhost:9650");
+
+    let entries = recorder.0.lock().expect("unexpected poisoned lock");
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].tx_id, format!("{:?}", tx_hash));
+    assert!(entries[0].tx_id.ends_with("00ab"));
+    assert_eq!(
+        (entries[0].chain.as_str(), entries[0].tx_type.as_str()),
+        ("C", "eip1559")
+    );
+    assert!(entries[0].issued_at > 0);
 }
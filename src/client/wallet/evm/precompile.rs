@@ -0,0 +1,124 @@
+//! Calls to the subnet-evm stateful precompiles, signed by the wallet,
+//! which must have the admin (or manager) role of the precompile allow list.
+//!
+//! ```ignore
+//! use avalanche_types::subnet_evm::precompile::{self, Role};
+//!
+//! evm.set_allow_list_role(precompile::TX_ALLOW_LIST_ADDRESS, user, Role::Enabled).await?;
+//! assert_eq!(evm.read_allow_list(precompile::TX_ALLOW_LIST_ADDRESS, user).await?, Role::Enabled);
+//! ```
+
+use std::io;
+
+use crate::{
+    client::{
+        cancel,
+        wallet::evm::{new_typed_tx, revert, Evm},
+    },
+    key,
+    subnet_evm::{
+        genesis::FeeConfig,
+        precompile::{self, Role},
+    },
+};
+use ethers_providers::Middleware;
+use primitive_types::{H160, H256, U256};
+
+impl<'a, T, S> Evm<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    /// Sends the calldata to the precompile with the estimated gas limit,
+    /// so that a call the wallet is not allowed to make fails before it is
+    /// sent, and returns the transaction hash once accepted.
+    pub async fn call_precompile(
+        &self,
+        precompile_address: H160,
+        data: Vec<u8>,
+    ) -> io::Result<H256> {
+        let tx_request = ethers::prelude::TransactionRequest::new()
+            .from(ethers::prelude::H160::from(
+                self.inner.h160_address.as_fixed_bytes(),
+            ))
+            .to(ethers::prelude::H160::from(
+                precompile_address.as_fixed_bytes(),
+            ))
+            .chain_id(ethers::prelude::U64::from(self.chain_id.as_u64()))
+            .data(data);
+        let mut typed_tx = new_typed_tx(self.tx_type, tx_request, &[]);
+
+        let estimated_gas = cancel::run(async {
+            self.providers[self.inner.pick_http_rpc().0]
+                .estimate_gas(&typed_tx, None)
+                .await
+                .map_err(|e| revert::to_io_error("estimate_gas", e))
+        })
+        .await?;
+        typed_tx.set_gas(estimated_gas);
+
+        let receipt = self.send_and_confirm(typed_tx, 0).await?;
+        Ok(H256(receipt.transaction_hash.0))
+    }
+
+    /// Assigns the role to the address in the allow list of the precompile
+    /// (e.g., "precompile::TX_ALLOW_LIST_ADDRESS").
+    pub async fn set_allow_list_role(
+        &self,
+        precompile_address: H160,
+        addr: H160,
+        role: Role,
+    ) -> io::Result<H256> {
+        log::info!(
+            "setting {:?} role for {:?} on precompile {:?}",
+            role,
+            addr,
+            precompile_address
+        );
+        self.call_precompile(precompile_address, precompile::encode_set_role(role, &addr))
+            .await
+    }
+
+    /// Returns the role of the address in the allow list of the precompile.
+    pub async fn read_allow_list(&self, precompile_address: H160, addr: H160) -> io::Result<Role> {
+        let output = self
+            .eth_call(
+                precompile_address,
+                &precompile::encode_read_allow_list(&addr),
+            )
+            .await?;
+        precompile::decode_role(&output)
+    }
+
+    /// Sets the fee configuration of the chain through the fee manager.
+    pub async fn set_fee_config(&self, cfg: &FeeConfig) -> io::Result<H256> {
+        log::info!("setting fee config {:?}", cfg);
+        self.call_precompile(
+            precompile::FEE_MANAGER_ADDRESS,
+            precompile::encode_set_fee_config(cfg)?,
+        )
+        .await
+    }
+
+    /// Returns the current fee configuration from the fee manager.
+    pub async fn fee_config(&self) -> io::Result<FeeConfig> {
+        let output = self
+            .eth_call(
+                precompile::FEE_MANAGER_ADDRESS,
+                &precompile::encode_get_fee_config(),
+            )
+            .await?;
+        precompile::decode_fee_config(&output)
+    }
+
+    /// Mints the native coin to the address through the native minter.
+    pub async fn mint_native_coin(&self, addr: H160, amount: U256) -> io::Result<H256> {
+        log::info!("minting {} native coin to {:?}", amount, addr);
+        self.call_precompile(
+            precompile::CONTRACT_NATIVE_MINTER_ADDRESS,
+            precompile::encode_mint_native_coin(&addr, amount),
+        )
+        .await
+    }
+}
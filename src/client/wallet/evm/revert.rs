@@ -0,0 +1,281 @@
+//! Revert reasons of the failed EVM calls and transactions.
+//!
+//! The node returns the revert data in the JSON-RPC error (e.g.,
+//! {"code":3,"message":"execution reverted: not owner","data":"0x08c379a0..."}),
+//! which is taken out of the ethers provider error (see "ProviderRpcError").
+//! ref. https://docs.soliditylang.org/en/latest/control-structures.html#revert
+
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::client::wallet::evm::token;
+use ethers::middleware::signer::SignerMiddlewareError;
+use ethers::prelude::{Middleware, Signer};
+use ethers_providers::{HttpClientError, ProviderError};
+use primitive_types::U256;
+use thiserror::Error;
+
+/// "Error(string)" selector of "require" and "revert" with a message.
+pub const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// "Panic(uint256)" selector of the failed "assert", overflow, etc.
+pub const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Decoded revert data.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Reason {
+    /// Reverted without data (e.g., "revert()").
+    Empty,
+    /// "Error(string)" message.
+    Message(String),
+    /// "Panic(uint256)" code (e.g., 0x11 for arithmetic overflow).
+    Panic(U256),
+    /// Custom error (e.g., "error NotOwner(address)") with the ABI-encoded
+    /// arguments, to be decoded against the contract ABI.
+    Custom { selector: [u8; 4], data: Vec<u8> },
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Empty => write!(f, "no reason"),
+            Reason::Message(msg) => write!(f, "'{}'", msg),
+            Reason::Panic(code) => write!(f, "panic code 0x{:x}", code),
+            Reason::Custom { selector, data } => write!(
+                f,
+                "custom error 0x{} ({} bytes)",
+                hex::encode(selector),
+                data.len()
+            ),
+        }
+    }
+}
+
+/// Decodes the revert data returned by the node.
+pub fn decode(d: &[u8]) -> Reason {
+    if d.len() < 4 {
+        return Reason::Empty;
+    }
+    let mut selector = [0_u8; 4];
+    selector.copy_from_slice(&d[..4]);
+    let args = &d[4..];
+
+    if selector == ERROR_SELECTOR {
+        if let Ok(msg) = token::decode_string(args) {
+            return Reason::Message(msg);
+        }
+    }
+    if selector == PANIC_SELECTOR && args.len() >= 32 {
+        return Reason::Panic(U256::from_big_endian(&args[..32]));
+    }
+    Reason::Custom {
+        selector,
+        data: args.to_vec(),
+    }
+}
+
+/// Error of a reverted call or transaction, returned as the inner error of
+/// "io::Error" by the "wallet::evm" methods (see "revert_error").
+#[derive(Debug, Clone, Eq, PartialEq, Error)]
+#[error("failed to {method}, execution reverted with {reason}")]
+pub struct RevertError {
+    /// Provider method that failed (e.g., "estimate_gas").
+    pub method: &'static str,
+    pub reason: Reason,
+}
+
+/// Returns the revert error in the I/O error, if the call reverted.
+pub fn revert_error(e: &io::Error) -> Option<&RevertError> {
+    e.get_ref()?.downcast_ref::<RevertError>()
+}
+
+/// JSON-RPC error response of the node, borrowed from the provider error.
+#[derive(Debug, Clone, Copy)]
+pub struct RpcResponseError<'a> {
+    pub code: i64,
+    pub message: &'a str,
+    pub data: Option<&'a serde_json::Value>,
+}
+
+/// Provider error that may carry the JSON-RPC error response of the node.
+pub trait ProviderRpcError: fmt::Display {
+    fn rpc_response_error(&self) -> Option<RpcResponseError<'_>>;
+}
+
+impl ProviderRpcError for HttpClientError {
+    fn rpc_response_error(&self) -> Option<RpcResponseError<'_>> {
+        match self {
+            HttpClientError::JsonRpcError(e) => Some(RpcResponseError {
+                code: e.code,
+                message: &e.message,
+                data: e.data.as_ref(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl ProviderRpcError for ProviderError {
+    fn rpc_response_error(&self) -> Option<RpcResponseError<'_>> {
+        match self {
+            ProviderError::JsonRpcClientError(e) => {
+                e.downcast_ref::<HttpClientError>()?.rpc_response_error()
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<M, S> ProviderRpcError for SignerMiddlewareError<M, S>
+where
+    M: Middleware,
+    M::Error: ProviderRpcError,
+    S: Signer,
+{
+    fn rpc_response_error(&self) -> Option<RpcResponseError<'_>> {
+        match self {
+            SignerMiddlewareError::MiddlewareError(e) => e.rpc_response_error(),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the revert reason of the error response, if the call reverted.
+///
+/// The hex "data" is preferred as it carries the custom errors, falling
+/// back to the "execution reverted: [reason]" message of the nodes
+/// that do not return data.
+pub fn from_response(resp: &RpcResponseError<'_>) -> Option<Reason> {
+    let rest = resp.message.strip_prefix("execution reverted")?;
+
+    // some nodes nest the revert data (e.g., {"data":{"data":"0x..."}})
+    let data = match resp.data {
+        Some(serde_json::Value::Object(obj)) => obj.get("data"),
+        data => data,
+    };
+    if let Some(d) = data
+        .and_then(|v| v.as_str())
+        .and_then(|s| hex::decode(s.trim_start_matches("0x")).ok())
+    {
+        return Some(decode(&d));
+    }
+
+    match rest.strip_prefix(": ") {
+        Some(reason) => Some(Reason::Message(reason.to_string())),
+        None => Some(Reason::Empty),
+    }
+}
+
+/// Converts the provider error of the method to an I/O error,
+/// with the "RevertError" inside if the call reverted.
+pub fn to_io_error(method: &'static str, e: impl ProviderRpcError) -> io::Error {
+    match e.rpc_response_error().as_ref().and_then(from_response) {
+        Some(reason) => {
+            log::warn!("{} reverted with {} ({})", method, reason, e);
+            Error::new(ErrorKind::Other, RevertError { method, reason })
+        }
+        None => Error::new(ErrorKind::Other, format!("failed to {} '{}'", method, e)),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::revert::test_revert --exact --show-output
+#[test]
+fn test_revert() {
+    // Error("not owner")
+    let mut d = ERROR_SELECTOR.to_vec();
+    let mut args = vec![0_u8; 96];
+    args[31] = 0x20;
+    args[63] = 9;
+    args[64..73].copy_from_slice(b"not owner");
+    d.extend_from_slice(&args);
+    assert_eq!(decode(&d), Reason::Message(String::from("not owner")));
+
+    // Panic(0x11)
+    let mut p = PANIC_SELECTOR.to_vec();
+    let mut code = vec![0_u8; 32];
+    code[31] = 0x11;
+    p.extend_from_slice(&code);
+    assert_eq!(decode(&p), Reason::Panic(U256::from(0x11)));
+
+    // error NotOwner(), selector only
+    assert_eq!(
+        decode(&[0x30, 0xcd, 0x74, 0x71]),
+        Reason::Custom {
+            selector: [0x30, 0xcd, 0x74, 0x71],
+            data: Vec::new(),
+        }
+    );
+    assert_eq!(decode(&[]), Reason::Empty);
+
+    // ethers "JsonRpcError" response of the HTTP provider
+    let rpc_error = |resp: serde_json::Value| {
+        ProviderError::JsonRpcClientError(Box::new(HttpClientError::JsonRpcError(
+            serde_json::from_value(resp).unwrap(),
+        )))
+    };
+    let reverted = rpc_error(serde_json::json!({
+        "code": 3,
+        "message": "execution reverted: not owner",
+        "data": format!("0x{}", hex::encode(&p)),
+    }));
+    // prefers the data to the message
+    assert_eq!(
+        from_response(&reverted.rpc_response_error().unwrap()),
+        Some(Reason::Panic(U256::from(0x11)))
+    );
+    let reverted_no_data = rpc_error(serde_json::json!({
+        "code": -32000,
+        "message": "execution reverted: paused",
+    }));
+    assert_eq!(
+        from_response(&reverted_no_data.rpc_response_error().unwrap()),
+        Some(Reason::Message(String::from("paused")))
+    );
+    let reverted_empty = rpc_error(serde_json::json!({
+        "code": -32000,
+        "message": "execution reverted",
+    }));
+    assert_eq!(
+        from_response(&reverted_empty.rpc_response_error().unwrap()),
+        Some(Reason::Empty)
+    );
+    let nonce_too_low = rpc_error(serde_json::json!({
+        "code": -32000,
+        "message": "nonce too low",
+    }));
+    assert_eq!(
+        from_response(&nonce_too_low.rpc_response_error().unwrap()),
+        None
+    );
+    // the message alone does not make a revert
+    assert!(
+        ProviderError::CustomError(String::from("execution reverted"))
+            .rpc_response_error()
+            .is_none()
+    );
+
+    let e = to_io_error(
+        "estimate_gas",
+        rpc_error(serde_json::json!({
+            "code": 3,
+            "message": "execution reverted: not owner",
+            "data": format!("0x{}", hex::encode(&d)),
+        })),
+    );
+    let revert = revert_error(&e).unwrap();
+    assert_eq!(revert.method, "estimate_gas");
+    assert_eq!(revert.reason, Reason::Message(String::from("not owner")));
+    assert_eq!(
+        e.to_string(),
+        "failed to estimate_gas, execution reverted with 'not owner'"
+    );
+
+    let e = to_io_error("send_transaction", nonce_too_low);
+    assert!(revert_error(&e).is_none());
+    assert_eq!(
+        e.to_string(),
+        "failed to send_transaction '(code: -32000, message: nonce too low, data: None)'"
+    );
+}
@@ -0,0 +1,177 @@
+//! Native token of the EVM chain, which is AVAX on the C-chain but
+//! whatever the subnet chose (e.g., "WGM") on subnet-evm chains.
+
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+};
+
+use crate::units;
+use primitive_types::U256;
+
+/// ERC-20 "symbol()" selector.
+/// ref. https://eips.ethereum.org/EIPS/eip-20
+pub const SYMBOL_SELECTOR: [u8; 4] = [0x95, 0xd8, 0x9b, 0x41];
+
+/// ERC-20 "decimals()" selector.
+pub const DECIMALS_SELECTOR: [u8; 4] = [0x31, 0x3c, 0xe5, 0x67];
+
+/// Symbol and decimals of the native token.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NativeToken {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl Default for NativeToken {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl NativeToken {
+    /// AVAX with 18 decimals, as on the C-chain.
+    pub fn default() -> Self {
+        Self::new("AVAX", 18)
+    }
+
+    pub fn new(symbol: impl Into<String>, decimals: u8) -> Self {
+        Self {
+            symbol: symbol.into(),
+            decimals,
+        }
+    }
+
+    /// Formats the amount in the smallest unit with the symbol.
+    /// e.g., "1.5 AVAX".
+    pub fn format(&self, amount: U256) -> String {
+        format!(
+            "{} {}",
+            units::format_units(amount, self.decimals),
+            self.symbol
+        )
+    }
+}
+
+/// Balance in the native token.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Balance {
+    /// In the smallest unit (e.g., wei).
+    pub amount: U256,
+    pub token: NativeToken,
+}
+
+impl fmt::Display for Balance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.token.format(self.amount))
+    }
+}
+
+/// Decodes the return data of "decimals()", an ABI-encoded "uint8".
+pub fn decode_decimals(d: &[u8]) -> io::Result<u8> {
+    if d.len() < 32 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("decimals() returned {} bytes, expected 32", d.len()),
+        ));
+    }
+    let v = U256::from_big_endian(&d[..32]);
+    if v > U256::from(u8::MAX) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("decimals() returned {} beyond uint8", v),
+        ));
+    }
+    Ok(v.as_u32() as u8)
+}
+
+/// Decodes the return data of "symbol()", either an ABI-encoded "string"
+/// or, for some older tokens, a NUL-padded "bytes32".
+pub fn decode_symbol(d: &[u8]) -> io::Result<String> {
+    if d.len() == 32 {
+        let end = d.iter().position(|b| *b == 0).unwrap_or(32);
+        return String::from_utf8(d[..end].to_vec()).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("symbol() returned invalid UTF-8 '{}'", e),
+            )
+        });
+    }
+    decode_string(d).map_err(|e| Error::new(e.kind(), format!("symbol() {}", e)))
+}
+
+/// Decodes an ABI-encoded "string" (offset, length, then the UTF-8 bytes).
+/// ref. https://docs.soliditylang.org/en/latest/abi-spec.html
+pub fn decode_string(d: &[u8]) -> io::Result<String> {
+    if d.len() < 64 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("string has {} bytes, expected at least 64", d.len()),
+        ));
+    }
+
+    let offset = U256::from_big_endian(&d[..32]);
+    if offset > U256::from(d.len() - 32) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("string offset {} out of range", offset),
+        ));
+    }
+    let offset = offset.as_usize();
+    let len = U256::from_big_endian(&d[offset..offset + 32]);
+    if len > U256::from(d.len() - offset - 32) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("string length {} out of range", len),
+        ));
+    }
+    let start = offset + 32;
+    let end = start + len.as_usize();
+    String::from_utf8(d[start..end].to_vec()).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("string is invalid UTF-8 '{}'", e),
+        )
+    })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::token::test_native_token --exact --show-output
+#[test]
+fn test_native_token() {
+    let avax = NativeToken::default();
+    assert_eq!(
+        avax.format(U256::from(2_500_000_000_000_000_000_u64)),
+        "2.5 AVAX"
+    );
+
+    let balance = Balance {
+        amount: U256::from(1_230_000_u64),
+        token: NativeToken::new("USDX", 6),
+    };
+    assert_eq!(balance.to_string(), "1.23 USDX");
+
+    let mut d = vec![0_u8; 32];
+    d[31] = 6;
+    assert_eq!(decode_decimals(&d).unwrap(), 6);
+    d[30] = 1;
+    assert!(decode_decimals(&d).is_err());
+    assert!(decode_decimals(&[0_u8; 4]).is_err());
+
+    // ABI-encoded string "WGM"
+    let mut d = vec![0_u8; 96];
+    d[31] = 0x20;
+    d[63] = 3;
+    d[64..67].copy_from_slice(b"WGM");
+    assert_eq!(decode_symbol(&d).unwrap(), "WGM");
+
+    // bytes32 "MKR"
+    let mut d = vec![0_u8; 32];
+    d[..3].copy_from_slice(b"MKR");
+    assert_eq!(decode_symbol(&d).unwrap(), "MKR");
+
+    // string length beyond the data
+    let mut d = vec![0_u8; 64];
+    d[31] = 0x20;
+    d[63] = 5;
+    assert!(decode_symbol(&d).is_err());
+}
@@ -0,0 +1,313 @@
+//! Token bridging between two subnet-evm chains over Avalanche Warp Messaging.
+//!
+//! The sender on the source chain emits the token transfer through the warp
+//! precompile, the source subnet validators sign the message, and the relayer
+//! delivers the signed message to the receiver contract on the destination
+//! chain, which reads it with "getVerifiedWarpMessage".
+//!
+//! ```ignore
+//! let bridge = warp::Bridge::new(&source, &destination, receiver_contract);
+//! let delivered = bridge
+//!     .bridge(&TokenTransfer { recipient, amount })
+//!     .await?;
+//! ```
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    client::{cancel, evm as client_evm, time::Instant, wallet::evm as wallet_evm},
+    ids, key,
+    subnet_evm::warp::{self, Message, TokenTransfer, UnsignedMessage},
+};
+use primitive_types::{H160, H256, U256};
+
+/// Relays token transfers from the source chain to the destination chain.
+/// The source wallet sends the warp message, and the destination wallet
+/// (the relay key) pays for the delivery.
+#[derive(Clone, Debug)]
+pub struct Bridge<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    pub source: wallet_evm::Evm<'a, T, S>,
+    pub destination: wallet_evm::Evm<'a, T, S>,
+
+    /// Contract on the destination chain that receives the warp message.
+    pub receiver: H160,
+    /// Calldata of the delivery transaction to the receiver contract.
+    pub call_data: Vec<u8>,
+
+    /// Percentage of the source subnet stake that must sign the message.
+    pub quorum_num: u64,
+    /// Number of accepted blocks to wait for on top of each receipt.
+    pub confirmations: u64,
+}
+
+/// Outcome of a bridged token transfer.
+#[derive(Clone, Debug)]
+pub struct Delivery {
+    pub message_id: ids::Id,
+    pub message: Message,
+    pub source_tx_hash: H256,
+    pub destination_tx_hash: H256,
+}
+
+impl<'a, T, S> Bridge<'a, T, S>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    S: ethers_signers::Signer + Clone,
+    S::Error: 'static,
+{
+    /// Creates the bridge that calls "receiveWarpMessage(uint32 index)" on the
+    /// receiver contract with the predicate index 0.
+    pub fn new(
+        source: &wallet_evm::Evm<'a, T, S>,
+        destination: &wallet_evm::Evm<'a, T, S>,
+        receiver: H160,
+    ) -> Self {
+        Self {
+            source: source.clone(),
+            destination: destination.clone(),
+            receiver,
+            call_data: encode_receive_warp_message(0),
+            quorum_num: warp::DEFAULT_QUORUM_NUM,
+            confirmations: 0,
+        }
+    }
+
+    /// Sets the calldata of the delivery transaction.
+    #[must_use]
+    pub fn call_data(mut self, call_data: Vec<u8>) -> Self {
+        self.call_data = call_data;
+        self
+    }
+
+    /// Sets the percentage of the source subnet stake that must sign.
+    #[must_use]
+    pub fn quorum_num(mut self, quorum_num: u64) -> Self {
+        self.quorum_num = quorum_num;
+        self
+    }
+
+    /// Sets the number of confirmations for the source and destination receipts.
+    #[must_use]
+    pub fn confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Sends the token transfer through the source chain warp precompile,
+    /// and returns the transaction hash and the unsigned message that
+    /// the precompile emitted.
+    pub async fn send(&self, transfer: &TokenTransfer) -> io::Result<(H256, UnsignedMessage)> {
+        let tx_request = ethers::prelude::TransactionRequest::new()
+            .from(ethers::prelude::H160::from(
+                self.source.inner.h160_address.as_fixed_bytes(),
+            ))
+            .to(ethers::prelude::H160::from(
+                warp::PRECOMPILE_ADDRESS.as_fixed_bytes(),
+            ))
+            .chain_id(ethers::prelude::U64::from(self.source.chain_id.as_u64()))
+            .data(warp::encode_send_warp_message(&transfer.encode()));
+        let tx = wallet_evm::new_typed_tx(self.source.tx_type, tx_request, &[]);
+
+        log::info!(
+            "sending warp token transfer of {} to {:?} from chain {}",
+            transfer.amount,
+            transfer.recipient,
+            self.source.chain_id_alias
+        );
+        let receipt = self.source.send_and_confirm(tx, self.confirmations).await?;
+        let source_tx_hash = H256(receipt.transaction_hash.0);
+
+        let topic = ethers::prelude::H256(warp::send_warp_message_topic().0);
+        let log = receipt
+            .logs
+            .iter()
+            .find(|l| {
+                l.address.as_fixed_bytes() == warp::PRECOMPILE_ADDRESS.as_fixed_bytes()
+                    && l.topics.first() == Some(&topic)
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!("no SendWarpMessage log in {:?}", source_tx_hash),
+                )
+            })?;
+        let unsigned_message = warp::decode_send_warp_message_log(&log.data)?;
+
+        // the second indexed topic is the message Id
+        let message_id = unsigned_message.id()?;
+        if let Some(logged) = log.topics.get(2) {
+            if logged.as_bytes() != message_id.as_ref() {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "logged message Id {:?} does not match the message {}",
+                        logged, message_id
+                    ),
+                ));
+            }
+        }
+        log::info!("sent warp message {} in {:?}", message_id, source_tx_hash);
+        Ok((source_tx_hash, unsigned_message))
+    }
+
+    /// Polls the source chain for the signed message until enough validators
+    /// signed it, or the source wallet poll timeout expires.
+    pub async fn aggregate_signature(
+        &self,
+        unsigned_message: &UnsignedMessage,
+    ) -> io::Result<Message> {
+        let message_id = unsigned_message.id()?;
+        let http_rpc = self.source.inner.pick_http_rpc().1;
+
+        let start = Instant::now();
+        loop {
+            let elapsed = start.elapsed();
+            let res = client_evm::get_message_aggregate_signature(
+                &http_rpc,
+                &self.source.chain_id_alias,
+                &message_id,
+                self.quorum_num,
+            )
+            .await
+            .and_then(|resp| resp.into_result());
+            match res {
+                Ok(signed) => {
+                    let message = Message::unpack(&signed.0)?;
+                    if message.unsigned_message != *unsigned_message {
+                        return Err(Error::new(
+                            ErrorKind::InvalidData,
+                            format!(
+                                "signed message does not match the warp message {}",
+                                message_id
+                            ),
+                        ));
+                    }
+                    log::info!(
+                        "warp message {} signed by {} validator(s)",
+                        message_id,
+                        message.num_signers()
+                    );
+                    return Ok(message);
+                }
+                Err(e) => {
+                    if elapsed.gt(&self.source.poll_timeout) {
+                        return Err(Error::new(
+                            ErrorKind::TimedOut,
+                            format!(
+                                "failed to aggregate signatures for {} in time '{}'",
+                                message_id, e
+                            ),
+                        ));
+                    }
+                    log::warn!(
+                        "warp message {} not signed yet '{}' (elapsed {:?})",
+                        message_id,
+                        e,
+                        elapsed
+                    );
+                }
+            }
+            cancel::sleep(self.source.poll_interval).await?;
+        }
+    }
+
+    /// Delivers the signed message to the receiver contract, with the message
+    /// in the access list of the warp precompile as its predicate.
+    pub async fn deliver(&self, message: &Message) -> io::Result<H256> {
+        if self.destination.tx_type == wallet_evm::TxType::Legacy {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "warp message delivery requires an access list transaction type",
+            ));
+        }
+        let access_list = predicate_access_list(message)?;
+
+        let tx_request = ethers::prelude::TransactionRequest::new()
+            .from(ethers::prelude::H160::from(
+                self.destination.inner.h160_address.as_fixed_bytes(),
+            ))
+            .to(ethers::prelude::H160::from(self.receiver.as_fixed_bytes()))
+            .chain_id(ethers::prelude::U64::from(
+                self.destination.chain_id.as_u64(),
+            ))
+            .data(self.call_data.clone());
+        let tx = wallet_evm::new_typed_tx(self.destination.tx_type, tx_request, &access_list);
+
+        log::info!(
+            "delivering warp message {} to {:?} on chain {}",
+            message.unsigned_message.id()?,
+            self.receiver,
+            self.destination.chain_id_alias
+        );
+        let receipt = self
+            .destination
+            .send_and_confirm(tx, self.confirmations)
+            .await?;
+        Ok(H256(receipt.transaction_hash.0))
+    }
+
+    /// Sends, signs, and delivers the token transfer.
+    pub async fn bridge(&self, transfer: &TokenTransfer) -> io::Result<Delivery> {
+        let (source_tx_hash, unsigned_message) = self.send(transfer).await?;
+        let message = self.aggregate_signature(&unsigned_message).await?;
+        let destination_tx_hash = self.deliver(&message).await?;
+        Ok(Delivery {
+            message_id: unsigned_message.id()?,
+            message,
+            source_tx_hash,
+            destination_tx_hash,
+        })
+    }
+}
+
+/// Returns the access list that carries the signed message to the
+/// destination warp precompile.
+pub fn predicate_access_list(message: &Message) -> io::Result<wallet_evm::AccessList> {
+    let signed = message.pack()?;
+    Ok(vec![(
+        warp::PRECOMPILE_ADDRESS,
+        warp::pack_predicate(&signed),
+    )])
+}
+
+/// Returns the calldata of "receiveWarpMessage(uint32 index)", where "index"
+/// is the position of the warp predicate in the access list.
+pub fn encode_receive_warp_message(index: u32) -> Vec<u8> {
+    let mut b = warp::selector("receiveWarpMessage(uint32)").to_vec();
+    let mut word = [0u8; 32];
+    U256::from(index).to_big_endian(&mut word);
+    b.extend_from_slice(&word);
+    b
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::evm::warp::test_predicate_access_list --exact --show-output
+#[test]
+fn test_predicate_access_list() {
+    let message = Message {
+        unsigned_message: UnsignedMessage {
+            network_id: 12345,
+            source_chain_id: ids::Id::from_slice(&[1; 32]),
+            payload: TokenTransfer {
+                recipient: H160::from_low_u64_be(1),
+                amount: U256::from(100),
+            }
+            .encode(),
+        },
+        signers: vec![1],
+        signature: [2; warp::BLS_SIGNATURE_LEN],
+    };
+    let access_list = predicate_access_list(&message).unwrap();
+    assert_eq!(access_list.len(), 1);
+    assert_eq!(access_list[0].0, warp::PRECOMPILE_ADDRESS);
+    let signed = warp::unpack_predicate(&access_list[0].1).unwrap();
+    assert_eq!(Message::unpack(&signed).unwrap(), message);
+
+    let call_data = encode_receive_warp_message(3);
+    assert_eq!(call_data.len(), 36);
+    assert_eq!(call_data[35], 3);
+}
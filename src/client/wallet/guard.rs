@@ -0,0 +1,123 @@
+//! Guard against issuing the same signed transaction twice.
+//!
+//! A batch job that retries on an ambiguous network error (e.g., a timeout
+//! after the node received the request) would otherwise issue the identical
+//! transaction again. The guard remembers each attempted transaction ID for
+//! the window, and refuses another attempt within the window unless it is
+//! forced (e.g., after "platform.getTxStatus" shows that the node never
+//! received it). Set it with "wallet::Builder::issuance_guard".
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Error, ErrorKind},
+    sync::Mutex,
+};
+
+use crate::{
+    client::time::{Duration, Instant},
+    ids,
+};
+
+/// Long enough to cover the acceptance of a transaction.
+pub const DEFAULT_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug)]
+pub struct IssuanceGuard {
+    pub window: Duration,
+    attempted: Mutex<HashMap<ids::Id, Instant>>,
+    forced: Mutex<HashSet<ids::Id>>,
+}
+
+impl Default for IssuanceGuard {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl IssuanceGuard {
+    pub fn default() -> Self {
+        Self::new(DEFAULT_WINDOW)
+    }
+
+    pub fn new(window: Duration) -> Self {
+        Self {
+            window,
+            attempted: Mutex::new(HashMap::new()),
+            forced: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Records the issuance attempt of the transaction, or fails with
+    /// "AlreadyExists" if it was attempted within the window and not forced.
+    pub fn check(&self, tx_id: &ids::Id) -> io::Result<()> {
+        let now = Instant::now();
+        let mut attempted = self.attempted.lock().expect("unexpected poisoned lock");
+        attempted.retain(|_, at| now.duration_since(*at) < self.window);
+
+        if let Some(at) = attempted.get(tx_id) {
+            let forced = self
+                .forced
+                .lock()
+                .expect("unexpected poisoned lock")
+                .remove(tx_id);
+            if !forced {
+                return Err(Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!(
+                        "tx {} already issued {:?} ago (window {:?}), check its status or force the re-issuance",
+                        tx_id,
+                        now.duration_since(*at),
+                        self.window
+                    ),
+                ));
+            }
+            log::warn!("re-issuing tx {} as forced", tx_id);
+        }
+        attempted.insert(*tx_id, now);
+        Ok(())
+    }
+
+    /// Allows the next issuance of the transaction within the window.
+    pub fn force(&self, tx_id: &ids::Id) {
+        self.forced
+            .lock()
+            .expect("unexpected poisoned lock")
+            .insert(*tx_id);
+    }
+
+    /// Returns true if the transaction was attempted within the window.
+    pub fn is_attempted(&self, tx_id: &ids::Id) -> bool {
+        let attempted = self.attempted.lock().expect("unexpected poisoned lock");
+        attempted
+            .get(tx_id)
+            .map_or(false, |at| at.elapsed() < self.window)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::guard::test_issuance_guard --exact --show-output
+#[test]
+fn test_issuance_guard() {
+    let tx_id = ids::Id::from_slice(&[1; 32]);
+    let other = ids::Id::from_slice(&[2; 32]);
+
+    let guard = IssuanceGuard::default();
+    guard.check(&tx_id).unwrap();
+    assert!(guard.is_attempted(&tx_id));
+    assert_eq!(
+        guard.check(&tx_id).unwrap_err().kind(),
+        ErrorKind::AlreadyExists
+    );
+    guard.check(&other).unwrap();
+
+    // forced once
+    guard.force(&tx_id);
+    guard.check(&tx_id).unwrap();
+    assert!(guard.check(&tx_id).is_err());
+
+    // expired
+    let guard = IssuanceGuard::new(Duration::from_millis(10));
+    guard.check(&tx_id).unwrap();
+    std::thread::sleep(Duration::from_millis(20));
+    assert!(!guard.is_attempted(&tx_id));
+    guard.check(&tx_id).unwrap();
+}
@@ -0,0 +1,372 @@
+//! Issuance journal for the transactions that the wallet issues.
+//!
+//! The wallet records an entry after every successful P-chain, X-chain
+//! and EVM issuance when a journal is set via "wallet::Builder::journal".
+//! "JsonlFile" appends each entry as one JSON line, chained to the previous
+//! line by its HMAC-SHA256 with the caller's key, so that any edited,
+//! reordered or removed line fails "verify" unless the key is known.
+
+use std::{
+    fmt,
+    fs::{self, File, OpenOptions},
+    io::{self, BufRead, BufReader, Error, ErrorKind, Write},
+    path::{Path, PathBuf},
+    sync::Mutex,
+};
+
+use crate::client::{time, wallet::signed::Signed};
+use ring::hmac;
+use serde::{Deserialize, Serialize};
+
+/// Issued transaction.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    #[serde(rename = "txID")]
+    pub tx_id: String,
+    /// Chain alias (e.g., "X", "P", "C").
+    pub chain: String,
+    /// Transaction type (e.g., "transfer", "add_validator", "raw", "eip1559").
+    pub tx_type: String,
+
+    /// Sum of the consumed AVAX inputs in nano-AVAX, zero if unknown
+    /// (e.g., for the EVM transactions).
+    pub input_amount: u64,
+    /// Sum of the created AVAX outputs in nano-AVAX, zero if unknown.
+    pub output_amount: u64,
+    /// Amount burned in nano-AVAX, zero if unknown.
+    pub fee: u64,
+
+    /// Endpoint that the transaction was issued to.
+    pub http_rpc: String,
+    /// Unix timestamp in milliseconds when the transaction was signed,
+    /// zero if signed outside of the wallet.
+    pub signed_at: u64,
+    /// Unix timestamp in milliseconds when the node accepted the issuance.
+    pub issued_at: u64,
+}
+
+impl Default for Entry {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Entry {
+    pub fn default() -> Self {
+        Self {
+            tx_id: String::new(),
+            chain: String::new(),
+            tx_type: String::new(),
+            input_amount: 0,
+            output_amount: 0,
+            fee: 0,
+            http_rpc: String::new(),
+            signed_at: 0,
+            issued_at: 0,
+        }
+    }
+
+    /// Creates the entry for the transaction signed by the wallet,
+    /// and issued to the endpoint just now.
+    pub fn from_signed(signed: &Signed, http_rpc: &str) -> Self {
+        Self {
+            tx_id: signed.tx_id.to_string(),
+            chain: signed.chain.clone(),
+            tx_type: signed.tx_type.clone(),
+            input_amount: signed.input_amount,
+            output_amount: signed.output_amount,
+            fee: signed.fee,
+            http_rpc: http_rpc.to_string(),
+            signed_at: signed.signed_at,
+            issued_at: now_unix_millis(),
+        }
+    }
+}
+
+/// Returns the current Unix timestamp in milliseconds.
+pub fn now_unix_millis() -> u64 {
+    time::unix_now().as_millis() as u64
+}
+
+/// Persists the entries of the issued transactions.
+/// Implementations must be cheap enough to call inline after each issuance.
+pub trait TxJournal: fmt::Debug + Send + Sync {
+    fn record(&self, entry: &Entry) -> io::Result<()>;
+}
+
+/// One line in the JSONL journal file.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Record {
+    /// Zero-based position in the journal.
+    pub seq: u64,
+    #[serde(flatten)]
+    pub entry: Entry,
+    /// MAC of the previous record, empty for the first record.
+    pub prev_mac: String,
+    /// Hex-encoded HMAC-SHA256 of this record serialized with the empty MAC.
+    #[serde(default)]
+    pub mac: String,
+}
+
+impl Record {
+    fn new(key: &hmac::Key, seq: u64, entry: &Entry, prev_mac: &str) -> io::Result<Self> {
+        let mut r = Self {
+            seq,
+            entry: entry.clone(),
+            prev_mac: prev_mac.to_string(),
+            mac: String::new(),
+        };
+        r.mac = hex::encode(r.sign(key)?.as_ref());
+        Ok(r)
+    }
+
+    fn sign(&self, key: &hmac::Key) -> io::Result<hmac::Tag> {
+        Ok(hmac::sign(key, &self.unsigned_bytes()?))
+    }
+
+    /// Verifies the MAC in constant time.
+    fn verify(&self, key: &hmac::Key) -> io::Result<bool> {
+        let mac = match hex::decode(&self.mac) {
+            Ok(mac) => mac,
+            Err(_) => return Ok(false),
+        };
+        Ok(hmac::verify(key, &self.unsigned_bytes()?, &mac).is_ok())
+    }
+
+    fn unsigned_bytes(&self) -> io::Result<Vec<u8>> {
+        let mut unsigned = self.clone();
+        unsigned.mac = String::new();
+        serde_json::to_vec(&unsigned).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed serde_json::to_vec '{}'", e),
+            )
+        })
+    }
+}
+
+/// Returns the HMAC-SHA256 key of the journal, which must not be empty.
+fn new_key(key: &[u8]) -> io::Result<hmac::Key> {
+    if key.is_empty() {
+        return Err(Error::new(ErrorKind::InvalidInput, "empty tx journal key"));
+    }
+    Ok(hmac::Key::new(hmac::HMAC_SHA256, key))
+}
+
+/// Appends the entries to a JSONL file with the HMAC chain.
+pub struct JsonlFile {
+    path: PathBuf,
+    key: hmac::Key,
+    /// Next sequence number and the MAC of the last record.
+    last: Mutex<(u64, String)>,
+}
+
+impl fmt::Debug for JsonlFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // never print the key
+        f.debug_struct("JsonlFile")
+            .field("path", &self.path)
+            .finish_non_exhaustive()
+    }
+}
+
+impl JsonlFile {
+    /// Opens the journal file with the HMAC key, creating the file if missing.
+    /// The existing records are verified so that new records never extend
+    /// a tampered chain. The key must be kept apart from the file (e.g., in
+    /// a secret manager), as anyone with both can rewrite the chain.
+    pub fn open(path: impl AsRef<Path>, key: &[u8]) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let key = new_key(key)?;
+        let last = if path.exists() {
+            let records = verify_with(&path, &key)?;
+            match records.last() {
+                Some(r) => (r.seq + 1, r.mac.clone()),
+                None => (0, String::new()),
+            }
+        } else {
+            if let Some(parent) = path.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent)?;
+                }
+            }
+            (0, String::new())
+        };
+        log::info!("opened tx journal {} at seq {}", path.display(), last.0);
+
+        Ok(Self {
+            path,
+            key,
+            last: Mutex::new(last),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl TxJournal for JsonlFile {
+    fn record(&self, entry: &Entry) -> io::Result<()> {
+        let mut last = self.last.lock().expect("unexpected poisoned lock");
+
+        let r = Record::new(&self.key, last.0, entry, &last.1)?;
+        let mut line = serde_json::to_string(&r).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed serde_json::to_string '{}'", e),
+            )
+        })?;
+        line.push('\n');
+
+        let mut f = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        f.write_all(line.as_bytes())?;
+        f.sync_data()?;
+
+        *last = (r.seq + 1, r.mac);
+        Ok(())
+    }
+}
+
+/// Reads the journal file and verifies its HMAC chain with the key.
+/// Returns the records in order, or an error naming the first broken line.
+pub fn verify(path: impl AsRef<Path>, key: &[u8]) -> io::Result<Vec<Record>> {
+    verify_with(path.as_ref(), &new_key(key)?)
+}
+
+fn verify_with(path: &Path, key: &hmac::Key) -> io::Result<Vec<Record>> {
+    let f = File::open(path)?;
+
+    let mut records: Vec<Record> = Vec::new();
+    for (i, line) in BufReader::new(f).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let r: Record = serde_json::from_str(&line).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("line {} is not a journal record '{}'", i + 1, e),
+            )
+        })?;
+
+        let (expected_seq, expected_prev) = match records.last() {
+            Some(prev) => (prev.seq + 1, prev.mac.as_str()),
+            None => (0, ""),
+        };
+        if r.seq != expected_seq || r.prev_mac != expected_prev {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "line {} breaks the chain (seq {}, expected {})",
+                    i + 1,
+                    r.seq,
+                    expected_seq
+                ),
+            ));
+        }
+        if !r.verify(key)? {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("line {} does not match its MAC", i + 1),
+            ));
+        }
+        records.push(r);
+    }
+    Ok(records)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::journal::test_jsonl_file --exact --show-output
+#[test]
+fn test_jsonl_file() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("journal").join("txs.jsonl");
+
+    let key = b"journal key";
+    assert_eq!(
+        JsonlFile::open(&path, b"").unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+    let journal = JsonlFile::open(&path, key).unwrap();
+    for i in 0..3 {
+        journal
+            .record(&Entry {
+                tx_id: format!("tx{}", i),
+                chain: String::from("X"),
+                tx_type: String::from("transfer"),
+                input_amount: 100,
+                output_amount: 99,
+                fee: 1,
+                http_rpc: String::from("http://localhost:9650"),
+                signed_at: 1,
+                issued_at: 2,
+            })
+            .unwrap();
+    }
+    let records = verify(&path, key).unwrap();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[2].seq, 2);
+    assert_eq!(records[2].prev_mac, records[1].mac);
+    assert!(!format!("{:?}", journal).contains("journal key"));
+    assert_eq!(records[0].entry.tx_id, "tx0");
+
+    // reopening resumes the chain
+    let journal = JsonlFile::open(&path, key).unwrap();
+    journal
+        .record(&Entry {
+            tx_id: String::from("tx3"),
+            ..Entry::default()
+        })
+        .unwrap();
+    assert_eq!(verify(&path, key).unwrap().len(), 4);
+
+    // a different key fails the first line
+    let err = verify(&path, b"other key").unwrap_err();
+    assert!(err.to_string().contains("line 1 does not match its MAC"));
+    assert!(JsonlFile::open(&path, b"other key").is_err());
+
+    // tampering with an amount breaks the chain
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::write(
+        &path,
+        contents.replacen("\"outputAmount\":99", "\"outputAmount\":98", 1),
+    )
+    .unwrap();
+    let err = verify(&path, key).unwrap_err();
+    assert!(err.to_string().contains("line 1"));
+    assert!(JsonlFile::open(&path, key).is_err());
+
+    // recomputing the chain without the key does not help
+    let records: Vec<Record> = contents
+        .replacen("\"outputAmount\":99", "\"outputAmount\":98", 1)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    let attacker = new_key(b"guessed key").unwrap();
+    let mut forged = String::new();
+    let mut prev_mac = String::new();
+    for r in records.iter() {
+        let r = Record::new(&attacker, r.seq, &r.entry, &prev_mac).unwrap();
+        forged.push_str(&serde_json::to_string(&r).unwrap());
+        forged.push('\n');
+        prev_mac = r.mac;
+    }
+    fs::write(&path, forged).unwrap();
+    assert!(verify(&path, key).is_err());
+
+    // removing a line breaks the chain
+    let lines: Vec<&str> = contents.lines().collect();
+    fs::write(&path, format!("{}\n{}\n", lines[0], lines[2])).unwrap();
+    let err = verify(&path, key).unwrap_err();
+    assert!(err.to_string().contains("line 2 breaks the chain"));
+}
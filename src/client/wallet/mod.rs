@@ -1,17 +1,27 @@
+pub mod batch;
+pub mod consolidate;
+pub mod guard;
+pub mod journal;
 pub mod p;
+pub mod profile;
+pub mod queue;
+pub mod signed;
+pub mod simulate;
 pub mod x;
 
-#[cfg(feature = "evm")]
+#[cfg(feature = "wallet_evm")]
 pub mod evm;
 
 use std::{
     fmt,
+    future::Future,
     io::{self, Error, ErrorKind},
     sync::{Arc, Mutex},
 };
 
 use crate::{
-    client::{evm as api_evm, info as api_info, x as api_x},
+    client::{evm as api_evm, info as api_info, time, x as api_x},
+    constants,
     ids::{self, short},
     key, units,
 };
@@ -25,6 +35,8 @@ pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone
 
     pub network_id: u32,
     pub network_name: String,
+    /// HRP of the bech32 addresses (e.g., "avax", "fuji", "custom").
+    pub hrp: String,
 
     pub h160_address: primitive_types::H160,
     pub x_address: String,
@@ -49,6 +61,14 @@ pub struct Wallet<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone
     pub create_subnet_tx_fee: u64,
     /// Transaction fee to create a new blockchain.
     pub create_blockchain_tx_fee: u64,
+
+    /// Journal that records every P-chain and X-chain issuance, if any.
+    pub journal: Option<Arc<dyn journal::TxJournal>>,
+    /// Guard that refuses to issue the same P-chain or X-chain
+    /// transaction twice within its window, if any.
+    pub issuance_guard: Option<Arc<guard::IssuanceGuard>>,
+    /// Queue that serializes the P-chain and X-chain issuances per chain, if any.
+    pub issuance_queue: Option<Arc<queue::IssuanceQueue>>,
 }
 
 /// ref. https://doc.rust-lang.org/std/string/trait.ToString.html
@@ -62,6 +82,7 @@ where
         write!(f, "http_rpcs: {:?}\n", self.http_rpcs)?;
         write!(f, "network_id: {}\n", self.network_id)?;
         write!(f, "network_name: {}\n", self.network_name)?;
+        write!(f, "hrp: {}\n", self.hrp)?;
 
         write!(f, "h160_address: {}\n", self.h160_address)?;
         write!(f, "x_address: {}\n", self.x_address)?;
@@ -110,6 +131,64 @@ where
         (picked, http_rpc)
     }
 
+    /// Returns the owner of the outputs that the wallet key alone can spend,
+    /// which receives the change by default.
+    pub fn owner(&self) -> key::secp256k1::txs::OutputOwners {
+        key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&self.short_address))
+    }
+
+    /// Returns the validated owners, or the wallet key owner if None.
+    pub fn owner_or_default(
+        &self,
+        owners: Option<&key::secp256k1::txs::OutputOwners>,
+    ) -> io::Result<key::secp256k1::txs::OutputOwners> {
+        match owners {
+            Some(owners) => {
+                owners.verify()?;
+                Ok(owners.clone())
+            }
+            None => Ok(self.owner()),
+        }
+    }
+
+    /// Records the issued transaction in the journal, if any.
+    /// The transaction is already issued at this point, so a failure to
+    /// record is logged rather than returned, to not trigger a retry that
+    /// would issue it twice.
+    pub fn record_issued(&self, entry: &journal::Entry) {
+        if let Some(journal) = &self.journal {
+            if let Err(e) = journal.record(entry) {
+                log::error!("failed to record {} in tx journal '{}'", entry.tx_id, e);
+            }
+        }
+    }
+
+    /// Checks the issuance guard, if any, right before the transaction is
+    /// sent to the node, so that a retry of an ambiguous failure is refused.
+    pub fn guard_issuance(&self, tx_id: &ids::Id) -> io::Result<()> {
+        match &self.issuance_guard {
+            Some(guard) => guard.check(tx_id),
+            None => Ok(()),
+        }
+    }
+
+    /// Runs the issuance in the issuance queue of the chain, if any.
+    pub async fn queue_issuance<F, R>(
+        &self,
+        chain: &str,
+        tx_type: &str,
+        check_acceptance: bool,
+        issuance: F,
+    ) -> io::Result<R>
+    where
+        F: Future<Output = io::Result<R>>,
+    {
+        match &self.issuance_queue {
+            Some(queue) => queue.run(chain, tx_type, check_acceptance, issuance).await,
+            None => issuance.await,
+        }
+    }
+
     #[must_use]
     pub fn x(&self) -> x::X<T> {
         x::X {
@@ -124,9 +203,15 @@ where
         }
     }
 
+    /// Starts a batch of dependent transactions to issue in order.
+    #[must_use]
+    pub fn batch(&self) -> batch::Batch<T> {
+        batch::Batch::new()
+    }
+
     /// Set "chain_id_alias" to either "C" or subnet_evm chain Id.
     /// e.g., "/ext/bc/C/rpc"
-    #[cfg(feature = "evm")]
+    #[cfg(feature = "wallet_evm")]
     #[must_use]
     pub fn evm<'a, S>(
         &self,
@@ -159,6 +244,10 @@ where
             chain_id,
             chain_id_alias,
             chain_rpc_url_path,
+            poll_interval: time::Duration::from_millis(700),
+            poll_timeout: time::Duration::from_secs(300),
+            tx_type: evm::TxType::DynamicFee,
+            native_token: evm::token::NativeToken::default(),
         })
     }
 }
@@ -167,6 +256,10 @@ where
 pub struct Builder<T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone> {
     pub key: T,
     pub http_rpcs: Vec<String>,
+    pub journal: Option<Arc<dyn journal::TxJournal>>,
+    pub issuance_guard: Option<Arc<guard::IssuanceGuard>>,
+    pub issuance_queue: Option<Arc<queue::IssuanceQueue>>,
+    pub hrp: Option<String>,
 }
 
 impl<T> Builder<T>
@@ -177,6 +270,10 @@ where
         Self {
             http_rpcs: Vec::new(),
             key: key.clone(),
+            journal: None,
+            issuance_guard: None,
+            issuance_queue: None,
+            hrp: None,
         }
     }
 
@@ -198,6 +295,36 @@ where
         self
     }
 
+    /// Sets the journal that records every issued transaction.
+    #[must_use]
+    pub fn journal(mut self, journal: Arc<dyn journal::TxJournal>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Sets the guard that refuses to re-issue a transaction within its window.
+    #[must_use]
+    pub fn issuance_guard(mut self, issuance_guard: Arc<guard::IssuanceGuard>) -> Self {
+        self.issuance_guard = Some(issuance_guard);
+        self
+    }
+
+    /// Sets the queue that runs one issuance at a time on each chain.
+    #[must_use]
+    pub fn issuance_queue(mut self, issuance_queue: Arc<queue::IssuanceQueue>) -> Self {
+        self.issuance_queue = Some(issuance_queue);
+        self
+    }
+
+    /// Sets the HRP of the bech32 addresses, for the private networks
+    /// with their own HRP. Defaults to the HRP of the network ID
+    /// (e.g., "custom" for the unknown network IDs).
+    #[must_use]
+    pub fn hrp(mut self, hrp: String) -> Self {
+        self.hrp = Some(hrp);
+        self
+    }
+
     pub async fn build(&self) -> io::Result<Wallet<T>> {
         log::info!("building wallet with {} endpoints", self.http_rpcs.len());
 
@@ -205,18 +332,23 @@ where
         let h160_address = keychain.keys[0].h160_address();
 
         let resp = api_info::get_network_id(&self.http_rpcs[0]).await?;
-        let network_id = resp.result.unwrap().network_id;
+        let network_id = resp.into_result()?.network_id;
         let resp = api_info::get_network_name(&self.http_rpcs[0]).await?;
-        let network_name = resp.result.unwrap().network_name;
+        let network_name = resp.into_result()?.network_name;
+
+        let hrp = match &self.hrp {
+            Some(hrp) => hrp.clone(),
+            None => constants::get_hrp(network_id).to_string(),
+        };
 
         let resp = api_info::get_blockchain_id(&self.http_rpcs[0], "X").await?;
-        let blockchain_id_x = resp.result.unwrap().blockchain_id;
+        let blockchain_id_x = resp.into_result()?.blockchain_id;
 
         let resp = api_info::get_blockchain_id(&self.http_rpcs[0], "P").await?;
-        let blockchain_id_p = resp.result.unwrap().blockchain_id;
+        let blockchain_id_p = resp.into_result()?.blockchain_id;
 
         let resp = api_info::get_blockchain_id(&self.http_rpcs[0], "C").await?;
-        let blockchain_id_c = resp.result.unwrap().blockchain_id;
+        let blockchain_id_c = resp.into_result()?.blockchain_id;
 
         let resp = api_evm::chain_id(&self.http_rpcs[0], "C")
             .await
@@ -226,16 +358,14 @@ where
                     format!("failed to get chainId for C-chain '{}'", e),
                 )
             })?;
-        let chain_id_c = resp.result;
+        let chain_id_c = resp.into_result()?;
 
         let resp = api_x::get_asset_description(&self.http_rpcs[0], "AVAX").await?;
-        let resp = resp
-            .result
-            .expect("unexpected None GetAssetDescriptionResult");
+        let resp = resp.into_result()?;
         let avax_asset_id = resp.asset_id;
 
         let resp = api_info::get_tx_fee(&self.http_rpcs[0]).await?;
-        let tx_fee = resp.result.unwrap().tx_fee;
+        let tx_fee = resp.into_result()?.tx_fee;
 
         let (create_subnet_tx_fee, create_blockchain_tx_fee) = if network_id == 1 {
             // ref. "genesi/genesis_mainnet.go"
@@ -256,9 +386,10 @@ where
             network_name,
 
             h160_address,
-            x_address: self.key.hrp_address(network_id, "X").unwrap(),
-            p_address: self.key.hrp_address(network_id, "P").unwrap(),
-            c_address: self.key.hrp_address(network_id, "C").unwrap(),
+            x_address: self.key.custom_hrp_address(&hrp, "X")?,
+            p_address: self.key.custom_hrp_address(&hrp, "P")?,
+            c_address: self.key.custom_hrp_address(&hrp, "C")?,
+            hrp,
             short_address: self.key.short_address().unwrap(),
             eth_address: self.key.eth_address(),
 
@@ -274,6 +405,10 @@ where
             add_primary_network_validator_fee: ADD_PRIMARY_NETWORK_VALIDATOR_FEE,
             create_subnet_tx_fee,
             create_blockchain_tx_fee,
+
+            journal: self.journal.clone(),
+            issuance_guard: self.issuance_guard.clone(),
+            issuance_queue: self.issuance_queue.clone(),
         };
         log::info!("initiated the wallet:\n{}", w);
 
@@ -1,16 +1,20 @@
-use std::{
-    io::{self, Error, ErrorKind},
-    time::SystemTime,
-};
+use std::io::{self, Error, ErrorKind};
 
 use crate::{
-    client::p as client_p,
-    formatting,
+    client::{
+        cancel, p as client_p,
+        time::{self, Duration, Instant},
+        wallet::{journal, queue, signed::Signed, simulate},
+    },
     ids::{self, node},
-    key, platformvm, txs,
+    key,
+    platformvm::{
+        self,
+        txs::multisig::{PartialTx, SubnetAuthTx},
+    },
+    txs,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
-use tokio::time::{sleep, Duration, Instant};
 
 /// Represents P-chain "AddSubnetValidator" transaction.
 /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/wallet/chain/p/builder.go#L360-L390 "NewAddSubnetValidatorTx"
@@ -29,6 +33,9 @@ where
     pub start_time: DateTime<Utc>,
     pub end_time: DateTime<Utc>,
 
+    /// Owner of the change output. Defaults to the wallet key.
+    pub change_owner: Option<key::secp256k1::txs::OutputOwners>,
+
     /// Set "true" to poll transaction status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -48,10 +55,7 @@ where
     T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
 {
     pub fn new(p: &crate::client::wallet::p::P<T>) -> Self {
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
+        let now_unix = time::unix_now_secs();
 
         let start_time = now_unix + 60;
         let native_dt = NaiveDateTime::from_timestamp(start_time as i64, 0);
@@ -71,6 +75,7 @@ where
             weight: 1000,
             start_time,
             end_time,
+            change_owner: None,
             check_acceptance: false,
             poll_initial_wait: Duration::from_secs(62), // enough to elapse validate start time
             poll_interval: Duration::from_secs(1),
@@ -114,6 +119,24 @@ where
         self
     }
 
+    /// Sets the address that receives the change.
+    #[must_use]
+    pub fn change_address(mut self, change_address: ids::short::Id) -> Self {
+        self.change_owner = Some(key::secp256k1::txs::OutputOwners::new(
+            0,
+            1,
+            &[change_address],
+        ));
+        self
+    }
+
+    /// Sets the owner of the change output (e.g., a multisig threshold or a locktime).
+    #[must_use]
+    pub fn change_owner(mut self, change_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.change_owner = Some(change_owner.sorted());
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -149,32 +172,9 @@ where
         self
     }
 
-    /// Issues the add subnet validator transaction and returns the transaction Id.
-    /// The boolean return represents whether the "add_subnet_validator" request was
-    /// successfully issued or not (regardless of its acceptance).
-    /// If the validator is already a validator, it returns an empty Id and false.
-    pub async fn issue(&self) -> io::Result<(ids::Id, bool)> {
-        let picked_http_rpc = self.inner.inner.pick_http_rpc();
-        log::info!(
-            "adding {} as subnet {} validator with weight {} via {}",
-            self.node_id,
-            self.subnet_id,
-            self.weight,
-            picked_http_rpc.1
-        );
-
-        let already_validator = self
-            .inner
-            .is_subnet_validator(&self.node_id, &self.subnet_id)
-            .await?;
-        if already_validator {
-            log::warn!(
-                "node Id {} is already a subnet validator -- returning empty tx Id",
-                self.node_id
-            );
-            return Ok((ids::Id::empty(), false));
-        }
-
+    /// Builds the unsigned add subnet validator transaction without the
+    /// subnet auth, and returns it with the signers of its inputs.
+    async fn build(&self) -> io::Result<(platformvm::txs::add_subnet_validator::Tx, Vec<Vec<T>>)> {
         let cur_balance_p = self.inner.balance().await?;
         if cur_balance_p < self.inner.inner.tx_fee {
             return Err(Error::new(
@@ -188,10 +188,16 @@ where
             cur_balance_p
         );
 
-        let (ins, unstaked_outs, _, signers) = self.inner.spend(0, self.inner.inner.tx_fee).await?;
-        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
+        let change_owner = self
+            .inner
+            .inner
+            .owner_or_default(self.change_owner.as_ref())?;
+        let (ins, unstaked_outs, _, signers) = self
+            .inner
+            .spend(0, self.inner.inner.tx_fee, &change_owner, &change_owner)
+            .await?;
 
-        let mut tx = platformvm::txs::add_subnet_validator::Tx {
+        let tx = platformvm::txs::add_subnet_validator::Tx {
             base_tx: txs::Tx {
                 network_id: self.inner.inner.network_id,
                 blockchain_id: self.inner.inner.blockchain_id_p,
@@ -208,19 +214,117 @@ where
                 },
                 subnet_id: self.subnet_id,
             },
-            // if "sig_indices" empty, it errors with "unauthorized subnet modification: input has less signers than expected"
-            subnet_auth,
             ..Default::default()
         };
+        Ok((tx, signers))
+    }
+
+    /// Builds and signs the add subnet validator transaction without issuing it.
+    pub async fn sign(&self) -> io::Result<Signed> {
+        let (mut tx, signers) = self.build().await?;
+        // if "sig_indices" empty, it errors with "unauthorized subnet modification: input has less signers than expected"
+        let (subnet_auth, subnet_signers) = self.inner.authorize(self.subnet_id).await?;
+        tx.subnet_auth = subnet_auth;
         tx.sign(vec![signers, subnet_signers].concat()).await?;
 
+        Signed::new(
+            "P",
+            "add_subnet_validator",
+            &tx,
+            &tx.base_tx,
+            &self.inner.inner.avax_asset_id,
+            self.inner.inner.tx_fee,
+        )
+    }
+
+    /// Builds the add subnet validator transaction for the subnet control
+    /// keys, signed by the keys of the wallet among its signers
+    /// (see "create_chain::Tx::sign_partial").
+    pub async fn sign_partial(
+        &self,
+        subnet_auth_signers: &[ids::short::Id],
+    ) -> io::Result<PartialTx> {
+        let (mut tx, signers) = self.build().await?;
+        let (subnet_auth, subnet_owners) = self
+            .inner
+            .authorize_signers(self.subnet_id, subnet_auth_signers)
+            .await?;
+        tx.subnet_auth = subnet_auth;
+        self.inner
+            .partial(
+                SubnetAuthTx::AddSubnetValidator(tx),
+                signers,
+                &subnet_owners,
+            )
+            .await
+    }
+
+    /// Builds and signs the add subnet validator transaction, and runs the simulation
+    /// checks instead of issuing it. With "node_check", also checks the
+    /// consumed UTXOs against the node.
+    pub async fn simulate(&self, node_check: bool) -> io::Result<simulate::Report> {
+        let signed = self.sign().await?;
+        simulate::run(&self.inner.inner, signed, node_check).await
+    }
+
+    /// Issues the add subnet validator transaction and returns the transaction Id.
+    /// The boolean return represents whether the "add_subnet_validator" request was
+    /// successfully issued or not (regardless of its acceptance).
+    /// If the validator is already a validator, it returns an empty Id and false.
+    pub async fn issue(&self) -> io::Result<(ids::Id, bool)> {
+        match self.issue_signed().await? {
+            Some(signed) => Ok((signed.tx_id, !self.dry_mode)),
+            None => Ok((ids::Id::empty(), false)),
+        }
+    }
+
+    /// Issues the add subnet validator transaction and returns the signed
+    /// transaction, or None if the node is already a subnet validator.
+    /// Runs after the previous issuances on the P-chain if the wallet has an issuance queue.
+    pub async fn issue_signed(&self) -> io::Result<Option<Signed>> {
+        self.inner
+            .inner
+            .queue_issuance(
+                "P",
+                "add_subnet_validator",
+                self.check_acceptance,
+                self.issue_signed_now(),
+            )
+            .await
+    }
+
+    async fn issue_signed_now(&self) -> io::Result<Option<Signed>> {
+        let picked_http_rpc = self.inner.inner.pick_http_rpc();
+        log::info!(
+            "adding {} as subnet {} validator with weight {} via {}",
+            self.node_id,
+            self.subnet_id,
+            self.weight,
+            picked_http_rpc.1
+        );
+
+        let already_validator = self
+            .inner
+            .is_subnet_validator(&self.node_id, &self.subnet_id)
+            .await?;
+        if already_validator {
+            log::warn!(
+                "node Id {} is already a subnet validator -- returning empty tx Id",
+                self.node_id
+            );
+            return Ok(None);
+        }
+
+        let signed = self.sign().await?;
+        txs::limits::check_tx_size(signed.tx_bytes.len())?;
         if self.dry_mode {
-            return Ok((tx.base_tx.metadata.unwrap().id, false));
+            return Ok(Some(signed));
         }
 
-        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
-        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        queue::mark_signed(&signed.tx_id);
+        self.inner.inner.guard_issuance(&signed.tx_id)?;
+
+        let resp = client_p::issue_tx(&picked_http_rpc.1, &signed.tx_hex).await?;
 
         if let Some(e) = resp.error {
             // handle duplicate validator
@@ -232,7 +336,7 @@ where
                     self.node_id,
                     e.message
                 );
-                return Ok((ids::Id::empty(), false));
+                return Ok(None);
             }
 
             return Err(Error::new(
@@ -241,17 +345,21 @@ where
             ));
         }
 
-        let tx_id = resp.result.unwrap().tx_id;
+        let tx_id = resp.into_result()?.tx_id;
         log::info!("{} successfully issued", tx_id);
+        queue::mark_issued();
+        self.inner
+            .inner
+            .record_issued(&journal::Entry::from_signed(&signed, &picked_http_rpc.1));
 
         if !self.check_acceptance {
             log::debug!("skipping checking acceptance...");
-            return Ok((tx_id, true));
+            return Ok(Some(signed));
         }
 
         // enough time for txs processing
         log::info!("initial waiting {:?}", self.poll_initial_wait);
-        sleep(self.poll_initial_wait).await;
+        cancel::sleep(self.poll_initial_wait).await?;
 
         log::info!("polling to confirm add subnet validator transaction");
         let (start, mut success) = (Instant::now(), false);
@@ -263,7 +371,7 @@ where
 
             let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
 
-            let status = resp.result.unwrap().status;
+            let status = resp.into_result()?.status;
             if status == platformvm::txs::status::Status::Committed {
                 log::info!("{} successfully committed", tx_id);
                 success = true;
@@ -277,7 +385,7 @@ where
                 picked_http_rpc.1,
                 elapsed
             );
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -309,7 +417,7 @@ where
                 self.node_id,
                 elapsed
             );
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -318,6 +426,6 @@ where
             ));
         }
 
-        Ok((tx_id, true))
+        Ok(Some(signed))
     }
 }
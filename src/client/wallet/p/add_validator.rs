@@ -1,16 +1,15 @@
-use std::{
-    io::{self, Error, ErrorKind},
-    time::SystemTime,
-};
+use std::io::{self, Error, ErrorKind};
 
 use crate::{
-    client::p as client_p,
-    formatting,
+    client::{
+        cancel, p as client_p,
+        time::{self, Duration, Instant},
+        wallet::{journal, queue, signed::Signed, simulate},
+    },
     ids::{self, node},
-    key, platformvm, txs, units,
+    key, network_upgrades, platformvm, txs, units,
 };
 use chrono::{DateTime, NaiveDateTime, Utc};
-use tokio::time::{sleep, Duration, Instant};
 
 /// Represents P-chain "AddValidator" transaction.
 /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/wallet/chain/p/builder.go#L325-L358 "NewAddValidatorTx"
@@ -37,6 +36,19 @@ where
     /// Validate reward fee in percent.
     pub reward_fee_percent: u32,
 
+    /// Owner of the unlocked stake returned at the end of the staking period.
+    /// Defaults to the wallet key.
+    pub stake_owner: Option<key::secp256k1::txs::OutputOwners>,
+    /// Owner of the staking rewards. Defaults to the wallet key.
+    pub rewards_owner: Option<key::secp256k1::txs::OutputOwners>,
+
+    /// Owner of the change output. Defaults to the wallet key.
+    pub change_owner: Option<key::secp256k1::txs::OutputOwners>,
+
+    /// Staking parameters to validate the stake amount and duration against.
+    /// Defaults to "platformvm::staking::Config::for_network" of the wallet network.
+    pub staking_config: platformvm::staking::Config,
+
     /// Set "true" to poll transaction status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -56,12 +68,9 @@ where
     T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
 {
     pub fn new(p: &crate::client::wallet::p::P<T>) -> Self {
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
+        let now_unix = time::unix_now_secs();
 
-        let start_time = now_unix + 60;
+        let start_time = now_unix + platformvm::staking::DEFAULT_START_BUFFER;
         let native_dt = NaiveDateTime::from_timestamp(start_time as i64, 0);
         let start_time = DateTime::<Utc>::from_utc(native_dt, Utc);
 
@@ -79,6 +88,10 @@ where
             start_time,
             end_time,
             reward_fee_percent: 2,
+            stake_owner: None,
+            rewards_owner: None,
+            change_owner: None,
+            staking_config: platformvm::staking::Config::for_network(p.inner.network_id),
             check_acceptance: false,
             poll_initial_wait: Duration::from_secs(62), // enough to elapse validate start time
             poll_interval: Duration::from_secs(1),
@@ -122,6 +135,46 @@ where
         self
     }
 
+    /// Sets the owner of the unlocked stake returned at the end of the staking period.
+    #[must_use]
+    pub fn stake_owner(mut self, stake_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.stake_owner = Some(stake_owner.sorted());
+        self
+    }
+
+    /// Sets the owner of the staking rewards.
+    #[must_use]
+    pub fn rewards_owner(mut self, rewards_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.rewards_owner = Some(rewards_owner.sorted());
+        self
+    }
+
+    /// Sets the address that receives the change.
+    #[must_use]
+    pub fn change_address(mut self, change_address: ids::short::Id) -> Self {
+        self.change_owner = Some(key::secp256k1::txs::OutputOwners::new(
+            0,
+            1,
+            &[change_address],
+        ));
+        self
+    }
+
+    /// Sets the owner of the change output (e.g., a multisig threshold or a locktime).
+    #[must_use]
+    pub fn change_owner(mut self, change_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.change_owner = Some(change_owner.sorted());
+        self
+    }
+
+    /// Sets the staking parameters of the network (e.g., for a custom network
+    /// whose genesis differs from the local defaults).
+    #[must_use]
+    pub fn staking_config(mut self, staking_config: platformvm::staking::Config) -> Self {
+        self.staking_config = staking_config;
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -157,37 +210,35 @@ where
         self
     }
 
-    /// Issues the add validator transaction and returns the transaction Id.
-    /// The boolean return represents whether the "add_validator" request was
-    /// successfully issued or not (regardless of its acceptance).
-    /// If the validator is already a validator, it returns an empty Id and false.
-    pub async fn issue(&self) -> io::Result<(ids::Id, bool)> {
-        let picked_http_rpc = self.inner.inner.pick_http_rpc();
-        log::info!(
-            "adding primary network validator {} with stake amount {} AVAX ({} nAVAX) via {}",
-            self.node_id,
-            units::convert_navax_for_x_and_p(self.stake_amount),
+    /// Builds and signs the add validator transaction without issuing it.
+    /// The staking window and amount are validated client-side before signing
+    /// against "staking_config" (see "platformvm::staking::Config::validate_add_validator"), and
+    /// the transaction type against the network upgrades active now.
+    pub async fn sign(&self) -> io::Result<Signed> {
+        let now_unix = time::unix_now_secs();
+        network_upgrades::check_p_tx_type(
+            self.inner.inner.network_id,
+            &platformvm::txs::add_validator::Tx::type_name(),
+            now_unix,
+        )?;
+        self.staking_config.validate_add_validator(
+            now_unix,
+            self.start_time.timestamp() as u64,
+            self.end_time.timestamp() as u64,
             self.stake_amount,
-            picked_http_rpc.1
-        );
-
-        let already_validator = self
-            .inner
-            .is_primary_network_validator(&self.node_id)
-            .await?;
-        if already_validator {
-            log::warn!(
-                "node Id {} is already a validator -- returning empty tx Id",
-                self.node_id
-            );
-            return Ok((ids::Id::empty(), false));
-        }
+            self.reward_fee_percent * 10000,
+        )?;
 
+        let required = units::NanoAvax(self.stake_amount)
+            .checked_add(units::NanoAvax(
+                self.inner.inner.add_primary_network_validator_fee,
+            ))?
+            .as_u64();
         let cur_balance_p = self.inner.balance().await?;
-        if cur_balance_p < self.stake_amount + self.inner.inner.add_primary_network_validator_fee {
+        if cur_balance_p < required {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
-                format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, self.stake_amount + self.inner.inner.add_primary_network_validator_fee),
+                format!("key address {} (balance {} nano-AVAX, network {}) does not have enough to cover stake amount + fee {}", self.inner.inner.p_address, cur_balance_p, self.inner.inner.network_name, required),
              ));
         };
         log::info!(
@@ -196,11 +247,25 @@ where
             cur_balance_p
         );
 
+        let change_owner = self
+            .inner
+            .inner
+            .owner_or_default(self.change_owner.as_ref())?;
+        let stake_owner = self
+            .inner
+            .inner
+            .owner_or_default(self.stake_owner.as_ref())?;
+        let rewards_owner = self
+            .inner
+            .inner
+            .owner_or_default(self.rewards_owner.as_ref())?;
         let (ins, unstaked_outs, staked_outs, signers) = self
             .inner
             .spend(
                 self.stake_amount,
                 self.inner.inner.add_primary_network_validator_fee,
+                &change_owner,
+                &stake_owner,
             )
             .await?;
 
@@ -219,23 +284,90 @@ where
                 weight: self.stake_amount,
             },
             stake_transferable_outputs: Some(staked_outs),
-            rewards_owner: key::secp256k1::txs::OutputOwners {
-                locktime: 0,
-                threshold: 1,
-                addresses: vec![self.inner.inner.short_address.clone()],
-            },
+            rewards_owner,
             shares: self.reward_fee_percent * 10000,
             ..Default::default()
         };
         tx.sign(signers).await?;
 
+        let mut signed = Signed::new(
+            "P",
+            "add_validator",
+            &tx,
+            &tx.base_tx,
+            &self.inner.inner.avax_asset_id,
+            self.inner.inner.add_primary_network_validator_fee,
+        )?;
+        signed.staked_amount = self.stake_amount;
+        Ok(signed)
+    }
+
+    /// Builds and signs the add validator transaction, and runs the simulation
+    /// checks instead of issuing it. With "node_check", also checks the
+    /// consumed UTXOs against the node.
+    pub async fn simulate(&self, node_check: bool) -> io::Result<simulate::Report> {
+        let signed = self.sign().await?;
+        simulate::run(&self.inner.inner, signed, node_check).await
+    }
+
+    /// Issues the add validator transaction and returns the transaction Id.
+    /// The boolean return represents whether the "add_validator" request was
+    /// successfully issued or not (regardless of its acceptance).
+    /// If the validator is already a validator, it returns an empty Id and false.
+    pub async fn issue(&self) -> io::Result<(ids::Id, bool)> {
+        match self.issue_signed().await? {
+            Some(signed) => Ok((signed.tx_id, !self.dry_mode)),
+            None => Ok((ids::Id::empty(), false)),
+        }
+    }
+
+    /// Issues the add validator transaction and returns the signed
+    /// transaction, or None if the node is already a validator.
+    /// Runs after the previous issuances on the P-chain if the wallet has an issuance queue.
+    pub async fn issue_signed(&self) -> io::Result<Option<Signed>> {
+        self.inner
+            .inner
+            .queue_issuance(
+                "P",
+                "add_validator",
+                self.check_acceptance,
+                self.issue_signed_now(),
+            )
+            .await
+    }
+
+    async fn issue_signed_now(&self) -> io::Result<Option<Signed>> {
+        let picked_http_rpc = self.inner.inner.pick_http_rpc();
+        log::info!(
+            "adding primary network validator {} with stake amount {} AVAX ({} nAVAX) via {}",
+            self.node_id,
+            units::convert_navax_for_x_and_p(self.stake_amount),
+            self.stake_amount,
+            picked_http_rpc.1
+        );
+
+        let already_validator = self
+            .inner
+            .is_primary_network_validator(&self.node_id)
+            .await?;
+        if already_validator {
+            log::warn!(
+                "node Id {} is already a validator -- returning empty tx Id",
+                self.node_id
+            );
+            return Ok(None);
+        }
+
+        let signed = self.sign().await?;
+        txs::limits::check_tx_size(signed.tx_bytes.len())?;
         if self.dry_mode {
-            return Ok((tx.base_tx.metadata.unwrap().id, false));
+            return Ok(Some(signed));
         }
 
-        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
-        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        queue::mark_signed(&signed.tx_id);
+        self.inner.inner.guard_issuance(&signed.tx_id)?;
+
+        let resp = client_p::issue_tx(&picked_http_rpc.1, &signed.tx_hex).await?;
 
         if let Some(e) = resp.error {
             // handle duplicate validator
@@ -249,7 +381,7 @@ where
                     self.node_id,
                     e.message
                 );
-                return Ok((ids::Id::empty(), false));
+                return Ok(None);
             }
 
             return Err(Error::new(
@@ -258,17 +390,21 @@ where
             ));
         }
 
-        let tx_id = resp.result.unwrap().tx_id;
+        let tx_id = resp.into_result()?.tx_id;
         log::info!("{} successfully issued", tx_id);
+        queue::mark_issued();
+        self.inner
+            .inner
+            .record_issued(&journal::Entry::from_signed(&signed, &picked_http_rpc.1));
 
         if !self.check_acceptance {
             log::debug!("skipping checking acceptance...");
-            return Ok((tx_id, true));
+            return Ok(Some(signed));
         }
 
         // enough time for txs processing
         log::info!("initial waiting {:?}", self.poll_initial_wait);
-        sleep(self.poll_initial_wait).await;
+        cancel::sleep(self.poll_initial_wait).await?;
 
         log::info!("polling to confirm add validator transaction");
         let (start, mut success) = (Instant::now(), false);
@@ -280,7 +416,7 @@ where
 
             let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
 
-            let status = resp.result.unwrap().status;
+            let status = resp.into_result()?.status;
             if status == platformvm::txs::status::Status::Committed {
                 log::info!("{} successfully committed", tx_id);
                 success = true;
@@ -294,7 +430,7 @@ where
                 picked_http_rpc.1,
                 elapsed
             );
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -326,7 +462,7 @@ where
                 self.node_id,
                 elapsed
             );
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -335,6 +471,6 @@ where
             ));
         }
 
-        Ok((tx_id, true))
+        Ok(Some(signed))
     }
 }
@@ -0,0 +1,291 @@
+use std::io;
+
+use crate::{
+    client::{
+        time::{self, Duration},
+        wallet::{consolidate, signed::Signed},
+    },
+    ids, key, platformvm, txs,
+};
+
+/// Sweeps the small P-chain AVAX UTXOs of the wallet owner (including the
+/// stakeable ones whose lock expired) into fewer outputs, issuing one
+/// base transaction per batch of inputs.
+///
+/// NOTE: The P-chain base transaction is only accepted since the Durango
+/// upgrade (avalanchego v1.11).
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::client::wallet::p::P<T>,
+
+    /// Maximum number of inputs per transaction.
+    pub max_inputs: usize,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Ids for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(p: &crate::client::wallet::p::P<T>) -> Self {
+        Self {
+            inner: p.clone(),
+
+            max_inputs: consolidate::DEFAULT_MAX_INPUTS,
+
+            check_acceptance: false,
+
+            poll_initial_wait: Duration::from_millis(1500),
+            poll_interval: Duration::from_secs(1),
+            poll_timeout: Duration::from_secs(300),
+
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the maximum number of inputs per transaction.
+    #[must_use]
+    pub fn max_inputs(mut self, max_inputs: usize) -> Self {
+        self.max_inputs = max_inputs;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Fetches the spendable AVAX UTXOs with their amounts,
+    /// including the stakeable UTXOs whose lock expired.
+    async fn spendable(&self) -> io::Result<Vec<(txs::utxo::Utxo, u64)>> {
+        let now_unix = time::unix_now_secs();
+        let wallet = &self.inner.inner;
+        Ok(self
+            .inner
+            .utxos()
+            .await?
+            .into_iter()
+            .filter_map(|utxo| {
+                consolidate::spendable_amount(
+                    &wallet.keychain,
+                    &utxo,
+                    &wallet.avax_asset_id,
+                    now_unix,
+                )
+                .map(|amount| (utxo, amount))
+            })
+            .collect())
+    }
+
+    /// Returns the batches that "issue" would consolidate.
+    pub async fn plan(&self) -> io::Result<Vec<consolidate::Batch>> {
+        consolidate::plan(
+            self.spendable().await?,
+            self.max_inputs,
+            self.inner.inner.tx_fee,
+        )
+    }
+
+    /// Returns the UTXOs that are worth less than the fee to move on their own.
+    pub async fn dust(&self) -> io::Result<consolidate::Dust> {
+        consolidate::dust(&self.spendable().await?, self.inner.inner.tx_fee)
+    }
+
+    /// Issues one transaction per batch, and returns the transaction Ids.
+    /// The batches spend disjoint UTXOs, so a failed batch does not
+    /// invalidate the ones issued before it.
+    pub async fn issue(&self) -> io::Result<Vec<ids::Id>> {
+        let batches = self.plan().await?;
+        log::info!(
+            "consolidating {} UTXOs in {} transaction(s)",
+            batches.iter().map(|b| b.utxos.len()).sum::<usize>(),
+            batches.len()
+        );
+
+        let mut tx_ids = Vec::new();
+        for batch in batches.iter() {
+            let signed = self.sign(batch).await?;
+            let issue = self
+                .inner
+                .issue_raw(&signed.tx_bytes)
+                .check_acceptance(self.check_acceptance)
+                .poll_initial_wait(self.poll_initial_wait)
+                .poll_interval(self.poll_interval)
+                .poll_timeout(self.poll_timeout);
+            if self.dry_mode {
+                tx_ids.push(issue.tx_id());
+                continue;
+            }
+            tx_ids.push(issue.issue().await?);
+        }
+        Ok(tx_ids)
+    }
+
+    /// Builds and signs the base transaction that spends the batch into
+    /// a single output owned by the wallet.
+    async fn sign(&self, batch: &consolidate::Batch) -> io::Result<Signed> {
+        let wallet = &self.inner.inner;
+        let now_unix = time::unix_now_secs();
+
+        let (inputs, signers) = consolidate::spend(&wallet.keychain, batch, now_unix)?;
+        let output = consolidate::output(batch, &wallet.avax_asset_id, &wallet.short_address);
+
+        let mut tx = platformvm::txs::base::Tx::new(txs::Tx {
+            network_id: wallet.network_id,
+            blockchain_id: wallet.blockchain_id_p,
+            transferable_outputs: Some(vec![output]),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        });
+        tx.sign(signers).await?;
+
+        Signed::new(
+            "P",
+            "consolidate",
+            &tx,
+            &tx.base_tx,
+            &self.inner.inner.avax_asset_id,
+            batch.total - batch.output_amount,
+        )
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::p::consolidate::test_sign --exact --show-output
+#[tokio::test]
+async fn test_sign() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::txs::verify::Verifiable;
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let addr = k.to_public_key().to_short_id().unwrap();
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+    let avax = ids::Id::from_slice(&[0xaa; 32]);
+    let wallet = crate::client::wallet::Wallet {
+        keychain: key::secp256k1::keychain::Keychain::new(vec![k]),
+        http_rpcs: vec![String::from("http://127.0.0.1:9650")],
+        http_rpc_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: String::from("mainnet"),
+        hrp: String::from("avax"),
+        h160_address: primitive_types::H160::zero(),
+        x_address: String::new(),
+        p_address: String::new(),
+        c_address: String::new(),
+        short_address: addr.clone(),
+        eth_address: String::new(),
+        blockchain_id_x: ids::Id::empty(),
+        blockchain_id_p: ids::Id::empty(),
+        blockchain_id_c: ids::Id::empty(),
+        chain_id_c: primitive_types::U256::from(43114),
+        avax_asset_id: avax,
+        tx_fee: 1_000_000,
+        add_primary_network_validator_fee: 0,
+        create_subnet_tx_fee: 0,
+        create_blockchain_tx_fee: 0,
+        journal: None,
+        issuance_guard: None,
+        issuance_queue: None,
+    };
+
+    // an unlocked UTXO, and a stakeable one whose lock expired
+    let unlocked = txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id::new(&[1; 32], 0, false).unwrap(),
+        asset_id: avax,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 3_000_000,
+            output_owners: owners.clone(),
+        }),
+        ..Default::default()
+    };
+    let expired = txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id::new(&[2; 32], 1, false).unwrap(),
+        asset_id: avax,
+        stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
+            locktime: 1,
+            transfer_output: key::secp256k1::txs::transfer::Output {
+                amount: 2_000_000,
+                output_owners: owners.clone(),
+            },
+        }),
+        ..Default::default()
+    };
+    let spendable: Vec<(txs::utxo::Utxo, u64)> =
+        vec![(unlocked.clone(), 3_000_000), (expired.clone(), 2_000_000)];
+    let batches =
+        consolidate::plan(spendable, consolidate::DEFAULT_MAX_INPUTS, wallet.tx_fee).unwrap();
+    assert_eq!(batches.len(), 1);
+
+    let p = crate::client::wallet::p::P { inner: wallet };
+    let signed = p.consolidate().sign(&batches[0]).await.unwrap();
+    assert_eq!(signed.chain, "P");
+    assert_eq!(signed.tx_type, "consolidate");
+    assert_eq!(signed.fee, 1_000_000);
+    assert_eq!(signed.input_amount, 5_000_000);
+    assert_eq!(signed.output_amount, 4_000_000);
+    assert_eq!(signed.consumed_utxos.len(), 2);
+    assert_eq!(signed.created_utxos.len(), 1);
+
+    // codec version, then the type ID of "platformvm.BaseTx"
+    assert_eq!(
+        &signed.unsigned_tx_bytes[..6],
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x22]
+    );
+
+    // rebuilds the transaction from the signed JSON to verify the credentials
+    let tx: platformvm::txs::base::Tx = serde_json::from_str(&signed.tx.to_string()).unwrap();
+    let ins = tx.base_tx.transferable_inputs.clone().unwrap();
+    assert_eq!(ins.len(), 2);
+    assert!(ins.iter().all(|i| i.transfer_input.is_some()));
+    let outs = tx.base_tx.transferable_outputs.clone().unwrap();
+    assert_eq!(outs.len(), 1);
+    let out = outs[0].transfer_output.as_ref().unwrap();
+    assert_eq!(out.amount, 4_000_000);
+    assert_eq!(out.output_owners.addresses, vec![addr]);
+    tx.verify_credentials(&[unlocked, expired]).unwrap();
+}
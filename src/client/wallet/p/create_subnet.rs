@@ -1,7 +1,13 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::{client::p as client_p, formatting, ids, key, platformvm, txs};
-use tokio::time::{sleep, Duration, Instant};
+use crate::{
+    client::{
+        cancel, p as client_p,
+        time::{Duration, Instant},
+        wallet::{journal, queue, signed::Signed, simulate},
+    },
+    ids, key, platformvm, txs,
+};
 
 /// Represents P-chain "CreateSubnet" transaction.
 /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/wallet/chain/p/builder.go#L500-L525 "NewCreateSubnetTx"
@@ -13,6 +19,13 @@ where
 {
     pub inner: crate::client::wallet::p::P<T>,
 
+    /// Owner of the subnet, whose keys authorize the subnet changes.
+    /// Defaults to the wallet key.
+    pub owner: Option<key::secp256k1::txs::OutputOwners>,
+
+    /// Owner of the change output. Defaults to the wallet key.
+    pub change_owner: Option<key::secp256k1::txs::OutputOwners>,
+
     /// Set "true" to poll transaction status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -34,6 +47,8 @@ where
     pub fn new(p: &crate::client::wallet::p::P<T>) -> Self {
         Self {
             inner: p.clone(),
+            owner: None,
+            change_owner: None,
             check_acceptance: false,
             poll_initial_wait: Duration::from_millis(1500),
             poll_interval: Duration::from_secs(1),
@@ -42,6 +57,31 @@ where
         }
     }
 
+    /// Sets the owner of the subnet.
+    #[must_use]
+    pub fn owner(mut self, owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.owner = Some(owner.sorted());
+        self
+    }
+
+    /// Sets the address that receives the change.
+    #[must_use]
+    pub fn change_address(mut self, change_address: ids::short::Id) -> Self {
+        self.change_owner = Some(key::secp256k1::txs::OutputOwners::new(
+            0,
+            1,
+            &[change_address],
+        ));
+        self
+    }
+
+    /// Sets the owner of the change output (e.g., a multisig threshold or a locktime).
+    #[must_use]
+    pub fn change_owner(mut self, change_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.change_owner = Some(change_owner.sorted());
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -77,11 +117,8 @@ where
         self
     }
 
-    /// Issues the create subnet transaction and returns the transaction Id.
-    pub async fn issue(&self) -> io::Result<ids::Id> {
-        let picked_http_rpc = self.inner.inner.pick_http_rpc();
-        log::info!("creating a new subnet via {}", picked_http_rpc.1);
-
+    /// Builds and signs the create subnet transaction without issuing it.
+    pub async fn sign(&self) -> io::Result<Signed> {
         let cur_balance_p = self.inner.balance().await?;
         if cur_balance_p < self.inner.inner.create_subnet_tx_fee {
             return Err(Error::new(
@@ -95,9 +132,19 @@ where
             cur_balance_p
         );
 
+        let owner = self.inner.inner.owner_or_default(self.owner.as_ref())?;
+        let change_owner = self
+            .inner
+            .inner
+            .owner_or_default(self.change_owner.as_ref())?;
         let (ins, unstaked_outs, _, signers) = self
             .inner
-            .spend(0, self.inner.inner.create_subnet_tx_fee)
+            .spend(
+                0,
+                self.inner.inner.create_subnet_tx_fee,
+                &change_owner,
+                &change_owner,
+            )
             .await?;
 
         let mut tx = platformvm::txs::create_subnet::Tx {
@@ -108,22 +155,62 @@ where
                 transferable_inputs: Some(ins),
                 ..Default::default()
             },
-            owner: key::secp256k1::txs::OutputOwners {
-                locktime: 0,
-                threshold: 1,
-                addresses: vec![self.inner.inner.short_address.clone()],
-            },
+            owner,
             ..Default::default()
         };
         tx.sign(signers).await?;
 
+        Signed::new(
+            "P",
+            "create_subnet",
+            &tx,
+            &tx.base_tx,
+            &self.inner.inner.avax_asset_id,
+            self.inner.inner.create_subnet_tx_fee,
+        )
+    }
+
+    /// Builds and signs the create subnet transaction, and runs the simulation
+    /// checks instead of issuing it. With "node_check", also checks the
+    /// consumed UTXOs against the node.
+    pub async fn simulate(&self, node_check: bool) -> io::Result<simulate::Report> {
+        let signed = self.sign().await?;
+        simulate::run(&self.inner.inner, signed, node_check).await
+    }
+
+    /// Issues the create subnet transaction and returns the transaction Id.
+    pub async fn issue(&self) -> io::Result<ids::Id> {
+        Ok(self.issue_signed().await?.tx_id)
+    }
+
+    /// Issues the create subnet transaction and returns the signed transaction.
+    /// Runs after the previous issuances on the P-chain if the wallet has an issuance queue.
+    pub async fn issue_signed(&self) -> io::Result<Signed> {
+        self.inner
+            .inner
+            .queue_issuance(
+                "P",
+                "create_subnet",
+                self.check_acceptance,
+                self.issue_signed_now(),
+            )
+            .await
+    }
+
+    async fn issue_signed_now(&self) -> io::Result<Signed> {
+        let picked_http_rpc = self.inner.inner.pick_http_rpc();
+        log::info!("creating a new subnet via {}", picked_http_rpc.1);
+
+        let signed = self.sign().await?;
+        txs::limits::check_tx_size(signed.tx_bytes.len())?;
         if self.dry_mode {
-            return Ok(tx.base_tx.metadata.unwrap().id);
+            return Ok(signed);
         }
 
-        let tx_bytes_with_signatures = tx.base_tx.metadata.unwrap().tx_bytes_with_signatures;
-        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_p::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        queue::mark_signed(&signed.tx_id);
+        self.inner.inner.guard_issuance(&signed.tx_id)?;
+
+        let resp = client_p::issue_tx(&picked_http_rpc.1, &signed.tx_hex).await?;
 
         if let Some(e) = resp.error {
             return Err(Error::new(
@@ -132,17 +219,21 @@ where
             ));
         }
 
-        let tx_id = resp.result.unwrap().tx_id;
+        let tx_id = resp.into_result()?.tx_id;
         log::info!("{} successfully issued", tx_id);
+        queue::mark_issued();
+        self.inner
+            .inner
+            .record_issued(&journal::Entry::from_signed(&signed, &picked_http_rpc.1));
 
         if !self.check_acceptance {
             log::debug!("skipping checking acceptance...");
-            return Ok(tx_id);
+            return Ok(signed);
         }
 
         // enough time for txs processing
         log::info!("initial waiting {:?}", self.poll_initial_wait);
-        sleep(self.poll_initial_wait).await;
+        cancel::sleep(self.poll_initial_wait).await?;
 
         log::info!("polling to confirm create subnet transaction");
         let (start, mut success) = (Instant::now(), false);
@@ -154,7 +245,7 @@ where
 
             let resp = client_p::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
 
-            let status = resp.result.unwrap().status;
+            let status = resp.into_result()?.status;
             if status == platformvm::txs::status::Status::Committed {
                 log::info!("{} successfully committed", tx_id);
                 success = true;
@@ -168,7 +259,7 @@ where
                 picked_http_rpc.1,
                 elapsed
             );
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -177,6 +268,6 @@ where
             ));
         }
 
-        Ok(tx_id)
+        Ok(signed)
     }
 }
@@ -1,20 +1,48 @@
 pub mod add_subnet_validator;
 pub mod add_validator;
+pub mod consolidate;
 pub mod create_chain;
 pub mod create_subnet;
+pub mod issue_raw;
 
 use std::{
     cmp,
     io::{self, Error, ErrorKind},
-    time::SystemTime,
 };
 
 use crate::{
-    client::p as client_p,
-    ids::{self, node},
-    key, platformvm, txs,
+    client::{p as client_p, time},
+    ids::{self, node, short},
+    key::{self, secp256k1::keychain},
+    platformvm::{
+        self,
+        txs::multisig::{PartialTx, SubnetAuthTx},
+    },
+    txs,
+    units::NanoAvax,
 };
 
+/// P-chain balance of the wallet owner broken down by lock state.
+/// Genesis allocations are often locked until a timestamp, and only show
+/// up in "locked_stakeable" (usable for staking) or "locked_not_stakeable".
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetbalance
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct BalanceDetail {
+    /// Sum of the unlocked and locked amounts, excluding the staked amount.
+    pub total: u64,
+    /// Spendable amount.
+    pub unlocked: u64,
+    /// Locked amount that can still be used for staking.
+    pub locked_stakeable: u64,
+    /// Locked amount that cannot be spent nor staked until its locktime.
+    pub locked_not_stakeable: u64,
+    /// Amount staked in the current and pending validator sets, which is
+    /// returned to the owner only at the end of the staking period.
+    pub staked: u64,
+    /// UTXOs that make up the balance.
+    pub utxo_ids: Vec<txs::utxo::Id>,
+}
+
 #[derive(Clone, Debug)]
 pub struct P<T>
 where
@@ -30,10 +58,7 @@ where
     /// Fetches the current balance of the wallet owner from the specified HTTP endpoint.
     pub async fn balance_with_endpoint(&self, http_rpc: &str) -> io::Result<u64> {
         let resp = client_p::get_balance(http_rpc, &self.inner.p_address).await?;
-        let cur_balance = resp
-            .result
-            .expect("unexpected None GetBalanceResult")
-            .balance;
+        let cur_balance = resp.into_result()?.balance;
         Ok(cur_balance)
     }
 
@@ -54,25 +79,39 @@ where
             .await
     }
 
+    /// Fetches the current balance of the wallet owner with its lock state
+    /// and staked amount.
+    pub async fn balance_detail(&self) -> io::Result<BalanceDetail> {
+        let http_rpc = self.inner.pick_http_rpc().1;
+        let balance = client_p::get_balance(&http_rpc, &self.inner.p_address)
+            .await?
+            .into_result()?;
+        let stake = client_p::get_stake(&http_rpc, std::slice::from_ref(&self.inner.p_address))
+            .await?
+            .into_result()?;
+        Ok(BalanceDetail {
+            total: balance.balance,
+            unlocked: balance.unlocked,
+            locked_stakeable: balance.locked_stakeable.unwrap_or_default(),
+            locked_not_stakeable: balance.locked_not_stakeable.unwrap_or_default(),
+            staked: stake.staked,
+            utxo_ids: balance.utxo_ids.unwrap_or_default(),
+        })
+    }
+
     /// Fetches UTXOs for "P" chain.
     /// TODO: cache this like avalanchego
     pub async fn utxos(&self) -> io::Result<Vec<txs::utxo::Utxo>> {
         let resp =
             client_p::get_utxos(&self.inner.pick_http_rpc().1, &self.inner.p_address).await?;
-        let utxos = resp
-            .result
-            .expect("unexpected None GetUtxosResult")
-            .utxos
-            .expect("unexpected None Utxos");
+        let utxos = resp.into_result()?.utxos.unwrap_or_default();
         Ok(utxos)
     }
 
     /// Returns "true" if the node_id is a current primary network validator.
     pub async fn is_primary_network_validator(&self, node_id: &node::Id) -> io::Result<bool> {
         let resp = client_p::get_primary_network_validators(&self.inner.pick_http_rpc().1).await?;
-        let resp = resp
-            .result
-            .expect("unexpected None GetCurrentValidatorResult");
+        let resp = resp.into_result()?;
         let validators = resp.validators.expect("unexpected None vaidators");
         for validator in validators.iter() {
             log::info!("listing primary network validator {}", node_id);
@@ -92,9 +131,7 @@ where
         let resp =
             client_p::get_subnet_validators(&self.inner.pick_http_rpc().1, &subnet_id.to_string())
                 .await?;
-        let resp = resp
-            .result
-            .expect("unexpected None GetCurrentValidatorResult");
+        let resp = resp.into_result()?;
         let validators = resp.validators.expect("unexpected None vaidators");
         for validator in validators.iter() {
             log::info!(
@@ -112,10 +149,14 @@ where
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/vms/platformvm/utxo/handler.go#L169 "Spend"
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/wallet/chain/p/builder.go#L325-L358 "NewAddValidatorTx"
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/vms/platformvm/txs/builder/builder.go#L428 "NewAddValidatorTx"
+    /// The unlocked staked outputs go to "stake_owner", and the unlocked change
+    /// goes to "change_owner". The change of locked outputs keeps their owners.
     async fn spend(
         &self,
         amount: u64,
         fee: u64,
+        change_owner: &key::secp256k1::txs::OutputOwners,
+        stake_owner: &key::secp256k1::txs::OutputOwners,
     ) -> io::Result<(
         Vec<txs::transferable::Input>,
         Vec<txs::transferable::Output>,
@@ -123,11 +164,9 @@ where
         Vec<Vec<T>>,
     )> {
         let utxos = self.utxos().await?;
+        let (amount, fee) = (NanoAvax(amount), NanoAvax(fee));
 
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
+        let now_unix = time::unix_now_secs();
 
         let mut ins: Vec<txs::transferable::Input> = Vec::new();
         let mut returned_outputs: Vec<txs::transferable::Output> = Vec::new();
@@ -135,7 +174,7 @@ where
         let mut signers: Vec<Vec<T>> = Vec::new();
 
         // amount of AVAX that has been staked
-        let mut amount_staked: u64 = 0_u64;
+        let mut amount_staked = NanoAvax::ZERO;
 
         // consume locked UTXOs
         for utxo in utxos.iter() {
@@ -171,14 +210,19 @@ where
                 continue;
             }
             let (transfer_input, in_signers) = res.unwrap();
+            keychain::verify_signers(
+                &inner.output_owners,
+                &transfer_input.sig_indices,
+                &in_signers,
+            )?;
 
-            let mut remaining_value = transfer_input.amount;
+            let mut remaining_value = NanoAvax(transfer_input.amount);
             let amount_to_stake = cmp::min(
-                amount - amount_staked, // amount we still need to stake
-                remaining_value,        // amount available to stake
+                amount.checked_sub(amount_staked)?, // amount we still need to stake
+                remaining_value,                    // amount available to stake
             );
-            amount_staked += amount_to_stake;
-            remaining_value -= amount_to_stake;
+            amount_staked = amount_staked.checked_add(amount_to_stake)?;
+            remaining_value = remaining_value.checked_sub(amount_to_stake)?;
 
             // add input to the consumed inputs
             ins.push(txs::transferable::Input {
@@ -197,14 +241,14 @@ where
                 stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
                     locktime: out.clone().locktime,
                     transfer_output: key::secp256k1::txs::transfer::Output {
-                        amount: remaining_value,
+                        amount: remaining_value.as_u64(),
                         output_owners: out.clone().transfer_output.output_owners,
                     },
                 }),
                 ..txs::transferable::Output::default()
             });
 
-            if remaining_value > 0 {
+            if remaining_value > NanoAvax::ZERO {
                 // this input provided more value than was needed to be locked
                 // some must be returned
                 returned_outputs.push(txs::transferable::Output {
@@ -212,7 +256,7 @@ where
                     stakeable_lock_out: Some(platformvm::txs::StakeableLockOut {
                         locktime: out.clone().locktime,
                         transfer_output: key::secp256k1::txs::transfer::Output {
-                            amount: amount_to_stake,
+                            amount: amount_to_stake.as_u64(),
                             output_owners: out.clone().transfer_output.output_owners,
                         },
                     }),
@@ -224,7 +268,7 @@ where
         }
 
         // amount of AVAX that has been burned
-        let mut amount_burned = 0_u64;
+        let mut amount_burned = NanoAvax::ZERO;
 
         for utxo in utxos.iter() {
             // have staked/burned more AVAX than we need
@@ -259,22 +303,23 @@ where
                 continue;
             }
             let (transfer_input, in_signers) = res.unwrap();
+            keychain::verify_signers(&out.output_owners, &transfer_input.sig_indices, &in_signers)?;
 
             // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L763
-            let mut remaining_value = transfer_input.amount;
+            let mut remaining_value = NanoAvax(transfer_input.amount);
             let amount_to_burn = cmp::min(
-                fee - amount_burned, // amount we still need to burn
-                remaining_value,     // amount available to burn
+                fee.checked_sub(amount_burned)?, // amount we still need to burn
+                remaining_value,                 // amount available to burn
             );
-            amount_burned += amount_to_burn;
-            remaining_value -= amount_to_burn;
+            amount_burned = amount_burned.checked_add(amount_to_burn)?;
+            remaining_value = remaining_value.checked_sub(amount_to_burn)?;
 
             let amount_to_stake = cmp::min(
-                amount - amount_staked, // amount we still need to stake
-                remaining_value,        // amount available to stake
+                amount.checked_sub(amount_staked)?, // amount we still need to stake
+                remaining_value,                    // amount available to stake
             );
-            amount_staked += amount_to_stake;
-            remaining_value -= amount_to_stake;
+            amount_staked = amount_staked.checked_add(amount_to_stake)?;
+            remaining_value = remaining_value.checked_sub(amount_to_stake)?;
 
             // add the input to the consumed inputs
             ins.push(txs::transferable::Input {
@@ -284,33 +329,25 @@ where
                 ..txs::transferable::Input::default()
             });
 
-            if amount_to_stake > 0 {
+            if amount_to_stake > NanoAvax::ZERO {
                 // some of this input was put for staking
                 staked_outputs.push(txs::transferable::Output {
                     asset_id: utxo.asset_id,
                     transfer_output: Some(key::secp256k1::txs::transfer::Output {
-                        amount: amount_to_stake,
-                        output_owners: key::secp256k1::txs::OutputOwners {
-                            locktime: 0,
-                            threshold: 1,
-                            addresses: vec![self.inner.short_address.clone()],
-                        },
+                        amount: amount_to_stake.as_u64(),
+                        output_owners: stake_owner.clone(),
                     }),
                     ..txs::transferable::Output::default()
                 });
             }
 
-            if remaining_value > 0 {
+            if remaining_value > NanoAvax::ZERO {
                 // this input had extra value, so some must be returned
                 returned_outputs.push(txs::transferable::Output {
                     asset_id: utxo.asset_id,
                     transfer_output: Some(key::secp256k1::txs::transfer::Output {
-                        amount: remaining_value,
-                        output_owners: key::secp256k1::txs::OutputOwners {
-                            locktime: 0,
-                            threshold: 1,
-                            addresses: vec![self.inner.short_address.clone()],
-                        },
+                        amount: remaining_value.as_u64(),
+                        output_owners: change_owner.clone(),
                     }),
                     ..txs::transferable::Output::default()
                 });
@@ -339,15 +376,27 @@ where
             ));
         }
 
-        // TODO: for now just ignore "signers" in the sorting
-        // since the wallet currently only supports one key
-        ins.sort();
+        // credentials must follow the sorted inputs
+        keychain::sort_with_signers(&mut ins, &mut signers);
         returned_outputs.sort();
         staked_outputs.sort();
 
         Ok((ins, returned_outputs, staked_outputs, signers))
     }
 
+    /// Fetches the owners of the subnet, whose threshold of control keys
+    /// must sign the subnet auth credential.
+    async fn subnet_owners(
+        &self,
+        subnet_id: ids::Id,
+    ) -> io::Result<key::secp256k1::txs::OutputOwners> {
+        let tx = client_p::get_tx(&self.inner.pick_http_rpc().1, &subnet_id.to_string()).await?;
+        match tx.result {
+            Some(tx_result) => Ok(tx_result.tx.unsigned_tx.output_owners),
+            None => Err(Error::new(ErrorKind::Other, "empty get tx result")),
+        }
+    }
+
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/vms/platformvm/utxo/handler.go#L411 "Authorize"
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/wallet/chain/p/builder.go#L360-L390 "NewAddSubnetValidatorTx"
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.0/vms/platformvm/txs/builder/builder.go#L512 "NewAddSubnetValidatorTx"
@@ -356,36 +405,101 @@ where
         subnet_id: ids::Id,
     ) -> io::Result<(key::secp256k1::txs::Input, Vec<Vec<T>>)> {
         log::info!("authorizing subnet {}", subnet_id);
+        let output_owners = self.subnet_owners(subnet_id).await?;
 
-        let tx = client_p::get_tx(&self.inner.pick_http_rpc().1, &subnet_id.to_string()).await?;
-        if let Some(tx_result) = tx.result {
-            let output_owners = tx_result.tx.unsigned_tx.output_owners;
-
-            let now_unix = SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .expect("unexpected None duration_since")
-                .as_secs();
-
-            let res = self
-                .inner
-                .keychain
-                .match_threshold(&output_owners, now_unix);
-            let threshold_met = res.is_some();
-            if !threshold_met {
-                return Err(Error::new(ErrorKind::Other, "no threshold met, can't sign"));
-            }
-            let (sig_indices, keys) = res.unwrap();
-
-            return Ok((
-                key::secp256k1::txs::Input {
-                    // if empty, it errors with "unauthorized subnet modification: input has less signers than expected"
-                    sig_indices,
-                },
-                vec![keys],
+        let now_unix = time::unix_now_secs();
+
+        let res = self
+            .inner
+            .keychain
+            .match_threshold(&output_owners, now_unix);
+        let threshold_met = res.is_some();
+        if !threshold_met {
+            return Err(Error::new(ErrorKind::Other, "no threshold met, can't sign"));
+        }
+        let (sig_indices, keys) = res.unwrap();
+        keychain::verify_signers(&output_owners, &sig_indices, &keys)?;
+
+        Ok((
+            key::secp256k1::txs::Input {
+                // if empty, it errors with "unauthorized subnet modification: input has less signers than expected"
+                sig_indices,
+            },
+            vec![keys],
+        ))
+    }
+
+    /// Same as "authorize", but for the control keys that sign the subnet auth
+    /// credential, which need not be in the keychain (see "PartialTx").
+    async fn authorize_signers(
+        &self,
+        subnet_id: ids::Id,
+        signers: &[short::Id],
+    ) -> io::Result<(
+        key::secp256k1::txs::Input,
+        key::secp256k1::txs::OutputOwners,
+    )> {
+        log::info!("authorizing subnet {} for {:?}", subnet_id, signers);
+        let output_owners = self.subnet_owners(subnet_id).await?;
+
+        let mut sig_indices = Vec::with_capacity(signers.len());
+        for signer in signers.iter() {
+            let pos = output_owners
+                .addresses
+                .iter()
+                .position(|addr| addr == signer)
+                .ok_or_else(|| {
+                    Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("{} is not a control key of subnet {}", signer, subnet_id),
+                    )
+                })?;
+            sig_indices.push(pos as u32);
+        }
+        sig_indices.sort_unstable();
+        sig_indices.dedup();
+        if sig_indices.len() != output_owners.threshold as usize {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "{} distinct control keys but the subnet threshold is {}",
+                    sig_indices.len(),
+                    output_owners.threshold
+                ),
             ));
         }
 
-        return Err(Error::new(ErrorKind::Other, "empty get tx result"));
+        Ok((key::secp256k1::txs::Input { sig_indices }, output_owners))
+    }
+
+    /// Creates the partially-signed transaction, with the signatures of the
+    /// keys in the keychain among its signers.
+    async fn partial(
+        &self,
+        tx: SubnetAuthTx,
+        signers: Vec<Vec<T>>,
+        subnet_owners: &key::secp256k1::txs::OutputOwners,
+    ) -> io::Result<PartialTx> {
+        let mut input_signers = Vec::with_capacity(signers.len());
+        for keys in signers.iter() {
+            input_signers.push(
+                keys.iter()
+                    .map(|k| k.short_address())
+                    .collect::<io::Result<Vec<short::Id>>>()?,
+            );
+        }
+
+        let mut partial = PartialTx::new(tx, input_signers, subnet_owners)?;
+        for addr in partial.missing() {
+            if let Some(k) = self.inner.keychain.get(&addr) {
+                partial.sign(&k).await?;
+            }
+        }
+        log::info!(
+            "partially signed tx, missing signatures of {:?}",
+            partial.missing()
+        );
+        Ok(partial)
     }
 
     /// Subnet validators must validate the primary network.
@@ -414,4 +528,16 @@ where
     pub fn create_chain(&self) -> create_chain::Tx<T> {
         create_chain::Tx::new(self)
     }
+
+    /// Issues the transaction that was built and signed outside of the wallet.
+    #[must_use]
+    pub fn issue_raw(&self, tx_bytes: &[u8]) -> issue_raw::Tx<T> {
+        issue_raw::Tx::new(self, tx_bytes)
+    }
+
+    /// Sweeps the small UTXOs into fewer outputs.
+    #[must_use]
+    pub fn consolidate(&self) -> consolidate::Tx<T> {
+        consolidate::Tx::new(self)
+    }
 }
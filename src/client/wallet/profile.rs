@@ -0,0 +1,307 @@
+//! Wallet profiles that persist the keychain public material and the
+//! wallet settings between runs (e.g., of a CLI tool), never the private keys.
+//!
+//! The profile is saved in a versioned envelope "{"version": N, "profile": ...}".
+//! "load" migrates the profiles saved by older versions of the schema one
+//! version at a time, and rejects the profiles saved by newer versions.
+
+use std::{
+    fs,
+    io::{self, Error, ErrorKind, Write},
+    path::Path,
+};
+
+use crate::{
+    client::wallet::{Builder, Wallet},
+    ids::short,
+    key,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// Current version of the profile schema.
+/// Version 1 saved one "httpRpc" and the keys as the short addresses only.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Public material of one keychain key.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Key {
+    pub short_address: short::Id,
+    /// Empty if migrated from the version 1, which did not save it.
+    #[serde(default)]
+    pub eth_address: String,
+}
+
+impl Key {
+    pub fn new<T: key::secp256k1::ReadOnly>(k: &T) -> io::Result<Self> {
+        Ok(Self {
+            short_address: k.short_address()?,
+            eth_address: k.eth_address(),
+        })
+    }
+}
+
+/// Wallet settings, which are the "wallet::Builder" options.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Settings {
+    pub http_rpcs: Vec<String>,
+    /// Network ID of the endpoints when saved, zero if unknown.
+    #[serde(default)]
+    pub network_id: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hrp: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    pub keys: Vec<Key>,
+    pub settings: Settings,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    version: u32,
+    profile: Value,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Profile {
+    pub fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            settings: Settings::default(),
+        }
+    }
+
+    /// Creates the profile of the keychain keys, with the settings to set.
+    pub fn from_keychain<T>(keychain: &key::secp256k1::keychain::Keychain<T>) -> io::Result<Self>
+    where
+        T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    {
+        let keys = keychain
+            .keys
+            .iter()
+            .map(Key::new)
+            .collect::<io::Result<Vec<_>>>()?;
+        Ok(Self {
+            keys,
+            settings: Settings::default(),
+        })
+    }
+
+    /// Creates the profile of the wallet keychain and settings.
+    pub fn from_wallet<T>(w: &Wallet<T>) -> io::Result<Self>
+    where
+        T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    {
+        let mut p = Self::from_keychain(&w.keychain)?;
+        p.settings = Settings {
+            http_rpcs: w.http_rpcs.clone(),
+            network_id: w.network_id,
+            hrp: Some(w.hrp.clone()),
+        };
+        Ok(p)
+    }
+
+    /// Returns the wallet builder with the saved settings, for the key that
+    /// must be in the profile (the private key is supplied by the caller).
+    pub fn builder<T>(&self, k: &T) -> io::Result<Builder<T>>
+    where
+        T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+    {
+        let short_address = k.short_address()?;
+        if !self.keys.iter().any(|p| p.short_address == short_address) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("key {} not in the profile", short_address),
+            ));
+        }
+        if self.settings.http_rpcs.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "no http rpc in the profile",
+            ));
+        }
+
+        let mut b = Builder::new(k).http_rpcs(self.settings.http_rpcs.clone());
+        if let Some(hrp) = &self.settings.hrp {
+            b = b.hrp(hrp.clone());
+        }
+        Ok(b)
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        let profile = serde_json::to_value(self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize profile to JSON {}", e),
+            )
+        })?;
+        serde_json::to_string_pretty(&Envelope {
+            version: SCHEMA_VERSION,
+            profile,
+        })
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize profile to JSON {}", e),
+            )
+        })
+    }
+
+    /// Decodes the profile of any version up to "SCHEMA_VERSION".
+    pub fn decode_json(d: &[u8]) -> io::Result<Self> {
+        let envelope: Envelope = serde_json::from_slice(d).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid profile envelope '{}'", e),
+            )
+        })?;
+        if envelope.version == 0 || envelope.version > SCHEMA_VERSION {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "unsupported profile version {} (supports up to {})",
+                    envelope.version, SCHEMA_VERSION
+                ),
+            ));
+        }
+
+        let mut profile = envelope.profile;
+        for version in envelope.version..SCHEMA_VERSION {
+            profile = migrate(version, profile)?;
+        }
+        serde_json::from_value(profile).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid profile version {} '{}'", SCHEMA_VERSION, e),
+            )
+        })
+    }
+
+    /// Saves the profile to a temporary file and renames it over the
+    /// previous one, so a crash leaves either profile.
+    pub fn sync(&self, file_path: &str) -> io::Result<()> {
+        log::info!("syncing wallet profile to '{}'", file_path);
+        let path = Path::new(file_path);
+        if let Some(parent_dir) = path.parent() {
+            fs::create_dir_all(parent_dir)?;
+        }
+
+        let d = self.encode_json()?;
+        let tmp_path = path.with_extension("tmp");
+        let mut f = fs::File::create(&tmp_path)?;
+        f.write_all(d.as_bytes())?;
+        f.sync_all()?;
+        fs::rename(&tmp_path, path)
+    }
+
+    pub fn load(file_path: &str) -> io::Result<Self> {
+        log::info!("loading wallet profile from '{}'", file_path);
+        let d = fs::read(file_path)?;
+        Self::decode_json(&d)
+    }
+}
+
+/// Migrates the profile from the version to the next.
+fn migrate(version: u32, mut profile: Value) -> io::Result<Value> {
+    match version {
+        1 => {
+            // "httpRpc" became "settings.httpRpcs", and each key an object
+            let obj = profile.as_object_mut().ok_or_else(|| {
+                Error::new(ErrorKind::InvalidData, "profile version 1 not an object")
+            })?;
+            let http_rpcs: Vec<Value> = obj.remove("httpRpc").into_iter().collect();
+            let keys: Vec<Value> = match obj.remove("keys") {
+                Some(Value::Array(addrs)) => addrs
+                    .into_iter()
+                    .map(|a| json!({ "shortAddress": a }))
+                    .collect(),
+                Some(_) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        "profile version 1 keys not an array",
+                    ))
+                }
+                None => Vec::new(),
+            };
+            Ok(json!({
+                "keys": keys,
+                "settings": {
+                    "httpRpcs": http_rpcs,
+                    "networkId": obj.remove("networkId").unwrap_or(json!(0)),
+                },
+            }))
+        }
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("no migration from profile version {}", version),
+        )),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::profile::test_profile --exact --show-output
+#[test]
+fn test_profile() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let k = key::secp256k1::TEST_KEYS[0].clone();
+    let keychain = key::secp256k1::keychain::Keychain::new(vec![k.clone()]);
+    let mut p = Profile::from_keychain(&keychain).unwrap();
+    p.settings.http_rpcs = vec![String::from("http://localhost:9650")];
+    p.settings.hrp = Some(String::from("custom"));
+
+    let d = p.encode_json().unwrap();
+    assert!(d.contains(r#""version": 2"#));
+    // only the public material is saved
+    let private_key = k.to_cb58();
+    assert!(!d.contains(&private_key));
+    assert_eq!(Profile::decode_json(d.as_bytes()).unwrap(), p);
+
+    let dir = tempfile::tempdir().unwrap();
+    let file_path = dir.path().join("profiles").join("default.json");
+    let file_path = file_path.to_str().unwrap();
+    p.sync(file_path).unwrap();
+    let loaded = Profile::load(file_path).unwrap();
+    assert_eq!(loaded, p);
+
+    let b = loaded.builder(&k).unwrap();
+    assert_eq!(b.http_rpcs, p.settings.http_rpcs);
+    assert_eq!(b.hrp, Some(String::from("custom")));
+    assert_eq!(
+        loaded
+            .builder(&key::secp256k1::TEST_KEYS[1])
+            .unwrap_err()
+            .kind(),
+        ErrorKind::InvalidInput
+    );
+
+    // version 1 is migrated
+    let v1 = format!(
+        r#"{{"version":1,"profile":{{"httpRpc":"http://localhost:9650","networkId":1337,"keys":["{}"]}}}}"#,
+        p.keys[0].short_address
+    );
+    let migrated = Profile::decode_json(v1.as_bytes()).unwrap();
+    assert_eq!(migrated.keys[0].short_address, p.keys[0].short_address);
+    assert_eq!(migrated.keys[0].eth_address, "");
+    assert_eq!(migrated.settings.http_rpcs, p.settings.http_rpcs);
+    assert_eq!(migrated.settings.network_id, 1337);
+    assert_eq!(migrated.settings.hrp, None);
+
+    let v3 = r#"{"version":3,"profile":{}}"#;
+    assert_eq!(
+        Profile::decode_json(v3.as_bytes()).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
@@ -0,0 +1,394 @@
+//! Issuance queue that serializes the issuances on each chain.
+//!
+//! Two transfers signed at the same time pick the same UTXOs, and the
+//! second one conflicts with the first. The queue runs one issuance per
+//! chain at a time (from the UTXO fetch to the acceptance check), in the
+//! submission order, while the issuances on different chains run in
+//! parallel. Set it with "wallet::Builder::issuance_queue", and enable
+//! "check_acceptance" for the transactions that spend the outputs of the
+//! previous one, so that the next one only starts after the acceptance.
+//!
+//! Every queued issuance gets a ticket, and its state can be inspected
+//! while it waits, runs, and for the last "max_finished" after it ends.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use crate::{
+    client::time::{Duration, Instant},
+    ids,
+};
+
+pub const DEFAULT_MAX_FINISHED: usize = 1024;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum State {
+    /// Waiting for the previous issuances on the chain.
+    Queued,
+    /// Fetching the UTXOs and signing.
+    Signing,
+    /// Issued to the node, and waiting for the acceptance.
+    Pending,
+    /// Issued without checking the acceptance.
+    Issued,
+    Accepted,
+    Failed,
+}
+
+impl State {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, State::Issued | State::Accepted | State::Failed)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Entry {
+    pub ticket: u64,
+    /// Chain alias (e.g., "X", "P").
+    pub chain: String,
+    /// Transaction type (e.g., "transfer", "add_validator", "raw").
+    pub tx_type: String,
+    pub state: State,
+    /// Known once the transaction is signed and about to be issued.
+    pub tx_id: Option<ids::Id>,
+    pub error: Option<String>,
+    pub queued_at: Instant,
+    /// Time from the submission to the end, if finished.
+    pub elapsed: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub struct IssuanceQueue {
+    pub max_finished: usize,
+    chains: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    entries: Mutex<BTreeMap<u64, Entry>>,
+    next_ticket: AtomicU64,
+}
+
+impl Default for IssuanceQueue {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+tokio::task_local! {
+    static CURRENT: (Arc<IssuanceQueue>, u64);
+}
+
+impl IssuanceQueue {
+    pub fn default() -> Self {
+        Self::new(DEFAULT_MAX_FINISHED)
+    }
+
+    /// Creates the queue that keeps the last "max_finished" finished entries.
+    pub fn new(max_finished: usize) -> Self {
+        Self {
+            max_finished,
+            chains: Mutex::new(HashMap::new()),
+            entries: Mutex::new(BTreeMap::new()),
+            next_ticket: AtomicU64::new(0),
+        }
+    }
+
+    /// Runs the issuance once the previous issuances on the chain end.
+    /// The wallet marks the entry as pending when it sends the transaction,
+    /// and it ends as accepted if "check_acceptance", otherwise as issued.
+    /// The entry of an issuance that sends nothing (e.g., dry mode) is dropped,
+    /// and so is the entry of an issuance whose future is dropped before it
+    /// sends anything. If dropped after sending, it ends as failed.
+    pub async fn run<F, R>(
+        self: &Arc<Self>,
+        chain: &str,
+        tx_type: &str,
+        check_acceptance: bool,
+        issuance: F,
+    ) -> std::io::Result<R>
+    where
+        F: Future<Output = std::io::Result<R>>,
+    {
+        let ticket = self.next_ticket.fetch_add(1, Ordering::SeqCst);
+        let queued_at = Instant::now();
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .insert(
+                ticket,
+                Entry {
+                    ticket,
+                    chain: chain.to_string(),
+                    tx_type: tx_type.to_string(),
+                    state: State::Queued,
+                    tx_id: None,
+                    error: None,
+                    queued_at,
+                    elapsed: None,
+                },
+            );
+        let mut guard = Guard {
+            queue: self,
+            ticket,
+            finished: false,
+        };
+
+        // "tokio::sync::Mutex" is fair, so the waiters run in the submission order
+        let chain_lock = self
+            .chains
+            .lock()
+            .expect("unexpected poisoned lock")
+            .entry(chain.to_string())
+            .or_default()
+            .clone();
+        let _slot = chain_lock.lock().await;
+        self.update(ticket, |e| e.state = State::Signing);
+        log::debug!("running queued {} issuance #{}", chain, ticket);
+
+        let res = CURRENT.scope((self.clone(), ticket), issuance).await;
+
+        guard.finished = true;
+        let outcome = match &res {
+            Ok(_) if check_acceptance => Outcome::Accepted,
+            Ok(_) => Outcome::Issued,
+            Err(e) => Outcome::Failed(e.to_string()),
+        };
+        self.finish(ticket, outcome);
+        res
+    }
+
+    /// Ends the entry with the outcome, and removes the oldest finished
+    /// entries beyond "max_finished".
+    fn finish(&self, ticket: u64, outcome: Outcome) {
+        let mut entries = self.entries.lock().expect("unexpected poisoned lock");
+        let sent = match entries.get(&ticket) {
+            Some(entry) => entry.tx_id.is_some(),
+            None => return,
+        };
+        let (state, error) = match outcome {
+            Outcome::Accepted | Outcome::Issued | Outcome::Dropped if !sent => {
+                entries.remove(&ticket);
+                return;
+            }
+            Outcome::Accepted => (State::Accepted, None),
+            Outcome::Issued => (State::Issued, None),
+            Outcome::Failed(e) => (State::Failed, Some(e)),
+            Outcome::Dropped => (
+                State::Failed,
+                Some(String::from("issuance dropped before it ended")),
+            ),
+        };
+        if let Some(entry) = entries.get_mut(&ticket) {
+            entry.state = state;
+            entry.error = error;
+            entry.elapsed = Some(entry.queued_at.elapsed());
+        }
+
+        let finished: Vec<u64> = entries
+            .values()
+            .filter(|e| e.state.is_finished())
+            .map(|e| e.ticket)
+            .collect();
+        for ticket in finished
+            .iter()
+            .take(finished.len().saturating_sub(self.max_finished))
+        {
+            entries.remove(ticket);
+        }
+    }
+
+    fn update<U: FnOnce(&mut Entry)>(&self, ticket: u64, u: U) {
+        if let Some(entry) = self
+            .entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .get_mut(&ticket)
+        {
+            u(entry);
+        }
+    }
+
+    /// Returns the entries in the submission order.
+    pub fn entries(&self) -> Vec<Entry> {
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    pub fn entry(&self, ticket: u64) -> Option<Entry> {
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .get(&ticket)
+            .cloned()
+    }
+
+    /// Returns the latest entry of the transaction.
+    pub fn find(&self, tx_id: &ids::Id) -> Option<Entry> {
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .values()
+            .rev()
+            .find(|e| e.tx_id.as_ref() == Some(tx_id))
+            .cloned()
+    }
+
+    /// Returns the entries in the state, on any chain if None.
+    pub fn in_state(&self, state: State, chain: Option<&str>) -> Vec<Entry> {
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .values()
+            .filter(|e| e.state == state && chain.map_or(true, |c| e.chain == c))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the number of the issuances that have not ended yet.
+    pub fn in_flight(&self) -> usize {
+        self.entries
+            .lock()
+            .expect("unexpected poisoned lock")
+            .values()
+            .filter(|e| !e.state.is_finished())
+            .count()
+    }
+}
+
+enum Outcome {
+    Accepted,
+    Issued,
+    Failed(String),
+    /// The future of the issuance was dropped before it ended.
+    Dropped,
+}
+
+/// Ends the entry if the future of the issuance is dropped while it waits
+/// or runs (e.g., on a timeout), so that it does not stay in flight.
+struct Guard<'a> {
+    queue: &'a IssuanceQueue,
+    ticket: u64,
+    finished: bool,
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        if !self.finished {
+            log::warn!("queued issuance #{} dropped before it ended", self.ticket);
+            self.queue.finish(self.ticket, Outcome::Dropped);
+        }
+    }
+}
+
+/// Sets the transaction ID of the queued issuance that runs the caller, if any.
+/// Call it right before sending the transaction to the node.
+pub(crate) fn mark_signed(tx_id: &ids::Id) {
+    let _ = CURRENT.try_with(|(queue, ticket)| queue.update(*ticket, |e| e.tx_id = Some(*tx_id)));
+}
+
+/// Marks the queued issuance that runs the caller as pending, if any.
+/// Call it once the node has accepted the transaction for issuance.
+pub(crate) fn mark_issued() {
+    let _ = CURRENT.try_with(|(queue, ticket)| queue.update(*ticket, |e| e.state = State::Pending));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::queue::test_issuance_queue --exact --show-output
+#[tokio::test]
+async fn test_issuance_queue() {
+    use std::io::{Error, ErrorKind};
+
+    use futures::{future::FutureExt, poll};
+    use tokio::sync::oneshot;
+
+    let queue = Arc::new(IssuanceQueue::new(3));
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    // the issuance runs until "release" fires, if any
+    let issue = |chain: &'static str, n: u8, release: Option<oneshot::Receiver<()>>| {
+        let (queue, order) = (queue.clone(), order.clone());
+        async move {
+            queue
+                .run(chain, "transfer", n % 2 == 0, async {
+                    let tx_id = ids::Id::from_slice(&[n; 32]);
+                    mark_signed(&tx_id);
+                    order.lock().unwrap().push(format!("{}{} start", chain, n));
+                    if let Some(release) = release {
+                        release.await.unwrap();
+                    }
+                    mark_issued();
+                    order.lock().unwrap().push(format!("{}{} end", chain, n));
+                    if n == 3 {
+                        return Err(Error::new(ErrorKind::Other, "conflict"));
+                    }
+                    Ok(tx_id)
+                })
+                .await
+        }
+        .boxed()
+    };
+
+    // the first X issuance runs until released, and the second X one
+    // waits for it, while the P issuance runs meanwhile
+    let (release_x1, rx) = oneshot::channel();
+    let mut x1 = issue("X", 1, Some(rx));
+    assert!(poll!(&mut x1).is_pending());
+    let mut x2 = issue("X", 2, None);
+    assert!(poll!(&mut x2).is_pending());
+    issue("P", 3, None).await.unwrap_err();
+
+    assert_eq!(queue.in_flight(), 2);
+    assert_eq!(queue.in_state(State::Signing, Some("X")).len(), 1);
+    assert_eq!(queue.in_state(State::Queued, None)[0].ticket, 1);
+    assert_eq!(queue.entry(2).unwrap().state, State::Failed);
+    assert_eq!(queue.entry(2).unwrap().error.as_deref(), Some("conflict"));
+
+    release_x1.send(()).unwrap();
+    x1.await.unwrap();
+    x2.await.unwrap();
+    assert_eq!(
+        *order.lock().unwrap(),
+        vec!["X1 start", "P3 start", "P3 end", "X1 end", "X2 start", "X2 end"]
+    );
+    assert_eq!(queue.entry(0).unwrap().state, State::Issued);
+    assert_eq!(
+        queue.find(&ids::Id::from_slice(&[2; 32])).unwrap().state,
+        State::Accepted
+    );
+    assert!(queue.entry(0).unwrap().elapsed.is_some());
+
+    // dry runs leave no entry, and only the last finished are kept
+    queue
+        .run("X", "transfer", false, async { Ok(()) })
+        .await
+        .unwrap();
+    assert_eq!(queue.entries().len(), 3);
+    issue("X", 5, None).await.unwrap();
+    let tickets: Vec<u64> = queue.entries().iter().map(|e| e.ticket).collect();
+    assert_eq!(tickets, vec![1, 2, 4]);
+    assert_eq!(queue.in_flight(), 0);
+
+    // dropped while sending, it fails, and while queued, it leaves no entry
+    let (_release_x6, rx) = oneshot::channel();
+    let mut x6 = issue("X", 6, Some(rx));
+    assert!(poll!(&mut x6).is_pending());
+    let mut x7 = issue("X", 7, None);
+    assert!(poll!(&mut x7).is_pending());
+    assert_eq!(queue.in_flight(), 2);
+    drop(x7);
+    drop(x6);
+    assert_eq!(queue.in_flight(), 0);
+    assert!(queue.entry(6).is_none());
+    let x6 = queue.entry(5).unwrap();
+    assert_eq!(x6.state, State::Failed);
+    assert!(x6.error.is_some());
+
+    // the chain is free again
+    issue("X", 8, None).await.unwrap();
+}
@@ -0,0 +1,287 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{client::wallet::journal, ids, jsonrpc, key, packer, txs, units::NanoAvax};
+use serde::Serialize;
+
+/// Transaction built and signed by the wallet, with everything that audit
+/// logs need without decoding the transaction again.
+#[derive(Debug, Serialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Signed {
+    /// Chain alias (e.g., "X", "P").
+    pub chain: String,
+    /// Transaction type (e.g., "create_subnet").
+    pub tx_type: String,
+    #[serde(rename = "txID")]
+    pub tx_id: ids::Id,
+
+    /// Signed transaction bytes.
+    #[serde(skip)]
+    pub tx_bytes: Vec<u8>,
+    /// Unsigned transaction bytes, whose SHA256 digest the credentials sign.
+    #[serde(skip)]
+    pub unsigned_tx_bytes: Vec<u8>,
+    /// Signed transaction bytes in the 0x-prefixed hex with checksum,
+    /// as accepted by the issueTx APIs.
+    pub tx_hex: String,
+
+    /// Amount burned by the transaction in nano-AVAX.
+    pub fee: u64,
+    /// Sum of the consumed AVAX inputs in nano-AVAX, excluding the other
+    /// assets whose amounts are not comparable (e.g., the transferred ANTs).
+    pub input_amount: u64,
+    /// Sum of the created AVAX outputs in nano-AVAX, excluding the staked
+    /// outputs and the other assets.
+    pub output_amount: u64,
+    /// Amount locked in the staked outputs in nano-AVAX.
+    pub staked_amount: u64,
+    /// UTXOs that the transaction spends.
+    #[serde(rename = "consumedUTXOs")]
+    pub consumed_utxos: Vec<txs::utxo::Id>,
+    /// UTXOs that the transaction creates, excluding the staked outputs
+    /// which are only returned at the end of the staking period.
+    #[serde(rename = "createdUTXOs")]
+    pub created_utxos: Vec<txs::utxo::Id>,
+
+    /// JSON rendering of the unsigned transaction.
+    pub tx: serde_json::Value,
+
+    /// Unix timestamp in milliseconds when the transaction was signed.
+    pub signed_at: u64,
+}
+
+impl Signed {
+    /// Creates from the signed transaction and its base transaction,
+    /// with the amounts of the AVAX asset.
+    pub fn new<S: Serialize>(
+        chain: &str,
+        tx_type: &str,
+        tx: &S,
+        base_tx: &txs::Tx,
+        avax_asset_id: &ids::Id,
+        fee: u64,
+    ) -> io::Result<Self> {
+        let metadata = base_tx.metadata.as_ref().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} transaction is not signed", tx_type),
+            )
+        })?;
+        let tx_id = metadata.id;
+        txs::limits::check_base_tx(base_tx)?;
+
+        let consumed_utxos = base_tx
+            .transferable_inputs
+            .as_ref()
+            .map(|ins| ins.iter().map(|i| i.utxo_id.clone()).collect())
+            .unwrap_or_default();
+        let input_amount = NanoAvax::checked_sum(
+            base_tx
+                .transferable_inputs
+                .iter()
+                .flatten()
+                .filter(|i| i.asset_id == *avax_asset_id)
+                .map(txs::burn::input_amount),
+        )?
+        .as_u64();
+        let output_amount = NanoAvax::checked_sum(
+            base_tx
+                .transferable_outputs
+                .iter()
+                .flatten()
+                .filter(|o| o.asset_id == *avax_asset_id)
+                .map(txs::burn::output_amount),
+        )?
+        .as_u64();
+        let mut created_utxos = Vec::new();
+        let n = base_tx
+            .transferable_outputs
+            .as_ref()
+            .map(|outs| outs.len())
+            .unwrap_or(0);
+        for i in 0..n {
+            created_utxos.push(txs::utxo::Id::new(tx_id.as_ref(), i as u32, false)?);
+        }
+
+        let tx = serde_json::to_value(tx).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed serde_json::to_value '{}'", e),
+            )
+        })?;
+        Ok(Self {
+            chain: chain.to_string(),
+            tx_type: tx_type.to_string(),
+            tx_id,
+            tx_bytes: metadata.tx_bytes_with_signatures.clone(),
+            unsigned_tx_bytes: metadata.tx_bytes_with_no_signature.clone(),
+            tx_hex: jsonrpc::issue::encode_tx_hex(&metadata.tx_bytes_with_signatures),
+            fee,
+            input_amount,
+            output_amount,
+            staked_amount: 0,
+            consumed_utxos,
+            created_utxos,
+            tx,
+            signed_at: journal::now_unix_millis(),
+        })
+    }
+
+    /// Returns the signatures of each credential, unpacked from the signed
+    /// bytes that are the unsigned bytes followed by the credentials.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Credential
+    pub fn credentials(&self) -> io::Result<Vec<Vec<Vec<u8>>>> {
+        if !self.tx_bytes.starts_with(&self.unsigned_tx_bytes) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "signed bytes do not start with the unsigned bytes",
+            ));
+        }
+        let packer = packer::Packer::load_bytes_for_unpack(
+            self.tx_bytes.len(),
+            &self.tx_bytes[self.unsigned_tx_bytes.len()..],
+        );
+
+        let creds_len = unpack_slice_len(&packer)?;
+        let mut creds = Vec::new();
+        for _ in 0..creds_len {
+            // secp256k1fx credential type Id
+            packer.unpack_u32()?;
+            let sigs_len = unpack_slice_len(&packer)?;
+            let mut sigs = Vec::new();
+            for _ in 0..sigs_len {
+                sigs.push(packer.unpack_bytes(key::secp256k1::signature::LEN)?);
+            }
+            creds.push(sigs);
+        }
+        if packer.get_offset() != self.tx_bytes.len() - self.unsigned_tx_bytes.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "unexpected trailing bytes after the credentials",
+            ));
+        }
+        Ok(creds)
+    }
+
+    /// Renders the JSON for audit logs.
+    pub fn to_json(&self) -> io::Result<String> {
+        serde_json::to_string(self).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed serde_json::to_string '{}'", e),
+            )
+        })
+    }
+}
+
+/// Unpacks the length of a slice, bounded as the node's codec does.
+fn unpack_slice_len(packer: &packer::Packer) -> io::Result<usize> {
+    let n = packer.unpack_u32()? as usize;
+    if n > txs::limits::MAX_SLICE_LEN {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "slice length {} exceeds the maximum of {}",
+                n,
+                txs::limits::MAX_SLICE_LEN
+            ),
+        ));
+    }
+    Ok(n)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::signed::test_signed --exact --show-output
+#[test]
+fn test_signed() {
+    // the ANT amounts would overflow the sum with the AVAX amounts
+    let avax_asset_id = ids::Id::empty();
+    let ant_asset_id = ids::Id::from_slice(&[9; 32]);
+    let mut base_tx = txs::Tx {
+        network_id: 1,
+        transferable_inputs: Some(vec![
+            txs::transferable::Input {
+                utxo_id: txs::utxo::Id::new(&[7; 32], 2, false).unwrap(),
+                transfer_input: Some(crate::key::secp256k1::txs::transfer::Input {
+                    amount: 3_000_000,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            txs::transferable::Input {
+                utxo_id: txs::utxo::Id::new(&[8; 32], 0, false).unwrap(),
+                asset_id: ant_asset_id,
+                transfer_input: Some(crate::key::secp256k1::txs::transfer::Input {
+                    amount: u64::MAX,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ]),
+        transferable_outputs: Some(vec![
+            txs::transferable::Output {
+                transfer_output: Some(crate::key::secp256k1::txs::transfer::Output {
+                    amount: 1_500_000,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            txs::transferable::Output::default(),
+            txs::transferable::Output {
+                asset_id: ant_asset_id,
+                transfer_output: Some(crate::key::secp256k1::txs::transfer::Output {
+                    amount: u64::MAX,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        ]),
+        ..Default::default()
+    };
+    assert!(Signed::new("X", "transfer", &base_tx, &base_tx, &avax_asset_id, 1).is_err());
+
+    let tx_id = ids::Id::from_slice(&[1; 32]);
+    base_tx.metadata = Some(txs::Metadata {
+        id: tx_id,
+        tx_bytes_with_no_signature: vec![1, 2],
+        tx_bytes_with_signatures: vec![1, 2, 3],
+    });
+    let signed = Signed::new(
+        "X",
+        "transfer",
+        &base_tx,
+        &base_tx,
+        &avax_asset_id,
+        1_000_000,
+    )
+    .unwrap();
+    assert_eq!(signed.tx_id, tx_id);
+    assert_eq!(signed.tx_bytes, vec![1, 2, 3]);
+    assert_eq!(
+        jsonrpc::issue::decode_tx_hex(&signed.tx_hex).unwrap(),
+        signed.tx_bytes
+    );
+    assert_eq!(signed.input_amount, 3_000_000);
+    assert_eq!(signed.output_amount, 1_500_000);
+    assert!(signed.signed_at > 0);
+    assert_eq!(signed.consumed_utxos.len(), 2);
+    assert_eq!(signed.consumed_utxos[0].output_index, 2);
+    assert_eq!(signed.created_utxos.len(), 3);
+    assert_eq!(signed.created_utxos[1].tx_id, tx_id);
+    assert_eq!(signed.created_utxos[1].output_index, 1);
+
+    let json: serde_json::Value = serde_json::from_str(&signed.to_json().unwrap()).unwrap();
+    assert_eq!(json["txID"], tx_id.to_string());
+    assert_eq!(json["txHex"], signed.tx_hex);
+    assert_eq!(json["fee"], 1_000_000);
+    assert_eq!(json["createdUTXOs"][1]["outputIndex"], 1);
+    assert_eq!(json["tx"]["networkID"], 1);
+
+    // the credentials count is bounded before unpacking
+    let mut signed = signed;
+    signed.unsigned_tx_bytes = vec![1, 2];
+    signed.tx_bytes = vec![1, 2, 0xff, 0xff, 0xff, 0xff];
+    assert_eq!(
+        signed.credentials().unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
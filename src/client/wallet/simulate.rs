@@ -0,0 +1,302 @@
+//! Simulation of the wallet-built transactions without issuing them.
+//!
+//! Neither the P-chain nor the X-chain API has a dry-run endpoint, so the
+//! node-side checks are limited to the state that the transaction depends
+//! on: the consumed UTXOs must still be unspent, and each credential must be
+//! signed by the owners of the UTXO that it spends.
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{
+    client::wallet::{signed::Signed, Wallet},
+    ids::short,
+    key, txs,
+};
+use ring::digest::{digest, SHA256};
+use serde::Serialize;
+
+/// Result of one simulation check.
+#[derive(Debug, Serialize, Eq, PartialEq, Clone)]
+pub struct Check {
+    pub name: String,
+    pub passed: bool,
+    pub message: String,
+}
+
+impl Check {
+    fn new(name: &str, result: Result<String, String>) -> Self {
+        let (passed, message) = match result {
+            Ok(m) => (true, m),
+            Err(m) => (false, m),
+        };
+        Self {
+            name: name.to_string(),
+            passed,
+            message,
+        }
+    }
+}
+
+/// Structured simulation report returned instead of issuing.
+#[derive(Debug, Serialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Report {
+    pub signed: Signed,
+    pub tx_size: usize,
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    /// Returns "true" if every check passed.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|c| c.passed)
+    }
+
+    pub fn failures(&self) -> Vec<&Check> {
+        self.checks.iter().filter(|c| !c.passed).collect()
+    }
+}
+
+/// Checks that the inputs cover exactly the outputs, the stake and the fee,
+/// since any excess would be burned silently.
+pub fn check_amounts(signed: &Signed) -> Check {
+    let spent = signed
+        .output_amount
+        .checked_add(signed.staked_amount)
+        .and_then(|v| v.checked_add(signed.fee));
+    let result = match spent {
+        Some(spent) if spent == signed.input_amount => Ok(format!(
+            "inputs {} = outputs {} + staked {} + fee {}",
+            signed.input_amount, signed.output_amount, signed.staked_amount, signed.fee
+        )),
+        Some(spent) => Err(format!(
+            "inputs {} != outputs {} + staked {} + fee {} (= {})",
+            signed.input_amount, signed.output_amount, signed.staked_amount, signed.fee, spent
+        )),
+        None => Err(String::from("outputs + staked + fee overflows u64")),
+    };
+    Check::new("amounts", result)
+}
+
+/// Checks the signed bytes against the mempool size limit.
+pub fn check_size(signed: &Signed) -> Check {
+    let n = signed.tx_bytes.len();
+    let result = txs::limits::check_tx_size(n)
+        .map(|_| format!("{} bytes (limit {})", n, txs::limits::MAX_TX_SIZE))
+        .map_err(|e| e.to_string());
+    Check::new("size", result)
+}
+
+/// Recovers the signer addresses of each credential.
+pub fn recover_signers(signed: &Signed) -> io::Result<Vec<Vec<short::Id>>> {
+    let hash = digest(&SHA256, &signed.unsigned_tx_bytes);
+
+    let mut signers = Vec::new();
+    for sigs in signed.credentials()? {
+        let mut addrs = Vec::new();
+        for sig in sigs.iter() {
+            let pubkey = key::secp256k1::public_key::Key::from_signature(hash.as_ref(), sig)?;
+            addrs.push(pubkey.to_short_id()?);
+        }
+        signers.push(addrs);
+    }
+    Ok(signers)
+}
+
+/// Checks that every input has a credential, and every signature recovers
+/// to one of the allowed addresses (e.g., the wallet keychain).
+pub fn check_signatures(signed: &Signed, allowed: &HashSet<short::Id>) -> Check {
+    let result = recover_signers(signed)
+        .map_err(|e| format!("failed to recover signers '{}'", e))
+        .and_then(|signers| {
+            if signers.len() < signed.consumed_utxos.len() {
+                return Err(format!(
+                    "{} credentials for {} inputs",
+                    signers.len(),
+                    signed.consumed_utxos.len()
+                ));
+            }
+            for (i, addrs) in signers.iter().enumerate() {
+                if addrs.is_empty() {
+                    return Err(format!("credential {} has no signature", i));
+                }
+                if let Some(addr) = addrs.iter().find(|a| !allowed.contains(a)) {
+                    return Err(format!("credential {} signed by unknown key {}", i, addr));
+                }
+            }
+            Ok(format!("{} credentials verified", signers.len()))
+        });
+    Check::new("signatures", result)
+}
+
+/// Checks the consumed UTXOs against the UTXOs that the node returns:
+/// each must be unspent, and its credential must be signed by its owners.
+pub fn check_utxos(signed: &Signed, utxos: &[txs::utxo::Utxo]) -> Check {
+    let mut owners = HashMap::new();
+    for u in utxos.iter() {
+        let out = if let Some(out) = &u.transfer_output {
+            out
+        } else if let Some(lock) = &u.stakeable_lock_out {
+            &lock.transfer_output
+        } else {
+            continue;
+        };
+        owners.insert(
+            (u.utxo_id.tx_id, u.utxo_id.output_index),
+            &out.output_owners.addresses,
+        );
+    }
+
+    let result = recover_signers(signed)
+        .map_err(|e| format!("failed to recover signers '{}'", e))
+        .and_then(|signers| {
+            for (i, utxo_id) in signed.consumed_utxos.iter().enumerate() {
+                let addrs = owners
+                    .get(&(utxo_id.tx_id, utxo_id.output_index))
+                    .ok_or_else(|| {
+                        format!(
+                            "UTXO {}:{} not found (spent or not owned by the wallet)",
+                            utxo_id.tx_id, utxo_id.output_index
+                        )
+                    })?;
+                let empty = Vec::new();
+                let cred = signers.get(i).unwrap_or(&empty);
+                if let Some(addr) = cred.iter().find(|a| !addrs.contains(a)) {
+                    return Err(format!(
+                        "UTXO {}:{} is not owned by the signer {}",
+                        utxo_id.tx_id, utxo_id.output_index, addr
+                    ));
+                }
+            }
+            Ok(format!(
+                "{} UTXOs unspent and signed by their owners",
+                signed.consumed_utxos.len()
+            ))
+        });
+    Check::new("utxos", result)
+}
+
+/// Runs the local checks on the signed transaction, and with "node_check",
+/// the UTXO checks against the node.
+pub async fn run<T>(wallet: &Wallet<T>, signed: Signed, node_check: bool) -> io::Result<Report>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    let allowed: HashSet<short::Id> = wallet
+        .keychain
+        .short_addr_to_key_index
+        .keys()
+        .cloned()
+        .collect();
+
+    let mut checks = vec![
+        check_amounts(&signed),
+        check_size(&signed),
+        check_signatures(&signed, &allowed),
+    ];
+    if node_check {
+        let utxos = match signed.chain.as_str() {
+            "P" => wallet.p().utxos().await?,
+            "X" => wallet.x().utxos().await?,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::Unsupported,
+                    format!("no node check for chain {}", signed.chain),
+                ))
+            }
+        };
+        checks.push(check_utxos(&signed, &utxos));
+    }
+
+    let report = Report {
+        tx_size: signed.tx_bytes.len(),
+        signed,
+        checks,
+    };
+    for c in report.failures() {
+        log::warn!("simulation check {} failed '{}'", c.name, c.message);
+    }
+    Ok(report)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::simulate::test_checks --exact --show-output
+#[tokio::test]
+async fn test_checks() {
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let addr = k.to_public_key().to_short_id().unwrap();
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+
+    let utxo_id = txs::utxo::Id::new(&[7; 32], 0, false).unwrap();
+    let mut tx = crate::platformvm::txs::create_subnet::Tx {
+        base_tx: txs::Tx {
+            network_id: 1,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id: utxo_id.clone(),
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 1_000,
+                    sig_indices: vec![0],
+                }),
+                ..Default::default()
+            }]),
+            transferable_outputs: Some(vec![txs::transferable::Output {
+                transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                    amount: 900,
+                    output_owners: owners.clone(),
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        },
+        owner: owners.clone(),
+        ..Default::default()
+    };
+    tx.sign(vec![vec![k.clone()]]).await.unwrap();
+
+    let signed = Signed::new(
+        "P",
+        "create_subnet",
+        &tx,
+        &tx.base_tx,
+        &crate::ids::Id::empty(),
+        100,
+    )
+    .unwrap();
+    assert_eq!(signed.credentials().unwrap().len(), 1);
+    assert_eq!(recover_signers(&signed).unwrap(), vec![vec![addr.clone()]]);
+
+    assert!(check_amounts(&signed).passed);
+    assert!(check_size(&signed).passed);
+    assert!(check_signatures(&signed, &HashSet::from([addr])).passed);
+
+    let other = key::secp256k1::private_key::Key::generate().unwrap();
+    let other_addr = other.to_public_key().to_short_id().unwrap();
+    let c = check_signatures(&signed, &HashSet::from([other_addr]));
+    assert!(!c.passed);
+    assert!(c.message.contains("unknown key"));
+
+    let utxo = txs::utxo::Utxo {
+        utxo_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 1_000,
+            output_owners: owners,
+        }),
+        ..Default::default()
+    };
+    assert!(check_utxos(&signed, std::slice::from_ref(&utxo)).passed);
+    let c = check_utxos(&signed, &[]);
+    assert!(!c.passed);
+    assert!(c.message.contains("not found"));
+
+    let mut overpaid = signed.clone();
+    overpaid.fee = 10;
+    let report = Report {
+        tx_size: signed.tx_bytes.len(),
+        checks: vec![check_amounts(&signed), check_amounts(&overpaid)],
+        signed,
+    };
+    assert!(!report.passed());
+    assert_eq!(report.failures().len(), 1);
+}
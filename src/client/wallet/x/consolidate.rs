@@ -0,0 +1,192 @@
+use std::io;
+
+use crate::{
+    avm,
+    client::{
+        time::{self, Duration},
+        wallet::{consolidate, signed::Signed},
+    },
+    ids, key, txs,
+};
+
+/// Sweeps the small AVAX UTXOs of the wallet owner into fewer outputs,
+/// issuing one base transaction per batch of inputs.
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::client::wallet::x::X<T>,
+
+    /// Maximum number of inputs per transaction.
+    pub max_inputs: usize,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+
+    /// Set to true to return transaction Ids for "issue" in dry mode.
+    pub dry_mode: bool,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(x: &crate::client::wallet::x::X<T>) -> Self {
+        Self {
+            inner: x.clone(),
+
+            max_inputs: consolidate::DEFAULT_MAX_INPUTS,
+
+            check_acceptance: false,
+
+            poll_initial_wait: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(700),
+            poll_timeout: Duration::from_secs(300),
+
+            dry_mode: false,
+        }
+    }
+
+    /// Sets the maximum number of inputs per transaction.
+    #[must_use]
+    pub fn max_inputs(mut self, max_inputs: usize) -> Self {
+        self.max_inputs = max_inputs;
+        self
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Sets the dry mode boolean flag.
+    #[must_use]
+    pub fn dry_mode(mut self, dry_mode: bool) -> Self {
+        self.dry_mode = dry_mode;
+        self
+    }
+
+    /// Fetches the spendable AVAX UTXOs with their amounts.
+    async fn spendable(&self) -> io::Result<Vec<(txs::utxo::Utxo, u64)>> {
+        let now_unix = time::unix_now_secs();
+        let wallet = &self.inner.inner;
+        Ok(self
+            .inner
+            .utxos()
+            .await?
+            .into_iter()
+            .filter_map(|utxo| {
+                consolidate::spendable_amount(
+                    &wallet.keychain,
+                    &utxo,
+                    &wallet.avax_asset_id,
+                    now_unix,
+                )
+                .map(|amount| (utxo, amount))
+            })
+            .collect())
+    }
+
+    /// Returns the batches that "issue" would consolidate.
+    pub async fn plan(&self) -> io::Result<Vec<consolidate::Batch>> {
+        consolidate::plan(
+            self.spendable().await?,
+            self.max_inputs,
+            self.inner.inner.tx_fee,
+        )
+    }
+
+    /// Returns the UTXOs that are worth less than the fee to move on their own.
+    pub async fn dust(&self) -> io::Result<consolidate::Dust> {
+        consolidate::dust(&self.spendable().await?, self.inner.inner.tx_fee)
+    }
+
+    /// Issues one transaction per batch, and returns the transaction Ids.
+    /// The batches spend disjoint UTXOs, so a failed batch does not
+    /// invalidate the ones issued before it.
+    pub async fn issue(&self) -> io::Result<Vec<ids::Id>> {
+        let batches = self.plan().await?;
+        log::info!(
+            "consolidating {} UTXOs in {} transaction(s)",
+            batches.iter().map(|b| b.utxos.len()).sum::<usize>(),
+            batches.len()
+        );
+
+        let mut tx_ids = Vec::new();
+        for batch in batches.iter() {
+            let signed = self.sign(batch).await?;
+            let issue = self
+                .inner
+                .issue_raw(&signed.tx_bytes)
+                .check_acceptance(self.check_acceptance)
+                .poll_initial_wait(self.poll_initial_wait)
+                .poll_interval(self.poll_interval)
+                .poll_timeout(self.poll_timeout);
+            if self.dry_mode {
+                tx_ids.push(issue.tx_id());
+                continue;
+            }
+            tx_ids.push(issue.issue().await?);
+        }
+        Ok(tx_ids)
+    }
+
+    /// Builds and signs the base transaction that spends the batch into
+    /// a single output owned by the wallet.
+    async fn sign(&self, batch: &consolidate::Batch) -> io::Result<Signed> {
+        let wallet = &self.inner.inner;
+        let now_unix = time::unix_now_secs();
+
+        let (inputs, signers) = consolidate::spend(&wallet.keychain, batch, now_unix)?;
+        let output = consolidate::output(batch, &wallet.avax_asset_id, &wallet.short_address);
+
+        let mut tx = avm::txs::Tx::new(txs::Tx {
+            network_id: wallet.network_id,
+            blockchain_id: wallet.blockchain_id_x,
+            transferable_outputs: Some(vec![output]),
+            transferable_inputs: Some(inputs),
+            ..Default::default()
+        });
+        tx.sign(signers).await?;
+
+        Signed::new(
+            "X",
+            "consolidate",
+            &tx,
+            &tx.base_tx,
+            &self.inner.inner.avax_asset_id,
+            batch.total - batch.output_amount,
+        )
+    }
+}
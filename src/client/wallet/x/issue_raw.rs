@@ -0,0 +1,270 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    client::{
+        cancel,
+        time::Duration,
+        txs as client_txs,
+        wallet::{journal, queue},
+        x as client_x,
+    },
+    ids, jsonrpc, key, txs,
+};
+use ring::digest::{digest, SHA256};
+
+/// Issues the transaction that was built and signed outside of the wallet
+/// (e.g., by a hardware wallet or a remote signer).
+#[derive(Clone, Debug)]
+pub struct Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub inner: crate::client::wallet::x::X<T>,
+
+    /// Signed transaction bytes (unsigned transaction with the credentials).
+    pub tx_bytes: Vec<u8>,
+
+    /// Set "true" to poll transaction status after issuance for its acceptance.
+    pub check_acceptance: bool,
+
+    /// Initial wait duration before polling for acceptance.
+    pub poll_initial_wait: Duration,
+    /// Wait between each poll intervals for acceptance.
+    pub poll_interval: Duration,
+    /// Maximum duration for polling.
+    pub poll_timeout: Duration,
+}
+
+impl<T> Tx<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(x: &crate::client::wallet::x::X<T>, tx_bytes: &[u8]) -> Self {
+        Self {
+            inner: x.clone(),
+
+            tx_bytes: tx_bytes.to_vec(),
+
+            check_acceptance: false,
+
+            poll_initial_wait: Duration::from_millis(500),
+            poll_interval: Duration::from_millis(700),
+            poll_timeout: Duration::from_secs(300),
+        }
+    }
+
+    /// Sets the check acceptance boolean flag.
+    #[must_use]
+    pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
+        self.check_acceptance = check_acceptance;
+        self
+    }
+
+    /// Sets the initial poll wait time.
+    #[must_use]
+    pub fn poll_initial_wait(mut self, poll_initial_wait: Duration) -> Self {
+        self.poll_initial_wait = poll_initial_wait;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the poll timeout.
+    #[must_use]
+    pub fn poll_timeout(mut self, poll_timeout: Duration) -> Self {
+        self.poll_timeout = poll_timeout;
+        self
+    }
+
+    /// Returns the transaction Id, the SHA256 digest of the signed bytes.
+    pub fn tx_id(&self) -> ids::Id {
+        ids::Id::from_slice(digest(&SHA256, &self.tx_bytes).as_ref())
+    }
+
+    /// Issues the signed transaction and returns the transaction Id.
+    /// Fails if the node reports a different transaction Id than
+    /// the one computed from the signed bytes.
+    /// Runs after the previous issuances on the X-chain if the wallet has an issuance queue.
+    pub async fn issue(&self) -> io::Result<ids::Id> {
+        self.inner
+            .inner
+            .queue_issuance("X", "raw", self.check_acceptance, self.issue_now())
+            .await
+    }
+
+    async fn issue_now(&self) -> io::Result<ids::Id> {
+        if self.tx_bytes.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "empty signed transaction bytes",
+            ));
+        }
+        txs::limits::check_tx_size(self.tx_bytes.len())?;
+
+        let picked_http_rpc = self.inner.inner.pick_http_rpc();
+        let expected_tx_id = self.tx_id();
+        log::info!(
+            "issuing raw transaction {} ({} bytes) via {}",
+            expected_tx_id,
+            self.tx_bytes.len(),
+            picked_http_rpc.1
+        );
+
+        let hex_tx = jsonrpc::issue::encode_tx_hex(&self.tx_bytes);
+        queue::mark_signed(&expected_tx_id);
+        self.inner.inner.guard_issuance(&expected_tx_id)?;
+        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+
+        let tx_id = resp.into_result()?.tx_id;
+        if tx_id != expected_tx_id {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "issued tx Id {} does not match the signed bytes {}",
+                    tx_id, expected_tx_id
+                ),
+            ));
+        }
+        log::info!("{} successfully issued", tx_id);
+        queue::mark_issued();
+        self.inner.inner.record_issued(&journal::Entry {
+            tx_id: tx_id.to_string(),
+            chain: String::from("X"),
+            tx_type: String::from("raw"),
+            http_rpc: picked_http_rpc.1.clone(),
+            issued_at: journal::now_unix_millis(),
+            ..Default::default()
+        });
+
+        if !self.check_acceptance {
+            log::debug!("skipping checking acceptance...");
+            return Ok(tx_id);
+        }
+
+        // enough time for txs processing
+        log::info!("initial waiting {:?}", self.poll_initial_wait);
+        cancel::sleep(self.poll_initial_wait).await?;
+
+        log::info!("polling to confirm raw transaction");
+        client_txs::await_x_accepted(
+            &picked_http_rpc.1,
+            &tx_id,
+            self.poll_interval,
+            self.poll_timeout,
+        )
+        .await?;
+
+        Ok(tx_id)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::x::issue_raw::test_issue --exact --show-output
+#[tokio::test]
+async fn test_issue() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::client::transport::{scope, Transport};
+
+    /// Issues with the tx Id, and then reports the statuses in order.
+    struct Fake {
+        tx_id: ids::Id,
+        statuses: Mutex<Vec<&'static str>>,
+    }
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, _: &str, url_path: &str, body: &str) -> io::Result<Vec<u8>> {
+            assert_eq!(url_path, "/ext/bc/X");
+            let req: serde_json::Value = serde_json::from_str(body).unwrap();
+            let result = match req["method"].as_str().unwrap() {
+                "avm.issueTx" => format!(r#"{{"txID":"{}"}}"#, self.tx_id),
+                "avm.getTxStatus" => {
+                    format!(
+                        r#"{{"status":"{}"}}"#,
+                        self.statuses.lock().unwrap().remove(0)
+                    )
+                }
+                method => panic!("unexpected method {}", method),
+            };
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":{}}}"#,
+                req["id"], result
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let wallet = crate::client::wallet::Wallet {
+        keychain: key::secp256k1::keychain::Keychain::new(vec![k.clone()]),
+        http_rpcs: vec![String::from("http://node")],
+        http_rpc_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: String::from("mainnet"),
+        hrp: String::from("avax"),
+        h160_address: primitive_types::H160::zero(),
+        x_address: String::new(),
+        p_address: String::new(),
+        c_address: String::new(),
+        short_address: k.to_public_key().to_short_id().unwrap(),
+        eth_address: String::new(),
+        blockchain_id_x: ids::Id::empty(),
+        blockchain_id_p: ids::Id::empty(),
+        blockchain_id_c: ids::Id::empty(),
+        chain_id_c: primitive_types::U256::from(43114),
+        avax_asset_id: ids::Id::empty(),
+        tx_fee: 0,
+        add_primary_network_validator_fee: 0,
+        create_subnet_tx_fee: 0,
+        create_blockchain_tx_fee: 0,
+        journal: None,
+        issuance_guard: None,
+        issuance_queue: None,
+    };
+    let tx = Tx::new(&crate::client::wallet::x::X { inner: wallet }, &[1, 2, 3])
+        .check_acceptance(true)
+        .poll_initial_wait(Duration::from_millis(1))
+        .poll_interval(Duration::from_millis(1));
+    let tx_id = tx.tx_id();
+
+    // polls until the status is final
+    let fake = Arc::new(Fake {
+        tx_id,
+        statuses: Mutex::new(vec!["Processing", "Processing", "Accepted"]),
+    });
+    assert_eq!(scope(fake.clone(), tx.issue()).await.unwrap(), tx_id);
+    assert!(fake.statuses.lock().unwrap().is_empty());
+
+    let fake = Arc::new(Fake {
+        tx_id,
+        statuses: Mutex::new(vec!["Processing", "Rejected"]),
+    });
+    assert!(scope(fake, tx.issue()).await.is_err());
+
+    // the node reports a different Id than the signed bytes
+    let fake = Arc::new(Fake {
+        tx_id: ids::Id::empty(),
+        statuses: Mutex::new(Vec::new()),
+    });
+    assert_eq!(
+        scope(fake, tx.issue()).await.unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+
+    let fake = Arc::new(Fake {
+        tx_id,
+        statuses: Mutex::new(Vec::new()),
+    });
+    let empty = Tx::new(&tx.inner, &[]);
+    assert_eq!(
+        scope(fake, empty.issue()).await.unwrap_err().kind(),
+        ErrorKind::InvalidInput
+    );
+}
@@ -1,8 +1,11 @@
+pub mod consolidate;
+pub mod issue_raw;
 pub mod transfer;
 
 use std::io;
 
-use crate::{client::x as client_x, key, txs};
+use crate::{client::x as client_x, ids, jsonrpc::avm, key, txs, units};
+use primitive_types::U256;
 
 #[derive(Clone, Debug)]
 pub struct X<T>
@@ -19,10 +22,7 @@ where
     /// Fetches the current balance of the wallet owner from the specified HTTP endpoint.
     pub async fn balance_with_endpoint(&self, http_rpc: &str) -> io::Result<u64> {
         let resp = client_x::get_balance(http_rpc, &self.inner.x_address).await?;
-        let cur_balance = resp
-            .result
-            .expect("unexpected None GetBalanceResult")
-            .balance;
+        let cur_balance = resp.into_result()?.balance;
         Ok(cur_balance)
     }
 
@@ -43,6 +43,44 @@ where
             .await
     }
 
+    /// Fetches the current balance of the asset (e.g., an ANT) of the wallet owner.
+    pub async fn asset_balance(&self, asset_id: &ids::Id) -> io::Result<u64> {
+        let resp = client_x::get_asset_balance(
+            &self.inner.pick_http_rpc().1,
+            &self.inner.x_address,
+            &asset_id.to_string(),
+        )
+        .await?;
+        Ok(resp.into_result()?.balance)
+    }
+
+    /// Fetches the name, the symbol and the denomination of the asset.
+    pub async fn asset_description(
+        &self,
+        asset_id: &ids::Id,
+    ) -> io::Result<avm::GetAssetDescriptionResult> {
+        let resp =
+            client_x::get_asset_description(&self.inner.pick_http_rpc().1, &asset_id.to_string())
+                .await?;
+        resp.into_result()
+    }
+
+    /// Formats the amount in the denomination of the asset (e.g., "1.5 AVAX").
+    /// Fetches the description of any asset other than AVAX.
+    pub async fn format_amount(&self, asset_id: &ids::Id, amount: u64) -> io::Result<String> {
+        let (symbol, denomination) = if *asset_id == self.inner.avax_asset_id {
+            (String::from("AVAX"), 9)
+        } else {
+            let desc = self.asset_description(asset_id).await?;
+            (desc.symbol, desc.denomination.min(u8::MAX as usize) as u8)
+        };
+        Ok(format!(
+            "{} {}",
+            units::format_units(U256::from(amount), denomination),
+            symbol
+        ))
+    }
+
     /// Fetches UTXOs for "X" chain.
     /// TODO: cache this like avalanchego
     pub async fn utxos(&self) -> io::Result<Vec<txs::utxo::Utxo>> {
@@ -52,12 +90,8 @@ where
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L355 "AddValidator"
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
         let resp =
-            client_x::get_utxos(&self.inner.pick_http_rpc().1, &self.inner.p_address).await?;
-        let utxos = resp
-            .result
-            .expect("unexpected None GetUtxosResult")
-            .utxos
-            .expect("unexpected None Utxos");
+            client_x::get_utxos(&self.inner.pick_http_rpc().1, &self.inner.x_address).await?;
+        let utxos = resp.into_result()?.utxos.unwrap_or_default();
         Ok(utxos)
     }
 
@@ -65,4 +99,16 @@ where
     pub fn transfer(&self) -> transfer::Tx<T> {
         transfer::Tx::new(self)
     }
+
+    /// Sweeps the small UTXOs into fewer outputs.
+    #[must_use]
+    pub fn consolidate(&self) -> consolidate::Tx<T> {
+        consolidate::Tx::new(self)
+    }
+
+    /// Issues the transaction that was built and signed outside of the wallet.
+    #[must_use]
+    pub fn issue_raw(&self, tx_bytes: &[u8]) -> issue_raw::Tx<T> {
+        issue_raw::Tx::new(self, tx_bytes)
+    }
 }
@@ -1,18 +1,22 @@
 use std::{
     cmp,
     io::{self, Error, ErrorKind},
-    time::SystemTime,
 };
 
 use crate::{
     avm,
     choices::status::Status,
-    client::x as client_x,
-    formatting,
+    client::{
+        cancel,
+        time::{self, Duration, Instant},
+        wallet::{journal, queue, signed::Signed, simulate},
+        x as client_x,
+    },
     ids::{self, short},
-    key, txs,
+    key::{self, secp256k1::keychain},
+    txs,
+    units::NanoAvax,
 };
-use tokio::time::{sleep, Duration, Instant};
 
 #[derive(Clone, Debug)]
 pub struct Tx<T>
@@ -24,9 +28,23 @@ where
     /// Transfer fund receiver address.
     pub receiver: short::Id,
 
-    /// Transfer amount.
+    /// Transfer amount, in the smallest unit of the asset.
     pub amount: u64,
 
+    /// Asset to transfer (e.g., an ANT minted on the X-chain).
+    /// Defaults to AVAX.
+    pub asset_id: Option<ids::Id>,
+
+    /// Owners of the transferred output, overriding the receiver
+    /// (e.g., a locktime for vesting, or multiple addresses with a threshold).
+    pub receiver_owners: Option<key::secp256k1::txs::OutputOwners>,
+
+    /// Owner of the change output. Defaults to the wallet key.
+    pub change_owner: Option<key::secp256k1::txs::OutputOwners>,
+
+    /// Arbitrary bytes attached to the transaction (e.g., a payment reference).
+    pub memo: Option<Vec<u8>>,
+
     /// Set "true" to poll transfer status after issuance for its acceptance.
     pub check_acceptance: bool,
 
@@ -52,6 +70,12 @@ where
             receiver: short::Id::empty(),
 
             amount: 0,
+            asset_id: None,
+
+            receiver_owners: None,
+            change_owner: None,
+
+            memo: None,
 
             check_acceptance: false,
 
@@ -77,6 +101,46 @@ where
         self
     }
 
+    /// Sets the asset to transfer instead of AVAX.
+    /// The wallet pays the fee in AVAX, so it needs both.
+    #[must_use]
+    pub fn asset_id(mut self, asset_id: ids::Id) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    /// Sets the owners of the transferred output, which overrides the receiver.
+    #[must_use]
+    pub fn receiver_owners(mut self, receiver_owners: key::secp256k1::txs::OutputOwners) -> Self {
+        self.receiver_owners = Some(receiver_owners.sorted());
+        self
+    }
+
+    /// Sets the address that receives the change.
+    #[must_use]
+    pub fn change_address(mut self, change_address: ids::short::Id) -> Self {
+        self.change_owner = Some(key::secp256k1::txs::OutputOwners::new(
+            0,
+            1,
+            &[change_address],
+        ));
+        self
+    }
+
+    /// Sets the owner of the change output (e.g., a multisig threshold or a locktime).
+    #[must_use]
+    pub fn change_owner(mut self, change_owner: key::secp256k1::txs::OutputOwners) -> Self {
+        self.change_owner = Some(change_owner.sorted());
+        self
+    }
+
+    /// Sets the memo, up to "txs::limits::MAX_MEMO_SIZE" bytes.
+    #[must_use]
+    pub fn memo(mut self, memo: Vec<u8>) -> Self {
+        self.memo = Some(memo);
+        self
+    }
+
     /// Sets the check acceptance boolean flag.
     #[must_use]
     pub fn check_acceptance(mut self, check_acceptance: bool) -> Self {
@@ -112,16 +176,12 @@ where
         self
     }
 
-    /// Issues the transfer transaction and returns the transaction Id.
-    pub async fn issue(&self) -> io::Result<ids::Id> {
+    /// Builds and signs the transfer transaction without issuing it.
+    pub async fn sign(&self) -> io::Result<Signed> {
+        if let Some(memo) = &self.memo {
+            txs::limits::check_memo(memo)?;
+        }
         let picked_http_rpc = self.inner.inner.pick_http_rpc();
-        log::info!(
-            "transferring {} AVAX from {} to {} via {}",
-            self.amount,
-            self.inner.inner.short_address,
-            self.receiver,
-            picked_http_rpc.1
-        );
 
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/wallet/chain/p/builder.go
         // ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/vms/platformvm/add_validator_tx.go#L263
@@ -130,7 +190,7 @@ where
         // ref. https://github.com/ava-labs/subnet-cli/blob/6bbe9f4aff353b812822af99c08133af35dbc6bd/client/p.go#L614 "stake"
         let sender_x_utxos =
             client_x::get_utxos(&picked_http_rpc.1, &self.inner.inner.x_address).await?;
-        let sender_x_utxos_result = sender_x_utxos.result.unwrap();
+        let sender_x_utxos_result = sender_x_utxos.into_result()?;
         let sender_x_utxos = sender_x_utxos_result.utxos.unwrap();
         log::debug!(
             "fetched UTXOs for inputs: numFetched {:?}, endIndex {:?} and {} UTXOs",
@@ -140,18 +200,32 @@ where
         );
         // TODO: paginate next results
 
+        let receiver_owners = match &self.receiver_owners {
+            Some(owners) => {
+                owners.verify()?;
+                owners.clone()
+            }
+            None => {
+                key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&self.receiver))
+            }
+        };
+        let change_owner = self
+            .inner
+            .inner
+            .owner_or_default(self.change_owner.as_ref())?;
+
+        let avax_asset_id = self.inner.inner.avax_asset_id;
+        let asset_id = self.asset_id.unwrap_or(avax_asset_id);
+
         let mut inputs: Vec<txs::transferable::Input> = Vec::new();
+        let mut signers: Vec<Vec<T>> = Vec::new();
         let mut outputs: Vec<txs::transferable::Output> = vec![
             // receiver
             txs::transferable::Output {
-                asset_id: self.inner.inner.avax_asset_id.clone(),
+                asset_id,
                 transfer_output: Some(key::secp256k1::txs::transfer::Output {
                     amount: self.amount,
-                    output_owners: key::secp256k1::txs::OutputOwners {
-                        locktime: 0,
-                        threshold: 1,
-                        addresses: vec![self.receiver.clone()],
-                    },
+                    output_owners: receiver_owners,
                 }),
                 ..Default::default()
             },
@@ -159,60 +233,39 @@ where
 
         // ref. "avalanchego/wallet/chain/x"
         // "math.Add64(toBurn[assetID], out.Out.Amount())"
-        let mut remaining_amount_to_burn = self.amount + self.inner.inner.tx_fee;
+        let to_spend = if asset_id == avax_asset_id {
+            vec![(
+                asset_id,
+                NanoAvax(self.amount)
+                    .checked_add(NanoAvax(self.inner.inner.tx_fee))?
+                    .as_u64(),
+            )]
+        } else {
+            // the fee is paid in AVAX whatever the transferred asset
+            vec![
+                (asset_id, self.amount),
+                (avax_asset_id, self.inner.inner.tx_fee),
+            ]
+        };
 
         // ref. "avalanchego/vms/avm#Service.SendMultiple"
-        let now_unix = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .expect("unexpected None duration_since")
-            .as_secs();
-
-        for utxo in sender_x_utxos.iter() {
-            if utxo.asset_id != self.inner.inner.avax_asset_id {
-                continue;
-            }
-
-            // consumed enough, no need to burn more
-            if remaining_amount_to_burn == 0 {
-                continue;
-            }
-
-            if let Some(out) = &utxo.transfer_output {
-                let (input, _) = self.inner.inner.keychain.spend(out, now_unix).unwrap();
-
-                inputs.push(txs::transferable::Input {
-                    utxo_id: utxo.utxo_id.clone(),
-                    asset_id: utxo.asset_id.clone(),
-                    transfer_input: Some(input.clone()),
-                    ..Default::default()
-                });
-
-                // burn any value that should be burned
-                let amount_to_burn = cmp::min(
-                    remaining_amount_to_burn, // amount we still need to burn
-                    out.amount,               // amount available to burn
-                );
-                remaining_amount_to_burn -= amount_to_burn;
-
-                let remaining_amount = out.amount - amount_to_burn;
-                if remaining_amount > 0 {
-                    // this input had extra value, so some must be returned
-                    outputs.push(txs::transferable::Output {
-                        asset_id: self.inner.inner.avax_asset_id.clone(),
-                        transfer_output: Some(key::secp256k1::txs::transfer::Output {
-                            amount: remaining_amount,
-                            output_owners: key::secp256k1::txs::OutputOwners {
-                                locktime: 0,
-                                threshold: 1,
-                                addresses: vec![self.inner.inner.short_address.clone()],
-                            },
-                        }),
-                        ..Default::default()
-                    })
-                }
-            }
+        let now_unix = time::unix_now_secs();
+
+        for (asset_id, amount) in to_spend {
+            let (ins, in_signers, change) = spend_asset(
+                &self.inner.inner.keychain,
+                &sender_x_utxos,
+                &asset_id,
+                amount,
+                &change_owner,
+                now_unix,
+            )?;
+            inputs.extend(ins);
+            signers.extend(in_signers);
+            outputs.extend(change);
         }
-        inputs.sort();
+        // credentials must follow the sorted inputs
+        keychain::sort_with_signers(&mut inputs, &mut signers);
         outputs.sort();
 
         log::debug!(
@@ -225,14 +278,12 @@ where
             blockchain_id: self.inner.inner.blockchain_id_x.clone(),
             transferable_outputs: Some(outputs),
             transferable_inputs: Some(inputs.clone()),
+            memo: self.memo.clone(),
             ..Default::default()
         };
 
-        // make sure it does not incur "tx has 1 credentials but 2 inputs. Should be same" error
-        let mut signers: Vec<Vec<T>> = Vec::new();
-        for _ in 0..inputs.len() {
-            signers.push(vec![self.inner.inner.keychain.keys[0].clone()]);
-        }
+        // one credential per input, or it fails with
+        // "tx has 1 credentials but 2 inputs. Should be same"
         if inputs.len() > 1 {
             log::debug!("signing for multiple inputs ({} inputs)", inputs.len());
         }
@@ -240,37 +291,90 @@ where
         let mut tx = avm::txs::Tx::new(base_tx);
         tx.sign(signers).await?;
 
+        Signed::new(
+            "X",
+            "transfer",
+            &tx,
+            &tx.base_tx,
+            &self.inner.inner.avax_asset_id,
+            self.inner.inner.tx_fee,
+        )
+    }
+
+    /// Builds and signs the transfer transaction, and runs the simulation
+    /// checks instead of issuing it. With "node_check", also checks the
+    /// consumed UTXOs against the node.
+    pub async fn simulate(&self, node_check: bool) -> io::Result<simulate::Report> {
+        let signed = self.sign().await?;
+        simulate::run(&self.inner.inner, signed, node_check).await
+    }
+
+    /// Issues the transfer transaction and returns the transaction Id.
+    pub async fn issue(&self) -> io::Result<ids::Id> {
+        Ok(self.issue_signed().await?.tx_id)
+    }
+
+    /// Issues the transfer transaction and returns the signed transaction.
+    /// Runs after the previous issuances on the X-chain if the wallet has an issuance queue.
+    pub async fn issue_signed(&self) -> io::Result<Signed> {
+        self.inner
+            .inner
+            .queue_issuance(
+                "X",
+                "transfer",
+                self.check_acceptance,
+                self.issue_signed_now(),
+            )
+            .await
+    }
+
+    async fn issue_signed_now(&self) -> io::Result<Signed> {
+        let picked_http_rpc = self.inner.inner.pick_http_rpc();
+
+        // formatting an ANT amount fetches the asset description,
+        // so only do it when the line is logged
+        if log::log_enabled!(log::Level::Info) {
+            let asset_id = self.asset_id.unwrap_or(self.inner.inner.avax_asset_id);
+            let amount = self
+                .inner
+                .format_amount(&asset_id, self.amount)
+                .await
+                .unwrap_or_else(|_| format!("{} of asset {}", self.amount, asset_id));
+            log::info!(
+                "transferring {} from {} to {} via {}",
+                amount,
+                self.inner.inner.short_address,
+                self.receiver,
+                picked_http_rpc.1
+            );
+        }
+
+        let signed = self.sign().await?;
+        txs::limits::check_tx_size(signed.tx_bytes.len())?;
         if self.dry_mode {
-            return Ok(tx.base_tx.metadata.unwrap().id);
+            return Ok(signed);
         }
 
-        let tx_bytes_with_signatures = tx
-            .base_tx
-            .metadata
-            .clone()
-            .unwrap()
-            .tx_bytes_with_signatures;
-        let hex_tx = formatting::encode_hex_with_checksum(&tx_bytes_with_signatures);
-        let resp = client_x::issue_tx(&picked_http_rpc.1, &hex_tx).await?;
+        queue::mark_signed(&signed.tx_id);
+        self.inner.inner.guard_issuance(&signed.tx_id)?;
 
-        if resp.result.is_none() {
-            return Err(Error::new(
-                ErrorKind::Other,
-                format!("failed to issue tx {:?}", resp.error),
-            ));
-        }
+        let resp = client_x::issue_tx(&picked_http_rpc.1, &signed.tx_hex).await?;
 
-        let tx_id = resp.result.unwrap().tx_id;
+        let tx_id = resp.into_result()?.tx_id;
         log::info!("{} successfully issued", tx_id);
+        queue::mark_issued();
+        self.inner
+            .inner
+            .record_issued(&journal::Entry::from_signed(&signed, &picked_http_rpc.1));
 
         if !self.check_acceptance {
             log::debug!("skipping checking acceptance...");
-            return Ok(tx_id);
+            return Ok(signed);
         }
 
         // enough time for txs processing
         log::info!("initial waiting {:?}", self.poll_initial_wait);
-        sleep(self.poll_initial_wait).await;
+        cancel::sleep(self.poll_initial_wait).await?;
 
         log::info!("polling to confirm base transaction");
         let (start, mut success) = (Instant::now(), false);
@@ -282,7 +386,7 @@ where
 
             let resp = client_x::get_tx_status(&picked_http_rpc.1, &tx_id.to_string()).await?;
 
-            let status = resp.result.unwrap().status;
+            let status = resp.into_result()?.status;
             if status == Status::Accepted {
                 log::info!("{} successfully accepted", tx_id);
                 success = true;
@@ -296,7 +400,7 @@ where
                 picked_http_rpc.1,
                 elapsed
             );
-            sleep(self.poll_interval).await;
+            cancel::sleep(self.poll_interval).await?;
         }
         if !success {
             return Err(Error::new(
@@ -305,6 +409,293 @@ where
             ));
         }
 
-        Ok(tx_id)
+        Ok(signed)
+    }
+}
+
+/// Spends the UTXOs of the asset until they cover the amount, and returns
+/// the inputs with their signers and the change output of the excess.
+#[allow(clippy::type_complexity)]
+fn spend_asset<T>(
+    keychain: &keychain::Keychain<T>,
+    utxos: &[txs::utxo::Utxo],
+    asset_id: &ids::Id,
+    amount: u64,
+    change_owner: &key::secp256k1::txs::OutputOwners,
+    now_unix: u64,
+) -> io::Result<(
+    Vec<txs::transferable::Input>,
+    Vec<Vec<T>>,
+    Option<txs::transferable::Output>,
+)>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    let mut inputs = Vec::new();
+    let mut signers = Vec::new();
+    let mut remaining_amount_to_burn = NanoAvax(amount);
+    let mut change_amount = NanoAvax::ZERO;
+
+    for utxo in utxos.iter() {
+        if utxo.asset_id != *asset_id {
+            continue;
+        }
+
+        // consumed enough, no need to burn more
+        if remaining_amount_to_burn == NanoAvax::ZERO {
+            break;
+        }
+
+        if let Some(out) = &utxo.transfer_output {
+            let res = keychain.spend(out, now_unix);
+            if res.is_none() {
+                // cannot spend the output (e.g., locked), move onto next
+                continue;
+            }
+            let (input, in_signers) = res.unwrap();
+            keychain::verify_signers(&out.output_owners, &input.sig_indices, &in_signers)?;
+            signers.push(in_signers);
+
+            inputs.push(txs::transferable::Input {
+                utxo_id: utxo.utxo_id.clone(),
+                asset_id: utxo.asset_id,
+                transfer_input: Some(input),
+                ..Default::default()
+            });
+
+            // burn any value that should be burned
+            let amount_to_burn = cmp::min(
+                remaining_amount_to_burn, // amount we still need to burn
+                NanoAvax(out.amount),     // amount available to burn
+            );
+            remaining_amount_to_burn = remaining_amount_to_burn.checked_sub(amount_to_burn)?;
+
+            // this input had extra value, so some must be returned
+            change_amount =
+                change_amount.checked_add(NanoAvax(out.amount).checked_sub(amount_to_burn)?)?;
+        }
+    }
+    if remaining_amount_to_burn > NanoAvax::ZERO {
+        return Err(Error::new(
+            ErrorKind::Other,
+            format!(
+                "insufficient funds of asset {}: {} short of {}",
+                asset_id,
+                remaining_amount_to_burn.as_u64(),
+                amount
+            ),
+        ));
     }
+
+    let change = if change_amount > NanoAvax::ZERO {
+        Some(txs::transferable::Output {
+            asset_id: *asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: change_amount.as_u64(),
+                output_owners: change_owner.clone(),
+            }),
+            ..Default::default()
+        })
+    } else {
+        None
+    };
+    Ok((inputs, signers, change))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::x::transfer::test_spend_asset --exact --show-output
+#[test]
+fn test_spend_asset() {
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let addr = k.to_public_key().to_short_id().unwrap();
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+    let keychain = keychain::Keychain::new(vec![k]);
+
+    let avax = ids::Id::from_slice(&[0xaa; 32]);
+    let ant = ids::Id::from_slice(&[0xbb; 32]);
+    let utxo = |n: u8, asset_id: ids::Id, amount: u64| txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id::new(&[n; 32], 0, false).unwrap(),
+        asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount,
+            output_owners: owners.clone(),
+        }),
+        ..Default::default()
+    };
+    let utxos = vec![
+        utxo(1, avax, 100),
+        utxo(2, ant, 30),
+        utxo(3, ant, 50),
+        utxo(4, avax, 5),
+    ];
+
+    // the ANT transfer spends only the ANT UTXOs, and returns the excess in the ANT
+    let (ins, signers, change) = spend_asset(&keychain, &utxos, &ant, 60, &owners, 0).unwrap();
+    assert_eq!(ins.len(), 2);
+    assert_eq!(signers.len(), 2);
+    assert!(ins.iter().all(|i| i.asset_id == ant));
+    let change = change.unwrap();
+    assert_eq!(change.asset_id, ant);
+    assert_eq!(change.transfer_output.unwrap().amount, 20);
+
+    // the AVAX fee stops at the first UTXO that covers it
+    let (ins, _, change) = spend_asset(&keychain, &utxos, &avax, 100, &owners, 0).unwrap();
+    assert_eq!(ins.len(), 1);
+    assert!(change.is_none());
+
+    let err = spend_asset(&keychain, &utxos, &ant, 81, &owners, 0).unwrap_err();
+    assert!(err.to_string().contains("1 short of 81"));
+
+    // the UTXOs of the other keys are not spendable
+    let other = key::secp256k1::private_key::Key::generate().unwrap();
+    let other_keychain = keychain::Keychain::new(vec![other]);
+    assert!(spend_asset(&other_keychain, &utxos, &ant, 1, &owners, 0).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::wallet::x::transfer::test_sign_asset --exact --show-output
+#[tokio::test]
+async fn test_sign_asset() {
+    use std::sync::{Arc, Mutex};
+
+    use crate::{
+        client::transport::{scope, Transport},
+        txs::verify::Verifiable,
+    };
+
+    /// Serves the UTXOs, and counts the asset description calls.
+    struct Fake {
+        utxos: Vec<txs::utxo::Utxo>,
+        descriptions: Mutex<usize>,
+    }
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, _: &str, url_path: &str, body: &str) -> io::Result<Vec<u8>> {
+            assert_eq!(url_path, "/ext/bc/X");
+            let req: serde_json::Value = serde_json::from_str(body).unwrap();
+            let result = match req["method"].as_str().unwrap() {
+                "avm.getUTXOs" => {
+                    let utxos: Vec<String> = self
+                        .utxos
+                        .iter()
+                        .map(|u| format!(r#""{}""#, u.to_hex().unwrap()))
+                        .collect();
+                    format!(
+                        r#"{{"numFetched":"{}","utxos":[{}],"encoding":"hex"}}"#,
+                        utxos.len(),
+                        utxos.join(",")
+                    )
+                }
+                "avm.getAssetDescription" => {
+                    *self.descriptions.lock().unwrap() += 1;
+                    format!(
+                        r#"{{"assetID":"{}","name":"Token","symbol":"TKN","denomination":"2"}}"#,
+                        req["params"]["assetID"].as_str().unwrap()
+                    )
+                }
+                method => panic!("unexpected method {}", method),
+            };
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":{}}}"#,
+                req["id"], result
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let addr = k.to_public_key().to_short_id().unwrap();
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+    let avax = ids::Id::from_slice(&[0xaa; 32]);
+    let ant = ids::Id::from_slice(&[0xbb; 32]);
+    let wallet = crate::client::wallet::Wallet {
+        keychain: keychain::Keychain::new(vec![k]),
+        http_rpcs: vec![String::from("http://node")],
+        http_rpc_cursor: Arc::new(Mutex::new(0)),
+        network_id: 1,
+        network_name: String::from("mainnet"),
+        hrp: String::from("avax"),
+        h160_address: primitive_types::H160::zero(),
+        x_address: String::new(),
+        p_address: String::new(),
+        c_address: String::new(),
+        short_address: addr.clone(),
+        eth_address: String::new(),
+        blockchain_id_x: ids::Id::empty(),
+        blockchain_id_p: ids::Id::empty(),
+        blockchain_id_c: ids::Id::empty(),
+        chain_id_c: primitive_types::U256::from(43114),
+        avax_asset_id: avax,
+        tx_fee: 1_000_000,
+        add_primary_network_validator_fee: 0,
+        create_subnet_tx_fee: 0,
+        create_blockchain_tx_fee: 0,
+        journal: None,
+        issuance_guard: None,
+        issuance_queue: None,
+    };
+
+    let utxo = |n: u8, asset_id: ids::Id, amount: u64| txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id::new(&[n; 32], 0, false).unwrap(),
+        asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount,
+            output_owners: owners.clone(),
+        }),
+        ..Default::default()
+    };
+    let utxos = vec![utxo(1, ant, 30), utxo(2, avax, 5_000_000), utxo(3, ant, 50)];
+    let fake = Arc::new(Fake {
+        utxos: utxos.clone(),
+        descriptions: Mutex::new(0),
+    });
+
+    let receiver = short::Id::from_slice(&[0x11; 20]);
+    let transfer = Tx::new(&crate::client::wallet::x::X { inner: wallet })
+        .receiver(receiver.clone())
+        .asset_id(ant)
+        .amount(60)
+        .dry_mode(true);
+    let signed = scope(fake.clone(), transfer.issue_signed()).await.unwrap();
+
+    // the description is only fetched to log the amount
+    let expected_descriptions = usize::from(log::log_enabled!(log::Level::Info));
+    assert_eq!(*fake.descriptions.lock().unwrap(), expected_descriptions);
+
+    // only the AVAX amounts count towards the fee
+    assert_eq!(signed.fee, 1_000_000);
+    assert_eq!(signed.input_amount, 5_000_000);
+    assert_eq!(signed.output_amount, 4_000_000);
+    assert_eq!(signed.consumed_utxos.len(), 3);
+    assert_eq!(signed.created_utxos.len(), 3);
+
+    // rebuilds the transaction from the signed JSON to verify the credentials
+    let tx: avm::txs::Tx = serde_json::from_str(&signed.tx.to_string()).unwrap();
+    let ins = tx.base_tx.transferable_inputs.clone().unwrap();
+    assert_eq!(ins.len(), 3);
+    assert_eq!(ins.iter().filter(|i| i.asset_id == ant).count(), 2);
+    assert_eq!(ins.iter().filter(|i| i.asset_id == avax).count(), 1);
+
+    let outs = tx.base_tx.transferable_outputs.clone().unwrap();
+    let amounts: Vec<(ids::Id, u64, Vec<short::Id>)> = outs
+        .iter()
+        .map(|o| {
+            let out = o.transfer_output.as_ref().unwrap();
+            (o.asset_id, out.amount, out.output_owners.addresses.clone())
+        })
+        .collect();
+    assert_eq!(amounts.len(), 3);
+    assert!(amounts.contains(&(ant, 60, vec![receiver])));
+    assert!(amounts.contains(&(ant, 20, vec![addr.clone()])));
+    assert!(amounts.contains(&(avax, 4_000_000, vec![addr])));
+    tx.verify_credentials(&utxos).unwrap();
+
+    // not enough AVAX for the fee
+    let fake = Arc::new(Fake {
+        utxos: vec![utxo(1, ant, 100), utxo(2, avax, 999_999)],
+        descriptions: Mutex::new(0),
+    });
+    let err = scope(fake, transfer.sign()).await.unwrap_err();
+    assert!(err.to_string().contains("1 short of 1000000"), "{}", err);
 }
@@ -0,0 +1,556 @@
+//! Activity watcher for a set of X, P and C-chain addresses.
+//!
+//! The watcher polls the UTXOs of the X and P-chain addresses, and the nonce
+//! and balance of the C-chain addresses, and diffs each poll against the
+//! previous one: new UTXOs are reported per transaction that created them,
+//! spent UTXOs per poll, and the C-chain changes as nonce and balance
+//! updates. The events go to the sinks (e.g., a log, a channel, a webhook),
+//! so that alerting bots only decide what to do with them.
+//!
+//! ```
+//! # async fn run() {
+//! use std::{sync::Arc, time::Duration};
+//! use avalanche_types::client::watch::{Address, Log, Watcher};
+//!
+//! Watcher::new("http://localhost:9650")
+//!     .watch(Address::x("X-avax1tnuesf6cqwnjw7fxjyk7lhch0vhf0v95wj5jvy"))
+//!     .watch(Address::c("0x8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC"))
+//!     .interval(Duration::from_secs(10))
+//!     .sink(Arc::new(Log))
+//!     .run()
+//!     .await;
+//! # }
+//! ```
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, io,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    client::{cancel, evm as client_evm, p as client_p, transport, x as client_x},
+    ids,
+    txs::utxo,
+};
+use async_trait::async_trait;
+use primitive_types::U256;
+use serde::Serialize;
+
+/// Default wait between polls.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Chain of a watched address.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize)]
+pub enum Chain {
+    X,
+    P,
+    C,
+}
+
+/// Watched address, e.g., "X-avax1..." or "0x..." on the C-chain.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize)]
+pub struct Address {
+    pub chain: Chain,
+    pub address: String,
+}
+
+impl Address {
+    pub fn x(address: &str) -> Self {
+        Self {
+            chain: Chain::X,
+            address: address.to_string(),
+        }
+    }
+
+    pub fn p(address: &str) -> Self {
+        Self {
+            chain: Chain::P,
+            address: address.to_string(),
+        }
+    }
+
+    pub fn c(address: &str) -> Self {
+        Self {
+            chain: Chain::C,
+            address: address.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}:{}", self.chain, self.address)
+    }
+}
+
+/// Amount of an asset in a UTXO.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Amount {
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    pub amount: u64,
+}
+
+/// Activity of a watched address since the previous poll.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Event {
+    /// The transaction created UTXOs owned by the address.
+    #[serde(rename_all = "camelCase")]
+    Received {
+        address: Address,
+        #[serde(rename = "txID")]
+        tx_id: ids::Id,
+        amounts: Vec<Amount>,
+    },
+    /// The UTXOs owned by the address were spent.
+    #[serde(rename_all = "camelCase")]
+    Spent {
+        address: Address,
+        #[serde(rename = "utxoIDs")]
+        utxo_ids: Vec<utxo::Id>,
+    },
+    /// The C-chain address sent transactions.
+    #[serde(rename_all = "camelCase")]
+    NonceChanged {
+        address: Address,
+        from: u64,
+        to: u64,
+    },
+    /// The C-chain balance changed, in wei.
+    #[serde(rename_all = "camelCase")]
+    BalanceChanged {
+        address: Address,
+        from: U256,
+        to: U256,
+    },
+}
+
+impl Event {
+    pub fn address(&self) -> &Address {
+        match self {
+            Event::Received { address, .. }
+            | Event::Spent { address, .. }
+            | Event::NonceChanged { address, .. }
+            | Event::BalanceChanged { address, .. } => address,
+        }
+    }
+}
+
+/// State of an address at a poll.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Snapshot {
+    /// X or P-chain UTXOs by their IDs.
+    Utxos(BTreeMap<utxo::Id, Amount>),
+    /// C-chain account.
+    Account { nonce: u64, balance: U256 },
+}
+
+impl Snapshot {
+    /// Returns the events of the address between the two snapshots.
+    pub fn diff(&self, address: &Address, next: &Snapshot) -> Vec<Event> {
+        let mut events = Vec::new();
+        match (self, next) {
+            (Snapshot::Utxos(prev), Snapshot::Utxos(next)) => {
+                let mut received: BTreeMap<ids::Id, Vec<Amount>> = BTreeMap::new();
+                for (utxo_id, amount) in next.iter() {
+                    if !prev.contains_key(utxo_id) {
+                        received
+                            .entry(utxo_id.tx_id)
+                            .or_default()
+                            .push(amount.clone());
+                    }
+                }
+                for (tx_id, amounts) in received {
+                    events.push(Event::Received {
+                        address: address.clone(),
+                        tx_id,
+                        amounts,
+                    });
+                }
+
+                let spent: Vec<utxo::Id> = prev
+                    .keys()
+                    .filter(|utxo_id| !next.contains_key(utxo_id))
+                    .cloned()
+                    .collect();
+                if !spent.is_empty() {
+                    events.push(Event::Spent {
+                        address: address.clone(),
+                        utxo_ids: spent,
+                    });
+                }
+            }
+            (
+                Snapshot::Account {
+                    nonce: prev_nonce,
+                    balance: prev_balance,
+                },
+                Snapshot::Account { nonce, balance },
+            ) => {
+                if nonce != prev_nonce {
+                    events.push(Event::NonceChanged {
+                        address: address.clone(),
+                        from: *prev_nonce,
+                        to: *nonce,
+                    });
+                }
+                if balance != prev_balance {
+                    events.push(Event::BalanceChanged {
+                        address: address.clone(),
+                        from: *prev_balance,
+                        to: *balance,
+                    });
+                }
+            }
+            _ => log::warn!("snapshots of {} differ in kind, skipping", address),
+        }
+        events
+    }
+}
+
+fn utxo_snapshot(utxos: Option<Vec<utxo::Utxo>>) -> Snapshot {
+    Snapshot::Utxos(
+        utxos
+            .unwrap_or_default()
+            .into_iter()
+            .map(|u| {
+                let amount = u
+                    .transfer_output
+                    .as_ref()
+                    .map(|o| o.amount)
+                    .or_else(|| {
+                        u.stakeable_lock_out
+                            .as_ref()
+                            .map(|o| o.transfer_output.amount)
+                    })
+                    .unwrap_or_default();
+                (
+                    u.utxo_id,
+                    Amount {
+                        asset_id: u.asset_id,
+                        amount,
+                    },
+                )
+            })
+            .collect(),
+    )
+}
+
+/// Receives the events of the watcher, e.g., to send the alerts.
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait Sink: Send + Sync {
+    async fn notify(&self, event: &Event) -> io::Result<()>;
+}
+
+/// Logs the events.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Log;
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Sink for Log {
+    async fn notify(&self, event: &Event) -> io::Result<()> {
+        log::info!("activity of {}: {:?}", event.address(), event);
+        Ok(())
+    }
+}
+
+/// Sends the events to the channel.
+#[derive(Debug, Clone)]
+pub struct Channel(pub tokio::sync::mpsc::UnboundedSender<Event>);
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Sink for Channel {
+    async fn notify(&self, event: &Event) -> io::Result<()> {
+        self.0
+            .send(event.clone())
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "event receiver dropped"))
+    }
+}
+
+/// Posts each event as JSON to the URL path of the endpoint
+/// (e.g., a chat webhook behind a relay).
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub http_rpc: String,
+    pub url_path: String,
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Sink for Webhook {
+    async fn notify(&self, event: &Event) -> io::Result<()> {
+        let d = serde_json::to_string(event).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::Other,
+                format!("failed to serialize to JSON {}", e),
+            )
+        })?;
+        transport::post_non_tls(&self.http_rpc, &self.url_path, &d).await?;
+        Ok(())
+    }
+}
+
+/// Polls the addresses and notifies the sinks of their activity.
+#[derive(Clone)]
+pub struct Watcher {
+    pub http_rpc: String,
+    pub addresses: BTreeSet<Address>,
+    pub interval: Duration,
+    pub sinks: Vec<Arc<dyn Sink>>,
+}
+
+impl Watcher {
+    pub fn new(http_rpc: &str) -> Self {
+        Self {
+            http_rpc: http_rpc.to_string(),
+            addresses: BTreeSet::new(),
+            interval: DEFAULT_INTERVAL,
+            sinks: Vec::new(),
+        }
+    }
+
+    /// Adds the address to watch.
+    #[must_use]
+    pub fn watch(mut self, address: Address) -> Self {
+        self.addresses.insert(address);
+        self
+    }
+
+    /// Sets the wait between polls.
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Adds the sink for the events.
+    #[must_use]
+    pub fn sink(mut self, sink: Arc<dyn Sink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Fetches the current state of the address.
+    /// Only the first 1,024 X-chain and 100 P-chain UTXOs are returned by
+    /// the node (no pagination), so heavily used addresses may miss events.
+    pub async fn snapshot(&self, address: &Address) -> io::Result<Snapshot> {
+        match address.chain {
+            Chain::X => {
+                let resp = client_x::get_utxos(&self.http_rpc, &address.address).await?;
+                Ok(utxo_snapshot(resp.into_result()?.utxos))
+            }
+            Chain::P => {
+                let resp = client_p::get_utxos(&self.http_rpc, &address.address).await?;
+                Ok(utxo_snapshot(resp.into_result()?.utxos))
+            }
+            Chain::C => {
+                let nonce =
+                    client_evm::get_latest_transaction_count(&self.http_rpc, "C", &address.address)
+                        .await?
+                        .into_result()?;
+                let balance = client_evm::get_balance(&self.http_rpc, "C", &address.address)
+                    .await?
+                    .into_result()?;
+                Ok(Snapshot::Account {
+                    nonce: nonce.low_u64(),
+                    balance,
+                })
+            }
+        }
+    }
+
+    /// Polls all the addresses, and returns the events since the previous
+    /// snapshots, updating them. The first poll of an address only records
+    /// its snapshot. Failed polls are logged and retried at the next poll.
+    pub async fn poll(&self, snapshots: &mut BTreeMap<Address, Snapshot>) -> Vec<Event> {
+        let mut events = Vec::new();
+        for address in self.addresses.iter() {
+            let next = match self.snapshot(address).await {
+                Ok(next) => next,
+                Err(e) => {
+                    log::warn!("failed to poll {} '{}'", address, e);
+                    continue;
+                }
+            };
+            if let Some(prev) = snapshots.get(address) {
+                events.extend(prev.diff(address, &next));
+            }
+            snapshots.insert(address.clone(), next);
+        }
+        events
+    }
+
+    /// Polls every interval and notifies the sinks, until the future is
+    /// dropped or the "cancel::scope" is cancelled. A failed sink does not
+    /// stop the others.
+    pub async fn run(&self) {
+        let mut snapshots = BTreeMap::new();
+        loop {
+            for event in self.poll(&mut snapshots).await {
+                for sink in self.sinks.iter() {
+                    if let Err(e) = sink.notify(&event).await {
+                        log::warn!("failed to notify {:?} '{}'", event, e);
+                    }
+                }
+            }
+            if cancel::sleep(self.interval).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Amount of AVAX (or another asset) in the received event.
+pub fn received_amount(event: &Event, asset_id: &ids::Id) -> u64 {
+    match event {
+        Event::Received { amounts, .. } => amounts
+            .iter()
+            .filter(|a| a.asset_id == *asset_id)
+            .map(|a| a.amount)
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::watch::test_watcher --exact --show-output
+#[tokio::test]
+async fn test_watcher() {
+    use crate::{
+        client::transport::{scope, Transport},
+        key::secp256k1::txs::transfer,
+    };
+    use std::sync::Mutex;
+
+    let avax = ids::Id::sha256(b"AVAX");
+    let utxo_id = |tx: &[u8], output_index: u32| {
+        utxo::Id::new(ids::Id::sha256(tx).as_ref(), output_index, false).unwrap()
+    };
+    let new_utxo = |tx: &[u8], output_index: u32, amount: u64| utxo::Utxo {
+        utxo_id: utxo_id(tx, output_index),
+        asset_id: avax,
+        transfer_output: Some(transfer::Output {
+            amount,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    /// Replays the X-chain UTXOs and the C-chain nonces by poll.
+    struct Fake {
+        utxos: Vec<Vec<utxo::Utxo>>,
+        nonces: Vec<u64>,
+        polls: Mutex<(usize, usize)>,
+    }
+    #[async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, _: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            let id = crate::jsonrpc::request_id(body).unwrap_or_default();
+            let mut polls = self.polls.lock().expect("unexpected poisoned lock");
+            let result = if body.contains("avm.getUTXOs") {
+                let i = polls.0.min(self.utxos.len() - 1);
+                polls.0 += 1;
+                let utxos: Vec<String> = self.utxos[i]
+                    .iter()
+                    .map(|u| format!(r#""{}""#, u.to_hex().unwrap()))
+                    .collect();
+                format!(
+                    r#"{{"numFetched":"{}","utxos":[{}],"encoding":"hex"}}"#,
+                    utxos.len(),
+                    utxos.join(",")
+                )
+            } else if body.contains("eth_getTransactionCount") {
+                let i = polls.1.min(self.nonces.len() - 1);
+                polls.1 += 1;
+                format!(r#""0x{:x}""#, self.nonces[i])
+            } else {
+                // balance is unchanged
+                r#""0xde0b6b3a7640000""#.to_string()
+            };
+            Ok(format!(r#"{{"jsonrpc":"2.0","id":{},"result":{}}}"#, id, result).into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let fake = Arc::new(Fake {
+        utxos: vec![
+            vec![new_utxo(b"tx0", 0, 100)],
+            vec![
+                new_utxo(b"tx0", 0, 100),
+                new_utxo(b"tx1", 0, 5),
+                new_utxo(b"tx1", 1, 7),
+            ],
+            vec![new_utxo(b"tx1", 1, 7)],
+        ],
+        nonces: vec![3, 3, 4],
+        polls: Mutex::new((0, 0)),
+    });
+    let x_addr = Address::x("X-custom1");
+    let c_addr = Address::c("0x8db97C7cEcE249c2b98bDC0226Cc4C2A57BF52FC");
+    let watcher = Watcher::new("http://node")
+        .watch(x_addr.clone())
+        .watch(c_addr.clone());
+
+    let mut snapshots = BTreeMap::new();
+    let events: Vec<Vec<Event>> = scope(fake, async {
+        let mut events = Vec::new();
+        for _ in 0..3 {
+            events.push(watcher.poll(&mut snapshots).await);
+        }
+        events
+    })
+    .await;
+
+    // baseline
+    assert!(events[0].is_empty());
+    assert_eq!(
+        events[1],
+        vec![Event::Received {
+            address: x_addr.clone(),
+            tx_id: ids::Id::sha256(b"tx1"),
+            amounts: vec![
+                Amount {
+                    asset_id: avax,
+                    amount: 5
+                },
+                Amount {
+                    asset_id: avax,
+                    amount: 7
+                }
+            ],
+        }]
+    );
+    assert_eq!(received_amount(&events[1][0], &avax), 12);
+    assert_eq!(events[2].len(), 2);
+    let mut spent = vec![utxo_id(b"tx0", 0), utxo_id(b"tx1", 0)];
+    spent.sort();
+    assert_eq!(
+        events[2][0],
+        Event::Spent {
+            address: x_addr,
+            utxo_ids: spent,
+        }
+    );
+    assert_eq!(
+        events[2][1],
+        Event::NonceChanged {
+            address: c_addr,
+            from: 3,
+            to: 4
+        }
+    );
+    let d = serde_json::to_string(&events[2][1]).unwrap();
+    assert!(d.contains(r#""type":"nonceChanged""#));
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    Channel(tx).notify(&events[2][1]).await.unwrap();
+    assert_eq!(rx.recv().await.unwrap(), events[2][1]);
+}
@@ -1,71 +1,80 @@
 use std::{
     collections::HashMap,
     io::{self, Error, ErrorKind},
+    time::Duration,
 };
 
-use crate::jsonrpc::{self, avm};
+use crate::{
+    client::{cancel, transport},
+    jsonrpc::{self, avm},
+};
 
 /// e.g., "avm.issueTx" on "http://[ADDR]:9650" and "/ext/bc/X" path.
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmissuetx
 pub async fn issue_tx(http_rpc: &str, tx: &str) -> io::Result<avm::IssueTxResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/bc/X")?;
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
     log::debug!("issuing a transaction via {}", joined.as_str());
 
-    let mut data = avm::IssueTxRequest::default();
-    data.method = String::from("avm.issueTx");
-
-    let params = avm::IssueTxParams {
-        tx: prefix_manager::prepend_0x(tx),
-        encoding: String::from("hex"), // don't use "cb58"
-    };
-    data.params = Some(params);
+    let mut data = jsonrpc::issue::new_avm_issue_tx(tx);
+    let call = jsonrpc::Call::new(&data.method);
+    data.id = call.id;
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed avm.issueTx '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "avm.getTxStatus" on "http://[ADDR]:9650" and "/ext/bc/X" path.
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgettxstatus
 pub async fn get_tx_status(http_rpc: &str, tx_id: &str) -> io::Result<avm::GetTxStatusResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/bc/X")?;
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
     log::debug!("getting tx status via {}", joined.as_str());
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("avm.getTxStatus");
+    let call = jsonrpc::Call::new("avm.getTxStatus");
 
     let mut params = HashMap::new();
     params.insert(String::from("txID"), String::from(tx_id));
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed avm.getTxStatus '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "avm.getBalance" on "http://[ADDR]:9650" and "/ext/bc/X" path.
 /// ref. https://docs.avax.network/build/avalanchego-apis/x-chain#avmgetbalance
 pub async fn get_balance(http_rpc: &str, xaddr: &str) -> io::Result<avm::GetBalanceResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/bc/X")?;
-    log::debug!("getting balances for {} via {}", xaddr, joined.as_str());
+    get_asset_balance(http_rpc, xaddr, "AVAX").await
+}
+
+/// Fetches the balance of the asset, by its ID or alias (e.g., "AVAX").
+/// ref. https://docs.avax.network/build/avalanchego-apis/x-chain#avmgetbalance
+pub async fn get_asset_balance(
+    http_rpc: &str,
+    xaddr: &str,
+    asset_id: &str,
+) -> io::Result<avm::GetBalanceResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
+    log::debug!(
+        "getting {} balances for {} via {}",
+        asset_id,
+        xaddr,
+        joined.as_str()
+    );
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("avm.getBalance");
+    let call = jsonrpc::Call::new("avm.getBalance");
 
     let mut params = HashMap::new();
-    params.insert(String::from("assetID"), String::from("AVAX"));
+    params.insert(String::from("assetID"), asset_id.to_string());
     params.insert(String::from("address"), xaddr.to_string());
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed avm.getBalance '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "avm.getAssetDescription".
@@ -80,33 +89,29 @@ pub async fn get_asset_description(
         asset_id
     );
 
-    let mut data = jsonrpc::Request::default();
-    data.method = String::from("avm.getAssetDescription");
+    let call = jsonrpc::Call::new("avm.getAssetDescription");
 
     let mut params = HashMap::new();
     params.insert(String::from("assetID"), String::from(asset_id));
-    data.params = Some(params);
+    let data = call.request_with_params(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "ext/bc/X", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "ext/bc/X", &d).await?;
 
-    serde_json::from_slice(&rb).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed avm.getAssetDescription '{}'", e),
-        )
-    })
+    call.decode(&rb)
 }
 
 /// e.g., "avm.getUTXOs" on "http://[ADDR]:9650" and "/ext/bc/X" path.
 /// TODO: support paginated calls
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos
 pub async fn get_utxos(http_rpc: &str, xaddr: &str) -> io::Result<avm::GetUtxosResponse> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/bc/X")?;
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
     log::debug!("getting UTXOs for {} via {:?}", xaddr, joined);
 
+    let call = jsonrpc::Call::new("avm.getUTXOs");
     let mut data = avm::GetUtxosRequest::default();
-    data.method = String::from("avm.getUTXOs");
+    data.id = call.id;
+    data.method = call.method.clone();
 
     let params = avm::GetUtxosParams {
         addresses: vec![xaddr.to_string()],
@@ -116,29 +121,302 @@ pub async fn get_utxos(http_rpc: &str, xaddr: &str) -> io::Result<avm::GetUtxosR
     data.params = Some(params);
 
     let d = data.encode_json()?;
-    let rb = http_manager::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
 
-    serde_json::from_slice(&rb)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed avm.getUTXOs '{}'", e)))
+    call.decode(&rb)
 }
 
 /// e.g., "avm.issueStopVertex" on "http://[ADDR]:9650" and "/ext/bc/X" path.
 /// Issue itself is asynchronous, so the internal error is not exposed!
 pub async fn issue_stop_vertex(http_rpc: &str) -> io::Result<()> {
-    let joined = http_manager::join_uri(http_rpc, "/ext/bc/X")?;
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
     log::debug!(
         "issuing a stop vertex transaction via {:?}",
         joined.as_str()
     );
 
+    let call = jsonrpc::Call::new("avm.issueStopVertex");
     let mut data = avm::IssueStopVertexRequest::default();
-    data.method = String::from("avm.issueStopVertex");
+    data.id = call.id;
+    data.method = call.method.clone();
 
     let params = avm::IssueStopVertexParams {};
     data.params = Some(params);
 
     let d = data.encode_json()?;
-    http_manager::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+    transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
 
     Ok(())
 }
+
+/// e.g., "avm.getHeight" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight
+pub async fn get_height(http_rpc: &str) -> io::Result<avm::GetHeightResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
+    log::debug!("getting height via {}", joined.as_str());
+
+    let call = jsonrpc::Call::new("avm.getHeight");
+
+    let data = call.request_with_params(HashMap::new());
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "avm.getBlock" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblock
+pub async fn get_block(
+    http_rpc: &str,
+    block_id: &str,
+    encoding: &str,
+) -> io::Result<avm::GetBlockResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
+    log::debug!("getting block {} via {}", block_id, joined.as_str());
+
+    let call = jsonrpc::Call::new("avm.getBlock");
+
+    let mut params = HashMap::new();
+    params.insert(String::from("blockID"), String::from(block_id));
+    params.insert(String::from("encoding"), String::from(encoding));
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// e.g., "avm.getBlockByHeight" on "http://[ADDR]:9650" and "/ext/bc/X" path.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblockbyheight
+pub async fn get_block_by_height(
+    http_rpc: &str,
+    height: u64,
+    encoding: &str,
+) -> io::Result<avm::GetBlockResponse> {
+    let joined = transport::join_uri(http_rpc, "/ext/bc/X")?;
+    log::debug!("getting block at height {} via {}", height, joined.as_str());
+
+    let call = jsonrpc::Call::new("avm.getBlockByHeight");
+
+    let mut params = HashMap::new();
+    params.insert(String::from("height"), height.to_string());
+    params.insert(String::from("encoding"), String::from(encoding));
+    let data = call.request_with_params(params);
+
+    let d = data.encode_json()?;
+    let rb = transport::post_non_tls(http_rpc, "/ext/bc/X", &d).await?;
+
+    call.decode(&rb)
+}
+
+/// Walks the linearized X-chain by height, yielding the "json" decoded
+/// blocks with their typed transactions.
+///
+/// ```no_run
+/// # async fn run() -> std::io::Result<()> {
+/// use avalanche_types::client::x::BlockWalker;
+/// use futures::StreamExt;
+///
+/// let blocks = BlockWalker::new("http://localhost:9650", 1).stream();
+/// futures::pin_mut!(blocks);
+/// while let Some(blk) = blocks.next().await {
+///     let blk = blk?;
+///     for tx in blk.txs.iter() {
+///         println!("height {} has {} {}", blk.height, tx.unsigned_tx.type_name(), tx.id);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BlockWalker {
+    http_rpc: String,
+    next_height: u64,
+    end_height: Option<u64>,
+    /// Interval to poll for new blocks once the tip is reached.
+    /// If None, the walker ends at the tip.
+    follow_interval: Option<Duration>,
+    tip: Option<u64>,
+}
+
+impl BlockWalker {
+    pub fn new(http_rpc: &str, start_height: u64) -> Self {
+        Self {
+            http_rpc: http_rpc.to_string(),
+            next_height: start_height,
+            end_height: None,
+            follow_interval: None,
+            tip: None,
+        }
+    }
+
+    /// Sets the last height to walk (inclusive).
+    #[must_use]
+    pub fn end_height(mut self, end_height: u64) -> Self {
+        self.end_height = Some(end_height);
+        self
+    }
+
+    /// Sets the walker to wait for new blocks at the tip, polling at the interval.
+    #[must_use]
+    pub fn follow(mut self, interval: Duration) -> Self {
+        self.follow_interval = Some(interval);
+        self
+    }
+
+    /// Returns the height of the next block to yield.
+    pub fn next_height(&self) -> u64 {
+        self.next_height
+    }
+
+    /// Returns the stream of blocks, which ends once the end height or
+    /// the tip (unless following) is reached, or after an error.
+    pub fn stream(self) -> impl futures::Stream<Item = io::Result<avm::ApiBlock>> {
+        futures::stream::unfold(Some(self), |walker| async move {
+            let mut walker = walker?;
+            let next = walker.next().await?;
+            let done = next.is_err();
+            Some((next, if done { None } else { Some(walker) }))
+        })
+    }
+
+    /// Returns the next block, or None once the end height or the tip is reached.
+    async fn next(&mut self) -> Option<io::Result<avm::ApiBlock>> {
+        if let Some(end) = self.end_height {
+            if self.next_height > end {
+                return None;
+            }
+        }
+
+        loop {
+            if self.tip.map_or(true, |tip| self.next_height > tip) {
+                let tip = match get_height(&self.http_rpc).await {
+                    Ok(resp) => match (resp.result, resp.error) {
+                        (Some(r), _) => r.height,
+                        (None, e) => {
+                            return Some(Err(Error::new(
+                                ErrorKind::Other,
+                                format!("failed avm.getHeight {:?}", e),
+                            )))
+                        }
+                    },
+                    Err(e) => return Some(Err(e)),
+                };
+                self.tip = Some(tip);
+            }
+            if self.next_height <= self.tip.unwrap_or_default() {
+                break;
+            }
+            match self.follow_interval {
+                Some(interval) => {
+                    if let Err(e) = cancel::sleep(interval).await {
+                        return Some(Err(e));
+                    }
+                }
+                None => return None,
+            }
+        }
+
+        let resp = match get_block_by_height(&self.http_rpc, self.next_height, "json").await {
+            Ok(resp) => resp,
+            Err(e) => return Some(Err(e)),
+        };
+        let blk = match (resp.result, resp.error) {
+            (Some(r), _) => r.api_block(),
+            (None, e) => Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "failed avm.getBlockByHeight at {} {:?}",
+                    self.next_height, e
+                ),
+            )),
+        };
+        if blk.is_ok() {
+            self.next_height += 1;
+        }
+        Some(blk)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- client::x::test_block_walker --exact --show-output
+#[tokio::test]
+async fn test_block_walker() {
+    use std::sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    };
+
+    use async_trait::async_trait;
+    use futures::StreamExt;
+
+    /// Serves blocks up to the tip, which grows by "step" on every "avm.getHeight",
+    /// and fails the block at the height, if any.
+    struct Chain(AtomicU64, u64, Option<u64>);
+    #[async_trait]
+    impl transport::Transport for Chain {
+        async fn post_non_tls(&self, _: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            let req: serde_json::Value = serde_json::from_str(body).unwrap();
+            let resp = if req["method"] == "avm.getHeight" {
+                let tip = self.0.fetch_add(self.1, Ordering::SeqCst);
+                format!(
+                    "{{\"jsonrpc\":\"2.0\",\"result\":{{\"height\":\"{}\"}},\"id\":{}}}",
+                    tip, req["id"]
+                )
+            } else if req["params"]["height"]
+                .as_str()
+                .map(|h| h.parse::<u64>().unwrap())
+                == self.2
+            {
+                format!(
+                    "{{\"jsonrpc\":\"2.0\",\"error\":{{\"code\":-32000,\"message\":\"not found\"}},\"id\":{}}}",
+                    req["id"]
+                )
+            } else {
+                let height = req["params"]["height"].as_str().unwrap();
+                format!(
+                    "{{\"jsonrpc\":\"2.0\",\"result\":{{\"block\":{{\"parentID\":\"11111111111111111111111111111111LpoYY\",\"height\":{},\"time\":0,\"merkleRoot\":\"11111111111111111111111111111111LpoYY\",\"txs\":[]}},\"encoding\":\"json\"}},\"id\":{}}}",
+                    height, req["id"]
+                )
+            };
+            Ok(resp.into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    // ends at the tip without following
+    transport::scope(Arc::new(Chain(AtomicU64::new(2), 0, None)), async {
+        let blocks = BlockWalker::new("http://test", 1).stream();
+        let heights: Vec<u64> = blocks.map(|blk| blk.unwrap().height).collect().await;
+        assert_eq!(heights, vec![1, 2]);
+    })
+    .await;
+
+    // ends after the failed block
+    transport::scope(Arc::new(Chain(AtomicU64::new(5), 0, Some(2))), async {
+        let blocks: Vec<io::Result<avm::ApiBlock>> =
+            BlockWalker::new("http://test", 1).stream().collect().await;
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].as_ref().unwrap().height, 1);
+        assert!(blocks[1].is_err());
+    })
+    .await;
+
+    // follows the growing tip up to the end height
+    transport::scope(Arc::new(Chain(AtomicU64::new(2), 1, None)), async {
+        let walker = BlockWalker::new("http://test", 3)
+            .end_height(5)
+            .follow(Duration::from_millis(1));
+        assert_eq!(walker.next_height(), 3);
+        let heights: Vec<u64> = walker
+            .stream()
+            .map(|blk| blk.unwrap().height)
+            .collect()
+            .await;
+        assert_eq!(heights, vec![3, 4, 5]);
+    })
+    .await;
+}
@@ -0,0 +1,241 @@
+//! Clock skew estimation and a clock adjusted by the estimated skew, for
+//! validating block timestamps against the network time rather than the
+//! local clock (see "client::clock" to sample the nodes).
+
+use std::{
+    io::{self, Error, ErrorKind},
+    sync::{Arc, RwLock},
+};
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Maximum duration that a block timestamp may be ahead of the local time.
+/// ref. "avalanchego/vms/platformvm/txs/executor.SyncBound"
+pub fn default_max_future() -> Duration {
+    Duration::seconds(10)
+}
+
+/// Remote timestamp, with the local times when it was requested and received.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Sample {
+    /// e.g., the node endpoint.
+    pub source: String,
+    pub sent_at: DateTime<Utc>,
+    pub received_at: DateTime<Utc>,
+    pub remote: DateTime<Utc>,
+}
+
+impl Sample {
+    pub fn round_trip(&self) -> Duration {
+        self.received_at - self.sent_at
+    }
+
+    /// Returns how far the remote time is ahead of the local time, assuming
+    /// the remote timestamp was taken halfway through the round trip.
+    pub fn offset(&self) -> Duration {
+        let midpoint = self.sent_at + self.round_trip() / 2;
+        self.remote - midpoint
+    }
+}
+
+/// Estimated offset of the remote times from the local time.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Skew {
+    /// Median offset, positive if the local clock is behind.
+    pub offset: Duration,
+    pub min_offset: Duration,
+    pub max_offset: Duration,
+    pub samples: usize,
+}
+
+impl Skew {
+    /// Returns the spread of the offsets, which bounds the error of the estimate.
+    pub fn spread(&self) -> Duration {
+        self.max_offset - self.min_offset
+    }
+}
+
+/// Estimates the skew from the median offset, so that a minority of
+/// nodes with a wrong clock does not move the estimate.
+pub fn estimate(samples: &[Sample]) -> io::Result<Skew> {
+    if samples.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "no sample to estimate the clock skew",
+        ));
+    }
+
+    let mut offsets: Vec<Duration> = samples.iter().map(Sample::offset).collect();
+    offsets.sort();
+    let n = offsets.len();
+    let offset = if n % 2 == 1 {
+        offsets[n / 2]
+    } else {
+        (offsets[n / 2 - 1] + offsets[n / 2]) / 2
+    };
+    Ok(Skew {
+        offset,
+        min_offset: offsets[0],
+        max_offset: offsets[n - 1],
+        samples: n,
+    })
+}
+
+#[derive(Debug)]
+struct State {
+    offset: Duration,
+    frozen: Option<DateTime<Utc>>,
+}
+
+/// Local clock adjusted by the offset to the network time,
+/// shared by its clones (e.g., across the VM handlers).
+#[derive(Debug, Clone)]
+pub struct SyncedClock {
+    state: Arc<RwLock<State>>,
+}
+
+impl Default for SyncedClock {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl SyncedClock {
+    pub fn default() -> Self {
+        Self::new(Duration::zero())
+    }
+
+    pub fn new(offset: Duration) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(State {
+                offset,
+                frozen: None,
+            })),
+        }
+    }
+
+    /// Returns the clock stopped at the time, e.g., for tests.
+    pub fn frozen(t: DateTime<Utc>) -> Self {
+        let c = Self::default();
+        c.freeze(t);
+        c
+    }
+
+    pub fn freeze(&self, t: DateTime<Utc>) {
+        let mut state = self.state.write().expect("unexpected poisoned lock");
+        state.frozen = Some(t);
+    }
+
+    pub fn unfreeze(&self) {
+        let mut state = self.state.write().expect("unexpected poisoned lock");
+        state.frozen = None;
+    }
+
+    pub fn offset(&self) -> Duration {
+        self.state.read().expect("unexpected poisoned lock").offset
+    }
+
+    pub fn set_offset(&self, offset: Duration) {
+        let mut state = self.state.write().expect("unexpected poisoned lock");
+        state.offset = offset;
+    }
+
+    pub fn now(&self) -> DateTime<Utc> {
+        let state = self.state.read().expect("unexpected poisoned lock");
+        match state.frozen {
+            Some(t) => t,
+            None => Utc::now() + state.offset,
+        }
+    }
+
+    pub fn now_unix(&self) -> u64 {
+        self.now().timestamp().max(0) as u64
+    }
+
+    /// Fails if the timestamp is more than "max_future" ahead of now,
+    /// or before the parent timestamp (e.g., of the parent block).
+    /// ref. "avalanchego/vms/proposervm.(*postForkCommonComponents).Verify"
+    pub fn verify_timestamp(
+        &self,
+        timestamp: DateTime<Utc>,
+        parent_timestamp: Option<DateTime<Utc>>,
+        max_future: Duration,
+    ) -> io::Result<()> {
+        if let Some(parent_timestamp) = parent_timestamp {
+            if timestamp < parent_timestamp {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "timestamp {} before the parent timestamp {}",
+                        timestamp, parent_timestamp
+                    ),
+                ));
+            }
+        }
+
+        let max_timestamp = self.now() + max_future;
+        if timestamp > max_timestamp {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "timestamp {} too far in the future (max {})",
+                    timestamp, max_timestamp
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- clock::test_clock --exact --show-output
+#[test]
+fn test_clock() {
+    let t0 = DateTime::parse_from_rfc3339("2023-01-01T00:00:00Z")
+        .unwrap()
+        .with_timezone(&Utc);
+    let sample = |source: &str, rtt_ms: i64, offset_ms: i64| Sample {
+        source: source.to_string(),
+        sent_at: t0,
+        received_at: t0 + Duration::milliseconds(rtt_ms),
+        remote: t0 + Duration::milliseconds(rtt_ms / 2 + offset_ms),
+    };
+    assert_eq!(sample("a", 200, 50).offset(), Duration::milliseconds(50));
+    assert_eq!(
+        sample("a", 200, 50).round_trip(),
+        Duration::milliseconds(200)
+    );
+
+    // the wrong clock of one node does not move the median
+    let skew = estimate(&[
+        sample("a", 100, 1000),
+        sample("b", 300, 1200),
+        sample("c", 20, -3_600_000),
+    ])
+    .unwrap();
+    assert_eq!(skew.offset, Duration::milliseconds(1000));
+    assert_eq!(skew.samples, 3);
+    assert_eq!(skew.spread(), Duration::milliseconds(3_601_200));
+    let skew = estimate(&[sample("a", 100, 1000), sample("b", 300, 1200)]).unwrap();
+    assert_eq!(skew.offset, Duration::milliseconds(1100));
+    assert!(estimate(&[]).is_err());
+
+    let c = SyncedClock::frozen(t0);
+    assert_eq!(c.now(), t0);
+    assert_eq!(c.now_unix(), t0.timestamp() as u64);
+    let max_future = default_max_future();
+    c.verify_timestamp(t0 + max_future, Some(t0), max_future)
+        .unwrap();
+    assert!(c
+        .verify_timestamp(t0 + max_future + Duration::seconds(1), None, max_future)
+        .is_err());
+    assert!(c
+        .verify_timestamp(t0, Some(t0 + Duration::seconds(1)), max_future)
+        .is_err());
+
+    let c = SyncedClock::new(Duration::hours(1));
+    let clone = c.clone();
+    assert!(clone.now() > Utc::now() + Duration::minutes(59));
+    c.set_offset(Duration::zero());
+    assert_eq!(clone.offset(), Duration::zero());
+    assert!(clone.now() <= Utc::now());
+}
@@ -1,11 +1,106 @@
 pub mod serde;
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Error, ErrorKind},
+};
 
 use lazy_static::lazy_static;
 
+/// Codec version that the transactions are marshaled with by default.
 pub const VERSION: u16 = 0;
 
+/// VM whose codec marshals the type.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub enum Vm {
+    /// X-chain "avm".
+    X,
+    /// P-chain "platformvm".
+    P,
+}
+
+/// ref. https://doc.rust-lang.org/std/string/trait.ToString.html
+/// ref. https://doc.rust-lang.org/std/fmt/trait.Display.html
+/// Use "Self.to_string()" to directly invoke this
+impl fmt::Display for Vm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vm::X => write!(f, "avm"),
+            Vm::P => write!(f, "platformvm"),
+        }
+    }
+}
+
+/// Type Ids of each codec version per VM.
+/// A new codec version is added by registering its own type table,
+/// so the bytes marshaled with the existing versions keep their type Ids.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/codec#Manager
+#[derive(Debug, Clone, Default)]
+pub struct Registry {
+    types: HashMap<(Vm, u16), HashMap<String, usize>>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the type table of the codec version, replacing the existing one.
+    pub fn register(&mut self, vm: Vm, version: u16, types: HashMap<String, usize>) {
+        self.types.insert((vm, version), types);
+    }
+
+    /// Returns the registered codec versions of the VM in ascending order.
+    pub fn versions(&self, vm: Vm) -> Vec<u16> {
+        let mut versions: Vec<u16> = self
+            .types
+            .keys()
+            .filter(|(v, _)| *v == vm)
+            .map(|(_, version)| *version)
+            .collect();
+        versions.sort_unstable();
+        versions
+    }
+
+    pub fn is_supported(&self, vm: Vm, version: u16) -> bool {
+        self.types.contains_key(&(vm, version))
+    }
+
+    /// Returns an error if the codec version is not registered for the VM.
+    pub fn check_version(&self, vm: Vm, version: u16) -> io::Result<()> {
+        if !self.is_supported(vm, version) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "unknown {} codec version {} (supported {:?})",
+                    vm,
+                    version,
+                    self.versions(vm)
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the type Id of the type name in the codec version.
+    pub fn type_id(&self, vm: Vm, version: u16, type_name: &str) -> io::Result<u32> {
+        self.check_version(vm, version)?;
+        self.types[&(vm, version)]
+            .get(type_name)
+            .map(|id| *id as u32)
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "type {} is not registered in {} codec version {}",
+                        type_name, vm, version
+                    ),
+                )
+            })
+    }
+}
+
 lazy_static! {
     /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/codec#Registry
     /// ref. https://github.com/ava-labs/avalanchego/blob/v1.7.9/wallet/chain/x/codec.go#L31
@@ -65,6 +160,45 @@ lazy_static! {
         m.insert("platformvm.UnsignedRewardValidatorTx".to_string(), 20);
         m.insert("platformvm.StakeableLockIn".to_string(), 21);
         m.insert("platformvm.StakeableLockOut".to_string(), 22);
+        // registered after the Banff (23-32) types in Durango, which are not supported here
+        // ref. https://github.com/ava-labs/avalanchego/blob/v1.11.0/vms/platformvm/txs/codec.go
+        m.insert("platformvm.BaseTx".to_string(), 34);
         m
     };
+
+    /// Codec versions known to this crate.
+    pub static ref REGISTRY: Registry = {
+        let mut r = Registry::new();
+        r.register(Vm::X, 0, X_TYPES.clone());
+        r.register(Vm::P, 0, P_TYPES.clone());
+        r
+    };
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- codec::test_registry --exact --show-output
+#[test]
+fn test_registry() {
+    assert_eq!(REGISTRY.versions(Vm::X), vec![VERSION]);
+    assert_eq!(
+        REGISTRY
+            .type_id(Vm::P, VERSION, "platformvm.UnsignedAddValidatorTx")
+            .unwrap(),
+        12
+    );
+    assert_eq!(
+        REGISTRY
+            .type_id(Vm::X, VERSION, "secp256k1fx.Credential")
+            .unwrap(),
+        9
+    );
+    assert!(REGISTRY.check_version(Vm::P, 1).is_err());
+    assert!(REGISTRY.type_id(Vm::X, VERSION, "avm.Unknown").is_err());
+
+    let mut r = REGISTRY.clone();
+    let mut p_types = P_TYPES.clone();
+    p_types.insert("platformvm.FutureTx".to_string(), 100);
+    r.register(Vm::P, 1, p_types);
+    assert_eq!(r.versions(Vm::P), vec![0, 1]);
+    assert_eq!(r.type_id(Vm::P, 1, "platformvm.FutureTx").unwrap(), 100);
+    assert!(r.type_id(Vm::P, 0, "platformvm.FutureTx").is_err());
 }
@@ -0,0 +1,182 @@
+use std::io::{self, Error, ErrorKind};
+
+use serde::{self, Deserialize, Deserializer, Serializer};
+use serde_with::{DeserializeAs, SerializeAs};
+
+use crate::{formatting, ids};
+
+/// Parses the Id from any of the encodings that avalanchego APIs produce:
+/// cb58 ("TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES"),
+/// 0x-prefixed hex (with or without the 4-byte checksum),
+/// or base64 (with the "codec_base64" feature).
+pub fn parse(s: &str) -> io::Result<ids::Id> {
+    let s = s.trim();
+
+    if let Some(h) = s.strip_prefix("0x") {
+        let decoded = hex::decode(h).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed to decode hex id '{}'", e),
+            )
+        })?;
+        return match decoded.len() {
+            ids::LEN => Ok(ids::Id::from_slice(&decoded)),
+            n if n == ids::LEN + 4 => Ok(ids::Id::from_slice(
+                &formatting::decode_hex_with_checksum(h.as_bytes())?,
+            )),
+            n => Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid hex id length {} (expected {})", n, ids::LEN),
+            )),
+        };
+    }
+
+    let cb58_err = match formatting::decode_cb58_with_checksum(s) {
+        Ok(decoded) if decoded.len() == ids::LEN => return Ok(ids::Id::from_slice(&decoded)),
+        Ok(decoded) => format!("invalid cb58 id length {}", decoded.len()),
+        Err(e) => e.to_string(),
+    };
+
+    #[cfg(feature = "codec_base64")]
+    if let Ok(decoded) = base64::decode(s) {
+        if decoded.len() == ids::LEN {
+            return Ok(ids::Id::from_slice(&decoded));
+        }
+    }
+
+    Err(Error::new(
+        ErrorKind::InvalidInput,
+        format!("'{}' is not a cb58, hex, or base64 id ({})", s, cb58_err),
+    ))
+}
+
+/// Serializes the Id in cb58.
+pub fn serialize<S>(x: &ids::Id, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&x.to_string())
+}
+
+/// Deserializes the Id from cb58, hex, or base64.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<ids::Id, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    parse(&s).map_err(serde::de::Error::custom)
+}
+
+/// Serializes the Id in cb58, and deserializes from any encoding.
+pub struct FlexibleId;
+
+impl SerializeAs<ids::Id> for FlexibleId {
+    fn serialize_as<S>(x: &ids::Id, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&x.to_string())
+    }
+}
+
+impl<'de> DeserializeAs<'de, ids::Id> for FlexibleId {
+    fn deserialize_as<D>(deserializer: D) -> Result<ids::Id, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes the Id in 0x-prefixed hex (without checksum),
+/// and deserializes from any encoding.
+pub struct Hex0xId;
+
+impl SerializeAs<ids::Id> for Hex0xId {
+    fn serialize_as<S>(x: &ids::Id, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&format!("0x{}", hex::encode(x.as_ref())))
+    }
+}
+
+impl<'de> DeserializeAs<'de, ids::Id> for Hex0xId {
+    fn deserialize_as<D>(deserializer: D) -> Result<ids::Id, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Serializes the Id in base64, and deserializes from any encoding.
+#[cfg(feature = "codec_base64")]
+pub struct Base64Id;
+
+#[cfg(feature = "codec_base64")]
+impl SerializeAs<ids::Id> for Base64Id {
+    fn serialize_as<S>(x: &ids::Id, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&base64::encode(x.as_ref()))
+    }
+}
+
+#[cfg(feature = "codec_base64")]
+impl<'de> DeserializeAs<'de, ids::Id> for Base64Id {
+    fn deserialize_as<D>(deserializer: D) -> Result<ids::Id, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        parse(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- codec::serde::flexible_id::test_custom_de_serializer --exact --show-output
+#[test]
+fn test_custom_de_serializer() {
+    use std::str::FromStr;
+
+    use serde::Serialize;
+    use serde_with::serde_as;
+
+    #[serde_as]
+    #[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+    struct Data {
+        #[serde(with = "crate::codec::serde::flexible_id")]
+        id: ids::Id,
+        #[serde_as(as = "Vec<Hex0xId>")]
+        ids: Vec<ids::Id>,
+    }
+
+    let id = ids::Id::from_str("TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES").unwrap();
+    let d = Data {
+        id,
+        ids: vec![id, ids::Id::empty()],
+    };
+
+    let json_encoded = serde_json::to_string(&d).unwrap();
+    assert!(json_encoded.contains("\"TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES\""));
+    let json_decoded = serde_json::from_str(&json_encoded).unwrap();
+    assert_eq!(d, json_decoded);
+
+    let hex_id = format!("0x{}", hex::encode(id.as_ref()));
+    let hex_id_with_checksum = format!("0x{}", formatting::encode_hex_with_checksum(id.as_ref()));
+    let json_decoded_2: Data = serde_json::from_str(&format!(
+        "{{\"id\":\"{}\",\"ids\":[\"{}\",\"11111111111111111111111111111111LpoYY\"]}}",
+        hex_id, hex_id_with_checksum
+    ))
+    .unwrap();
+    assert_eq!(d, json_decoded_2);
+
+    assert!(parse("0x1234").is_err());
+    assert!(parse("not-an-id").is_err());
+
+    #[cfg(feature = "codec_base64")]
+    assert_eq!(parse(&base64::encode(id.as_ref())).unwrap(), id);
+}
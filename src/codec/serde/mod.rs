@@ -1,3 +1,4 @@
+pub mod flexible_id;
 pub mod hex_0x_bytes;
 pub mod hex_0x_primitive_types_h256;
 pub mod hex_0x_primitive_types_u256;
@@ -55,3 +55,13 @@ lazy_static! {
         m
     };
 }
+
+/// Returns the HRP of the network, or "custom" for the networks
+/// without one (e.g., private networks).
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#GetHRP
+pub fn get_hrp(network_id: u32) -> &'static str {
+    NETWORK_ID_TO_HRP
+        .get(&network_id)
+        .copied()
+        .unwrap_or(FALLBACK_HRP)
+}
@@ -40,6 +40,12 @@ impl Error {
     }
 }
 
+impl From<Error> for std::io::Error {
+    fn from(e: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::Other, e.message())
+    }
+}
+
 #[derive(Debug)]
 pub struct Errors {
     d: Rc<RefCell<Vec<Error>>>,
@@ -1,4 +1,5 @@
 pub mod txs;
+pub mod utils;
 
 use primitive_types::{H160, H256};
 use rlp_derive::{RlpDecodable, RlpDecodableWrapper, RlpEncodable, RlpEncodableWrapper};
@@ -0,0 +1,60 @@
+use crate::{ids, txs::transferable};
+use primitive_types::H160;
+use serde::{Deserialize, Serialize};
+
+/// Output of the C-chain atomic import transaction, which credits the
+/// address with the amount in nano-AVAX (or the asset units).
+/// ref. https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#EVMOutput
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct Output {
+    pub address: H160,
+    pub amount: u64,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+}
+
+/// Input of the C-chain atomic export transaction, which debits the
+/// address by the amount in nano-AVAX (or the asset units).
+/// ref. https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#EVMInput
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct Input {
+    pub address: H160,
+    pub amount: u64,
+    #[serde(rename = "assetID")]
+    pub asset_id: ids::Id,
+    pub nonce: u64,
+}
+
+/// Imports the UTXOs from the shared memory of the source chain
+/// into the C-chain accounts.
+/// ref. https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedImportTx
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct ImportTx {
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(rename = "sourceChain")]
+    pub source_chain: ids::Id,
+    #[serde(rename = "importedInputs")]
+    pub imported_inputs: Vec<transferable::Input>,
+    #[serde(rename = "outputs")]
+    pub outs: Vec<Output>,
+}
+
+/// Exports the funds of the C-chain accounts as the UTXOs
+/// in the shared memory of the destination chain.
+/// ref. https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedExportTx
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct ExportTx {
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(rename = "destinationChain")]
+    pub destination_chain: ids::Id,
+    #[serde(rename = "inputs")]
+    pub ins: Vec<Input>,
+    #[serde(rename = "exportedOutputs")]
+    pub exported_outputs: Vec<transferable::Output>,
+}
@@ -1,3 +1,4 @@
+pub mod atomic;
 pub mod eip1559;
 pub mod legacy;
 
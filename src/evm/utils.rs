@@ -0,0 +1,121 @@
+//! EVM address and Keccak256 helpers, to render and validate the
+//! C-chain and subnet-evm addresses without ethers.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{hash, key::secp256k1::address};
+use primitive_types::{H160, H256};
+
+/// Returns the EIP-55 checksummed "0x" address.
+/// ref. https://eips.ethereum.org/EIPS/eip-55
+pub fn to_checksum_address(addr: &H160) -> String {
+    address::h160_to_eth_address(*addr)
+}
+
+/// Returns true if the address is "0x" followed by 40 hex characters
+/// in the EIP-55 checksummed case.
+pub fn is_checksum_address(addr: &str) -> bool {
+    match addr.strip_prefix("0x") {
+        Some(hex_part) if is_hex_address(hex_part) => address::eth_checksum(hex_part) == hex_part,
+        _ => false,
+    }
+}
+
+/// Parses the "0x" address (the prefix is optional). All lower-case or
+/// all upper-case addresses carry no checksum and are accepted as is,
+/// while mixed-case addresses must have a valid EIP-55 checksum.
+pub fn parse_address(addr: &str) -> io::Result<H160> {
+    let hex_part = addr.trim().trim_start_matches("0x");
+    if !is_hex_address(hex_part) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid EVM address '{}'", addr),
+        ));
+    }
+
+    let is_mixed_case = hex_part.chars().any(|c| c.is_ascii_uppercase())
+        && hex_part.chars().any(|c| c.is_ascii_lowercase());
+    if is_mixed_case && address::eth_checksum(hex_part) != hex_part {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid EIP-55 checksum for '{}'", addr),
+        ));
+    }
+
+    let b = hex::decode(hex_part).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("failed hex::decode '{}'", e),
+        )
+    })?;
+    Ok(H160::from_slice(&b))
+}
+
+fn is_hex_address(hex_part: &str) -> bool {
+    hex_part.len() == 40 && hex_part.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Returns the Keccak256 digest.
+pub fn keccak256(b: impl AsRef<[u8]>) -> H256 {
+    hash::keccak256(b)
+}
+
+/// Returns the 4-byte selector of the Solidity function signature
+/// (e.g., "transfer(address,uint256)").
+pub fn selector(signature: &str) -> [u8; 4] {
+    let mut s = [0u8; 4];
+    s.copy_from_slice(&keccak256(signature.as_bytes()).as_bytes()[..4]);
+    s
+}
+
+/// Returns the first topic of the logs of the Solidity event signature
+/// (e.g., "Transfer(address,address,uint256)").
+pub fn event_topic(signature: &str) -> H256 {
+    keccak256(signature.as_bytes())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- evm::utils::test_address --exact --show-output
+#[test]
+fn test_address() {
+    // ref. https://eips.ethereum.org/EIPS/eip-55#test-cases
+    for addr in [
+        "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+        "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+        "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+        "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+    ] {
+        assert!(is_checksum_address(addr));
+        let h160 = parse_address(addr).unwrap();
+        assert_eq!(to_checksum_address(&h160), addr);
+        assert_eq!(parse_address(&addr.to_lowercase()).unwrap(), h160);
+        assert!(!is_checksum_address(&addr.to_lowercase()));
+    }
+
+    // one flipped case
+    assert!(!is_checksum_address(
+        "0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    ));
+    assert!(parse_address("0x5AAeb6053F3E94C9b9A09f33669435E7Ef1BeAed").is_err());
+    assert!(parse_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beae").is_err());
+    assert!(parse_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaeg").is_err());
+    assert!(!is_checksum_address(
+        "5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed"
+    ));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- evm::utils::test_keccak256 --exact --show-output
+#[test]
+fn test_keccak256() {
+    assert_eq!(
+        hex::encode(keccak256([])),
+        "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+    );
+    assert_eq!(
+        hex::encode(selector("transfer(address,uint256)")),
+        "a9059cbb"
+    );
+    assert_eq!(
+        hex::encode(event_topic("Transfer(address,address,uint256)")),
+        "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef"
+    );
+}
@@ -0,0 +1,55 @@
+/*
+ * C ABI of "avalanche-types" built with the "ffi" feature.
+ * ref. src/ffi/mod.rs
+ *
+ * Output strings are allocated by the library and must be released with
+ * "avax_string_free". Functions return AVAX_OK or a negative error code,
+ * with the message of the calling thread in "avax_last_error".
+ */
+
+#ifndef AVALANCHE_TYPES_H
+#define AVALANCHE_TYPES_H
+
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define AVAX_OK 0
+#define AVAX_ERR_INVALID_INPUT -1
+#define AVAX_ERR_NETWORK -2
+#define AVAX_ERR_PANIC -3
+#define AVAX_ERR_INVALID_DATA -4
+#define AVAX_ERR_TIMED_OUT -5
+#define AVAX_ERR_INTERRUPTED -6
+#define AVAX_ERR_NOT_FOUND -7
+#define AVAX_ERR_ALREADY_EXISTS -8
+#define AVAX_ERR_UNSUPPORTED -9
+#define AVAX_ERR_OTHER -10
+
+void avax_string_free(char *s);
+
+char *avax_last_error(void);
+
+int avax_key_generate(char **out_private_key);
+
+int avax_key_address(const char *private_key, uint32_t network_id, const char *chain_alias,
+                     char **out_address);
+
+int avax_key_eth_address(const char *private_key, char **out_address);
+
+int avax_balance(const char *http_rpc, const char *private_key, const char *chain_alias,
+                 uint64_t *out_balance);
+
+int avax_x_transfer(const char *http_rpc, const char *private_key, const char *receiver,
+                    uint64_t amount, int issue, char **out_signed_json);
+
+int avax_issue_tx(const char *http_rpc, const char *chain_alias, const char *tx_hex,
+                  char **out_tx_id);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* AVALANCHE_TYPES_H */
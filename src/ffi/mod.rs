@@ -0,0 +1,373 @@
+//! C ABI over the key and wallet operations, for mobile wallets
+//! (e.g., Swift or Kotlin via JNI) that embed the crate instead of
+//! re-implementing the Avalanche serialization.
+//!
+//! Build the library with the "ffi" feature, and include "avalanche_types.h":
+//!
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+//!
+//! Conventions:
+//! - Input strings are NUL-terminated UTF-8.
+//! - Output strings are allocated by the library, and must be released
+//!   with "avax_string_free".
+//! - Functions return "AVAX_OK" (0) or a negative error code of the failure
+//!   (e.g., "AVAX_ERR_TIMED_OUT"), and the error message of the calling
+//!   thread is available via "avax_last_error".
+//! - Functions with the network calls block the calling thread, so call them
+//!   off the UI thread.
+//! - Private keys are accepted in hex ("0x...") or CB58 ("PrivateKey-...").
+
+use std::{
+    cell::RefCell,
+    ffi::{CStr, CString},
+    io::{self, Error, ErrorKind},
+    os::raw::{c_char, c_int},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use crate::{
+    bindings::{self, parse_key, RUNTIME},
+    key::secp256k1::private_key::Key,
+};
+
+pub const AVAX_OK: c_int = 0;
+/// Invalid argument (e.g., a NULL pointer, a malformed key or address).
+pub const AVAX_ERR_INVALID_INPUT: c_int = -1;
+/// Failed to reach the node (e.g., connection refused or reset).
+pub const AVAX_ERR_NETWORK: c_int = -2;
+/// Panic caught at the ABI boundary.
+pub const AVAX_ERR_PANIC: c_int = -3;
+/// Malformed data (e.g., an undecodable response or transaction).
+pub const AVAX_ERR_INVALID_DATA: c_int = -4;
+/// The call or the acceptance poll timed out.
+pub const AVAX_ERR_TIMED_OUT: c_int = -5;
+/// The call was cancelled.
+pub const AVAX_ERR_INTERRUPTED: c_int = -6;
+/// Not found (e.g., an unknown transaction or chain).
+pub const AVAX_ERR_NOT_FOUND: c_int = -7;
+/// Already exists (e.g., a transaction issued twice).
+pub const AVAX_ERR_ALREADY_EXISTS: c_int = -8;
+/// Not supported (e.g., by the node version).
+pub const AVAX_ERR_UNSUPPORTED: c_int = -9;
+/// Any other failure (e.g., the node rejected the transaction,
+/// or the funds are insufficient).
+pub const AVAX_ERR_OTHER: c_int = -10;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs the call and converts its result to the status code,
+/// catching panics so that they never unwind into the caller.
+fn call<F>(f: F) -> c_int
+where
+    F: FnOnce() -> io::Result<()>,
+{
+    let (code, msg) = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => (AVAX_OK, None),
+        Ok(Err(e)) => (error_code(e.kind()), Some(e.to_string())),
+        Err(_) => (AVAX_ERR_PANIC, Some(String::from("panicked"))),
+    };
+    LAST_ERROR.with(|last| *last.borrow_mut() = msg);
+    code
+}
+
+/// Returns the status code of the error kind.
+fn error_code(kind: ErrorKind) -> c_int {
+    match kind {
+        ErrorKind::InvalidInput => AVAX_ERR_INVALID_INPUT,
+        ErrorKind::ConnectionRefused
+        | ErrorKind::ConnectionReset
+        | ErrorKind::ConnectionAborted
+        | ErrorKind::NotConnected
+        | ErrorKind::AddrNotAvailable
+        | ErrorKind::BrokenPipe => AVAX_ERR_NETWORK,
+        ErrorKind::InvalidData | ErrorKind::UnexpectedEof => AVAX_ERR_INVALID_DATA,
+        ErrorKind::TimedOut => AVAX_ERR_TIMED_OUT,
+        ErrorKind::Interrupted => AVAX_ERR_INTERRUPTED,
+        ErrorKind::NotFound => AVAX_ERR_NOT_FOUND,
+        ErrorKind::AlreadyExists => AVAX_ERR_ALREADY_EXISTS,
+        ErrorKind::Unsupported => AVAX_ERR_UNSUPPORTED,
+        _ => AVAX_ERR_OTHER,
+    }
+}
+
+unsafe fn read_str<'a>(p: *const c_char, name: &str) -> io::Result<&'a str> {
+    if p.is_null() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("{} is NULL", name),
+        ));
+    }
+    CStr::from_ptr(p).to_str().map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("{} is not UTF-8 '{}'", name, e),
+        )
+    })
+}
+
+unsafe fn write_str(out: *mut *mut c_char, s: String) -> io::Result<()> {
+    if out.is_null() {
+        return Err(Error::new(ErrorKind::InvalidInput, "output is NULL"));
+    }
+    let s = CString::new(s)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("failed CString::new {}", e)))?;
+    *out = s.into_raw();
+    Ok(())
+}
+
+/// Releases the string returned by the library. NULL is ignored.
+///
+/// # Safety
+/// "s" must be NULL or a string returned by this library, not yet freed.
+#[no_mangle]
+pub unsafe extern "C" fn avax_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Returns the error message of the last failed call on this thread,
+/// or NULL if the last call succeeded. Free it with "avax_string_free".
+#[no_mangle]
+pub extern "C" fn avax_last_error() -> *mut c_char {
+    LAST_ERROR.with(|last| match last.borrow().as_ref() {
+        Some(msg) => CString::new(msg.replace('\0', " "))
+            .map(CString::into_raw)
+            .unwrap_or(ptr::null_mut()),
+        None => ptr::null_mut(),
+    })
+}
+
+/// Generates a new private key, and writes it in CB58 ("PrivateKey-...").
+///
+/// # Safety
+/// "out_private_key" must be a valid pointer to write the string to.
+#[no_mangle]
+pub unsafe extern "C" fn avax_key_generate(out_private_key: *mut *mut c_char) -> c_int {
+    call(|| {
+        let key = Key::generate()?;
+        write_str(out_private_key, key.to_cb58())
+    })
+}
+
+/// Writes the bech32 address of the key for the chain alias
+/// (e.g., "X", "P") on the network (e.g., "X-avax1...").
+///
+/// # Safety
+/// The strings must be valid NUL-terminated strings, and "out_address"
+/// a valid pointer to write the string to.
+#[no_mangle]
+pub unsafe extern "C" fn avax_key_address(
+    private_key: *const c_char,
+    network_id: u32,
+    chain_alias: *const c_char,
+    out_address: *mut *mut c_char,
+) -> c_int {
+    call(|| {
+        let key = parse_key(read_str(private_key, "private_key")?)?;
+        let chain_alias = read_str(chain_alias, "chain_alias")?;
+        let addr = key.to_public_key().hrp_address(network_id, chain_alias)?;
+        write_str(out_address, addr)
+    })
+}
+
+/// Writes the EIP-55 checksummed Ethereum address of the key (e.g., C-chain).
+///
+/// # Safety
+/// "private_key" must be a valid NUL-terminated string, and "out_address"
+/// a valid pointer to write the string to.
+#[no_mangle]
+pub unsafe extern "C" fn avax_key_eth_address(
+    private_key: *const c_char,
+    out_address: *mut *mut c_char,
+) -> c_int {
+    call(|| {
+        let key = parse_key(read_str(private_key, "private_key")?)?;
+        write_str(out_address, key.to_public_key().eth_address())
+    })
+}
+
+/// Writes the balance of the key on the "X" or "P" chain in nano-AVAX.
+///
+/// # Safety
+/// The strings must be valid NUL-terminated strings, and "out_balance"
+/// a valid pointer.
+#[no_mangle]
+pub unsafe extern "C" fn avax_balance(
+    http_rpc: *const c_char,
+    private_key: *const c_char,
+    chain_alias: *const c_char,
+    out_balance: *mut u64,
+) -> c_int {
+    call(|| {
+        let http_rpc = read_str(http_rpc, "http_rpc")?;
+        let key = parse_key(read_str(private_key, "private_key")?)?;
+        let chain_alias = read_str(chain_alias, "chain_alias")?;
+        if out_balance.is_null() {
+            return Err(Error::new(ErrorKind::InvalidInput, "out_balance is NULL"));
+        }
+
+        let balance = RUNTIME.block_on(async {
+            let w = bindings::build_wallet(http_rpc, &key).await?;
+            match chain_alias {
+                "X" => w.x().balance().await,
+                "P" => w.p().balance().await,
+                _ => Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("unsupported chain alias '{}'", chain_alias),
+                )),
+            }
+        })?;
+        *out_balance = balance;
+        Ok(())
+    })
+}
+
+/// Builds and signs the X-chain AVAX transfer to the receiver address
+/// (e.g., "X-avax1..."), and issues it if "issue" is non-zero.
+/// Writes the signed transaction in JSON, including "txID" and "txHex"
+/// (e.g., to issue later with "avax_issue_tx").
+///
+/// # Safety
+/// The strings must be valid NUL-terminated strings, and "out_signed_json"
+/// a valid pointer to write the string to.
+#[no_mangle]
+pub unsafe extern "C" fn avax_x_transfer(
+    http_rpc: *const c_char,
+    private_key: *const c_char,
+    receiver: *const c_char,
+    amount: u64,
+    issue: c_int,
+    out_signed_json: *mut *mut c_char,
+) -> c_int {
+    call(|| {
+        let http_rpc = read_str(http_rpc, "http_rpc")?;
+        let key = parse_key(read_str(private_key, "private_key")?)?;
+        let receiver = read_str(receiver, "receiver")?;
+
+        let signed = RUNTIME.block_on(bindings::x_transfer(
+            http_rpc,
+            &key,
+            receiver,
+            amount,
+            issue != 0,
+            false,
+        ))?;
+        let json = serde_json::to_string(&signed).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed serde_json::to_string '{}'", e),
+            )
+        })?;
+        write_str(out_signed_json, json)
+    })
+}
+
+/// Issues the signed transaction in hex (e.g., "txHex" of "avax_x_transfer")
+/// to the "X" or "P" chain, and writes the transaction Id.
+///
+/// # Safety
+/// The strings must be valid NUL-terminated strings, and "out_tx_id"
+/// a valid pointer to write the string to.
+#[no_mangle]
+pub unsafe extern "C" fn avax_issue_tx(
+    http_rpc: *const c_char,
+    chain_alias: *const c_char,
+    tx_hex: *const c_char,
+    out_tx_id: *mut *mut c_char,
+) -> c_int {
+    call(|| {
+        let http_rpc = read_str(http_rpc, "http_rpc")?;
+        let chain_alias = read_str(chain_alias, "chain_alias")?;
+        let tx_hex = read_str(tx_hex, "tx_hex")?;
+
+        let tx_id = RUNTIME.block_on(bindings::issue_tx(http_rpc, chain_alias, tx_hex))?;
+        write_str(out_tx_id, tx_id.to_string())
+    })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- ffi::test_keys --exact --show-output
+#[test]
+fn test_keys() {
+    unsafe {
+        let mut pk: *mut c_char = ptr::null_mut();
+        assert_eq!(avax_key_generate(&mut pk), AVAX_OK);
+        assert!(avax_last_error().is_null());
+        let pk_str = CStr::from_ptr(pk).to_str().unwrap().to_string();
+        assert!(pk_str.starts_with("PrivateKey-"));
+
+        let key = parse_key(&pk_str).unwrap();
+        let chain = CString::new("X").unwrap();
+        let mut addr: *mut c_char = ptr::null_mut();
+        assert_eq!(avax_key_address(pk, 1, chain.as_ptr(), &mut addr), AVAX_OK);
+        assert_eq!(
+            CStr::from_ptr(addr).to_str().unwrap(),
+            key.to_public_key().hrp_address(1, "X").unwrap()
+        );
+
+        let hex = CString::new(key.to_hex()).unwrap();
+        let mut eth: *mut c_char = ptr::null_mut();
+        assert_eq!(avax_key_eth_address(hex.as_ptr(), &mut eth), AVAX_OK);
+        assert_eq!(
+            CStr::from_ptr(eth).to_str().unwrap(),
+            key.to_public_key().eth_address()
+        );
+
+        avax_string_free(pk);
+        avax_string_free(addr);
+        avax_string_free(eth);
+
+        let bad = CString::new("PrivateKey-invalid").unwrap();
+        let mut out: *mut c_char = ptr::null_mut();
+        assert_eq!(
+            avax_key_eth_address(bad.as_ptr(), &mut out),
+            AVAX_ERR_INVALID_INPUT
+        );
+        assert!(out.is_null());
+        let err = avax_last_error();
+        assert!(!err.is_null());
+        avax_string_free(err);
+
+        assert_eq!(
+            avax_key_address(ptr::null(), 1, chain.as_ptr(), &mut out),
+            AVAX_ERR_INVALID_INPUT
+        );
+        assert_eq!(avax_key_generate(ptr::null_mut()), AVAX_ERR_INVALID_INPUT);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- ffi::test_error_codes --exact --show-output
+#[test]
+fn test_error_codes() {
+    let codes = [
+        (ErrorKind::InvalidInput, AVAX_ERR_INVALID_INPUT),
+        (ErrorKind::ConnectionRefused, AVAX_ERR_NETWORK),
+        (ErrorKind::InvalidData, AVAX_ERR_INVALID_DATA),
+        (ErrorKind::TimedOut, AVAX_ERR_TIMED_OUT),
+        (ErrorKind::Interrupted, AVAX_ERR_INTERRUPTED),
+        (ErrorKind::NotFound, AVAX_ERR_NOT_FOUND),
+        (ErrorKind::AlreadyExists, AVAX_ERR_ALREADY_EXISTS),
+        (ErrorKind::Unsupported, AVAX_ERR_UNSUPPORTED),
+        (ErrorKind::Other, AVAX_ERR_OTHER),
+    ];
+    for (kind, code) in codes {
+        assert_eq!(call(|| Err(Error::new(kind, "failed"))), code);
+        let err = avax_last_error();
+        assert!(!err.is_null());
+        unsafe { avax_string_free(err) };
+    }
+    // distinct codes, none of which is "AVAX_OK" or "AVAX_ERR_PANIC"
+    let mut seen: Vec<c_int> = codes.iter().map(|(_, code)| *code).collect();
+    seen.sort_unstable();
+    seen.dedup();
+    assert_eq!(seen.len(), codes.len());
+    assert!(!seen.contains(&AVAX_OK) && !seen.contains(&AVAX_ERR_PANIC));
+
+    assert_eq!(call(|| panic!("ohh snap!")), AVAX_ERR_PANIC);
+}
@@ -0,0 +1,132 @@
+//! CB58 encoding: base58 with the 4-byte sha256 checksum appended,
+//! as used for Ids, keys, and payloads across avalanchego APIs.
+//!
+//! ```
+//! use avalanche_types::formatting::cb58;
+//!
+//! let encoded = cb58::encode(&[1, 2, 3]);
+//! assert_eq!(cb58::decode(&encoded).unwrap(), vec![1, 2, 3]);
+//! assert!(matches!(cb58::decode("3"), Err(cb58::Error::MissingChecksum { .. })));
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::io;
+use ring::digest::{digest, SHA256};
+
+/// Length of the checksum suffix in bytes.
+pub const CHECKSUM_LENGTH: usize = 4;
+
+/// Errors from decoding CB58 strings.
+/// "Display" is implemented by hand, since "thiserror" requires std.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    InvalidBase58(String),
+    MissingChecksum { len: usize },
+    InvalidChecksum { expected: Vec<u8>, actual: Vec<u8> },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidBase58(e) => write!(f, "failed to decode base58 ({})", e),
+            Error::MissingChecksum { len } => write!(
+                f,
+                "decoded length {} is shorter than the {}-byte checksum",
+                len, CHECKSUM_LENGTH
+            ),
+            Error::InvalidChecksum { expected, actual } => {
+                write!(f, "invalid checksum {:?} != {:?}", actual, expected)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, e.to_string())
+    }
+}
+
+/// Returns the last 4 bytes of the sha256 digest.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum
+pub fn checksum(d: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let h = digest(&SHA256, d);
+    let h = h.as_ref();
+    let mut c = [0u8; CHECKSUM_LENGTH];
+    c.copy_from_slice(&h[h.len() - CHECKSUM_LENGTH..]);
+    c
+}
+
+/// Appends the checksum to the payload and encodes it in base58.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#Encode
+pub fn encode(d: &[u8]) -> String {
+    bs58::encode(&with_checksum(d)).into_string()
+}
+
+/// Same as "encode" but returns the base58 bytes.
+pub fn encode_vec(d: &[u8]) -> Vec<u8> {
+    bs58::encode(&with_checksum(d)).into_vec()
+}
+
+fn with_checksum(d: &[u8]) -> Vec<u8> {
+    let mut checked = Vec::with_capacity(d.len() + CHECKSUM_LENGTH);
+    checked.extend_from_slice(d);
+    checked.extend_from_slice(&checksum(d));
+    checked
+}
+
+/// Decodes the base58 string and validates the trailing checksum,
+/// returning the payload without the checksum.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#Decode
+pub fn decode(s: &str) -> Result<Vec<u8>, Error> {
+    let decoded = bs58::decode(s)
+        .into_vec()
+        .map_err(|e| Error::InvalidBase58(e.to_string()))?;
+    if decoded.len() < CHECKSUM_LENGTH {
+        return Err(Error::MissingChecksum { len: decoded.len() });
+    }
+
+    let (orig, actual) = decoded.split_at(decoded.len() - CHECKSUM_LENGTH);
+    let expected = checksum(orig);
+    if actual != expected {
+        return Err(Error::InvalidChecksum {
+            expected: expected.to_vec(),
+            actual: actual.to_vec(),
+        });
+    }
+    Ok(orig.to_vec())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::cb58::test_encode_decode --exact --show-output
+#[test]
+fn test_encode_decode() {
+    let d: Vec<u8> = vec![
+        0x3d, 0x0a, 0xd1, 0x2b, 0x8e, 0xe8, 0x92, 0x8e, 0xdf, 0x24, //
+        0x8c, 0xa9, 0x1c, 0xa5, 0x56, 0x00, 0xfb, 0x38, 0x3f, 0x07, //
+    ];
+    let encoded = encode(&d);
+    assert_eq!(encoded, "6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwx");
+    assert_eq!(encode_vec(&d), encoded.as_bytes().to_vec());
+    assert_eq!(decode(&encoded).unwrap(), d);
+
+    assert_eq!(encode(&[]), "45PJLL");
+    assert!(decode("45PJLL").unwrap().is_empty());
+
+    assert!(matches!(decode("0OIl"), Err(Error::InvalidBase58(_))));
+    assert_eq!(decode("2"), Err(Error::MissingChecksum { len: 1 }));
+    assert!(matches!(
+        decode("6ZmBHXTqjknJoZtXbnJ6x7af863rXDTwy"),
+        Err(Error::InvalidChecksum { .. })
+    ));
+
+    let e: io::Error = decode("2").unwrap_err().into();
+    assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+}
@@ -1,5 +1,8 @@
-use std::io::{self, Error, ErrorKind};
+pub mod cb58;
 
+use alloc::{format, string::String, vec::Vec};
+
+use crate::io::{self, Error, ErrorKind};
 use bech32::{ToBase32, Variant};
 use ring::digest::{digest, SHA256};
 
@@ -10,18 +13,7 @@ const CHECKSUM_LENGTH: usize = 4;
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#EncodeWithChecksum
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum
 pub fn encode_cb58_with_checksum_string(d: &[u8]) -> String {
-    // "hashing.Checksum" of "sha256.Sum256"
-    let checksum: Vec<u8> = digest(&SHA256, d).as_ref().into();
-    let checksum_length = checksum.len();
-    let checksum = &checksum[checksum_length - CHECKSUM_LENGTH..];
-
-    let mut checked = d.to_vec();
-    let mut checksum = checksum.to_vec();
-    checked.append(&mut checksum);
-
-    // ref. "utils/formatting encode.CB58"
-    // ref. "base58.Encode"
-    bs58::encode(&checked).into_string()
+    cb58::encode(d)
 }
 
 /// Implements "formatting.EncodeWithChecksum" with "formatting.CB58".
@@ -29,50 +21,14 @@ pub fn encode_cb58_with_checksum_string(d: &[u8]) -> String {
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#EncodeWithChecksum
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#Checksum
 pub fn encode_cb58_with_checksum_vec(d: &[u8]) -> Vec<u8> {
-    // "hashing.Checksum" of "sha256.Sum256"
-    let checksum: Vec<u8> = digest(&SHA256, d).as_ref().into();
-    let checksum_length = checksum.len();
-    let checksum = &checksum[checksum_length - CHECKSUM_LENGTH..];
-
-    let mut checked = d.to_vec();
-    let mut checksum = checksum.to_vec();
-    checked.append(&mut checksum);
-
-    // ref. "utils/formatting encode.CB58"
-    // ref. "base58.Encode"
-    bs58::encode(&checked).into_vec()
+    cb58::encode_vec(d)
 }
 
 /// Implements "formatting.Decode" with "formatting.CB58".
+/// Use "cb58::decode" to match on the specific error.
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting#Decode
 pub fn decode_cb58_with_checksum(d: &str) -> io::Result<Vec<u8>> {
-    let decoded = match bs58::decode(d).into_vec() {
-        Ok(v) => v,
-        Err(e) => {
-            return Err(Error::new(
-                ErrorKind::InvalidInput,
-                format!("failed to decode base58 ({})", e),
-            ));
-        }
-    };
-    let decoded_length = decoded.len();
-
-    // verify checksum
-    let checksum = &decoded[decoded_length - CHECKSUM_LENGTH..];
-    let orig = &decoded[..decoded_length - CHECKSUM_LENGTH];
-
-    // "hashing.Checksum" of "sha256.Sum256"
-    let orig_checksum: Vec<u8> = digest(&SHA256, orig).as_ref().into();
-    let orig_checksum_length = orig_checksum.len();
-    let orig_checksum = &orig_checksum[orig_checksum_length - CHECKSUM_LENGTH..];
-    if !cmp_manager::eq_vectors(checksum, orig_checksum) {
-        return Err(Error::new(
-            ErrorKind::InvalidInput,
-            format!("invalid checksum {:?} != {:?}", checksum, orig_checksum),
-        ));
-    }
-
-    Ok(orig.to_vec())
+    cb58::decode(d).map_err(io::Error::from)
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_encode_c58_with_checksum --exact --show-output
@@ -146,7 +102,7 @@ pub fn decode_hex_with_checksum(d: &[u8]) -> io::Result<Vec<u8>> {
     let orig_checksum: Vec<u8> = digest(&SHA256, orig).as_ref().into();
     let orig_checksum_length = orig_checksum.len();
     let orig_checksum = &orig_checksum[orig_checksum_length - CHECKSUM_LENGTH..];
-    if !cmp_manager::eq_vectors(checksum, orig_checksum) {
+    if checksum != orig_checksum {
         return Err(Error::new(
             ErrorKind::InvalidInput,
             format!("invalid checksum {:?} != {:?}", checksum, orig_checksum),
@@ -210,3 +166,29 @@ pub fn address(chain_id_alias: &str, hrp: &str, d: &[u8]) -> io::Result<String>
     };
     Ok(format!("{}-{}", chain_id_alias, encoded))
 }
+
+/// Decodes the bech32 address without the chain alias prefix
+/// (e.g., "avax1...") into its HRP and the 20-byte payload.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/formatting/address#ParseBech32
+pub fn decode_address(addr: &str) -> io::Result<(String, Vec<u8>)> {
+    let (hrp, data, _) = bech32::decode(addr.trim())
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed bech32::decode '{}'", e)))?;
+    let decoded = bech32::convert_bits(&data, 5, 8, false).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed bech32::convert_bits '{}'", e),
+        )
+    })?;
+    Ok((hrp, decoded))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- formatting::test_decode_address --exact --show-output
+#[test]
+fn test_decode_address() {
+    let d = [7u8; 20];
+    let addr = address("X", "avax", &d).unwrap();
+    let (hrp, decoded) = decode_address(addr.trim_start_matches("X-")).unwrap();
+    assert_eq!(hrp, "avax");
+    assert_eq!(decoded, d.to_vec());
+    assert!(decode_address("avax1invalid").is_err());
+}
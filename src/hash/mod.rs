@@ -1,9 +1,14 @@
-use std::io::{self, Error, ErrorKind};
+use alloc::{format, vec::Vec};
+
+use crate::io::{self, Error, ErrorKind};
 
 use ring::digest::{digest, SHA256};
 use ripemd::{Digest, Ripemd160};
 use sha3::Keccak256;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Converts bytes to the short address bytes (20-byte).
 /// e.g., "hashing.PubkeyBytesToAddress" and "ids.ToShortID"
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/hashing#PubkeyBytesToAddress
@@ -47,6 +52,55 @@ fn test_sha256_ripemd160() {
     assert_eq!(d.len(), 20);
 }
 
+/// Returns the SHA256 digest of each input, hashed on the rayon thread pool
+/// with the "parallel" feature, or one after another without.
+pub fn sha256_batch<B>(bs: &[B]) -> Vec<[u8; 32]>
+where
+    B: AsRef<[u8]> + Sync,
+{
+    #[cfg(feature = "parallel")]
+    let it = bs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let it = bs.iter();
+
+    it.map(|b| {
+        let mut d = [0u8; 32];
+        d.copy_from_slice(digest(&SHA256, b.as_ref()).as_ref());
+        d
+    })
+    .collect()
+}
+
+/// Returns the short address bytes of each input (see "sha256_ripemd160"),
+/// e.g., of the public keys recovered from a block of credentials.
+pub fn sha256_ripemd160_batch<B>(bs: &[B]) -> io::Result<Vec<Vec<u8>>>
+where
+    B: AsRef<[u8]> + Sync,
+{
+    #[cfg(feature = "parallel")]
+    let it = bs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let it = bs.iter();
+
+    it.map(sha256_ripemd160).collect()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- hash::test_batch --exact --show-output
+#[test]
+fn test_batch() {
+    let bs: Vec<Vec<u8>> = (0..100u8).map(|i| alloc::vec![i; i as usize]).collect();
+
+    let ds = sha256_batch(&bs);
+    assert_eq!(ds.len(), bs.len());
+    let ds2 = sha256_ripemd160_batch(&bs).unwrap();
+    for (i, b) in bs.iter().enumerate() {
+        // in the input order
+        assert_eq!(ds[i].as_slice(), digest(&SHA256, b).as_ref());
+        assert_eq!(ds2[i], sha256_ripemd160(b).unwrap());
+    }
+    assert!(sha256_batch::<Vec<u8>>(&[]).is_empty());
+}
+
 pub fn keccak256(b: impl AsRef<[u8]>) -> primitive_types::H256 {
     primitive_types::H256::from_slice(&Keccak256::digest(b.as_ref()))
 }
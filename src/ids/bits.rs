@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 use crate::ids::Id;
 
@@ -320,20 +320,20 @@ fn test_first_difference_vacuous() {
 }
 
 #[derive(
-    std::clone::Clone,
-    std::cmp::Eq,
-    std::cmp::Ord,
-    std::cmp::PartialEq,
-    std::cmp::PartialOrd,
-    std::fmt::Debug,
-    std::hash::Hash,
+    core::clone::Clone,
+    core::cmp::Eq,
+    core::cmp::Ord,
+    core::cmp::PartialEq,
+    core::cmp::PartialOrd,
+    core::fmt::Debug,
+    core::hash::Hash,
 )]
 pub enum Bit {
     Zero,
     One,
 }
 
-impl std::convert::From<usize> for Bit {
+impl core::convert::From<usize> for Bit {
     fn from(v: usize) -> Self {
         assert!(v <= 1);
         match v {
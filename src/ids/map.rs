@@ -0,0 +1,51 @@
+//! Map keyed by the IDs hashed by their first 8 bytes (see "ids::IdHasher"),
+//! for the trusted (e.g., locally computed) IDs on the hot paths;
+//! the IDs received from peers belong in the SipHash-based std collections.
+//!
+//! ```
+//! use avalanche_types::ids;
+//!
+//! let mut heights: ids::map::Map<u64> = ids::map::new(1);
+//! heights.insert(ids::Id::from_slice(&[1; 32]), 10);
+//! assert_eq!(heights.get(&ids::Id::from_slice(&[1; 32])), Some(&10));
+//! ```
+
+use std::collections::HashMap;
+
+use crate::ids::{BuildIdHasher, Id};
+
+pub type Map<V> = HashMap<Id, V, BuildIdHasher>;
+
+/// Returns a new map with initial capacity [size].
+pub fn new<V>(size: usize) -> Map<V> {
+    HashMap::with_capacity_and_hasher(size, BuildIdHasher::default())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- ids::map::test_map --exact --show-output
+#[test]
+fn test_map() {
+    let ids: Vec<Id> = (0..1000_u32).map(|i| Id::sha256(i.to_be_bytes())).collect();
+    let mut m: Map<usize> = Map::default();
+    for (i, id) in ids.iter().enumerate() {
+        assert!(m.insert(*id, i).is_none());
+    }
+    assert_eq!(m.len(), ids.len());
+    for (i, id) in ids.iter().enumerate() {
+        assert_eq!(m[id], i);
+    }
+    *m.entry(ids[0]).or_default() += 5;
+    assert_eq!(m.get(&ids[0]), Some(&5));
+    assert_eq!(m.remove(&ids[2]), Some(2));
+    assert!(!m.contains_key(&ids[2]));
+    assert!(!m.contains_key(&Id::empty()));
+
+    // the IDs that share the first 8 bytes still map to their own values
+    let (mut a, mut b) = ([7_u8; 32], [7_u8; 32]);
+    a[31] = 1;
+    b[31] = 2;
+    let mut m = new(2);
+    m.insert(Id::from_slice(&a), "a");
+    m.insert(Id::from_slice(&b), "b");
+    assert_eq!(m[&Id::from_slice(&a)], "a");
+    assert_eq!(m[&Id::from_slice(&b)], "b");
+}
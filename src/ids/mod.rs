@@ -6,32 +6,42 @@
 //! assert_eq!(format!("{}", ids::Id::default()), "11111111111111111111111111111111LpoYY");
 //! ```
 
+#[cfg(feature = "std")]
 pub mod bag;
 pub mod bits;
+#[cfg(feature = "std")]
+pub mod map;
 pub mod node;
+#[cfg(feature = "std")]
+pub mod set;
 pub mod short;
 
-use std::{
+use alloc::{
+    borrow::Cow,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
     cmp::Ordering,
-    collections::HashSet,
     fmt,
-    hash::{Hash, Hasher},
-    io::{self, Error, ErrorKind},
+    hash::{BuildHasherDefault, Hash, Hasher},
     str::FromStr,
 };
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
-use crate::{formatting, packer};
-use lazy_static::lazy_static;
+use crate::{
+    formatting,
+    io::{self, Error, ErrorKind},
+    packer,
+};
 use ring::digest::{digest, SHA256};
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use zerocopy::{AsBytes, FromBytes, Unaligned};
 
 pub const LEN: usize = 32;
 
-lazy_static! {
-    static ref EMPTY: Vec<u8> = vec![0; LEN];
-}
-
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/ids#ID
 /// ref. https://docs.rs/zerocopy/latest/zerocopy/trait.AsBytes.html#safety
 #[derive(Debug, Clone, Copy, Eq, AsBytes, FromBytes, Unaligned)]
@@ -150,8 +160,8 @@ impl FromStr for Id {
     }
 }
 
-impl From<std::borrow::Cow<'static, str>> for Id {
-    fn from(v: std::borrow::Cow<'static, str>) -> Self {
+impl From<Cow<'static, str>> for Id {
+    fn from(v: Cow<'static, str>) -> Self {
         Id::from_str(v.as_ref()).unwrap()
     }
 }
@@ -337,10 +347,12 @@ fn test_serialize() {
 
 /// Set is a set of Ids.
 /// https://pkg.go.dev/github.com/ava-labs/avalanchego/ids#Set
+#[cfg(feature = "std")]
 pub type Set = HashSet<Id>;
 
 /// Return a new set with initial capacity [size].
 /// More or less than [size] elements can be added to this set.
+#[cfg(feature = "std")]
 pub fn new_set(size: usize) -> Set {
     let set: HashSet<Id> = HashSet::with_capacity(size);
     set
@@ -409,6 +421,33 @@ impl Hash for Id {
     }
 }
 
+/// Hasher for the keys that are already uniformly distributed (e.g., the
+/// SHA256 digest of the IDs), which takes the first 8 bytes as the hash
+/// instead of running SipHash over the whole key.
+/// Unlike the std maps (and the Go maps in avalanchego), the hash is not seeded,
+/// so do not use it for the keys that a peer can choose (e.g., gossiped IDs),
+/// nor for the keys whose bytes are not random (e.g., the integers).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IdHasher(u64);
+
+impl Hasher for IdHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut b = [0_u8; 8];
+        let n = bytes.len().min(8);
+        b[..n].copy_from_slice(&bytes[..n]);
+        self.0 = self.0.rotate_left(8) ^ u64::from_le_bytes(b);
+    }
+
+    /// The length prefix of the key bytes is the same for every key.
+    fn write_usize(&mut self, _: usize) {}
+}
+
+pub type BuildIdHasher = BuildHasherDefault<IdHasher>;
+
 #[derive(Debug, Eq, Clone)]
 pub struct Ids(Vec<Id>);
 
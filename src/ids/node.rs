@@ -1,17 +1,25 @@
-use std::{
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
     cmp::Ordering,
-    collections::HashSet,
     fmt,
     hash::{Hash, Hasher},
-    io::{self, Error, ErrorKind},
     str::FromStr,
 };
+#[cfg(feature = "std")]
+use std::collections::HashSet;
 
-use lazy_static::lazy_static;
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use zerocopy::{AsBytes, FromBytes, Unaligned};
 
-use crate::{formatting, hash, ids::short};
+use crate::{
+    formatting, hash,
+    ids::short,
+    io::{self, Error, ErrorKind},
+};
 
 #[cfg(feature = "cert")]
 use crate::key::cert;
@@ -19,10 +27,6 @@ use crate::key::cert;
 pub const LEN: usize = 20;
 pub const ENCODE_PREFIX: &str = "NodeID-";
 
-lazy_static! {
-    static ref EMPTY: Vec<u8> = vec![0; LEN];
-}
-
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/ids#ShortID
 /// ref. https://docs.rs/zerocopy/latest/zerocopy/trait.AsBytes.html#safety
 #[derive(Debug, Copy, Clone, Eq, AsBytes, FromBytes, Unaligned)]
@@ -264,12 +268,14 @@ fn test_serialize() {
 
 /// Set is a set of NodeIds
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/ids#NewNodeIDSet
+#[cfg(feature = "std")]
 pub type Set = HashSet<Id>;
 
 /// Return a new NodeIdSet with initial capacity [size].
 /// More or less than [size] elements can be added to this set.
 /// Using NewNodeIDSet() rather than ids.NodeIDSet{} is just an optimization that can
 /// be used if you know how many elements will be put in this set.
+#[cfg(feature = "std")]
 pub fn new_set(size: usize) -> Set {
     let set: HashSet<Id> = HashSet::with_capacity(size);
     set
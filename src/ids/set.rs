@@ -0,0 +1,57 @@
+//! Set of IDs hashed by their first 8 bytes (see "ids::IdHasher"),
+//! for the trusted (e.g., locally computed) IDs on the hot paths;
+//! the IDs received from peers belong in "ids::Set", which hashes the whole
+//! ID with SipHash.
+//!
+//! ```
+//! use avalanche_types::ids;
+//!
+//! let mut set = ids::set::new(2);
+//! set.insert(ids::Id::from_slice(&[1; 32]));
+//! assert!(set.contains(&ids::Id::from_slice(&[1; 32])));
+//! ```
+
+use std::collections::HashSet;
+
+use crate::ids::{BuildIdHasher, Id};
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/set#Set
+pub type Set = HashSet<Id, BuildIdHasher>;
+
+/// Returns a new set with initial capacity [size].
+pub fn new(size: usize) -> Set {
+    HashSet::with_capacity_and_hasher(size, BuildIdHasher::default())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- ids::set::test_set --exact --show-output
+#[test]
+fn test_set() {
+    use crate::ids::IdHasher;
+    use std::hash::{Hash, Hasher};
+
+    let ids: Vec<Id> = (0..1000_u32).map(|i| Id::sha256(i.to_be_bytes())).collect();
+    let mut set = new(ids.len());
+    for id in ids.iter() {
+        assert!(set.insert(*id));
+    }
+    assert!(!set.insert(ids[0]));
+    assert_eq!(set.len(), ids.len());
+    assert!(ids.iter().all(|id| set.contains(id)));
+    assert!(!set.contains(&Id::empty()));
+    assert!(set.remove(&ids[1]));
+    assert!(!set.contains(&ids[1]));
+
+    let other: Set = ids[..10].iter().copied().collect();
+    assert_eq!(set.intersection(&other).count(), 9);
+
+    // same hash as the first 8 bytes, up to the length prefix
+    let hash = |id: &Id| {
+        let mut h = IdHasher::default();
+        id.hash(&mut h);
+        h.finish()
+    };
+    let mut first = [0_u8; 8];
+    first.copy_from_slice(&ids[0].as_ref()[..8]);
+    assert_eq!(hash(&ids[0]), u64::from_le_bytes(first));
+    assert_ne!(hash(&ids[0]), hash(&ids[1]));
+}
@@ -1,22 +1,24 @@
-use std::{
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{
     cmp::Ordering,
     fmt,
     hash::{Hash, Hasher},
-    io::{self, Error, ErrorKind},
     str::FromStr,
 };
 
-use crate::{formatting, hash, key::secp256k1};
-use lazy_static::lazy_static;
+use crate::{
+    formatting, hash,
+    io::{self, Error, ErrorKind},
+};
 use serde::{self, Deserialize, Deserializer, Serialize, Serializer};
 use zerocopy::{AsBytes, FromBytes, Unaligned};
 
 pub const LEN: usize = 20;
 
-lazy_static! {
-    static ref EMPTY: Vec<u8> = vec![0; LEN];
-}
-
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/ids#ShortID
 /// ref. https://docs.rs/zerocopy/latest/zerocopy/trait.AsBytes.html#safety
 #[derive(Debug, Clone, Eq, AsBytes, FromBytes, Unaligned)]
@@ -123,8 +125,8 @@ impl<'de> Deserialize<'de> for Id {
         }
 
         let addr = ss[1];
-        let (_, short_bytes) = secp256k1::address::avax_address_to_short_bytes("", addr)
-            .map_err(serde::de::Error::custom)?;
+        let (_, short_bytes) =
+            formatting::decode_address(addr).map_err(serde::de::Error::custom)?;
         Ok(Id::from_slice(&short_bytes))
     }
 }
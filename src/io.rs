@@ -0,0 +1,78 @@
+//! I/O error types for the "core" modules ("ids", "formatting", "hash" and
+//! "packer"), which return "io::Result" with or without std.
+//!
+//! With the "std" feature, these are the "std::io" types. Without it, these
+//! are alloc-only stand-ins with the same constructor and "kind" accessor,
+//! so the same code compiles for hardware wallets and wasm targets.
+
+#[cfg(feature = "std")]
+pub use std::io::{Error, ErrorKind, Result};
+
+#[cfg(not(feature = "std"))]
+pub use self::no_std::{Error, ErrorKind, Result};
+
+#[cfg(not(feature = "std"))]
+mod no_std {
+    use alloc::string::{String, ToString};
+    use core::fmt;
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// Subset of "std::io::ErrorKind" used by the "core" modules.
+    #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+    #[non_exhaustive]
+    pub enum ErrorKind {
+        InvalidInput,
+        InvalidData,
+        UnexpectedEof,
+        Unsupported,
+        Other,
+    }
+
+    impl fmt::Display for ErrorKind {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let s = match self {
+                ErrorKind::InvalidInput => "invalid input parameter",
+                ErrorKind::InvalidData => "invalid data",
+                ErrorKind::UnexpectedEof => "unexpected end of file",
+                ErrorKind::Unsupported => "unsupported",
+                ErrorKind::Other => "other error",
+            };
+            write!(f, "{}", s)
+        }
+    }
+
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    pub struct Error {
+        kind: ErrorKind,
+        message: String,
+    }
+
+    impl Error {
+        pub fn new<M: fmt::Display>(kind: ErrorKind, message: M) -> Self {
+            Self {
+                kind,
+                message: message.to_string(),
+            }
+        }
+
+        pub fn kind(&self) -> ErrorKind {
+            self.kind
+        }
+    }
+
+    impl From<ErrorKind> for Error {
+        fn from(kind: ErrorKind) -> Self {
+            Self {
+                kind,
+                message: kind.to_string(),
+            }
+        }
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.message)
+        }
+    }
+}
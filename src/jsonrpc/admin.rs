@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/admin#adminloadvms
+pub type LoadVmsResponse = super::Response<LoadVmsResult>;
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/admin#adminloadvms
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/api/admin#LoadVMsReply
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct LoadVmsResult {
+    /// Newly loaded VM Ids and their aliases.
+    #[serde(rename = "newVMs", default)]
+    pub new_vms: HashMap<String, Vec<String>>,
+    /// VM Ids that failed to load, with the error.
+    #[serde(rename = "failedVMs", default, skip_serializing_if = "Option::is_none")]
+    pub failed_vms: Option<HashMap<String, String>>,
+}
+
+impl Default for LoadVmsResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl LoadVmsResult {
+    pub fn default() -> Self {
+        Self {
+            new_vms: HashMap::new(),
+            failed_vms: None,
+        }
+    }
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/admin#admingetloggerlevel
+pub type GetLoggerLevelResponse = super::Response<GetLoggerLevelResult>;
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/admin#admingetloggerlevel
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/api/admin#LoggerLevelReply
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct GetLoggerLevelResult {
+    /// Logger names (e.g., "C", "P", "main") and their levels.
+    #[serde(rename = "loggerLevels", default)]
+    pub logger_levels: HashMap<String, LogAndDisplayLevels>,
+}
+
+/// Levels of the log file and of the standard output (e.g., "INFO", "DEBUG").
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/api/admin#LogAndDisplayLevels
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LogAndDisplayLevels {
+    pub log_level: String,
+    pub display_level: String,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::admin::test_load_vms --exact --show-output
+#[test]
+fn test_load_vms() {
+    let resp: LoadVmsResponse = serde_json::from_str(
+        "
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"newVMs\": {
+            \"tGas3T58KzdjcJ2iKSyiYsWiqYctRXaPTqBCA11BqEkNg8kPc\": [\"foovm\"]
+        },
+        \"failedVMs\": {
+            \"rXJsCSEYXg2TehWxCEEGj6JU2PWKTkd6cBdNLjoe2SpsKD9cy\": \"error message\"
+        }
+    },
+    \"id\": 1
+}
+",
+    )
+    .unwrap();
+    let result = resp.into_result().unwrap();
+    assert_eq!(
+        result.new_vms["tGas3T58KzdjcJ2iKSyiYsWiqYctRXaPTqBCA11BqEkNg8kPc"],
+        vec![String::from("foovm")]
+    );
+    assert_eq!(
+        result.failed_vms.unwrap()["rXJsCSEYXg2TehWxCEEGj6JU2PWKTkd6cBdNLjoe2SpsKD9cy"],
+        "error message"
+    );
+
+    let resp: LoadVmsResponse =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","result":{"newVMs":{}},"id":1}"#).unwrap();
+    assert_eq!(resp.into_result().unwrap(), LoadVmsResult::default());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::admin::test_get_logger_level --exact --show-output
+#[test]
+fn test_get_logger_level() {
+    let resp: GetLoggerLevelResponse = serde_json::from_str(
+        r#"{
+    "jsonrpc": "2.0",
+    "result": {
+        "loggerLevels": {
+            "C": {"logLevel": "DEBUG", "displayLevel": "INFO"},
+            "main": {"logLevel": "INFO", "displayLevel": "INFO"}
+        }
+    },
+    "id": 1
+}"#,
+    )
+    .unwrap();
+    let result = resp.into_result().unwrap();
+    assert_eq!(result.logger_levels.len(), 2);
+    assert_eq!(
+        result.logger_levels["C"],
+        LogAndDisplayLevels {
+            log_level: String::from("DEBUG"),
+            display_level: String::from("INFO"),
+        }
+    );
+}
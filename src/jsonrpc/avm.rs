@@ -1,6 +1,10 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::{choices, codec::serde::hex_0x_utxo::Hex0xUtxo, ids, jsonrpc, txs};
+use crate::{
+    avm, choices,
+    codec::serde::{hex_0x_bytes::Hex0xBytes, hex_0x_utxo::Hex0xUtxo},
+    ids, jsonrpc, key, txs,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
@@ -53,34 +57,7 @@ pub struct IssueTxParams {
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmissuetx
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct IssueTxResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<IssueTxResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<super::ResponseError>,
-}
-
-impl Default for IssueTxResponse {
-    fn default() -> Self {
-        Self::default()
-    }
-}
-
-impl IssueTxResponse {
-    pub fn default() -> Self {
-        Self {
-            jsonrpc: "2.0".to_string(),
-            id: 1,
-            result: None,
-            error: None,
-        }
-    }
-}
+pub type IssueTxResponse = jsonrpc::Response<IssueTxResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmissuetx
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -138,12 +115,13 @@ fn test_issue_tx() {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetTxStatusResponse {
     pub jsonrpc: String,
+    #[serde(default, deserialize_with = "super::deserialize_id")]
     pub id: u32,
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub result: Option<GetTxStatusResult>,
 
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub error: Option<jsonrpc::ResponseError>,
 }
 
@@ -162,6 +140,18 @@ impl GetTxStatusResponse {
             error: None,
         }
     }
+
+    /// Returns the result, or the error if the server returned an error
+    /// object or no result.
+    pub fn into_result(self) -> io::Result<GetTxStatusResult> {
+        jsonrpc::Response {
+            jsonrpc: self.jsonrpc,
+            id: self.id,
+            result: self.result,
+            error: self.error,
+        }
+        .into_result()
+    }
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgettxstatus
@@ -267,17 +257,7 @@ pub struct GetUtxosParams {
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetUtxosResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetUtxosResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<super::ResponseError>,
-}
+pub type GetUtxosResponse = jsonrpc::Response<GetUtxosResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain/#avmgetutxos
 #[serde_as]
@@ -404,17 +384,7 @@ fn test_get_utxos_non_empty() {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/x-chain#avmgetbalance
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetBalanceResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetBalanceResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<jsonrpc::ResponseError>,
-}
+pub type GetBalanceResponse = jsonrpc::Response<GetBalanceResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/x-chain#avmgetbalance
 #[serde_as]
@@ -487,17 +457,7 @@ fn test_get_balance() {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/x-chain/#avmgetassetdescription
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetAssetDescriptionResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetAssetDescriptionResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<jsonrpc::ResponseError>,
-}
+pub type GetAssetDescriptionResponse = jsonrpc::Response<GetAssetDescriptionResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/x-chain/#avmgetassetdescription
 #[serde_as]
@@ -613,3 +573,334 @@ impl IssueStopVertexRequest {
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct IssueStopVertexParams {}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight
+pub type GetHeightResponse = jsonrpc::Response<GetHeightResult>;
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetheight
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct GetHeightResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: u64,
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblock
+/// ref. https://docs.avax.network/apis/avalanchego/apis/x-chain#avmgetblockbyheight
+pub type GetBlockResponse = jsonrpc::Response<GetBlockResult>;
+
+/// "block" is the 0x-prefixed hex string with checksum for "hex" encoding,
+/// and the JSON object for "json" encoding.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockResult {
+    pub block: serde_json::Value,
+    pub encoding: String,
+}
+
+impl GetBlockResult {
+    /// Parses the "hex" encoded block.
+    pub fn parse(&self) -> io::Result<avm::block::Block> {
+        let s = self.block.as_str().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("block in '{}' encoding is not a hex string", self.encoding),
+            )
+        })?;
+        avm::block::Block::unpack(&jsonrpc::issue::decode_tx_hex(s)?)
+    }
+
+    /// Decodes the "json" encoded block with its transactions.
+    pub fn api_block(&self) -> io::Result<ApiBlock> {
+        // deserialize from the reference, since ids::Id borrows the string
+        ApiBlock::deserialize(&self.block).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("failed to decode '{}' block '{}'", self.encoding, e),
+            )
+        })
+    }
+}
+
+/// X-chain block in "json" encoding.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiBlock {
+    #[serde(rename = "parentID")]
+    pub parent_id: ids::Id,
+    pub height: u64,
+    pub time: u64,
+    #[serde(rename = "merkleRoot")]
+    pub merkle_root: ids::Id,
+    #[serde(default)]
+    pub txs: Vec<ApiBlockTx>,
+}
+
+/// Transaction in the "json" encoded block.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiBlockTx {
+    pub id: ids::Id,
+    #[serde(rename = "unsignedTx")]
+    pub unsigned_tx: ApiUnsignedTx,
+    #[serde(default)]
+    pub credentials: Vec<ApiCredential>,
+}
+
+/// Unsigned transaction in the "json" encoded block, told apart by the fields
+/// that each tx type adds to the base tx.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(untagged)]
+pub enum ApiUnsignedTx {
+    Import(ApiImportTx),
+    Export(ApiExportTx),
+    CreateAsset(ApiCreateAssetTx),
+    Operation(ApiOperationTx),
+    /// Matches any tx with the base fields, so must be the last.
+    Base(ApiBaseTx),
+}
+
+impl ApiUnsignedTx {
+    /// Returns the base tx that every tx type embeds.
+    pub fn base_tx(&self) -> &ApiBaseTx {
+        match self {
+            ApiUnsignedTx::Import(tx) => &tx.base_tx,
+            ApiUnsignedTx::Export(tx) => &tx.base_tx,
+            ApiUnsignedTx::CreateAsset(tx) => &tx.base_tx,
+            ApiUnsignedTx::Operation(tx) => &tx.base_tx,
+            ApiUnsignedTx::Base(tx) => tx,
+        }
+    }
+
+    /// Returns the type name as registered in the codec (e.g., "avm.ImportTx").
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ApiUnsignedTx::Import(_) => "avm.ImportTx",
+            ApiUnsignedTx::Export(_) => "avm.ExportTx",
+            ApiUnsignedTx::CreateAsset(_) => "avm.CreateAssetTx",
+            ApiUnsignedTx::Operation(_) => "avm.OperationTx",
+            ApiUnsignedTx::Base(_) => "avm.BaseTx",
+        }
+    }
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#BaseTx
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiBaseTx {
+    #[serde(rename = "networkID")]
+    pub network_id: u32,
+    #[serde(rename = "blockchainID")]
+    pub blockchain_id: ids::Id,
+    #[serde(default)]
+    pub outputs: Vec<txs::transferable::Output>,
+    #[serde(default)]
+    pub inputs: Vec<txs::transferable::Input>,
+    #[serde_as(as = "Hex0xBytes")]
+    #[serde(default)]
+    pub memo: Vec<u8>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#ImportTx
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiImportTx {
+    #[serde(flatten)]
+    pub base_tx: ApiBaseTx,
+    #[serde(rename = "sourceChain")]
+    pub source_chain: ids::Id,
+    #[serde(rename = "importedInputs")]
+    pub imported_inputs: Vec<txs::transferable::Input>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#ExportTx
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiExportTx {
+    #[serde(flatten)]
+    pub base_tx: ApiBaseTx,
+    #[serde(rename = "destinationChain")]
+    pub destination_chain: ids::Id,
+    #[serde(rename = "exportedOutputs")]
+    pub exported_outputs: Vec<txs::transferable::Output>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#CreateAssetTx
+/// The initial states are kept in JSON, as their outputs vary by fx.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiCreateAssetTx {
+    #[serde(flatten)]
+    pub base_tx: ApiBaseTx,
+    pub name: String,
+    pub symbol: String,
+    pub denomination: u8,
+    #[serde(rename = "initialStates")]
+    pub initial_states: Vec<serde_json::Value>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#OperationTx
+/// The operations are kept in JSON, as they vary by fx.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiOperationTx {
+    #[serde(flatten)]
+    pub base_tx: ApiBaseTx,
+    pub operations: Vec<serde_json::Value>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#FxCredential
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct ApiCredential {
+    #[serde(rename = "fxID")]
+    pub fx_id: ids::Id,
+    pub credential: key::secp256k1::txs::Credential,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::avm::test_get_block --exact --show-output
+#[test]
+fn test_get_block() {
+    use std::str::FromStr;
+
+    let resp: GetHeightResponse =
+        serde_json::from_str("{\"jsonrpc\":\"2.0\",\"result\":{\"height\":\"5\"},\"id\":1}")
+            .unwrap();
+    assert_eq!(resp.result.unwrap().height, 5);
+
+    let resp: GetBlockResponse = serde_json::from_str(
+        r#"{
+    "jsonrpc": "2.0",
+    "result": {
+        "block": {
+            "parentID": "TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES",
+            "height": 2,
+            "time": 1673379474,
+            "merkleRoot": "11111111111111111111111111111111LpoYY",
+            "txs": [
+                {
+                    "unsignedTx": {
+                        "networkID": 5,
+                        "blockchainID": "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm",
+                        "outputs": [],
+                        "inputs": [],
+                        "memo": "0x"
+                    },
+                    "credentials": [],
+                    "id": "G3BuH6ytQ2averrLxJJugjWZHTRubzCrUZEXoheG5JMqL5ccY"
+                }
+            ]
+        },
+        "encoding": "json"
+    },
+    "id": 1
+}"#,
+    )
+    .unwrap();
+    let blk = resp.result.unwrap().api_block().unwrap();
+    assert_eq!(blk.height, 2);
+    assert_eq!(blk.time, 1673379474);
+    assert_eq!(blk.txs.len(), 1);
+    assert_eq!(
+        blk.txs[0].id,
+        ids::Id::from_str("G3BuH6ytQ2averrLxJJugjWZHTRubzCrUZEXoheG5JMqL5ccY").unwrap()
+    );
+    let base_tx = blk.txs[0].unsigned_tx.base_tx();
+    assert_eq!(blk.txs[0].unsigned_tx.type_name(), "avm.BaseTx");
+    assert_eq!(base_tx.network_id, 5);
+    assert!(base_tx.memo.is_empty());
+
+    let blk: ApiBlock = serde_json::from_str(
+        r#"{
+    "parentID": "TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES",
+    "height": 3,
+    "time": 1673379475,
+    "merkleRoot": "11111111111111111111111111111111LpoYY",
+    "txs": [
+        {
+            "unsignedTx": {
+                "networkID": 5,
+                "blockchainID": "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm",
+                "outputs": [
+                    {
+                        "assetID": "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK",
+                        "fxID": "spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ",
+                        "output": {
+                            "addresses": ["X-custom12szthht8tnl455u4mz3ns3nvvkel8ezvw2n8cx"],
+                            "amount": 999000000,
+                            "locktime": 0,
+                            "threshold": 1
+                        }
+                    }
+                ],
+                "inputs": [],
+                "memo": "0x",
+                "sourceChain": "11111111111111111111111111111111LpoYY",
+                "importedInputs": [
+                    {
+                        "txID": "nN5QsURgEpM8D3e9q8FonS4EE13mnaBDtnQmgSwwUfBZ6FSW1",
+                        "outputIndex": 1,
+                        "assetID": "U8iRqJoiJm8xZHAacmvYyZVwqQx6uDNtQeP3CQ6fcgQk3JqnK",
+                        "fxID": "spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ",
+                        "input": {
+                            "amount": 1000000000,
+                            "signatureIndices": [0]
+                        }
+                    }
+                ]
+            },
+            "credentials": [
+                {
+                    "fxID": "spdxUxVJQbX85MGxMHbKw1sHxMnSqJ3QBzDyDYEP3h6TLuxqQ",
+                    "credential": {
+                        "signatures": [
+                            "0xcb356822dc8990672b5777ec50b57da91baf572240e7d4e9e38f26ec9dbdfd8e376fdc5f30769b842668cd8d81bd71db926dfbe326585137d363566ee500369f01"
+                        ]
+                    }
+                }
+            ],
+            "id": "G3BuH6ytQ2averrLxJJugjWZHTRubzCrUZEXoheG5JMqL5ccY"
+        },
+        {
+            "unsignedTx": {
+                "networkID": 5,
+                "blockchainID": "2JVSBoinj9C2J33VntvzYtVJNZdN2NKiwwKjcumHUWEb5DbBrm",
+                "outputs": [],
+                "inputs": [],
+                "memo": "0x0102",
+                "destinationChain": "11111111111111111111111111111111LpoYY",
+                "exportedOutputs": []
+            },
+            "credentials": [],
+            "id": "G3BuH6ytQ2averrLxJJugjWZHTRubzCrUZEXoheG5JMqL5ccY"
+        }
+    ]
+}"#,
+    )
+    .unwrap();
+    match &blk.txs[0].unsigned_tx {
+        ApiUnsignedTx::Import(tx) => {
+            assert_eq!(tx.source_chain, ids::Id::empty());
+            assert_eq!(tx.imported_inputs.len(), 1);
+            assert_eq!(tx.imported_inputs[0].utxo_id.output_index, 1);
+            assert_eq!(
+                tx.imported_inputs[0]
+                    .transfer_input
+                    .as_ref()
+                    .unwrap()
+                    .amount,
+                1000000000
+            );
+            assert_eq!(
+                tx.base_tx.outputs[0]
+                    .transfer_output
+                    .as_ref()
+                    .unwrap()
+                    .amount,
+                999000000
+            );
+        }
+        tx => panic!("unexpected tx {}", tx.type_name()),
+    }
+    assert_eq!(blk.txs[0].credentials[0].credential.signatures[0].len(), 65);
+    match &blk.txs[1].unsigned_tx {
+        ApiUnsignedTx::Export(tx) => {
+            assert_eq!(tx.destination_chain, ids::Id::empty());
+            assert_eq!(tx.base_tx.memo, vec![1, 2]);
+        }
+        tx => panic!("unexpected tx {}", tx.type_name()),
+    }
+}
@@ -1,18 +1,48 @@
-use crate::codec::serde::{hex_0x_bytes::Hex0xBytes, hex_0x_primitive_types_h256::Hex0xH256};
+use std::io;
+
+use crate::codec::serde::{
+    hex_0x_bytes::Hex0xBytes, hex_0x_primitive_types_h256::Hex0xH256,
+    hex_0x_primitive_types_u256::Hex0xU256,
+};
 use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
-/// Response for "eth_blockNumber".
-/// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_blocknumber
+/// Response for the methods whose result is a hex-encoded quantity
+/// (e.g., "eth_blockNumber"). The result is "None" if the node
+/// returns an error object instead.
+/// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#hex-encoding
+#[serde_as]
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct BlockNumberResponse {
+pub struct QuantityResponse {
     pub jsonrpc: String,
     pub id: u32,
 
-    #[serde(with = "crate::codec::serde::hex_0x_primitive_types_u256")]
-    pub result: primitive_types::U256,
+    #[serde_as(as = "Option<Hex0xU256>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<primitive_types::U256>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<super::ResponseError>,
+}
+
+impl QuantityResponse {
+    /// Returns the result, or the error if the node returned an error
+    /// object or no result.
+    pub fn into_result(self) -> io::Result<primitive_types::U256> {
+        super::Response {
+            jsonrpc: self.jsonrpc,
+            id: self.id,
+            result: self.result,
+            error: self.error,
+        }
+        .into_result()
+    }
 }
 
+/// Response for "eth_blockNumber".
+/// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_blocknumber
+pub type BlockNumberResponse = QuantityResponse;
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_block_number --exact --show-output
 #[test]
 fn test_block_number() {
@@ -31,21 +61,23 @@ fn test_block_number() {
     let expected = BlockNumberResponse {
         jsonrpc: "2.0".to_string(),
         id: 83,
-        result: primitive_types::U256::from_str_radix("0x4b7", 16).unwrap(),
+        result: Some(primitive_types::U256::from_str_radix("0x4b7", 16).unwrap()),
+        error: None,
     };
     assert_eq!(resp, expected);
+
+    let resp: BlockNumberResponse = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","error":{"code":-32000,"message":"unavailable"},"id":83}"#,
+    )
+    .unwrap();
+    assert!(resp.result.is_none());
+    assert_eq!(resp.error.as_ref().unwrap().code, -32000);
+    assert!(resp.into_result().is_err());
 }
 
 /// Response for "eth_chainId".
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_blocknumber
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct ChainIdResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(with = "crate::codec::serde::hex_0x_primitive_types_u256")]
-    pub result: primitive_types::U256,
-}
+pub type ChainIdResponse = QuantityResponse;
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_chain_id --exact --show-output
 #[test]
@@ -65,21 +97,15 @@ fn test_chain_id() {
     let expected = ChainIdResponse {
         jsonrpc: "2.0".to_string(),
         id: 83,
-        result: primitive_types::U256::from_str_radix("0x4b7", 16).unwrap(),
+        result: Some(primitive_types::U256::from_str_radix("0x4b7", 16).unwrap()),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
 
 /// Response for "eth_gasPrice".
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gasprice
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GasPriceResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(with = "crate::codec::serde::hex_0x_primitive_types_u256")]
-    pub result: primitive_types::U256,
-}
+pub type GasPriceResponse = QuantityResponse;
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_gas_price --exact --show-output
 #[test]
@@ -99,7 +125,8 @@ fn test_gas_price() {
     let expected = GasPriceResponse {
         jsonrpc: "2.0".to_string(),
         id: 1,
-        result: primitive_types::U256::from_str_radix("0x1dfd14000", 16).unwrap(),
+        result: Some(primitive_types::U256::from_str_radix("0x1dfd14000", 16).unwrap()),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
@@ -107,14 +134,7 @@ fn test_gas_price() {
 /// Response for "eth_getBalance".
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_getbalance
 /// ref. https://docs.avax.network/build/avalanchego-apis/c-chain#eth_getassetbalance
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetBalanceResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(with = "crate::codec::serde::hex_0x_primitive_types_u256")]
-    pub result: primitive_types::U256,
-}
+pub type GetBalanceResponse = QuantityResponse;
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_balance --exact --show-output
 #[test]
@@ -135,7 +155,8 @@ fn test_get_balance() {
     let expected = GetBalanceResponse {
         jsonrpc: "2.0".to_string(),
         id: 1,
-        result: primitive_types::U256::from_str_radix("0x1388", 16).unwrap(),
+        result: Some(primitive_types::U256::from_str_radix("0x1388", 16).unwrap()),
+        error: None,
     };
     assert_eq!(resp, expected);
 
@@ -154,7 +175,8 @@ fn test_get_balance() {
     let expected = GetBalanceResponse {
         jsonrpc: "2.0".to_string(),
         id: 1,
-        result: primitive_types::U256::from_str_radix("0x0234c8a3397aab58", 16).unwrap(),
+        result: Some(primitive_types::U256::from_str_radix("0x0234c8a3397aab58", 16).unwrap()),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
@@ -162,15 +184,7 @@ fn test_get_balance() {
 /// Response for "eth_getTransactionCount".
 /// Returns the number of transactions send from this address.
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactioncount
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetTransactionCountResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    /// The number of transactions send from this address.
-    #[serde(with = "crate::codec::serde::hex_0x_primitive_types_u256")]
-    pub result: primitive_types::U256,
-}
+pub type GetTransactionCountResponse = QuantityResponse;
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_transaction_count --exact --show-output
 #[test]
@@ -190,7 +204,8 @@ fn test_get_transaction_count() {
     let expected = GetTransactionCountResponse {
         jsonrpc: "2.0".to_string(),
         id: 1,
-        result: primitive_types::U256::from_str_radix("0x1", 16).unwrap(),
+        result: Some(primitive_types::U256::from_str_radix("0x1", 16).unwrap()),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
@@ -198,14 +213,7 @@ fn test_get_transaction_count() {
 /// Response for "eth_getTransactionReceipt".
 /// Returns the receipt of a transaction by transaction hash.
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactionreceipt
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetTransactionReceiptResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetTransactionReceiptResult>,
-}
+pub type GetTransactionReceiptResponse = super::Response<GetTransactionReceiptResult>;
 
 /// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactionreceipt
 #[serde_as]
@@ -292,6 +300,7 @@ fn test_get_transaction_receipt() {
 
             status: primitive_types::U256::from_str_radix("0x1", 16).unwrap(),
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
@@ -340,3 +349,30 @@ fn test_send_raw_transaction() {
     };
     assert_eq!(resp, expected);
 }
+
+/// Response for "warp_getMessageAggregateSignature".
+/// ref. https://github.com/ava-labs/subnet-evm/blob/master/warp/service.go
+pub type GetMessageAggregateSignatureResponse = super::Response<GetMessageAggregateSignatureResult>;
+
+/// Signed warp message bytes, with the aggregate BLS signature of the
+/// source subnet validators.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(transparent)]
+pub struct GetMessageAggregateSignatureResult(#[serde_as(as = "Hex0xBytes")] pub Vec<u8>);
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::evm::test_get_message_aggregate_signature --exact --show-output
+#[test]
+fn test_get_message_aggregate_signature() {
+    let resp: GetMessageAggregateSignatureResponse =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","result":"0x0000abcd","id":1}"#).unwrap();
+    let expected = GetMessageAggregateSignatureResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetMessageAggregateSignatureResult(vec![
+            0x00, 0x00, 0xab, 0xcd,
+        ])),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
@@ -5,12 +5,12 @@ use std::{
 };
 
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 
 /// Represents AvalancheGo health status.
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/api/health#APIHealthReply
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct Response {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub checks: Option<HashMap<String, CheckResult>>,
@@ -20,7 +20,7 @@ pub struct Response {
 /// Represents AvalancheGo health status.
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/api/health#Result
 #[serde_as]
-#[derive(Debug, Deserialize, Eq, PartialEq, Clone)]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CheckResult {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,152 @@
+use std::io::{self, Error, ErrorKind};
+
+use crate::{formatting, ids};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+/// Maximum number of containers that "index.getContainerRange" returns.
+/// ref. "indexer.MaxFetchedByRange"
+pub const MAX_FETCHED_BY_RANGE: u64 = 1024;
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetContainerRangeRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<GetContainerRangeParams>,
+}
+
+impl Default for GetContainerRangeRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetContainerRangeRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize to JSON {}", e),
+            )
+        })
+    }
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/indexer#GetContainerRangeArgs
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetContainerRangeParams {
+    #[serde_as(as = "DisplayFromStr")]
+    pub start_index: u64,
+    /// At most "MAX_FETCHED_BY_RANGE".
+    #[serde_as(as = "DisplayFromStr")]
+    pub num_to_fetch: u64,
+    pub encoding: String,
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/index-api#indexgetcontainerrange
+pub type GetContainerRangeResponse = super::Response<GetContainerRangeResult>;
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/indexer#GetContainerRangeResponse
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+pub struct GetContainerRangeResult {
+    #[serde(default)]
+    pub containers: Vec<Container>,
+}
+
+/// Accepted container (block, vertex or transaction) in the index.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/indexer#FormattedContainer
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Container {
+    pub id: ids::Id,
+    /// Encoded with "encoding" and the checksum (see "decode_bytes").
+    pub bytes: String,
+    #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
+    pub timestamp: DateTime<Utc>,
+    pub encoding: String,
+    /// Position in the order of acceptance, starting at 0.
+    #[serde_as(as = "DisplayFromStr")]
+    pub index: u64,
+}
+
+impl Container {
+    /// Returns the container bytes, where only the "hex" encoding
+    /// is supported ("cb58" is deprecated by the node).
+    pub fn decode_bytes(&self) -> io::Result<Vec<u8>> {
+        if self.encoding != "hex" {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!("unsupported container encoding '{}'", self.encoding),
+            ));
+        }
+        // ref. "utils/formatting.encode" prepends "0x" for "Hex" encoding
+        let d = self.bytes.trim_start_matches("0x");
+        formatting::decode_hex_with_checksum(d.as_bytes())
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::index::test_get_container_range --exact --show-output
+#[test]
+fn test_get_container_range() {
+    let req = GetContainerRangeRequest {
+        method: String::from("index.getContainerRange"),
+        params: Some(GetContainerRangeParams {
+            start_index: 5,
+            num_to_fetch: 10,
+            encoding: String::from("hex"),
+        }),
+        ..GetContainerRangeRequest::default()
+    };
+    assert_eq!(
+        req.encode_json().unwrap(),
+        r#"{"jsonrpc":"2.0","id":1,"method":"index.getContainerRange","params":{"startIndex":"5","numToFetch":"10","encoding":"hex"}}"#
+    );
+
+    let bytes = format!("0x{}", formatting::encode_hex_with_checksum(&[1, 2, 3]));
+    let resp: GetContainerRangeResponse = serde_json::from_str(&format!(
+        r#"{{
+    "jsonrpc": "2.0",
+    "result": {{
+        "containers": [
+            {{
+                "id": "TtF4d2QWbk5vzQGTEPrN48x6vwgAoAmKQ9cbp79inpQmcRKES",
+                "bytes": "{}",
+                "timestamp": "2021-04-02T15:34:00.262979-07:00",
+                "encoding": "hex",
+                "index": "5"
+            }}
+        ]
+    }},
+    "id": 1
+}}"#,
+        bytes
+    ))
+    .unwrap();
+    let containers = resp.into_result().unwrap().containers;
+    assert_eq!(containers.len(), 1);
+    assert_eq!(containers[0].index, 5);
+    assert_eq!(containers[0].decode_bytes().unwrap(), vec![1, 2, 3]);
+
+    let mut c = containers[0].clone();
+    c.bytes = String::from("0x0102030405");
+    assert!(c.decode_bytes().is_err());
+    c.encoding = String::from("cb58");
+    assert_eq!(c.decode_bytes().unwrap_err().kind(), ErrorKind::Unsupported);
+}
@@ -1,18 +1,14 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+};
 
 use crate::ids::{self, node};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkname
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetNetworkNameResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetNetworkNameResult>,
-}
+pub type GetNetworkNameResponse = super::Response<GetNetworkNameResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkname
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -36,14 +32,7 @@ impl GetNetworkNameResult {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkid
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetNetworkIdResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetNetworkIdResult>,
-}
+pub type GetNetworkIdResponse = super::Response<GetNetworkIdResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnetworkid
 #[serde_as]
@@ -91,19 +80,13 @@ fn test_get_network_id() {
         result: Some(GetNetworkIdResult {
             network_id: 9999999_u32,
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetblockchainid
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetBlockchainIdResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetBlockchainIdResult>,
-}
+pub type GetBlockchainIdResponse = super::Response<GetBlockchainIdResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetblockchainid
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -154,19 +137,13 @@ fn test_get_blockchain_id() {
             blockchain_id: ids::Id::from_str("sV6o671RtkGBcno1FiaDbVcFv2sG5aVXMZYzKdP4VQAWmJQnM")
                 .unwrap(),
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnodeid
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetNodeIdResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetNodeIdResult>,
-}
+pub type GetNodeIdResponse = super::Response<GetNodeIdResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnodeid
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -215,19 +192,13 @@ fn test_get_node_id() {
         result: Some(GetNodeIdResult {
             node_id: node::Id::from_str("NodeID-5mb46qkSBj81k9g9e4VFjGGSbaaSLFRzD").unwrap(),
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnodeversion
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetNodeVersionResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetNodeVersionResult>,
-}
+pub type GetNodeVersionResponse = super::Response<GetNodeVersionResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetnodeversion
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -237,6 +208,9 @@ pub struct GetNodeVersionResult {
     pub database_version: String,
     pub git_commit: String,
     pub vm_versions: VmVersions,
+    /// Plugin protocol version of the node, reported since avalanchego v1.9.2.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rpc_protocol_version: Option<String>,
 }
 
 impl Default for GetNodeVersionResult {
@@ -252,6 +226,7 @@ impl GetNodeVersionResult {
             database_version: String::new(),
             git_commit: String::new(),
             vm_versions: VmVersions::default(),
+            rpc_protocol_version: None,
         }
     }
 }
@@ -317,20 +292,24 @@ fn test_get_node_version() {
                 evm: String::from("v0.5.5-rc.1"),
                 platform: String::from("v1.4.10"),
             },
+            rpc_protocol_version: None,
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
+
+    let resp: GetNodeVersionResponse = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","result":{"version":"avalanche/1.9.3","databaseVersion":"v1.4.5","rpcProtocolVersion":"19","gitCommit":"","vmVersions":{"avm":"v1.9.3","evm":"v0.11.2","platform":"v1.9.3"}},"id":1}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        resp.result.unwrap().rpc_protocol_version,
+        Some("19".to_string())
+    );
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetvms
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetVmsResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetVmsResult>,
-}
+pub type GetVmsResponse = super::Response<GetVmsResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogetvms
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -353,14 +332,7 @@ impl GetVmsResult {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infoisbootstrapped
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct IsBootstrappedResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<IsBootstrappedResult>,
-}
+pub type IsBootstrappedResponse = super::Response<IsBootstrappedResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infoisbootstrapped
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -384,14 +356,7 @@ impl IsBootstrappedResult {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogettxfee
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetTxFeeResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetTxFeeResult>,
-}
+pub type GetTxFeeResponse = super::Response<GetTxFeeResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/info/#infogettxfee
 #[serde_as]
@@ -446,19 +411,13 @@ fn test_get_tx_fee() {
             creation_tx_fee: 10000000_u64,
             tx_fee: 1000000_u64,
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/info#infouptime
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
-pub struct UptimeResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<UptimeResult>,
-}
+pub type UptimeResponse = super::Response<UptimeResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/info#infouptime
 #[serde_as]
@@ -513,6 +472,215 @@ fn test_uptime() {
             rewarding_stake_percentage: 100.0000_f64,
             weighted_average_percentage: 99.0000_f64,
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/info#infopeers
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct PeersRequest {
+    pub jsonrpc: String,
+    pub id: u32,
+
+    pub method: String,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<PeersParams>,
+}
+
+impl Default for PeersRequest {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl PeersRequest {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(super::DEFAULT_VERSION),
+            id: super::DEFAULT_ID,
+            method: String::new(),
+            params: None,
+        }
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize to JSON {}", e),
+            )
+        })
+    }
+}
+
+/// Empty "node_ids" returns all the connected peers.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct PeersParams {
+    #[serde(rename = "nodeIDs")]
+    pub node_ids: Vec<node::Id>,
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/info#infopeers
+pub type PeersResponse = super::Response<PeersResult>;
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/info#infopeers
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PeersResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub num_peers: u32,
+    #[serde(default)]
+    pub peers: Vec<Peer>,
+}
+
+impl Default for PeersResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl PeersResult {
+    pub fn default() -> Self {
+        Self {
+            num_peers: 0,
+            peers: Vec::new(),
+        }
+    }
+
+    /// Returns the peer of the node Id, if connected.
+    pub fn get(&self, node_id: &node::Id) -> Option<&Peer> {
+        self.peers.iter().find(|p| p.node_id == *node_id)
+    }
+
+    /// Returns the peers that are benched on any chain.
+    pub fn benched(&self) -> Vec<&Peer> {
+        self.peers
+            .iter()
+            .filter(|p| !p.benched.is_empty())
+            .collect()
+    }
+}
+
+/// Connected peer as seen by the node.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/network/peer#Info
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Peer {
+    pub ip: String,
+    #[serde(rename = "publicIP")]
+    pub public_ip: String,
+    #[serde(rename = "nodeID")]
+    pub node_id: node::Id,
+    pub version: String,
+
+    #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
+    pub last_sent: chrono::DateTime<chrono::Utc>,
+    #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
+    pub last_received: chrono::DateTime<chrono::Utc>,
+
+    /// Chains on which the node benched the peer for failing to respond.
+    #[serde(default)]
+    pub benched: Vec<String>,
+
+    /// Uptime percentage of the node as observed by the peer.
+    #[serde_as(as = "DisplayFromStr")]
+    pub observed_uptime: u32,
+    #[serde_as(as = "HashMap<_, DisplayFromStr>")]
+    #[serde(default)]
+    pub observed_subnet_uptimes: HashMap<ids::Id, u32>,
+    #[serde(default)]
+    pub tracked_subnets: Vec<ids::Id>,
+}
+
+impl Peer {
+    pub fn is_benched(&self, chain_id: &str) -> bool {
+        self.benched.iter().any(|c| c == chain_id)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::info::test_peers --exact --show-output
+#[test]
+fn test_peers() {
+    use std::str::FromStr;
+
+    // ref. https://docs.avax.network/apis/avalanchego/apis/info#infopeers
+    let resp: PeersResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"numPeers\": \"2\",
+        \"peers\": [
+            {
+                \"ip\": \"206.189.137.87:9651\",
+                \"publicIP\": \"206.189.137.87:9651\",
+                \"nodeID\": \"NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg\",
+                \"version\": \"avalanche/1.9.4\",
+                \"lastSent\": \"2020-06-01T15:23:02Z\",
+                \"lastReceived\": \"2020-06-01T15:22:57Z\",
+                \"benched\": [],
+                \"observedUptime\": \"99\",
+                \"observedSubnetUptimes\": {},
+                \"trackedSubnets\": []
+            },
+            {
+                \"ip\": \"158.255.67.151:9651\",
+                \"publicIP\": \"158.255.67.151:9651\",
+                \"nodeID\": \"NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ\",
+                \"version\": \"avalanche/1.9.3\",
+                \"lastSent\": \"2020-06-01T15:23:02Z\",
+                \"lastReceived\": \"2020-06-01T15:22:34Z\",
+                \"benched\": [\"2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5\"],
+                \"observedUptime\": \"75\",
+                \"observedSubnetUptimes\": {
+                    \"29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL\": \"100\"
+                },
+                \"trackedSubnets\": [\"29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL\"]
+            }
+        ]
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+
+    let result = resp.result.unwrap();
+    assert_eq!(result.num_peers, 2);
+    assert_eq!(result.peers.len(), 2);
+
+    let first = node::Id::from_str("NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg").unwrap();
+    let peer = result.get(&first).unwrap();
+    assert_eq!(peer.version, "avalanche/1.9.4");
+    assert_eq!(peer.observed_uptime, 99);
+    assert_eq!(peer.last_received.to_rfc3339(), "2020-06-01T15:22:57+00:00");
+
+    let benched = result.benched();
+    assert_eq!(benched.len(), 1);
+    assert!(benched[0].is_benched("2q9e4r6Mu3U68nU1fYjgbR6JvwrRx36CohpAX5UQxse55x1Q5"));
+    let subnet_id =
+        ids::Id::from_str("29uVeLPJB1eQJkzRemU8g8wZDw5uJRqpab5U2mX9euieVwiEbL").unwrap();
+    assert_eq!(
+        benched[0].observed_subnet_uptimes.get(&subnet_id),
+        Some(&100)
+    );
+    assert_eq!(benched[0].tracked_subnets, vec![subnet_id]);
+
+    let req = PeersRequest {
+        method: String::from("info.peers"),
+        params: Some(PeersParams {
+            node_ids: vec![first],
+        }),
+        ..PeersRequest::default()
+    };
+    assert_eq!(
+        req.encode_json().unwrap(),
+        r#"{"jsonrpc":"2.0","id":1,"method":"info.peers","params":{"nodeIDs":["NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg"]}}"#
+    );
+}
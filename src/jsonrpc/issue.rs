@@ -0,0 +1,74 @@
+//! Builds the issuance payloads for signed transactions, following the
+//! encoding rules of "avm.issueTx" and "platform.issueTx" that take
+//! 0x-prefixed hex with the 4-byte checksum.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{formatting, jsonrpc};
+
+/// Encoding for "issueTx" requests ("cb58" is deprecated in avalanchego).
+pub const ENCODING_HEX: &str = "hex";
+
+/// Encodes the signed transaction bytes in 0x-prefixed hex with checksum.
+pub fn encode_tx_hex(signed_bytes: &[u8]) -> String {
+    format!("0x{}", formatting::encode_hex_with_checksum(signed_bytes))
+}
+
+/// Decodes the 0x-prefixed hex with checksum into the signed transaction bytes.
+pub fn decode_tx_hex(s: &str) -> io::Result<Vec<u8>> {
+    let h = s.trim().trim_start_matches("0x");
+    if h.len() < 8 {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("hex tx '{}' is too short for the checksum", s),
+        ));
+    }
+    formatting::decode_hex_with_checksum(h.as_bytes())
+}
+
+/// Creates the "avm.issueTx" request for the hex-encoded signed transaction
+/// (see "encode_tx_hex"), with or without its "0x" prefix.
+pub fn new_avm_issue_tx(tx_hex: &str) -> jsonrpc::avm::IssueTxRequest {
+    jsonrpc::avm::IssueTxRequest {
+        method: String::from("avm.issueTx"),
+        params: Some(jsonrpc::avm::IssueTxParams {
+            tx: prefix_manager::prepend_0x(tx_hex),
+            encoding: String::from(ENCODING_HEX),
+        }),
+        ..Default::default()
+    }
+}
+
+/// Creates the "platform.issueTx" request for the hex-encoded signed transaction
+/// (see "encode_tx_hex"), with or without its "0x" prefix.
+pub fn new_platformvm_issue_tx(tx_hex: &str) -> jsonrpc::platformvm::IssueTxRequest {
+    jsonrpc::platformvm::IssueTxRequest {
+        method: String::from("platform.issueTx"),
+        params: Some(jsonrpc::platformvm::IssueTxParams {
+            tx: prefix_manager::prepend_0x(tx_hex),
+            encoding: String::from(ENCODING_HEX),
+        }),
+        ..Default::default()
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::issue::test_issue --exact --show-output
+#[test]
+fn test_issue() {
+    let signed_bytes = vec![0x00, 0x00, 0x01, 0x02];
+    let hex_tx = encode_tx_hex(&signed_bytes);
+    assert!(hex_tx.starts_with("0x00000102"));
+    assert_eq!(hex_tx.len(), 2 + (4 + 4) * 2);
+    assert_eq!(decode_tx_hex(&hex_tx).unwrap(), signed_bytes);
+    assert!(decode_tx_hex("0x00").is_err());
+
+    let req = new_avm_issue_tx(&hex_tx);
+    let d = req.encode_json().unwrap();
+    assert!(d.contains("\"method\":\"avm.issueTx\""));
+    assert!(d.contains(&format!("\"tx\":\"{}\"", hex_tx)));
+    assert!(d.contains("\"encoding\":\"hex\""));
+
+    let req = new_platformvm_issue_tx(hex_tx.trim_start_matches("0x"));
+    assert_eq!(req.method, "platform.issueTx");
+    assert_eq!(req.params.unwrap().tx, hex_tx);
+}
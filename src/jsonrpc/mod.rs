@@ -1,15 +1,23 @@
+pub mod admin;
 pub mod avm;
 pub mod evm;
 pub mod health;
+pub mod index;
 pub mod info;
+pub mod issue;
 pub mod platformvm;
 
 use std::{
-    collections::HashMap,
+    collections::{BTreeSet, HashMap},
+    fmt,
     io::{self, Error, ErrorKind},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Mutex,
+    },
 };
 
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 pub const DEFAULT_VERSION: &str = "2.0";
 pub const DEFAULT_ID: u32 = 1;
@@ -147,6 +155,247 @@ pub struct EndIndex {
     pub utxo: String,
 }
 
+/// Response envelope shared by the JSON-RPC methods, where "T" is the
+/// method-specific result type. Exactly one of "result" and "error" is set
+/// by a conforming server.
+/// ref. https://www.jsonrpc.org/specification#response_object
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Response<T> {
+    pub jsonrpc: String,
+    /// Some servers echo the request ID as a string, and the ID is null
+    /// when the server fails to parse the request.
+    #[serde(default, deserialize_with = "deserialize_id")]
+    pub id: u32,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<T>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+}
+
+impl<T> Default for Response<T> {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl<T> Response<T> {
+    pub fn default() -> Self {
+        Self {
+            jsonrpc: String::from(DEFAULT_VERSION),
+            id: DEFAULT_ID,
+            result: None,
+            error: None,
+        }
+    }
+
+    /// Fails if the response ID does not match the request ID,
+    /// unless the server failed to parse the request.
+    pub fn check_id(&self, request_id: u32) -> io::Result<()> {
+        // the server does not know the ID of a request that it failed to parse
+        let unparsed = self.id == 0 && self.error.is_some();
+        if self.id != request_id && !unparsed {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "response ID {} does not match request ID {}",
+                    self.id, request_id
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Returns the result, or the error if the server returned an error
+    /// object or no result.
+    pub fn into_result(self) -> io::Result<T> {
+        if let Some(e) = self.error {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!("JSON-RPC error {} '{}'", e.code, e.message),
+            ));
+        }
+        self.result
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "missing JSON-RPC result"))
+    }
+}
+
+/// Request ID counter shared by the whole process. The API clients are free
+/// functions over an endpoint (e.g., "client::x::issue_tx") rather than client
+/// instances, so there is no per-client scope to keep a counter in, and one
+/// counter also keeps the IDs unique across the clients that share an endpoint
+/// (e.g., the concurrent calls of "client::multi"). The IDs are therefore
+/// only meant to correlate a response with its request, and are not
+/// reproducible across runs.
+static NEXT_ID: AtomicU32 = AtomicU32::new(DEFAULT_ID);
+
+/// Returns the next request ID, unique within the process until it wraps
+/// around. Zero is skipped, since the servers reply "null" (decoded as zero)
+/// when they fail to parse the request.
+pub fn next_id() -> u32 {
+    loop {
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        if id != 0 {
+            return id;
+        }
+    }
+}
+
+/// Method and ID of a request, which the client calls use to check that the
+/// response belongs to the request and to tell the failed calls apart
+/// in bulk operations (e.g., "client::multi").
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Call {
+    pub method: String,
+    pub id: u32,
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (request ID {})", self.method, self.id)
+    }
+}
+
+impl Call {
+    /// Returns the call with the next request ID.
+    pub fn new(method: &str) -> Self {
+        Self {
+            method: method.to_string(),
+            id: next_id(),
+        }
+    }
+
+    /// Returns the request of the call with no params.
+    pub fn request(&self) -> Request {
+        Request {
+            id: self.id,
+            method: self.method.clone(),
+            ..Request::default()
+        }
+    }
+
+    /// Returns the request of the call with the named params.
+    pub fn request_with_params(&self, params: HashMap<String, String>) -> Request {
+        Request {
+            params: Some(params),
+            ..self.request()
+        }
+    }
+
+    /// Returns the request of the call with the positional params
+    /// (e.g., "eth_getBalance").
+    pub fn request_with_params_array(&self, params: Vec<String>) -> RequestWithParamsArray {
+        RequestWithParamsArray {
+            id: self.id,
+            method: self.method.clone(),
+            params: Some(params),
+            ..RequestWithParamsArray::default()
+        }
+    }
+
+    /// Decodes the response (see "decode") and checks its ID,
+    /// with the method and the request ID in the errors.
+    pub fn decode<T: DeserializeOwned>(&self, b: &[u8]) -> io::Result<T> {
+        let envelope: Response<serde::de::IgnoredAny> = serde_json::from_slice(b)
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed {} '{}'", self, e)))?;
+        envelope
+            .check_id(self.id)
+            .map_err(|e| Error::new(e.kind(), format!("failed {}, {}", self, e)))?;
+
+        decode(b).map_err(|e| Error::new(e.kind(), format!("failed {} '{}'", self, e)))
+    }
+}
+
+/// Returns the ID of the encoded request, if any (e.g., for the transports
+/// that answer on behalf of the node, to echo the ID as the node does).
+pub fn request_id(body: &str) -> Option<u32> {
+    #[derive(Deserialize)]
+    struct Envelope {
+        #[serde(default, deserialize_with = "deserialize_id")]
+        id: u32,
+    }
+    serde_json::from_str::<Envelope>(body).ok().map(|e| e.id)
+}
+
+fn deserialize_id<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawId {
+        Num(u32),
+        Str(String),
+    }
+    match Option::<RawId>::deserialize(deserializer)? {
+        None => Ok(0),
+        Some(RawId::Num(id)) => Ok(id),
+        Some(RawId::Str(id)) => id
+            .parse::<u32>()
+            .map_err(|_| serde::de::Error::custom(format!("non-numeric JSON-RPC ID '{}'", id))),
+    }
+}
+
+static STRICT: AtomicBool = AtomicBool::new(cfg!(feature = "jsonrpc_strict"));
+
+/// Sets whether the responses with unknown fields are rejected.
+/// Defaults to "true" with the "jsonrpc_strict" feature.
+pub fn set_strict(strict: bool) {
+    STRICT.store(strict, Ordering::Relaxed);
+}
+
+pub fn is_strict() -> bool {
+    STRICT.load(Ordering::Relaxed)
+}
+
+/// Unknown fields already warned about, per response type.
+static REPORTED: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+/// Returns the unknown fields of the response type that are not yet reported,
+/// and records them as reported.
+fn newly_ignored(type_name: &str, ignored: &[String]) -> Vec<String> {
+    let mut reported = REPORTED.lock().expect("unexpected poisoned lock");
+    ignored
+        .iter()
+        .filter(|path| reported.insert(format!("{}.{}", type_name, path)))
+        .cloned()
+        .collect()
+}
+
+/// Decodes the response, reporting the fields that the type does not read
+/// (e.g., added or renamed by a newer avalanchego). The unknown fields are
+/// logged once per response type and field, or rejected in the strict mode
+/// (see "set_strict").
+pub fn decode<T: DeserializeOwned>(b: &[u8]) -> io::Result<T> {
+    decode_with(b, is_strict())
+}
+
+pub fn decode_with<T: DeserializeOwned>(b: &[u8], strict: bool) -> io::Result<T> {
+    let mut ignored = Vec::new();
+    let mut de = serde_json::Deserializer::from_slice(b);
+    let decoded: T = serde_ignored::deserialize(&mut de, |path| ignored.push(path.to_string()))
+        .and_then(|v| de.end().map(|_| v))
+        .map_err(|e| Error::new(ErrorKind::InvalidData, format!("invalid JSON '{}'", e)))?;
+
+    if !ignored.is_empty() {
+        if strict {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown fields {:?}", ignored),
+            ));
+        }
+        // a newer node adds the same fields to every response of the method
+        let type_name = std::any::type_name::<T>();
+        let new = newly_ignored(type_name, &ignored);
+        if !new.is_empty() {
+            log::warn!("ignored unknown fields {:?} of {}", new, type_name);
+        }
+        log::debug!("ignored unknown fields {:?} of {}", ignored, type_name);
+    }
+    Ok(decoded)
+}
+
 /// e.g., {"jsonrpc":"2.0","error":{"code":-32000,"message":"problem decoding transaction: invalid input checksum","data":null},"id":1}
 /// e.g., {"jsonrpc":"2.0","error":{"code":-32000,"message":"problem decoding transaction: missing 0x prefix to hex encoding","data":null},"id":1}
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -173,3 +422,119 @@ impl ResponseError {
         }
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::test_response --exact --show-output
+#[test]
+fn test_response() {
+    let resp: Response<u64> =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","result":10,"id":"1"}"#).unwrap();
+    assert_eq!(resp.id, 1);
+    assert!(resp.check_id(1).is_ok());
+    assert!(resp.check_id(2).is_err());
+    assert_eq!(resp.into_result().unwrap(), 10);
+
+    let resp: Response<u64> = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","error":{"code":-32600,"message":"invalid request"},"id":null}"#,
+    )
+    .unwrap();
+    assert_eq!(resp.id, 0);
+    assert_eq!(resp.error.as_ref().unwrap().code, -32600);
+    assert!(resp.into_result().is_err());
+
+    let resp: Response<u64> = serde_json::from_str(r#"{"jsonrpc":"2.0","id":1}"#).unwrap();
+    assert_eq!(
+        resp.into_result().unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert!(serde_json::from_str::<Response<u64>>(r#"{"jsonrpc":"2.0","id":"abc"}"#).is_err());
+
+    let resp = Response::<u64>::default();
+    assert_eq!(
+        serde_json::to_string(&resp).unwrap(),
+        r#"{"jsonrpc":"2.0","id":1}"#
+    );
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::test_decode --exact --show-output
+#[test]
+fn test_decode() {
+    let d = br#"{"jsonrpc":"2.0","result":{"txID":"11111111111111111111111111111111LpoYY","newField":1},"id":1}"#;
+    let resp: avm::IssueTxResponse = decode_with(d, false).unwrap();
+    assert_eq!(resp.result.unwrap().tx_id, crate::ids::Id::empty());
+
+    // reported once per response type and field
+    let fields = vec![
+        "result.newField".to_string(),
+        "result.otherField".to_string(),
+    ];
+    assert_eq!(newly_ignored("test_decode::A", &fields), fields);
+    assert!(newly_ignored("test_decode::A", &fields).is_empty());
+    assert_eq!(
+        newly_ignored("test_decode::A", &["result.thirdField".to_string()]),
+        vec!["result.thirdField".to_string()]
+    );
+    assert_eq!(newly_ignored("test_decode::B", &fields), fields);
+
+    let err = decode_with::<avm::IssueTxResponse>(d, true).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err.to_string().contains("newField"));
+
+    let d =
+        br#"{"jsonrpc":"2.0","result":{"txID":"11111111111111111111111111111111LpoYY"},"id":1}"#;
+    assert!(decode_with::<avm::IssueTxResponse>(d, true).is_ok());
+    assert!(decode_with::<avm::IssueTxResponse>(b"{} {}", false).is_err());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::test_call --exact --show-output
+#[test]
+fn test_call() {
+    let call = Call::new("avm.getTxStatus");
+    let next = Call::new("avm.getTxStatus");
+    assert_ne!(call.id, next.id);
+    assert_ne!(call.id, 0);
+
+    let req = call.request();
+    assert_eq!(
+        (req.id, req.method.as_str(), req.params),
+        (call.id, "avm.getTxStatus", None)
+    );
+    let req = call.request_with_params(HashMap::from([("txID".to_string(), "x".to_string())]));
+    assert_eq!(req.id, call.id);
+    assert_eq!(req.params.unwrap()["txID"], "x");
+    let req = call.request_with_params_array(vec!["latest".to_string()]);
+    assert_eq!(
+        (req.id, req.params),
+        (call.id, Some(vec!["latest".to_string()]))
+    );
+
+    let d = format!(
+        r#"{{"jsonrpc":"2.0","result":{{"status":"Accepted"}},"id":{}}}"#,
+        call.id
+    );
+    let resp: avm::GetTxStatusResponse = call.decode(d.as_bytes()).unwrap();
+    assert_eq!(resp.id, call.id);
+
+    let err = next
+        .decode::<avm::GetTxStatusResponse>(d.as_bytes())
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+    assert!(err
+        .to_string()
+        .contains(&format!("avm.getTxStatus (request ID {})", next.id)));
+
+    // unparsed requests are answered with a null ID
+    let d = br#"{"jsonrpc":"2.0","error":{"code":-32700,"message":"parse error"},"id":null}"#;
+    let resp: avm::GetTxStatusResponse = call.decode(d).unwrap();
+    assert_eq!(resp.error.unwrap().code, -32700);
+
+    let err = call.decode::<avm::GetTxStatusResponse>(b"{").unwrap_err();
+    assert_eq!(
+        request_id(&format!(
+            r#"{{"jsonrpc":"2.0","id":{},"method":"m"}}"#,
+            call.id
+        )),
+        Some(call.id)
+    );
+    assert_eq!(request_id("not json"), None);
+    assert!(err.to_string().starts_with(&format!("failed {}", call)));
+}
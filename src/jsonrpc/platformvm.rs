@@ -1,4 +1,7 @@
-use std::io::{self, Error, ErrorKind};
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+};
 
 use crate::{
     codec::serde::hex_0x_utxo::Hex0xUtxo,
@@ -56,34 +59,7 @@ pub struct IssueTxParams {
     pub encoding: String,
 }
 
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct IssueTxResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<IssueTxResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<super::ResponseError>,
-}
-
-impl Default for IssueTxResponse {
-    fn default() -> Self {
-        Self::default()
-    }
-}
-
-impl IssueTxResponse {
-    pub fn default() -> Self {
-        Self {
-            jsonrpc: "2.0".to_string(),
-            id: 1,
-            result: None,
-            error: None,
-        }
-    }
-}
+pub type IssueTxResponse = jsonrpc::Response<IssueTxResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformissuetx
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -138,34 +114,7 @@ fn test_issue_tx() {
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettx
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetTxResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetTxResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<jsonrpc::ResponseError>,
-}
-
-impl Default for GetTxResponse {
-    fn default() -> Self {
-        Self::default()
-    }
-}
-
-impl GetTxResponse {
-    pub fn default() -> Self {
-        Self {
-            jsonrpc: "2.0".to_string(),
-            id: 1,
-            result: None,
-            error: None,
-        }
-    }
-}
+pub type GetTxResponse = jsonrpc::Response<GetTxResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettx
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -261,34 +210,7 @@ fn test_get_tx() {
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettxstatus
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetTxStatusResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetTxStatusResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<jsonrpc::ResponseError>,
-}
-
-impl Default for GetTxStatusResponse {
-    fn default() -> Self {
-        Self::default()
-    }
-}
-
-impl GetTxStatusResponse {
-    pub fn default() -> Self {
-        Self {
-            jsonrpc: "2.0".to_string(),
-            id: 1,
-            result: None,
-            error: None,
-        }
-    }
-}
+pub type GetTxStatusResponse = jsonrpc::Response<GetTxStatusResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain/#platformgettxstatus
 #[serde_as]
@@ -342,17 +264,7 @@ fn test_get_tx_status() {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetheight
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetHeightResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetHeightResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<jsonrpc::ResponseError>,
-}
+pub type GetHeightResponse = jsonrpc::Response<GetHeightResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetheight
 #[serde_as]
@@ -402,6 +314,269 @@ fn test_get_height() {
     assert_eq!(resp, expected);
 }
 
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettimestamp
+pub type GetTimestampResponse = jsonrpc::Response<GetTimestampResult>;
+
+/// Chain time of the P-chain, which is the timestamp of the last accepted
+/// block, so it trails the wall clock when no block is produced.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#GetTimestampReply
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTimestampResult {
+    #[serde_as(as = "crate::codec::serde::rfc_3339::DateTimeUtc")]
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_timestamp --exact --show-output
+#[test]
+fn test_get_timestamp() {
+    let resp: GetTimestampResponse = serde_json::from_str(
+        r#"{"jsonrpc":"2.0","result":{"timestamp":"2021-09-07T00:00:00-04:00"},"id":1}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        resp.into_result().unwrap().timestamp.timestamp(),
+        1630987200
+    );
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake
+pub type GetTotalStakeResponse = jsonrpc::Response<GetTotalStakeResult>;
+
+/// "stake" is deprecated in favor of "weight", and is only
+/// returned for the primary network.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgettotalstake
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetTotalStakeResult {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    pub stake: Option<u64>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub weight: u64,
+}
+
+impl Default for GetTotalStakeResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetTotalStakeResult {
+    pub fn default() -> Self {
+        Self {
+            stake: None,
+            weight: 0,
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_total_stake --exact --show-output
+#[test]
+fn test_get_total_stake() {
+    let resp: GetTotalStakeResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"stake\": \"279825917679866811\",
+        \"weight\": \"279825917679866811\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+
+    let expected = GetTotalStakeResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetTotalStakeResult {
+            stake: Some(279825917679866811),
+            weight: 279825917679866811,
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+
+    let resp: GetTotalStakeResponse =
+        serde_json::from_str(r#"{"jsonrpc":"2.0","result":{"weight":"100"},"id":1}"#).unwrap();
+    assert_eq!(resp.result.unwrap().weight, 100);
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply
+pub type GetCurrentSupplyResponse = jsonrpc::Response<GetCurrentSupplyResult>;
+
+/// Upper bound on the number of tokens that exist, including the tokens
+/// minted as staking rewards.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetcurrentsupply
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetCurrentSupplyResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub supply: u64,
+}
+
+impl Default for GetCurrentSupplyResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetCurrentSupplyResult {
+    pub fn default() -> Self {
+        Self { supply: 0 }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_current_supply --exact --show-output
+#[test]
+fn test_get_current_supply() {
+    let resp: GetCurrentSupplyResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"supply\": \"365865167637779183\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+
+    let expected = GetCurrentSupplyResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetCurrentSupplyResult {
+            supply: 365865167637779183,
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetmaxstakeamount
+pub type GetMaxStakeAmountResponse = jsonrpc::Response<GetMaxStakeAmountResult>;
+
+/// Maximum amount staked on the validator at any point
+/// in the queried time range.
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetmaxstakeamount
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetMaxStakeAmountResult {
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount: u64,
+}
+
+impl Default for GetMaxStakeAmountResult {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl GetMaxStakeAmountResult {
+    pub fn default() -> Self {
+        Self { amount: 0 }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_max_stake_amount --exact --show-output
+#[test]
+fn test_get_max_stake_amount() {
+    let resp: GetMaxStakeAmountResponse = serde_json::from_str(
+        "
+
+{
+    \"jsonrpc\": \"2.0\",
+    \"result\": {
+        \"amount\": \"2000000000000000\"
+    },
+    \"id\": 1
+}
+
+",
+    )
+    .unwrap();
+
+    let expected = GetMaxStakeAmountResponse {
+        jsonrpc: "2.0".to_string(),
+        id: 1,
+        result: Some(GetMaxStakeAmountResult {
+            amount: 2000000000000000,
+        }),
+        error: None,
+    };
+    assert_eq!(resp, expected);
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblock
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetblockbyheight
+pub type GetBlockResponse = jsonrpc::Response<GetBlockResult>;
+
+/// "block" is the 0x-prefixed hex string with checksum for "hex" encoding,
+/// and the JSON object for "json" encoding.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct GetBlockResult {
+    pub block: serde_json::Value,
+    pub encoding: String,
+}
+
+impl GetBlockResult {
+    /// Returns the block bytes of the "hex" encoded result.
+    pub fn block_bytes(&self) -> io::Result<Vec<u8>> {
+        let s = self.block.as_str().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("block in '{}' encoding is not a hex string", self.encoding),
+            )
+        })?;
+        jsonrpc::issue::decode_tx_hex(s)
+    }
+
+    /// Parses the "hex" encoded block.
+    pub fn parse(&self) -> io::Result<platformvm::block::Block> {
+        platformvm::block::Block::unpack(&self.block_bytes()?)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_block --exact --show-output
+#[test]
+fn test_get_block() {
+    use crate::{codec, packer::Packer};
+
+    let packer = Packer::new(platformvm::block::MAX_BLOCK_SIZE, 64);
+    packer.pack_u16(codec::VERSION).unwrap();
+    packer
+        .pack_u32(platformvm::block::Kind::BanffCommit.type_id())
+        .unwrap();
+    packer.pack_u64(1_670_000_000).unwrap();
+    packer.pack_bytes(ids::Id::empty().as_ref()).unwrap();
+    packer.pack_u64(10).unwrap();
+    let b = packer.take_bytes();
+
+    let resp: GetBlockResponse = serde_json::from_str(&format!(
+        "{{\"jsonrpc\":\"2.0\",\"result\":{{\"block\":\"{}\",\"encoding\":\"hex\"}},\"id\":1}}",
+        jsonrpc::issue::encode_tx_hex(&b)
+    ))
+    .unwrap();
+    let blk = resp.result.unwrap().parse().unwrap();
+    assert_eq!(blk.height, 10);
+    assert_eq!(blk.kind, platformvm::block::Kind::BanffCommit);
+
+    let resp: GetBlockResponse = serde_json::from_str(
+        "{\"jsonrpc\":\"2.0\",\"result\":{\"block\":{\"parentID\":\"11111111111111111111111111111111LpoYY\",\"height\":10,\"time\":1670000000},\"encoding\":\"json\"},\"id\":1}",
+    )
+    .unwrap();
+    let result = resp.result.unwrap();
+    assert_eq!(result.block["height"], 10);
+    assert!(result.parse().is_err());
+}
+
 /// ref. https://docs.avax.network/build/avalanchego-apis/issuing-api-calls
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub struct GetUtxosRequest {
@@ -453,17 +628,7 @@ pub struct GetUtxosParams {
 }
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetutxos
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetUtxosResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetUtxosResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<super::ResponseError>,
-}
+pub type GetUtxosResponse = jsonrpc::Response<GetUtxosResult>;
 
 /// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetutxos
 #[serde_as]
@@ -590,17 +755,7 @@ fn test_get_utxos_non_empty() {
 }
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetbalance
-#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
-pub struct GetBalanceResponse {
-    pub jsonrpc: String,
-    pub id: u32,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetBalanceResult>,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub error: Option<jsonrpc::ResponseError>,
-}
+pub type GetBalanceResponse = jsonrpc::Response<GetBalanceResult>;
 
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetbalance
 #[serde_as]
@@ -620,6 +775,27 @@ pub struct GetBalanceResult {
 
     #[serde(rename = "utxoIDs", skip_serializing_if = "Option::is_none")]
     pub utxo_ids: Option<Vec<txs::utxo::Id>>,
+
+    /// Per-asset amounts, returned by avalanchego v1.9.6+.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<HashMap<_, DisplayFromStr>>")]
+    #[serde(default)]
+    pub balances: Option<HashMap<ids::Id, u64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<HashMap<_, DisplayFromStr>>")]
+    #[serde(default)]
+    pub unlockeds: Option<HashMap<ids::Id, u64>>,
+    #[serde(rename = "lockedStakeables", skip_serializing_if = "Option::is_none")]
+    #[serde_as(as = "Option<HashMap<_, DisplayFromStr>>")]
+    #[serde(default)]
+    pub locked_stakeables: Option<HashMap<ids::Id, u64>>,
+    #[serde(
+        rename = "lockedNotStakeables",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde_as(as = "Option<HashMap<_, DisplayFromStr>>")]
+    #[serde(default)]
+    pub locked_not_stakeables: Option<HashMap<ids::Id, u64>>,
 }
 
 impl Default for GetBalanceResult {
@@ -636,6 +812,10 @@ impl GetBalanceResult {
             locked_stakeable: None,
             locked_not_stakeable: None,
             utxo_ids: None,
+            balances: None,
+            unlockeds: None,
+            locked_stakeables: None,
+            locked_not_stakeables: None,
         }
     }
 }
@@ -695,38 +875,106 @@ fn test_get_balance() {
                     ..txs::utxo::Id::default()
                 },
             ]),
+            ..GetBalanceResult::default()
         }),
         error: None,
     };
     assert_eq!(resp, expected);
 }
 
-/// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators
-#[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
-pub struct GetCurrentValidatorsResponse {
-    pub jsonrpc: String,
-    pub id: u32,
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_balance_per_asset --exact --show-output
+#[test]
+fn test_get_balance_per_asset() {
+    use std::str::FromStr;
+
+    let resp: GetBalanceResponse = serde_json::from_str(
+        r#"{
+    "jsonrpc": "2.0",
+    "result": {
+        "balance": "30000000000000000",
+        "unlocked": "20000000000000000",
+        "lockedStakeable": "10000000000000000",
+        "lockedNotStakeable": "0",
+        "balances": {"FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z": "30000000000000000"},
+        "unlockeds": {"FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z": "20000000000000000"},
+        "lockedStakeables": {"FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z": "10000000000000000"},
+        "lockedNotStakeables": {},
+        "utxoIDs": []
+    },
+    "id": 1
+}"#,
+    )
+    .unwrap();
+    let result = resp.into_result().unwrap();
+
+    let avax = ids::Id::from_str("FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z").unwrap();
+    assert_eq!(result.balance, 30000000000000000);
+    assert_eq!(result.unlockeds.unwrap()[&avax], 20000000000000000);
+    assert_eq!(result.locked_stakeables.unwrap()[&avax], 10000000000000000);
+    assert!(result.locked_not_stakeables.unwrap().is_empty());
+}
+
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake
+pub type GetStakeResponse = jsonrpc::Response<GetStakeResult>;
 
+/// ref. https://docs.avax.network/apis/avalanchego/apis/p-chain#platformgetstake
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#GetStakeReply
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStakeResult {
+    /// AVAX staked by the addresses in the current and pending validator sets.
+    #[serde_as(as = "DisplayFromStr")]
+    pub staked: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub result: Option<GetCurrentValidatorsResult>,
+    #[serde_as(as = "Option<HashMap<_, DisplayFromStr>>")]
+    #[serde(default)]
+    pub stakeds: Option<HashMap<ids::Id, u64>>,
+    /// Hex-encoded staked outputs.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub staked_outputs: Option<Vec<String>>,
 }
 
-impl Default for GetCurrentValidatorsResponse {
+impl Default for GetStakeResult {
     fn default() -> Self {
         Self::default()
     }
 }
 
-impl GetCurrentValidatorsResponse {
+impl GetStakeResult {
     pub fn default() -> Self {
         Self {
-            jsonrpc: "2.0".to_string(),
-            id: 1,
-            result: Some(GetCurrentValidatorsResult::default()),
+            staked: 0,
+            stakeds: None,
+            staked_outputs: None,
         }
     }
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- jsonrpc::platformvm::test_get_stake --exact --show-output
+#[test]
+fn test_get_stake() {
+    let resp: GetStakeResponse = serde_json::from_str(
+        r#"{
+    "jsonrpc": "2.0",
+    "result": {
+        "staked": "5000000",
+        "stakeds": {"FvwEAhmxKfeiG8SnEvq42hc6whRyY3EFYAvebMqDNDGCgxN5Z": "5000000"},
+        "stakedOutputs": ["0x000021e67317cbc4be2aeb00677ad6462778a8f52274b9d605df2591b23027a87dff00000007000000000000c3500000000000000000000000010000000120f6e2a3ed8e7a5e5a3f3c3c31ee69a8f12d0b4f7f6e6ef0"],
+        "encoding": "hex"
+    },
+    "id": 1
+}"#,
+    )
+    .unwrap();
+    let result = resp.into_result().unwrap();
+    assert_eq!(result.staked, 5000000);
+    assert_eq!(result.staked_outputs.unwrap().len(), 1);
+}
+
+/// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators
+pub type GetCurrentValidatorsResponse = jsonrpc::Response<GetCurrentValidatorsResult>;
+
 /// ref. https://docs.avax.network/build/avalanchego-apis/p-chain/#platformgetcurrentvalidators
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#ClientPermissionlessValidator
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
@@ -1015,6 +1263,7 @@ fn test_get_current_validators() {
                 },
             ])),
         }),
+        error: None,
     };
     assert_eq!(resp, expected);
 }
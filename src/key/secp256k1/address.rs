@@ -1,6 +1,6 @@
 use std::io::{self, Error, ErrorKind};
 
-use crate::hash;
+use crate::{constants, formatting, hash, ids::short, key::secp256k1::public_key};
 
 /// ref. https://github.com/Ethereum/EIPs/blob/master/EIPS/eip-55.md
 pub fn eth_checksum(addr: &str) -> String {
@@ -66,16 +66,7 @@ pub fn avax_address_to_short_bytes(chain_alias: &str, addr: &str) -> io::Result<
         addr.trim_start_matches(&pfx).to_string()
     };
 
-    let (hrp, data, _) = bech32::decode(&trimmed)
-        .map_err(|e| Error::new(ErrorKind::Other, format!("failed bech32::decode '{}'", e)))?;
-
-    let convert = bech32::convert_bits(&data, 5, 8, false).map_err(|e| {
-        Error::new(
-            ErrorKind::Other,
-            format!("failed bech32::convert_bits '{}'", e),
-        )
-    })?;
-    Ok((hrp, convert))
+    formatting::decode_address(&trimmed)
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::address::test_avax_address_to_short_bytes --exact --show-output
@@ -109,3 +100,289 @@ fn test_avax_address_to_short_bytes() {
     assert_eq!(hrp, "avax");
     assert_eq!(parsed_short_addr, short_addr);
 }
+
+/// Every representation of an address on a network. The EVM address is
+/// the Keccak256 digest of the public key, while the short Id (and the
+/// bech32 addresses) is its SHA256 and RIPEMD160 digest, so one cannot be
+/// derived from the other without the public key.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Addresses {
+    pub network_id: u32,
+    pub public_key: Option<public_key::Key>,
+    pub short_id: Option<short::Id>,
+    /// e.g., "X-avax1..."
+    pub x: Option<String>,
+    /// e.g., "P-avax1..."
+    pub p: Option<String>,
+    /// e.g., "C-avax1...", used for the C-chain atomic transactions.
+    pub c: Option<String>,
+    /// EIP-55 checksummed "0x" address for the C-chain EVM.
+    pub eth: Option<String>,
+}
+
+impl Addresses {
+    fn from_short_id(network_id: u32, hrp: &str, short_id: short::Id) -> io::Result<Self> {
+        let b = short_id.as_ref();
+        let (x, p, c) = (
+            formatting::address("X", hrp, b)?,
+            formatting::address("P", hrp, b)?,
+            formatting::address("C", hrp, b)?,
+        );
+        Ok(Self {
+            network_id,
+            public_key: None,
+            short_id: Some(short_id),
+            x: Some(x),
+            p: Some(p),
+            c: Some(c),
+            eth: None,
+        })
+    }
+
+    fn from_public_key(network_id: u32, hrp: &str, pubkey: public_key::Key) -> io::Result<Self> {
+        let mut addrs = Self::from_short_id(network_id, hrp, pubkey.to_short_id()?)?;
+        addrs.eth = Some(pubkey.eth_address());
+        addrs.public_key = Some(pubkey);
+        Ok(addrs)
+    }
+}
+
+/// Converts any form of address into all the other forms on the network:
+/// the bech32 address with or without the chain alias (e.g., "X-avax1...",
+/// "avax1..."), the "0x" EVM address, the CB58 short Id, or the hex-encoded
+/// compressed or uncompressed public key.
+///
+/// Fails if the bech32 HRP does not belong to the network, the chain alias
+/// is not one of "X", "P", and "C", or the mixed-case EVM address has an
+/// invalid EIP-55 checksum.
+pub fn convert(network_id: u32, addr: &str) -> io::Result<Addresses> {
+    convert_with_hrp(network_id, constants::get_hrp(network_id), addr)
+}
+
+/// Same as "convert" but the bech32 addresses use the HRP given
+/// (e.g., of a private network) instead of the HRP of the network ID.
+pub fn convert_with_hrp(network_id: u32, hrp: &str, addr: &str) -> io::Result<Addresses> {
+    let addr = addr.trim();
+    if addr.is_empty() {
+        return Err(Error::new(ErrorKind::InvalidInput, "empty address"));
+    }
+
+    let hex_part = addr.trim_start_matches(super::private_key::HEX_ENCODE_PREFIX);
+    let is_hex = hex_part.chars().all(|c| c.is_ascii_hexdigit());
+
+    // compressed (33-byte) or uncompressed (65-byte) public key
+    if is_hex && (hex_part.len() == 66 || hex_part.len() == 130) {
+        let b = hex::decode(hex_part).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid public key hex '{}'", e),
+            )
+        })?;
+        let pubkey = public_key::Key::from_sec1_bytes(&b)?;
+        return Addresses::from_public_key(network_id, hrp, pubkey);
+    }
+
+    if addr.starts_with(super::private_key::HEX_ENCODE_PREFIX) {
+        if !is_hex || hex_part.len() != 40 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid EVM address '{}'", addr),
+            ));
+        }
+        let checksummed = eth_checksum(hex_part);
+        let is_mixed_case = hex_part.chars().any(|c| c.is_ascii_uppercase())
+            && hex_part.chars().any(|c| c.is_ascii_lowercase());
+        if is_mixed_case && checksummed != hex_part {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid EIP-55 checksum for '{}'", addr),
+            ));
+        }
+        return Ok(Addresses {
+            network_id,
+            public_key: None,
+            short_id: None,
+            x: None,
+            p: None,
+            c: None,
+            eth: Some(prefix_manager::prepend_0x(&checksummed)),
+        });
+    }
+
+    // bech32 address with an optional chain alias
+    let (alias, bech32_addr) = match addr.split_once('-') {
+        Some((alias, rest)) => (Some(alias), rest),
+        None => (None, addr),
+    };
+    if let Some(alias) = alias {
+        if !matches!(alias, "X" | "P" | "C") {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("unknown chain alias '{}'", alias),
+            ));
+        }
+    }
+    let expected_hrp = hrp.to_lowercase();
+    // the HRP is everything before the last "1" separator, and is checked
+    // before decoding so that an address of another network is reported
+    // as such rather than as a malformed address
+    let addr_hrp = bech32_addr
+        .rsplit_once('1')
+        .map(|(addr_hrp, _)| addr_hrp.to_lowercase());
+    let is_bech32 = alias.is_some()
+        || addr_hrp.as_deref().map_or(false, |addr_hrp| {
+            addr_hrp == expected_hrp
+                || addr_hrp == constants::FALLBACK_HRP
+                || constants::HRP_TO_NETWORK_ID.contains_key(addr_hrp)
+        });
+    if is_bech32 {
+        if let Some(addr_hrp) = addr_hrp {
+            if addr_hrp != expected_hrp {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "HRP '{}' does not match network Id {} (expected '{}')",
+                        addr_hrp, network_id, expected_hrp
+                    ),
+                ));
+            }
+        }
+        let (_, data, variant) = bech32::decode(bech32_addr).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed bech32::decode '{}'", e),
+            )
+        })?;
+        if variant != bech32::Variant::Bech32 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "unexpected bech32m encoding",
+            ));
+        }
+        let b = bech32::convert_bits(&data, 5, 8, false).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("failed bech32::convert_bits '{}'", e),
+            )
+        })?;
+        return Addresses::from_short_id(network_id, hrp, to_short_id(&b)?);
+    }
+
+    // CB58-encoded short Id
+    let b = formatting::decode_cb58_with_checksum(addr).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("unrecognized address '{}' ({})", addr, e),
+        )
+    })?;
+    Addresses::from_short_id(network_id, hrp, to_short_id(&b)?)
+}
+
+fn to_short_id(b: &[u8]) -> io::Result<short::Id> {
+    if b.len() != short::LEN {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "invalid short address length {} (expected {})",
+                b.len(),
+                short::LEN
+            ),
+        ));
+    }
+    Ok(short::Id::from_slice(b))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::address::test_convert --exact --show-output
+#[test]
+fn test_convert() {
+    let pk = crate::key::secp256k1::private_key::Key::generate().unwrap();
+    let pubkey = pk.to_public_key();
+
+    let all = convert(1, &hex::encode(pubkey.to_compressed_bytes())).unwrap();
+    assert_eq!(all.public_key, Some(pubkey));
+    assert_eq!(all.short_id, Some(pubkey.to_short_id().unwrap()));
+    assert_eq!(all.x, Some(pubkey.hrp_address(1, "X").unwrap()));
+    assert_eq!(all.p, Some(pubkey.hrp_address(1, "P").unwrap()));
+    assert_eq!(all.c, Some(pubkey.hrp_address(1, "C").unwrap()));
+    assert_eq!(all.eth, Some(pubkey.eth_address()));
+    let uncompressed = format!("0x{}", hex::encode(pubkey.to_uncompressed_bytes()));
+    assert_eq!(convert(1, &uncompressed).unwrap(), all);
+
+    // every bech32 form and the short Id give the same addresses,
+    // except for the EVM address
+    let from_short_id = convert(1, &all.short_id.unwrap().to_string()).unwrap();
+    assert_eq!(from_short_id.x, all.x);
+    assert_eq!(from_short_id.eth, None);
+    for addr in [
+        all.x.clone().unwrap(),
+        all.p.clone().unwrap(),
+        all.c.clone().unwrap(),
+        all.x.clone().unwrap().trim_start_matches("X-").to_string(),
+    ] {
+        assert_eq!(convert(1, &addr).unwrap(), from_short_id);
+    }
+
+    let eth = all.eth.clone().unwrap();
+    assert_eq!(
+        convert(1, &eth.to_lowercase()).unwrap().eth,
+        Some(eth.clone())
+    );
+
+    // strict validation
+    assert!(convert(5, all.x.as_ref().unwrap()).is_err());
+
+    // an address of another network reports the HRP mismatch,
+    // with or without the chain alias and even with a bad checksum
+    let fuji_x = pubkey.hrp_address(5, "X").unwrap();
+    let fuji_bare = fuji_x.trim_start_matches("X-").to_string();
+    let mut corrupted = fuji_bare.clone();
+    let last = corrupted.pop().unwrap();
+    corrupted.push(if last == 'q' { 'p' } else { 'q' });
+    for addr in [&fuji_x, &fuji_bare, &corrupted] {
+        let err = convert(1, addr).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert!(
+            err.to_string()
+                .contains("HRP 'fuji' does not match network Id 1"),
+            "{}",
+            err
+        );
+    }
+    assert!(convert(1, &all.x.as_ref().unwrap().replacen("X-", "Z-", 1)).is_err());
+    assert!(convert(1, "0x1234").is_err());
+    assert!(convert(1, "").is_err());
+    assert!(convert(1, "not-an-address").is_err());
+    let flipped: String = eth
+        .chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if i >= 2 && c.is_ascii_alphabetic() {
+                if c.is_ascii_uppercase() {
+                    c.to_ascii_lowercase()
+                } else {
+                    c.to_ascii_uppercase()
+                }
+            } else {
+                c
+            }
+        })
+        .collect();
+    if flipped != eth && flipped.chars().any(|c| c.is_ascii_lowercase()) {
+        assert!(convert(1, &flipped).is_err());
+    }
+
+    // private network with its own HRP
+    let x = pubkey.custom_hrp_address("mynet", "X").unwrap();
+    assert!(x.starts_with("X-mynet1"));
+    let custom = convert_with_hrp(9999, "mynet", &x).unwrap();
+    assert_eq!(custom.x, Some(x));
+    assert_eq!(custom.short_id, Some(pubkey.to_short_id().unwrap()));
+    assert_eq!(
+        convert_with_hrp(9999, "mynet", &pubkey.to_short_id().unwrap().to_string())
+            .unwrap()
+            .p,
+        Some(pubkey.custom_hrp_address("mynet", "P").unwrap())
+    );
+    assert!(convert(9999, custom.x.as_ref().unwrap()).is_err());
+    assert!(pubkey.custom_hrp_address("", "X").is_err());
+}
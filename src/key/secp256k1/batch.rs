@@ -0,0 +1,129 @@
+//! Batch verification of the credential signatures.
+//!
+//! ECDSA has no batch verification equation, and the recoverable signature
+//! already commits to its signer, so each signature is verified as the node
+//! verifies a credential: the public key recovered from the digest and the
+//! signature must hash to the expected short address. The batch is spread
+//! over the rayon thread pool with the "parallel" feature.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Fx.VerifyCredentials
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{ids::short, key::secp256k1::signature::Sig};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// One signature to verify.
+#[derive(Debug, Clone, Copy)]
+pub struct Item<'a> {
+    /// 32-byte SHA256 digest of the signed bytes (e.g., the unsigned tx).
+    pub digest: &'a [u8],
+    /// 65-byte recoverable signature.
+    pub sig: &'a [u8],
+    /// Short address that must have signed.
+    pub signer: &'a short::Id,
+}
+
+/// Recovers the short address of the signer of the digest.
+pub fn recover_signer(digest: &[u8], sig: &[u8]) -> io::Result<short::Id> {
+    if digest.len() != ring::digest::SHA256_OUTPUT_LEN {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid digest length {}", digest.len()),
+        ));
+    }
+    let (pubkey, _) = Sig::from_bytes(sig)?.recover_public_key(digest)?;
+    pubkey.to_short_id()
+}
+
+/// Recovers the signer of each pair of digest and signature, in order.
+pub fn recover_signers(pairs: &[(&[u8], &[u8])]) -> Vec<io::Result<short::Id>> {
+    #[cfg(feature = "parallel")]
+    let it = pairs.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let it = pairs.iter();
+
+    it.map(|(digest, sig)| recover_signer(digest, sig))
+        .collect()
+}
+
+/// Returns whether each signature is valid, in order, where malformed
+/// digests or signatures are invalid.
+pub fn verify(items: &[Item]) -> Vec<bool> {
+    #[cfg(feature = "parallel")]
+    let it = items.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let it = items.iter();
+
+    it.map(|item| match recover_signer(item.digest, item.sig) {
+        Ok(signer) => signer == *item.signer,
+        Err(e) => {
+            log::debug!("failed to recover signer '{}'", e);
+            false
+        }
+    })
+    .collect()
+}
+
+/// Fails with the position of the first invalid signature, if any.
+pub fn verify_all(items: &[Item]) -> io::Result<()> {
+    match verify(items).iter().position(|valid| !valid) {
+        Some(pos) => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "invalid signature at {} for signer {}",
+                pos, items[pos].signer
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::batch::test_verify --exact --show-output
+#[test]
+fn test_verify() {
+    use crate::key::secp256k1::ReadOnly;
+    use ring::digest::{digest, SHA256};
+
+    let keys = &crate::key::secp256k1::TEST_KEYS;
+    let mut digests = Vec::new();
+    let mut sigs = Vec::new();
+    let mut signers = Vec::new();
+    for i in 0..64 {
+        let k = &keys[i % keys.len()];
+        let d = digest(&SHA256, &[i as u8; 100]);
+        digests.push(d.as_ref().to_vec());
+        sigs.push(k.sign_digest(d.as_ref()).unwrap().to_bytes().to_vec());
+        signers.push(k.short_address().unwrap());
+    }
+    let mut items: Vec<Item> = (0..digests.len())
+        .map(|i| Item {
+            digest: &digests[i],
+            sig: &sigs[i],
+            signer: &signers[i],
+        })
+        .collect();
+    assert!(verify(&items).iter().all(|v| *v));
+    verify_all(&items).unwrap();
+
+    let pairs: Vec<(&[u8], &[u8])> = items.iter().map(|i| (i.digest, i.sig)).collect();
+    let recovered: Vec<short::Id> = recover_signers(&pairs)
+        .into_iter()
+        .collect::<io::Result<_>>()
+        .unwrap();
+    assert_eq!(recovered, signers);
+
+    // signed by another key
+    items[5].signer = &signers[6];
+    // malformed
+    let short_digest = [0u8; 4];
+    items[9].digest = &short_digest;
+    let valid = verify(&items);
+    assert_eq!(valid.iter().filter(|v| !**v).count(), 2);
+    assert!(!valid[5] && !valid[9]);
+    assert!(verify_all(&items)
+        .unwrap_err()
+        .to_string()
+        .contains("invalid signature at 5"));
+}
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Error, ErrorKind},
+};
 
 use crate::{ids::short, key};
 use serde::{Deserialize, Serialize};
@@ -45,11 +48,14 @@ where
             return None;
         }
 
+        // signature indices are in the ascending order of the owner addresses,
+        // and each address is matched at most once so that no key signs twice
         let mut sig_indices: Vec<u32> = Vec::new();
         let mut keys: Vec<T> = Vec::new();
+        let mut matched: HashSet<&short::Id> = HashSet::new();
         for (pos, addr) in output_owners.addresses.iter().enumerate() {
             let key = self.get(addr);
-            if key.is_none() {
+            if key.is_none() || !matched.insert(addr) {
                 continue;
             }
             sig_indices.push(pos as u32);
@@ -92,3 +98,123 @@ where
         ))
     }
 }
+
+/// Verifies that the keys sign for the output owners at the signature indices,
+/// as the node would verify the credential: the indices must be sorted and
+/// unique, match the threshold, and each key must own the address at its index.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Fx.VerifyCredentials
+pub fn verify_signers<T: key::secp256k1::ReadOnly>(
+    output_owners: &key::secp256k1::txs::OutputOwners,
+    sig_indices: &[u32],
+    keys: &[T],
+) -> io::Result<()> {
+    if sig_indices.len() != keys.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "{} signature indices but {} keys",
+                sig_indices.len(),
+                keys.len()
+            ),
+        ));
+    }
+    if !cmp_manager::is_sorted_and_unique(sig_indices) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("signature indices {:?} not sorted and unique", sig_indices),
+        ));
+    }
+    if sig_indices.len() != output_owners.threshold as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "{} signers but the threshold is {}",
+                sig_indices.len(),
+                output_owners.threshold
+            ),
+        ));
+    }
+
+    let mut signed: HashSet<short::Id> = HashSet::new();
+    for (idx, k) in sig_indices.iter().zip(keys.iter()) {
+        let owner = output_owners.addresses.get(*idx as usize).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "signature index {} out of bounds ({} owners)",
+                    idx,
+                    output_owners.addresses.len()
+                ),
+            )
+        })?;
+        let addr = k.short_address()?;
+        if addr != *owner {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "key {} does not own the address {} at signature index {}",
+                    addr, owner, idx
+                ),
+            ));
+        }
+        if !signed.insert(addr.clone()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("duplicate signer {}", addr),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Sorts the inputs in the canonical order while keeping each input
+/// aligned with its signers, since the credentials must be in
+/// the same order as the inputs.
+pub fn sort_with_signers<I: Ord, T>(ins: &mut Vec<I>, signers: &mut Vec<Vec<T>>) {
+    let mut pairs: Vec<(I, Vec<T>)> = ins.drain(..).zip(signers.drain(..)).collect();
+    pairs.sort_by(|a, b| a.0.cmp(&b.0));
+    for (input, keys) in pairs {
+        ins.push(input);
+        signers.push(keys);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::keychain::test_verify_signers --exact --show-output
+#[test]
+fn test_verify_signers() {
+    use crate::key::secp256k1::{private_key::Key, ReadOnly};
+
+    let k1 = Key::generate().unwrap();
+    let k2 = Key::generate().unwrap();
+    let k3 = Key::generate().unwrap();
+    let (a1, a2, a3) = (
+        k1.short_address().unwrap(),
+        k2.short_address().unwrap(),
+        k3.short_address().unwrap(),
+    );
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 2, &[a1.clone(), a2, a3]);
+
+    let keychain = Keychain::new(vec![k3.clone(), k1.clone()]);
+    let (sig_indices, keys) = keychain.match_threshold(&owners, 0).unwrap();
+    assert_eq!(sig_indices, vec![0, 2]);
+    verify_signers(&owners, &sig_indices, &keys).unwrap();
+
+    // unsorted, wrong key, too few, out of bounds
+    assert!(verify_signers(&owners, &[2, 0], &[k3.clone(), k1.clone()]).is_err());
+    assert!(verify_signers(&owners, &[0, 1], &[k1.clone(), k3.clone()]).is_err());
+    assert!(verify_signers(&owners, &[0], std::slice::from_ref(&k1)).is_err());
+    assert!(verify_signers(&owners, &[0, 5], &[k1.clone(), k3.clone()]).is_err());
+
+    // the same address listed twice is only matched once
+    let dup_owners = key::secp256k1::txs::OutputOwners::new(0, 2, &[a1.clone(), a1]);
+    assert!(Keychain::new(vec![k1.clone()])
+        .match_threshold(&dup_owners, 0)
+        .is_none());
+    assert!(verify_signers(&dup_owners, &[0, 1], &[k1.clone(), k1]).is_err());
+
+    let mut ins = vec![3, 1, 2];
+    let mut signers = vec![vec!["c"], vec!["a"], vec!["b"]];
+    sort_with_signers(&mut ins, &mut signers);
+    assert_eq!(ins, vec![1, 2, 3]);
+    assert_eq!(signers, vec![vec!["a"], vec!["b"], vec!["c"]]);
+}
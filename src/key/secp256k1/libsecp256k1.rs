@@ -198,10 +198,7 @@ impl PublicKey {
     }
 
     pub fn to_avax_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
-        let hrp = match constants::NETWORK_ID_TO_HRP.get(&network_id) {
-            Some(v) => v,
-            None => constants::FALLBACK_HRP,
-        };
+        let hrp = constants::get_hrp(network_id);
         // ref. "pk.PublicKey().Address().Bytes()"
         let short_address_bytes = self.to_short_bytes()?;
 
@@ -1,6 +1,6 @@
 use std::io::{self, Error, ErrorKind};
 
-use bip32::{DerivationPath, Language, Mnemonic, XPrv};
+use bip32::{ChildNumber, DerivationPath, Language, Mnemonic, XPrv};
 use rand_core::OsRng;
 
 /// ref. https://github.com/ava-labs/avax-js-cli-tools/blob/3e3f714e4227aca83dc3978fcb6a4fd698e09065/address_gen.js
@@ -55,6 +55,69 @@ impl crate::key::secp256k1::private_key::Key {
         let pk = child_xprv.private_key().to_bytes();
         Self::from_bytes(&pk)
     }
+
+    /// Derives "count" keys from the mnemonic phrase, at the external chain
+    /// addresses of the AVAX account ("m/44'/9000'/0'/0/{index}") starting
+    /// from the "start" index. The account key is derived once, so this is
+    /// much cheaper than calling "from_mnemonic_phrase" for each index.
+    pub fn derive_batch_from_mnemonic_phrase<S>(
+        phrase: S,
+        start: u32,
+        count: u32,
+    ) -> io::Result<Vec<Self>>
+    where
+        S: AsRef<str>,
+    {
+        let deriv: DerivationPath = AVAX_ACCOUNT_DERIV_PATH.parse().map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to parse derive path ({})", e),
+            )
+        })?;
+        let mnemonic = Mnemonic::new(phrase, Language::English).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to read mnemonic phrase ({})", e),
+            )
+        })?;
+        let seed = mnemonic.to_seed("password");
+
+        let account_xprv = XPrv::derive_from_path(&seed, &deriv).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to derive AVAX account path ({})", e),
+            )
+        })?;
+        let external_xprv = derive_child(&account_xprv, 0)?;
+
+        let end = start.checked_add(count).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("index range {}+{} overflows", start, count),
+            )
+        })?;
+        let mut keys = Vec::with_capacity(count as usize);
+        for index in start..end {
+            let child_xprv = derive_child(&external_xprv, index)?;
+            keys.push(Self::from_bytes(&child_xprv.private_key().to_bytes())?);
+        }
+        Ok(keys)
+    }
+}
+
+fn derive_child(xprv: &XPrv, index: u32) -> io::Result<XPrv> {
+    let child_number = ChildNumber::new(index, false).map_err(|e| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            format!("invalid child index {} ({})", index, e),
+        )
+    })?;
+    xprv.derive_child(child_number).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed to derive child {} ({})", index, e),
+        )
+    })
 }
 
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::mnemonic::test_mnemonic --exact --show-output
@@ -95,3 +158,44 @@ fn test_mnemonic() {
         }
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::mnemonic::test_derive_batch --exact --show-output
+#[test]
+fn test_derive_batch() {
+    let phrase = gen_24();
+
+    let keys =
+        crate::key::secp256k1::private_key::Key::derive_batch_from_mnemonic_phrase(&phrase, 0, 3)
+            .unwrap();
+    assert_eq!(keys.len(), 3);
+    assert_eq!(
+        keys[0],
+        crate::key::secp256k1::private_key::Key::from_mnemonic_phrase(
+            phrase.as_str(),
+            AVAX_ACCOUNT_DERIV_PATH_0
+        )
+        .unwrap()
+    );
+    for (i, k) in keys.iter().enumerate() {
+        let path = format!("{}/0/{}", AVAX_ACCOUNT_DERIV_PATH, i);
+        assert_eq!(
+            *k,
+            crate::key::secp256k1::private_key::Key::from_mnemonic_phrase(phrase.as_str(), &path)
+                .unwrap()
+        );
+    }
+
+    let shifted =
+        crate::key::secp256k1::private_key::Key::derive_batch_from_mnemonic_phrase(&phrase, 1, 2)
+            .unwrap();
+    assert_eq!(shifted, keys[1..].to_vec());
+
+    assert!(
+        crate::key::secp256k1::private_key::Key::derive_batch_from_mnemonic_phrase(
+            &phrase,
+            u32::MAX,
+            2
+        )
+        .is_err()
+    );
+}
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod batch;
 pub mod keychain;
 pub mod kms;
 pub mod private_key;
@@ -12,6 +13,9 @@ pub mod libsecp256k1;
 #[cfg(feature = "mnemonic")]
 pub mod mnemonic;
 
+#[cfg(feature = "vanity")]
+pub mod vanity;
+
 use std::{
     collections::{HashMap, VecDeque},
     fmt,
@@ -51,6 +55,11 @@ pub trait ReadOnly {
     /// "human readable part" (hrp) must be valid output from "constants.GetHRP(networkID)".
     /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants
     fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String>;
+    /// Same as "hrp_address" but with the HRP given (e.g., of a private
+    /// network that does not use "custom"), regardless of the network ID.
+    fn custom_hrp_address(&self, hrp: &str, chain_id_alias: &str) -> io::Result<String> {
+        crate::formatting::address(chain_id_alias, hrp, &self.short_address_bytes()?)
+    }
     fn short_address(&self) -> io::Result<short::Id>;
     fn short_address_bytes(&self) -> io::Result<Vec<u8>>;
     fn eth_address(&self) -> String;
@@ -119,11 +119,12 @@ impl Key {
     }
 
     pub fn hrp_address(&self, network_id: u32, chain_id_alias: &str) -> io::Result<String> {
-        let hrp = match constants::NETWORK_ID_TO_HRP.get(&network_id) {
-            Some(v) => v,
-            None => constants::FALLBACK_HRP,
-        };
+        self.custom_hrp_address(constants::get_hrp(network_id), chain_id_alias)
+    }
 
+    /// Encodes the address with the HRP (e.g., "X-local1...") instead of
+    /// the HRP of the network ID, for the private networks with their own HRP.
+    pub fn custom_hrp_address(&self, hrp: &str, chain_id_alias: &str) -> io::Result<String> {
         // ref. "pk.PublicKey().Address().Bytes()"
         let short_address_bytes = self.to_short_bytes()?;
 
@@ -178,6 +179,10 @@ impl key::secp256k1::ReadOnly for Key {
         self.hrp_address(network_id, chain_id_alias)
     }
 
+    fn custom_hrp_address(&self, hrp: &str, chain_id_alias: &str) -> io::Result<String> {
+        self.custom_hrp_address(hrp, chain_id_alias)
+    }
+
     fn short_address(&self) -> io::Result<short::Id> {
         self.to_short_id()
     }
@@ -1,6 +1,9 @@
 pub mod transfer;
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    io::{self, Error, ErrorKind},
+};
 
 use crate::{
     codec::{self, serde::hex_0x_bytes::Hex0xBytes},
@@ -261,6 +264,43 @@ impl OutputOwners {
         }
     }
 
+    /// Sorts and dedups the addresses, as required for the output owners
+    /// to be valid.
+    #[must_use]
+    pub fn sorted(mut self) -> Self {
+        self.addresses.sort();
+        self.addresses.dedup();
+        self
+    }
+
+    /// Checks that the output is spendable and canonical.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners.Verify
+    pub fn verify(&self) -> io::Result<()> {
+        if self.threshold as usize > self.addresses.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "output is unspendable (threshold {} > {} addresses)",
+                    self.threshold,
+                    self.addresses.len()
+                ),
+            ));
+        }
+        if self.threshold == 0 && !self.addresses.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "output is unoptimized (threshold 0 with addresses)",
+            ));
+        }
+        if !self.addresses.windows(2).all(|w| w[0] < w[1]) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "addresses not sorted and unique",
+            ));
+        }
+        Ok(())
+    }
+
     pub fn type_name() -> String {
         "secp256k1fx.OutputOwners".to_string()
     }
@@ -295,6 +335,30 @@ impl PartialEq for OutputOwners {
     }
 }
 
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::test_output_owners_verify --exact --show-output
+#[test]
+fn test_output_owners_verify() {
+    let a = short::Id::from_slice(&[1, 2, 3]);
+    let b = short::Id::from_slice(&[2, 2, 3]);
+
+    assert!(OutputOwners::new(0, 1, std::slice::from_ref(&a))
+        .verify()
+        .is_ok());
+    assert!(OutputOwners::new(0, 0, &[]).verify().is_ok());
+    assert!(OutputOwners::new(0, 2, std::slice::from_ref(&a))
+        .verify()
+        .is_err());
+    assert!(OutputOwners::new(0, 0, std::slice::from_ref(&a))
+        .verify()
+        .is_err());
+
+    let owners = OutputOwners::new(1700000000, 2, &[b.clone(), a.clone(), b.clone()]);
+    assert!(owners.verify().is_err());
+    let owners = owners.sorted();
+    assert_eq!(owners.addresses, vec![a, b]);
+    assert!(owners.verify().is_ok());
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::txs::test_sort_output_owners --exact --show-output
 #[test]
 fn test_sort_output_owners() {
@@ -0,0 +1,109 @@
+//! Vanity address search over randomly generated keys, spread across
+//! all cores with rayon. Useful to generate recognizable test fixtures.
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{constants, key::secp256k1::private_key::Key};
+use rayon::prelude::*;
+
+/// Characters allowed in the data part of bech32 addresses.
+/// ref. https://github.com/bitcoin/bips/blob/master/bip-0173.mediawiki
+pub const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Searches for a key whose bech32 address (e.g., "X-avax1{prefix}...")
+/// starts with the prefix right after the "1" separator.
+/// Returns None if no match is found within "max_attempts" keys.
+/// Each additional character multiplies the expected attempts by 32.
+pub fn search(
+    network_id: u32,
+    chain_id_alias: &str,
+    prefix: &str,
+    max_attempts: u64,
+) -> io::Result<Option<(Key, String)>> {
+    search_with_hrp(
+        constants::get_hrp(network_id),
+        chain_id_alias,
+        prefix,
+        max_attempts,
+    )
+}
+
+/// Same as "search" but the addresses use the HRP given
+/// (e.g., "X-mynet1{prefix}...") instead of the HRP of the network ID.
+pub fn search_with_hrp(
+    hrp: &str,
+    chain_id_alias: &str,
+    prefix: &str,
+    max_attempts: u64,
+) -> io::Result<Option<(Key, String)>> {
+    validate_prefix(prefix)?;
+
+    (0..max_attempts)
+        .into_par_iter()
+        .map(|_| {
+            let k = Key::generate()?;
+            let addr = k.to_public_key().custom_hrp_address(hrp, chain_id_alias)?;
+            Ok((k, addr))
+        })
+        .find_map_any(|res: io::Result<(Key, String)>| match res {
+            Ok((k, addr)) => {
+                if data_part(&addr).starts_with(prefix) {
+                    Some(Ok((k, addr)))
+                } else {
+                    None
+                }
+            }
+            Err(e) => Some(Err(e)),
+        })
+        .transpose()
+}
+
+/// Checks that the prefix can appear in a bech32 address.
+pub fn validate_prefix(prefix: &str) -> io::Result<()> {
+    if let Some(c) = prefix.chars().find(|c| !BECH32_CHARSET.contains(*c)) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "prefix '{}' has character '{}' not in the bech32 charset '{}'",
+                prefix, c, BECH32_CHARSET
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the part after the "1" separator of "{chain}-{hrp}1{data}".
+fn data_part(addr: &str) -> &str {
+    match addr.rfind('1') {
+        Some(pos) => &addr[pos + 1..],
+        None => addr,
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- key::secp256k1::vanity::test_search --exact --show-output
+#[test]
+fn test_search() {
+    assert!(validate_prefix("qq").is_ok());
+    assert!(validate_prefix("b").is_err());
+    assert!(validate_prefix("Q").is_err());
+    assert!(search(1, "X", "1", 10).is_err());
+
+    assert_eq!(data_part("X-avax1qqq"), "qqq");
+    assert_eq!(data_part("P-custom1q1ab"), "ab");
+
+    let (k, addr) = search(1, "X", "q", 100_000).unwrap().unwrap();
+    assert!(addr.starts_with("X-avax1q"));
+    assert_eq!(k.to_public_key().hrp_address(1, "X").unwrap(), addr);
+
+    let (k, addr) = search_with_hrp("mynet", "P", "q", 100_000)
+        .unwrap()
+        .unwrap();
+    assert!(addr.starts_with("P-mynet1q"));
+    assert_eq!(
+        k.to_public_key().custom_hrp_address("mynet", "P").unwrap(),
+        addr
+    );
+
+    // "zzzzzzzzzz" takes ~2^50 attempts
+    assert!(search(1, "X", "zzzzzzzzzz", 10).unwrap().is_none());
+}
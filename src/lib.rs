@@ -1,22 +1,59 @@
+//! Avalanche primitive types.
+//!
+//! "ids", "formatting", "hash" and "packer" build without std (alloc only)
+//! with "default-features = false, features = ["core"]", so that hardware
+//! wallets and wasm targets can reuse the same serialization. Everything else
+//! requires the "std" feature (enabled by default).
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(any(feature = "std", feature = "core")))]
+compile_error!("either the \"std\" or the \"core\" feature must be enabled");
+
+extern crate alloc;
+
+// "core" modules, no_std with alloc
+pub mod formatting;
+pub mod hash;
+pub mod ids;
+pub mod io;
+pub mod packer;
+
+#[cfg(feature = "std")]
 pub mod avm;
+#[cfg(feature = "std")]
 pub mod choices;
+#[cfg(feature = "std")]
+pub mod clock;
+#[cfg(feature = "std")]
 pub mod codec;
+#[cfg(feature = "std")]
 pub mod constants;
+#[cfg(feature = "std")]
 pub mod errors;
-pub mod formatting;
-pub mod hash;
-pub mod ids;
+#[cfg(feature = "std")]
 pub mod jsonrpc;
+#[cfg(feature = "std")]
 pub mod key;
+#[cfg(feature = "std")]
 pub mod message;
+#[cfg(feature = "std")]
+pub mod network_upgrades;
+#[cfg(feature = "std")]
 pub mod node;
-pub mod packer;
+#[cfg(feature = "std")]
 pub mod platformvm;
+#[cfg(feature = "std")]
 pub mod txs;
+#[cfg(feature = "std")]
 pub mod units;
+#[cfg(feature = "std")]
 pub mod verify;
+#[cfg(feature = "std")]
 pub mod version;
 
+#[cfg(feature = "avalanche_cli")]
+pub mod avalanche_cli;
+
 #[cfg(feature = "avalanchego")]
 pub mod avalanchego;
 
@@ -26,14 +63,26 @@ pub mod coreth;
 #[cfg(feature = "subnet_evm")]
 pub mod subnet_evm;
 
+#[cfg(any(feature = "ffi", feature = "python"))]
+mod bindings;
+
 #[cfg(feature = "client")]
 pub mod client;
 
 #[cfg(feature = "evm")]
 pub mod evm;
 
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
 #[cfg(feature = "proto")]
 pub mod proto;
 
-#[cfg(feature = "subnet")]
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(any(feature = "subnet_vm", feature = "wallet"))]
 pub mod subnet;
+
+#[cfg(feature = "testnet")]
+pub mod testnet;
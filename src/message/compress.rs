@@ -1,4 +1,4 @@
-use std::io::{self, Cursor, Read};
+use std::io::{self, Cursor, Error, ErrorKind, Read};
 
 #[cfg(feature = "message_compress_gzip")]
 use flate2::{
@@ -6,6 +6,101 @@ use flate2::{
     Compression,
 };
 
+/// Default maximum size of an uncompressed message (2 MiB).
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#DefaultMaxMessageSize
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// Defines the compression algorithm.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/compression#Type
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Type {
+    None,
+    Gzip,
+    Zstd,
+}
+
+impl Type {
+    /// Returns the string representation of the compression type.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Type::None => "none",
+            Type::Gzip => "gzip",
+            Type::Zstd => "zstd",
+        }
+    }
+}
+
+/// Compresses the input bytes with the compression type.
+/// Errors if the uncompressed input is larger than "max_size",
+/// as avalanchego would reject it.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/compression#Compressor
+pub fn pack<S>(typ: Type, d: S, max_size: usize) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    let d = d.as_ref();
+    check_size(d.len(), max_size)?;
+    match typ {
+        Type::None => Ok(d.to_vec()),
+
+        #[cfg(feature = "message_compress_gzip")]
+        Type::Gzip => pack_gzip(d),
+
+        #[cfg(feature = "message_compress_zstd")]
+        Type::Zstd => pack_zstd(d, max_size),
+
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("compression type '{}' is not enabled", typ.as_str()),
+        )),
+    }
+}
+
+/// Decompresses the input bytes with the compression type.
+/// Errors if the decompressed output exceeds "max_size",
+/// to guard against decompression bombs.
+pub fn unpack<S>(typ: Type, d: S, max_size: usize) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    let d = d.as_ref();
+    match typ {
+        Type::None => {
+            check_size(d.len(), max_size)?;
+            Ok(d.to_vec())
+        }
+
+        #[cfg(feature = "message_compress_gzip")]
+        Type::Gzip => {
+            let mut gz = GzDecoder::new(Cursor::new(d)).take(max_size as u64 + 1);
+            let mut decoded = Vec::new();
+            gz.read_to_end(&mut decoded)?;
+            check_size(decoded.len(), max_size)?;
+            Ok(decoded)
+        }
+
+        #[cfg(feature = "message_compress_zstd")]
+        Type::Zstd => unpack_zstd(d, max_size),
+
+        #[allow(unreachable_patterns)]
+        _ => Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("compression type '{}' is not enabled", typ.as_str()),
+        )),
+    }
+}
+
+fn check_size(size: usize, max_size: usize) -> io::Result<()> {
+    if size > max_size {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("msg length ({}) > maximum msg length ({})", size, max_size),
+        ));
+    }
+    Ok(())
+}
+
 /// Compress the input bytes.
 #[cfg(feature = "message_compress_gzip")]
 pub fn pack_gzip<S>(d: S) -> io::Result<Vec<u8>>
@@ -31,3 +126,68 @@ where
     gz.read_to_end(&mut decoded)?;
     Ok(decoded)
 }
+
+/// Compresses the input bytes with zstd.
+/// Errors if the input is larger than "max_size".
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/compression#NewZstdCompressor
+#[cfg(feature = "message_compress_zstd")]
+pub fn pack_zstd<S>(d: S, max_size: usize) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    let d = d.as_ref();
+    check_size(d.len(), max_size)?;
+    zstd::stream::encode_all(Cursor::new(d), zstd::DEFAULT_COMPRESSION_LEVEL)
+}
+
+/// Decompresses the zstd input bytes.
+/// Errors if the decompressed output exceeds "max_size".
+#[cfg(feature = "message_compress_zstd")]
+pub fn unpack_zstd<S>(d: S, max_size: usize) -> io::Result<Vec<u8>>
+where
+    S: AsRef<[u8]>,
+{
+    let decoder = zstd::stream::read::Decoder::new(Cursor::new(d.as_ref()))?;
+    let mut decoded = Vec::new();
+    decoder
+        .take(max_size as u64 + 1)
+        .read_to_end(&mut decoded)?;
+    check_size(decoded.len(), max_size)?;
+    Ok(decoded)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- message::compress::test_pack_unpack --exact --show-output
+#[test]
+fn test_pack_unpack() {
+    let d = vec![7u8; 1024];
+
+    let packed = pack(Type::None, &d, DEFAULT_MAX_MESSAGE_SIZE).unwrap();
+    assert_eq!(
+        unpack(Type::None, packed, DEFAULT_MAX_MESSAGE_SIZE).unwrap(),
+        d
+    );
+    assert!(pack(Type::None, &d, 1023).is_err());
+
+    #[cfg(feature = "message_compress_gzip")]
+    {
+        let packed = pack(Type::Gzip, &d, DEFAULT_MAX_MESSAGE_SIZE).unwrap();
+        assert!(packed.len() < d.len());
+        assert_eq!(
+            unpack(Type::Gzip, &packed, DEFAULT_MAX_MESSAGE_SIZE).unwrap(),
+            d
+        );
+        assert!(unpack(Type::Gzip, &packed, 1023).is_err());
+    }
+
+    #[cfg(feature = "message_compress_zstd")]
+    {
+        let packed = pack(Type::Zstd, &d, DEFAULT_MAX_MESSAGE_SIZE).unwrap();
+        assert!(packed.len() < d.len());
+        assert_eq!(
+            unpack(Type::Zstd, &packed, DEFAULT_MAX_MESSAGE_SIZE).unwrap(),
+            d
+        );
+        assert!(unpack(Type::Zstd, &packed, 1023).is_err());
+        assert!(pack_zstd(&d, 1023).is_err());
+    }
+}
@@ -0,0 +1,311 @@
+//! Activation times of the network upgrades on the public networks, so that
+//! the transaction builders and parsers can check what the target network
+//! accepts at a given time (e.g., no "AddValidatorTx" after Durango).
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/upgrade
+//!
+//! The other networks (e.g., local and custom networks) set the activation
+//! times in their genesis or node flags, so they have no schedule here and
+//! nothing is reported active for them unless given a "Schedule".
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::platformvm;
+
+/// Network upgrade, in the activation order.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Upgrade {
+    ApricotPhase1,
+    ApricotPhase2,
+    ApricotPhase3,
+    ApricotPhase4,
+    ApricotPhase5,
+    ApricotPhasePre6,
+    ApricotPhase6,
+    ApricotPhasePost6,
+    Banff,
+    Cortina,
+    Durango,
+    Etna,
+    Fortuna,
+    Granite,
+}
+
+impl Upgrade {
+    pub const ALL: &'static [Upgrade] = &[
+        Upgrade::ApricotPhase1,
+        Upgrade::ApricotPhase2,
+        Upgrade::ApricotPhase3,
+        Upgrade::ApricotPhase4,
+        Upgrade::ApricotPhase5,
+        Upgrade::ApricotPhasePre6,
+        Upgrade::ApricotPhase6,
+        Upgrade::ApricotPhasePost6,
+        Upgrade::Banff,
+        Upgrade::Cortina,
+        Upgrade::Durango,
+        Upgrade::Etna,
+        Upgrade::Fortuna,
+        Upgrade::Granite,
+    ];
+
+    /// Number of the upgrades, the length of "Schedule::times".
+    pub const COUNT: usize = Self::ALL.len();
+
+    /// Returns the position in "Upgrade::ALL" and "Schedule::times".
+    pub fn index(&self) -> usize {
+        match self {
+            Upgrade::ApricotPhase1 => 0,
+            Upgrade::ApricotPhase2 => 1,
+            Upgrade::ApricotPhase3 => 2,
+            Upgrade::ApricotPhase4 => 3,
+            Upgrade::ApricotPhase5 => 4,
+            Upgrade::ApricotPhasePre6 => 5,
+            Upgrade::ApricotPhase6 => 6,
+            Upgrade::ApricotPhasePost6 => 7,
+            Upgrade::Banff => 8,
+            Upgrade::Cortina => 9,
+            Upgrade::Durango => 10,
+            Upgrade::Etna => 11,
+            Upgrade::Fortuna => 12,
+            Upgrade::Granite => 13,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Upgrade::ApricotPhase1 => "apricotPhase1",
+            Upgrade::ApricotPhase2 => "apricotPhase2",
+            Upgrade::ApricotPhase3 => "apricotPhase3",
+            Upgrade::ApricotPhase4 => "apricotPhase4",
+            Upgrade::ApricotPhase5 => "apricotPhase5",
+            Upgrade::ApricotPhasePre6 => "apricotPhasePre6",
+            Upgrade::ApricotPhase6 => "apricotPhase6",
+            Upgrade::ApricotPhasePost6 => "apricotPhasePost6",
+            Upgrade::Banff => "banff",
+            Upgrade::Cortina => "cortina",
+            Upgrade::Durango => "durango",
+            Upgrade::Etna => "etna",
+            Upgrade::Fortuna => "fortuna",
+            Upgrade::Granite => "granite",
+        }
+    }
+}
+
+/// Unix activation time in seconds of each upgrade, in the order of "Upgrade::ALL".
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Schedule {
+    pub times: [u64; Upgrade::COUNT],
+}
+
+/// ref. "avalanchego/upgrade.Mainnet"
+pub const MAINNET: Schedule = Schedule {
+    times: [
+        1617199200, // 2021-03-31 14:00 UTC
+        1620644400, // 2021-05-10 11:00 UTC
+        1629813600, // 2021-08-24 14:00 UTC
+        1632344400, // 2021-09-22 21:00 UTC
+        1638468000, // 2021-12-02 18:00 UTC
+        1662341400, // 2022-09-05 01:30 UTC
+        1662494400, // 2022-09-06 20:00 UTC
+        1662519600, // 2022-09-07 03:00 UTC
+        1666108800, // 2022-10-18 16:00 UTC
+        1682434800, // 2023-04-25 15:00 UTC
+        1709740800, // 2024-03-06 16:00 UTC
+        1734368400, // 2024-12-16 17:00 UTC
+        1744124400, // 2025-04-08 15:00 UTC
+        1763568000, // 2025-11-19 16:00 UTC
+    ],
+};
+
+/// ref. "avalanchego/upgrade.Fuji"
+pub const FUJI: Schedule = Schedule {
+    times: [
+        1616767200, // 2021-03-26 14:00 UTC
+        1620223200, // 2021-05-05 14:00 UTC
+        1629140400, // 2021-08-16 19:00 UTC
+        1631826000, // 2021-09-16 21:00 UTC
+        1637766000, // 2021-11-24 15:00 UTC
+        1662494400, // 2022-09-06 20:00 UTC
+        1662494400, // 2022-09-06 20:00 UTC
+        1662530400, // 2022-09-07 06:00 UTC
+        1664805600, // 2022-10-03 14:00 UTC
+        1680793200, // 2023-04-06 15:00 UTC
+        1707840000, // 2024-02-13 16:00 UTC
+        1732550400, // 2024-11-25 16:00 UTC
+        1741878000, // 2025-03-13 15:00 UTC
+        1761750000, // 2025-10-29 15:00 UTC
+    ],
+};
+
+impl Schedule {
+    /// Returns the schedule with every upgrade active from genesis,
+    /// as the local networks of avalanchego default to.
+    pub fn all_active() -> Self {
+        Self {
+            times: [0; Upgrade::COUNT],
+        }
+    }
+
+    /// Returns the schedule of the public network, if any.
+    pub fn of(network_id: u32) -> Option<Self> {
+        match network_id {
+            1 => Some(MAINNET),
+            5 => Some(FUJI),
+            _ => None,
+        }
+    }
+
+    pub fn activation_time(&self, upgrade: Upgrade) -> u64 {
+        self.times[upgrade.index()]
+    }
+
+    pub fn is_active(&self, upgrade: Upgrade, unix_time: u64) -> bool {
+        unix_time >= self.activation_time(upgrade)
+    }
+
+    /// Returns the latest upgrade active at the time, if any.
+    pub fn latest_active(&self, unix_time: u64) -> Option<Upgrade> {
+        Upgrade::ALL
+            .iter()
+            .rev()
+            .find(|u| self.is_active(**u, unix_time))
+            .copied()
+    }
+
+    /// Fails if the network does not accept the P-chain transaction type
+    /// (e.g., "platformvm.UnsignedAddValidatorTx") at the time.
+    /// ref. "avalanchego/vms/platformvm/txs/executor.ErrAddValidatorTxPostDurango"
+    pub fn check_p_tx_type(&self, type_name: &str, unix_time: u64) -> io::Result<()> {
+        let removed_by = match type_name {
+            "platformvm.UnsignedAddValidatorTx" | "platformvm.UnsignedAddDelegatorTx" => {
+                Upgrade::Durango
+            }
+            "platformvm.UnsignedTransformSubnetTx" => Upgrade::Etna,
+            _ => return Ok(()),
+        };
+        if self.is_active(removed_by, unix_time) {
+            return Err(Error::new(
+                ErrorKind::Unsupported,
+                format!(
+                    "{} is not accepted after {} (activated at {})",
+                    type_name,
+                    removed_by.name(),
+                    self.activation_time(removed_by)
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Fails if the P-chain block kind is not accepted at the time:
+    /// the Banff blocks replace the Apricot blocks at Banff.
+    pub fn check_p_block_kind(
+        &self,
+        kind: platformvm::block::Kind,
+        unix_time: u64,
+    ) -> io::Result<()> {
+        let banff = self.is_active(Upgrade::Banff, unix_time);
+        if kind.is_banff() != banff {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "{:?} block is not accepted {} banff (activated at {})",
+                    kind,
+                    if banff { "after" } else { "before" },
+                    self.activation_time(Upgrade::Banff)
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the activation time of the upgrade on the public network, if any.
+pub fn activation_time(network_id: u32, upgrade: Upgrade) -> Option<u64> {
+    Schedule::of(network_id).map(|s| s.activation_time(upgrade))
+}
+
+/// Returns true if the upgrade is active on the public network at the time,
+/// and false for the networks without a schedule.
+pub fn is_active(network_id: u32, upgrade: Upgrade, unix_time: u64) -> bool {
+    Schedule::of(network_id)
+        .map(|s| s.is_active(upgrade, unix_time))
+        .unwrap_or(false)
+}
+
+/// Returns the latest upgrade active on the public network at the time, if any.
+pub fn latest_active(network_id: u32, unix_time: u64) -> Option<Upgrade> {
+    Schedule::of(network_id).and_then(|s| s.latest_active(unix_time))
+}
+
+/// Fails if the public network does not accept the P-chain transaction type
+/// at the time, and never for the networks without a schedule.
+pub fn check_p_tx_type(network_id: u32, type_name: &str, unix_time: u64) -> io::Result<()> {
+    match Schedule::of(network_id) {
+        Some(s) => s.check_p_tx_type(type_name, unix_time),
+        None => Ok(()),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- network_upgrades::test_network_upgrades --exact --show-output
+#[test]
+fn test_network_upgrades() {
+    use crate::platformvm::block::Kind;
+
+    for s in [MAINNET, FUJI] {
+        for w in s.times.windows(2) {
+            assert!(w[0] <= w[1]);
+        }
+    }
+    assert_eq!(Upgrade::ALL.len(), MAINNET.times.len());
+    for (i, u) in Upgrade::ALL.iter().enumerate() {
+        assert_eq!(u.index(), i);
+    }
+
+    let durango = activation_time(1, Upgrade::Durango).unwrap();
+    assert!(!is_active(1, Upgrade::Durango, durango - 1));
+    assert!(is_active(1, Upgrade::Durango, durango));
+    assert_eq!(latest_active(1, durango), Some(Upgrade::Durango));
+    assert_eq!(latest_active(1, durango - 1), Some(Upgrade::Cortina));
+    assert_eq!(latest_active(1, 0), None);
+    // fuji activates before mainnet
+    assert!(is_active(5, Upgrade::Durango, durango - 1));
+
+    // no schedule for the other networks
+    assert_eq!(activation_time(12345, Upgrade::Banff), None);
+    assert!(!is_active(12345, Upgrade::Banff, u64::MAX));
+    assert_eq!(
+        Schedule::all_active().latest_active(0),
+        Some(Upgrade::Granite)
+    );
+
+    // Etna is no longer the latest on the public networks
+    let fortuna = activation_time(1, Upgrade::Fortuna).unwrap();
+    assert_eq!(latest_active(1, fortuna - 1), Some(Upgrade::Etna));
+    assert_eq!(latest_active(1, fortuna), Some(Upgrade::Fortuna));
+    assert_eq!(latest_active(5, fortuna), Some(Upgrade::Fortuna));
+    let granite = activation_time(1, Upgrade::Granite).unwrap();
+    assert_eq!(latest_active(1, granite), Some(Upgrade::Granite));
+
+    let tx = "platformvm.UnsignedAddValidatorTx";
+    check_p_tx_type(1, tx, durango - 1).unwrap();
+    let err = check_p_tx_type(1, tx, durango).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+    check_p_tx_type(1, "platformvm.UnsignedCreateChainTx", durango).unwrap();
+    check_p_tx_type(12345, tx, durango).unwrap();
+    assert!(Schedule::all_active().check_p_tx_type(tx, 0).is_err());
+
+    let banff = MAINNET.activation_time(Upgrade::Banff);
+    MAINNET
+        .check_p_block_kind(Kind::ApricotStandard, banff - 1)
+        .unwrap();
+    assert!(MAINNET
+        .check_p_block_kind(Kind::ApricotStandard, banff)
+        .is_err());
+    MAINNET
+        .check_p_block_kind(Kind::BanffStandard, banff)
+        .unwrap();
+    assert!(MAINNET
+        .check_p_block_kind(Kind::BanffCommit, banff - 1)
+        .is_err());
+}
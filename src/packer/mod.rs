@@ -1,11 +1,10 @@
+#[cfg(feature = "std")]
 pub mod ip;
 
-use std::{
-    cell::Cell,
-    io::{self, Error, ErrorKind},
-    u16,
-};
+use alloc::{format, string::String, vec::Vec};
+use core::{cell::Cell, u16};
 
+use crate::io::{self, Error, ErrorKind};
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 
 pub const MAX_STR_LEN: u16 = u16::MAX - 1;
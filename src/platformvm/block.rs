@@ -0,0 +1,177 @@
+//! P-chain block parser.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{codec, ids, packer::Packer};
+
+/// Maximum size of a P-chain block.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#DefaultMaxMessageSize
+pub const MAX_BLOCK_SIZE: usize = 2 * 1024 * 1024;
+
+/// Block type with its codec type ID.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#RegisterApricotBlockTypes
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/blocks#RegisterBanffBlockTypes
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Kind {
+    ApricotProposal,
+    ApricotAbort,
+    ApricotCommit,
+    ApricotStandard,
+    ApricotAtomic,
+    BanffProposal,
+    BanffAbort,
+    BanffCommit,
+    BanffStandard,
+}
+
+impl Kind {
+    pub fn type_id(&self) -> u32 {
+        match self {
+            Kind::ApricotProposal => 0,
+            Kind::ApricotAbort => 1,
+            Kind::ApricotCommit => 2,
+            Kind::ApricotStandard => 3,
+            Kind::ApricotAtomic => 4,
+            Kind::BanffProposal => 29,
+            Kind::BanffAbort => 30,
+            Kind::BanffCommit => 31,
+            Kind::BanffStandard => 32,
+        }
+    }
+
+    pub fn from_type_id(type_id: u32) -> io::Result<Self> {
+        match type_id {
+            0 => Ok(Kind::ApricotProposal),
+            1 => Ok(Kind::ApricotAbort),
+            2 => Ok(Kind::ApricotCommit),
+            3 => Ok(Kind::ApricotStandard),
+            4 => Ok(Kind::ApricotAtomic),
+            29 => Ok(Kind::BanffProposal),
+            30 => Ok(Kind::BanffAbort),
+            31 => Ok(Kind::BanffCommit),
+            32 => Ok(Kind::BanffStandard),
+            _ => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown P-chain block type ID {}", type_id),
+            )),
+        }
+    }
+
+    /// Returns true for the blocks that carry the timestamp (post-Banff).
+    pub fn is_banff(&self) -> bool {
+        matches!(
+            self,
+            Kind::BanffProposal | Kind::BanffAbort | Kind::BanffCommit | Kind::BanffStandard
+        )
+    }
+}
+
+/// Parsed P-chain block.
+/// The transactions are kept in their signed bytes, concatenated,
+/// since their boundaries are only known by decoding each tx.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Block {
+    /// SHA256 hash of the block bytes.
+    pub id: ids::Id,
+    /// Codec version that the block is marshaled with.
+    pub codec_version: u16,
+    pub kind: Kind,
+    pub parent_id: ids::Id,
+    pub height: u64,
+    /// Unix timestamp, only set for the Banff blocks.
+    pub timestamp: Option<u64>,
+    pub tx_count: u32,
+    pub txs_bytes: Vec<u8>,
+}
+
+impl Block {
+    /// Parses the block from its codec bytes.
+    pub fn unpack(d: &[u8]) -> io::Result<Self> {
+        let packer = Packer::load_bytes_for_unpack(MAX_BLOCK_SIZE, d);
+
+        let codec_version = packer.unpack_u16()?;
+        codec::REGISTRY.check_version(codec::Vm::P, codec_version)?;
+        let kind = Kind::from_type_id(packer.unpack_u32()?)?;
+
+        // Banff blocks embed the Apricot block after the timestamp
+        let timestamp = if kind.is_banff() {
+            Some(packer.unpack_u64()?)
+        } else {
+            None
+        };
+        let parent_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let height = packer.unpack_u64()?;
+
+        let tx_count = match kind {
+            Kind::ApricotProposal | Kind::ApricotAtomic | Kind::BanffProposal => 1,
+            Kind::ApricotStandard | Kind::BanffStandard => packer.unpack_u32()?,
+            Kind::ApricotAbort | Kind::ApricotCommit | Kind::BanffAbort | Kind::BanffCommit => 0,
+        };
+        let txs_bytes = packer.unpack_bytes(d.len() - packer.get_offset())?;
+        if tx_count == 0 && !txs_bytes.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("{} unexpected trailing bytes", txs_bytes.len()),
+            ));
+        }
+
+        Ok(Self {
+            id: ids::Id::sha256(d),
+            codec_version,
+            kind,
+            parent_id,
+            height,
+            timestamp,
+            tx_count,
+            txs_bytes,
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::block::test_unpack --exact --show-output
+#[test]
+fn test_unpack() {
+    let parent_id = ids::Id::sha256(b"parent");
+
+    // banff standard block with two (fake) txs
+    let packer = Packer::new(MAX_BLOCK_SIZE, 128);
+    packer.pack_u16(codec::VERSION).unwrap();
+    packer.pack_u32(Kind::BanffStandard.type_id()).unwrap();
+    packer.pack_u64(1_670_000_000).unwrap();
+    packer.pack_bytes(parent_id.as_ref()).unwrap();
+    packer.pack_u64(42).unwrap();
+    packer.pack_u32(2).unwrap();
+    packer.pack_bytes(&[0xaa, 0xbb, 0xcc]).unwrap();
+    let b = packer.take_bytes();
+
+    let blk = Block::unpack(&b).unwrap();
+    assert_eq!(blk.id, ids::Id::sha256(&b));
+    assert_eq!(blk.codec_version, codec::VERSION);
+    assert_eq!(blk.kind, Kind::BanffStandard);
+    assert_eq!(blk.timestamp, Some(1_670_000_000));
+    assert_eq!(blk.parent_id, parent_id);
+    assert_eq!(blk.height, 42);
+    assert_eq!(blk.tx_count, 2);
+    assert_eq!(blk.txs_bytes, vec![0xaa, 0xbb, 0xcc]);
+
+    // apricot commit block
+    let packer = Packer::new(MAX_BLOCK_SIZE, 128);
+    packer.pack_u16(codec::VERSION).unwrap();
+    packer.pack_u32(Kind::ApricotCommit.type_id()).unwrap();
+    packer.pack_bytes(parent_id.as_ref()).unwrap();
+    packer.pack_u64(7).unwrap();
+    let b = packer.take_bytes();
+
+    let blk = Block::unpack(&b).unwrap();
+    assert_eq!(blk.kind, Kind::ApricotCommit);
+    assert_eq!(blk.timestamp, None);
+    assert_eq!(blk.height, 7);
+    assert_eq!(blk.tx_count, 0);
+
+    let mut trailing = b.to_vec();
+    trailing.push(0);
+    assert!(Block::unpack(&trailing).is_err());
+    assert!(Block::unpack(&[0, 0, 0, 0, 0, 9]).is_err());
+    assert!(Block::unpack(&[0, 0]).is_err());
+}
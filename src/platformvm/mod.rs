@@ -1,3 +1,5 @@
+pub mod block;
+pub mod staking;
 pub mod txs;
 
 use crate::ids;
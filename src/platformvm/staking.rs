@@ -0,0 +1,293 @@
+//! Staking window helpers for validator start/end times.
+//!
+//! ```
+//! use avalanche_types::platformvm::staking;
+//!
+//! let cfg = staking::Config::for_network(1);
+//! let (start, end) = cfg.window(1_000_000, 60, 30 * 24 * 60 * 60).unwrap();
+//! assert!(cfg.validate_window(1_000_000, start, end).is_ok());
+//! ```
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::units;
+
+/// Maximum clock difference between the issuer and the P-chain
+/// before the chain time is considered out of sync.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs/executor#pkg-variables "SyncBound"
+pub const SYNC_BOUND: u64 = 10;
+
+/// The validation start time must not be further in the future than this (2-week).
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs/executor#pkg-constants "MaxFutureStartTime"
+pub const MAX_FUTURE_START_TIME: u64 = 14 * 24 * 60 * 60;
+
+/// Default margin added to the current time when computing the start time,
+/// to cover the issuance and block production latency.
+pub const DEFAULT_START_BUFFER: u64 = 60;
+
+/// Staking parameters of the primary network.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#StakingConfig
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Config {
+    /// Minimum stake, in nano-AVAX, required to validate the primary network.
+    pub min_validator_stake: u64,
+    /// Maximum stake, in nano-AVAX, allowed to be placed on a single validator.
+    pub max_validator_stake: u64,
+    /// Minimum delegation fee, in the range [0, 1000000], that can be charged.
+    pub min_delegation_fee: u32,
+    /// Minimum staking duration in seconds.
+    pub min_stake_duration: u64,
+    /// Maximum staking duration in seconds.
+    pub max_stake_duration: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Config {
+    /// Returns the staking parameters of "genesis_local.go",
+    /// which avalanchego also uses for all custom networks.
+    pub fn default() -> Self {
+        Self {
+            min_validator_stake: 2 * units::KILO_AVAX,
+            max_validator_stake: 3 * units::MEGA_AVAX,
+            min_delegation_fee: 20000, // 2%
+            min_stake_duration: 24 * 60 * 60,
+            max_stake_duration: 365 * 24 * 60 * 60,
+        }
+    }
+
+    /// Returns the staking parameters for the network Id.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/genesis#GetConfig
+    pub fn for_network(network_id: u32) -> Self {
+        match network_id {
+            // ref. "genesis/genesis_mainnet.go"
+            1 => Self {
+                min_validator_stake: 2 * units::KILO_AVAX,
+                max_validator_stake: 3 * units::MEGA_AVAX,
+                min_delegation_fee: 20000,
+                min_stake_duration: 2 * 7 * 24 * 60 * 60,
+                max_stake_duration: 365 * 24 * 60 * 60,
+            },
+            // ref. "genesis/genesis_fuji.go"
+            5 => Self {
+                min_validator_stake: units::AVAX,
+                max_validator_stake: 3 * units::MEGA_AVAX,
+                min_delegation_fee: 20000,
+                min_stake_duration: 24 * 60 * 60,
+                max_stake_duration: 365 * 24 * 60 * 60,
+            },
+            _ => Self::default(),
+        }
+    }
+
+    /// Returns the earliest start time (unix seconds) that the P-chain
+    /// accepts for the current time, with the clock skew margin.
+    pub fn earliest_start(&self, now_unix: u64) -> u64 {
+        now_unix + SYNC_BOUND
+    }
+
+    /// Computes the validation window (start, end) in unix seconds,
+    /// starting "start_buffer" seconds after the current time and lasting
+    /// "duration" seconds. The buffer is raised to the clock skew margin
+    /// if smaller, so the returned window is never "too soon".
+    pub fn window(
+        &self,
+        now_unix: u64,
+        start_buffer: u64,
+        duration: u64,
+    ) -> io::Result<(u64, u64)> {
+        let start = now_unix + start_buffer.max(SYNC_BOUND);
+        let end = start.checked_add(duration).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("stake duration {} overflows start time {}", duration, start),
+            )
+        })?;
+        self.validate_window(now_unix, start, end)?;
+        Ok((start, end))
+    }
+
+    /// Computes the longest validation window allowed for the network,
+    /// starting "start_buffer" seconds after the current time.
+    pub fn max_window(&self, now_unix: u64, start_buffer: u64) -> io::Result<(u64, u64)> {
+        self.window(now_unix, start_buffer, self.max_stake_duration)
+    }
+
+    /// Validates the validation start and end times against the current time.
+    /// ref. "avalanchego/vms/platformvm/txs/executor" "verifyAddValidatorTx"
+    pub fn validate_window(&self, now_unix: u64, start: u64, end: u64) -> io::Result<()> {
+        if start < self.earliest_start(now_unix) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "start time {} is too soon (must be at least {} seconds after current time {} for clock skew)",
+                    start, SYNC_BOUND, now_unix
+                ),
+            ));
+        }
+        if start > now_unix + MAX_FUTURE_START_TIME {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "start time {} is too far in the future (must be at most {} seconds after current time {})",
+                    start, MAX_FUTURE_START_TIME, now_unix
+                ),
+            ));
+        }
+        if end <= start {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("end time {} must be after start time {}", end, start),
+            ));
+        }
+
+        let duration = end - start;
+        if duration < self.min_stake_duration {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "staking period {} seconds is too short (min {} seconds)",
+                    duration, self.min_stake_duration
+                ),
+            ));
+        }
+        if duration > self.max_stake_duration {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "staking period {} seconds is too long (max {} seconds)",
+                    duration, self.max_stake_duration
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the "AddValidator" parameters client-side.
+    /// The "shares" is the delegation fee in the range [0, 1000000].
+    /// ref. "avalanchego/vms/platformvm/txs/executor" "verifyAddValidatorTx"
+    pub fn validate_add_validator(
+        &self,
+        now_unix: u64,
+        start: u64,
+        end: u64,
+        stake_amount: u64,
+        shares: u32,
+    ) -> io::Result<()> {
+        if stake_amount < self.min_validator_stake {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "stake amount {} is less than the minimum {} nano-AVAX",
+                    stake_amount, self.min_validator_stake
+                ),
+            ));
+        }
+        if stake_amount > self.max_validator_stake {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "stake amount {} is greater than the maximum {} nano-AVAX",
+                    stake_amount, self.max_validator_stake
+                ),
+            ));
+        }
+        if shares < self.min_delegation_fee || shares > 1_000_000 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "delegation fee {} is out of range [{}, 1000000]",
+                    shares, self.min_delegation_fee
+                ),
+            ));
+        }
+
+        self.validate_window(now_unix, start, end)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::staking::test_window --exact --show-output
+#[test]
+fn test_window() {
+    let now = 1_000_000_u64;
+
+    let cfg = Config::for_network(1);
+    let (start, end) = cfg.window(now, 60, 30 * 24 * 60 * 60).unwrap();
+    assert_eq!(start, now + 60);
+    assert_eq!(end, now + 60 + 30 * 24 * 60 * 60);
+
+    // buffer smaller than the clock skew margin is raised
+    let (start, _) = cfg.window(now, 0, 30 * 24 * 60 * 60).unwrap();
+    assert_eq!(start, now + SYNC_BOUND);
+    assert!(cfg
+        .validate_window(now, now + 5, now + 5 + 30 * 24 * 60 * 60)
+        .is_err());
+
+    // mainnet requires at least 2-week
+    assert!(cfg.window(now, 60, 24 * 60 * 60).is_err());
+    assert!(Config::for_network(5).window(now, 60, 24 * 60 * 60).is_ok());
+
+    let (start, end) = cfg.max_window(now, 60).unwrap();
+    assert_eq!(end - start, cfg.max_stake_duration);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::staking::test_validate_add_validator --exact --show-output
+#[test]
+fn test_validate_add_validator() {
+    let now = 1_000_000_u64;
+    let day = 24 * 60 * 60;
+    let cfg = Config::for_network(12345);
+    assert_eq!(cfg, Config::default());
+
+    assert!(cfg
+        .validate_add_validator(
+            now,
+            now + 60,
+            now + 60 + 30 * day,
+            2 * units::KILO_AVAX,
+            20000
+        )
+        .is_ok());
+
+    // start time too soon
+    let err = cfg
+        .validate_add_validator(now, now + 5, now + 30 * day, 2 * units::KILO_AVAX, 20000)
+        .unwrap_err();
+    assert!(err.to_string().contains("too soon"));
+
+    // start time too far in the future
+    assert!(cfg
+        .validate_add_validator(
+            now,
+            now + 15 * day,
+            now + 45 * day,
+            2 * units::KILO_AVAX,
+            20000
+        )
+        .is_err());
+
+    // end before start
+    assert!(cfg
+        .validate_add_validator(now, now + 60, now + 30, 2 * units::KILO_AVAX, 20000)
+        .is_err());
+
+    // too long
+    assert!(cfg
+        .validate_add_validator(now, now + 60, now + 400 * day, 2 * units::KILO_AVAX, 20000)
+        .is_err());
+
+    // stake too small
+    assert!(cfg
+        .validate_add_validator(now, now + 60, now + 30 * day, units::AVAX, 20000)
+        .is_err());
+
+    // delegation fee too small
+    assert!(cfg
+        .validate_add_validator(now, now + 60, now + 30 * day, 2 * units::KILO_AVAX, 100)
+        .is_err());
+}
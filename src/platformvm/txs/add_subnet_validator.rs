@@ -1,7 +1,9 @@
-use std::io::{self, Error, ErrorKind};
+use std::io;
 
-use crate::{codec, ids, key, platformvm, txs};
-use ring::digest::{digest, SHA256};
+use crate::{
+    codec, ids, key, packer, platformvm,
+    txs::{self, sign::Signable, verify::Verifiable},
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
@@ -92,9 +94,17 @@ impl Tx {
         &mut self,
         signers: Vec<Vec<T>>,
     ) -> io::Result<()> {
+        txs::sign::sign_with_codec_version(self, codec::VERSION, signers).await
+    }
+}
+
+impl Verifiable for Tx {
+    const VM: codec::Vm = codec::Vm::P;
+
+    fn pack_unsigned(&self, codec_version: u16) -> io::Result<packer::Packer> {
         // marshal "unsigned tx" with the codec version
-        let type_id = Self::type_id();
-        let packer = self.base_tx.pack(codec::VERSION, type_id)?;
+        let type_id = codec::REGISTRY.type_id(Self::VM, codec_version, &Self::type_name())?;
+        let packer = self.base_tx.pack(Self::VM, codec_version, type_id)?;
 
         // "avalanchego" marshals the whole struct again for signed bytes
         // even when the underlying "unsigned_tx" is already once marshaled
@@ -114,74 +124,40 @@ impl Tx {
         packer.pack_bytes(self.validator.subnet_id.as_ref())?;
 
         // pack the third field "subnet_auth" in the struct
-        let subnet_auth_type_id = key::secp256k1::txs::Input::type_id();
+        let subnet_auth_type_id = codec::REGISTRY.type_id(
+            Self::VM,
+            codec_version,
+            &key::secp256k1::txs::Input::type_name(),
+        )?;
         packer.pack_u32(subnet_auth_type_id)?;
         packer.pack_u32(self.subnet_auth.sig_indices.len() as u32)?;
         for sig_idx in self.subnet_auth.sig_indices.iter() {
             packer.pack_u32(*sig_idx)?;
         }
 
-        // take bytes just for hashing computation
-        let tx_bytes_with_no_signature = packer.take_bytes();
-        packer.set_bytes(&tx_bytes_with_no_signature);
-
-        // compute sha256 for marshaled "unsigned tx" bytes
-        // IMPORTANT: take the hash only for the type "platformvm.UnsignedAddValidatorTx" unsigned tx
-        // not other fields -- only hash "platformvm.UnsignedAddValidatorTx.*" but not "platformvm.Tx.Creds"
-        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#UnsignedAddValidatorTx
-        let tx_bytes_hash: Vec<u8> = digest(&SHA256, &tx_bytes_with_no_signature).as_ref().into();
-
-        // number of of credentials
-        let creds_len = signers.len() as u32;
-        // pack the fourth field in the struct
-        packer.pack_u32(creds_len)?;
-
-        // sign the hash with the signers (in case of multi-sig)
-        // and combine all signatures into a secp256k1fx credential
-        self.creds = Vec::new();
-        for keys in signers.iter() {
-            let mut sigs: Vec<Vec<u8>> = Vec::new();
-            for k in keys.iter() {
-                let sig = k.sign_digest(&tx_bytes_hash).await.map_err(|e| {
-                    Error::new(ErrorKind::Other, format!("failed sign_digest {}", e))
-                })?;
-                sigs.push(Vec::from(sig));
-            }
+        Ok(packer)
+    }
 
-            let mut cred = key::secp256k1::txs::Credential::default();
-            cred.signatures = sigs;
+    fn signed_inputs(&self) -> Vec<txs::transferable::Input> {
+        self.base_tx.transferable_inputs.clone().unwrap_or_default()
+    }
 
-            // add a new credential to "Tx"
-            self.creds.push(cred);
-        }
-        if creds_len > 0 {
-            // pack each "cred" which is "secp256k1fx.Credential"
-            // marshal type ID for "secp256k1fx.Credential"
-            let cred_type_id = key::secp256k1::txs::Credential::type_id();
-            for cred in self.creds.iter() {
-                // marshal type ID for "secp256k1fx.Credential"
-                packer.pack_u32(cred_type_id)?;
+    fn credentials(&self) -> Vec<key::secp256k1::txs::Credential> {
+        self.creds.clone()
+    }
 
-                // marshal fields for "secp256k1fx.Credential"
-                packer.pack_u32(cred.signatures.len() as u32)?;
-                for sig in cred.signatures.iter() {
-                    packer.pack_bytes(sig)?;
-                }
-            }
-        }
-        let tx_bytes_with_signatures = packer.take_bytes();
-        let tx_id: Vec<u8> = digest(&SHA256, &tx_bytes_with_signatures).as_ref().into();
+    fn subnet_auth(&self) -> Option<&key::secp256k1::txs::Input> {
+        Some(&self.subnet_auth)
+    }
+}
 
-        // update "BaseTx.Metadata" with id/unsigned bytes/bytes
-        // ref. "avalanchego/vms/platformvm.Tx.Sign"
-        // ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
-        self.base_tx.metadata = Some(txs::Metadata {
-            id: ids::Id::from_slice(&tx_id),
-            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
-            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
-        });
+impl Signable for Tx {
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
 
-        Ok(())
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.creds = creds;
     }
 }
 
@@ -0,0 +1,162 @@
+use std::io;
+
+use crate::{
+    codec, ids, key, packer,
+    txs::{self, sign::Signable, verify::Verifiable},
+};
+use serde::{Deserialize, Serialize};
+
+/// Base transaction of the P-chain, which only moves the funds between
+/// the inputs and the outputs (e.g., to consolidate the UTXOs).
+/// Accepted by the nodes since the Durango upgrade.
+///
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#BaseTx
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#UnsignedTx
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Tx {
+    /// The transaction ID is empty for unsigned tx
+    /// as long as "avax.BaseTx.Metadata" is "None".
+    /// Once Metadata is updated with signing and "Tx.Initialize",
+    /// Tx.ID() is non-empty.
+    pub base_tx: txs::Tx,
+
+    /// To be updated after signing.
+    pub creds: Vec<key::secp256k1::txs::Credential>,
+}
+
+impl Default for Tx {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Tx {
+    pub fn default() -> Self {
+        Self {
+            base_tx: txs::Tx::default(),
+            creds: Vec::new(),
+        }
+    }
+
+    pub fn new(base_tx: txs::Tx) -> Self {
+        Self {
+            base_tx,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the transaction ID.
+    /// Only non-empty if the embedded metadata is updated
+    /// with the signing process.
+    pub fn tx_id(&self) -> ids::Id {
+        if self.base_tx.metadata.is_some() {
+            let m = self.base_tx.metadata.clone().unwrap();
+            m.id
+        } else {
+            ids::Id::default()
+        }
+    }
+
+    pub fn type_name() -> String {
+        "platformvm.BaseTx".to_string()
+    }
+
+    pub fn type_id() -> u32 {
+        *(codec::P_TYPES.get(&Self::type_name()).unwrap()) as u32
+    }
+
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign
+    /// TODO: support ledger signing
+    pub async fn sign<T: key::secp256k1::SignOnly>(
+        &mut self,
+        signers: Vec<Vec<T>>,
+    ) -> io::Result<()> {
+        txs::sign::sign_with_codec_version(self, codec::VERSION, signers).await
+    }
+}
+
+impl Verifiable for Tx {
+    const VM: codec::Vm = codec::Vm::P;
+
+    fn pack_unsigned(&self, codec_version: u16) -> io::Result<packer::Packer> {
+        // marshal "unsigned tx" with the codec version
+        let type_id = codec::REGISTRY.type_id(Self::VM, codec_version, &Self::type_name())?;
+        self.base_tx.pack(Self::VM, codec_version, type_id)
+    }
+
+    fn signed_inputs(&self) -> Vec<txs::transferable::Input> {
+        self.base_tx.transferable_inputs.clone().unwrap_or_default()
+    }
+
+    fn credentials(&self) -> Vec<key::secp256k1::txs::Credential> {
+        self.creds.clone()
+    }
+}
+
+impl Signable for Tx {
+    fn base_tx_mut(&mut self) -> &mut txs::Tx {
+        &mut self.base_tx
+    }
+
+    fn set_credentials(&mut self, creds: Vec<key::secp256k1::txs::Credential>) {
+        self.creds = creds;
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::base::test_base_tx_sign --exact --show-output
+#[test]
+fn test_base_tx_sign() {
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let k = key::secp256k1::private_key::Key::generate().unwrap();
+    let owners =
+        key::secp256k1::txs::OutputOwners::new(0, 1, &[k.to_public_key().to_short_id().unwrap()]);
+    let asset_id = ids::Id::from_slice(&[0x79, 0x65, 0x65, 0x74]);
+    let utxo = txs::utxo::Utxo {
+        utxo_id: txs::utxo::Id::new(&[0x2c; 32], 1, false).unwrap(),
+        asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 500000000,
+            output_owners: owners.clone(),
+        }),
+        ..Default::default()
+    };
+
+    let mut tx = Tx::new(txs::Tx {
+        network_id: 10,
+        transferable_outputs: Some(vec![txs::transferable::Output {
+            asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount: 499000000,
+                output_owners: owners,
+            }),
+            ..Default::default()
+        }]),
+        transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: utxo.utxo_id.clone(),
+            asset_id,
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 500000000,
+                sig_indices: vec![0],
+            }),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    });
+    assert_eq!(Tx::type_id(), 34);
+    ab!(tx.sign(vec![vec![k]])).unwrap();
+
+    // codec version, then the type ID of "platformvm.BaseTx"
+    let metadata = tx.base_tx.metadata.clone().unwrap();
+    assert_eq!(
+        &metadata.tx_bytes_with_no_signature[..6],
+        &[0x00, 0x00, 0x00, 0x00, 0x00, 0x22]
+    );
+    assert_eq!(tx.tx_id(), metadata.id);
+    assert_eq!(tx.creds.len(), 1);
+    tx.verify_credentials(&[utxo]).unwrap();
+}
@@ -1,9 +1,11 @@
 pub mod add_subnet_validator;
 pub mod add_validator;
+pub mod base;
 pub mod create_chain;
 pub mod create_subnet;
 pub mod export;
 pub mod import;
+pub mod multisig;
 pub mod status;
 
 use std::cmp::Ordering;
@@ -0,0 +1,468 @@
+//! Partially-signed subnet auth transactions, for the subnets whose
+//! control keys are held by different parties.
+//!
+//! The builder fixes the signers of every credential in the unsigned
+//! transaction (the signature indices are part of the signed bytes),
+//! then the partial transaction is passed between the signers in JSON.
+//! Each signer signs the same digest in any order, and the signature is
+//! placed by the address it recovers to. Once every credential has all
+//! its signatures, the transaction is assembled and can be issued
+//! (e.g., with "wallet::p::P::issue_raw").
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/wallet/chain/p#Signer
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    codec::{self, serde::hex_0x_bytes::Hex0xBytes},
+    ids::{self, short},
+    key::{
+        self,
+        secp256k1::{batch, txs::OutputOwners},
+    },
+    platformvm::txs::{add_subnet_validator, create_chain},
+    txs::{self, sign::Signable, verify::Verifiable},
+};
+use ring::digest::{digest, SHA256};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+/// Transaction that requires the subnet auth credential.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "snake_case", tag = "type", content = "tx")]
+pub enum SubnetAuthTx {
+    CreateChain(create_chain::Tx),
+    AddSubnetValidator(add_subnet_validator::Tx),
+}
+
+impl SubnetAuthTx {
+    pub fn base_tx(&self) -> &txs::Tx {
+        match self {
+            SubnetAuthTx::CreateChain(tx) => &tx.base_tx,
+            SubnetAuthTx::AddSubnetValidator(tx) => &tx.base_tx,
+        }
+    }
+
+    pub fn subnet_auth(&self) -> &key::secp256k1::txs::Input {
+        match self {
+            SubnetAuthTx::CreateChain(tx) => &tx.subnet_auth,
+            SubnetAuthTx::AddSubnetValidator(tx) => &tx.subnet_auth,
+        }
+    }
+
+    pub fn creds(&self) -> &[key::secp256k1::txs::Credential] {
+        match self {
+            SubnetAuthTx::CreateChain(tx) => &tx.creds,
+            SubnetAuthTx::AddSubnetValidator(tx) => &tx.creds,
+        }
+    }
+
+    /// Returns the transaction ID, only non-empty once assembled.
+    pub fn tx_id(&self) -> ids::Id {
+        match self {
+            SubnetAuthTx::CreateChain(tx) => tx.tx_id(),
+            SubnetAuthTx::AddSubnetValidator(tx) => tx.tx_id(),
+        }
+    }
+
+    /// Returns the signed transaction bytes, only non-empty once assembled.
+    pub fn tx_bytes(&self) -> Vec<u8> {
+        self.base_tx()
+            .metadata
+            .as_ref()
+            .map(|m| m.tx_bytes_with_signatures.clone())
+            .unwrap_or_default()
+    }
+
+    /// Marshals the transaction without credentials, and returns the
+    /// unsigned bytes that the credentials sign.
+    fn unsigned_bytes(&self) -> io::Result<Vec<u8>> {
+        let packer = match self {
+            SubnetAuthTx::CreateChain(tx) => tx.pack_unsigned(codec::VERSION)?,
+            SubnetAuthTx::AddSubnetValidator(tx) => tx.pack_unsigned(codec::VERSION)?,
+        };
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    fn set_signed(&mut self, creds: Vec<key::secp256k1::txs::Credential>) -> io::Result<()> {
+        match self {
+            SubnetAuthTx::CreateChain(tx) => tx.set_signed(codec::VERSION, creds),
+            SubnetAuthTx::AddSubnetValidator(tx) => tx.set_signed(codec::VERSION, creds),
+        }
+    }
+}
+
+/// Signers of one credential, in the order of its signature indices.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct Slot {
+    pub signers: Vec<short::Id>,
+    #[serde_as(as = "Vec<Option<Hex0xBytes>>")]
+    pub signatures: Vec<Option<Vec<u8>>>,
+}
+
+impl Slot {
+    fn new(signers: Vec<short::Id>) -> Self {
+        let signatures = vec![None; signers.len()];
+        Self {
+            signers,
+            signatures,
+        }
+    }
+}
+
+/// Subnet auth transaction that collects the signatures of its credentials:
+/// one per input, followed by the subnet auth credential.
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+pub struct PartialTx {
+    pub tx: SubnetAuthTx,
+    #[serde_as(as = "Hex0xBytes")]
+    pub unsigned_tx_bytes: Vec<u8>,
+    pub slots: Vec<Slot>,
+}
+
+impl PartialTx {
+    /// Creates from the unsigned transaction, the signers of each input
+    /// (e.g., the owners of the spent UTXOs at the signature indices),
+    /// and the subnet owners that the subnet auth indices refer to.
+    pub fn new(
+        tx: SubnetAuthTx,
+        input_signers: Vec<Vec<short::Id>>,
+        subnet_owners: &OutputOwners,
+    ) -> io::Result<Self> {
+        let ins = tx.base_tx().transferable_inputs.clone().unwrap_or_default();
+        if ins.len() != input_signers.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "{} inputs but signers for {}",
+                    ins.len(),
+                    input_signers.len()
+                ),
+            ));
+        }
+        for (i, (input, signers)) in ins.iter().zip(input_signers.iter()).enumerate() {
+            let sig_indices = match (&input.transfer_input, &input.stakeable_lock_in) {
+                (Some(transfer_in), _) => &transfer_in.sig_indices,
+                (None, Some(lock_in)) => &lock_in.transfer_input.sig_indices,
+                (None, None) => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        format!("input {} has no transfer input", i),
+                    ))
+                }
+            };
+            if sig_indices.len() != signers.len() {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "input {} has {} signature indices but {} signers",
+                        i,
+                        sig_indices.len(),
+                        signers.len()
+                    ),
+                ));
+            }
+        }
+
+        let sig_indices = &tx.subnet_auth().sig_indices;
+        if sig_indices.len() != subnet_owners.threshold as usize
+            || !cmp_manager::is_sorted_and_unique(sig_indices)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "subnet auth signature indices {:?} must be sorted and meet the threshold {}",
+                    sig_indices, subnet_owners.threshold
+                ),
+            ));
+        }
+        let mut subnet_signers = Vec::with_capacity(sig_indices.len());
+        for idx in sig_indices.iter() {
+            let addr = subnet_owners.addresses.get(*idx as usize).ok_or_else(|| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!(
+                        "subnet auth signature index {} out of bounds ({} owners)",
+                        idx,
+                        subnet_owners.addresses.len()
+                    ),
+                )
+            })?;
+            subnet_signers.push(addr.clone());
+        }
+
+        let unsigned_tx_bytes = tx.unsigned_bytes()?;
+        let mut slots: Vec<Slot> = input_signers.into_iter().map(Slot::new).collect();
+        slots.push(Slot::new(subnet_signers));
+        Ok(Self {
+            tx,
+            unsigned_tx_bytes,
+            slots,
+        })
+    }
+
+    /// Returns the SHA256 digest that every signer signs.
+    pub fn digest(&self) -> Vec<u8> {
+        digest(&SHA256, &self.unsigned_tx_bytes).as_ref().to_vec()
+    }
+
+    /// Fails if the transaction no longer marshals to the unsigned bytes
+    /// (e.g., it was modified after the signers received it), so that
+    /// no signer signs a digest other than the transaction's.
+    fn check_unsigned_bytes(&self) -> io::Result<()> {
+        if self.tx.unsigned_bytes()? != self.unsigned_tx_bytes {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "transaction does not match its unsigned bytes",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Adds the signature to every credential that expects its signer,
+    /// and returns the number of signatures placed.
+    /// Fails if the signer is not expected by any credential,
+    /// or if the transaction does not match its unsigned bytes.
+    pub fn add_signature(&mut self, sig: &[u8]) -> io::Result<usize> {
+        self.check_unsigned_bytes()?;
+        self.place_signature(sig)
+    }
+
+    /// Signs the digest with the key and adds the signature.
+    /// Refuses to sign if the transaction does not match its unsigned bytes.
+    pub async fn sign<T: key::secp256k1::SignOnly>(&mut self, k: &T) -> io::Result<usize> {
+        self.check_unsigned_bytes()?;
+        let sig = k
+            .sign_digest(&self.digest())
+            .await
+            .map_err(|e| Error::new(ErrorKind::Other, format!("failed sign_digest {}", e)))?;
+        self.place_signature(&sig)
+    }
+
+    fn place_signature(&mut self, sig: &[u8]) -> io::Result<usize> {
+        let signer = batch::recover_signer(&self.digest(), sig)?;
+        let mut placed = 0;
+        for slot in self.slots.iter_mut() {
+            for (addr, s) in slot.signers.iter().zip(slot.signatures.iter_mut()) {
+                if *addr == signer {
+                    *s = Some(sig.to_vec());
+                    placed += 1;
+                }
+            }
+        }
+        if placed == 0 {
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                format!("{} is not a signer of the transaction", signer),
+            ));
+        }
+        Ok(placed)
+    }
+
+    /// Returns the signers whose signatures are missing, without duplicates.
+    pub fn missing(&self) -> Vec<short::Id> {
+        let mut missing: Vec<short::Id> = Vec::new();
+        for slot in self.slots.iter() {
+            for (addr, s) in slot.signers.iter().zip(slot.signatures.iter()) {
+                if s.is_none() && !missing.contains(addr) {
+                    missing.push(addr.clone());
+                }
+            }
+        }
+        missing
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.slots
+            .iter()
+            .all(|slot| slot.signatures.iter().all(Option::is_some))
+    }
+
+    /// Assembles the signed transaction once every signature is in.
+    /// Fails if the transaction no longer marshals to the signed bytes
+    /// (e.g., it was modified after the signers received it).
+    pub fn assemble(self) -> io::Result<SubnetAuthTx> {
+        let missing = self.missing();
+        if !missing.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("missing signatures of {:?}", missing),
+            ));
+        }
+        self.check_unsigned_bytes()?;
+
+        let creds: Vec<key::secp256k1::txs::Credential> = self
+            .slots
+            .into_iter()
+            .map(|slot| {
+                key::secp256k1::txs::Credential::new(
+                    slot.signatures.into_iter().flatten().collect(),
+                )
+            })
+            .collect();
+
+        // same as the credentials that "Tx.sign" appends to the unsigned bytes
+        let mut tx = self.tx;
+        tx.set_signed(creds)?;
+        Ok(tx)
+    }
+
+    pub fn encode_json(&self) -> io::Result<String> {
+        serde_json::to_string(&self).map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to serialize partial tx to JSON {}", e),
+            )
+        })
+    }
+
+    pub fn decode_json(d: &str) -> io::Result<Self> {
+        serde_json::from_str(d)
+            .map_err(|e| Error::new(ErrorKind::InvalidData, format!("invalid JSON: {}", e)))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- platformvm::txs::multisig::test_partial_tx --exact --show-output
+#[test]
+fn test_partial_tx() {
+    use crate::txs::verify::Verifiable;
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    let payer = key::secp256k1::private_key::Key::generate().unwrap();
+    let keys: Vec<key::secp256k1::private_key::Key> = (0..3)
+        .map(|_| key::secp256k1::private_key::Key::generate().unwrap())
+        .collect();
+    let addr = |k: &key::secp256k1::private_key::Key| k.to_public_key().to_short_id().unwrap();
+
+    let payer_owners = OutputOwners::new(0, 1, &[addr(&payer)]);
+    let subnet_owners =
+        OutputOwners::new(0, 2, &keys.iter().map(addr).collect::<Vec<_>>()).sorted();
+    // the control keys at the last two indices sign
+    let signers: Vec<&key::secp256k1::private_key::Key> = subnet_owners.addresses[1..]
+        .iter()
+        .map(|a| keys.iter().find(|k| addr(k) == *a).unwrap())
+        .collect();
+
+    let asset_id = ids::Id::sha256(b"AVAX");
+    let utxo_id = txs::utxo::Id {
+        tx_id: ids::Id::sha256(b"prev"),
+        output_index: 0,
+        ..txs::utxo::Id::default()
+    };
+    let utxos = vec![txs::utxo::Utxo {
+        utxo_id: utxo_id.clone(),
+        asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+            1000,
+            payer_owners,
+        )),
+        ..txs::utxo::Utxo::default()
+    }];
+    let tx = create_chain::Tx {
+        base_tx: txs::Tx {
+            network_id: 1,
+            transferable_inputs: Some(vec![txs::transferable::Input {
+                utxo_id,
+                asset_id,
+                transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                    amount: 1000,
+                    sig_indices: vec![0],
+                }),
+                ..txs::transferable::Input::default()
+            }]),
+            ..txs::Tx::default()
+        },
+        subnet_id: ids::Id::sha256(b"subnet"),
+        chain_name: String::from("test"),
+        subnet_auth: key::secp256k1::txs::Input::new(vec![1, 2]),
+        ..create_chain::Tx::default()
+    };
+
+    // wrong number of input signers
+    assert!(PartialTx::new(
+        SubnetAuthTx::CreateChain(tx.clone()),
+        Vec::new(),
+        &subnet_owners
+    )
+    .is_err());
+
+    let mut partial = PartialTx::new(
+        SubnetAuthTx::CreateChain(tx.clone()),
+        vec![vec![addr(&payer)]],
+        &subnet_owners,
+    )
+    .unwrap();
+    assert_eq!(partial.missing().len(), 3);
+
+    // out of order, and passed around in JSON
+    assert_eq!(ab!(partial.sign(signers[1])).unwrap(), 1);
+    let mut partial = PartialTx::decode_json(&partial.encode_json().unwrap()).unwrap();
+    let outsider = key::secp256k1::private_key::Key::generate().unwrap();
+    assert_eq!(
+        ab!(partial.sign(&outsider)).unwrap_err().kind(),
+        ErrorKind::PermissionDenied
+    );
+    ab!(partial.sign(&payer)).unwrap();
+    assert!(!partial.is_complete());
+    assert!(partial.clone().assemble().is_err());
+
+    // the unsigned bytes no longer match the transaction, so nobody signs them
+    let mut tampered = partial.clone();
+    tampered.unsigned_tx_bytes.push(0x00);
+    assert_eq!(
+        ab!(tampered.sign(signers[0])).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    let sig = ab!(key::secp256k1::SignOnly::sign_digest(
+        signers[0],
+        &tampered.digest()
+    ))
+    .unwrap();
+    assert_eq!(
+        tampered.add_signature(&sig).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+
+    let sig = ab!(key::secp256k1::SignOnly::sign_digest(
+        signers[0],
+        &partial.digest()
+    ))
+    .unwrap();
+    partial.add_signature(&sig).unwrap();
+    assert!(partial.is_complete());
+
+    // modified after signing
+    let mut tampered = partial.clone();
+    if let SubnetAuthTx::CreateChain(tx) = &mut tampered.tx {
+        tx.chain_name = String::from("other");
+    }
+    assert_eq!(
+        ab!(tampered.clone().sign(signers[0])).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+    assert!(tampered.assemble().is_err());
+
+    let signed = partial.assemble().unwrap();
+    let signed = match signed {
+        SubnetAuthTx::CreateChain(tx) => tx,
+        _ => unreachable!(),
+    };
+    signed
+        .verify_credentials_with_subnet_owners(&utxos, &subnet_owners)
+        .unwrap();
+
+    // same bytes as signing with all keys at once
+    let mut expected = tx;
+    ab!(expected.sign(vec![
+        vec![payer.clone()],
+        vec![signers[0].clone(), signers[1].clone()],
+    ]))
+    .unwrap();
+    assert_eq!(signed.base_tx.metadata, expected.base_tx.metadata);
+}
@@ -0,0 +1,333 @@
+//! Python bindings (pyo3) for the JSON-RPC clients, key management,
+//! and the X-chain transfer, e.g., for scripts that otherwise shell out
+//! to avalanche-cli.
+//!
+//! ```text
+//! cargo rustc --release --features python_extension --crate-type cdylib
+//! cp target/release/libavalanche_types.so avalanche_types.so
+//! ```
+//!
+//! ```text
+//! import avalanche_types as avax
+//!
+//! key = avax.PrivateKey.generate()
+//! print(key.address("X", 1))
+//! print(avax.get_network_id("http://localhost:9650"))
+//! signed = avax.x_transfer("http://localhost:9650", key, "X-custom1...", 1_000_000, issue=False)
+//! print(signed["txID"], signed["txHex"])
+//! ```
+//!
+//! The API responses and the signed transactions are returned as dicts with
+//! the same field names as the JSON-RPC APIs. Network calls release the GIL.
+//! "ValueError" is raised for invalid arguments, and "IOError" for failures
+//! from the node or the network.
+
+use std::{
+    future::Future,
+    io::{self, ErrorKind},
+};
+
+use crate::{
+    bindings::{self, RUNTIME},
+    client::{health as client_health, info as client_info, p as client_p, x as client_x},
+    key::secp256k1::private_key::Key,
+};
+use pyo3::{
+    exceptions::{PyIOError, PyValueError},
+    prelude::*,
+};
+use serde::Serialize;
+
+fn to_py_err(e: io::Error) -> PyErr {
+    match e.kind() {
+        ErrorKind::InvalidInput | ErrorKind::InvalidData => PyValueError::new_err(e.to_string()),
+        _ => PyIOError::new_err(e.to_string()),
+    }
+}
+
+/// Converts to the Python object via JSON, so that the dicts keep
+/// the field names of the JSON-RPC APIs.
+fn to_py<S: Serialize>(py: Python<'_>, v: &S) -> PyResult<PyObject> {
+    let s = serde_json::to_string(v).map_err(|e| PyValueError::new_err(e.to_string()))?;
+    let obj = py.import("json")?.call_method1("loads", (s,))?;
+    Ok(obj.to_object(py))
+}
+
+/// Runs the future on the shared runtime without holding the GIL.
+fn block_on<F, T>(py: Python<'_>, f: F) -> PyResult<T>
+where
+    F: Future<Output = io::Result<T>> + Send,
+    T: Send,
+{
+    py.allow_threads(|| RUNTIME.block_on(f)).map_err(to_py_err)
+}
+
+/// secp256k1 private key.
+#[pyclass(name = "PrivateKey")]
+#[derive(Clone)]
+pub struct PyPrivateKey {
+    inner: Key,
+}
+
+#[pymethods]
+impl PyPrivateKey {
+    #[staticmethod]
+    fn generate() -> PyResult<Self> {
+        let inner = Key::generate().map_err(to_py_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Loads the key in hex ("0x...").
+    #[staticmethod]
+    fn from_hex(s: &str) -> PyResult<Self> {
+        let inner = Key::from_hex(s).map_err(to_py_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Loads the key in CB58 ("PrivateKey-...").
+    #[staticmethod]
+    fn from_cb58(s: &str) -> PyResult<Self> {
+        let inner = Key::from_cb58(s).map_err(to_py_err)?;
+        Ok(Self { inner })
+    }
+
+    fn to_hex(&self) -> String {
+        self.inner.to_hex()
+    }
+
+    fn to_cb58(&self) -> String {
+        self.inner.to_cb58()
+    }
+
+    /// Returns the bech32 address for the chain alias (e.g., "X-avax1...").
+    fn address(&self, chain_alias: &str, network_id: u32) -> PyResult<String> {
+        self.inner
+            .to_public_key()
+            .hrp_address(network_id, chain_alias)
+            .map_err(to_py_err)
+    }
+
+    /// Returns the EIP-55 checksummed Ethereum address (e.g., C-chain).
+    fn eth_address(&self) -> String {
+        self.inner.to_public_key().eth_address()
+    }
+
+    /// Returns the short address in CB58, as used in the owners of outputs.
+    fn short_address(&self) -> PyResult<String> {
+        let id = self
+            .inner
+            .to_public_key()
+            .to_short_id()
+            .map_err(to_py_err)?;
+        Ok(id.to_string())
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!("PrivateKey(address={})", self.short_address()?))
+    }
+}
+
+/// "info.getNetworkID"
+#[pyfunction]
+fn get_network_id(py: Python<'_>, http_rpc: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_info::get_network_id(http_rpc).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "info.getNetworkName"
+#[pyfunction]
+fn get_network_name(py: Python<'_>, http_rpc: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_info::get_network_name(http_rpc).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "info.getBlockchainID"
+#[pyfunction]
+fn get_blockchain_id(py: Python<'_>, http_rpc: &str, chain_alias: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_info::get_blockchain_id(http_rpc, chain_alias)
+            .await?
+            .into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "info.getNodeID"
+#[pyfunction]
+fn get_node_id(py: Python<'_>, http_rpc: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_info::get_node_id(http_rpc).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "info.getNodeVersion"
+#[pyfunction]
+fn get_node_version(py: Python<'_>, http_rpc: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_info::get_node_version(http_rpc).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "/ext/health", or "/ext/health/liveness" with "liveness".
+#[pyfunction]
+#[pyo3(signature = (http_rpc, liveness = false))]
+fn health(py: Python<'_>, http_rpc: &str, liveness: bool) -> PyResult<PyObject> {
+    let r = block_on(py, client_health::spawn_check(http_rpc, liveness))?;
+    to_py(py, &r)
+}
+
+/// "avm.getBalance" for the AVAX asset.
+#[pyfunction]
+fn x_get_balance(py: Python<'_>, http_rpc: &str, xaddr: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_x::get_balance(http_rpc, xaddr).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "avm.getTxStatus"
+#[pyfunction]
+fn x_get_tx_status(py: Python<'_>, http_rpc: &str, tx_id: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_x::get_tx_status(http_rpc, tx_id)
+            .await?
+            .into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "platform.getBalance"
+#[pyfunction]
+fn p_get_balance(py: Python<'_>, http_rpc: &str, paddr: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_p::get_balance(http_rpc, paddr).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "platform.getHeight"
+#[pyfunction]
+fn p_get_height(py: Python<'_>, http_rpc: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_p::get_height(http_rpc).await?.into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// "platform.getTxStatus"
+#[pyfunction]
+fn p_get_tx_status(py: Python<'_>, http_rpc: &str, tx_id: &str) -> PyResult<PyObject> {
+    let r = block_on(py, async {
+        client_p::get_tx_status(http_rpc, tx_id)
+            .await?
+            .into_result()
+    })?;
+    to_py(py, &r)
+}
+
+/// Issues the signed transaction in hex to the "X" or "P" chain,
+/// and returns the transaction Id.
+#[pyfunction]
+fn issue_tx(py: Python<'_>, http_rpc: &str, chain_alias: &str, tx_hex: &str) -> PyResult<String> {
+    let tx_id = block_on(py, bindings::issue_tx(http_rpc, chain_alias, tx_hex))?;
+    Ok(tx_id.to_string())
+}
+
+/// Builds and signs the X-chain AVAX transfer to the receiver address
+/// (e.g., "X-avax1..."), and issues it unless "issue" is False.
+/// Returns the signed transaction with "txID" and "txHex".
+#[pyfunction]
+#[pyo3(signature = (http_rpc, key, receiver, amount, issue = true, check_acceptance = false))]
+fn x_transfer(
+    py: Python<'_>,
+    http_rpc: &str,
+    key: &PyPrivateKey,
+    receiver: &str,
+    amount: u64,
+    issue: bool,
+    check_acceptance: bool,
+) -> PyResult<PyObject> {
+    let signed = block_on(
+        py,
+        bindings::x_transfer(
+            http_rpc,
+            &key.inner,
+            receiver,
+            amount,
+            issue,
+            check_acceptance,
+        ),
+    )?;
+    to_py(py, &signed)
+}
+
+/// Python module "avalanche_types".
+#[pymodule]
+fn avalanche_types(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_class::<PyPrivateKey>()?;
+
+    m.add_function(wrap_pyfunction!(get_network_id, m)?)?;
+    m.add_function(wrap_pyfunction!(get_network_name, m)?)?;
+    m.add_function(wrap_pyfunction!(get_blockchain_id, m)?)?;
+    m.add_function(wrap_pyfunction!(get_node_id, m)?)?;
+    m.add_function(wrap_pyfunction!(get_node_version, m)?)?;
+    m.add_function(wrap_pyfunction!(health, m)?)?;
+
+    m.add_function(wrap_pyfunction!(x_get_balance, m)?)?;
+    m.add_function(wrap_pyfunction!(x_get_tx_status, m)?)?;
+    m.add_function(wrap_pyfunction!(p_get_balance, m)?)?;
+    m.add_function(wrap_pyfunction!(p_get_height, m)?)?;
+    m.add_function(wrap_pyfunction!(p_get_tx_status, m)?)?;
+    m.add_function(wrap_pyfunction!(issue_tx, m)?)?;
+
+    m.add_function(wrap_pyfunction!(x_transfer, m)?)?;
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features python -- python::test_module --exact --show-output
+#[test]
+fn test_module() {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let m = PyModule::new(py, "avalanche_types").unwrap();
+        avalanche_types(py, m).unwrap();
+
+        let key = m
+            .getattr("PrivateKey")
+            .unwrap()
+            .call_method0("generate")
+            .unwrap();
+        let cb58: String = key.call_method0("to_cb58").unwrap().extract().unwrap();
+        let inner = Key::from_cb58(&cb58).unwrap();
+        let addr: String = key
+            .call_method1("address", ("X", 1))
+            .unwrap()
+            .extract()
+            .unwrap();
+        assert_eq!(addr, inner.to_public_key().hrp_address(1, "X").unwrap());
+
+        // invalid arguments raise "ValueError" before any network call
+        let err = m
+            .getattr("issue_tx")
+            .unwrap()
+            .call1(("http://localhost:9650", "C", "0x00"))
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        let err = m
+            .getattr("x_transfer")
+            .unwrap()
+            .call1(("http://localhost:9650", key, "X-invalid", 1))
+            .unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+
+        let dict = to_py(py, &serde_json::json!({"txID": "abc"})).unwrap();
+        let tx_id: String = dict.as_ref(py).get_item("txID").unwrap().extract().unwrap();
+        assert_eq!(tx_id, "abc");
+    });
+}
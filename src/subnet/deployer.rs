@@ -0,0 +1,348 @@
+//! One-shot deployment of a custom VM chain on a new subnet.
+//!
+//! The deployer creates the subnet, adds the target nodes as its validators,
+//! creates the blockchain, and waits until every target node has bootstrapped
+//! the new chain. The target nodes must already track the subnet (e.g.,
+//! "--track-subnets") and have the VM plugin installed, which the deployer
+//! checks before issuing any transaction.
+//!
+//! ```ignore
+//! let deployment = Deployer::new(&wallet, vm_id, genesis_bytes)
+//!     .chain_name("timestampvm")
+//!     .validators(vec![node_id1, node_id2])
+//!     .node_http_rpcs(vec!["http://node1:9650".to_string(), "http://node2:9650".to_string()])
+//!     .deploy()
+//!     .await?;
+//! println!("{}", serde_json::to_string_pretty(&deployment)?);
+//! ```
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    client::{
+        cancel, info as client_info, plugin,
+        time::{Duration, Instant},
+        wallet::{batch::Ref, Wallet},
+    },
+    ids::{self, node},
+    key,
+};
+use serde::{Deserialize, Serialize};
+
+/// Subnet validator added by the deployment.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Validator {
+    pub node_id: node::Id,
+    /// Empty if the node was already a subnet validator.
+    pub tx_id: ids::Id,
+}
+
+/// Outcome of a subnet deployment.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Deployment {
+    pub network_id: u32,
+    pub subnet_id: ids::Id,
+    pub blockchain_id: ids::Id,
+    pub vm_id: ids::Id,
+    pub chain_name: String,
+    pub validators: Vec<Validator>,
+    /// Node endpoints that bootstrapped the new chain.
+    pub bootstrapped: Vec<String>,
+}
+
+/// Deploys a chain of the VM on a new subnet with the funded wallet.
+#[derive(Clone, Debug)]
+pub struct Deployer<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub wallet: Wallet<T>,
+
+    pub vm_id: ids::Id,
+    pub genesis_data: Vec<u8>,
+    pub chain_name: String,
+
+    /// Nodes to add as subnet validators.
+    pub validators: Vec<node::Id>,
+    /// Subnet validator weight of each node.
+    pub validator_weight: u64,
+
+    /// Endpoints of the nodes that must bootstrap the new chain.
+    pub node_http_rpcs: Vec<String>,
+
+    /// Wait between each poll intervals for the chain bootstrap.
+    pub poll_interval: Duration,
+    /// Maximum duration for the chain bootstrap.
+    pub bootstrap_timeout: Duration,
+}
+
+impl<T> Deployer<T>
+where
+    T: key::secp256k1::ReadOnly + key::secp256k1::SignOnly + Clone,
+{
+    pub fn new(wallet: &Wallet<T>, vm_id: ids::Id, genesis_data: Vec<u8>) -> Self {
+        Self {
+            wallet: wallet.clone(),
+            vm_id,
+            genesis_data,
+            chain_name: String::from("subnet"),
+            validators: Vec::new(),
+            validator_weight: 1000,
+            node_http_rpcs: Vec::new(),
+            poll_interval: Duration::from_secs(5),
+            bootstrap_timeout: Duration::from_secs(600),
+        }
+    }
+
+    /// Sets the chain name.
+    #[must_use]
+    pub fn chain_name(mut self, chain_name: impl Into<String>) -> Self {
+        self.chain_name = chain_name.into();
+        self
+    }
+
+    /// Sets the node Ids to add as subnet validators.
+    #[must_use]
+    pub fn validators(mut self, validators: Vec<node::Id>) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    /// Sets the subnet validator weight.
+    #[must_use]
+    pub fn validator_weight(mut self, validator_weight: u64) -> Self {
+        self.validator_weight = validator_weight;
+        self
+    }
+
+    /// Sets the node endpoints to wait for the chain bootstrap.
+    #[must_use]
+    pub fn node_http_rpcs(mut self, node_http_rpcs: Vec<String>) -> Self {
+        self.node_http_rpcs = node_http_rpcs;
+        self
+    }
+
+    /// Sets the poll wait time between intervals.
+    #[must_use]
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Sets the bootstrap timeout.
+    #[must_use]
+    pub fn bootstrap_timeout(mut self, bootstrap_timeout: Duration) -> Self {
+        self.bootstrap_timeout = bootstrap_timeout;
+        self
+    }
+
+    /// Runs the deployment and returns its report.
+    pub async fn deploy(&self) -> io::Result<Deployment> {
+        if self.validators.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "no subnet validator to deploy with",
+            ));
+        }
+        log::info!(
+            "deploying vm {} chain '{}' with {} validator(s)",
+            self.vm_id,
+            self.chain_name,
+            self.validators.len()
+        );
+
+        // fail before spending any fee if a target node cannot run the VM
+        let missing = plugin::mismatches(&self.node_http_rpcs, &self.vm_id).await?;
+        if !missing.is_empty() {
+            let reports: Vec<String> = missing.iter().map(|r| r.to_string()).collect();
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!(
+                    "vm plugin not loaded on target nodes: {}",
+                    reports.join("; ")
+                ),
+            ));
+        }
+
+        // create subnet -> add subnet validators -> create chain
+        let p = self.wallet.p();
+        let mut batch = self.wallet.batch().create_subnet(p.create_subnet());
+        for node_id in self.validators.iter() {
+            batch = batch.add_subnet_validator(
+                p.add_subnet_validator()
+                    .node_id(*node_id)
+                    .weight(self.validator_weight),
+                Ref::Step(0),
+            );
+        }
+        batch = batch.create_chain(
+            p.create_chain()
+                .vm_id(self.vm_id)
+                .genesis_data(self.genesis_data.clone())
+                .chain_name(self.chain_name.clone()),
+            Ref::Step(0),
+        );
+        let tx_ids = batch.issue().await?;
+
+        let subnet_id = tx_ids[0];
+        let blockchain_id = tx_ids[tx_ids.len() - 1];
+        log::info!(
+            "created subnet {} and blockchain {}",
+            subnet_id,
+            blockchain_id
+        );
+
+        wait_for_bootstrap(
+            &self.node_http_rpcs,
+            &blockchain_id,
+            self.poll_interval,
+            self.bootstrap_timeout,
+        )
+        .await?;
+
+        Ok(Deployment {
+            network_id: self.wallet.network_id,
+            subnet_id,
+            blockchain_id,
+            vm_id: self.vm_id,
+            chain_name: self.chain_name.clone(),
+            validators: self
+                .validators
+                .iter()
+                .zip(tx_ids[1..tx_ids.len() - 1].iter())
+                .map(|(node_id, tx_id)| Validator {
+                    node_id: *node_id,
+                    tx_id: *tx_id,
+                })
+                .collect(),
+            bootstrapped: self.node_http_rpcs.clone(),
+        })
+    }
+}
+
+/// Polls every node until it has bootstrapped the blockchain, or the timeout
+/// expires. A node that fails to respond (e.g., restarting to load the new
+/// chain) is retried.
+pub async fn wait_for_bootstrap(
+    http_rpcs: &[String],
+    blockchain_id: &ids::Id,
+    poll_interval: Duration,
+    timeout: Duration,
+) -> io::Result<()> {
+    let chain = blockchain_id.to_string();
+    let start = Instant::now();
+
+    let mut pending: Vec<&String> = http_rpcs.iter().collect();
+    loop {
+        let mut still_pending = Vec::new();
+        for http_rpc in pending {
+            let res = client_info::is_chain_bootstrapped(http_rpc, &chain)
+                .await
+                .and_then(|resp| resp.into_result());
+            match res {
+                Ok(r) if r.is_bootstrapped => {
+                    log::info!("{} bootstrapped chain {}", http_rpc, chain);
+                }
+                Ok(_) => still_pending.push(http_rpc),
+                Err(e) => {
+                    log::warn!("failed to check bootstrap of {} '{}'", http_rpc, e);
+                    still_pending.push(http_rpc);
+                }
+            }
+        }
+        if still_pending.is_empty() {
+            return Ok(());
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed.gt(&timeout) {
+            return Err(Error::new(
+                ErrorKind::TimedOut,
+                format!(
+                    "chain {} not bootstrapped on {:?} in time (check the tracked subnets and the vm plugin)",
+                    chain, still_pending
+                ),
+            ));
+        }
+        log::info!(
+            "{} node(s) still bootstrapping chain {} (elapsed {:?})",
+            still_pending.len(),
+            chain,
+            elapsed
+        );
+        pending = still_pending;
+        cancel::sleep(poll_interval).await?;
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::deployer::test_wait_for_bootstrap --exact --show-output
+#[tokio::test]
+async fn test_wait_for_bootstrap() {
+    use crate::client::transport::{self, Transport};
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    /// Bootstraps "node1" on the third poll, and never "node2".
+    struct Fake {
+        polls: AtomicUsize,
+    }
+    #[async_trait::async_trait]
+    impl Transport for Fake {
+        async fn post_non_tls(&self, url: &str, _: &str, body: &str) -> io::Result<Vec<u8>> {
+            assert!(body.contains("info.isBootstrapped"));
+            let bootstrapped = if url.contains("node1") {
+                self.polls.fetch_add(1, Ordering::SeqCst) >= 2
+            } else {
+                false
+            };
+            Ok(format!(
+                r#"{{"jsonrpc":"2.0","id":{},"result":{{"isBootstrapped":{}}}}}"#,
+                crate::jsonrpc::request_id(body).unwrap_or_default(),
+                bootstrapped
+            )
+            .into_bytes())
+        }
+        async fn get_non_tls(&self, _: &str, _: &str) -> io::Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    let fake = Arc::new(Fake {
+        polls: AtomicUsize::new(0),
+    });
+    let blockchain_id = ids::Id::from_slice(&[1; 32]);
+
+    let node1 = vec![String::from("http://node1")];
+    transport::scope(
+        fake.clone(),
+        wait_for_bootstrap(
+            &node1,
+            &blockchain_id,
+            Duration::from_millis(1),
+            Duration::from_secs(5),
+        ),
+    )
+    .await
+    .unwrap();
+    assert_eq!(fake.polls.load(Ordering::SeqCst), 3);
+
+    let both = vec![String::from("http://node1"), String::from("http://node2")];
+    let err = transport::scope(
+        fake,
+        wait_for_bootstrap(
+            &both,
+            &blockchain_id,
+            Duration::from_millis(1),
+            Duration::from_millis(20),
+        ),
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::TimedOut);
+    assert!(err.to_string().contains("node2"));
+    assert!(!err.to_string().contains("node1"));
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "wallet")]
+pub mod deployer;
+#[cfg(feature = "subnet_vm")]
 pub mod rpc;
 
 use std::io::{self, Error, ErrorKind};
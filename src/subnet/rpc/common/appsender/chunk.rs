@@ -0,0 +1,559 @@
+//! Size-checked app messages, with gossip payloads larger than a single
+//! message split into chunks and reassembled by the receiving VM.
+//!
+//! The node drops the outbound messages above its maximum message size
+//! without reporting back to the VM, so the sizes are checked here first.
+//! Requests and responses can not be chunked since a request ID is matched
+//! to exactly one response, so the oversized ones are rejected instead.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/constants#DefaultMaxMessageSize
+
+use std::{
+    collections::HashMap,
+    io,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    ids,
+    packer::Packer,
+    subnet::rpc::{common::appsender::AppSender, context::ctx_log},
+};
+use thiserror::Error;
+
+/// Maximum size of a message accepted by the node.
+pub const MAX_MESSAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// Room left for the p2p envelope around the app payload
+/// (op, chain ID, request ID, deadline, compression).
+pub const MESSAGE_OVERHEAD: usize = 1024;
+
+/// Version byte, message ID, chunk index, and chunk count.
+pub const CHUNK_HEADER_LEN: usize = 1 + 8 + 2 + 2;
+
+/// Version of the chunk framing.
+pub const CHUNK_VERSION: u8 = 0;
+
+/// Default maximum number of chunks per payload.
+pub const DEFAULT_MAX_CHUNKS: usize = 16;
+
+/// Default time to wait for the missing chunks of a payload.
+pub const DEFAULT_REASSEMBLY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default maximum number of payloads being reassembled at once.
+pub const DEFAULT_MAX_PENDING: usize = 256;
+
+/// Default maximum number of bytes buffered for the payloads being reassembled.
+pub const DEFAULT_MAX_BUFFERED_BYTES: usize = 64 * 1024 * 1024;
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("payload of {size} bytes exceeds the maximum of {max} bytes")]
+    PayloadTooLarge { size: usize, max: usize },
+    #[error("payload needs {chunks} chunks, more than the maximum of {max}")]
+    TooManyChunks { chunks: usize, max: usize },
+    #[error("invalid chunk ({0})")]
+    InvalidChunk(String),
+    #[error("payload of at least {size} bytes exceeds the reassembly buffer of {max} bytes")]
+    BufferFull { size: usize, max: usize },
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, e.to_string())
+    }
+}
+
+/// Returns the largest app payload that fits in a single message.
+pub fn max_payload_size(max_message_size: usize) -> usize {
+    max_message_size.saturating_sub(MESSAGE_OVERHEAD)
+}
+
+/// Returns an error if the payload does not fit in a single message.
+pub fn check_size(payload: &[u8], max_message_size: usize) -> Result<(), Error> {
+    let max = max_payload_size(max_message_size);
+    if payload.len() > max {
+        return Err(Error::PayloadTooLarge {
+            size: payload.len(),
+            max,
+        });
+    }
+    Ok(())
+}
+
+/// Wraps the app sender to check the message sizes,
+/// and to send the large gossip payloads in chunks.
+pub struct Sender {
+    inner: Box<dyn AppSender + Send + Sync>,
+    max_message_size: usize,
+    max_chunks: usize,
+    next_message_id: AtomicU64,
+}
+
+impl Sender {
+    pub fn new(inner: Box<dyn AppSender + Send + Sync>) -> Self {
+        Self {
+            inner,
+            max_message_size: MAX_MESSAGE_SIZE,
+            max_chunks: DEFAULT_MAX_CHUNKS,
+            next_message_id: AtomicU64::new(rand::random()),
+        }
+    }
+
+    /// Sets the maximum message size, for nodes configured with a lower limit.
+    #[must_use]
+    pub fn max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Sets the maximum number of chunks per gossip payload.
+    #[must_use]
+    pub fn max_chunks(mut self, max_chunks: usize) -> Self {
+        self.max_chunks = max_chunks;
+        self
+    }
+
+    /// Splits the payload into the framed chunks.
+    pub fn split(&self, payload: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+        let chunk_size = max_payload_size(self.max_message_size).saturating_sub(CHUNK_HEADER_LEN);
+        if chunk_size == 0 {
+            return Err(Error::PayloadTooLarge {
+                size: payload.len(),
+                max: 0,
+            });
+        }
+        let chunks = std::cmp::max(1, (payload.len() + chunk_size - 1) / chunk_size);
+        let max_chunks = self.max_chunks.min(u16::MAX as usize);
+        if chunks > max_chunks {
+            return Err(Error::TooManyChunks {
+                chunks,
+                max: max_chunks,
+            });
+        }
+
+        let message_id = self.next_message_id.fetch_add(1, Ordering::Relaxed);
+        let mut framed = Vec::with_capacity(chunks);
+        for index in 0..chunks {
+            let start = index * chunk_size;
+            let data = &payload[start..std::cmp::min(start + chunk_size, payload.len())];
+            let packer = Packer::new(CHUNK_HEADER_LEN + data.len(), CHUNK_HEADER_LEN + data.len());
+            packer
+                .pack_byte(CHUNK_VERSION)
+                .and_then(|_| packer.pack_u64(message_id))
+                .and_then(|_| packer.pack_u16(index as u16))
+                .and_then(|_| packer.pack_u16(chunks as u16))
+                .and_then(|_| packer.pack_bytes(data))
+                .map_err(|e| Error::InvalidChunk(e.to_string()))?;
+            framed.push(packer.take_bytes().to_vec());
+        }
+        Ok(framed)
+    }
+
+    /// Gossips the payload, in chunks if it exceeds a single message.
+    pub async fn send_app_gossip(&self, payload: &[u8]) -> io::Result<()> {
+        for chunk in self.split(payload)? {
+            self.inner.send_app_gossip(chunk).await?;
+        }
+        Ok(())
+    }
+
+    /// Gossips the payload to the nodes, in chunks if it exceeds a single message.
+    pub async fn send_app_gossip_specific(
+        &self,
+        node_ids: ids::node::Set,
+        payload: &[u8],
+    ) -> io::Result<()> {
+        for chunk in self.split(payload)? {
+            self.inner
+                .send_app_gossip_specific(node_ids.clone(), chunk)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Sends the request if it fits in a single message.
+    pub async fn send_app_request(
+        &self,
+        node_ids: ids::node::Set,
+        request_id: u32,
+        request: Vec<u8>,
+    ) -> io::Result<()> {
+        check_size(&request, self.max_message_size)?;
+        self.inner
+            .send_app_request(node_ids, request_id, request)
+            .await
+    }
+
+    /// Sends the response if it fits in a single message.
+    pub async fn send_app_response(
+        &self,
+        node_id: ids::node::Id,
+        request_id: u32,
+        response: Vec<u8>,
+    ) -> io::Result<()> {
+        check_size(&response, self.max_message_size)?;
+        self.inner
+            .send_app_response(node_id, request_id, response)
+            .await
+    }
+}
+
+struct Partial {
+    chunks: Vec<Option<Vec<u8>>>,
+    received: usize,
+    /// Bytes of the chunks received so far.
+    size: usize,
+    started: Instant,
+}
+
+/// Collects the chunks received from the peers into the original payloads.
+/// The number of payloads and the bytes buffered for them are bounded,
+/// and the oldest payloads are evicted to make room for the new chunks,
+/// so that the peers that never send the last chunk can not exhaust memory.
+pub struct Reassembler {
+    max_chunks: usize,
+    max_pending: usize,
+    max_buffered_bytes: usize,
+    timeout: Duration,
+    pending: HashMap<(ids::node::Id, u64), Partial>,
+    buffered_bytes: usize,
+}
+
+impl Default for Reassembler {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Reassembler {
+    pub fn default() -> Self {
+        Self::new(
+            DEFAULT_MAX_CHUNKS,
+            DEFAULT_MAX_PENDING,
+            DEFAULT_REASSEMBLY_TIMEOUT,
+        )
+    }
+
+    pub fn new(max_chunks: usize, max_pending: usize, timeout: Duration) -> Self {
+        Self {
+            max_chunks,
+            max_pending,
+            max_buffered_bytes: DEFAULT_MAX_BUFFERED_BYTES,
+            timeout,
+            pending: HashMap::new(),
+            buffered_bytes: 0,
+        }
+    }
+
+    /// Sets the maximum number of bytes buffered for the pending payloads.
+    #[must_use]
+    pub fn max_buffered_bytes(mut self, max_buffered_bytes: usize) -> Self {
+        self.max_buffered_bytes = max_buffered_bytes;
+        self
+    }
+
+    /// Returns the number of payloads waiting for more chunks.
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns the number of bytes buffered for the pending payloads.
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered_bytes
+    }
+
+    /// Adds the chunk gossiped by the node, and returns the payload
+    /// once all of its chunks have been received.
+    pub fn receive(
+        &mut self,
+        node_id: ids::node::Id,
+        msg: &[u8],
+    ) -> Result<Option<Vec<u8>>, Error> {
+        if msg.len() < CHUNK_HEADER_LEN {
+            return Err(Error::InvalidChunk(format!(
+                "{} bytes is shorter than the header",
+                msg.len()
+            )));
+        }
+        let packer = Packer::load_bytes_for_unpack(msg.len(), msg);
+        let unpack = || -> io::Result<(u8, u64, u16, u16, Vec<u8>)> {
+            Ok((
+                packer.unpack_byte()?,
+                packer.unpack_u64()?,
+                packer.unpack_u16()?,
+                packer.unpack_u16()?,
+                packer.unpack_bytes(msg.len() - CHUNK_HEADER_LEN)?,
+            ))
+        };
+        let (version, message_id, index, total, data) =
+            unpack().map_err(|e| Error::InvalidChunk(e.to_string()))?;
+        if version != CHUNK_VERSION {
+            return Err(Error::InvalidChunk(format!("unknown version {}", version)));
+        }
+        let (index, total) = (index as usize, total as usize);
+        if total == 0 || index >= total {
+            return Err(Error::InvalidChunk(format!(
+                "chunk index {} out of {}",
+                index, total
+            )));
+        }
+        if total > self.max_chunks {
+            return Err(Error::TooManyChunks {
+                chunks: total,
+                max: self.max_chunks,
+            });
+        }
+        if total == 1 {
+            return Ok(Some(data));
+        }
+
+        self.expire();
+        let key = (node_id, message_id);
+        if let Some(partial) = self.pending.get(&key) {
+            if partial.chunks.len() != total {
+                return Err(Error::InvalidChunk(format!(
+                    "chunk count {} != {}",
+                    total,
+                    partial.chunks.len()
+                )));
+            }
+            if partial.chunks[index].is_some() {
+                return Ok(None);
+            }
+        }
+
+        let size = self.pending.get(&key).map_or(0, |p| p.size) + data.len();
+        if size > self.max_buffered_bytes {
+            self.remove(&key);
+            return Err(Error::BufferFull {
+                size,
+                max: self.max_buffered_bytes,
+            });
+        }
+        if !self.pending.contains_key(&key) {
+            while self.pending.len() >= self.max_pending.max(1) {
+                self.evict_oldest(&key);
+            }
+        }
+        while self.buffered_bytes + data.len() > self.max_buffered_bytes {
+            self.evict_oldest(&key);
+        }
+
+        self.buffered_bytes += data.len();
+        let partial = self.pending.entry(key).or_insert_with(|| Partial {
+            chunks: vec![None; total],
+            received: 0,
+            size: 0,
+            started: Instant::now(),
+        });
+        partial.size += data.len();
+        partial.chunks[index] = Some(data);
+        partial.received += 1;
+        if partial.received < total {
+            return Ok(None);
+        }
+
+        let partial = self.remove(&key).expect("unexpected missing partial");
+        Ok(Some(
+            partial.chunks.into_iter().flatten().flatten().collect(),
+        ))
+    }
+
+    /// Drops the payloads whose chunks did not all arrive in time.
+    pub fn expire(&mut self) {
+        let timeout = self.timeout;
+        let mut expired = 0;
+        self.pending.retain(|_, partial| {
+            let keep = partial.started.elapsed() < timeout;
+            if !keep {
+                expired += partial.size;
+            }
+            keep
+        });
+        self.buffered_bytes -= expired;
+    }
+
+    fn remove(&mut self, key: &(ids::node::Id, u64)) -> Option<Partial> {
+        let partial = self.pending.remove(key)?;
+        self.buffered_bytes -= partial.size;
+        Some(partial)
+    }
+
+    /// Drops the payload that started the earliest, other than the one
+    /// being received (which fits on its own, as checked by the caller).
+    fn evict_oldest(&mut self, keep: &(ids::node::Id, u64)) {
+        let oldest = self
+            .pending
+            .iter()
+            .filter(|(k, _)| *k != keep)
+            .min_by_key(|(_, p)| p.started)
+            .map(|(k, _)| *k)
+            .expect("unexpected no payload to evict");
+        if let Some(partial) = self.remove(&oldest) {
+            ctx_log!(
+                warn,
+                "evicted the partial payload {} from {} ({} of {} chunks, {} bytes)",
+                oldest.1,
+                oldest.0,
+                partial.received,
+                partial.chunks.len(),
+                partial.size
+            );
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::common::appsender::chunk::test_chunk --exact --show-output
+#[tokio::test]
+async fn test_chunk() {
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<Vec<u8>>>>);
+    #[tonic::async_trait]
+    impl AppSender for Recorder {
+        async fn send_app_request(&self, _: ids::node::Set, _: u32, r: Vec<u8>) -> io::Result<()> {
+            self.0.lock().unwrap().push(r);
+            Ok(())
+        }
+        async fn send_app_response(&self, _: ids::node::Id, _: u32, r: Vec<u8>) -> io::Result<()> {
+            self.0.lock().unwrap().push(r);
+            Ok(())
+        }
+        async fn send_app_gossip(&self, msg: Vec<u8>) -> io::Result<()> {
+            self.0.lock().unwrap().push(msg);
+            Ok(())
+        }
+        async fn send_app_gossip_specific(
+            &self,
+            _: ids::node::Set,
+            msg: Vec<u8>,
+        ) -> io::Result<()> {
+            self.0.lock().unwrap().push(msg);
+            Ok(())
+        }
+        async fn send_cross_chain_app_request(
+            &self,
+            _: ids::Id,
+            _: u32,
+            _: Vec<u8>,
+        ) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_cross_chain_app_response(
+            &self,
+            _: ids::Id,
+            _: u32,
+            _: Vec<u8>,
+        ) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    // 100-byte payload per chunk
+    let max_message_size = MESSAGE_OVERHEAD + CHUNK_HEADER_LEN + 100;
+    let recorder = Recorder::default();
+    let sender = Sender::new(Box::new(recorder.clone()))
+        .max_message_size(max_message_size)
+        .max_chunks(3);
+
+    let payload: Vec<u8> = (0..250).map(|i| i as u8).collect();
+    sender.send_app_gossip(&payload).await.unwrap();
+    let mut sent = recorder.0.lock().unwrap().clone();
+    assert_eq!(sent.len(), 3);
+    assert!(sent
+        .iter()
+        .all(|c| c.len() <= max_payload_size(max_message_size)));
+
+    // reassembles out of order, once per payload
+    let node_id = ids::node::Id::empty();
+    let mut reassembler = Reassembler::new(3, 1, DEFAULT_REASSEMBLY_TIMEOUT);
+    sent.swap(0, 2);
+    assert_eq!(reassembler.receive(node_id, &sent[0]).unwrap(), None);
+    assert_eq!(reassembler.receive(node_id, &sent[0]).unwrap(), None);
+    assert_eq!(reassembler.pending(), 1);
+    assert_eq!(reassembler.receive(node_id, &sent[1]).unwrap(), None);
+    assert_eq!(
+        reassembler.receive(node_id, &sent[2]).unwrap(),
+        Some(payload.clone())
+    );
+    assert_eq!(reassembler.pending(), 0);
+
+    // single chunk and empty payloads are returned right away
+    let single = sender.split(b"hello").unwrap();
+    assert_eq!(single.len(), 1);
+    assert_eq!(
+        reassembler.receive(node_id, &single[0]).unwrap(),
+        Some(b"hello".to_vec())
+    );
+    let empty = sender.split(&[]).unwrap();
+    assert_eq!(
+        reassembler.receive(node_id, &empty[0]).unwrap(),
+        Some(vec![])
+    );
+
+    assert_eq!(
+        sender.split(&[0; 301]).unwrap_err(),
+        Error::TooManyChunks { chunks: 4, max: 3 }
+    );
+    assert_eq!(
+        sender
+            .send_app_request(ids::node::Set::new(), 1, vec![0; 200])
+            .await
+            .unwrap_err()
+            .kind(),
+        io::ErrorKind::InvalidInput
+    );
+    assert!(sender
+        .send_app_response(node_id, 1, vec![0; 100])
+        .await
+        .is_ok());
+    assert!(matches!(
+        check_size(&[0; 200], max_message_size),
+        Err(Error::PayloadTooLarge { size: 200, .. })
+    ));
+
+    assert!(matches!(
+        reassembler.receive(node_id, &[0; 3]),
+        Err(Error::InvalidChunk(_))
+    ));
+
+    // a new payload evicts the oldest pending one beyond "max_pending"
+    let first = sender.split(&payload).unwrap();
+    let second = sender.split(&payload).unwrap();
+    assert_eq!(reassembler.receive(node_id, &first[0]).unwrap(), None);
+    assert_eq!(reassembler.receive(node_id, &second[0]).unwrap(), None);
+    assert_eq!(reassembler.pending(), 1);
+    assert_eq!(reassembler.buffered_bytes(), 100);
+    assert_eq!(reassembler.receive(node_id, &second[1]).unwrap(), None);
+    assert_eq!(
+        reassembler.receive(node_id, &second[2]).unwrap(),
+        Some(payload.clone())
+    );
+    assert_eq!(reassembler.pending(), 0);
+    assert_eq!(reassembler.buffered_bytes(), 0);
+
+    // the oldest payloads are evicted to stay within the buffered bytes
+    let mut reassembler =
+        Reassembler::new(3, 8, DEFAULT_REASSEMBLY_TIMEOUT).max_buffered_bytes(200);
+    let third = sender.split(&payload).unwrap();
+    assert_eq!(reassembler.receive(node_id, &first[0]).unwrap(), None);
+    assert_eq!(reassembler.receive(node_id, &second[0]).unwrap(), None);
+    assert_eq!(reassembler.receive(node_id, &third[0]).unwrap(), None);
+    assert_eq!(reassembler.pending(), 2);
+    assert_eq!(reassembler.buffered_bytes(), 200);
+    assert_eq!(reassembler.receive(node_id, &third[1]).unwrap(), None);
+    assert_eq!(reassembler.pending(), 1);
+
+    // a payload larger than the whole buffer is dropped
+    assert_eq!(
+        reassembler.receive(node_id, &third[2]).unwrap_err(),
+        Error::BufferFull {
+            size: 250,
+            max: 200
+        }
+    );
+    assert_eq!(reassembler.pending(), 0);
+    assert_eq!(reassembler.buffered_bytes(), 0);
+}
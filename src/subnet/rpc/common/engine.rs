@@ -0,0 +1,452 @@
+//! Forwards the VM messages (e.g., "PendingTxs") to the consensus engine
+//! over the messenger service, with a bounded queue in between.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/rpcchainvm#VMServer.Initialize
+
+use std::{
+    collections::VecDeque,
+    io::{self, Error, ErrorKind},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    proto::pb::messenger::{messenger_client::MessengerClient, NotifyRequest},
+    subnet::rpc::{common::message::Message, context::ctx_log},
+};
+use tokio::{sync::mpsc, time::Instant};
+use tonic::transport::Channel;
+
+/// Default capacity of the engine message queue.
+pub const DEFAULT_CAPACITY: usize = 100;
+
+/// What to do when the VM sends a message and the queue is full.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Overflow {
+    /// The VM waits until the engine catches up ("send().await" blocks,
+    /// "try_send" fails).
+    Block,
+    /// The oldest queued message is dropped, so the VM never waits.
+    /// Safe for "PendingTxs" which only needs to be delivered once.
+    DropOldest,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Config {
+    pub capacity: usize,
+    pub overflow: Overflow,
+    /// Minimum interval between a block build and the next "PendingTxs",
+    /// if set (see "BuildThrottle").
+    pub build_throttle: Option<Duration>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl Config {
+    pub fn default() -> Self {
+        Self {
+            capacity: DEFAULT_CAPACITY,
+            overflow: Overflow::Block,
+            build_throttle: None,
+        }
+    }
+
+    /// Sets the queue capacity.
+    #[must_use]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets the overflow strategy.
+    #[must_use]
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Throttles the "PendingTxs" notifications to at most one per
+    /// block build, no sooner than the interval after the last build.
+    #[must_use]
+    pub fn build_throttle(mut self, min_interval: Duration) -> Self {
+        self.build_throttle = Some(min_interval);
+        self
+    }
+}
+
+/// Delivers a message to the engine.
+#[tonic::async_trait]
+pub trait Notifier: Send + 'static {
+    async fn notify(&mut self, msg: Message) -> io::Result<()>;
+}
+
+#[tonic::async_trait]
+impl Notifier for MessengerClient<Channel> {
+    async fn notify(&mut self, msg: Message) -> io::Result<()> {
+        MessengerClient::notify(
+            self,
+            NotifyRequest {
+                message: msg as u32,
+            },
+        )
+        .await
+        .map(|_| ())
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to notify engine '{}'", e)))
+    }
+}
+
+/// Counters and the last delivery error of the forwarder.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub forwarded: u64,
+    pub dropped: u64,
+    pub failed: u64,
+    /// Set when the last delivery failed, cleared on the next success.
+    pub last_error: Option<String>,
+}
+
+/// Shared view of the forwarder state, to report in the health check.
+#[derive(Debug, Clone, Default)]
+pub struct Health(Arc<Mutex<Stats>>);
+
+impl Health {
+    pub fn stats(&self) -> Stats {
+        self.0.lock().expect("unexpected poisoned lock").clone()
+    }
+
+    /// Returns the error if the engine could not be notified.
+    pub fn check(&self) -> io::Result<()> {
+        match self.stats().last_error {
+            Some(e) => Err(Error::new(
+                ErrorKind::Other,
+                format!("engine messages are not delivered '{}'", e),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Stats)) {
+        f(&mut self.0.lock().expect("unexpected poisoned lock"))
+    }
+}
+
+#[derive(Debug, Default)]
+struct ThrottleState {
+    /// Set once "PendingTxs" is delivered, until the engine builds a block.
+    notified: bool,
+    last_build: Option<Instant>,
+    throttled: u64,
+}
+
+/// Keeps the VMs under a flood of "PendingTxs" from building empty or
+/// redundant blocks: once the engine is notified, the next notifications
+/// are dropped until it builds a block, and the first one after the build
+/// is held back until the minimum interval has passed.
+/// The VM server calls "built" whenever the engine builds a block.
+#[derive(Debug, Clone, Default)]
+pub struct BuildThrottle(Arc<Mutex<ThrottleState>>);
+
+impl BuildThrottle {
+    /// Records that the engine built (or attempted to build) a block.
+    pub fn built(&self) {
+        let mut s = self.0.lock().expect("unexpected poisoned lock");
+        s.notified = false;
+        s.last_build = Some(Instant::now());
+    }
+
+    /// Returns the number of the "PendingTxs" dropped as redundant.
+    pub fn throttled(&self) -> u64 {
+        self.0.lock().expect("unexpected poisoned lock").throttled
+    }
+
+    /// Returns None if the notification is redundant, or how long
+    /// to hold it back otherwise.
+    fn admit(&self, min_interval: Duration) -> Option<Duration> {
+        let mut s = self.0.lock().expect("unexpected poisoned lock");
+        if s.notified {
+            s.throttled += 1;
+            return None;
+        }
+        s.notified = true;
+        let wait = s.last_build.map_or(Duration::ZERO, |t| {
+            (t + min_interval).saturating_duration_since(Instant::now())
+        });
+        Some(wait)
+    }
+
+    fn failed(&self) {
+        self.0.lock().expect("unexpected poisoned lock").notified = false;
+    }
+}
+
+/// Notifier that passes the "PendingTxs" through the build throttle.
+struct Throttled<N> {
+    inner: N,
+    throttle: BuildThrottle,
+    min_interval: Duration,
+}
+
+#[tonic::async_trait]
+impl<N: Notifier> Notifier for Throttled<N> {
+    async fn notify(&mut self, msg: Message) -> io::Result<()> {
+        if msg != Message::PendingTxs {
+            return self.inner.notify(msg).await;
+        }
+        let wait = match self.throttle.admit(self.min_interval) {
+            Some(wait) => wait,
+            None => {
+                ctx_log!(
+                    debug,
+                    "engine not built since the last pending txs, dropping"
+                );
+                return Ok(());
+            }
+        };
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        let res = self.inner.notify(msg).await;
+        if res.is_err() {
+            self.throttle.failed();
+        }
+        res
+    }
+}
+
+/// Creates the channel that the VM sends the engine messages to, and spawns
+/// the task that forwards them with the notifier. A delivery error is
+/// recorded in the health state and the next messages are still forwarded.
+pub fn spawn<N: Notifier>(config: Config, notifier: N, health: Health) -> mpsc::Sender<Message> {
+    spawn_with_throttle(config, notifier, health, BuildThrottle::default())
+}
+
+/// Same as "spawn", throttling the "PendingTxs" notifications with
+/// the build throttle if "Config::build_throttle" is set.
+pub fn spawn_with_throttle<N: Notifier>(
+    config: Config,
+    notifier: N,
+    health: Health,
+    throttle: BuildThrottle,
+) -> mpsc::Sender<Message> {
+    match config.build_throttle {
+        Some(min_interval) => spawn_forward(
+            config,
+            Throttled {
+                inner: notifier,
+                throttle,
+                min_interval,
+            },
+            health,
+        ),
+        None => spawn_forward(config, notifier, health),
+    }
+}
+
+fn spawn_forward<N: Notifier>(
+    config: Config,
+    notifier: N,
+    health: Health,
+) -> mpsc::Sender<Message> {
+    let capacity = config.capacity.max(1);
+    let (tx, rx) = mpsc::channel(capacity);
+    match config.overflow {
+        Overflow::Block => tokio::spawn(forward(rx, notifier, health)),
+        Overflow::DropOldest => tokio::spawn(forward_drop_oldest(rx, capacity, notifier, health)),
+    };
+    tx
+}
+
+async fn forward<N: Notifier>(mut rx: mpsc::Receiver<Message>, mut notifier: N, health: Health) {
+    while let Some(msg) = rx.recv().await {
+        deliver(&mut notifier, msg, &health).await;
+    }
+    ctx_log!(info, "engine message channel closed");
+}
+
+/// Keeps receiving from the VM while a message is being delivered,
+/// dropping the oldest queued message once the queue is full.
+async fn forward_drop_oldest<N: Notifier>(
+    mut rx: mpsc::Receiver<Message>,
+    capacity: usize,
+    mut notifier: N,
+    health: Health,
+) {
+    let mut queue: VecDeque<Message> = VecDeque::with_capacity(capacity);
+    let mut open = true;
+    loop {
+        let msg = match queue.pop_front() {
+            Some(msg) => msg,
+            None if open => match rx.recv().await {
+                Some(msg) => msg,
+                None => break,
+            },
+            None => break,
+        };
+
+        let delivery = deliver(&mut notifier, msg, &health);
+        tokio::pin!(delivery);
+        loop {
+            tokio::select! {
+                _ = &mut delivery => break,
+                received = rx.recv(), if open => match received {
+                    Some(msg) => {
+                        if queue.len() >= capacity {
+                            queue.pop_front();
+                            health.update(|s| s.dropped += 1);
+                        }
+                        queue.push_back(msg);
+                    }
+                    None => open = false,
+                },
+            }
+        }
+    }
+    ctx_log!(info, "engine message channel closed");
+}
+
+async fn deliver<N: Notifier>(notifier: &mut N, msg: Message, health: &Health) {
+    ctx_log!(debug, "message received: {:?}", msg);
+    match notifier.notify(msg).await {
+        Ok(_) => health.update(|s| {
+            s.forwarded += 1;
+            s.last_error = None;
+        }),
+        Err(e) => {
+            ctx_log!(warn, "failed to notify engine: {}", e);
+            health.update(|s| {
+                s.failed += 1;
+                s.last_error = Some(e.to_string());
+            });
+        }
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::common::engine::test_forward --exact --show-output
+#[tokio::test]
+async fn test_forward() {
+    use std::time::Duration;
+
+    /// Fails the first delivery, and waits for the gate before each one.
+    struct Gated {
+        gate: Arc<tokio::sync::Semaphore>,
+        delivered: mpsc::UnboundedSender<Message>,
+        calls: u64,
+    }
+    #[tonic::async_trait]
+    impl Notifier for Gated {
+        async fn notify(&mut self, msg: Message) -> io::Result<()> {
+            self.gate.acquire().await.unwrap().forget();
+            self.calls += 1;
+            if self.calls == 1 {
+                return Err(Error::new(ErrorKind::Other, "engine unavailable"));
+            }
+            self.delivered.send(msg).unwrap();
+            Ok(())
+        }
+    }
+
+    let gate = Arc::new(tokio::sync::Semaphore::new(0));
+    let (delivered_tx, mut delivered) = mpsc::unbounded_channel();
+    let health = Health::default();
+    let tx = spawn(
+        Config::default().capacity(2).overflow(Overflow::DropOldest),
+        Gated {
+            gate: gate.clone(),
+            delivered: delivered_tx,
+            calls: 0,
+        },
+        health.clone(),
+    );
+
+    // first message is in flight, the next 3 overflow the queue of 2
+    tx.send(Message::PendingTxs).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    tx.send(Message::PendingTxs).await.unwrap();
+    tx.send(Message::StateSyncDone).await.unwrap();
+    tx.send(Message::StopVertex).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(health.stats().dropped, 1);
+
+    // failed delivery is reported, and the forwarding goes on
+    gate.add_permits(1);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(health.check().is_err());
+    assert_eq!(health.stats().failed, 1);
+
+    gate.add_permits(2);
+    assert_eq!(delivered.recv().await, Some(Message::StateSyncDone));
+    assert_eq!(delivered.recv().await, Some(Message::StopVertex));
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(health.check().is_ok());
+    assert_eq!(
+        health.stats(),
+        Stats {
+            forwarded: 2,
+            dropped: 1,
+            failed: 1,
+            last_error: None,
+        }
+    );
+
+    // blocking channel applies the backpressure to the VM
+    let gate = Arc::new(tokio::sync::Semaphore::new(0));
+    let (delivered_tx, _delivered) = mpsc::unbounded_channel();
+    let health = Health::default();
+    let tx = spawn(
+        Config::default().capacity(1),
+        Gated {
+            gate,
+            delivered: delivered_tx,
+            calls: 0,
+        },
+        health.clone(),
+    );
+    tx.send(Message::PendingTxs).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    tx.send(Message::PendingTxs).await.unwrap();
+    assert!(tx.try_send(Message::PendingTxs).is_err());
+    assert_eq!(health.stats().dropped, 0);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::common::engine::test_build_throttle --exact --show-output
+#[tokio::test]
+async fn test_build_throttle() {
+    struct Recorder(mpsc::UnboundedSender<(Message, Instant)>);
+    #[tonic::async_trait]
+    impl Notifier for Recorder {
+        async fn notify(&mut self, msg: Message) -> io::Result<()> {
+            self.0.send((msg, Instant::now())).unwrap();
+            Ok(())
+        }
+    }
+
+    let (delivered_tx, mut delivered) = mpsc::unbounded_channel();
+    let throttle = BuildThrottle::default();
+    let min_interval = Duration::from_millis(100);
+    let tx = spawn_with_throttle(
+        Config::default().build_throttle(min_interval),
+        Recorder(delivered_tx),
+        Health::default(),
+        throttle.clone(),
+    );
+
+    // only the first notification goes through until the engine builds
+    for _ in 0..5 {
+        tx.send(Message::PendingTxs).await.unwrap();
+    }
+    tx.send(Message::StateSyncDone).await.unwrap();
+    assert_eq!(delivered.recv().await.unwrap().0, Message::PendingTxs);
+    assert_eq!(delivered.recv().await.unwrap().0, Message::StateSyncDone);
+    assert_eq!(throttle.throttled(), 4);
+
+    // the next one is held back until the interval after the build
+    throttle.built();
+    let built = Instant::now();
+    tx.send(Message::PendingTxs).await.unwrap();
+    let (msg, at) = delivered.recv().await.unwrap();
+    assert_eq!(msg, Message::PendingTxs);
+    assert!(at >= built + min_interval);
+}
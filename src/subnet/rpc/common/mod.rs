@@ -1,5 +1,6 @@
 pub mod apphandler;
 pub mod appsender;
+pub mod engine;
 pub mod http_handler;
 pub mod message;
 pub mod vm;
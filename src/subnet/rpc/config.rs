@@ -0,0 +1,150 @@
+//! VM configuration from the "config_bytes" of the initialize request,
+//! which avalanchego reads from the chain config file (e.g.,
+//! "~/.avalanchego/configs/chains/[chain ID]/config.json").
+//! ref. https://docs.avax.network/nodes/maintain/chain-config-flags
+//!
+//! The JSON object may carry a "version" field with the schema version,
+//! so that VMs can reject configs written for a schema they do not know.
+
+use std::io;
+
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+/// Name of the schema version field in the JSON object.
+pub const VERSION_FIELD: &str = "version";
+
+/// VM configuration read from the "config_bytes".
+///
+/// Missing fields should fall back to the defaults with "#[serde(default)]"
+/// on the struct. Empty "config_bytes" (i.e., no chain config file)
+/// is read as "Default::default()".
+pub trait Config: DeserializeOwned + Default {
+    /// Current schema version, assumed when the "version" field is missing.
+    const VERSION: u64;
+    /// Oldest schema version still accepted.
+    const MIN_VERSION: u64 = Self::VERSION;
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("config is not valid JSON at line {line} column {column} ({message})")]
+    Syntax {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[error("config field '{path}' is invalid ({message})")]
+    Field { path: String, message: String },
+    #[error("config version {version} is not supported (expected {min}..={max})")]
+    UnsupportedVersion { version: u64, min: u64, max: u64 },
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, e.to_string())
+    }
+}
+
+/// Parses the "config_bytes" into the VM config.
+/// Errors point at the offending JSON path (e.g., "gossip.max_size").
+pub fn parse<T: Config>(config_bytes: &[u8]) -> Result<T, Error> {
+    if config_bytes.iter().all(|b| b.is_ascii_whitespace()) {
+        return Ok(T::default());
+    }
+
+    let v: serde_json::Value = serde_json::from_slice(config_bytes).map_err(|e| Error::Syntax {
+        line: e.line(),
+        column: e.column(),
+        message: e.to_string(),
+    })?;
+
+    let version = match v.get(VERSION_FIELD) {
+        None => T::VERSION,
+        Some(ver) => ver.as_u64().ok_or_else(|| Error::Field {
+            path: VERSION_FIELD.to_string(),
+            message: format!("expected an unsigned integer, got {}", ver),
+        })?,
+    };
+    if version < T::MIN_VERSION || version > T::VERSION {
+        return Err(Error::UnsupportedVersion {
+            version,
+            min: T::MIN_VERSION,
+            max: T::VERSION,
+        });
+    }
+
+    serde_path_to_error::deserialize(v).map_err(|e| Error::Field {
+        path: e.path().to_string(),
+        message: e.into_inner().to_string(),
+    })
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::config::test_parse --exact --show-output
+#[test]
+fn test_parse() {
+    use serde::Deserialize;
+
+    #[derive(Debug, Default, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct Gossip {
+        max_size: u32,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    #[serde(default, deny_unknown_fields)]
+    struct VmConfig {
+        version: u64,
+        log_level: String,
+        gossip: Gossip,
+    }
+
+    impl Default for VmConfig {
+        fn default() -> Self {
+            Self {
+                version: 2,
+                log_level: String::from("info"),
+                gossip: Gossip::default(),
+            }
+        }
+    }
+
+    impl Config for VmConfig {
+        const VERSION: u64 = 2;
+        const MIN_VERSION: u64 = 1;
+    }
+
+    assert_eq!(parse::<VmConfig>(b"").unwrap(), VmConfig::default());
+    assert_eq!(parse::<VmConfig>(b" \n").unwrap(), VmConfig::default());
+
+    let cfg: VmConfig = parse(br#"{"version": 1, "gossip": {"max_size": 10}}"#).unwrap();
+    assert_eq!(cfg.version, 1);
+    assert_eq!(cfg.log_level, "info");
+    assert_eq!(cfg.gossip.max_size, 10);
+
+    let e = parse::<VmConfig>(br#"{"gossip": {"max_size": "ten"}}"#).unwrap_err();
+    match &e {
+        Error::Field { path, .. } => assert_eq!(path, "gossip.max_size"),
+        _ => panic!("unexpected error {}", e),
+    }
+    let e = parse::<VmConfig>(br#"{"gossip": {"max_sise": 1}}"#).unwrap_err();
+    assert!(e.to_string().contains("max_sise"));
+
+    assert_eq!(
+        parse::<VmConfig>(br#"{"version": 3}"#).unwrap_err(),
+        Error::UnsupportedVersion {
+            version: 3,
+            min: 1,
+            max: 2
+        }
+    );
+    assert!(matches!(
+        parse::<VmConfig>(br#"{"version": "2"}"#).unwrap_err(),
+        Error::Field { .. }
+    ));
+
+    let e = parse::<VmConfig>(b"{\n  \"log_level\": \"debug\",\n}").unwrap_err();
+    assert!(matches!(e, Error::Syntax { line: 3, .. }));
+    let e: io::Error = e.into();
+    assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+}
@@ -1,6 +1,6 @@
 use std::io::Result;
 
-use crate::{choices::status::Status, ids::Id};
+use crate::{choices::status::Status, ids::Id, subnet::rpc::snowman::block::Context};
 
 /// ref.https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/consensus/snowman#Block
 #[tonic::async_trait]
@@ -22,24 +22,26 @@ pub trait Block: Decidable + Initializer + StatusWriter + Sync + Send {
 
     /// Returns error if the block can not be verified.
     async fn verify(&mut self) -> Result<()>;
-}
-
-/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/choices#Decidable
-#[tonic::async_trait]
-pub trait Decidable {
-    /// Returns the ID of this block's parent.
-    async fn id(&self) -> Id;
 
-    /// Returns the current status.
-    async fn status(&self) -> Status;
-
-    /// Accepts this element.
-    async fn accept(&mut self) -> Result<()>;
-
-    /// Rejects this element.
-    async fn reject(&mut self) -> Result<()>;
+    /// Returns true if the block should be verified with "verify_with_context"
+    /// instead of "verify". Defaults to false.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/snowman/block#WithVerifyContext
+    async fn should_verify_with_context(&self) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Returns error if the block can not be verified with the block context.
+    /// Only called when "should_verify_with_context" returns true.
+    async fn verify_with_context(&mut self, _ctx: &Context) -> Result<()> {
+        self.verify().await
+    }
 }
 
+/// Same trait as "choices::decidable::AsyncDecidable", so that
+/// a "choices::decidable::Decidable" can be used as a block through
+/// "choices::decidable::AsyncAdapter".
+pub use crate::choices::decidable::AsyncDecidable as Decidable;
+
 #[tonic::async_trait]
 pub trait Initializer {
     /// Initializes the block.
@@ -1,13 +1,17 @@
+#[cfg(feature = "keystore")]
+use crate::proto::pb::keystore::keystore_client::KeystoreClient;
+#[cfg(feature = "sharedmemory")]
+use crate::proto::pb::sharedmemory::shared_memory_client::SharedMemoryClient;
 use crate::{
     ids::node::Id as NodeId,
     ids::Id,
     proto::pb::{
         aliasreader::alias_reader_client::AliasReaderClient,
-        keystore::keystore_client::KeystoreClient,
-        sharedmemory::shared_memory_client::SharedMemoryClient,
         subnetlookup::subnet_lookup_client::SubnetLookupClient,
     },
 };
+use std::{fmt, sync::RwLock};
+
 use tonic::transport::Channel;
 
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow#Context
@@ -19,9 +23,107 @@ pub struct Context {
     pub node_id: NodeId,
     pub x_chain_id: Id,
     pub avax_asset_id: Id,
+    /// Requires the "keystore" feature.
+    #[cfg(feature = "keystore")]
     pub keystore: KeystoreClient<Channel>,
+    /// Requires the "sharedmemory" feature.
+    #[cfg(feature = "sharedmemory")]
     pub shared_memory: SharedMemoryClient<Channel>,
     pub bc_lookup: AliasReaderClient<Channel>,
     pub sn_lookup: SubnetLookupClient<Channel>,
     // TODO metrics
 }
+
+impl Context {
+    /// Returns the keystore client with crate types.
+    #[cfg(feature = "keystore")]
+    pub fn keystore(&self) -> crate::subnet::rpc::keystore::Client {
+        crate::subnet::rpc::keystore::Client::new(self.keystore.clone())
+    }
+
+    /// Returns the shared memory client with crate types.
+    #[cfg(feature = "sharedmemory")]
+    pub fn shared_memory(&self) -> crate::subnet::rpc::sharedmemory::Client {
+        crate::subnet::rpc::sharedmemory::Client::new(self.shared_memory.clone())
+    }
+
+    /// Returns the IDs that tell the logs of this chain apart from
+    /// the other chains of the same node.
+    pub fn log_fields(&self) -> LogFields {
+        LogFields {
+            chain_id: self.chain_id,
+            subnet_id: self.subnet_id,
+            node_id: self.node_id,
+        }
+    }
+}
+
+/// Chain, subnet and node IDs that the VM plugin logs as the key-values
+/// "chain_id", "subnet_id" and "node_id" (see "ctx_log").
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct LogFields {
+    pub chain_id: Id,
+    pub subnet_id: Id,
+    pub node_id: NodeId,
+}
+
+impl fmt::Display for LogFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "chain_id={} subnet_id={} node_id={}",
+            self.chain_id, self.subnet_id, self.node_id
+        )
+    }
+}
+
+/// The node runs each VM plugin process for one chain, so the log fields
+/// are set once per process on "initialize".
+static LOG_FIELDS: RwLock<Option<LogFields>> = RwLock::new(None);
+
+/// Sets the log fields of the chain that the plugin process runs.
+pub fn set_log_fields(fields: LogFields) {
+    *LOG_FIELDS.write().expect("unexpected poisoned lock") = Some(fields);
+}
+
+/// Returns the log fields of the chain, once the VM is initialized.
+pub fn log_fields() -> Option<LogFields> {
+    *LOG_FIELDS.read().expect("unexpected poisoned lock")
+}
+
+/// Logs with the chain, subnet and node IDs as key-values once the VM is
+/// initialized, so that the logs of several chains on one node can be told
+/// apart, e.g., "ctx_log!(debug, "get response: {:?}", resp)".
+macro_rules! ctx_log {
+    ($lvl:ident, $($arg:tt)+) => {
+        match $crate::subnet::rpc::context::log_fields() {
+            Some(fields) => log::$lvl!(
+                chain_id:% = fields.chain_id,
+                subnet_id:% = fields.subnet_id,
+                node_id:% = fields.node_id;
+                $($arg)+
+            ),
+            None => log::$lvl!($($arg)+),
+        }
+    };
+}
+pub(crate) use ctx_log;
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::context::test_log_fields --exact --show-output
+#[test]
+fn test_log_fields() {
+    let fields = LogFields {
+        chain_id: Id::from_slice(&[1]),
+        subnet_id: Id::empty(),
+        node_id: NodeId::from_slice(&[2; 20]),
+    };
+    let s = fields.to_string();
+    assert!(s.starts_with(&format!("chain_id={} ", Id::from_slice(&[1]))));
+    assert!(s.contains(&format!("subnet_id={}", Id::empty())));
+    assert!(s.ends_with(&format!("node_id={}", NodeId::from_slice(&[2; 20]))));
+
+    ctx_log!(debug, "before initialize {}", 1);
+    set_log_fields(fields);
+    assert_eq!(log_fields(), Some(fields));
+    ctx_log!(debug, "after initialize {}", 2);
+}
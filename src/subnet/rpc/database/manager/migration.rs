@@ -0,0 +1,153 @@
+//! Helpers for VMs that migrate their on-disk state from the previous
+//! database version to the current one on startup.
+//!
+//! Completion is recorded as a marker key in the current database,
+//! so an interrupted migration is re-run on the next start and a finished
+//! one is never run twice.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/database/manager#Manager
+
+use std::{future::Future, io};
+
+use crate::subnet::rpc::{
+    context::ctx_log,
+    database::{
+        errors,
+        manager::{versioned_database::VersionedDatabase, Manager},
+        Database,
+    },
+};
+use semver::Version;
+
+/// Prefix of the marker keys, followed by the database version.
+pub const MARKER_PREFIX: &[u8] = b"migration_complete:";
+
+/// Returns the marker key of the migration to the version.
+pub fn marker_key(version: &Version) -> Vec<u8> {
+    let mut key = MARKER_PREFIX.to_vec();
+    key.extend_from_slice(version.to_string().as_bytes());
+    key
+}
+
+/// Returns true if the migration to the database version was completed.
+pub async fn is_migrated(db: &VersionedDatabase) -> io::Result<bool> {
+    db.db.has(&marker_key(&db.version)).await
+}
+
+/// Records the migration to the database version as completed.
+pub async fn mark_migrated(db: &mut VersionedDatabase) -> io::Result<()> {
+    let key = marker_key(&db.version);
+    db.db.put(&key, &[1]).await
+}
+
+/// Copies the values of the keys from one database to the other,
+/// skipping the keys that do not exist. Returns the number of copied keys.
+pub async fn copy_keys(
+    from: &(dyn Database + Send + Sync),
+    to: &mut Box<dyn Database + Send + Sync>,
+    keys: &[Vec<u8>],
+) -> io::Result<usize> {
+    let mut copied = 0;
+    for key in keys.iter() {
+        let value = match from.get(key).await {
+            Ok(v) => v,
+            Err(e) if errors::is_not_found(&e) => continue,
+            Err(e) => return Err(e),
+        };
+        to.put(key, &value).await?;
+        copied += 1;
+    }
+    Ok(copied)
+}
+
+/// Runs the migration from the previous to the current database,
+/// unless it was already completed, and marks the current database as migrated.
+/// Returns true if the migration ran. A manager without a previous database
+/// has nothing to migrate, so the current database is only marked.
+pub async fn migrate<F, Fut>(manager: &(dyn Manager + Send + Sync), f: F) -> io::Result<bool>
+where
+    F: FnOnce(VersionedDatabase, VersionedDatabase) -> Fut,
+    Fut: Future<Output = io::Result<()>>,
+{
+    let mut current = manager.current().await?;
+    if is_migrated(&current).await? {
+        return Ok(false);
+    }
+
+    let ran = match manager.previous().await {
+        Some(previous) => {
+            ctx_log!(
+                info,
+                "migrating database from version {} to {}",
+                previous.version,
+                current.version
+            );
+            f(previous, current.clone()).await?;
+            true
+        }
+        None => false,
+    };
+    mark_migrated(&mut current).await?;
+    Ok(ran)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::database::manager::migration::test_migrate --exact --show-output
+#[tokio::test]
+async fn test_migrate() {
+    use crate::subnet::rpc::database::{manager::DatabaseManager, memdb};
+
+    let mut previous = VersionedDatabase::new(memdb::Database::new(), Version::new(1, 0, 0));
+    previous.db.put(b"foo", b"bar").await.unwrap();
+    let current = VersionedDatabase::new(memdb::Database::new(), Version::new(1, 4, 5));
+    let manager = DatabaseManager::new_from_databases(vec![previous, current]);
+
+    let ran = migrate(manager.as_ref(), |previous, mut current| async move {
+        let copied = copy_keys(
+            previous.db.as_ref(),
+            &mut current.db,
+            &[b"foo".to_vec(), b"missing".to_vec()],
+        )
+        .await?;
+        assert_eq!(copied, 1);
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert!(ran);
+
+    let current = manager.current().await.unwrap();
+    assert_eq!(current.db.get(b"foo").await.unwrap(), b"bar".to_vec());
+    assert!(is_migrated(&current).await.unwrap());
+
+    // already migrated
+    let ran = migrate(manager.as_ref(), |_, _| async move {
+        panic!("must not migrate twice")
+    })
+    .await
+    .unwrap();
+    assert!(!ran);
+
+    // a failed migration is not marked
+    let current = VersionedDatabase::new(memdb::Database::new(), Version::new(1, 5, 0));
+    let previous = VersionedDatabase::new(memdb::Database::new(), Version::new(1, 4, 5));
+    let manager = DatabaseManager::new_from_databases(vec![current, previous]);
+    assert!(migrate(manager.as_ref(), |_, _| async move {
+        Err(io::Error::new(io::ErrorKind::Other, "boom"))
+    })
+    .await
+    .is_err());
+    assert!(!is_migrated(&manager.current().await.unwrap())
+        .await
+        .unwrap());
+
+    // nothing to migrate
+    let manager = DatabaseManager::new_from_databases(vec![VersionedDatabase::new(
+        memdb::Database::new(),
+        Version::new(1, 0, 0),
+    )]);
+    assert!(!migrate(manager.as_ref(), |_, _| async move { Ok(()) })
+        .await
+        .unwrap());
+    assert!(is_migrated(&manager.current().await.unwrap())
+        .await
+        .unwrap());
+}
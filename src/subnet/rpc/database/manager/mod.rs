@@ -1,3 +1,4 @@
+pub mod migration;
 pub mod versioned_database;
 
 use std::{
@@ -6,6 +7,7 @@ use std::{
 };
 
 use crate::subnet::rpc::database::manager::versioned_database::VersionedDatabase;
+use semver::Version;
 use tokio::sync::RwLock;
 
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/database/manager#Manager
@@ -13,6 +15,16 @@ use tokio::sync::RwLock;
 pub trait Manager {
     async fn current(&self) -> io::Result<VersionedDatabase>;
     async fn previous(&self) -> Option<VersionedDatabase>;
+    async fn get_databases(&self) -> Vec<VersionedDatabase>;
+
+    /// Returns the database with the exact version, if any.
+    async fn get_database(&self, version: &Version) -> Option<VersionedDatabase> {
+        self.get_databases()
+            .await
+            .into_iter()
+            .find(|d| &d.version == version)
+    }
+
     async fn close(&self) -> io::Result<()>;
 }
 
@@ -23,10 +35,17 @@ pub struct DatabaseManager {
 
 impl DatabaseManager {
     /// Returns a database manager from a Vec of versioned database.
+    /// The databases are ordered from the newest to the oldest version,
+    /// as the avalanchego manager keeps them.
     pub fn new_from_databases(databases: Vec<VersionedDatabase>) -> Box<dyn Manager + Send + Sync> {
-        Box::new(DatabaseManager {
+        Box::new(Self::from_databases(databases))
+    }
+
+    fn from_databases(mut databases: Vec<VersionedDatabase>) -> Self {
+        databases.sort_by(|a, b| b.version.cmp(&a.version));
+        DatabaseManager {
             inner: Arc::new(RwLock::new(databases)),
-        })
+        }
     }
 }
 
@@ -35,7 +54,13 @@ impl Manager for DatabaseManager {
     /// Returns the database with the current database version.
     async fn current(&self) -> io::Result<VersionedDatabase> {
         let databases = self.inner.read().await;
-        return Ok(databases[0].clone());
+        match databases.first() {
+            Some(db) => Ok(db.clone()),
+            None => Err(Error::new(
+                ErrorKind::NotFound,
+                "database manager has no database",
+            )),
+        }
     }
 
     /// Returns the database prior to the current database and true if a
@@ -49,6 +74,12 @@ impl Manager for DatabaseManager {
         return Some(databases[1].clone());
     }
 
+    /// Returns all the databases sorted from the current to the oldest version.
+    async fn get_databases(&self) -> Vec<VersionedDatabase> {
+        let databases = self.inner.read().await;
+        databases.clone()
+    }
+
     /// Close all of the databases controlled by the manager.
     async fn close(&self) -> io::Result<()> {
         let dbs = self.inner.read().await;
@@ -71,3 +102,46 @@ impl Manager for DatabaseManager {
         Ok(())
     }
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::database::manager::test_database_manager --exact --show-output
+#[tokio::test]
+async fn test_database_manager() {
+    use crate::subnet::rpc::database::memdb;
+
+    let manager = DatabaseManager::from_databases(Vec::new());
+    match manager.current().await {
+        Ok(_) => panic!("expected no current database"),
+        Err(e) => assert_eq!(e.kind(), ErrorKind::NotFound),
+    }
+    assert!(manager.previous().await.is_none());
+
+    let manager = DatabaseManager::from_databases(vec![
+        VersionedDatabase::new(memdb::Database::new(), Version::new(1, 0, 0)),
+        VersionedDatabase::new(memdb::Database::new(), Version::new(1, 4, 5)),
+        VersionedDatabase::new(memdb::Database::new(), Version::new(1, 0, 3)),
+    ]);
+    assert_eq!(
+        manager.current().await.unwrap().version,
+        Version::new(1, 4, 5)
+    );
+    assert_eq!(
+        manager.previous().await.unwrap().version,
+        Version::new(1, 0, 3)
+    );
+    let versions: Vec<Version> = manager
+        .get_databases()
+        .await
+        .into_iter()
+        .map(|d| d.version)
+        .collect();
+    assert_eq!(
+        versions,
+        vec![
+            Version::new(1, 4, 5),
+            Version::new(1, 0, 3),
+            Version::new(1, 0, 0)
+        ]
+    );
+    assert!(manager.get_database(&Version::new(1, 0, 0)).await.is_some());
+    assert!(manager.get_database(&Version::new(1, 0, 1)).await.is_none());
+}
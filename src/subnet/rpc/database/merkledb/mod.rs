@@ -0,0 +1,429 @@
+//! Merkleized key-value store layered over the Database trait.
+//!
+//! Keys are hashed into a compact sparse Merkle trie, where a subtree with a
+//! single leaf collapses into that leaf. The root changes with every write
+//! and can be embedded in blocks, and inclusion proofs can be verified
+//! against the root without access to the database.
+//!
+//! Writes are buffered in memory until "commit", which flushes the values
+//! together with the leaf index, so that the underlying database never holds
+//! the values that the persisted index does not know about.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::{self, Error, ErrorKind},
+    sync::Arc,
+};
+
+use crate::{ids, packer::Packer, subnet::rpc::database::errors};
+use tokio::sync::RwLock;
+
+/// Prefix for the user values in the underlying database.
+const VALUE_PREFIX: u8 = 0x00;
+/// Prefix for the leaf index entries, keyed by the big-endian slot number.
+const INDEX_PREFIX: u8 = 0x01;
+/// Key of the number of the leaf index entries.
+const INDEX_LEN_KEY: &[u8] = &[0x02];
+
+const LEAF_TAG: u8 = 0x00;
+const NODE_TAG: u8 = 0x01;
+
+/// Trie leaf indexed by the key hash.
+#[derive(Clone, Debug)]
+struct Leaf {
+    key: Vec<u8>,
+    value_hash: ids::Id,
+    /// Position of the leaf in the persisted index.
+    slot: u32,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    leaves: BTreeMap<ids::Id, Leaf>,
+    /// Key hashes of the leaves by their index slot.
+    slots: Vec<ids::Id>,
+    /// Uncommitted writes, where "None" is a deletion.
+    pending: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
+    /// Index slots to rewrite (or delete, if past the end) on commit.
+    dirty_slots: BTreeSet<u32>,
+}
+
+impl State {
+    fn root(&self) -> ids::Id {
+        let nodes: Vec<(&ids::Id, &Leaf)> = self.leaves.iter().collect();
+        subtree_root(&nodes, 0)
+    }
+}
+
+#[derive(Clone)]
+pub struct Database {
+    db: Box<dyn crate::subnet::rpc::database::Database + Send + Sync>,
+    state: Arc<RwLock<State>>,
+}
+
+impl Database {
+    /// Opens the merkleized store, loading the leaf index
+    /// last persisted with "commit" if any.
+    pub async fn open(
+        db: Box<dyn crate::subnet::rpc::database::Database + Send + Sync>,
+    ) -> io::Result<Self> {
+        let n = match db.get(INDEX_LEN_KEY).await {
+            Ok(b) => {
+                let b: [u8; 4] = b.as_slice().try_into().map_err(|_| {
+                    Error::new(
+                        ErrorKind::InvalidData,
+                        format!("invalid leaf index length bytes {}", b.len()),
+                    )
+                })?;
+                u32::from_be_bytes(b)
+            }
+            Err(e) if errors::is_not_found(&e) => 0,
+            Err(e) => return Err(e),
+        };
+
+        let mut state = State::default();
+        for slot in 0..n {
+            let b = db.get(&Self::index_key(slot)).await?;
+            let packer = Packer::load_bytes_for_unpack(b.len(), &b);
+            let key = packer.unpack_bytes_with_header()?;
+            let value_hash = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+
+            let key_hash = ids::Id::sha256(&key);
+            state.slots.push(key_hash);
+            state.leaves.insert(
+                key_hash,
+                Leaf {
+                    key,
+                    value_hash,
+                    slot,
+                },
+            );
+        }
+
+        Ok(Self {
+            db,
+            state: Arc::new(RwLock::new(state)),
+        })
+    }
+
+    /// Flushes the buffered writes and the changed leaf index entries,
+    /// and returns the current root. The index length is written last,
+    /// so the trailing entries of a shrunk index are never loaded.
+    pub async fn commit(&mut self) -> io::Result<ids::Id> {
+        let mut state = self.state.write().await;
+
+        for (key, value) in state.pending.iter() {
+            match value {
+                Some(v) => self.db.put(&Self::value_key(key), v).await?,
+                None => self.db.delete(&Self::value_key(key)).await?,
+            }
+        }
+        for slot in state.dirty_slots.iter() {
+            match state.slots.get(*slot as usize) {
+                Some(key_hash) => {
+                    let leaf = &state.leaves[key_hash];
+                    let packer = Packer::new(4 + leaf.key.len() + ids::LEN, 0);
+                    packer.pack_bytes_with_header(&leaf.key)?;
+                    packer.pack_bytes(leaf.value_hash.as_ref())?;
+                    self.db
+                        .put(&Self::index_key(*slot), &packer.take_bytes())
+                        .await?;
+                }
+                None => self.db.delete(&Self::index_key(*slot)).await?,
+            }
+        }
+        self.db
+            .put(INDEX_LEN_KEY, &(state.slots.len() as u32).to_be_bytes())
+            .await?;
+
+        state.pending.clear();
+        state.dirty_slots.clear();
+        Ok(state.root())
+    }
+
+    /// Returns the Merkle root of the current state.
+    /// The empty store has the empty Id as its root.
+    pub async fn root(&self) -> ids::Id {
+        self.state.read().await.root()
+    }
+
+    /// Generates the inclusion proof for the key.
+    pub async fn prove(&self, key: &[u8]) -> io::Result<Proof> {
+        let key_hash = ids::Id::sha256(key);
+        let state = self.state.read().await;
+        let leaf = state.leaves.get(&key_hash).ok_or_else(errors::not_found)?;
+
+        let mut nodes: Vec<(&ids::Id, &Leaf)> = state.leaves.iter().collect();
+        let mut siblings = Vec::new();
+        let mut depth = 0;
+        while nodes.len() > 1 {
+            let (left, right): (Vec<_>, Vec<_>) =
+                nodes.into_iter().partition(|(h, _)| !bit(h, depth));
+            if bit(&key_hash, depth) {
+                siblings.push(subtree_root(&left, depth + 1));
+                nodes = right;
+            } else {
+                siblings.push(subtree_root(&right, depth + 1));
+                nodes = left;
+            }
+            depth += 1;
+        }
+
+        Ok(Proof {
+            key: key.to_vec(),
+            value_hash: leaf.value_hash,
+            siblings,
+        })
+    }
+
+    fn value_key(key: &[u8]) -> Vec<u8> {
+        let mut k = Vec::with_capacity(1 + key.len());
+        k.push(VALUE_PREFIX);
+        k.extend_from_slice(key);
+        k
+    }
+
+    fn index_key(slot: u32) -> Vec<u8> {
+        let mut k = Vec::with_capacity(5);
+        k.push(INDEX_PREFIX);
+        k.extend_from_slice(&slot.to_be_bytes());
+        k
+    }
+}
+
+/// Inclusion proof of a key-value pair against a Merkle root.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proof {
+    pub key: Vec<u8>,
+    pub value_hash: ids::Id,
+    /// Sibling subtree roots from the top of the trie down to the leaf.
+    pub siblings: Vec<ids::Id>,
+}
+
+impl Proof {
+    /// Verifies that the value is stored under the key for the root.
+    pub fn verify(&self, root: &ids::Id, value: &[u8]) -> io::Result<()> {
+        if ids::Id::sha256(value) != self.value_hash {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "value does not match the proof",
+            ));
+        }
+
+        let key_hash = ids::Id::sha256(&self.key);
+        let mut h = leaf_hash(&key_hash, &self.value_hash);
+        for (depth, sibling) in self.siblings.iter().enumerate().rev() {
+            h = if bit(&key_hash, depth) {
+                node_hash(sibling, &h)
+            } else {
+                node_hash(&h, sibling)
+            };
+        }
+        if h != *root {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("proof root {} does not match expected root {}", h, root),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Returns the bit of the key hash at the depth, from the most significant bit.
+fn bit(h: &ids::Id, depth: usize) -> bool {
+    (h.as_ref()[depth / 8] >> (7 - depth % 8)) & 1 == 1
+}
+
+fn leaf_hash(key_hash: &ids::Id, value_hash: &ids::Id) -> ids::Id {
+    let mut b = Vec::with_capacity(1 + 2 * ids::LEN);
+    b.push(LEAF_TAG);
+    b.extend_from_slice(key_hash.as_ref());
+    b.extend_from_slice(value_hash.as_ref());
+    ids::Id::sha256(b)
+}
+
+fn node_hash(left: &ids::Id, right: &ids::Id) -> ids::Id {
+    let mut b = Vec::with_capacity(1 + 2 * ids::LEN);
+    b.push(NODE_TAG);
+    b.extend_from_slice(left.as_ref());
+    b.extend_from_slice(right.as_ref());
+    ids::Id::sha256(b)
+}
+
+/// Computes the root of the subtree with the leaves that share
+/// the same key hash prefix up to the depth.
+fn subtree_root(nodes: &[(&ids::Id, &Leaf)], depth: usize) -> ids::Id {
+    match nodes.len() {
+        0 => ids::Id::empty(),
+        1 => leaf_hash(nodes[0].0, &nodes[0].1.value_hash),
+        _ => {
+            // leaves are sorted by the key hash, so the right subtree starts
+            // at the first leaf with the bit set
+            let mid = nodes.partition_point(|(h, _)| !bit(h, depth));
+            node_hash(
+                &subtree_root(&nodes[..mid], depth + 1),
+                &subtree_root(&nodes[mid..], depth + 1),
+            )
+        }
+    }
+}
+
+impl crate::subnet::rpc::database::Database for Database {}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::KeyValueReaderWriterDeleter for Database {
+    async fn has(&self, key: &[u8]) -> io::Result<bool> {
+        if let Some(value) = self.state.read().await.pending.get(key) {
+            return Ok(value.is_some());
+        }
+        self.db.has(&Self::value_key(key)).await
+    }
+
+    async fn get(&self, key: &[u8]) -> io::Result<Vec<u8>> {
+        if let Some(value) = self.state.read().await.pending.get(key) {
+            return value.clone().ok_or_else(errors::not_found);
+        }
+        self.db.get(&Self::value_key(key)).await
+    }
+
+    /// Buffers the value until "commit", and updates the trie leaf.
+    async fn put(&mut self, key: &[u8], value: &[u8]) -> io::Result<()> {
+        let mut state = self.state.write().await;
+        state.pending.insert(key.to_vec(), Some(value.to_vec()));
+
+        let key_hash = ids::Id::sha256(key);
+        let value_hash = ids::Id::sha256(value);
+        let slot = match state.leaves.get_mut(&key_hash) {
+            Some(leaf) => {
+                leaf.value_hash = value_hash;
+                leaf.slot
+            }
+            None => {
+                let slot = state.slots.len() as u32;
+                state.slots.push(key_hash);
+                state.leaves.insert(
+                    key_hash,
+                    Leaf {
+                        key: key.to_vec(),
+                        value_hash,
+                        slot,
+                    },
+                );
+                slot
+            }
+        };
+        state.dirty_slots.insert(slot);
+        Ok(())
+    }
+
+    /// Buffers the deletion until "commit", and removes the trie leaf.
+    /// The last index slot moves into the freed one.
+    async fn delete(&mut self, key: &[u8]) -> io::Result<()> {
+        let mut state = self.state.write().await;
+        state.pending.insert(key.to_vec(), None);
+
+        let leaf = match state.leaves.remove(&ids::Id::sha256(key)) {
+            Some(leaf) => leaf,
+            None => return Ok(()),
+        };
+        let last = (state.slots.len() - 1) as u32;
+        state.slots.swap_remove(leaf.slot as usize);
+        if leaf.slot != last {
+            let moved = state.slots[leaf.slot as usize];
+            if let Some(l) = state.leaves.get_mut(&moved) {
+                l.slot = leaf.slot;
+            }
+        }
+        state.dirty_slots.insert(leaf.slot);
+        state.dirty_slots.insert(last);
+        Ok(())
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::database::Closer for Database {
+    async fn close(&self) -> io::Result<()> {
+        self.db.close().await
+    }
+}
+
+#[tonic::async_trait]
+impl crate::subnet::rpc::health::Checkable for Database {
+    async fn health_check(&self) -> io::Result<Vec<u8>> {
+        self.db.health_check().await
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::database::merkledb::test_merkledb --exact --show-output
+#[tokio::test]
+async fn test_merkledb() {
+    use crate::subnet::rpc::database::{memdb, KeyValueReaderWriterDeleter};
+
+    let inner = memdb::Database::new();
+    let mut db = Database::open(inner.clone()).await.unwrap();
+    assert_eq!(db.root().await, ids::Id::empty());
+
+    db.put(b"foo", b"bar").await.unwrap();
+    let root1 = db.root().await;
+    assert_ne!(root1, ids::Id::empty());
+    assert_eq!(db.get(b"foo").await.unwrap(), b"bar".to_vec());
+
+    db.put(b"hello", b"world").await.unwrap();
+    db.put(b"a", b"b").await.unwrap();
+    let root2 = db.root().await;
+    assert_ne!(root1, root2);
+
+    // same content, different insertion order, same root
+    let mut other = Database::open(memdb::Database::new()).await.unwrap();
+    other.put(b"a", b"b").await.unwrap();
+    other.put(b"hello", b"world").await.unwrap();
+    other.put(b"foo", b"bar").await.unwrap();
+    assert_eq!(other.root().await, root2);
+
+    for (k, v) in [
+        (b"foo".to_vec(), b"bar".to_vec()),
+        (b"a".to_vec(), b"b".to_vec()),
+    ] {
+        let proof = db.prove(&k).await.unwrap();
+        assert!(proof.verify(&root2, &v).is_ok());
+        assert!(proof.verify(&root2, b"wrong").is_err());
+        assert!(proof.verify(&root1, &v).is_err());
+    }
+    assert!(db.prove(b"missing").await.is_err());
+
+    db.delete(b"hello").await.unwrap();
+    db.delete(b"a").await.unwrap();
+    assert_eq!(db.root().await, root1);
+    assert!(!db.has(b"a").await.unwrap());
+
+    // re-open from the persisted index
+    let committed = db.commit().await.unwrap();
+    let reopened = Database::open(inner.clone()).await.unwrap();
+    assert_eq!(reopened.root().await, committed);
+    assert_eq!(reopened.get(b"foo").await.unwrap(), b"bar".to_vec());
+
+    // uncommitted writes are readable, but never reach the underlying database
+    db.put(b"foo", b"baz").await.unwrap();
+    db.put(b"x", b"y").await.unwrap();
+    assert_eq!(db.get(b"foo").await.unwrap(), b"baz".to_vec());
+    assert!(db.has(b"x").await.unwrap());
+    let reopened = Database::open(inner.clone()).await.unwrap();
+    assert_eq!(reopened.root().await, committed);
+    assert_eq!(reopened.get(b"foo").await.unwrap(), b"bar".to_vec());
+    assert!(!reopened.has(b"x").await.unwrap());
+
+    // deleting from the middle of the index moves the last leaf into its slot
+    db.put(b"hello", b"world").await.unwrap();
+    db.delete(b"x").await.unwrap();
+    assert!(db.get(b"x").await.is_err());
+    let committed = db.commit().await.unwrap();
+    db.delete(b"foo").await.unwrap();
+    db.put(b"a", b"b").await.unwrap();
+    let committed2 = db.commit().await.unwrap();
+    assert_ne!(committed, committed2);
+    let reopened = Database::open(inner).await.unwrap();
+    assert_eq!(reopened.root().await, committed2);
+    assert!(!reopened.has(b"foo").await.unwrap());
+    assert_eq!(reopened.get(b"hello").await.unwrap(), b"world".to_vec());
+    assert_eq!(reopened.get(b"a").await.unwrap(), b"b".to_vec());
+}
@@ -2,6 +2,7 @@ pub mod corruptabledb;
 pub mod errors;
 pub mod manager;
 pub mod memdb;
+pub mod merkledb;
 pub mod rpcdb;
 
 use std::io::Result;
@@ -8,9 +8,8 @@ use crate::{
             GetRequest, PutRequest,
         },
     },
-    subnet::rpc::database::{errors, DatabaseError},
+    subnet::rpc::{context::ctx_log, database::errors, errors as rpc_errors},
 };
-use num_traits::FromPrimitive;
 use prost::bytes::Bytes;
 use tonic::transport::Channel;
 
@@ -46,12 +45,10 @@ impl crate::subnet::rpc::database::KeyValueReaderWriterDeleter for DatabaseClien
             .map_err(|e| Error::new(ErrorKind::Other, format!("has request failed: {:?}", e)))?
             .into_inner();
 
-        let err = DatabaseError::from_u32(resp.err);
-        match err {
-            Some(DatabaseError::Closed) => Err(errors::database_closed()),
-            Some(DatabaseError::NotFound) => Ok(false),
-            Some(DatabaseError::None) => Ok(true),
-            _ => Err(Error::new(ErrorKind::Other, "unexpected database error")),
+        match rpc_errors::from_code(resp.err) {
+            Ok(()) => Ok(true),
+            Err(e) if errors::is_not_found(&e) => Ok(false),
+            Err(e) => Err(e),
         }
     }
 
@@ -66,14 +63,9 @@ impl crate::subnet::rpc::database::KeyValueReaderWriterDeleter for DatabaseClien
             .map_err(|e| Error::new(ErrorKind::Other, format!("get request failed: {:?}", e)))?
             .into_inner();
 
-        log::debug!("get response: {:?}", resp);
-        let err = DatabaseError::from_u32(resp.err);
-        match err {
-            Some(DatabaseError::None) => Ok(resp.value.to_vec()),
-            Some(DatabaseError::Closed) => Err(errors::database_closed()),
-            Some(DatabaseError::NotFound) => Err(errors::not_found()),
-            _ => Err(Error::new(ErrorKind::Other, "unexpected database error")),
-        }
+        ctx_log!(debug, "get response: {:?}", resp);
+        rpc_errors::from_code(resp.err)?;
+        Ok(resp.value.to_vec())
     }
 
     /// Attempts to set the value this key maps to.
@@ -88,13 +80,7 @@ impl crate::subnet::rpc::database::KeyValueReaderWriterDeleter for DatabaseClien
             .map_err(|e| Error::new(ErrorKind::Other, format!("put request failed: {:?}", e)))?
             .into_inner();
 
-        let err = DatabaseError::from_u32(resp.err);
-        match err {
-            Some(DatabaseError::None) => Ok(()),
-            Some(DatabaseError::Closed) => Err(errors::database_closed()),
-            Some(DatabaseError::NotFound) => Err(errors::not_found()),
-            _ => Err(Error::new(ErrorKind::Other, "unexpected database error")),
-        }
+        rpc_errors::from_code(resp.err)
     }
 
     /// Attempts to remove any mapping from the key.
@@ -107,13 +93,7 @@ impl crate::subnet::rpc::database::KeyValueReaderWriterDeleter for DatabaseClien
             .await
             .map_err(|e| Error::new(ErrorKind::Other, format!("delete request failed: {:?}", e)))?
             .into_inner();
-        let err = DatabaseError::from_u32(resp.err);
-        match err {
-            Some(DatabaseError::None) => Ok(()),
-            Some(DatabaseError::Closed) => Err(errors::database_closed()),
-            Some(DatabaseError::NotFound) => Err(errors::not_found()),
-            _ => Err(Error::new(ErrorKind::Other, "unexpected database error")),
-        }
+        rpc_errors::from_code(resp.err)
     }
 }
 
@@ -128,13 +108,7 @@ impl crate::subnet::rpc::database::Closer for DatabaseClient {
             .map_err(|e| Error::new(ErrorKind::Other, format!("close request failed: {:?}", e)))?
             .into_inner();
 
-        let err = DatabaseError::from_u32(resp.err);
-        match err {
-            Some(DatabaseError::None) => Ok(()),
-            Some(DatabaseError::Closed) => Err(errors::database_closed()),
-            Some(DatabaseError::NotFound) => Err(errors::not_found()),
-            _ => Err(Error::new(ErrorKind::Other, "unexpected database error")),
-        }
+        rpc_errors::from_code(resp.err)
     }
 }
 
@@ -15,6 +15,8 @@ lazy_static! {
     };
 }
 
+/// Returns the code of the error message, zero if unknown.
+#[deprecated(note = "use \"subnet::rpc::errors::to_code\" which fails on unknown errors")]
 pub fn error_to_error_code(msg: &str) -> io::Result<u32> {
     match ERROR_TO_ERROR_CODE.get(msg) {
         None => Ok(0),
@@ -13,7 +13,7 @@ use crate::{
             WriteBatchResponse,
         },
     },
-    subnet::rpc::database::{rpcdb::error_to_error_code, DatabaseError},
+    subnet::rpc::{database::DatabaseError, errors},
 };
 use prost::bytes::Bytes;
 use tokio::sync::RwLock;
@@ -48,7 +48,7 @@ impl pb::rpcdb::database_server::Database for Server {
             })),
             Err(e) => Ok(Response::new(HasResponse {
                 has: false,
-                err: error_to_error_code(&e.to_string()).unwrap(),
+                err: errors::to_code(&e)?,
             })),
         }
     }
@@ -64,7 +64,7 @@ impl pb::rpcdb::database_server::Database for Server {
             })),
             Err(e) => Ok(Response::new(GetResponse {
                 value: Bytes::from(""),
-                err: error_to_error_code(&e.to_string()).unwrap(),
+                err: errors::to_code(&e)?,
             })),
         }
     }
@@ -78,7 +78,7 @@ impl pb::rpcdb::database_server::Database for Server {
                 err: DatabaseError::None as u32,
             })),
             Err(e) => Ok(Response::new(PutResponse {
-                err: error_to_error_code(&e.to_string()).unwrap(),
+                err: errors::to_code(&e)?,
             })),
         }
     }
@@ -95,7 +95,7 @@ impl pb::rpcdb::database_server::Database for Server {
                 err: DatabaseError::None as u32,
             })),
             Err(e) => Ok(Response::new(DeleteResponse {
-                err: error_to_error_code(&e.to_string()).unwrap(),
+                err: errors::to_code(&e)?,
             })),
         }
     }
@@ -118,7 +118,7 @@ impl pb::rpcdb::database_server::Database for Server {
                 err: DatabaseError::None as u32,
             })),
             Err(e) => Ok(Response::new(CloseResponse {
-                err: error_to_error_code(&e.to_string()).unwrap(),
+                err: errors::to_code(&e)?,
             })),
         }
     }
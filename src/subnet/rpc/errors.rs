@@ -0,0 +1,74 @@
+//! Error codes in the "err" fields of the VM and database gRPC responses.
+//!
+//! avalanchego only sends the known errors as codes, and any other error as
+//! a gRPC error, so that an unknown failure is never read as success.
+//! ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/database/rpcdb/errors.go
+//! ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/rpcchainvm/errors.go
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::subnet::rpc::database::{errors, DatabaseError};
+
+/// Returns the known error of the I/O error, if any.
+/// The VM and the database report a missing block or key with
+/// "ErrorKind::NotFound" (see "database::errors::not_found").
+pub fn to_database_error(e: &Error) -> Option<DatabaseError> {
+    if errors::database_is_closed(e) {
+        return Some(DatabaseError::Closed);
+    }
+    if e.kind() == ErrorKind::NotFound {
+        return Some(DatabaseError::NotFound);
+    }
+    None
+}
+
+/// Returns the code of the known error for the response,
+/// or the gRPC error to return instead.
+// same error type as the gRPC handlers
+#[allow(clippy::result_large_err)]
+pub fn to_code(e: &Error) -> Result<u32, tonic::Status> {
+    match to_database_error(e) {
+        Some(err) => Ok(err as u32),
+        None => Err(tonic::Status::unknown(e.to_string())),
+    }
+}
+
+/// Returns the error of the code in the response, zero for success.
+pub fn from_code(code: u32) -> io::Result<()> {
+    match num_traits::FromPrimitive::from_u32(code) {
+        Some(DatabaseError::None) => Ok(()),
+        Some(DatabaseError::Closed) => Err(errors::database_closed()),
+        Some(DatabaseError::NotFound) => Err(errors::not_found()),
+        None => Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("unknown error code {}", code),
+        )),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::errors::test_codes --exact --show-output
+#[test]
+fn test_codes() {
+    assert_eq!(
+        to_code(&errors::database_closed()).unwrap(),
+        DatabaseError::Closed as u32
+    );
+    assert_eq!(
+        to_code(&errors::not_found()).unwrap(),
+        DatabaseError::NotFound as u32
+    );
+    assert_eq!(
+        to_code(&Error::new(ErrorKind::NotFound, "block 123 not found")).unwrap(),
+        DatabaseError::NotFound as u32
+    );
+    // only the error kind marks a missing key, not the message
+    assert!(to_code(&Error::new(ErrorKind::Other, "not found")).is_err());
+    let status = to_code(&Error::new(ErrorKind::Other, "ohh snap!")).unwrap_err();
+    assert_eq!(status.code(), tonic::Code::Unknown);
+    assert_eq!(status.message(), "ohh snap!");
+
+    assert!(from_code(0).is_ok());
+    assert!(errors::database_is_closed(&from_code(1).unwrap_err()));
+    assert!(errors::is_not_found(&from_code(2).unwrap_err()));
+    assert_eq!(from_code(9).unwrap_err().kind(), ErrorKind::InvalidData);
+}
@@ -0,0 +1,107 @@
+//! Keystore of the node, which stores a database per user.
+//! Requires the "keystore" feature.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/rpcchainvm/gkeystore
+
+use std::io;
+
+use crate::{
+    proto::pb::keystore::{keystore_client::KeystoreClient, GetDatabaseRequest},
+    subnet::rpc::database::{rpcdb::client::DatabaseClient, Database},
+};
+use thiserror::Error;
+use tonic::transport::{Channel, Endpoint};
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("user '{0}' not found")]
+    UserNotFound(String),
+    #[error("failed to get the database of user '{username}' ({code:?} '{message}')")]
+    GetDatabase {
+        username: String,
+        code: tonic::Code,
+        message: String,
+    },
+    #[error("failed to connect to the database server {server_addr} '{message}'")]
+    Connect {
+        server_addr: String,
+        message: String,
+    },
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        let kind = match e {
+            Error::UserNotFound(_) => io::ErrorKind::NotFound,
+            Error::GetDatabase { .. } | Error::Connect { .. } => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, e.to_string())
+    }
+}
+
+/// Keystore client that returns the user databases as "Database",
+/// instead of the raw gRPC server addresses.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: KeystoreClient<Channel>,
+}
+
+impl Client {
+    pub fn new(inner: KeystoreClient<Channel>) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the database of the user, authenticated with the password.
+    pub async fn get_database(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Result<Box<dyn Database + Send + Sync>, Error> {
+        let mut client = self.inner.clone();
+        let resp = client
+            .get_database(GetDatabaseRequest {
+                username: username.to_string(),
+                password: password.to_string(),
+            })
+            .await
+            .map_err(|s| match s.code() {
+                tonic::Code::NotFound => Error::UserNotFound(username.to_string()),
+                code => Error::GetDatabase {
+                    username: username.to_string(),
+                    code,
+                    message: s.message().to_string(),
+                },
+            })?
+            .into_inner();
+
+        let server_addr = resp.server_addr;
+        let conn = Endpoint::from_shared(format!("http://{}", server_addr))
+            .map_err(|e| Error::Connect {
+                server_addr: server_addr.clone(),
+                message: e.to_string(),
+            })?
+            .connect()
+            .await
+            .map_err(|e| Error::Connect {
+                server_addr: server_addr.clone(),
+                message: e.to_string(),
+            })?;
+        Ok(DatabaseClient::new(conn))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::keystore::test_error --exact --show-output
+#[test]
+fn test_error() {
+    let e: io::Error = Error::UserNotFound("alice".to_string()).into();
+    assert_eq!(e.kind(), io::ErrorKind::NotFound);
+    assert_eq!(e.to_string(), "user 'alice' not found");
+
+    let e: io::Error = Error::GetDatabase {
+        username: "alice".to_string(),
+        code: tonic::Code::Unauthenticated,
+        message: "incorrect password".to_string(),
+    }
+    .into();
+    assert_eq!(e.kind(), io::ErrorKind::Other);
+    assert!(e.to_string().contains("incorrect password"));
+}
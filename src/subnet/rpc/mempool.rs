@@ -0,0 +1,326 @@
+//! Generic transaction mempool that custom VMs can embed.
+//! Transactions are de-duplicated by Id and ordered by fee,
+//! and the engine is notified once the mempool becomes non-empty.
+
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap},
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{
+    ids,
+    subnet::rpc::{common::message::Message, context::ctx_log},
+};
+use tokio::sync::mpsc::Sender;
+
+/// Default maximum number of transactions in the mempool.
+pub const DEFAULT_MAX_TXS: usize = 4096;
+
+/// Default maximum total size of the transactions in the mempool (64 MiB).
+pub const DEFAULT_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+/// Transaction that can be stored in the mempool.
+pub trait Tx: Clone + Send + Sync {
+    /// Returns the unique transaction Id used for de-duplication.
+    fn id(&self) -> ids::Id;
+    /// Returns the fee used for prioritization (higher first).
+    fn fee(&self) -> u64;
+    /// Returns the size of the transaction in bytes.
+    fn size(&self) -> usize;
+}
+
+/// (fee, arrival order) so that the earlier transaction wins between equal fees.
+type Priority = (u64, Reverse<u64>, ids::Id);
+
+/// Represents the fee-ordered mempool.
+/// Wrap it in a lock to share across the VM and its handlers.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs/mempool#Mempool
+#[derive(Debug)]
+pub struct Mempool<T: Tx> {
+    max_txs: usize,
+    max_bytes: usize,
+
+    txs: HashMap<ids::Id, (T, Priority)>,
+    priorities: BTreeSet<Priority>,
+    bytes: usize,
+    seq: u64,
+
+    /// Locally issued transactions that have not been gossiped yet.
+    pending_gossip: Vec<ids::Id>,
+
+    to_engine: Option<Sender<Message>>,
+}
+
+impl<T: Tx> Default for Mempool<T> {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_TXS, DEFAULT_MAX_BYTES)
+    }
+}
+
+impl<T: Tx> Mempool<T> {
+    pub fn new(max_txs: usize, max_bytes: usize) -> Self {
+        Self {
+            max_txs,
+            max_bytes,
+            txs: HashMap::new(),
+            priorities: BTreeSet::new(),
+            bytes: 0,
+            seq: 0,
+            pending_gossip: Vec::new(),
+            to_engine: None,
+        }
+    }
+
+    /// Sets the engine channel to send "PendingTxs" to,
+    /// when the first transaction is added to an empty mempool.
+    #[must_use]
+    pub fn to_engine(mut self, to_engine: Sender<Message>) -> Self {
+        self.to_engine = Some(to_engine);
+        self
+    }
+
+    /// Adds a locally issued transaction, and queues it for gossip.
+    /// Returns false if the transaction is already in the mempool.
+    pub fn add(&mut self, tx: T) -> io::Result<bool> {
+        let tx_id = tx.id();
+        let added = self.insert(tx)?;
+        if added {
+            self.pending_gossip.push(tx_id);
+        }
+        Ok(added)
+    }
+
+    /// Adds a transaction received via gossip, which is not re-gossiped.
+    /// Returns false if the transaction is already in the mempool.
+    pub fn add_gossiped(&mut self, tx: T) -> io::Result<bool> {
+        self.insert(tx)
+    }
+
+    fn insert(&mut self, tx: T) -> io::Result<bool> {
+        let tx_id = tx.id();
+        if self.txs.contains_key(&tx_id) {
+            ctx_log!(debug, "tx {} already in mempool", tx_id);
+            return Ok(false);
+        }
+
+        let size = tx.size();
+        if size > self.max_bytes {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "tx {} size {} exceeds mempool limit {}",
+                    tx_id, size, self.max_bytes
+                ),
+            ));
+        }
+
+        // evict the lowest-fee txs to make room, only if the new one pays more
+        // than every one of them: collect the whole eviction set first, so that
+        // a failed insert leaves the mempool unchanged
+        let fee = tx.fee();
+        let mut evicted = Vec::new();
+        let (mut txs, mut bytes) = (self.txs.len(), self.bytes);
+        let mut lowest = self.priorities.iter();
+        while txs >= self.max_txs || bytes + size > self.max_bytes {
+            let p = match lowest.next() {
+                Some(p) => p,
+                None => break,
+            };
+            if p.0 >= fee {
+                return Err(Error::new(
+                    ErrorKind::OutOfMemory,
+                    format!("mempool is full (tx {} fee {} too low)", tx_id, fee),
+                ));
+            }
+            txs -= 1;
+            bytes -= self.txs[&p.2].0.size();
+            evicted.push(p.2);
+        }
+        for evict_id in evicted.iter() {
+            ctx_log!(
+                debug,
+                "evicting tx {} to make room for tx {}",
+                evict_id,
+                tx_id
+            );
+            self.remove(evict_id);
+        }
+
+        let was_empty = self.txs.is_empty();
+
+        let priority = (fee, Reverse(self.seq), tx_id);
+        self.seq += 1;
+        self.priorities.insert(priority);
+        self.txs.insert(tx_id, (tx, priority));
+        self.bytes += size;
+
+        if was_empty {
+            self.notify_engine();
+        }
+        Ok(true)
+    }
+
+    fn notify_engine(&self) {
+        if let Some(to_engine) = &self.to_engine {
+            if let Err(e) = to_engine.try_send(Message::PendingTxs) {
+                ctx_log!(warn, "failed to notify engine of pending txs: {}", e);
+            }
+        }
+    }
+
+    /// Removes the transaction (e.g., once included in an accepted block).
+    pub fn remove(&mut self, tx_id: &ids::Id) -> Option<T> {
+        let (tx, priority) = self.txs.remove(tx_id)?;
+        self.priorities.remove(&priority);
+        self.bytes -= tx.size();
+        self.pending_gossip.retain(|id| id != tx_id);
+        Some(tx)
+    }
+
+    /// Returns the highest-fee transaction without removing it.
+    pub fn peek(&self) -> Option<&T> {
+        let priority = self.priorities.iter().next_back()?;
+        self.txs.get(&priority.2).map(|(tx, _)| tx)
+    }
+
+    /// Removes and returns the highest-fee transaction, for block building.
+    pub fn pop(&mut self) -> Option<T> {
+        let tx_id = self.priorities.iter().next_back()?.2;
+        self.remove(&tx_id)
+    }
+
+    /// Removes and returns the highest-fee transactions up to the total size in bytes.
+    pub fn pop_up_to(&mut self, max_bytes: usize) -> Vec<T> {
+        let mut txs = Vec::new();
+        let mut total = 0_usize;
+        while let Some(tx) = self.peek() {
+            if total + tx.size() > max_bytes {
+                break;
+            }
+            total += tx.size();
+            if let Some(tx) = self.pop() {
+                txs.push(tx);
+            }
+        }
+        txs
+    }
+
+    /// Drains the locally issued transactions that are pending gossip.
+    pub fn take_pending_gossip(&mut self) -> Vec<T> {
+        let ids: Vec<ids::Id> = self.pending_gossip.drain(..).collect();
+        ids.iter()
+            .filter_map(|id| self.txs.get(id).map(|(tx, _)| tx.clone()))
+            .collect()
+    }
+
+    pub fn get(&self, tx_id: &ids::Id) -> Option<&T> {
+        self.txs.get(tx_id).map(|(tx, _)| tx)
+    }
+
+    pub fn contains(&self, tx_id: &ids::Id) -> bool {
+        self.txs.contains_key(tx_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.txs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.txs.is_empty()
+    }
+
+    /// Returns the total size of the transactions in bytes.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::mempool::test_mempool --exact --show-output
+#[tokio::test]
+async fn test_mempool() {
+    #[derive(Clone, Debug)]
+    struct TestTx(u8, u64);
+    impl Tx for TestTx {
+        fn id(&self) -> ids::Id {
+            ids::Id::from_slice(&[self.0])
+        }
+        fn fee(&self) -> u64 {
+            self.1
+        }
+        fn size(&self) -> usize {
+            10
+        }
+    }
+
+    let (tx_engine, mut rx_engine) = tokio::sync::mpsc::channel(10);
+    let mut mempool = Mempool::new(3, 100).to_engine(tx_engine);
+
+    assert!(mempool.add(TestTx(1, 5)).unwrap());
+    assert_eq!(rx_engine.try_recv().unwrap(), Message::PendingTxs);
+    assert!(!mempool.add(TestTx(1, 5)).unwrap());
+
+    assert!(mempool.add(TestTx(2, 10)).unwrap());
+    assert!(mempool.add_gossiped(TestTx(3, 5)).unwrap());
+    assert!(rx_engine.try_recv().is_err());
+    assert_eq!(mempool.bytes(), 30);
+
+    // full and fee not higher than the lowest
+    assert!(mempool.add(TestTx(4, 5)).is_err());
+    // evicts the lowest fee (earliest arrival wins between equal fees)
+    assert!(mempool.add(TestTx(4, 7)).unwrap());
+    assert!(mempool.contains(&TestTx(1, 5).id()));
+    assert!(!mempool.contains(&TestTx(3, 5).id()));
+
+    let gossip = mempool.take_pending_gossip();
+    assert_eq!(gossip.len(), 3);
+    assert!(mempool.take_pending_gossip().is_empty());
+
+    assert_eq!(mempool.peek().unwrap().0, 2);
+    let txs = mempool.pop_up_to(25);
+    assert_eq!(txs.iter().map(|tx| tx.0).collect::<Vec<u8>>(), vec![2, 4]);
+    assert_eq!(mempool.pop().unwrap().0, 1);
+    assert!(mempool.is_empty());
+
+    assert!(mempool.add(TestTx(5, 1)).unwrap());
+    assert_eq!(rx_engine.try_recv().unwrap(), Message::PendingTxs);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::mempool::test_mempool_eviction_all_or_nothing --exact --show-output
+#[test]
+fn test_mempool_eviction_all_or_nothing() {
+    #[derive(Clone, Debug)]
+    struct TestTx(u8, u64, usize);
+    impl Tx for TestTx {
+        fn id(&self) -> ids::Id {
+            ids::Id::from_slice(&[self.0])
+        }
+        fn fee(&self) -> u64 {
+            self.1
+        }
+        fn size(&self) -> usize {
+            self.2
+        }
+    }
+
+    let mut mempool = Mempool::new(10, 20);
+    assert!(mempool.add(TestTx(1, 1, 10)).unwrap());
+    assert!(mempool.add(TestTx(2, 10, 10)).unwrap());
+
+    // making room requires evicting both, but tx 2 pays more
+    assert!(mempool.add(TestTx(3, 5, 20)).is_err());
+    assert_eq!(mempool.len(), 2);
+    assert_eq!(mempool.bytes(), 20);
+    assert!(mempool.contains(&TestTx(1, 1, 10).id()));
+    assert!(mempool.contains(&TestTx(2, 10, 10).id()));
+
+    // evicting the lowest fee alone is enough
+    assert!(mempool.add(TestTx(3, 5, 10)).unwrap());
+    assert!(!mempool.contains(&TestTx(1, 1, 10).id()));
+    assert_eq!(mempool.bytes(), 20);
+
+    // evicts both, once the new tx pays more than every one of them
+    assert!(mempool.add(TestTx(4, 11, 20)).unwrap());
+    assert_eq!(mempool.len(), 1);
+    assert_eq!(mempool.bytes(), 20);
+}
@@ -1,11 +1,21 @@
 pub mod common;
+pub mod config;
 pub mod consensus;
 pub mod context;
 pub mod database;
+pub mod errors;
 pub mod health;
 pub mod http;
+#[cfg(feature = "keystore")]
+pub mod keystore;
+pub mod mempool;
 pub mod plugin;
+pub mod profiling;
+pub mod requests;
+#[cfg(feature = "sharedmemory")]
+pub mod sharedmemory;
 pub mod snow;
 pub mod snowman;
+pub mod upgrade;
 pub mod utils;
 pub mod vm;
@@ -1,4 +1,5 @@
 use std::{
+    env,
     io::{Error, ErrorKind, Result},
     net::SocketAddr,
 };
@@ -15,7 +16,7 @@ use crate::{
     subnet::rpc::utils,
 };
 use jsonrpc_core::futures::FutureExt;
-use tokio::sync::broadcast::Receiver;
+use tokio::{runtime::Runtime, sync::broadcast::Receiver};
 use tonic::transport::server::NamedService;
 use tonic_health::server::health_reporter;
 
@@ -38,6 +39,147 @@ impl NamedService for Plugin {
     const NAME: &'static str = "plugin";
 }
 
+/// Environment variable that overrides the number of runtime worker threads.
+pub const ENV_WORKER_THREADS: &str = "AVALANCHE_PLUGIN_WORKER_THREADS";
+
+/// Environment variable that overrides the maximum number of blocking threads.
+pub const ENV_MAX_BLOCKING_THREADS: &str = "AVALANCHE_PLUGIN_MAX_BLOCKING_THREADS";
+
+/// Environment variable that overrides the runtime thread name.
+pub const ENV_THREAD_NAME: &str = "AVALANCHE_PLUGIN_THREAD_NAME";
+
+/// Default name of the runtime threads, shown in "top -H" and in profiles.
+pub const DEFAULT_THREAD_NAME: &str = "avalanche-plugin";
+
+/// Configures the multi-threaded tokio runtime that runs the plugin server.
+/// VMs with CPU-heavy block verification may want more worker threads than
+/// CPUs reserved for the node, or more blocking threads for "spawn_blocking".
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RuntimeConfig {
+    /// Number of worker threads, defaults to the number of CPUs.
+    pub worker_threads: Option<usize>,
+    /// Maximum number of threads for blocking operations, defaults to 512.
+    pub max_blocking_threads: Option<usize>,
+    pub thread_name: String,
+    /// Stack size of the worker threads in bytes, defaults to 2 MiB.
+    pub thread_stack_size: Option<usize>,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl RuntimeConfig {
+    pub fn default() -> Self {
+        Self {
+            worker_threads: None,
+            max_blocking_threads: None,
+            thread_name: DEFAULT_THREAD_NAME.to_string(),
+            thread_stack_size: None,
+        }
+    }
+
+    /// Loads the config from the "AVALANCHE_PLUGIN_*" environment variables,
+    /// so that operators can tune the runtime without rebuilding the VM.
+    pub fn from_env() -> Result<Self> {
+        Self::from_vars(|k| env::var(k).ok())
+    }
+
+    fn from_vars(get: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let parse = |k: &str| -> Result<Option<usize>> {
+            match get(k) {
+                Some(v) => {
+                    let n = v.trim().parse::<usize>().map_err(|e| {
+                        Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("invalid {} '{}' ({})", k, v, e),
+                        )
+                    })?;
+                    if n == 0 {
+                        return Err(Error::new(
+                            ErrorKind::InvalidInput,
+                            format!("{} must be greater than 0", k),
+                        ));
+                    }
+                    Ok(Some(n))
+                }
+                None => Ok(None),
+            }
+        };
+
+        let mut cfg = Self::default();
+        cfg.worker_threads = parse(ENV_WORKER_THREADS)?;
+        cfg.max_blocking_threads = parse(ENV_MAX_BLOCKING_THREADS)?;
+        if let Some(name) = get(ENV_THREAD_NAME) {
+            cfg.thread_name = name;
+        }
+        Ok(cfg)
+    }
+
+    /// Sets the number of worker threads.
+    #[must_use]
+    pub fn worker_threads(mut self, n: usize) -> Self {
+        self.worker_threads = Some(n);
+        self
+    }
+
+    /// Sets the maximum number of blocking threads.
+    #[must_use]
+    pub fn max_blocking_threads(mut self, n: usize) -> Self {
+        self.max_blocking_threads = Some(n);
+        self
+    }
+
+    /// Sets the name of the runtime threads.
+    #[must_use]
+    pub fn thread_name(mut self, name: &str) -> Self {
+        self.thread_name = name.to_string();
+        self
+    }
+
+    /// Sets the stack size of the worker threads.
+    #[must_use]
+    pub fn thread_stack_size(mut self, size: usize) -> Self {
+        self.thread_stack_size = Some(size);
+        self
+    }
+
+    /// Builds the multi-threaded runtime with all drivers enabled.
+    pub fn build(&self) -> Result<Runtime> {
+        let mut builder = tokio::runtime::Builder::new_multi_thread();
+        builder.enable_all().thread_name(&self.thread_name);
+        if let Some(n) = self.worker_threads {
+            builder.worker_threads(n);
+        }
+        if let Some(n) = self.max_blocking_threads {
+            builder.max_blocking_threads(n);
+        }
+        if let Some(size) = self.thread_stack_size {
+            builder.thread_stack_size(size);
+        }
+        builder.build().map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to build tokio runtime '{}'", e),
+            )
+        })
+    }
+}
+
+/// Builds the runtime from the config, and blocks on "serve" until the
+/// stop channel fires. Use this from a synchronous "main" in place of
+/// "#[tokio::main]" to control the runtime.
+pub fn serve_with_runtime<V>(config: &RuntimeConfig, vm: V, stop_ch: Receiver<()>) -> Result<()>
+where
+    V: Vm,
+{
+    let rt = config.build()?;
+    log::info!("serving plugin with runtime config {:?}", config);
+    rt.block_on(serve(vm, stop_ch))
+}
+
 /// serve starts a gRPC server which serves the Vm service and generates the handshake message for plugin support.
 /// Reflection is enabled by default.
 pub async fn serve<V>(vm: V, stop_ch: Receiver<()>) -> Result<()>
@@ -100,3 +242,42 @@ where
 
     Ok(())
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::plugin::test_runtime_config --exact --show-output
+#[test]
+fn test_runtime_config() {
+    use std::collections::HashMap;
+
+    let cfg = RuntimeConfig::from_vars(|_| None).unwrap();
+    assert_eq!(cfg, RuntimeConfig::default());
+
+    let vars = HashMap::from([
+        (ENV_WORKER_THREADS, "3"),
+        (ENV_MAX_BLOCKING_THREADS, "16"),
+        (ENV_THREAD_NAME, "timestampvm"),
+    ]);
+    let cfg = RuntimeConfig::from_vars(|k| vars.get(k).map(|v| v.to_string())).unwrap();
+    assert_eq!(
+        cfg,
+        RuntimeConfig::default()
+            .worker_threads(3)
+            .max_blocking_threads(16)
+            .thread_name("timestampvm")
+    );
+
+    let rt = cfg.build().unwrap();
+    assert_eq!(rt.metrics().num_workers(), 3);
+    let name = rt.block_on(async {
+        tokio::spawn(async { std::thread::current().name().map(String::from) })
+            .await
+            .unwrap()
+    });
+    assert_eq!(name.as_deref(), Some("timestampvm"));
+
+    assert!(
+        RuntimeConfig::from_vars(|k| (k == ENV_WORKER_THREADS).then(|| "0".to_string())).is_err()
+    );
+    assert!(
+        RuntimeConfig::from_vars(|k| (k == ENV_WORKER_THREADS).then(|| "x".to_string())).is_err()
+    );
+}
@@ -0,0 +1,198 @@
+//! Debug handler for profiling the VM plugin in production,
+//! served as a VM static handler under "/ext/vm/[VM ID]/debug".
+//!
+//! "debug.runtimeMetrics" returns the tokio runtime metrics, and
+//! "debug.cpuProfile" (with the "subnet_pprof" feature) samples the
+//! process and returns the pprof protobuf, base64-encoded, which can be
+//! decoded and opened with "go tool pprof".
+//!
+//! Heap profiles are not exposed, since they require a profiling allocator
+//! (e.g., jemalloc) to be installed by the VM binary.
+//!
+//! ```ignore
+//! async fn create_static_handlers(&mut self) -> io::Result<HashMap<String, HttpHandler>> {
+//!     let mut handlers = HashMap::new();
+//!     handlers.insert(
+//!         profiling::HANDLER_PREFIX.to_string(),
+//!         profiling::new_handler(),
+//!     );
+//!     Ok(handlers)
+//! }
+//! ```
+//! ref. https://pkg.go.dev/net/http/pprof
+
+use crate::subnet::rpc::common::http_handler::{HttpHandler, LockOptions};
+use jsonrpc_core::{IoHandler, Params, Value};
+use serde::{Deserialize, Serialize};
+
+/// Extension of the static handler.
+pub const HANDLER_PREFIX: &str = "/debug";
+
+/// Default duration of the CPU profile.
+pub const DEFAULT_CPU_PROFILE_SECONDS: u64 = 30;
+
+/// Maximum duration of the CPU profile, to bound the sampling overhead.
+pub const MAX_CPU_PROFILE_SECONDS: u64 = 300;
+
+/// Default sampling frequency in Hz, same as the Go runtime.
+pub const DEFAULT_CPU_PROFILE_FREQUENCY: i32 = 100;
+
+/// Metrics of the tokio runtime that serves the plugin.
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeMetrics {
+    /// "multi_thread" or "current_thread".
+    pub flavor: String,
+    pub workers: usize,
+    pub alive_tasks: usize,
+    /// Number of tasks in the global queue, not yet picked up by a worker.
+    pub global_queue_depth: usize,
+}
+
+/// Returns the metrics of the current runtime, or None if called
+/// outside of a tokio runtime.
+pub fn runtime_metrics() -> Option<RuntimeMetrics> {
+    let handle = tokio::runtime::Handle::try_current().ok()?;
+    let metrics = handle.metrics();
+    let flavor = match handle.runtime_flavor() {
+        tokio::runtime::RuntimeFlavor::CurrentThread => "current_thread",
+        _ => "multi_thread",
+    };
+    Some(RuntimeMetrics {
+        flavor: flavor.to_string(),
+        workers: metrics.num_workers(),
+        alive_tasks: metrics.num_alive_tasks(),
+        global_queue_depth: metrics.global_queue_depth(),
+    })
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuProfileArgs {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seconds: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuProfileReply {
+    /// Base64-encoded pprof protobuf.
+    pub profile: String,
+    pub seconds: u64,
+}
+
+/// Samples the process for the duration and returns the pprof protobuf.
+/// Blocks the calling thread, so run it with "spawn_blocking".
+#[cfg(feature = "subnet_pprof")]
+pub fn cpu_profile(seconds: u64, frequency: i32) -> std::io::Result<Vec<u8>> {
+    use pprof::protos::Message;
+    use std::io::{Error, ErrorKind};
+
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(frequency)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::Other,
+                format!("failed to start profiler '{}'", e),
+            )
+        })?;
+    std::thread::sleep(std::time::Duration::from_secs(seconds));
+
+    let profile = guard
+        .report()
+        .build()
+        .and_then(|report| report.pprof())
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed to build profile '{}'", e)))?;
+    Ok(profile.encode_to_vec())
+}
+
+fn internal_error(msg: String) -> jsonrpc_core::Error {
+    jsonrpc_core::Error {
+        code: jsonrpc_core::ErrorCode::InternalError,
+        message: msg,
+        data: None,
+    }
+}
+
+/// Creates the JSON-RPC handler for the debug endpoints.
+/// It takes no lock, so it can be used while the VM is busy.
+pub fn new_handler() -> HttpHandler {
+    let mut handler = IoHandler::new();
+
+    handler.add_method("debug.runtimeMetrics", |_params: Params| async move {
+        let metrics = runtime_metrics()
+            .ok_or_else(|| internal_error("not running in a tokio runtime".to_string()))?;
+        serde_json::to_value(metrics).map_err(|e| internal_error(e.to_string()))
+    });
+
+    handler.add_method("debug.cpuProfile", |params: Params| async move {
+        let args: CpuProfileArgs = match params {
+            Params::None => CpuProfileArgs::default(),
+            p => p.parse()?,
+        };
+        let seconds = args.seconds.unwrap_or(DEFAULT_CPU_PROFILE_SECONDS);
+        if seconds == 0 || seconds > MAX_CPU_PROFILE_SECONDS {
+            return Err(jsonrpc_core::Error::invalid_params(format!(
+                "seconds must be in [1, {}], got {}",
+                MAX_CPU_PROFILE_SECONDS, seconds
+            )));
+        }
+        let frequency = args.frequency.unwrap_or(DEFAULT_CPU_PROFILE_FREQUENCY);
+        cpu_profile_reply(seconds, frequency).await
+    });
+
+    HttpHandler {
+        lock_option: LockOptions::NoLock,
+        handler: Some(handler),
+        server_addr: None,
+    }
+}
+
+#[cfg(feature = "subnet_pprof")]
+async fn cpu_profile_reply(seconds: u64, frequency: i32) -> jsonrpc_core::Result<Value> {
+    let profile = tokio::task::spawn_blocking(move || cpu_profile(seconds, frequency))
+        .await
+        .map_err(|e| internal_error(e.to_string()))?
+        .map_err(|e| internal_error(e.to_string()))?;
+    serde_json::to_value(CpuProfileReply {
+        profile: base64::encode(profile),
+        seconds,
+    })
+    .map_err(|e| internal_error(e.to_string()))
+}
+
+#[cfg(not(feature = "subnet_pprof"))]
+async fn cpu_profile_reply(_seconds: u64, _frequency: i32) -> jsonrpc_core::Result<Value> {
+    Err(internal_error(
+        "CPU profiling requires the \"subnet_pprof\" feature".to_string(),
+    ))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::profiling::test_handler --exact --show-output
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_handler() {
+    let metrics = runtime_metrics().unwrap();
+    assert_eq!(metrics.flavor, "multi_thread");
+    assert_eq!(metrics.workers, 2);
+
+    let handler = new_handler().handler.unwrap();
+    let resp = handler
+        .handle_request(r#"{"jsonrpc":"2.0","method":"debug.runtimeMetrics","id":1}"#)
+        .await
+        .unwrap();
+    let resp: serde_json::Value = serde_json::from_str(&resp).unwrap();
+    assert_eq!(resp["result"]["workers"], 2);
+
+    let resp = handler
+        .handle_request(
+            r#"{"jsonrpc":"2.0","method":"debug.cpuProfile","params":{"seconds":0},"id":2}"#,
+        )
+        .await
+        .unwrap();
+    let resp: serde_json::Value = serde_json::from_str(&resp).unwrap();
+    assert_eq!(resp["error"]["code"], -32602);
+}
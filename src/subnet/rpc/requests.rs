@@ -0,0 +1,277 @@
+//! Tracks the outstanding app requests, so that VM code can await the
+//! response of an app request sent to a peer.
+//!
+//! The VM forwards its "app_response" and "app_request_failed" callbacks
+//! to the manager, which resolves the future of the matching request.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/common#AppHandler
+//! ref. https://pkg.go.dev/github.com/ava-labs/coreth/peer#NetworkClient
+
+use std::{
+    collections::HashMap,
+    io::{self, Error, ErrorKind},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::{
+    ids,
+    subnet::rpc::{common::appsender::AppSender, context::ctx_log},
+};
+use tokio::sync::oneshot;
+
+/// Default time to wait for a response, same as the node's default
+/// "network-maximum-timeout".
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+type Waiter = oneshot::Sender<io::Result<Vec<u8>>>;
+
+#[derive(Default)]
+struct Inner {
+    next_request_id: u32,
+    pending: HashMap<(ids::node::Id, u32), Waiter>,
+}
+
+impl Inner {
+    /// Returns the next request ID not in use for the node.
+    fn assign(&mut self, node_id: ids::node::Id, waiter: Waiter) -> u32 {
+        loop {
+            let request_id = self.next_request_id;
+            self.next_request_id = self.next_request_id.wrapping_add(1);
+            if let std::collections::hash_map::Entry::Vacant(e) =
+                self.pending.entry((node_id, request_id))
+            {
+                e.insert(waiter);
+                return request_id;
+            }
+        }
+    }
+}
+
+/// Assigns the request IDs and routes the responses back to the requests.
+/// Clones share the same outstanding requests.
+#[derive(Clone)]
+pub struct Manager {
+    app_sender: Box<dyn AppSender + Send + Sync>,
+    timeout: Duration,
+    inner: Arc<Mutex<Inner>>,
+}
+
+impl Manager {
+    pub fn new(app_sender: Box<dyn AppSender + Send + Sync>) -> Self {
+        Self {
+            app_sender,
+            timeout: DEFAULT_TIMEOUT,
+            inner: Arc::new(Mutex::new(Inner::default())),
+        }
+    }
+
+    /// Sets the time to wait for a response before failing locally.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns the number of requests waiting for a response.
+    pub fn outstanding(&self) -> usize {
+        self.inner
+            .lock()
+            .expect("unexpected poisoned lock")
+            .pending
+            .len()
+    }
+
+    /// Sends the request to the node and waits for its response.
+    /// Fails with "TimedOut" if the node does not respond in time,
+    /// and with "ConnectionAborted" if the node reports the request failed.
+    pub async fn request(&self, node_id: ids::node::Id, request: Vec<u8>) -> io::Result<Vec<u8>> {
+        let (tx, rx) = oneshot::channel();
+        let request_id = self
+            .inner
+            .lock()
+            .expect("unexpected poisoned lock")
+            .assign(node_id, tx);
+        // removes the request however this returns, including when the
+        // caller drops the future (e.g., "tokio::select!" or an abort)
+        let _pending = Pending {
+            manager: self,
+            node_id,
+            request_id,
+        };
+        ctx_log!(debug, "sending app request {} to {}", request_id, node_id);
+
+        let mut node_ids = ids::node::Set::with_capacity(1);
+        node_ids.insert(node_id);
+        self.app_sender
+            .send_app_request(node_ids, request_id, request)
+            .await?;
+
+        match tokio::time::timeout(self.timeout, rx).await {
+            Ok(Ok(resp)) => resp,
+            Ok(Err(_)) => Err(Error::new(
+                ErrorKind::Other,
+                format!("app request {} to {} was dropped", request_id, node_id),
+            )),
+            Err(_) => Err(Error::new(
+                ErrorKind::TimedOut,
+                format!(
+                    "app request {} to {} timed out after {:?}",
+                    request_id, node_id, self.timeout
+                ),
+            )),
+        }
+    }
+
+    /// Delivers the response to the waiting request.
+    /// Returns false if the request is unknown (e.g., already timed out).
+    pub fn on_app_response(
+        &self,
+        node_id: &ids::node::Id,
+        request_id: u32,
+        response: &[u8],
+    ) -> bool {
+        match self.remove(*node_id, request_id) {
+            Some(waiter) => waiter.send(Ok(response.to_vec())).is_ok(),
+            None => {
+                ctx_log!(
+                    debug,
+                    "dropping response to unknown app request {} from {}",
+                    request_id,
+                    node_id
+                );
+                false
+            }
+        }
+    }
+
+    /// Fails the waiting request.
+    /// Returns false if the request is unknown (e.g., already timed out).
+    pub fn on_app_request_failed(&self, node_id: &ids::node::Id, request_id: u32) -> bool {
+        match self.remove(*node_id, request_id) {
+            Some(waiter) => waiter
+                .send(Err(Error::new(
+                    ErrorKind::ConnectionAborted,
+                    format!("app request {} to {} failed", request_id, node_id),
+                )))
+                .is_ok(),
+            None => false,
+        }
+    }
+
+    fn remove(&self, node_id: ids::node::Id, request_id: u32) -> Option<Waiter> {
+        self.inner
+            .lock()
+            .expect("unexpected poisoned lock")
+            .pending
+            .remove(&(node_id, request_id))
+    }
+}
+
+/// Outstanding request of "Manager::request".
+struct Pending<'a> {
+    manager: &'a Manager,
+    node_id: ids::node::Id,
+    request_id: u32,
+}
+
+impl Drop for Pending<'_> {
+    fn drop(&mut self) {
+        self.manager.remove(self.node_id, self.request_id);
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::requests::test_manager --exact --show-output
+#[tokio::test]
+async fn test_manager() {
+    /// Hands the sent requests over to the test.
+    #[derive(Clone)]
+    struct Loopback(tokio::sync::mpsc::UnboundedSender<(ids::node::Id, u32, Vec<u8>)>);
+    #[tonic::async_trait]
+    impl AppSender for Loopback {
+        async fn send_app_request(
+            &self,
+            node_ids: ids::node::Set,
+            request_id: u32,
+            request: Vec<u8>,
+        ) -> io::Result<()> {
+            for node_id in node_ids {
+                self.0.send((node_id, request_id, request.clone())).unwrap();
+            }
+            Ok(())
+        }
+        async fn send_app_response(&self, _: ids::node::Id, _: u32, _: Vec<u8>) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_app_gossip(&self, _: Vec<u8>) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_app_gossip_specific(&self, _: ids::node::Set, _: Vec<u8>) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_cross_chain_app_request(
+            &self,
+            _: ids::Id,
+            _: u32,
+            _: Vec<u8>,
+        ) -> io::Result<()> {
+            Ok(())
+        }
+        async fn send_cross_chain_app_response(
+            &self,
+            _: ids::Id,
+            _: u32,
+            _: Vec<u8>,
+        ) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let manager = Manager::new(Box::new(Loopback(tx))).timeout(Duration::from_millis(200));
+    let node_id = ids::node::Id::from_slice(&[1; 20]);
+
+    // the peer echoes the first request and fails the second
+    let peer = manager.clone();
+    tokio::spawn(async move {
+        let (node_id, request_id, request) = rx.recv().await.unwrap();
+        assert!(peer.on_app_response(&node_id, request_id, &request));
+        let (node_id, request_id, _) = rx.recv().await.unwrap();
+        assert!(peer.on_app_request_failed(&node_id, request_id));
+        // never responds to the others
+        while rx.recv().await.is_some() {}
+    });
+
+    assert_eq!(
+        manager.request(node_id, b"ping".to_vec()).await.unwrap(),
+        b"ping"
+    );
+    assert_eq!(
+        manager
+            .request(node_id, b"fail".to_vec())
+            .await
+            .unwrap_err()
+            .kind(),
+        ErrorKind::ConnectionAborted
+    );
+    assert_eq!(
+        manager
+            .request(node_id, b"drop".to_vec())
+            .await
+            .unwrap_err()
+            .kind(),
+        ErrorKind::TimedOut
+    );
+    assert_eq!(manager.outstanding(), 0);
+
+    // a request abandoned by the caller is no longer outstanding
+    assert!(tokio::time::timeout(
+        Duration::from_millis(20),
+        manager.request(node_id, b"abandoned".to_vec())
+    )
+    .await
+    .is_err());
+    assert_eq!(manager.outstanding(), 0);
+
+    // late responses are ignored
+    assert!(!manager.on_app_response(&node_id, 2, b"late"));
+}
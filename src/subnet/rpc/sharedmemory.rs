@@ -0,0 +1,238 @@
+//! Shared memory of the node, through which chains exchange atomic UTXOs
+//! (e.g., import and export transactions). Requires the "sharedmemory" feature.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/chains/atomic#SharedMemory
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/chains/atomic/gsharedmemory
+
+use std::{
+    collections::BTreeMap,
+    io,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+};
+
+use crate::{
+    ids,
+    proto::pb::sharedmemory::{
+        self as pb, shared_memory_client::SharedMemoryClient, ApplyRequest, AtomicRequest,
+        GetRequest, IndexedRequest,
+    },
+};
+use prost::bytes::Bytes;
+use thiserror::Error;
+use tonic::transport::Channel;
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("not found")]
+    NotFound,
+    #[error("sharedmemory {method} failed ({code:?} '{message}')")]
+    Rpc {
+        method: &'static str,
+        code: tonic::Code,
+        message: String,
+    },
+}
+
+impl Error {
+    fn rpc(method: &'static str, s: tonic::Status) -> Self {
+        if s.code() == tonic::Code::NotFound || s.message() == "not found" {
+            return Error::NotFound;
+        }
+        Error::Rpc {
+            method,
+            code: s.code(),
+            message: s.message().to_string(),
+        }
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::NotFound => crate::subnet::rpc::database::errors::not_found(),
+            Error::Rpc { .. } => io::Error::new(io::ErrorKind::Other, e.to_string()),
+        }
+    }
+}
+
+/// The requests of the import and export transactions are computed by
+/// "txs::atomic".
+pub use crate::txs::atomic::{Element, Requests};
+
+/// Page of the values indexed by traits.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Indexed {
+    pub values: Vec<Vec<u8>>,
+    /// Trait and key to resume the next page from.
+    pub last_trait: Vec<u8>,
+    pub last_key: Vec<u8>,
+}
+
+/// Shared memory client with "ids::Id" chain IDs and byte vectors,
+/// which also follows the continued responses of large reads.
+#[derive(Clone, Debug)]
+pub struct Client {
+    inner: SharedMemoryClient<Channel>,
+    /// Shared by the clones, since the server tracks the continued
+    /// requests by their IDs.
+    next_id: Arc<AtomicI64>,
+}
+
+impl Client {
+    pub fn new(inner: SharedMemoryClient<Channel>) -> Self {
+        Self {
+            inner,
+            next_id: Arc::new(AtomicI64::new(1)),
+        }
+    }
+
+    fn request_id(&self) -> i64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Returns the values of the keys in the shared memory
+    /// from the peer chain, in the order of the keys.
+    pub async fn get(
+        &self,
+        peer_chain_id: ids::Id,
+        keys: &[Vec<u8>],
+    ) -> Result<Vec<Vec<u8>>, Error> {
+        let mut client = self.inner.clone();
+        let id = self.request_id();
+        let mut req = GetRequest {
+            peer_chain_id: Bytes::from(peer_chain_id.to_vec()),
+            keys: keys.iter().map(|k| Bytes::from(k.clone())).collect(),
+            id,
+            continues: false,
+        };
+
+        let mut values = Vec::with_capacity(keys.len());
+        loop {
+            let resp = client
+                .get(req)
+                .await
+                .map_err(|s| Error::rpc("get", s))?
+                .into_inner();
+            values.extend(resp.values.into_iter().map(|v| v.to_vec()));
+            if !resp.continues {
+                return Ok(values);
+            }
+            req = GetRequest {
+                id,
+                continues: true,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Returns up to "limit" values from the peer chain with any of the traits,
+    /// starting after "start_trait" and "start_key" (empty for the first page).
+    pub async fn indexed(
+        &self,
+        peer_chain_id: ids::Id,
+        traits: &[Vec<u8>],
+        start_trait: &[u8],
+        start_key: &[u8],
+        limit: i32,
+    ) -> Result<Indexed, Error> {
+        let mut client = self.inner.clone();
+        let id = self.request_id();
+        let mut req = IndexedRequest {
+            peer_chain_id: Bytes::from(peer_chain_id.to_vec()),
+            traits: traits.iter().map(|t| Bytes::from(t.clone())).collect(),
+            start_trait: Bytes::from(start_trait.to_vec()),
+            start_key: Bytes::from(start_key.to_vec()),
+            limit,
+            id,
+            continues: false,
+        };
+
+        let mut indexed = Indexed::default();
+        loop {
+            let resp = client
+                .indexed(req)
+                .await
+                .map_err(|s| Error::rpc("indexed", s))?
+                .into_inner();
+            indexed
+                .values
+                .extend(resp.values.into_iter().map(|v| v.to_vec()));
+            indexed.last_trait = resp.last_trait.to_vec();
+            indexed.last_key = resp.last_key.to_vec();
+            if !resp.continues {
+                return Ok(indexed);
+            }
+            req = IndexedRequest {
+                id,
+                continues: true,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Applies the operations on the shared memory with each peer chain atomically.
+    pub async fn apply(&self, requests: BTreeMap<ids::Id, Requests>) -> Result<(), Error> {
+        let mut client = self.inner.clone();
+        let requests = requests
+            .into_iter()
+            .map(|(peer_chain_id, r)| to_atomic_request(peer_chain_id, r))
+            .collect();
+        client
+            .apply(ApplyRequest {
+                requests,
+                batches: Vec::new(),
+                id: self.request_id(),
+                continues: false,
+            })
+            .await
+            .map_err(|s| Error::rpc("apply", s))?;
+        Ok(())
+    }
+}
+
+fn to_atomic_request(peer_chain_id: ids::Id, r: Requests) -> AtomicRequest {
+    AtomicRequest {
+        remove_requests: r.remove_requests.into_iter().map(Bytes::from).collect(),
+        put_requests: r
+            .put_requests
+            .into_iter()
+            .map(|e| pb::Element {
+                key: Bytes::from(e.key),
+                value: Bytes::from(e.value),
+                traits: e.traits.into_iter().map(Bytes::from).collect(),
+            })
+            .collect(),
+        peer_chain_id: Bytes::from(peer_chain_id.to_vec()),
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::sharedmemory::test_atomic_request --exact --show-output
+#[test]
+fn test_atomic_request() {
+    let peer_chain_id = ids::Id::from_slice(&[7]);
+    let req = to_atomic_request(
+        peer_chain_id,
+        Requests {
+            remove_requests: vec![vec![1]],
+            put_requests: vec![Element {
+                key: vec![2],
+                value: vec![3],
+                traits: vec![vec![4], vec![5]],
+            }],
+        },
+    );
+    assert_eq!(req.peer_chain_id.to_vec(), peer_chain_id.to_vec());
+    assert_eq!(req.remove_requests, vec![Bytes::from(vec![1])]);
+    assert_eq!(req.put_requests[0].value, Bytes::from(vec![3]));
+    assert_eq!(req.put_requests[0].traits.len(), 2);
+
+    let e = Error::rpc("get", tonic::Status::unknown("not found"));
+    assert_eq!(e, Error::NotFound);
+    let e: io::Error = e.into();
+    assert!(crate::subnet::rpc::database::errors::is_not_found(&e));
+
+    let e = Error::rpc("apply", tonic::Status::internal("boom"));
+    assert_eq!(e.to_string(), "sharedmemory apply failed (Internal 'boom')");
+}
@@ -0,0 +1,315 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{
+    ids::{self, node},
+    jsonrpc::platformvm::ApiPrimaryValidator,
+};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// Represents a validator with its stake weight.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/validators#Validator
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Validator {
+    pub node_id: node::Id,
+    pub tx_id: ids::Id,
+    pub weight: u64,
+}
+
+/// Represents a change in the validator weight (e.g., from P-chain height diffs).
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/state#ValidatorWeightDiff
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct WeightDiff {
+    pub decrease: bool,
+    pub amount: u64,
+}
+
+/// Represents a set of validators, indexed by the node Id.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/validators#Set
+#[derive(Debug, Clone, Default)]
+pub struct Set {
+    validators: Vec<Validator>,
+    indices: HashMap<node::Id, usize>,
+    total_weight: u64,
+}
+
+impl Set {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a validator set from the "platform.getCurrentValidators" response,
+    /// including the delegated stake in each validator weight.
+    pub fn from_current_validators(validators: &[ApiPrimaryValidator]) -> io::Result<Self> {
+        let mut set = Self::new();
+        for v in validators.iter() {
+            let mut weight = v.weight.or(v.stake_amount).unwrap_or(0);
+            if let Some(delegators) = &v.delegators {
+                for d in delegators.iter() {
+                    weight = weight
+                        .checked_add(d.weight.unwrap_or(d.stake_amount))
+                        .ok_or_else(|| {
+                            Error::new(ErrorKind::InvalidData, "validator weight overflow")
+                        })?;
+                }
+            }
+            set.add(v.node_id, v.tx_id, weight)?;
+        }
+        Ok(set)
+    }
+
+    /// Adds a new validator. Errors if the validator already exists
+    /// or the weight is zero.
+    pub fn add(&mut self, node_id: node::Id, tx_id: ids::Id, weight: u64) -> io::Result<()> {
+        if weight == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("weight must be non-zero for {}", node_id),
+            ));
+        }
+        if self.indices.contains_key(&node_id) {
+            return Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!("duplicate validator {}", node_id),
+            ));
+        }
+        self.total_weight = self.total_weight.checked_add(weight).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("total weight overflow adding {}", node_id),
+            )
+        })?;
+
+        self.indices.insert(node_id, self.validators.len());
+        self.validators.push(Validator {
+            node_id,
+            tx_id,
+            weight,
+        });
+        Ok(())
+    }
+
+    /// Increases the weight of an existing validator.
+    pub fn add_weight(&mut self, node_id: &node::Id, weight: u64) -> io::Result<()> {
+        let idx = self.index(node_id)?;
+        let total_weight = self.total_weight.checked_add(weight).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("total weight overflow adding weight to {}", node_id),
+            )
+        })?;
+
+        self.validators[idx].weight += weight;
+        self.total_weight = total_weight;
+        Ok(())
+    }
+
+    /// Decreases the weight of an existing validator.
+    /// The validator is removed once its weight reaches zero.
+    pub fn remove_weight(&mut self, node_id: &node::Id, weight: u64) -> io::Result<()> {
+        let idx = self.index(node_id)?;
+        let cur = self.validators[idx].weight;
+        if weight > cur {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "cannot remove weight {} from {} (current weight {})",
+                    weight, node_id, cur
+                ),
+            ));
+        }
+
+        self.total_weight -= weight;
+        if weight < cur {
+            self.validators[idx].weight -= weight;
+            return Ok(());
+        }
+
+        // swap with the last element to keep the removal O(1)
+        self.validators.swap_remove(idx);
+        self.indices.remove(node_id);
+        if let Some(moved) = self.validators.get(idx) {
+            self.indices.insert(moved.node_id, idx);
+        }
+        Ok(())
+    }
+
+    /// Applies the weight diffs to the set, adding new validators
+    /// for increases on unknown node Ids.
+    pub fn apply_diff(&mut self, diffs: &HashMap<node::Id, WeightDiff>) -> io::Result<()> {
+        for (node_id, diff) in diffs.iter() {
+            if diff.amount == 0 {
+                continue;
+            }
+            if diff.decrease {
+                self.remove_weight(node_id, diff.amount)?;
+            } else if self.contains(node_id) {
+                self.add_weight(node_id, diff.amount)?;
+            } else {
+                self.add(*node_id, ids::Id::empty(), diff.amount)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn get(&self, node_id: &node::Id) -> Option<&Validator> {
+        self.indices.get(node_id).map(|idx| &self.validators[*idx])
+    }
+
+    pub fn contains(&self, node_id: &node::Id) -> bool {
+        self.indices.contains_key(node_id)
+    }
+
+    /// Returns the weight of the validator, or zero if not found.
+    pub fn weight(&self, node_id: &node::Id) -> u64 {
+        self.get(node_id).map(|v| v.weight).unwrap_or(0)
+    }
+
+    pub fn total_weight(&self) -> u64 {
+        self.total_weight
+    }
+
+    pub fn len(&self) -> usize {
+        self.validators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.validators.is_empty()
+    }
+
+    pub fn list(&self) -> &[Validator] {
+        &self.validators
+    }
+
+    /// Samples "size" node Ids weighted by stake, without replacement of the
+    /// weight units. As in avalanchego, a node may be returned more than once
+    /// in proportion to its weight. Set the seed for deterministic results.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/validators#Set "Sample"
+    pub fn sample(&self, size: usize, seed: Option<u64>) -> io::Result<Vec<node::Id>> {
+        if size as u64 > self.total_weight {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "insufficient weight to sample {} (total weight {})",
+                    size, self.total_weight
+                ),
+            ));
+        }
+
+        let mut rng = match seed {
+            Some(s) => StdRng::seed_from_u64(s),
+            None => StdRng::from_entropy(),
+        };
+
+        let mut cumulative = Vec::with_capacity(self.validators.len());
+        let mut acc = 0_u64;
+        for v in self.validators.iter() {
+            acc += v.weight;
+            cumulative.push(acc);
+        }
+
+        let mut drawn = HashSet::with_capacity(size);
+        let mut sampled = Vec::with_capacity(size);
+        while sampled.len() < size {
+            let unit = rng.gen_range(0..self.total_weight);
+            if !drawn.insert(unit) {
+                continue;
+            }
+            let idx = cumulative.partition_point(|c| *c <= unit);
+            sampled.push(self.validators[idx].node_id);
+        }
+        Ok(sampled)
+    }
+
+    fn index(&self, node_id: &node::Id) -> io::Result<usize> {
+        self.indices.get(node_id).copied().ok_or_else(|| {
+            Error::new(
+                ErrorKind::NotFound,
+                format!("validator {} not found", node_id),
+            )
+        })
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::snow::validators::test_set --exact --show-output
+#[test]
+fn test_set() {
+    let n1 = node::Id::from_slice(&[1; 20]);
+    let n2 = node::Id::from_slice(&[2; 20]);
+    let n3 = node::Id::from_slice(&[3; 20]);
+
+    let mut set = Set::new();
+    assert!(set.add(n1, ids::Id::empty(), 0).is_err());
+    set.add(n1, ids::Id::empty(), 10).unwrap();
+    set.add(n2, ids::Id::empty(), 20).unwrap();
+    set.add(n3, ids::Id::empty(), 30).unwrap();
+    assert!(set.add(n1, ids::Id::empty(), 1).is_err());
+    assert_eq!(set.total_weight(), 60);
+    assert_eq!(set.len(), 3);
+
+    set.add_weight(&n1, 5).unwrap();
+    assert_eq!(set.weight(&n1), 15);
+    assert!(set.remove_weight(&n1, 16).is_err());
+
+    // removing all weight removes the validator and re-indexes the last one
+    set.remove_weight(&n1, 15).unwrap();
+    assert!(!set.contains(&n1));
+    assert_eq!(set.get(&n3).unwrap().weight, 30);
+    assert_eq!(set.total_weight(), 50);
+
+    let mut diffs = HashMap::new();
+    diffs.insert(
+        n1,
+        WeightDiff {
+            decrease: false,
+            amount: 7,
+        },
+    );
+    diffs.insert(
+        n2,
+        WeightDiff {
+            decrease: true,
+            amount: 20,
+        },
+    );
+    set.apply_diff(&diffs).unwrap();
+    assert_eq!(set.weight(&n1), 7);
+    assert!(!set.contains(&n2));
+    assert_eq!(set.total_weight(), 37);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::snow::validators::test_sample --exact --show-output
+#[test]
+fn test_sample() {
+    let n1 = node::Id::from_slice(&[1; 20]);
+    let n2 = node::Id::from_slice(&[2; 20]);
+
+    let mut set = Set::new();
+    set.add(n1, ids::Id::empty(), 1).unwrap();
+    set.add(n2, ids::Id::empty(), 3).unwrap();
+
+    assert!(set.sample(5, None).is_err());
+
+    // every weight unit is drawn exactly once
+    let mut sampled = set.sample(4, None).unwrap();
+    sampled.sort();
+    assert_eq!(sampled, vec![n1, n2, n2, n2]);
+
+    let a = set.sample(2, Some(1234)).unwrap();
+    let b = set.sample(2, Some(1234)).unwrap();
+    assert_eq!(a, b);
+
+    let v = ApiPrimaryValidator {
+        node_id: n1,
+        stake_amount: Some(2000),
+        delegators: Some(vec![crate::jsonrpc::platformvm::ApiPrimaryDelegator {
+            stake_amount: 25,
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+    let set = Set::from_current_validators(&[v]).unwrap();
+    assert_eq!(set.weight(&n1), 2025);
+}
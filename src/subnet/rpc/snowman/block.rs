@@ -9,6 +9,17 @@ pub trait ChainVm: crate::subnet::rpc::common::vm::Vm + Getter + Parser {
     /// Returns either a block or an error
     async fn build_block(&self) -> Result<Box<dyn snowman::Block + Send + Sync>>;
 
+    /// Attempt to create a new block with the block context of the proposervm,
+    /// which is only provided once the proposervm activates.
+    /// Defaults to "build_block", ignoring the context.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/snowman/block#BuildBlockWithContextChainVM
+    async fn build_block_with_context(
+        &self,
+        _ctx: &Context,
+    ) -> Result<Box<dyn snowman::Block + Send + Sync>> {
+        self.build_block().await
+    }
+
     /// Issues a transaction to the chain
     async fn issue_tx(&self) -> Result<Box<dyn snowman::Block + Send + Sync>>;
 
@@ -31,3 +42,13 @@ pub trait Getter {
 pub trait Parser {
     async fn parse_block(&self, bytes: &[u8]) -> Result<Box<dyn snowman::Block + Send + Sync>>;
 }
+
+/// Represents the block context that is optionally provided by the proposervm
+/// to the VM, for blocks that opt in with "should_verify_with_context".
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/snowman/block#Context
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct Context {
+    /// The P-chain height that the block is verified against
+    /// (e.g., to look up the validator set for warp messages).
+    pub p_chain_height: u64,
+}
@@ -0,0 +1,223 @@
+//! Network upgrade schedule from the "upgrade_bytes" of the initialize request,
+//! which avalanchego reads from the chain upgrade file (e.g.,
+//! "~/.avalanchego/configs/chains/[chain ID]/upgrade.json").
+//!
+//! The schedule lists the upgrades in activation order, each with
+//! its unix timestamp in seconds and an optional VM-specific config:
+//!
+//! {"upgrades": [{"name": "durango", "timestamp": 1700000000, "config": {...}}]}
+
+use std::{
+    collections::HashSet,
+    io,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("upgrade schedule is invalid at '{path}' ({message})")]
+    Decode { path: String, message: String },
+    #[error("upgrade '{name}' at {timestamp} is not after the previous upgrade at {previous}")]
+    NotMonotonic {
+        name: String,
+        timestamp: u64,
+        previous: u64,
+    },
+    #[error("upgrade '{0}' is scheduled more than once")]
+    Duplicate(String),
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, e.to_string())
+    }
+}
+
+/// Upgrade that activates at the timestamp.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de> + Default"))]
+pub struct Upgrade<T = serde_json::Value> {
+    pub name: String,
+    /// Unix timestamp in seconds.
+    pub timestamp: u64,
+    #[serde(default)]
+    pub config: T,
+}
+
+/// Upgrades sorted by strictly increasing timestamps.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de> + Default"))]
+pub struct Schedule<T = serde_json::Value> {
+    #[serde(default = "Vec::new")]
+    upgrades: Vec<Upgrade<T>>,
+}
+
+impl<T> Default for Schedule<T> {
+    fn default() -> Self {
+        Self {
+            upgrades: Vec::new(),
+        }
+    }
+}
+
+impl<T> Schedule<T> {
+    /// Returns the validated schedule of the upgrades.
+    pub fn new(upgrades: Vec<Upgrade<T>>) -> Result<Self, Error> {
+        let schedule = Self { upgrades };
+        schedule.validate()?;
+        Ok(schedule)
+    }
+
+    /// Checks that the timestamps strictly increase and the names are unique.
+    pub fn validate(&self) -> Result<(), Error> {
+        let mut names = HashSet::new();
+        let mut previous: Option<u64> = None;
+        for u in self.upgrades.iter() {
+            if !names.insert(u.name.as_str()) {
+                return Err(Error::Duplicate(u.name.clone()));
+            }
+            if let Some(prev) = previous {
+                if u.timestamp <= prev {
+                    return Err(Error::NotMonotonic {
+                        name: u.name.clone(),
+                        timestamp: u.timestamp,
+                        previous: prev,
+                    });
+                }
+            }
+            previous = Some(u.timestamp);
+        }
+        Ok(())
+    }
+
+    pub fn upgrades(&self) -> &[Upgrade<T>] {
+        &self.upgrades
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.upgrades.is_empty()
+    }
+
+    /// Returns the latest upgrade active at the timestamp, if any.
+    pub fn active_at(&self, timestamp: u64) -> Option<&Upgrade<T>> {
+        self.upgrades
+            .iter()
+            .take_while(|u| u.timestamp <= timestamp)
+            .last()
+    }
+
+    /// Returns the latest upgrade active now, if any.
+    pub fn active_now(&self) -> Option<&Upgrade<T>> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        self.active_at(now)
+    }
+
+    /// Returns true if the named upgrade is scheduled and active at the timestamp.
+    pub fn is_active(&self, name: &str, timestamp: u64) -> bool {
+        self.get(name).map_or(false, |u| u.timestamp <= timestamp)
+    }
+
+    /// Returns the first upgrade that activates after the timestamp, if any.
+    pub fn next_after(&self, timestamp: u64) -> Option<&Upgrade<T>> {
+        self.upgrades.iter().find(|u| u.timestamp > timestamp)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Upgrade<T>> {
+        self.upgrades.iter().find(|u| u.name == name)
+    }
+}
+
+/// Parses and validates the "upgrade_bytes". Empty bytes (i.e.,
+/// no chain upgrade file) are read as an empty schedule.
+pub fn parse<T: DeserializeOwned + Default>(upgrade_bytes: &[u8]) -> Result<Schedule<T>, Error> {
+    if upgrade_bytes.iter().all(|b| b.is_ascii_whitespace()) {
+        return Ok(Schedule::default());
+    }
+
+    let mut de = serde_json::Deserializer::from_slice(upgrade_bytes);
+    let schedule: Schedule<T> =
+        serde_path_to_error::deserialize(&mut de).map_err(|e| Error::Decode {
+            path: e.path().to_string(),
+            message: e.into_inner().to_string(),
+        })?;
+    schedule.validate()?;
+    Ok(schedule)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::upgrade::test_schedule --exact --show-output
+#[test]
+fn test_schedule() {
+    #[derive(Clone, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+    struct FeeConfig {
+        min_base_fee: Option<u64>,
+    }
+
+    let schedule: Schedule<FeeConfig> = parse(b"").unwrap();
+    assert!(schedule.is_empty());
+    assert!(schedule.active_at(u64::MAX).is_none());
+
+    let schedule: Schedule<FeeConfig> = parse(
+        br#"{"upgrades": [
+            {"name": "a", "timestamp": 100},
+            {"name": "b", "timestamp": 200, "config": {"min_base_fee": 25}}
+        ]}"#,
+    )
+    .unwrap();
+    assert_eq!(schedule.upgrades().len(), 2);
+    assert!(schedule.active_at(99).is_none());
+    assert_eq!(schedule.active_at(100).unwrap().name, "a");
+    assert_eq!(schedule.active_at(199).unwrap().name, "a");
+    assert_eq!(
+        schedule.active_at(200).unwrap().config.min_base_fee,
+        Some(25)
+    );
+    assert!(schedule.is_active("a", 150));
+    assert!(!schedule.is_active("b", 150));
+    assert!(!schedule.is_active("c", u64::MAX));
+    assert_eq!(schedule.next_after(100).unwrap().name, "b");
+    assert!(schedule.next_after(200).is_none());
+    assert_eq!(schedule.active_now().unwrap().name, "b");
+
+    assert_eq!(
+        parse::<FeeConfig>(
+            br#"{"upgrades": [{"name": "a", "timestamp": 100}, {"name": "b", "timestamp": 100}]}"#
+        )
+        .unwrap_err(),
+        Error::NotMonotonic {
+            name: String::from("b"),
+            timestamp: 100,
+            previous: 100
+        }
+    );
+    assert_eq!(
+        parse::<FeeConfig>(
+            br#"{"upgrades": [{"name": "a", "timestamp": 1}, {"name": "a", "timestamp": 2}]}"#
+        )
+        .unwrap_err(),
+        Error::Duplicate(String::from("a"))
+    );
+    match parse::<FeeConfig>(br#"{"upgrades": [{"name": "a", "timestamp": "soon"}]}"#) {
+        Err(Error::Decode { path, .. }) => assert_eq!(path, "upgrades[0].timestamp"),
+        r => panic!("unexpected {:?}", r),
+    }
+
+    assert!(Schedule::new(vec![
+        Upgrade {
+            name: String::from("b"),
+            timestamp: 2,
+            config: serde_json::Value::Null,
+        },
+        Upgrade {
+            name: String::from("a"),
+            timestamp: 1,
+            config: serde_json::Value::Null,
+        },
+    ])
+    .is_err());
+}
@@ -0,0 +1,390 @@
+//! Block caches between the gRPC server and the inner VM, so that the
+//! repeated "get_block" and "parse_block" calls from the consensus engine
+//! are answered without calling into the VM.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/chain#State
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    io,
+};
+
+use crate::{
+    choices::status::Status,
+    ids,
+    subnet::rpc::{consensus::snowman::Block, database::DatabaseError, errors},
+};
+use prost::bytes::Bytes;
+
+/// Default number of decided blocks kept in memory.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/chain#Config
+pub const DEFAULT_DECIDED_CACHE_SIZE: usize = 2048;
+
+/// Default number of block IDs remembered as missing.
+pub const DEFAULT_MISSING_CACHE_SIZE: usize = 2048;
+
+/// Default number of block bytes to block ID mappings.
+pub const DEFAULT_BYTES_TO_ID_CACHE_SIZE: usize = 2048;
+
+/// Least-recently-used cache with a fixed capacity.
+#[derive(Debug)]
+pub struct Lru<K, V> {
+    capacity: usize,
+    entries: HashMap<K, (V, u64)>,
+    /// Access sequence number to key, oldest first.
+    order: BTreeMap<u64, K>,
+    seq: u64,
+}
+
+impl<K: Hash + Eq + Clone, V> Lru<K, V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            seq: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the value and marks it as the most recently used.
+    pub fn get(&mut self, k: &K) -> Option<&V> {
+        self.seq += 1;
+        let seq = self.seq;
+        match self.entries.get_mut(k) {
+            Some((v, last)) => {
+                let key = self.order.remove(last).expect("unexpected missing order");
+                self.order.insert(seq, key);
+                *last = seq;
+                Some(v)
+            }
+            None => None,
+        }
+    }
+
+    pub fn contains(&self, k: &K) -> bool {
+        self.entries.contains_key(k)
+    }
+
+    /// Inserts the value, evicting the least recently used entry when full.
+    pub fn put(&mut self, k: K, v: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        self.seq += 1;
+        if let Some((_, last)) = self.entries.insert(k.clone(), (v, self.seq)) {
+            self.order.remove(&last);
+        }
+        self.order.insert(self.seq, k);
+
+        while self.entries.len() > self.capacity {
+            let seq = *self.order.keys().next().expect("unexpected empty order");
+            let oldest = self.order.remove(&seq).expect("unexpected missing order");
+            self.entries.remove(&oldest);
+        }
+    }
+
+    pub fn remove(&mut self, k: &K) -> Option<V> {
+        let (v, last) = self.entries.remove(k)?;
+        self.order.remove(&last);
+        Some(v)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+/// Block fields that the gRPC server returns to the engine.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BlockData {
+    pub id: ids::Id,
+    pub parent_id: ids::Id,
+    pub bytes: Bytes,
+    pub status: Status,
+    pub height: u64,
+    /// Unix timestamp.
+    pub timestamp: u64,
+    pub verify_with_context: bool,
+}
+
+impl BlockData {
+    pub async fn from_block(block: &(dyn Block + Send + Sync)) -> io::Result<Self> {
+        Ok(Self {
+            id: block.id().await,
+            parent_id: block.parent().await,
+            bytes: Bytes::from(block.bytes().await.to_vec()),
+            status: block.status().await,
+            height: block.height().await,
+            timestamp: block.timestamp().await,
+            verify_with_context: block.should_verify_with_context().await?,
+        })
+    }
+
+    pub fn is_decided(&self) -> bool {
+        self.status.is_decided()
+    }
+}
+
+/// Caches the blocks by their consensus state:
+/// decided blocks never change so they are kept in an LRU, verified blocks
+/// are kept until they are decided (the engine accepts or rejects the same
+/// instance it verified), and the IDs that the VM could not find are kept
+/// in a negative cache until the block is parsed.
+pub struct ChainState {
+    decided: Lru<ids::Id, BlockData>,
+    missing: Lru<ids::Id, ()>,
+    /// SHA256 hash of the block bytes to the VM-defined block ID.
+    bytes_to_id: Lru<ids::Id, ids::Id>,
+    verified: HashMap<ids::Id, Box<dyn Block + Send + Sync>>,
+}
+
+impl Default for ChainState {
+    fn default() -> Self {
+        Self::default()
+    }
+}
+
+impl ChainState {
+    pub fn default() -> Self {
+        Self::new(
+            DEFAULT_DECIDED_CACHE_SIZE,
+            DEFAULT_MISSING_CACHE_SIZE,
+            DEFAULT_BYTES_TO_ID_CACHE_SIZE,
+        )
+    }
+
+    pub fn new(
+        decided_cache_size: usize,
+        missing_cache_size: usize,
+        bytes_to_id_cache_size: usize,
+    ) -> Self {
+        Self {
+            decided: Lru::new(decided_cache_size),
+            missing: Lru::new(missing_cache_size),
+            bytes_to_id: Lru::new(bytes_to_id_cache_size),
+            verified: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached block, from the verified blocks first.
+    pub async fn get(&mut self, id: &ids::Id) -> io::Result<Option<BlockData>> {
+        if let Some(block) = self.verified.get(id) {
+            return Ok(Some(BlockData::from_block(block.as_ref()).await?));
+        }
+        Ok(self.decided.get(id).cloned())
+    }
+
+    /// Returns the cached block for the block bytes.
+    pub async fn get_by_bytes(&mut self, bytes: &[u8]) -> io::Result<Option<BlockData>> {
+        let id = match self.bytes_to_id.get(&ids::Id::sha256(bytes)) {
+            Some(id) => *id,
+            None => return Ok(None),
+        };
+        self.get(&id).await
+    }
+
+    /// Returns true if the VM previously failed to find the block.
+    pub fn is_missing(&mut self, id: &ids::Id) -> bool {
+        self.missing.get(id).is_some()
+    }
+
+    /// Records the block returned by the VM.
+    /// Only decided blocks are cached, since the processing ones
+    /// may still change their status.
+    pub fn put(&mut self, bytes: Option<&[u8]>, data: &BlockData) {
+        self.missing.remove(&data.id);
+        if let Some(bytes) = bytes {
+            self.bytes_to_id.put(ids::Id::sha256(bytes), data.id);
+        }
+        if data.is_decided() {
+            self.decided.put(data.id, data.clone());
+        }
+    }
+
+    /// Records the VM error, and returns true if the block was not found.
+    pub fn put_error(&mut self, id: ids::Id, e: &io::Error) -> bool {
+        let not_found = matches!(errors::to_database_error(e), Some(DatabaseError::NotFound));
+        if not_found {
+            self.missing.put(id, ());
+        }
+        not_found
+    }
+
+    /// Holds on to the verified block until it is accepted or rejected.
+    pub async fn put_verified(&mut self, block: Box<dyn Block + Send + Sync>) {
+        let id = block.id().await;
+        self.missing.remove(&id);
+        self.bytes_to_id
+            .put(ids::Id::sha256(block.bytes().await), id);
+        self.verified.insert(id, block);
+    }
+
+    pub fn is_verified(&self, id: &ids::Id) -> bool {
+        self.verified.contains_key(id)
+    }
+
+    /// Removes the verified block to be accepted or rejected.
+    pub fn take_verified(&mut self, id: &ids::Id) -> Option<Box<dyn Block + Send + Sync>> {
+        self.verified.remove(id)
+    }
+
+    /// Returns the number of blocks waiting to be decided.
+    pub fn verified_len(&self) -> usize {
+        self.verified.len()
+    }
+
+    /// Drops all cached blocks (e.g., when the VM state is reset).
+    pub fn flush(&mut self) {
+        self.decided.clear();
+        self.missing.clear();
+        self.bytes_to_id.clear();
+        self.verified.clear();
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::vm::chain_state::test_lru --exact --show-output
+#[test]
+fn test_lru() {
+    let mut lru = Lru::new(2);
+    lru.put(1, "a");
+    lru.put(2, "b");
+    assert_eq!(lru.get(&1), Some(&"a"));
+
+    // 2 is the least recently used
+    lru.put(3, "c");
+    assert_eq!(lru.len(), 2);
+    assert!(!lru.contains(&2));
+    assert!(lru.contains(&1));
+    assert!(lru.contains(&3));
+
+    lru.put(1, "aa");
+    lru.put(4, "d");
+    assert_eq!(lru.get(&1), Some(&"aa"));
+    assert!(!lru.contains(&3));
+    assert_eq!(lru.remove(&4), Some("d"));
+    assert_eq!(lru.len(), 1);
+
+    let mut empty: Lru<u32, u32> = Lru::new(0);
+    empty.put(1, 1);
+    assert!(empty.is_empty());
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet::rpc::vm::chain_state::test_chain_state --exact --show-output
+#[tokio::test]
+async fn test_chain_state() {
+    use crate::subnet::rpc::consensus::snowman::{Decidable, Initializer, StatusWriter};
+
+    struct TestBlock {
+        id: ids::Id,
+        bytes: Vec<u8>,
+        status: Status,
+    }
+    #[tonic::async_trait]
+    impl Block for TestBlock {
+        async fn bytes(&self) -> &[u8] {
+            &self.bytes
+        }
+        async fn to_bytes(&self) -> io::Result<Vec<u8>> {
+            Ok(self.bytes.clone())
+        }
+        async fn height(&self) -> u64 {
+            1
+        }
+        async fn timestamp(&self) -> u64 {
+            1_680_000_000
+        }
+        async fn parent(&self) -> ids::Id {
+            ids::Id::empty()
+        }
+        async fn verify(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    #[tonic::async_trait]
+    impl Decidable for TestBlock {
+        async fn id(&self) -> ids::Id {
+            self.id
+        }
+        async fn status(&self) -> Status {
+            self.status.clone()
+        }
+        async fn accept(&mut self) -> io::Result<()> {
+            self.status = Status::Accepted;
+            Ok(())
+        }
+        async fn reject(&mut self) -> io::Result<()> {
+            self.status = Status::Rejected;
+            Ok(())
+        }
+    }
+    #[tonic::async_trait]
+    impl Initializer for TestBlock {
+        async fn init(&mut self, _bytes: &[u8], _status: Status) -> io::Result<()> {
+            Ok(())
+        }
+    }
+    #[tonic::async_trait]
+    impl StatusWriter for TestBlock {
+        async fn set_status(&mut self, status: Status) {
+            self.status = status;
+        }
+    }
+
+    let mut state = ChainState::new(2, 2, 2);
+    let id = ids::Id::sha256(b"block");
+    let bytes = b"block".to_vec();
+
+    // not found in the VM
+    assert!(state.put_error(id, &io::Error::new(io::ErrorKind::NotFound, "not found")));
+    assert!(state.is_missing(&id));
+    assert!(!state.put_error(
+        ids::Id::empty(),
+        &io::Error::new(io::ErrorKind::Other, "database closed")
+    ));
+    assert!(!state.is_missing(&ids::Id::empty()));
+
+    // verified, then accepted
+    state
+        .put_verified(Box::new(TestBlock {
+            id,
+            bytes: bytes.clone(),
+            status: Status::Processing,
+        }))
+        .await;
+    assert!(!state.is_missing(&id));
+    assert!(state.is_verified(&id));
+    let data = state.get_by_bytes(&bytes).await.unwrap().unwrap();
+    assert_eq!(data.id, id);
+    assert_eq!(data.status, Status::Processing);
+
+    let mut block = state.take_verified(&id).unwrap();
+    block.accept().await.unwrap();
+    let data = BlockData::from_block(block.as_ref()).await.unwrap();
+    state.put(None, &data);
+    assert_eq!(state.verified_len(), 0);
+    assert_eq!(
+        state.get(&id).await.unwrap().unwrap().status,
+        Status::Accepted
+    );
+
+    // processing blocks from the VM are not cached
+    let processing = BlockData {
+        id: ids::Id::sha256(b"processing"),
+        status: Status::Processing,
+        ..data.clone()
+    };
+    state.put(Some(b"processing"), &processing);
+    assert!(state.get(&processing.id).await.unwrap().is_none());
+
+    state.flush();
+    assert!(state.get(&id).await.unwrap().is_none());
+}
@@ -1,3 +1,4 @@
+pub mod chain_state;
 pub mod server;
 
 // TODO: This is where we would define alternate Vm traits based on features.
@@ -1,57 +1,236 @@
-use std::sync::Arc;
+use std::{
+    io,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use crate::{
     ids,
     proto::{
         grpcutil::timestamp_from_time,
         pb::{
-            self,
-            aliasreader::alias_reader_client::AliasReaderClient,
-            google::protobuf::Empty,
-            keystore::keystore_client::KeystoreClient,
-            messenger::{messenger_client::MessengerClient, NotifyRequest},
-            sharedmemory::shared_memory_client::SharedMemoryClient,
-            subnetlookup::subnet_lookup_client::SubnetLookupClient,
-            vm,
+            self, aliasreader::alias_reader_client::AliasReaderClient, google::protobuf::Empty,
+            messenger::messenger_client::MessengerClient,
+            subnetlookup::subnet_lookup_client::SubnetLookupClient, vm,
         },
     },
     subnet::{
         self,
         rpc::{
-            common::{appsender, message::Message},
-            context::Context,
+            common::{appsender, engine},
+            context::{self, ctx_log, Context},
             database::manager::{versioned_database, DatabaseManager},
-            database::rpcdb::{client::DatabaseClient, error_to_error_code},
+            database::rpcdb::client::DatabaseClient,
+            database::{self, DatabaseError},
+            errors,
             http::server::Server as HttpServer,
             snow::State,
+            snowman::block,
             utils,
+            vm::chain_state::{BlockData, ChainState},
         },
     },
 };
 use chrono::{TimeZone, Utc};
 use prost::bytes::Bytes;
 use semver::Version;
-use tokio::sync::{broadcast, mpsc, RwLock};
+use tokio::sync::{broadcast, Mutex, RwLock};
 use tonic::{transport::Endpoint, Request, Response};
 
+/// Length prefix of each block in the "get_ancestors" response.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/utils/wrappers#IntLen
+const INT_LEN: usize = 4;
+
 pub struct Server {
     /// Underlying Vm implementation.
     pub vm: Arc<RwLock<Box<dyn subnet::rpc::vm::Vm + Send + Sync>>>,
 
     /// Stop channel broadcast producer.
     pub stop_ch: broadcast::Sender<()>,
+
+    /// Caches the blocks returned by the VM.
+    pub chain_state: Arc<Mutex<ChainState>>,
+
+    /// Queue settings of the messages from the VM to the engine.
+    pub engine_config: engine::Config,
+
+    /// State of the message forwarding to the engine, reported in "health".
+    pub engine_health: engine::Health,
+
+    /// Spaces the "PendingTxs" notifications by the engine block builds,
+    /// if "engine_config.build_throttle" is set.
+    pub build_throttle: engine::BuildThrottle,
 }
 
 impl Server {
     pub fn new(
         vm: Box<dyn subnet::rpc::vm::Vm + Send + Sync>,
         stop_ch: broadcast::Sender<()>,
+    ) -> impl pb::vm::vm_server::Vm {
+        Self::new_with_chain_state(vm, stop_ch, ChainState::default())
+    }
+
+    /// Creates the server with the block caches of the given sizes.
+    pub fn new_with_chain_state(
+        vm: Box<dyn subnet::rpc::vm::Vm + Send + Sync>,
+        stop_ch: broadcast::Sender<()>,
+        chain_state: ChainState,
+    ) -> impl pb::vm::vm_server::Vm {
+        Self::new_with_options(vm, stop_ch, chain_state, engine::Config::default())
+    }
+
+    /// Creates the server with the block caches and the engine message queue settings.
+    pub fn new_with_options(
+        vm: Box<dyn subnet::rpc::vm::Vm + Send + Sync>,
+        stop_ch: broadcast::Sender<()>,
+        chain_state: ChainState,
+        engine_config: engine::Config,
     ) -> impl pb::vm::vm_server::Vm {
         Server {
             vm: Arc::new(RwLock::new(vm)),
             stop_ch,
+            chain_state: Arc::new(Mutex::new(chain_state)),
+            engine_config,
+            engine_health: engine::Health::default(),
+            build_throttle: engine::BuildThrottle::default(),
+        }
+    }
+}
+
+impl Server {
+    /// Returns the verified block to be decided, or loads it from the VM
+    /// if it was not verified through this server.
+    async fn take_block(
+        &self,
+        id: ids::Id,
+    ) -> std::result::Result<
+        Box<dyn subnet::rpc::consensus::snowman::Block + Send + Sync>,
+        tonic::Status,
+    > {
+        if let Some(block) = self.chain_state.lock().await.take_verified(&id) {
+            return Ok(block);
+        }
+
+        let inner_vm = self.vm.read().await;
+        inner_vm
+            .get_block(id)
+            .await
+            .map_err(|e| tonic::Status::unknown(e.to_string()))
+    }
+
+    async fn put_decided(
+        &self,
+        block: &(dyn subnet::rpc::consensus::snowman::Block + Send + Sync),
+    ) -> std::result::Result<(), tonic::Status> {
+        let data = BlockData::from_block(block)
+            .await
+            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        self.chain_state.lock().await.put(None, &data);
+        Ok(())
+    }
+
+    /// Returns the block from the caches, or loads it from the VM.
+    /// The cache lock is released while the VM loads the block, so that
+    /// the other calls are still answered from the caches meanwhile.
+    async fn get_block_data(&self, id: ids::Id) -> io::Result<BlockData> {
+        {
+            let mut chain_state = self.chain_state.lock().await;
+            if let Some(data) = chain_state.get(&id).await? {
+                return Ok(data);
+            }
+            if chain_state.is_missing(&id) {
+                ctx_log!(debug, "block {} is cached as missing", id);
+                return Err(database::errors::not_found());
+            }
+        }
+
+        let resp = {
+            let inner_vm = self.vm.read().await;
+            inner_vm.get_block(id).await
+        };
+        match resp {
+            Ok(block) => {
+                let data = BlockData::from_block(block.as_ref()).await?;
+                self.chain_state.lock().await.put(Some(&data.bytes), &data);
+                Ok(data)
+            }
+            Err(e) => {
+                ctx_log!(debug, "failed to get block {} '{}'", id, e);
+                self.chain_state.lock().await.put_error(id, &e);
+                Err(e)
+            }
         }
     }
+
+    /// Returns the block of the bytes from the caches, or parses it with the VM
+    /// (without holding the cache lock).
+    async fn parse_block_data(
+        &self,
+        bytes: &[u8],
+    ) -> std::result::Result<BlockData, tonic::Status> {
+        if let Some(data) = self
+            .chain_state
+            .lock()
+            .await
+            .get_by_bytes(bytes)
+            .await
+            .map_err(|e| tonic::Status::unknown(e.to_string()))?
+        {
+            return Ok(data);
+        }
+
+        let block = {
+            let inner_vm = self.vm.read().await;
+            inner_vm
+                .parse_block(bytes)
+                .await
+                .map_err(|e| tonic::Status::unknown(e.to_string()))?
+        };
+        let data = BlockData::from_block(block.as_ref())
+            .await
+            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        self.chain_state.lock().await.put(Some(bytes), &data);
+        Ok(data)
+    }
+}
+
+fn parse_block_response(data: BlockData) -> vm::ParseBlockResponse {
+    vm::ParseBlockResponse {
+        id: Bytes::from(data.id.to_vec()),
+        parent_id: Bytes::from(data.parent_id.to_vec()),
+        status: data.status.to_u32(),
+        height: data.height,
+        timestamp: Some(timestamp_from_time(
+            &Utc.timestamp(data.timestamp as i64, 0),
+        )),
+        verify_with_context: data.verify_with_context,
+    }
+}
+
+fn block_not_found_response(err: u32) -> vm::GetBlockResponse {
+    vm::GetBlockResponse {
+        parent_id: Bytes::new(),
+        bytes: Bytes::new(),
+        status: 0,
+        height: 0,
+        timestamp: Some(timestamp_from_time(&Utc.timestamp(0, 0))),
+        err,
+        verify_with_context: false,
+    }
+}
+
+fn get_block_response(data: BlockData) -> vm::GetBlockResponse {
+    vm::GetBlockResponse {
+        parent_id: Bytes::from(data.parent_id.to_vec()),
+        bytes: data.bytes,
+        status: data.status.to_u32(),
+        height: data.height,
+        timestamp: Some(timestamp_from_time(
+            &Utc.timestamp(data.timestamp as i64, 0),
+        )),
+        err: 0, // return 0 indicating no error
+        verify_with_context: data.verify_with_context,
+    }
 }
 
 #[tonic::async_trait]
@@ -60,7 +239,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::InitializeRequest>,
     ) -> std::result::Result<Response<vm::InitializeResponse>, tonic::Status> {
-        log::info!("initialize called");
+        ctx_log!(info, "initialize called");
 
         let req = req.into_inner();
         let client_conn = Endpoint::from_shared(format!("http://{}", req.server_addr))
@@ -71,25 +250,33 @@ impl pb::vm::vm_server::Vm for Server {
 
         // Multiplexing in tonic is done by cloning the client which is very cheap.
         // ref. https://docs.rs/tonic/latest/tonic/transport/struct.Channel.html#multiplexing-requests
-        let mut message = MessengerClient::new(client_conn.clone());
-        let keystore = KeystoreClient::new(client_conn.clone());
-        let shared_memory = SharedMemoryClient::new(client_conn.clone());
+        let message = MessengerClient::new(client_conn.clone());
+        #[cfg(feature = "keystore")]
+        let keystore = pb::keystore::keystore_client::KeystoreClient::new(client_conn.clone());
+        #[cfg(feature = "sharedmemory")]
+        let shared_memory =
+            pb::sharedmemory::shared_memory_client::SharedMemoryClient::new(client_conn.clone());
         let bc_lookup = AliasReaderClient::new(client_conn.clone());
         let sn_lookup = SubnetLookupClient::new(client_conn.clone());
         let app_sender = appsender::client::Client::new(client_conn.clone());
 
-        let ctx = Some(Context {
+        let ctx = Context {
             network_id: req.network_id,
             subnet_id: ids::Id::from_slice(&req.subnet_id),
             chain_id: ids::Id::from_slice(&req.chain_id),
             node_id: ids::node::Id::from_slice(&req.node_id),
             x_chain_id: ids::Id::from_slice(&req.x_chain_id),
             avax_asset_id: ids::Id::from_slice(&req.avax_asset_id),
+            #[cfg(feature = "keystore")]
             keystore,
+            #[cfg(feature = "sharedmemory")]
             shared_memory,
             bc_lookup,
             sn_lookup,
-        });
+        };
+        context::set_log_fields(ctx.log_fields());
+        ctx_log!(info, "initializing network {}", ctx.network_id);
+        let ctx = Some(ctx);
 
         let mut versioned_dbs: Vec<versioned_database::VersionedDatabase> =
             Vec::with_capacity(req.db_servers.len());
@@ -114,32 +301,12 @@ impl pb::vm::vm_server::Vm for Server {
         }
         let db_manager = DatabaseManager::new_from_databases(versioned_dbs);
 
-        let (tx_engine, mut rx_engine): (mpsc::Sender<Message>, mpsc::Receiver<Message>) =
-            mpsc::channel(100);
-        tokio::spawn(async move {
-            loop {
-                match rx_engine.recv().await {
-                    Some(msg) => {
-                        log::debug!("message received: {:?}", msg);
-                        match message
-                            .notify(NotifyRequest {
-                                message: msg as u32,
-                            })
-                            .await
-                        {
-                            Ok(_) => continue,
-                            Err(e) => {
-                                return tonic::Status::unknown(e.to_string());
-                            }
-                        }
-                    }
-                    None => {
-                        log::error!("engine receiver closed unexpectedly");
-                        return tonic::Status::unknown("engine receiver closed unexpectedly");
-                    }
-                }
-            }
-        });
+        let tx_engine = engine::spawn_with_throttle(
+            self.engine_config,
+            message,
+            self.engine_health.clone(),
+            self.build_throttle.clone(),
+        );
 
         let mut inner_vm = self.vm.write().await;
         inner_vm
@@ -164,7 +331,7 @@ impl pb::vm::vm_server::Vm for Server {
             .await
             .map_err(|e| tonic::Status::unknown(e.to_string()))?;
 
-        log::debug!("last_accepted_block id: {:?}", last_accepted);
+        ctx_log!(debug, "last_accepted_block id: {:?}", last_accepted);
 
         Ok(Response::new(vm::InitializeResponse {
             last_accepted_id: Bytes::from(last_accepted.to_vec()),
@@ -181,7 +348,10 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("shutdown called");
+        ctx_log!(debug, "shutdown called");
+
+        // release the blocks that are still waiting to be decided
+        self.chain_state.lock().await.flush();
 
         // notify all gRPC servers to shutdown
         self.stop_ch
@@ -206,7 +376,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::CreateHandlersResponse>, tonic::Status> {
-        log::debug!("create_handlers called");
+        ctx_log!(debug, "create_handlers called");
 
         // get handlers from underlying vm
         let mut inner_vm = self.vm.write().await;
@@ -219,7 +389,7 @@ impl pb::vm::vm_server::Vm for Server {
         for (prefix, handler) in handlers {
             let server_addr = utils::new_socket_addr();
             if handler.handler.clone().is_none() {
-                log::error!("handler did not provide an IoHandler: {}", prefix);
+                ctx_log!(error, "handler did not provide an IoHandler: {}", prefix);
                 continue;
             }
             let http_service = HttpServer::new(handler.handler.clone().expect("IoHandler"));
@@ -263,7 +433,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::CreateStaticHandlersResponse>, tonic::Status> {
-        log::debug!("create_handlers called");
+        ctx_log!(debug, "create_handlers called");
 
         // get handlers from underlying vm
         let mut inner_vm = self.vm.write().await;
@@ -276,7 +446,7 @@ impl pb::vm::vm_server::Vm for Server {
         for (prefix, handler) in handlers {
             let server_addr = utils::new_socket_addr();
             if handler.handler.clone().is_none() {
-                log::error!("handler did not provide an IoHandler: {}", prefix);
+                ctx_log!(error, "handler did not provide an IoHandler: {}", prefix);
                 continue;
             }
             let http_service = HttpServer::new(handler.handler.clone().expect("IoHandler"));
@@ -306,24 +476,40 @@ impl pb::vm::vm_server::Vm for Server {
 
     async fn build_block(
         &self,
-        _req: Request<Empty>,
+        req: Request<vm::BuildBlockRequest>,
     ) -> std::result::Result<Response<vm::BuildBlockResponse>, tonic::Status> {
-        log::debug!("build_block called");
+        ctx_log!(debug, "build_block called");
+        self.build_throttle.built();
 
-        let inner_vm = self.vm.write().await;
-        let block = inner_vm
-            .build_block()
+        let req = req.into_inner();
+        let block = {
+            let inner_vm = self.vm.write().await;
+            match req.p_chain_height {
+                Some(p_chain_height) => {
+                    inner_vm
+                        .build_block_with_context(&block::Context { p_chain_height })
+                        .await
+                }
+                None => inner_vm.build_block().await,
+            }
+            .map_err(|e| tonic::Status::unknown(e.to_string()))?
+        };
+
+        // the engine parses and verifies the built block next
+        let data = BlockData::from_block(block.as_ref())
             .await
             .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        self.chain_state.lock().await.put(Some(&data.bytes), &data);
 
         Ok(Response::new(vm::BuildBlockResponse {
-            id: Bytes::from(block.id().await.to_vec()),
-            parent_id: Bytes::from(block.parent().await.to_vec()),
-            bytes: Bytes::from(block.bytes().await.to_vec()),
-            height: block.height().await,
+            id: Bytes::from(data.id.to_vec()),
+            parent_id: Bytes::from(data.parent_id.to_vec()),
+            bytes: data.bytes,
+            height: data.height,
             timestamp: Some(timestamp_from_time(
-                &Utc.timestamp(block.timestamp().await as i64, 0),
+                &Utc.timestamp(data.timestamp as i64, 0),
             )),
+            verify_with_context: data.verify_with_context,
         }))
     }
 
@@ -331,24 +517,11 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::ParseBlockRequest>,
     ) -> std::result::Result<Response<vm::ParseBlockResponse>, tonic::Status> {
-        log::debug!("parse_block called");
+        ctx_log!(debug, "parse_block called");
 
         let req = req.into_inner();
-        let inner_vm = self.vm.write().await;
-        let block = inner_vm
-            .parse_block(req.bytes.as_ref())
-            .await
-            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
-
-        Ok(Response::new(vm::ParseBlockResponse {
-            id: Bytes::from(block.id().await.to_vec()),
-            parent_id: Bytes::from(block.parent().await.to_vec()),
-            status: block.status().await.to_u32(),
-            height: block.height().await,
-            timestamp: Some(timestamp_from_time(
-                &Utc.timestamp(block.timestamp().await as i64, 0),
-            )),
-        }))
+        let data = self.parse_block_data(req.bytes.as_ref()).await?;
+        Ok(Response::new(parse_block_response(data)))
     }
 
     /// Attempt to load a block.
@@ -366,36 +539,19 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::GetBlockRequest>,
     ) -> std::result::Result<Response<vm::GetBlockResponse>, tonic::Status> {
-        log::debug!("get_block called");
+        ctx_log!(debug, "get_block called");
 
         let req = req.into_inner();
-        let inner_vm = self.vm.read().await;
+        let id = ids::Id::from_slice(&req.id);
 
-        // determine if response is an error or not
-        match inner_vm.get_block(ids::Id::from_slice(&req.id)).await {
-            Ok(block) => Ok(Response::new(vm::GetBlockResponse {
-                parent_id: Bytes::from(block.parent().await.to_vec()),
-                bytes: Bytes::from(block.bytes().await.to_vec()),
-                status: block.status().await.to_u32(),
-                height: block.height().await,
-                timestamp: Some(timestamp_from_time(
-                    &Utc.timestamp(block.timestamp().await as i64, 0),
-                )),
-                err: 0, // return 0 indicating no error
-            })),
-            // if an error was found, generate empty response with ErrNotFound code
-            // ref: https://github.com/ava-labs/avalanchego/blob/master/vms/
-            Err(e) => {
-                log::debug!("Error getting block");
-                Ok(Response::new(vm::GetBlockResponse {
-                    parent_id: Bytes::new(),
-                    bytes: Bytes::new(),
-                    status: 0,
-                    height: 0,
-                    timestamp: Some(timestamp_from_time(&Utc.timestamp(0, 0))),
-                    err: error_to_error_code(&e.to_string()).unwrap(),
-                }))
-            }
+        // if the block was not found, generate empty response with ErrNotFound code
+        // and fail with the error otherwise
+        // ref: https://github.com/ava-labs/avalanchego/blob/master/vms/rpcchainvm/vm_server.go
+        match self.get_block_data(id).await {
+            Ok(data) => Ok(Response::new(get_block_response(data))),
+            Err(e) => Ok(Response::new(block_not_found_response(errors::to_code(
+                &e,
+            )?))),
         }
     }
 
@@ -403,7 +559,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::SetStateRequest>,
     ) -> std::result::Result<Response<vm::SetStateResponse>, tonic::Status> {
-        log::debug!("set_state called");
+        ctx_log!(debug, "set_state called");
 
         let req = req.into_inner();
         let inner_vm = self.vm.write().await;
@@ -415,6 +571,10 @@ impl pb::vm::vm_server::Vm for Server {
             .await
             .map_err(|e| tonic::Status::unknown(e.to_string()))?;
 
+        // the state transition (e.g., from the state sync) may have replaced
+        // the blocks under the caches
+        self.chain_state.lock().await.flush();
+
         let last_accepted_id = inner_vm
             .last_accepted()
             .await
@@ -440,7 +600,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::SetPreferenceRequest>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("set_preference called");
+        ctx_log!(debug, "set_preference called");
 
         let req = req.into_inner();
         let inner_vm = self.vm.read().await;
@@ -456,13 +616,16 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::HealthResponse>, tonic::Status> {
-        log::debug!("health called");
+        ctx_log!(debug, "health called");
 
         let inner_vm = self.vm.read().await;
         let resp = inner_vm
             .health_check()
             .await
             .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        self.engine_health
+            .check()
+            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
 
         Ok(Response::new(vm::HealthResponse {
             details: Bytes::from(resp),
@@ -473,7 +636,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::VersionResponse>, tonic::Status> {
-        log::debug!("version called");
+        ctx_log!(debug, "version called");
 
         let inner_vm = self.vm.read().await;
         let version = inner_vm
@@ -488,7 +651,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::ConnectedRequest>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("connected called");
+        ctx_log!(debug, "connected called");
 
         let req = req.into_inner();
         let inner_vm = self.vm.read().await;
@@ -505,7 +668,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::DisconnectedRequest>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("disconnected called");
+        ctx_log!(debug, "disconnected called");
 
         let req = req.into_inner();
         let inner_vm = self.vm.read().await;
@@ -523,7 +686,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::AppRequestMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("app_request called");
+        ctx_log!(debug, "app_request called");
 
         let req = req.into_inner();
         let node_id = ids::node::Id::from_slice(&req.node_id);
@@ -544,7 +707,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::AppRequestFailedMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("app_request_failed called");
+        ctx_log!(debug, "app_request_failed called");
 
         let req = req.into_inner();
         let node_id = ids::node::Id::from_slice(&req.node_id);
@@ -562,7 +725,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::AppResponseMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("app_response called");
+        ctx_log!(debug, "app_response called");
 
         let req = req.into_inner();
         let node_id = ids::node::Id::from_slice(&req.node_id);
@@ -580,7 +743,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::AppGossipMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("app_gossip called");
+        ctx_log!(debug, "app_gossip called");
 
         let req = req.into_inner();
         let node_id = ids::node::Id::from_slice(&req.node_id);
@@ -598,25 +761,35 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::BlockVerifyRequest>,
     ) -> std::result::Result<Response<vm::BlockVerifyResponse>, tonic::Status> {
-        log::debug!("block_verify called");
+        ctx_log!(debug, "block_verify called");
 
         let req = req.into_inner();
-        let inner_vm = self.vm.read().await;
+        let mut block = {
+            let inner_vm = self.vm.read().await;
+            inner_vm
+                .parse_block(&req.bytes)
+                .await
+                .map_err(|e| tonic::Status::unknown(e.to_string()))?
+        };
 
-        let mut block = inner_vm
-            .parse_block(&req.bytes)
-            .await
-            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        // the proposervm sets the P-chain height only for blocks
+        // that returned "verify_with_context" true
+        match req.p_chain_height {
+            Some(p_chain_height) => block
+                .verify_with_context(&block::Context { p_chain_height })
+                .await
+                .map_err(|e| tonic::Status::unknown(e.to_string()))?,
+            None => block
+                .verify()
+                .await
+                .map_err(|e| tonic::Status::unknown(e.to_string()))?,
+        }
 
-        block
-            .verify()
-            .await
-            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        let timestamp = block.timestamp().await;
+        self.chain_state.lock().await.put_verified(block).await;
 
         Ok(Response::new(vm::BlockVerifyResponse {
-            timestamp: Some(timestamp_from_time(
-                &Utc.timestamp(block.timestamp().await as i64, 0),
-            )),
+            timestamp: Some(timestamp_from_time(&Utc.timestamp(timestamp as i64, 0))),
         }))
     }
 
@@ -624,21 +797,17 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::BlockAcceptRequest>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("block_accept called");
+        ctx_log!(debug, "block_accept called");
 
         let req = req.into_inner();
-        let inner_vm = self.vm.read().await;
         let id = ids::Id::from_slice(&req.id);
-
-        let mut block = inner_vm
-            .get_block(id)
-            .await
-            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        let mut block = self.take_block(id).await?;
 
         block
             .accept()
             .await
             .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        self.put_decided(block.as_ref()).await?;
 
         Ok(Response::new(Empty {}))
     }
@@ -646,48 +815,88 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         req: Request<vm::BlockRejectRequest>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("block_reject called");
+        ctx_log!(debug, "block_reject called");
 
         let req = req.into_inner();
-        let inner_vm = self.vm.read().await;
         let id = ids::Id::from_slice(&req.id);
-
-        let mut block = inner_vm
-            .get_block(id)
-            .await
-            .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        let mut block = self.take_block(id).await?;
 
         block
             .reject()
             .await
             .map_err(|e| tonic::Status::unknown(e.to_string()))?;
+        self.put_decided(block.as_ref()).await?;
 
         Ok(Response::new(Empty {}))
     }
 
+    /// Returns the block bytes and its ancestors, newest first, within
+    /// the limits of the request.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/snow/engine/snowman/block#GetAncestors
     async fn get_ancestors(
         &self,
-        _req: Request<vm::GetAncestorsRequest>,
+        req: Request<vm::GetAncestorsRequest>,
     ) -> std::result::Result<Response<vm::GetAncestorsResponse>, tonic::Status> {
-        log::debug!("get_ancestors called");
+        ctx_log!(debug, "get_ancestors called");
 
-        Err(tonic::Status::unimplemented("get_ancestors"))
+        let req = req.into_inner();
+        let start = Instant::now();
+        let max_blocks_num = req.max_blocks_num.max(0) as usize;
+        let max_blocks_size = req.max_blocks_size.max(0) as usize;
+        let max_blocks_retrival_time =
+            Duration::from_nanos(req.max_blocks_retrival_time.max(0) as u64);
+
+        let mut data = match self.get_block_data(ids::Id::from_slice(&req.blk_id)).await {
+            Ok(data) => data,
+            // don't have the block, so drop the request
+            Err(e) if matches!(errors::to_database_error(&e), Some(DatabaseError::NotFound)) => {
+                return Ok(Response::new(vm::GetAncestorsResponse {
+                    blks_bytes: Vec::new(),
+                }))
+            }
+            Err(e) => return Err(tonic::Status::unknown(e.to_string())),
+        };
+
+        // each block is prefixed with its length in the response message
+        let mut blks_size = data.bytes.len() + INT_LEN;
+        let mut blks_bytes = vec![data.bytes.clone()];
+        while blks_bytes.len() < max_blocks_num && start.elapsed() < max_blocks_retrival_time {
+            data = match self.get_block_data(data.parent_id).await {
+                Ok(data) => data,
+                Err(_) => break,
+            };
+            let new_size = blks_size + data.bytes.len() + INT_LEN;
+            if new_size > max_blocks_size {
+                break;
+            }
+            blks_size = new_size;
+            blks_bytes.push(data.bytes.clone());
+        }
+
+        Ok(Response::new(vm::GetAncestorsResponse { blks_bytes }))
     }
 
     async fn batched_parse_block(
         &self,
-        _req: Request<vm::BatchedParseBlockRequest>,
+        req: Request<vm::BatchedParseBlockRequest>,
     ) -> std::result::Result<Response<vm::BatchedParseBlockResponse>, tonic::Status> {
-        log::debug!("batched_parse_block called");
+        ctx_log!(debug, "batched_parse_block called");
+
+        let req = req.into_inner();
+        let mut response = Vec::with_capacity(req.request.len());
+        for bytes in req.request.iter() {
+            let data = self.parse_block_data(bytes.as_ref()).await?;
+            response.push(parse_block_response(data));
+        }
 
-        Err(tonic::Status::unimplemented("batched_parse_block"))
+        Ok(Response::new(vm::BatchedParseBlockResponse { response }))
     }
 
     async fn gather(
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::GatherResponse>, tonic::Status> {
-        log::debug!("gather called");
+        ctx_log!(debug, "gather called");
 
         Err(tonic::Status::unimplemented("gather"))
     }
@@ -696,7 +905,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::CrossChainAppRequestMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("cross_chain_app_request called");
+        ctx_log!(debug, "cross_chain_app_request called");
 
         Err(tonic::Status::unimplemented("cross_chain_app_request"))
     }
@@ -705,7 +914,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::CrossChainAppRequestFailedMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("cross_chain_app_request_failed called");
+        ctx_log!(debug, "cross_chain_app_request_failed called");
 
         Err(tonic::Status::unimplemented(
             "send_cross_chain_app_request_failed",
@@ -716,7 +925,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::CrossChainAppResponseMsg>,
     ) -> std::result::Result<Response<Empty>, tonic::Status> {
-        log::debug!("cross_chain_app_response called");
+        ctx_log!(debug, "cross_chain_app_response called");
 
         Err(tonic::Status::unimplemented("cross_chain_app_response"))
     }
@@ -725,7 +934,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::StateSyncEnabledResponse>, tonic::Status> {
-        log::debug!("state_sync_enabled called");
+        ctx_log!(debug, "state_sync_enabled called");
 
         Err(tonic::Status::unimplemented("state_sync_enabled"))
     }
@@ -734,7 +943,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::GetOngoingSyncStateSummaryResponse>, tonic::Status> {
-        log::debug!("get_ongoing_sync_state_summary called");
+        ctx_log!(debug, "get_ongoing_sync_state_summary called");
 
         Err(tonic::Status::unimplemented(
             "get_ongoing_sync_state_summary",
@@ -745,7 +954,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::ParseStateSummaryRequest>,
     ) -> std::result::Result<tonic::Response<vm::ParseStateSummaryResponse>, tonic::Status> {
-        log::debug!("parse_state_summary called");
+        ctx_log!(debug, "parse_state_summary called");
 
         Err(tonic::Status::unimplemented("parse_state_summary"))
     }
@@ -754,7 +963,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::GetStateSummaryRequest>,
     ) -> std::result::Result<Response<vm::GetStateSummaryResponse>, tonic::Status> {
-        log::debug!("get_state_summary called");
+        ctx_log!(debug, "get_state_summary called");
 
         Err(tonic::Status::unimplemented("get_state_summary"))
     }
@@ -763,7 +972,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::GetLastStateSummaryResponse>, tonic::Status> {
-        log::debug!("get_last_state_summary called");
+        ctx_log!(debug, "get_last_state_summary called");
 
         Err(tonic::Status::unimplemented("get_last_state_summary"))
     }
@@ -772,7 +981,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::StateSummaryAcceptRequest>,
     ) -> std::result::Result<tonic::Response<vm::StateSummaryAcceptResponse>, tonic::Status> {
-        log::debug!("state_summary_accept called");
+        ctx_log!(debug, "state_summary_accept called");
 
         Err(tonic::Status::unimplemented("state_summary_accept"))
     }
@@ -781,7 +990,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<Empty>,
     ) -> std::result::Result<Response<vm::VerifyHeightIndexResponse>, tonic::Status> {
-        log::debug!("verify_height_index called");
+        ctx_log!(debug, "verify_height_index called");
         Err(tonic::Status::unimplemented("verify_height_index"))
     }
 
@@ -789,7 +998,7 @@ impl pb::vm::vm_server::Vm for Server {
         &self,
         _req: Request<vm::GetBlockIdAtHeightRequest>,
     ) -> std::result::Result<Response<vm::GetBlockIdAtHeightResponse>, tonic::Status> {
-        log::debug!("get_block_id_at_height called");
+        ctx_log!(debug, "get_block_id_at_height called");
 
         Err(tonic::Status::unimplemented("get_block_id_at_height"))
     }
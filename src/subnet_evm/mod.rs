@@ -1,2 +1,4 @@
 pub mod config;
 pub mod genesis;
+pub mod precompile;
+pub mod warp;
@@ -0,0 +1,260 @@
+//! Addresses and ABI call encoders of the subnet-evm stateful precompiles,
+//! which the admin tooling calls to manage the allow lists, the fee
+//! configuration, the native minting and the fee rewards of a chain.
+//! ref. https://github.com/ava-labs/subnet-evm/tree/master/precompile/contracts
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::subnet_evm::{genesis::FeeConfig, warp::selector};
+use primitive_types::{H160, U256};
+
+const fn precompile_address(last_byte: u8) -> H160 {
+    let mut b = [0u8; 20];
+    b[0] = 0x02;
+    b[19] = last_byte;
+    H160(b)
+}
+
+/// ref. "precompile/contracts/deployerallowlist.ContractAddress"
+pub const CONTRACT_DEPLOYER_ALLOW_LIST_ADDRESS: H160 = precompile_address(0x00);
+/// ref. "precompile/contracts/nativeminter.ContractAddress"
+pub const CONTRACT_NATIVE_MINTER_ADDRESS: H160 = precompile_address(0x01);
+/// ref. "precompile/contracts/txallowlist.ContractAddress"
+pub const TX_ALLOW_LIST_ADDRESS: H160 = precompile_address(0x02);
+/// ref. "precompile/contracts/feemanager.ContractAddress"
+pub const FEE_MANAGER_ADDRESS: H160 = precompile_address(0x03);
+/// ref. "precompile/contracts/rewardmanager.ContractAddress"
+pub const REWARD_MANAGER_ADDRESS: H160 = precompile_address(0x04);
+/// ref. "precompile/contracts/warp.ContractAddress"
+pub const WARP_ADDRESS: H160 = precompile_address(0x05);
+
+/// Role of an address in the allow list of a precompile, where only the
+/// admins (and the managers, since Durango) can change the roles.
+/// ref. https://pkg.go.dev/github.com/ava-labs/subnet-evm/precompile/allowlist#Role
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Role {
+    None,
+    Enabled,
+    Admin,
+    Manager,
+}
+
+impl Role {
+    pub fn to_u256(&self) -> U256 {
+        U256::from(match self {
+            Role::None => 0u64,
+            Role::Enabled => 1,
+            Role::Admin => 2,
+            Role::Manager => 3,
+        })
+    }
+
+    pub fn from_u256(v: U256) -> io::Result<Self> {
+        match v.low_u64() {
+            _ if v > U256::from(3) => Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown allow list role {}", v),
+            )),
+            0 => Ok(Role::None),
+            1 => Ok(Role::Enabled),
+            2 => Ok(Role::Admin),
+            _ => Ok(Role::Manager),
+        }
+    }
+
+    /// Returns the allow list function that assigns the role.
+    pub fn setter(&self) -> &'static str {
+        match self {
+            Role::None => "setNone(address)",
+            Role::Enabled => "setEnabled(address)",
+            Role::Admin => "setAdmin(address)",
+            Role::Manager => "setManager(address)",
+        }
+    }
+}
+
+fn address_word(addr: &H160) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(addr.as_bytes());
+    word
+}
+
+fn u256_word(v: U256) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    v.to_big_endian(&mut word);
+    word
+}
+
+/// Returns the calldata to assign the role to the address,
+/// on any precompile with an allow list.
+pub fn encode_set_role(role: Role, addr: &H160) -> Vec<u8> {
+    let mut b = selector(role.setter()).to_vec();
+    b.extend_from_slice(&address_word(addr));
+    b
+}
+
+/// Returns the calldata of "readAllowList(address)".
+pub fn encode_read_allow_list(addr: &H160) -> Vec<u8> {
+    let mut b = selector("readAllowList(address)").to_vec();
+    b.extend_from_slice(&address_word(addr));
+    b
+}
+
+/// Decodes the "uint256" role that "readAllowList" returns.
+pub fn decode_role(output: &[u8]) -> io::Result<Role> {
+    if output.len() != 32 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid readAllowList output length {}", output.len()),
+        ));
+    }
+    Role::from_u256(U256::from_big_endian(output))
+}
+
+/// Returns the calldata of "setFeeConfig", which requires every field.
+/// ref. https://pkg.go.dev/github.com/ava-labs/subnet-evm/precompile/contracts/feemanager#PackSetFeeConfig
+pub fn encode_set_fee_config(cfg: &FeeConfig) -> io::Result<Vec<u8>> {
+    let fields = [
+        ("gas_limit", cfg.gas_limit),
+        ("target_block_rate", cfg.target_block_rate),
+        ("min_base_fee", cfg.min_base_fee),
+        ("target_gas", cfg.target_gas),
+        (
+            "base_fee_change_denominator",
+            cfg.base_fee_change_denominator,
+        ),
+        ("min_block_gas_cost", cfg.min_block_gas_cost),
+        ("max_block_gas_cost", cfg.max_block_gas_cost),
+        ("block_gas_cost_step", cfg.block_gas_cost_step),
+    ];
+
+    let mut b =
+        selector("setFeeConfig(uint256,uint256,uint256,uint256,uint256,uint256,uint256,uint256)")
+            .to_vec();
+    for (name, v) in fields {
+        let v = v.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("fee config '{}' not set", name),
+            )
+        })?;
+        b.extend_from_slice(&u256_word(U256::from(v)));
+    }
+    Ok(b)
+}
+
+/// Returns the calldata of "getFeeConfig()".
+pub fn encode_get_fee_config() -> Vec<u8> {
+    selector("getFeeConfig()").to_vec()
+}
+
+/// Decodes the eight "uint256" words that "getFeeConfig" returns.
+pub fn decode_fee_config(output: &[u8]) -> io::Result<FeeConfig> {
+    if output.len() != 8 * 32 {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid getFeeConfig output length {}", output.len()),
+        ));
+    }
+    let mut words = Vec::with_capacity(8);
+    for chunk in output.chunks(32) {
+        let v = U256::from_big_endian(chunk);
+        if v > U256::from(u64::MAX) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("fee config value {} overflows u64", v),
+            ));
+        }
+        words.push(Some(v.as_u64()));
+    }
+    Ok(FeeConfig {
+        gas_limit: words[0],
+        target_block_rate: words[1],
+        min_base_fee: words[2],
+        target_gas: words[3],
+        base_fee_change_denominator: words[4],
+        min_block_gas_cost: words[5],
+        max_block_gas_cost: words[6],
+        block_gas_cost_step: words[7],
+    })
+}
+
+/// Returns the calldata of "mintNativeCoin(address,uint256)".
+pub fn encode_mint_native_coin(addr: &H160, amount: U256) -> Vec<u8> {
+    let mut b = selector("mintNativeCoin(address,uint256)").to_vec();
+    b.extend_from_slice(&address_word(addr));
+    b.extend_from_slice(&u256_word(amount));
+    b
+}
+
+/// Returns the calldata of "setRewardAddress(address)", which sends
+/// the fees to the address instead of burning them.
+pub fn encode_set_reward_address(addr: &H160) -> Vec<u8> {
+    let mut b = selector("setRewardAddress(address)").to_vec();
+    b.extend_from_slice(&address_word(addr));
+    b
+}
+
+/// Returns the calldata of "allowFeeRecipients()", which sends
+/// the fees to the block producers' fee recipients.
+pub fn encode_allow_fee_recipients() -> Vec<u8> {
+    selector("allowFeeRecipients()").to_vec()
+}
+
+/// Returns the calldata of "disableRewards()", which burns the fees.
+pub fn encode_disable_rewards() -> Vec<u8> {
+    selector("disableRewards()").to_vec()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet_evm::precompile::test_encode --exact --show-output
+#[test]
+fn test_encode() {
+    assert_eq!(
+        format!("{:?}", CONTRACT_DEPLOYER_ALLOW_LIST_ADDRESS),
+        "0x0200000000000000000000000000000000000000"
+    );
+    assert_eq!(
+        format!("{:?}", FEE_MANAGER_ADDRESS),
+        "0x0200000000000000000000000000000000000003"
+    );
+    assert_eq!(WARP_ADDRESS, crate::subnet_evm::warp::PRECOMPILE_ADDRESS);
+
+    let addr = H160::from_low_u64_be(0xabcd);
+    let d = encode_set_role(Role::Admin, &addr);
+    // ref. "setAdmin(address)" selector
+    assert_eq!(hex::encode(&d[..4]), "704b6c02");
+    assert_eq!(d.len(), 4 + 32);
+    assert_eq!(&d[4 + 30..], &[0xab, 0xcd]);
+    assert_eq!(
+        hex::encode(&encode_set_role(Role::Enabled, &addr)[..4]),
+        "0aaf7043"
+    );
+    assert_eq!(
+        hex::encode(&encode_set_role(Role::None, &addr)[..4]),
+        "8c6bfb3b"
+    );
+    assert_eq!(hex::encode(&encode_read_allow_list(&addr)[..4]), "eb54dae1");
+
+    for role in [Role::None, Role::Enabled, Role::Admin, Role::Manager] {
+        assert_eq!(decode_role(&u256_word(role.to_u256())).unwrap(), role);
+    }
+    assert!(decode_role(&u256_word(U256::from(4))).is_err());
+    assert!(decode_role(&[0u8; 31]).is_err());
+
+    let cfg = FeeConfig::default();
+    let d = encode_set_fee_config(&cfg).unwrap();
+    assert_eq!(hex::encode(&d[..4]), "8f10b586");
+    assert_eq!(d.len(), 4 + 8 * 32);
+    assert_eq!(decode_fee_config(&d[4..]).unwrap(), cfg);
+    let mut partial = cfg.clone();
+    partial.target_gas = None;
+    assert!(encode_set_fee_config(&partial)
+        .unwrap_err()
+        .to_string()
+        .contains("target_gas"));
+
+    let d = encode_mint_native_coin(&addr, U256::from(1000));
+    assert_eq!(hex::encode(&d[..4]), "4f5aaaba");
+    assert_eq!(U256::from_big_endian(&d[36..]), U256::from(1000));
+    assert_eq!(hex::encode(encode_disable_rewards()), "bc178628");
+}
@@ -0,0 +1,362 @@
+//! Avalanche Warp Messaging (AWM) messages, as sent and verified through
+//! the subnet-evm warp precompile.
+//!
+//! The source chain emits the unsigned message when a contract calls
+//! "sendWarpMessage", the source subnet validators sign it with their BLS
+//! keys, and the relayer submits the signed message to the destination chain
+//! as a predicate in the transaction access list.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/warp
+//! ref. https://github.com/ava-labs/subnet-evm/tree/master/precompile/contracts/warp
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{hash, ids, packer::Packer};
+use primitive_types::{H160, H256, U256};
+use ring::digest::{digest, SHA256};
+
+/// Codec version of the warp messages and payloads.
+pub const CODEC_VERSION: u16 = 0;
+
+/// Address of the subnet-evm warp precompile.
+pub const PRECOMPILE_ADDRESS: H160 = H160([
+    0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, //
+]);
+
+/// Length of the aggregate BLS signature.
+pub const BLS_SIGNATURE_LEN: usize = 96;
+
+/// Default percentage of the source subnet stake that must sign,
+/// same as the subnet-evm default "quorumNumerator".
+pub const DEFAULT_QUORUM_NUM: u64 = 67;
+
+/// Maximum size of a warp message, same as the avalanchego codec limit.
+pub const MAX_MESSAGE_SIZE: usize = 256 * 1024;
+
+const BIT_SET_SIGNATURE_TYPE_ID: u32 = 0;
+const ADDRESSED_CALL_TYPE_ID: u32 = 1;
+const PREDICATE_DELIMITER: u8 = 0xff;
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/warp#UnsignedMessage
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct UnsignedMessage {
+    pub network_id: u32,
+    pub source_chain_id: ids::Id,
+    pub payload: Vec<u8>,
+}
+
+impl UnsignedMessage {
+    fn pack_fields(&self, packer: &Packer) -> io::Result<()> {
+        packer.pack_u32(self.network_id)?;
+        packer.pack_bytes(self.source_chain_id.as_ref())?;
+        packer.pack_bytes_with_header(&self.payload)
+    }
+
+    fn unpack_fields(packer: &Packer) -> io::Result<Self> {
+        let network_id = packer.unpack_u32()?;
+        let source_chain_id = ids::Id::from_slice(&packer.unpack_bytes(ids::LEN)?);
+        let payload = packer.unpack_bytes_with_header()?;
+        Ok(Self {
+            network_id,
+            source_chain_id,
+            payload,
+        })
+    }
+
+    pub fn pack(&self) -> io::Result<Vec<u8>> {
+        let packer = Packer::new(MAX_MESSAGE_SIZE, 0);
+        packer.pack_u16(CODEC_VERSION)?;
+        self.pack_fields(&packer)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    pub fn unpack(b: &[u8]) -> io::Result<Self> {
+        let packer = Packer::load_bytes_for_unpack(MAX_MESSAGE_SIZE, b);
+        check_codec_version(&packer)?;
+        let msg = Self::unpack_fields(&packer)?;
+        check_consumed(&packer, b.len())?;
+        Ok(msg)
+    }
+
+    /// Returns the message ID, the SHA256 digest of the packed message.
+    pub fn id(&self) -> io::Result<ids::Id> {
+        let b = self.pack()?;
+        Ok(ids::Id::from_slice(digest(&SHA256, &b).as_ref()))
+    }
+}
+
+/// Unsigned message with the aggregate BLS signature of the source subnet
+/// validators, where "signers" is the bit set of the validator indices
+/// in the canonical validator set.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/warp#BitSetSignature
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Message {
+    pub unsigned_message: UnsignedMessage,
+    pub signers: Vec<u8>,
+    pub signature: [u8; BLS_SIGNATURE_LEN],
+}
+
+impl Message {
+    pub fn pack(&self) -> io::Result<Vec<u8>> {
+        let packer = Packer::new(MAX_MESSAGE_SIZE, 0);
+        packer.pack_u16(CODEC_VERSION)?;
+        self.unsigned_message.pack_fields(&packer)?;
+        packer.pack_u32(BIT_SET_SIGNATURE_TYPE_ID)?;
+        packer.pack_bytes_with_header(&self.signers)?;
+        packer.pack_bytes(&self.signature)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    pub fn unpack(b: &[u8]) -> io::Result<Self> {
+        let packer = Packer::load_bytes_for_unpack(MAX_MESSAGE_SIZE, b);
+        check_codec_version(&packer)?;
+        let unsigned_message = UnsignedMessage::unpack_fields(&packer)?;
+
+        let type_id = packer.unpack_u32()?;
+        if type_id != BIT_SET_SIGNATURE_TYPE_ID {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown signature type ID {}", type_id),
+            ));
+        }
+        let signers = packer.unpack_bytes_with_header()?;
+        let mut signature = [0u8; BLS_SIGNATURE_LEN];
+        signature.copy_from_slice(&packer.unpack_bytes(BLS_SIGNATURE_LEN)?);
+        check_consumed(&packer, b.len())?;
+
+        Ok(Self {
+            unsigned_message,
+            signers,
+            signature,
+        })
+    }
+
+    /// Returns the number of validators that signed the message.
+    pub fn num_signers(&self) -> u32 {
+        self.signers.iter().map(|b| b.count_ones()).sum()
+    }
+}
+
+/// Payload sent by a contract through the warp precompile, where
+/// "source_address" is the calling contract.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/warp/payload#AddressedCall
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct AddressedCall {
+    pub source_address: Vec<u8>,
+    pub payload: Vec<u8>,
+}
+
+impl AddressedCall {
+    pub fn pack(&self) -> io::Result<Vec<u8>> {
+        let packer = Packer::new(MAX_MESSAGE_SIZE, 0);
+        packer.pack_u16(CODEC_VERSION)?;
+        packer.pack_u32(ADDRESSED_CALL_TYPE_ID)?;
+        packer.pack_bytes_with_header(&self.source_address)?;
+        packer.pack_bytes_with_header(&self.payload)?;
+        Ok(packer.take_bytes().to_vec())
+    }
+
+    pub fn unpack(b: &[u8]) -> io::Result<Self> {
+        let packer = Packer::load_bytes_for_unpack(MAX_MESSAGE_SIZE, b);
+        check_codec_version(&packer)?;
+        let type_id = packer.unpack_u32()?;
+        if type_id != ADDRESSED_CALL_TYPE_ID {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unexpected payload type ID {}", type_id),
+            ));
+        }
+        let source_address = packer.unpack_bytes_with_header()?;
+        let payload = packer.unpack_bytes_with_header()?;
+        check_consumed(&packer, b.len())?;
+        Ok(Self {
+            source_address,
+            payload,
+        })
+    }
+}
+
+/// Token transfer carried in the addressed call payload, ABI-encoded as
+/// "abi.encode(address recipient, uint256 amount)" so that the destination
+/// contract can decode it with "abi.decode".
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct TokenTransfer {
+    pub recipient: H160,
+    pub amount: U256,
+}
+
+impl TokenTransfer {
+    pub const ENCODED_LEN: usize = 64;
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut b = vec![0u8; Self::ENCODED_LEN];
+        b[12..32].copy_from_slice(self.recipient.as_bytes());
+        self.amount.to_big_endian(&mut b[32..]);
+        b
+    }
+
+    pub fn decode(b: &[u8]) -> io::Result<Self> {
+        if b.len() != Self::ENCODED_LEN || b[..12].iter().any(|v| *v != 0) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("invalid token transfer payload ({} bytes)", b.len()),
+            ));
+        }
+        Ok(Self {
+            recipient: H160::from_slice(&b[12..32]),
+            amount: U256::from_big_endian(&b[32..]),
+        })
+    }
+}
+
+/// Packs the signed message into the storage keys of the access list entry
+/// for the warp precompile, delimited by 0xff and zero-padded to 32 bytes.
+/// ref. https://pkg.go.dev/github.com/ava-labs/subnet-evm/predicate#PackPredicate
+pub fn pack_predicate(b: &[u8]) -> Vec<H256> {
+    let mut padded = b.to_vec();
+    padded.push(PREDICATE_DELIMITER);
+    padded.resize((padded.len() + 31) / 32 * 32, 0);
+    padded.chunks(32).map(H256::from_slice).collect()
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/subnet-evm/predicate#UnpackPredicate
+pub fn unpack_predicate(storage_keys: &[H256]) -> io::Result<Vec<u8>> {
+    let mut b: Vec<u8> = storage_keys
+        .iter()
+        .flat_map(|k| k.as_bytes().to_vec())
+        .collect();
+    while b.last() == Some(&0) {
+        b.pop();
+    }
+    match b.pop() {
+        Some(PREDICATE_DELIMITER) => Ok(b),
+        _ => Err(Error::new(
+            ErrorKind::InvalidData,
+            "predicate is missing the delimiter",
+        )),
+    }
+}
+
+/// Returns the 4-byte selector of the Solidity function signature.
+pub fn selector(signature: &str) -> [u8; 4] {
+    let mut s = [0u8; 4];
+    s.copy_from_slice(&hash::keccak256(signature.as_bytes()).as_bytes()[..4]);
+    s
+}
+
+/// Returns the calldata of "sendWarpMessage(bytes payload)".
+pub fn encode_send_warp_message(payload: &[u8]) -> Vec<u8> {
+    let mut b = selector("sendWarpMessage(bytes)").to_vec();
+    let mut word = [0u8; 32];
+    U256::from(32).to_big_endian(&mut word);
+    b.extend_from_slice(&word);
+    U256::from(payload.len()).to_big_endian(&mut word);
+    b.extend_from_slice(&word);
+    b.extend_from_slice(payload);
+    b.resize(b.len() + (32 - payload.len() % 32) % 32, 0);
+    b
+}
+
+/// Topic of "SendWarpMessage(address indexed sender, bytes32 indexed messageID, bytes message)",
+/// whose data is the ABI-encoded unsigned message.
+pub fn send_warp_message_topic() -> H256 {
+    hash::keccak256("SendWarpMessage(address,bytes32,bytes)".as_bytes())
+}
+
+/// Decodes the ABI-encoded "bytes" of the "SendWarpMessage" log data.
+pub fn decode_send_warp_message_log(data: &[u8]) -> io::Result<UnsignedMessage> {
+    let invalid = || Error::new(ErrorKind::InvalidData, "invalid SendWarpMessage log data");
+    if data.len() < 64 {
+        return Err(invalid());
+    }
+    let offset = U256::from_big_endian(&data[..32]);
+    if offset != U256::from(32) {
+        return Err(invalid());
+    }
+    let len = U256::from_big_endian(&data[32..64]);
+    if len > U256::from(data.len() - 64) {
+        return Err(invalid());
+    }
+    UnsignedMessage::unpack(&data[64..64 + len.as_usize()])
+}
+
+fn check_codec_version(packer: &Packer) -> io::Result<()> {
+    let codec_version = packer.unpack_u16()?;
+    if codec_version != CODEC_VERSION {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("unknown codec version {}", codec_version),
+        ));
+    }
+    Ok(())
+}
+
+fn check_consumed(packer: &Packer, len: usize) -> io::Result<()> {
+    if packer.get_offset() != len {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("{} trailing bytes", len - packer.get_offset()),
+        ));
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- subnet_evm::warp::test_warp --exact --show-output
+#[test]
+fn test_warp() {
+    let transfer = TokenTransfer {
+        recipient: H160::from_low_u64_be(0xabcd),
+        amount: U256::from(1_000_000_u64),
+    };
+    let payload = AddressedCall {
+        source_address: H160::from_low_u64_be(0x1234).as_bytes().to_vec(),
+        payload: transfer.encode(),
+    };
+    let unsigned = UnsignedMessage {
+        network_id: 12345,
+        source_chain_id: ids::Id::from_slice(&[7; 32]),
+        payload: payload.pack().unwrap(),
+    };
+
+    let b = unsigned.pack().unwrap();
+    // codec version + network ID + chain ID + payload length + payload
+    assert_eq!(b.len(), 2 + 4 + 32 + 4 + unsigned.payload.len());
+    assert_eq!(UnsignedMessage::unpack(&b).unwrap(), unsigned);
+    assert_eq!(
+        unsigned.id().unwrap(),
+        ids::Id::from_slice(digest(&SHA256, &b).as_ref())
+    );
+    let mut trailing = b.clone();
+    trailing.push(0);
+    assert!(UnsignedMessage::unpack(&trailing).is_err());
+
+    let decoded = AddressedCall::unpack(&unsigned.payload).unwrap();
+    assert_eq!(TokenTransfer::decode(&decoded.payload).unwrap(), transfer);
+
+    let msg = Message {
+        unsigned_message: unsigned.clone(),
+        signers: vec![0b0000_0101, 0b1000_0000],
+        signature: [9; BLS_SIGNATURE_LEN],
+    };
+    let signed = msg.pack().unwrap();
+    assert_eq!(Message::unpack(&signed).unwrap(), msg);
+    assert_eq!(msg.num_signers(), 3);
+
+    // predicate round trip, including the exact multiple of 32 bytes
+    for b in [signed.clone(), vec![1; 31], vec![1; 32], vec![]] {
+        let keys = pack_predicate(&b);
+        assert_eq!(keys.len(), (b.len() + 1 + 31) / 32);
+        assert_eq!(unpack_predicate(&keys).unwrap(), b);
+    }
+    assert!(unpack_predicate(&[H256::zero()]).is_err());
+
+    // sendWarpMessage calldata carries the payload as ABI "bytes",
+    // the same encoding as the log data after the selector
+    let calldata = encode_send_warp_message(&b);
+    assert_eq!(&calldata[..4], &selector("sendWarpMessage(bytes)"));
+    assert_eq!((calldata.len() - 4) % 32, 0);
+    assert_eq!(
+        decode_send_warp_message_log(&calldata[4..]).unwrap(),
+        unsigned
+    );
+}
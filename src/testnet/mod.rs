@@ -0,0 +1,336 @@
+//! Local network launcher for end-to-end tests.
+//!
+//! Generates the staking certificates, the genesis, and the node configurations
+//! under a root directory, spawns one avalanchego process per node, and waits
+//! for all nodes to become healthy.
+//!
+//! ```no_run
+//! # async fn run() -> std::io::Result<()> {
+//! use avalanche_types::testnet;
+//!
+//! let cfg = testnet::Config::new("/usr/local/bin/avalanchego", "/tmp/testnet");
+//! let mut net = testnet::Network::launch(cfg).await?;
+//! println!("endpoints {:?}", net.endpoints());
+//! net.stop().await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! To orchestrate the network through avalanche-network-runner instead,
+//! see the "runner" module.
+
+pub mod runner;
+
+use std::{
+    fs::{self, File},
+    io::{self, Error, ErrorKind},
+    path::Path,
+    process::Stdio,
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{
+    avalanchego::{config as avalanchego_config, genesis as avalanchego_genesis},
+    client::health as client_health,
+    ids::node,
+    key::{self, secp256k1::private_key::Key},
+};
+use tokio::{
+    process::{Child, Command},
+    time::{sleep, Instant},
+};
+
+/// Default number of nodes, enough for the default staking quorum.
+pub const DEFAULT_NODES: usize = 5;
+/// Default HTTP port of the first node.
+/// Each node uses the HTTP port and the next one for staking.
+pub const DEFAULT_BASE_PORT: u32 = 19650;
+
+/// Private key of the "ewoq" test account pre-funded by default.
+/// ref. https://docs.avax.network/quickstart/fund-a-local-test-network
+pub const EWOQ_PRIVATE_KEY: &str = "PrivateKey-ewoqjP7PxY4yr3iLTpLisriqt94hdyDFNgchSxGGztUrTXtNN";
+
+/// Represents the local network configuration.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Path to the avalanchego binary.
+    pub avalanchego_path: String,
+    /// Directory for all node configurations, databases, and logs.
+    pub root_dir: String,
+    pub network_id: u32,
+    pub nodes: usize,
+    pub base_port: u32,
+    /// Keys to fund in the genesis.
+    /// The first key funds the initial stakes.
+    pub funded_keys: Vec<(Key, avalanchego_genesis::Funding)>,
+    pub log_level: String,
+    pub health_timeout: Duration,
+    pub health_poll_interval: Duration,
+}
+
+impl Config {
+    pub fn new(avalanchego_path: &str, root_dir: &str) -> Self {
+        Self {
+            avalanchego_path: avalanchego_path.to_string(),
+            root_dir: root_dir.to_string(),
+            network_id: crate::constants::DEFAULT_CUSTOM_NETWORK_ID,
+            nodes: DEFAULT_NODES,
+            base_port: DEFAULT_BASE_PORT,
+            funded_keys: vec![(
+                Key::from_cb58(EWOQ_PRIVATE_KEY).expect("unexpected invalid ewoq key"),
+                avalanchego_genesis::Funding::default(),
+            )],
+            log_level: String::from("INFO"),
+            health_timeout: Duration::from_secs(180),
+            health_poll_interval: Duration::from_secs(2),
+        }
+    }
+
+    /// Sets the number of nodes.
+    #[must_use]
+    pub fn nodes(mut self, nodes: usize) -> Self {
+        self.nodes = nodes;
+        self
+    }
+
+    /// Sets the HTTP port of the first node.
+    #[must_use]
+    pub fn base_port(mut self, base_port: u32) -> Self {
+        self.base_port = base_port;
+        self
+    }
+
+    /// Sets the keys to fund in the genesis.
+    #[must_use]
+    pub fn funded_keys(mut self, funded_keys: Vec<(Key, avalanchego_genesis::Funding)>) -> Self {
+        self.funded_keys = funded_keys;
+        self
+    }
+
+    /// Sets the maximum time to wait for all nodes to become healthy.
+    #[must_use]
+    pub fn health_timeout(mut self, health_timeout: Duration) -> Self {
+        self.health_timeout = health_timeout;
+        self
+    }
+
+    /// Generates the staking certificates, the genesis, and the node configurations
+    /// under the root directory, without spawning any process.
+    /// Existing certificates are reused, so re-launching keeps the node IDs.
+    pub fn prepare(&self) -> io::Result<(avalanchego_genesis::Genesis, Vec<Node>)> {
+        if self.nodes == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "zero nodes"));
+        }
+        if self.funded_keys.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no funded key"));
+        }
+        let root_dir = Path::new(&self.root_dir);
+        fs::create_dir_all(root_dir)?;
+
+        let mut node_ids = Vec::with_capacity(self.nodes);
+        for i in 0..self.nodes {
+            let node_dir = root_dir.join(format!("node{}", i + 1));
+            fs::create_dir_all(&node_dir)?;
+            let (node_id, _) = key::cert::x509::load_or_generate_pem(
+                &path_str(&node_dir.join("staking.key")),
+                &path_str(&node_dir.join("staking.crt")),
+            )?;
+            node_ids.push(node_id);
+        }
+
+        let genesis = avalanchego_genesis::Genesis::new_with_allocations(
+            self.network_id,
+            &self.funded_keys,
+            &node_ids,
+        )?;
+        let genesis_path = path_str(&root_dir.join("genesis.json"));
+        genesis.sync(&genesis_path)?;
+
+        // every node bootstraps from the first one
+        let bootstrap_ip = format!("127.0.0.1:{}", self.base_port + 1);
+        let bootstrap_id = node_ids[0].to_string();
+
+        let mut nodes = Vec::with_capacity(self.nodes);
+        for (i, node_id) in node_ids.into_iter().enumerate() {
+            let node_dir = root_dir.join(format!("node{}", i + 1));
+            let http_port = self.base_port + 2 * i as u32;
+
+            let mut cfg = avalanchego_config::Config::default_custom();
+            cfg.config_file = Some(path_str(&node_dir.join("config.json")));
+            cfg.genesis = Some(genesis_path.clone());
+            cfg.network_id = self.network_id;
+            cfg.db_dir = path_str(&node_dir.join("db"));
+            cfg.log_dir = path_str(&node_dir.join("logs"));
+            cfg.log_level = Some(self.log_level.clone());
+            cfg.http_port = http_port;
+            cfg.http_host = Some(String::from("127.0.0.1"));
+            cfg.public_ip = Some(String::from("127.0.0.1"));
+            cfg.staking_port = http_port + 1;
+            cfg.staking_tls_key_file = Some(path_str(&node_dir.join("staking.key")));
+            cfg.staking_tls_cert_file = Some(path_str(&node_dir.join("staking.crt")));
+            cfg.chain_config_dir = path_str(&node_dir.join("chains"));
+            cfg.subnet_config_dir = Some(path_str(&node_dir.join("subnets")));
+            cfg.profile_dir = Some(path_str(&node_dir.join("profile")));
+            if i > 0 {
+                cfg.bootstrap_ips = Some(bootstrap_ip.clone());
+                cfg.bootstrap_ids = Some(bootstrap_id.clone());
+            }
+
+            // small networks cannot meet the default sample size
+            let sample_size = (self.nodes as u32).min(avalanchego_config::DEFAULT_SNOW_SAMPLE_SIZE);
+            cfg.snow_sample_size = Some(sample_size);
+            cfg.snow_quorum_size = Some(sample_size / 2 + 1);
+
+            cfg.sync(None)?;
+            nodes.push(Node {
+                node_id,
+                http_rpc: format!("http://127.0.0.1:{}", http_port),
+                config: cfg,
+            });
+        }
+
+        Ok((genesis, nodes))
+    }
+}
+
+/// Represents a node in the local network.
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub node_id: node::Id,
+    pub http_rpc: String,
+    pub config: avalanchego_config::Config,
+}
+
+/// Represents the running local network.
+/// The avalanchego processes are killed when the network is dropped.
+pub struct Network {
+    pub genesis: avalanchego_genesis::Genesis,
+    pub nodes: Vec<Node>,
+    children: Vec<Child>,
+}
+
+impl Network {
+    /// Launches the avalanchego processes and waits for all nodes to become healthy.
+    pub async fn launch(cfg: Config) -> io::Result<Self> {
+        if !Path::new(&cfg.avalanchego_path).exists() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("avalanchego binary '{}' not found", cfg.avalanchego_path),
+            ));
+        }
+        let (genesis, nodes) = cfg.prepare()?;
+
+        let mut net = Self {
+            genesis,
+            nodes: Vec::new(),
+            children: Vec::new(),
+        };
+        for node in nodes {
+            let config_file = node
+                .config
+                .config_file
+                .clone()
+                .expect("unexpected None config_file");
+            let log_dir = Path::new(&node.config.log_dir);
+            fs::create_dir_all(log_dir)?;
+            let stdout = File::create(log_dir.join("stdout.log"))?;
+            let stderr = File::create(log_dir.join("stderr.log"))?;
+
+            log::info!("launching node {} at {}", node.node_id, node.http_rpc);
+            let child = Command::new(&cfg.avalanchego_path)
+                .arg(format!("--config-file={}", config_file))
+                .stdout(Stdio::from(stdout))
+                .stderr(Stdio::from(stderr))
+                .kill_on_drop(true)
+                .spawn()?;
+            net.children.push(child);
+            net.nodes.push(node);
+        }
+
+        net.wait_for_health(cfg.health_timeout, cfg.health_poll_interval)
+            .await?;
+        Ok(net)
+    }
+
+    /// Polls the health API of all nodes until they all report healthy.
+    pub async fn wait_for_health(&self, timeout: Duration, interval: Duration) -> io::Result<()> {
+        let start = Instant::now();
+        for node in self.nodes.iter() {
+            loop {
+                match client_health::check(Arc::new(node.http_rpc.clone()), false).await {
+                    Ok(resp) if resp.healthy => {
+                        log::info!("node {} is healthy", node.node_id);
+                        break;
+                    }
+                    Ok(_) => log::info!("node {} is not healthy yet", node.node_id),
+                    Err(e) => log::debug!("node {} health check failed '{}'", node.node_id, e),
+                }
+                if start.elapsed() > timeout {
+                    return Err(Error::new(
+                        ErrorKind::TimedOut,
+                        format!(
+                            "node {} not healthy after {:?}",
+                            node.node_id,
+                            start.elapsed()
+                        ),
+                    ));
+                }
+                sleep(interval).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the HTTP RPC endpoints of all nodes.
+    pub fn endpoints(&self) -> Vec<String> {
+        self.nodes.iter().map(|n| n.http_rpc.clone()).collect()
+    }
+
+    /// Kills all avalanchego processes.
+    pub async fn stop(&mut self) -> io::Result<()> {
+        for child in self.children.iter_mut() {
+            child.kill().await?;
+        }
+        self.children.clear();
+        Ok(())
+    }
+}
+
+fn path_str(p: &Path) -> String {
+    p.display().to_string()
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- testnet::test_prepare --exact --show-output
+#[test]
+fn test_prepare() {
+    let _ = env_logger::builder()
+        .filter_level(log::LevelFilter::Info)
+        .is_test(true)
+        .try_init();
+
+    let root_dir = tempfile::tempdir().unwrap();
+    let cfg = Config::new("avalanchego", root_dir.path().to_str().unwrap()).nodes(3);
+    let (genesis, nodes) = cfg.prepare().unwrap();
+    assert_eq!(nodes.len(), 3);
+    assert_eq!(genesis.initial_stakers.clone().unwrap().len(), 3);
+
+    assert_eq!(nodes[0].http_rpc, "http://127.0.0.1:19650");
+    assert_eq!(nodes[2].config.staking_port, 19655);
+    assert!(nodes[0].config.bootstrap_ids.is_none());
+    assert_eq!(
+        nodes[1].config.bootstrap_ids,
+        Some(nodes[0].node_id.to_string())
+    );
+    assert_eq!(nodes[2].config.snow_quorum_size, Some(2));
+
+    let loaded =
+        avalanchego_config::Config::load(nodes[1].config.config_file.as_ref().unwrap()).unwrap();
+    assert_eq!(loaded, nodes[1].config);
+
+    // certificates are reused across launches
+    let (_, nodes_2) = cfg.prepare().unwrap();
+    assert_eq!(nodes_2[2].node_id, nodes[2].node_id);
+
+    assert!(cfg.clone().nodes(0).prepare().is_err());
+}
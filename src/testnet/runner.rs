@@ -0,0 +1,322 @@
+//! Client for the avalanche-network-runner control API, through its
+//! gRPC gateway that serves the same messages in JSON over HTTP
+//! (e.g., "avalanche-network-runner server --grpc-gateway-port=:8081").
+//! ref. https://github.com/ava-labs/avalanche-network-runner/blob/main/rpcpb/rpc.proto
+
+use std::{
+    collections::BTreeMap,
+    io::{self, Error, ErrorKind},
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#StartRequest
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StartRequest {
+    pub exec_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_nodes: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub whitelisted_subnets: Option<String>,
+    /// Avalanchego flags in JSON applied to all nodes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub global_node_config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub root_data_dir: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub plugin_dir: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub blockchain_specs: Vec<BlockchainSpec>,
+    /// Avalanchego flags in JSON by node name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub custom_node_configs: BTreeMap<String, String>,
+    /// Chain configs in JSON by chain alias.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub chain_configs: BTreeMap<String, String>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#BlockchainSpec
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockchainSpec {
+    pub vm_name: String,
+    /// Genesis file path or contents.
+    pub genesis: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subnet_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chain_config: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_upgrade: Option<String>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#AddNodeRequest
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AddNodeRequest {
+    pub name: String,
+    pub exec_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_config: Option<String>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub chain_configs: BTreeMap<String, String>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#ClusterInfo
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterInfo {
+    #[serde(default)]
+    pub node_names: Vec<String>,
+    #[serde(default)]
+    pub node_infos: BTreeMap<String, NodeInfo>,
+    #[serde(default)]
+    pub pid: i32,
+    #[serde(default)]
+    pub root_data_dir: String,
+    #[serde(default)]
+    pub healthy: bool,
+    #[serde(default)]
+    pub custom_chains_healthy: bool,
+    /// Custom chain info by blockchain ID.
+    #[serde(default)]
+    pub custom_chains: BTreeMap<String, CustomChainInfo>,
+    #[serde(default)]
+    pub subnets: Vec<String>,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#NodeInfo
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeInfo {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub exec_path: String,
+    #[serde(default)]
+    pub uri: String,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub log_dir: String,
+    #[serde(default)]
+    pub db_dir: String,
+    #[serde(default)]
+    pub plugin_dir: String,
+    #[serde(default)]
+    pub whitelisted_subnets: String,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#CustomChainInfo
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomChainInfo {
+    #[serde(default)]
+    pub chain_name: String,
+    #[serde(rename = "vmId", default)]
+    pub vm_id: String,
+    #[serde(rename = "subnetId", default)]
+    pub subnet_id: String,
+    #[serde(rename = "chainId", default)]
+    pub chain_id: String,
+}
+
+/// Response of the control calls that return the cluster state
+/// (e.g., "start", "health", "status", "addnode", "removenode", "stop").
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterInfoResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cluster_info: Option<ClusterInfo>,
+}
+
+impl ClusterInfoResponse {
+    /// Returns the node URIs sorted by node name.
+    pub fn uris(&self) -> Vec<String> {
+        match &self.cluster_info {
+            Some(info) => info.node_infos.values().map(|n| n.uri.clone()).collect(),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#PingResponse
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PingResponse {
+    #[serde(default)]
+    pub pid: i32,
+}
+
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanche-network-runner/rpcpb#URIsResponse
+#[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UrisResponse {
+    #[serde(default)]
+    pub uris: Vec<String>,
+}
+
+/// "/v1/ping".
+pub async fn ping(http_rpc: &str) -> io::Result<PingResponse> {
+    call(http_rpc, "v1/ping", &serde_json::json!({})).await
+}
+
+/// "/v1/control/start" starts the network, and returns once the nodes
+/// are launched (use "health" to wait for the nodes to become healthy).
+pub async fn start(http_rpc: &str, req: &StartRequest) -> io::Result<ClusterInfoResponse> {
+    log::info!("starting network with {}", req.exec_path);
+    call(http_rpc, "v1/control/start", req).await
+}
+
+/// "/v1/control/health" waits for all nodes to become healthy.
+pub async fn health(http_rpc: &str) -> io::Result<ClusterInfoResponse> {
+    call(http_rpc, "v1/control/health", &serde_json::json!({})).await
+}
+
+/// "/v1/control/status".
+pub async fn status(http_rpc: &str) -> io::Result<ClusterInfoResponse> {
+    call(http_rpc, "v1/control/status", &serde_json::json!({})).await
+}
+
+/// "/v1/control/uris".
+pub async fn uris(http_rpc: &str) -> io::Result<UrisResponse> {
+    call(http_rpc, "v1/control/uris", &serde_json::json!({})).await
+}
+
+/// "/v1/control/addnode".
+pub async fn add_node(http_rpc: &str, req: &AddNodeRequest) -> io::Result<ClusterInfoResponse> {
+    log::info!("adding node {}", req.name);
+    call(http_rpc, "v1/control/addnode", req).await
+}
+
+/// "/v1/control/removenode".
+pub async fn remove_node(http_rpc: &str, name: &str) -> io::Result<ClusterInfoResponse> {
+    log::info!("removing node {}", name);
+    call(
+        http_rpc,
+        "v1/control/removenode",
+        &serde_json::json!({ "name": name }),
+    )
+    .await
+}
+
+/// "/v1/control/createsubnets" creates the subnets validated by the given nodes
+/// (all nodes if empty), one subnet per entry.
+pub async fn create_subnets(
+    http_rpc: &str,
+    participants: &[Vec<String>],
+) -> io::Result<ClusterInfoResponse> {
+    let specs: Vec<serde_json::Value> = participants
+        .iter()
+        .map(|p| serde_json::json!({ "participants": p }))
+        .collect();
+    call(
+        http_rpc,
+        "v1/control/createsubnets",
+        &serde_json::json!({ "subnetSpecs": specs }),
+    )
+    .await
+}
+
+/// "/v1/control/createblockchains".
+pub async fn create_blockchains(
+    http_rpc: &str,
+    specs: &[BlockchainSpec],
+) -> io::Result<ClusterInfoResponse> {
+    call(
+        http_rpc,
+        "v1/control/createblockchains",
+        &serde_json::json!({ "blockchainSpecs": specs }),
+    )
+    .await
+}
+
+/// "/v1/control/stop" stops all nodes.
+pub async fn stop(http_rpc: &str) -> io::Result<ClusterInfoResponse> {
+    log::info!("stopping network");
+    call(http_rpc, "v1/control/stop", &serde_json::json!({})).await
+}
+
+async fn call<Req, Resp>(http_rpc: &str, url_path: &str, req: &Req) -> io::Result<Resp>
+where
+    Req: Serialize,
+    Resp: DeserializeOwned,
+{
+    let d = serde_json::to_string(req).map_err(|e| {
+        Error::new(
+            ErrorKind::Other,
+            format!("failed to serialize {} request '{}'", url_path, e),
+        )
+    })?;
+    let rb = crate::client::transport::post_non_tls(http_rpc, url_path, &d).await?;
+    decode_response(url_path, &rb)
+}
+
+/// The gateway returns the gRPC status as "{code, message}" on failure.
+fn decode_response<Resp: DeserializeOwned>(url_path: &str, rb: &[u8]) -> io::Result<Resp> {
+    #[derive(Deserialize)]
+    struct Status {
+        code: i32,
+        message: String,
+    }
+    if let Ok(status) = serde_json::from_slice::<Status>(rb) {
+        if status.code != 0 {
+            return Err(Error::new(
+                ErrorKind::Other,
+                format!(
+                    "{} failed (code {}, message {})",
+                    url_path, status.code, status.message
+                ),
+            ));
+        }
+    }
+
+    serde_json::from_slice(rb)
+        .map_err(|e| Error::new(ErrorKind::Other, format!("failed {} '{}'", url_path, e)))
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- testnet::runner::test_runner_messages --exact --show-output
+#[test]
+fn test_runner_messages() {
+    let req = StartRequest {
+        exec_path: String::from("/usr/local/bin/avalanchego"),
+        num_nodes: Some(5),
+        blockchain_specs: vec![BlockchainSpec {
+            vm_name: String::from("subnetevm"),
+            genesis: String::from("/tmp/genesis.json"),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    let d = serde_json::to_string(&req).unwrap();
+    assert_eq!(
+        d,
+        "{\"execPath\":\"/usr/local/bin/avalanchego\",\"numNodes\":5,\"blockchainSpecs\":[{\"vmName\":\"subnetevm\",\"genesis\":\"/tmp/genesis.json\"}]}"
+    );
+
+    let resp: ClusterInfoResponse = decode_response(
+        "v1/control/health",
+        br#"{"clusterInfo":{"nodeNames":["node1","node2"],"nodeInfos":{"node2":{"name":"node2","uri":"http://127.0.0.1:9652","id":"NodeID-MFrZFVCXPv5iCn6M9K6XduxGTYp891xXZ"},"node1":{"name":"node1","uri":"http://127.0.0.1:9650","id":"NodeID-7Xhw2mDxuDS44j42TCB6U5579esbSt3Lg"}},"pid":123,"rootDataDir":"/tmp/network-runner","healthy":true,"customChains":{"2ebCneCbwthjQ1rYT41nhd7M76Hc6YmosMAQrTFhBq8qeqh6tt":{"chainName":"subnetevm","vmId":"srEXiWaHuhNyGwPUi444Tu47ZEDwxTWrbQiuD7FmgSAQ6X7Dy","subnetId":"p433wpuXyJiDhyazPYyZMJeaoPSW76CBZ2x7wrVPLgvokotXz","chainId":"2ebCneCbwthjQ1rYT41nhd7M76Hc6YmosMAQrTFhBq8qeqh6tt"}}}}"#,
+    )
+    .unwrap();
+    let info = resp.cluster_info.clone().unwrap();
+    assert!(info.healthy);
+    assert!(!info.custom_chains_healthy);
+    assert_eq!(info.node_names, vec!["node1", "node2"]);
+    assert_eq!(
+        resp.uris(),
+        vec!["http://127.0.0.1:9650", "http://127.0.0.1:9652"]
+    );
+    assert_eq!(
+        info.custom_chains.values().next().unwrap().vm_id.as_str(),
+        "srEXiWaHuhNyGwPUi444Tu47ZEDwxTWrbQiuD7FmgSAQ6X7Dy"
+    );
+
+    let err = decode_response::<ClusterInfoResponse>(
+        "v1/control/start",
+        br#"{"code":9,"message":"network already running","details":[]}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("network already running"));
+}
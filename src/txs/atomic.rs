@@ -0,0 +1,368 @@
+//! Atomic requests of the import and export transactions on the shared
+//! memory, byte-identical to the ones the node applies on acceptance, so
+//! a custom VM can exchange UTXOs with the X-chain and the P-chain.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/chains/atomic#Requests
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs/executor#StandardTxExecutor.ExportTx
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{avm, codec, ids, platformvm, txs};
+
+/// Value to put into the shared memory of the peer chain, indexed by
+/// its traits (e.g., the addresses of the UTXO owners).
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Element {
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+    pub traits: Vec<Vec<u8>>,
+}
+
+/// Atomic operations on the shared memory with a peer chain.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Requests {
+    /// Keys to remove from the shared memory of this chain.
+    pub remove_requests: Vec<Vec<u8>>,
+    /// Elements to put into the shared memory of the peer chain.
+    pub put_requests: Vec<Element>,
+}
+
+/// Returns the elements of the exported outputs, whose UTXOs are indexed
+/// after the "outs_len" outputs of the base transaction. The key is the
+/// UTXO input ID, the value the marshaled UTXO, and the traits the
+/// addresses of the owners.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#ExportTx
+pub fn export_elements(
+    tx_id: &ids::Id,
+    outs_len: usize,
+    exported_outs: &[txs::transferable::Output],
+) -> io::Result<Vec<Element>> {
+    let mut elems = Vec::with_capacity(exported_outs.len());
+    for (i, out) in exported_outs.iter().enumerate() {
+        let output_index = u32::try_from(outs_len + i).map_err(|_| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("output index {} overflows u32", outs_len + i),
+            )
+        })?;
+        let utxo = txs::utxo::Utxo {
+            utxo_id: txs::utxo::Id::new(tx_id.as_ref(), output_index, false)?,
+            asset_id: out.asset_id,
+            transfer_output: out.transfer_output.clone(),
+            stakeable_lock_out: out.stakeable_lock_out.clone(),
+        };
+        let value = utxo.pack(codec::VERSION)?.take_bytes().to_vec();
+
+        // only "secp256k1fx.TransferOutput" is "avax.Addressable"
+        let traits = match &out.transfer_output {
+            Some(o) => o
+                .output_owners
+                .addresses
+                .iter()
+                .map(|a| a.as_ref().to_vec())
+                .collect(),
+            None => Vec::new(),
+        };
+        elems.push(Element {
+            key: utxo.utxo_id.id.to_vec(),
+            value,
+            traits,
+        });
+    }
+    Ok(elems)
+}
+
+/// Returns the keys of the imported UTXOs to remove, which are their input IDs.
+pub fn import_remove_requests(
+    imported_ins: &[txs::transferable::Input],
+) -> io::Result<Vec<Vec<u8>>> {
+    imported_ins
+        .iter()
+        .map(|input| {
+            let id = input
+                .utxo_id
+                .tx_id
+                .prefix(&[input.utxo_id.output_index as u64])?;
+            Ok(id.to_vec())
+        })
+        .collect()
+}
+
+/// Transaction that operates on the shared memory with a peer chain.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs/executor#StandardTxExecutor.ImportTx
+pub trait AtomicOperations {
+    /// Returns the peer chain ID and the requests, where the transaction
+    /// must be signed since the exported UTXOs are keyed by its ID.
+    fn atomic_operations(&self) -> io::Result<(ids::Id, Requests)>;
+}
+
+fn signed_tx_id(tx_id: ids::Id) -> io::Result<ids::Id> {
+    if tx_id.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            "empty tx ID, the export tx must be signed",
+        ));
+    }
+    Ok(tx_id)
+}
+
+fn export_requests(
+    tx_id: ids::Id,
+    base_tx: &txs::Tx,
+    exported_outs: &Option<Vec<txs::transferable::Output>>,
+) -> io::Result<Requests> {
+    let outs_len = base_tx
+        .transferable_outputs
+        .as_ref()
+        .map_or(0, |outs| outs.len());
+    Ok(Requests {
+        remove_requests: Vec::new(),
+        put_requests: export_elements(
+            &signed_tx_id(tx_id)?,
+            outs_len,
+            exported_outs.as_deref().unwrap_or_default(),
+        )?,
+    })
+}
+
+fn import_requests(imported_ins: &Option<Vec<txs::transferable::Input>>) -> io::Result<Requests> {
+    Ok(Requests {
+        remove_requests: import_remove_requests(imported_ins.as_deref().unwrap_or_default())?,
+        put_requests: Vec::new(),
+    })
+}
+
+impl AtomicOperations for platformvm::txs::export::Tx {
+    fn atomic_operations(&self) -> io::Result<(ids::Id, Requests)> {
+        let reqs = export_requests(
+            self.tx_id(),
+            &self.base_tx,
+            &self.destination_chain_transferable_outputs,
+        )?;
+        Ok((self.destination_chain_id, reqs))
+    }
+}
+
+impl AtomicOperations for platformvm::txs::import::Tx {
+    fn atomic_operations(&self) -> io::Result<(ids::Id, Requests)> {
+        let reqs = import_requests(&self.source_chain_transferable_inputs)?;
+        Ok((self.source_chain_id, reqs))
+    }
+}
+
+impl AtomicOperations for avm::txs::export::Tx {
+    fn atomic_operations(&self) -> io::Result<(ids::Id, Requests)> {
+        let reqs = export_requests(
+            self.tx_id(),
+            &self.base_tx,
+            &self.destination_chain_transferable_outputs,
+        )?;
+        Ok((self.destination_chain_id, reqs))
+    }
+}
+
+impl AtomicOperations for avm::txs::import::Tx {
+    fn atomic_operations(&self) -> io::Result<(ids::Id, Requests)> {
+        let reqs = import_requests(&self.source_chain_transferable_inputs)?;
+        Ok((self.source_chain_id, reqs))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::atomic::test_atomic_operations --exact --show-output
+#[test]
+fn test_atomic_operations() {
+    use crate::{ids::short, key};
+
+    let tx_id = ids::Id::from_slice(&[7; 32]);
+    let chain_id = ids::Id::from_slice(&[9; 32]);
+    let owner = short::Id::from_slice(&[3; 20]);
+    let out = txs::transferable::Output {
+        asset_id: ids::Id::from_slice(&[1; 32]),
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 1000,
+            output_owners: key::secp256k1::txs::OutputOwners::new(
+                0,
+                1,
+                std::slice::from_ref(&owner),
+            ),
+        }),
+        ..txs::transferable::Output::default()
+    };
+
+    let mut tx = platformvm::txs::export::Tx::new(txs::Tx {
+        transferable_outputs: Some(vec![out.clone()]),
+        ..txs::Tx::default()
+    });
+    tx.destination_chain_id = chain_id;
+    tx.destination_chain_transferable_outputs = Some(vec![out.clone(), out]);
+    assert!(tx.atomic_operations().is_err());
+
+    tx.base_tx.metadata = Some(txs::Metadata {
+        id: tx_id,
+        ..txs::Metadata::default()
+    });
+    let (peer, reqs) = tx.atomic_operations().unwrap();
+    assert_eq!(peer, chain_id);
+    assert!(reqs.remove_requests.is_empty());
+    assert_eq!(reqs.put_requests.len(), 2);
+    for (i, elem) in reqs.put_requests.iter().enumerate() {
+        // indexed after the base tx output
+        let output_index = 1 + i as u32;
+        assert_eq!(
+            elem.key,
+            tx_id.prefix(&[output_index as u64]).unwrap().to_vec()
+        );
+        assert_eq!(elem.traits, vec![owner.as_ref().to_vec()]);
+
+        let utxo = txs::utxo::Utxo::unpack(&elem.value).unwrap();
+        assert_eq!(utxo.utxo_id.tx_id, tx_id);
+        assert_eq!(utxo.utxo_id.output_index, output_index);
+        assert_eq!(utxo.transfer_output.unwrap().amount, 1000);
+    }
+
+    // the importing chain removes the same keys
+    let mut import_tx = avm::txs::import::Tx::default();
+    import_tx.source_chain_id = chain_id;
+    import_tx.source_chain_transferable_inputs = Some(
+        (1..3)
+            .map(|output_index| txs::transferable::Input {
+                utxo_id: txs::utxo::Id {
+                    tx_id,
+                    output_index,
+                    ..txs::utxo::Id::default()
+                },
+                ..txs::transferable::Input::default()
+            })
+            .collect(),
+    );
+    let (peer, reqs) = import_tx.atomic_operations().unwrap();
+    assert_eq!(peer, chain_id);
+    assert!(reqs.put_requests.is_empty());
+    let put_keys: Vec<Vec<u8>> = tx
+        .atomic_operations()
+        .unwrap()
+        .1
+        .put_requests
+        .into_iter()
+        .map(|e| e.key)
+        .collect();
+    assert_eq!(reqs.remove_requests, put_keys);
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::atomic::test_atomic_operations_golden_export --exact --show-output
+/// ref. "avalanchego/vms/platformvm.TestNewExportTx"
+#[test]
+fn test_atomic_operations_golden_export() {
+    use crate::{ids::short, key};
+
+    macro_rules! ab {
+        ($e:expr) => {
+            tokio_test::block_on($e)
+        };
+    }
+
+    // signed "platformvm.UnsignedExportTx" of one exported output
+    // ref. "platformvm::txs::export::test_export_tx_serialization_with_one_signer"
+    let golden = hex::decode(
+        "0000000000120000000a000000000000000000000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000000000000007965657400000000000000000000000000000000000000000000000000000000000000050000000\
+01dcd65000000000100000000000000002c34ce1df23b838c5abf2a7f6437cca3d3067ed509ff25f11df6b11b582b51eb00000001796565740000000000000000000000000000000000000000000000000000000000000007000000001dcd649c000000000000000000000001000000013d0ad12b8ee8928edf248ca91ca55600fb383f07000000010000000900000001e2615afb7adec6f0a6ba4e6e235181ea3d8211d9c8890d031ff241e74cb1cdda25a187d89a8fc838cf8255e7b3429097aadd2e5f1afa6723e5ab373c7d94cab801",
+    )
+    .unwrap();
+    // "avax.TransferableOutput" of the exported output in the signed bytes
+    let golden_out = &golden[178..258];
+
+    let asset_id = ids::Id::from_slice(&[0x79, 0x65, 0x65, 0x74]);
+    let owner = short::Id::from_slice(&[
+        0x3d, 0x0a, 0xd1, 0x2b, 0x8e, 0xe8, 0x92, 0x8e, 0xdf, 0x24, //
+        0x8c, 0xa9, 0x1c, 0xa5, 0x56, 0x00, 0xfb, 0x38, 0x3f, 0x07, //
+    ]);
+    let chain_id = ids::Id::from_slice(&[
+        0x2c, 0x34, 0xce, 0x1d, 0xf2, 0x3b, 0x83, 0x8c, //
+        0x5a, 0xbf, 0x2a, 0x7f, 0x64, 0x37, 0xcc, 0xa3, //
+        0xd3, 0x06, 0x7e, 0xd5, 0x09, 0xff, 0x25, 0xf1, //
+        0x1d, 0xf6, 0xb1, 0x1b, 0x58, 0x2b, 0x51, 0xeb, //
+    ]);
+    let mut tx = platformvm::txs::export::Tx::new(txs::Tx {
+        network_id: 10,
+        transferable_inputs: Some(vec![txs::transferable::Input {
+            utxo_id: txs::utxo::Id {
+                id: chain_id,
+                ..txs::utxo::Id::default()
+            },
+            asset_id,
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 500000000,
+                sig_indices: vec![0],
+            }),
+            ..txs::transferable::Input::default()
+        }]),
+        ..txs::Tx::default()
+    });
+    tx.destination_chain_id = chain_id;
+    tx.destination_chain_transferable_outputs = Some(vec![txs::transferable::Output {
+        asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output {
+            amount: 499999900,
+            output_owners: key::secp256k1::txs::OutputOwners::new(
+                0,
+                1,
+                std::slice::from_ref(&owner),
+            ),
+        }),
+        ..txs::transferable::Output::default()
+    }]);
+
+    // ref. "avalanchego/vms/platformvm/vm_test.go"
+    let test_key = key::secp256k1::private_key::Key::from_cb58(
+        "PrivateKey-24jUJ9vZexUM6expyMcT48LBx27k1m7xpraoV62oSQAHdziao5",
+    )
+    .unwrap();
+    ab!(tx.sign(vec![vec![test_key]])).unwrap();
+    assert_eq!(
+        tx.base_tx
+            .metadata
+            .clone()
+            .unwrap()
+            .tx_bytes_with_signatures,
+        golden
+    );
+    let tx_id = tx.tx_id();
+    assert_eq!(
+        tx_id.to_string(),
+        "xjRjs4pcDFBwJR4kAKMtVHNLQEdhswojNPqXKVgwsjCDsn4rE"
+    );
+
+    let (peer, reqs) = tx.atomic_operations().unwrap();
+    assert_eq!(peer, chain_id);
+    assert!(reqs.remove_requests.is_empty());
+    assert_eq!(reqs.put_requests.len(), 1);
+
+    // "avax.UTXO" is the codec version, the UTXO ID (tx ID and output index),
+    // and then the same bytes as the exported output
+    let elem = &reqs.put_requests[0];
+    let mut expected_value = vec![0x00, 0x00];
+    expected_value.extend_from_slice(tx_id.as_ref());
+    expected_value.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]);
+    expected_value.extend_from_slice(golden_out);
+    assert_eq!(elem.value, expected_value);
+    assert_eq!(elem.key, tx_id.prefix(&[0]).unwrap().to_vec());
+    assert_eq!(elem.traits, vec![owner.as_ref().to_vec()]);
+
+    // the value unpacks and packs back to the same bytes
+    let utxo = txs::utxo::Utxo::unpack(&elem.value).unwrap();
+    assert_eq!(utxo.utxo_id.tx_id, tx_id);
+    assert_eq!(utxo.utxo_id.output_index, 0);
+    assert_eq!(
+        utxo.pack(codec::VERSION).unwrap().take_bytes().to_vec(),
+        elem.value
+    );
+
+    // importing the UTXO on the peer chain removes the same key
+    let mut import_tx = platformvm::txs::import::Tx::default();
+    import_tx.source_chain_transferable_inputs = Some(vec![txs::transferable::Input {
+        utxo_id: utxo.utxo_id,
+        asset_id: utxo.asset_id,
+        ..txs::transferable::Input::default()
+    }]);
+    let (_, reqs) = import_tx.atomic_operations().unwrap();
+    assert_eq!(reqs.remove_requests, vec![elem.key.clone()]);
+}
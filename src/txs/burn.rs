@@ -0,0 +1,500 @@
+//! AVAX burn accounting for supply analytics.
+//!
+//! On the X and P-chain, the fee of a transaction is the AVAX that its inputs
+//! consume and its outputs do not return, where the imported inputs and the
+//! exported and staked outputs count as well. On the C-chain, the coinbase of
+//! every block is the blackhole address, so the whole gas fee of every
+//! transaction is burned, the tip included: the gas used times the effective
+//! gas price of its receipt, before and since Apricot Phase 3 alike. The
+//! C-chain atomic transactions burn their AVAX like the X and P-chain ones.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#FlowChecker
+//! ref. https://pkg.go.dev/github.com/ava-labs/coreth/constants#BlackholeAddr
+//! ref. https://pkg.go.dev/github.com/ava-labs/coreth/plugin/evm#UnsignedImportTx.Burned
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{avm, ids, platformvm, txs::transferable};
+use primitive_types::U256;
+
+pub use crate::units::WEI_PER_NANO_AVAX;
+
+/// Returns the amount of the input in its asset.
+pub fn input_amount(input: &transferable::Input) -> u64 {
+    if let Some(transfer_input) = &input.transfer_input {
+        return transfer_input.amount;
+    }
+    input
+        .stakeable_lock_in
+        .as_ref()
+        .map(|lock_in| lock_in.transfer_input.amount)
+        .unwrap_or_default()
+}
+
+/// Returns the amount of the output in its asset.
+pub fn output_amount(output: &transferable::Output) -> u64 {
+    if let Some(transfer_output) = &output.transfer_output {
+        return transfer_output.amount;
+    }
+    output
+        .stakeable_lock_out
+        .as_ref()
+        .map(|lock_out| lock_out.transfer_output.amount)
+        .unwrap_or_default()
+}
+
+fn sum(amounts: impl Iterator<Item = u64>, what: &str) -> io::Result<u64> {
+    let mut total = 0_u64;
+    for amount in amounts {
+        total = total.checked_add(amount).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("{} amounts overflow u64", what),
+            )
+        })?;
+    }
+    Ok(total)
+}
+
+/// Returns the AVAX consumed by the inputs and not returned by the outputs.
+/// Fails if the outputs exceed the inputs, which the node never accepts.
+pub fn burned<'a>(
+    avax_asset_id: &ids::Id,
+    ins: impl IntoIterator<Item = &'a transferable::Input>,
+    outs: impl IntoIterator<Item = &'a transferable::Output>,
+) -> io::Result<u64> {
+    burned_amounts(
+        ins.into_iter()
+            .filter(|i| i.asset_id == *avax_asset_id)
+            .map(input_amount),
+        outs.into_iter()
+            .filter(|o| o.asset_id == *avax_asset_id)
+            .map(output_amount),
+    )
+}
+
+fn burned_amounts(
+    consumed: impl Iterator<Item = u64>,
+    produced: impl Iterator<Item = u64>,
+) -> io::Result<u64> {
+    let consumed = sum(consumed, "input")?;
+    let produced = sum(produced, "output")?;
+    consumed.checked_sub(produced).ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("AVAX outputs {} exceed the inputs {}", produced, consumed),
+        )
+    })
+}
+
+/// Transaction that burns AVAX, as its static fee.
+pub trait Burn {
+    /// Returns the AVAX burned by the transaction in nano-AVAX.
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64>;
+}
+
+impl Burn for crate::txs::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned(
+            avax_asset_id,
+            self.transferable_inputs.iter().flatten(),
+            self.transferable_outputs.iter().flatten(),
+        )
+    }
+}
+
+impl Burn for avm::txs::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        self.base_tx.burned(avax_asset_id)
+    }
+}
+
+impl Burn for avm::txs::import::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned(
+            avax_asset_id,
+            self.base_tx
+                .transferable_inputs
+                .iter()
+                .flatten()
+                .chain(self.source_chain_transferable_inputs.iter().flatten()),
+            self.base_tx.transferable_outputs.iter().flatten(),
+        )
+    }
+}
+
+impl Burn for avm::txs::export::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned(
+            avax_asset_id,
+            self.base_tx.transferable_inputs.iter().flatten(),
+            self.base_tx
+                .transferable_outputs
+                .iter()
+                .flatten()
+                .chain(self.destination_chain_transferable_outputs.iter().flatten()),
+        )
+    }
+}
+
+impl Burn for platformvm::txs::import::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned(
+            avax_asset_id,
+            self.base_tx
+                .transferable_inputs
+                .iter()
+                .flatten()
+                .chain(self.source_chain_transferable_inputs.iter().flatten()),
+            self.base_tx.transferable_outputs.iter().flatten(),
+        )
+    }
+}
+
+impl Burn for platformvm::txs::export::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned(
+            avax_asset_id,
+            self.base_tx.transferable_inputs.iter().flatten(),
+            self.base_tx
+                .transferable_outputs
+                .iter()
+                .flatten()
+                .chain(self.destination_chain_transferable_outputs.iter().flatten()),
+        )
+    }
+}
+
+/// The staked outputs are returned at the end of the staking period,
+/// so they are not burned.
+impl Burn for platformvm::txs::add_validator::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned(
+            avax_asset_id,
+            self.base_tx.transferable_inputs.iter().flatten(),
+            self.base_tx
+                .transferable_outputs
+                .iter()
+                .flatten()
+                .chain(self.stake_transferable_outputs.iter().flatten()),
+        )
+    }
+}
+
+impl Burn for platformvm::txs::add_subnet_validator::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        self.base_tx.burned(avax_asset_id)
+    }
+}
+
+impl Burn for platformvm::txs::create_subnet::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        self.base_tx.burned(avax_asset_id)
+    }
+}
+
+impl Burn for platformvm::txs::create_chain::Tx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        self.base_tx.burned(avax_asset_id)
+    }
+}
+
+#[cfg(feature = "evm")]
+impl Burn for crate::evm::txs::atomic::ImportTx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned_amounts(
+            self.imported_inputs
+                .iter()
+                .filter(|i| i.asset_id == *avax_asset_id)
+                .map(input_amount),
+            self.outs
+                .iter()
+                .filter(|o| o.asset_id == *avax_asset_id)
+                .map(|o| o.amount),
+        )
+    }
+}
+
+#[cfg(feature = "evm")]
+impl Burn for crate::evm::txs::atomic::ExportTx {
+    fn burned(&self, avax_asset_id: &ids::Id) -> io::Result<u64> {
+        burned_amounts(
+            self.ins
+                .iter()
+                .filter(|i| i.asset_id == *avax_asset_id)
+                .map(|i| i.amount),
+            self.exported_outputs
+                .iter()
+                .filter(|o| o.asset_id == *avax_asset_id)
+                .map(output_amount),
+        )
+    }
+}
+
+/// Gas fields of a C-chain transaction receipt.
+/// ref. https://ethereum.org/en/developers/docs/apis/json-rpc/#eth_gettransactionreceipt
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct Receipt {
+    pub gas_used: U256,
+    /// The gas price before Apricot Phase 3, and the base fee plus
+    /// the tip since.
+    pub effective_gas_price: U256,
+}
+
+impl Receipt {
+    /// Returns the gas fee burned by the transaction in wei.
+    pub fn burned(&self) -> U256 {
+        self.gas_used.saturating_mul(self.effective_gas_price)
+    }
+}
+
+/// Burned AVAX over a range of blocks of a chain, accumulated from the
+/// transactions of the X or P-chain blocks, or the receipts and the atomic
+/// transactions of the C-chain blocks.
+#[derive(Debug, Clone, Eq, PartialEq, Default)]
+pub struct Totals {
+    /// Lowest and highest heights of the accumulated blocks.
+    pub from_height: Option<u64>,
+    pub to_height: Option<u64>,
+    pub blocks: u64,
+    pub txs: u64,
+    /// Static fees of the X and P-chain transactions, and the C-chain
+    /// atomic transactions in nano-AVAX.
+    pub static_fees: u64,
+    /// Gas fees of the C-chain transactions in wei.
+    pub gas_fees: U256,
+}
+
+impl Totals {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn add_height(&mut self, height: u64) {
+        self.blocks += 1;
+        self.from_height = Some(self.from_height.map_or(height, |h| h.min(height)));
+        self.to_height = Some(self.to_height.map_or(height, |h| h.max(height)));
+    }
+
+    fn add_static_fees(&mut self, txs: &[&dyn Burn], avax_asset_id: &ids::Id) -> io::Result<()> {
+        let mut fees = 0_u64;
+        for tx in txs.iter() {
+            fees = fees
+                .checked_add(tx.burned(avax_asset_id)?)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "block fees overflow u64"))?;
+        }
+        self.static_fees = self
+            .static_fees
+            .checked_add(fees)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "total static fees overflow u64"))?;
+        self.txs += txs.len() as u64;
+        Ok(())
+    }
+
+    /// Adds an X or P-chain block with its transactions.
+    pub fn add_block(
+        &mut self,
+        height: u64,
+        txs: &[&dyn Burn],
+        avax_asset_id: &ids::Id,
+    ) -> io::Result<()> {
+        self.add_static_fees(txs, avax_asset_id)?;
+        self.add_height(height);
+        Ok(())
+    }
+
+    /// Adds a C-chain block with the receipts of its transactions,
+    /// and its atomic transactions (in the block extra data).
+    pub fn add_evm_block(
+        &mut self,
+        height: u64,
+        receipts: &[Receipt],
+        atomic_txs: &[&dyn Burn],
+        avax_asset_id: &ids::Id,
+    ) -> io::Result<()> {
+        self.add_static_fees(atomic_txs, avax_asset_id)?;
+        for receipt in receipts.iter() {
+            self.gas_fees = self.gas_fees.saturating_add(receipt.burned());
+        }
+        self.txs += receipts.len() as u64;
+        self.add_height(height);
+        Ok(())
+    }
+
+    /// Returns the total burned in nano-AVAX, where the wei below
+    /// one nano-AVAX are truncated.
+    pub fn total_nano_avax(&self) -> U256 {
+        U256::from(self.static_fees).saturating_add(self.gas_fees / U256::from(WEI_PER_NANO_AVAX))
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::burn::test_burned --exact --show-output
+#[test]
+fn test_burned() {
+    use crate::{key::secp256k1::txs::transfer, txs};
+
+    let avax = ids::Id::sha256(b"AVAX");
+    let other = ids::Id::sha256(b"other");
+    let input = |asset_id: ids::Id, amount: u64| transferable::Input {
+        asset_id,
+        transfer_input: Some(transfer::Input {
+            amount,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let output = |asset_id: ids::Id, amount: u64| transferable::Output {
+        asset_id,
+        transfer_output: Some(transfer::Output {
+            amount,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    // other assets are moved, not burned
+    let base_tx = txs::Tx {
+        transferable_inputs: Some(vec![input(avax, 1_000_000), input(other, 50)]),
+        transferable_outputs: Some(vec![output(avax, 999_000), output(other, 50)]),
+        ..Default::default()
+    };
+    assert_eq!(base_tx.burned(&avax).unwrap(), 1_000);
+
+    let import_tx = avm::txs::import::Tx {
+        base_tx: txs::Tx {
+            transferable_outputs: Some(vec![output(avax, 4_000_000)]),
+            ..Default::default()
+        },
+        source_chain_transferable_inputs: Some(vec![input(avax, 5_000_000)]),
+        ..Default::default()
+    };
+    assert_eq!(import_tx.burned(&avax).unwrap(), 1_000_000);
+
+    let export_tx = platformvm::txs::export::Tx {
+        base_tx: txs::Tx {
+            transferable_inputs: Some(vec![input(avax, 5_000_000)]),
+            ..Default::default()
+        },
+        destination_chain_transferable_outputs: Some(vec![output(avax, 4_000_000)]),
+        ..Default::default()
+    };
+    assert_eq!(export_tx.burned(&avax).unwrap(), 1_000_000);
+
+    let add_validator_tx = platformvm::txs::add_validator::Tx {
+        base_tx: txs::Tx {
+            transferable_inputs: Some(vec![input(avax, 2_000_000_000_000)]),
+            ..Default::default()
+        },
+        stake_transferable_outputs: Some(vec![output(avax, 2_000_000_000_000)]),
+        ..Default::default()
+    };
+    assert_eq!(add_validator_tx.burned(&avax).unwrap(), 0);
+
+    let invalid = txs::Tx {
+        transferable_inputs: Some(vec![input(avax, 1)]),
+        transferable_outputs: Some(vec![output(avax, 2)]),
+        ..Default::default()
+    };
+    assert!(invalid.burned(&avax).is_err());
+
+    let mut totals = Totals::new();
+    totals
+        .add_block(11, &[&base_tx, &import_tx], &avax)
+        .unwrap();
+    totals.add_block(10, &[&export_tx], &avax).unwrap();
+    assert!(totals.add_block(12, &[&invalid], &avax).is_err());
+    assert_eq!(totals.blocks, 2);
+    assert_eq!(totals.txs, 3);
+    assert_eq!((totals.from_height, totals.to_height), (Some(10), Some(11)));
+    assert_eq!(totals.static_fees, 2_001_000);
+    assert_eq!(totals.total_nano_avax(), U256::from(2_001_000));
+
+    // 21,000 gas at 25 nAVAX base fee plus 2 nAVAX tip, and at the
+    // 470 nAVAX gas price before Apricot Phase 3
+    let mut totals = Totals::new();
+    totals
+        .add_evm_block(
+            100,
+            &[Receipt {
+                gas_used: U256::from(21_000),
+                effective_gas_price: U256::from(27_000_000_000_u64),
+            }],
+            &[],
+            &avax,
+        )
+        .unwrap();
+    totals
+        .add_evm_block(
+            101,
+            &[Receipt {
+                gas_used: U256::from(21_000),
+                effective_gas_price: U256::from(470_000_000_000_u64),
+            }],
+            &[],
+            &avax,
+        )
+        .unwrap();
+    assert_eq!(totals.blocks, 2);
+    assert_eq!(totals.txs, 2);
+    assert_eq!(totals.gas_fees, U256::from(10_437_000_000_000_000_u64));
+    assert_eq!(totals.total_nano_avax(), U256::from(10_437_000));
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib --features evm -- txs::burn::test_burned_atomic --exact --show-output
+#[cfg(feature = "evm")]
+#[test]
+fn test_burned_atomic() {
+    use crate::{evm::txs::atomic, key::secp256k1::txs::transfer};
+
+    let avax = ids::Id::sha256(b"AVAX");
+    let import_tx = atomic::ImportTx {
+        imported_inputs: vec![transferable::Input {
+            asset_id: avax,
+            transfer_input: Some(transfer::Input {
+                amount: 1_000_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        outs: vec![atomic::Output {
+            amount: 999_000,
+            asset_id: avax,
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(import_tx.burned(&avax).unwrap(), 1_000);
+
+    let export_tx = atomic::ExportTx {
+        ins: vec![atomic::Input {
+            amount: 1_000_000,
+            asset_id: avax,
+            ..Default::default()
+        }],
+        exported_outputs: vec![transferable::Output {
+            asset_id: avax,
+            transfer_output: Some(transfer::Output {
+                amount: 990_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+    assert_eq!(export_tx.burned(&avax).unwrap(), 10_000);
+
+    let mut totals = Totals::new();
+    totals
+        .add_evm_block(
+            7,
+            &[Receipt {
+                gas_used: U256::from(21_000),
+                effective_gas_price: U256::from(25_000_000_000_u64),
+            }],
+            &[&import_tx, &export_tx],
+            &avax,
+        )
+        .unwrap();
+    assert_eq!(totals.txs, 3);
+    assert_eq!(totals.static_fees, 11_000);
+    assert_eq!(totals.total_nano_avax(), U256::from(536_000));
+}
@@ -0,0 +1,659 @@
+//! Human-readable rendering of the parsed transactions, for the CLI tools
+//! and the debugging output: the asset IDs are resolved to their symbols,
+//! the amounts are in the asset units (e.g., "1.5 AVAX"), and the owners
+//! are in bech32 (e.g., "P-avax1...").
+//!
+//! ```
+//! use avalanche_types::{ids, txs::{self, display::{Context, Render}}};
+//!
+//! let tx = txs::Tx { network_id: 1, ..Default::default() };
+//! let ctx = Context::new(1, ids::Id::sha256(b"AVAX"));
+//! println!("{}", tx.render(&ctx).to_text());
+//! ```
+
+use std::collections::HashMap;
+
+use crate::{
+    avm, constants, formatting, ids, key, platformvm,
+    txs::{self, burn::Burn, transferable},
+    units,
+};
+use chrono::{TimeZone, Utc};
+use primitive_types::U256;
+
+/// Rendered transaction, with the fields in the order of the transaction.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Node {
+    Text(String),
+    Number(u64),
+    Fields(Vec<(String, Node)>),
+    List(Vec<Node>),
+}
+
+impl Node {
+    fn text(s: impl Into<String>) -> Self {
+        Node::Text(s.into())
+    }
+
+    /// Returns the field, if any.
+    pub fn get(&self, key: &str) -> Option<&Node> {
+        match self {
+            Node::Fields(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+            _ => None,
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            Node::Text(s) => serde_json::Value::from(s.as_str()),
+            Node::Number(n) => serde_json::Value::from(*n),
+            Node::Fields(fields) => serde_json::Value::Object(
+                fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.to_json()))
+                    .collect(),
+            ),
+            Node::List(items) => {
+                serde_json::Value::Array(items.iter().map(Node::to_json).collect())
+            }
+        }
+    }
+
+    /// Returns the multi-line rendering, indented by two spaces per level.
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+        match self {
+            Node::Fields(fields) => write_fields(&mut out, 0, fields),
+            Node::List(items) => write_items(&mut out, 0, items),
+            Node::Text(s) => out.push_str(s),
+            Node::Number(n) => out.push_str(&n.to_string()),
+        }
+        out
+    }
+}
+
+fn write_fields(out: &mut String, indent: usize, fields: &[(String, Node)]) {
+    for (k, v) in fields {
+        out.push_str(&" ".repeat(indent));
+        out.push_str(k);
+        out.push(':');
+        write_child(out, indent, v);
+    }
+}
+
+fn write_items(out: &mut String, indent: usize, items: &[Node]) {
+    for (i, v) in items.iter().enumerate() {
+        out.push_str(&format!("{}[{}]:", " ".repeat(indent), i));
+        write_child(out, indent, v);
+    }
+}
+
+fn write_child(out: &mut String, indent: usize, v: &Node) {
+    match v {
+        Node::Text(s) => out.push_str(&format!(" {}\n", s)),
+        Node::Number(n) => out.push_str(&format!(" {}\n", n)),
+        Node::Fields(fields) if fields.is_empty() => out.push_str(" {}\n"),
+        Node::List(items) if items.is_empty() => out.push_str(" []\n"),
+        Node::Fields(fields) => {
+            out.push('\n');
+            write_fields(out, indent + 2, fields);
+        }
+        Node::List(items) => {
+            out.push('\n');
+            write_items(out, indent + 2, items);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Asset {
+    pub symbol: String,
+    pub denomination: u8,
+}
+
+/// Resolves the assets, the chains and the addresses of the network.
+#[derive(Debug, Clone)]
+pub struct Context {
+    pub hrp: String,
+    pub avax_asset_id: ids::Id,
+    pub assets: HashMap<ids::Id, Asset>,
+    /// Chain aliases (e.g., "X", "P", "C") by blockchain ID.
+    pub chains: HashMap<ids::Id, String>,
+}
+
+impl Context {
+    pub fn new(network_id: u32, avax_asset_id: ids::Id) -> Self {
+        let mut assets = HashMap::new();
+        assets.insert(
+            avax_asset_id,
+            Asset {
+                symbol: String::from("AVAX"),
+                denomination: 9,
+            },
+        );
+        Self {
+            hrp: constants::get_hrp(network_id).to_string(),
+            avax_asset_id,
+            assets,
+            chains: HashMap::new(),
+        }
+    }
+
+    /// Sets the HRP, for the private networks with their own HRP.
+    #[must_use]
+    pub fn hrp(mut self, hrp: &str) -> Self {
+        self.hrp = hrp.to_string();
+        self
+    }
+
+    /// Adds the asset (e.g., from "avm.getAssetDescription").
+    #[must_use]
+    pub fn asset(mut self, asset_id: ids::Id, symbol: &str, denomination: u8) -> Self {
+        self.assets.insert(
+            asset_id,
+            Asset {
+                symbol: symbol.to_string(),
+                denomination,
+            },
+        );
+        self
+    }
+
+    /// Adds the chain alias (e.g., from "info.getBlockchainID").
+    #[must_use]
+    pub fn chain(mut self, blockchain_id: ids::Id, alias: &str) -> Self {
+        self.chains.insert(blockchain_id, alias.to_string());
+        self
+    }
+
+    /// Returns the symbol of the asset, or its ID if unknown.
+    pub fn asset_name(&self, asset_id: &ids::Id) -> String {
+        self.assets
+            .get(asset_id)
+            .map(|a| a.symbol.clone())
+            .unwrap_or_else(|| asset_id.to_string())
+    }
+
+    /// Returns the amount in the units of the asset (e.g., "1.5 AVAX"),
+    /// or in its smallest unit if unknown.
+    pub fn amount(&self, asset_id: &ids::Id, amount: u64) -> String {
+        match self.assets.get(asset_id) {
+            Some(a) => format!(
+                "{} {}",
+                units::format_units(U256::from(amount), a.denomination),
+                a.symbol
+            ),
+            None => format!("{} (asset {})", amount, asset_id),
+        }
+    }
+
+    /// Returns the blockchain ID with its alias, if known.
+    pub fn chain_name(&self, blockchain_id: &ids::Id) -> String {
+        match self.chains.get(blockchain_id) {
+            Some(alias) => format!("{} ({})", blockchain_id, alias),
+            None => blockchain_id.to_string(),
+        }
+    }
+
+    /// Returns the bech32 address on the chain (e.g., "X-avax1...").
+    pub fn address(&self, chain_alias: &str, addr: &ids::short::Id) -> String {
+        formatting::address(chain_alias, &self.hrp, addr.as_ref())
+            .unwrap_or_else(|_| addr.to_string())
+    }
+
+    fn owners(&self, chain_alias: &str, owners: &key::secp256k1::txs::OutputOwners) -> Node {
+        let mut fields = vec![(
+            String::from("threshold"),
+            Node::Number(owners.threshold as u64),
+        )];
+        if owners.locktime > 0 {
+            fields.push((String::from("locktime"), time(owners.locktime)));
+        }
+        fields.push((
+            String::from("addresses"),
+            Node::List(
+                owners
+                    .addresses
+                    .iter()
+                    .map(|a| Node::text(self.address(chain_alias, a)))
+                    .collect(),
+            ),
+        ));
+        Node::Fields(fields)
+    }
+
+    fn input(&self, input: &transferable::Input) -> Node {
+        let mut fields = vec![
+            (
+                String::from("utxo"),
+                Node::text(format!(
+                    "{}:{}",
+                    input.utxo_id.tx_id, input.utxo_id.output_index
+                )),
+            ),
+            (
+                String::from("asset"),
+                Node::text(self.asset_name(&input.asset_id)),
+            ),
+            (
+                String::from("amount"),
+                Node::text(self.amount(&input.asset_id, txs::burn::input_amount(input))),
+            ),
+        ];
+        if let Some(lock_in) = &input.stakeable_lock_in {
+            fields.push((String::from("locktime"), time(lock_in.locktime)));
+        }
+        let sig_indices = match (&input.transfer_input, &input.stakeable_lock_in) {
+            (Some(transfer_input), _) => transfer_input.sig_indices.clone(),
+            (None, Some(lock_in)) => lock_in.transfer_input.sig_indices.clone(),
+            (None, None) => Vec::new(),
+        };
+        fields.push((
+            String::from("sigIndices"),
+            Node::List(
+                sig_indices
+                    .into_iter()
+                    .map(|i| Node::Number(i as u64))
+                    .collect(),
+            ),
+        ));
+        Node::Fields(fields)
+    }
+
+    fn output(&self, chain_alias: &str, output: &transferable::Output) -> Node {
+        let mut fields = vec![
+            (
+                String::from("asset"),
+                Node::text(self.asset_name(&output.asset_id)),
+            ),
+            (
+                String::from("amount"),
+                Node::text(self.amount(&output.asset_id, txs::burn::output_amount(output))),
+            ),
+        ];
+        let owners = match (&output.transfer_output, &output.stakeable_lock_out) {
+            (Some(transfer_output), _) => Some(&transfer_output.output_owners),
+            (None, Some(lock_out)) => {
+                fields.push((String::from("locktime"), time(lock_out.locktime)));
+                Some(&lock_out.transfer_output.output_owners)
+            }
+            (None, None) => None,
+        };
+        if let Some(owners) = owners {
+            fields.push((String::from("owners"), self.owners(chain_alias, owners)));
+        }
+        Node::Fields(fields)
+    }
+
+    fn inputs<'a>(&self, ins: impl IntoIterator<Item = &'a transferable::Input>) -> Node {
+        Node::List(ins.into_iter().map(|i| self.input(i)).collect())
+    }
+
+    fn outputs<'a>(
+        &self,
+        chain_alias: &str,
+        outs: impl IntoIterator<Item = &'a transferable::Output>,
+    ) -> Node {
+        Node::List(
+            outs.into_iter()
+                .map(|o| self.output(chain_alias, o))
+                .collect(),
+        )
+    }
+
+    /// Returns the alias of the other chain of an import or an export,
+    /// or the chain of the transaction if unknown.
+    fn alias_or<'a>(&'a self, blockchain_id: &ids::Id, chain_alias: &'a str) -> &'a str {
+        self.chains
+            .get(blockchain_id)
+            .map(String::as_str)
+            .unwrap_or(chain_alias)
+    }
+
+    /// Returns the fields of the base transaction, which every transaction starts with.
+    fn base(&self, chain_alias: &str, type_name: &str, base_tx: &txs::Tx) -> Vec<(String, Node)> {
+        let mut fields = vec![(String::from("type"), Node::text(type_name))];
+        if let Some(m) = &base_tx.metadata {
+            fields.push((String::from("txID"), Node::text(m.id.to_string())));
+        }
+        fields.push((
+            String::from("networkID"),
+            Node::Number(base_tx.network_id as u64),
+        ));
+        fields.push((
+            String::from("blockchainID"),
+            Node::text(self.chain_name(&base_tx.blockchain_id)),
+        ));
+        fields.push((
+            String::from("inputs"),
+            self.inputs(base_tx.transferable_inputs.iter().flatten()),
+        ));
+        fields.push((
+            String::from("outputs"),
+            self.outputs(chain_alias, base_tx.transferable_outputs.iter().flatten()),
+        ));
+        if let Some(memo) = base_tx.memo.as_ref().filter(|m| !m.is_empty()) {
+            fields.push((String::from("memo"), Node::text(memo_text(memo))));
+        }
+        fields
+    }
+
+    fn with_fee<T: Burn>(&self, mut fields: Vec<(String, Node)>, tx: &T) -> Node {
+        if let Ok(fee) = tx.burned(&self.avax_asset_id) {
+            fields.push((
+                String::from("fee"),
+                Node::text(self.amount(&self.avax_asset_id, fee)),
+            ));
+        }
+        Node::Fields(fields)
+    }
+}
+
+/// Returns the Unix time in RFC 3339 with the Unix time.
+fn time(unix: u64) -> Node {
+    match Utc.timestamp_opt(unix as i64, 0).single() {
+        Some(t) if unix <= i64::MAX as u64 => {
+            Node::text(format!("{} ({})", t.format("%Y-%m-%dT%H:%M:%SZ"), unix))
+        }
+        _ => Node::Number(unix),
+    }
+}
+
+/// Returns the memo as text if printable, otherwise in hex.
+fn memo_text(memo: &[u8]) -> String {
+    match std::str::from_utf8(memo) {
+        Ok(s) if !s.chars().any(char::is_control) => s.to_string(),
+        _ => format!("0x{}", hex::encode(memo)),
+    }
+}
+
+/// Transaction that renders for humans.
+pub trait Render {
+    fn render(&self, ctx: &Context) -> Node;
+}
+
+impl Render for txs::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let fields = ctx.base("X", &txs::Tx::type_name(), self);
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for avm::txs::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let fields = ctx.base("X", &Self::type_name(), &self.base_tx);
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for avm::txs::import::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("X", &Self::type_name(), &self.base_tx);
+        fields.push((
+            String::from("sourceChain"),
+            Node::text(ctx.chain_name(&self.source_chain_id)),
+        ));
+        fields.push((
+            String::from("importedInputs"),
+            ctx.inputs(self.source_chain_transferable_inputs.iter().flatten()),
+        ));
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for avm::txs::export::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("X", &Self::type_name(), &self.base_tx);
+        fields.push((
+            String::from("destinationChain"),
+            Node::text(ctx.chain_name(&self.destination_chain_id)),
+        ));
+        fields.push((
+            String::from("exportedOutputs"),
+            ctx.outputs(
+                ctx.alias_or(&self.destination_chain_id, "X"),
+                self.destination_chain_transferable_outputs.iter().flatten(),
+            ),
+        ));
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for platformvm::txs::import::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("P", &Self::type_name(), &self.base_tx);
+        fields.push((
+            String::from("sourceChain"),
+            Node::text(ctx.chain_name(&self.source_chain_id)),
+        ));
+        fields.push((
+            String::from("importedInputs"),
+            ctx.inputs(self.source_chain_transferable_inputs.iter().flatten()),
+        ));
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for platformvm::txs::export::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("P", &Self::type_name(), &self.base_tx);
+        fields.push((
+            String::from("destinationChain"),
+            Node::text(ctx.chain_name(&self.destination_chain_id)),
+        ));
+        fields.push((
+            String::from("exportedOutputs"),
+            ctx.outputs(
+                ctx.alias_or(&self.destination_chain_id, "P"),
+                self.destination_chain_transferable_outputs.iter().flatten(),
+            ),
+        ));
+        ctx.with_fee(fields, self)
+    }
+}
+
+fn validator(v: &platformvm::txs::Validator) -> Vec<(String, Node)> {
+    vec![
+        (String::from("nodeID"), Node::text(v.node_id.to_string())),
+        (String::from("start"), time(v.start)),
+        (String::from("end"), time(v.end)),
+    ]
+}
+
+impl Render for platformvm::txs::add_validator::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("P", &Self::type_name(), &self.base_tx);
+        fields.extend(validator(&self.validator));
+        fields.push((
+            String::from("stake"),
+            Node::text(ctx.amount(&ctx.avax_asset_id, self.validator.weight)),
+        ));
+        fields.push((
+            String::from("stakeOutputs"),
+            ctx.outputs("P", self.stake_transferable_outputs.iter().flatten()),
+        ));
+        fields.push((
+            String::from("rewardsOwner"),
+            ctx.owners("P", &self.rewards_owner),
+        ));
+        // shares are in units of 10,000 (e.g., 20000 is 2%)
+        fields.push((
+            String::from("delegationFee"),
+            Node::text(format!(
+                "{}%",
+                units::format_units(U256::from(self.shares), 4)
+            )),
+        ));
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for platformvm::txs::add_subnet_validator::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("P", &Self::type_name(), &self.base_tx);
+        fields.extend(validator(&self.validator.validator));
+        fields.push((
+            String::from("weight"),
+            Node::Number(self.validator.validator.weight),
+        ));
+        fields.push((
+            String::from("subnetID"),
+            Node::text(self.validator.subnet_id.to_string()),
+        ));
+        fields.push((String::from("subnetAuth"), sig_indices(&self.subnet_auth)));
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for platformvm::txs::create_subnet::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("P", &Self::type_name(), &self.base_tx);
+        fields.push((String::from("owner"), ctx.owners("P", &self.owner)));
+        ctx.with_fee(fields, self)
+    }
+}
+
+impl Render for platformvm::txs::create_chain::Tx {
+    fn render(&self, ctx: &Context) -> Node {
+        let mut fields = ctx.base("P", &Self::type_name(), &self.base_tx);
+        fields.push((
+            String::from("subnetID"),
+            Node::text(self.subnet_id.to_string()),
+        ));
+        fields.push((String::from("chainName"), Node::text(&self.chain_name)));
+        fields.push((String::from("vmID"), Node::text(self.vm_id.to_string())));
+        fields.push((
+            String::from("fxIDs"),
+            Node::List(
+                self.fx_ids
+                    .iter()
+                    .flatten()
+                    .map(|id| Node::text(id.to_string()))
+                    .collect(),
+            ),
+        ));
+        fields.push((
+            String::from("genesisBytes"),
+            Node::Number(self.genesis_data.len() as u64),
+        ));
+        fields.push((String::from("subnetAuth"), sig_indices(&self.subnet_auth)));
+        ctx.with_fee(fields, self)
+    }
+}
+
+fn sig_indices(input: &key::secp256k1::txs::Input) -> Node {
+    Node::Fields(vec![(
+        String::from("sigIndices"),
+        Node::List(
+            input
+                .sig_indices
+                .iter()
+                .map(|i| Node::Number(*i as u64))
+                .collect(),
+        ),
+    )])
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::display::test_render --exact --show-output
+#[test]
+fn test_render() {
+    let avax = ids::Id::sha256(b"AVAX");
+    let usdc = ids::Id::sha256(b"USDC");
+    let x = ids::Id::sha256(b"X");
+    let p = ids::Id::sha256(b"P");
+    let ctx = Context::new(1, avax)
+        .asset(usdc, "USDC", 6)
+        .chain(x, "X")
+        .chain(p, "P");
+
+    let addr = ids::short::Id::from_slice(&[1; 20]);
+    let owners = key::secp256k1::txs::OutputOwners::new(0, 1, std::slice::from_ref(&addr));
+    let base_tx = txs::Tx {
+        network_id: 1,
+        blockchain_id: x,
+        transferable_inputs: Some(vec![transferable::Input {
+            utxo_id: txs::utxo::Id {
+                tx_id: ids::Id::sha256(b"prev"),
+                output_index: 1,
+                ..Default::default()
+            },
+            asset_id: avax,
+            transfer_input: Some(key::secp256k1::txs::transfer::Input {
+                amount: 2_500_000_000,
+                sig_indices: vec![0],
+            }),
+            ..Default::default()
+        }]),
+        transferable_outputs: Some(vec![transferable::Output {
+            asset_id: avax,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+                1_499_000_000,
+                owners.clone(),
+            )),
+            ..Default::default()
+        }]),
+        memo: Some(b"hello".to_vec()),
+        ..Default::default()
+    };
+    let tx = avm::txs::export::Tx {
+        base_tx,
+        destination_chain_id: p,
+        destination_chain_transferable_outputs: Some(vec![transferable::Output {
+            asset_id: avax,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+                1_000_000_000,
+                owners,
+            )),
+            ..Default::default()
+        }]),
+        ..Default::default()
+    };
+
+    let node = tx.render(&ctx);
+    assert_eq!(node.get("type"), Some(&Node::text("avm.ExportTx")));
+    assert_eq!(node.get("fee"), Some(&Node::text("0.001 AVAX")));
+    assert_eq!(node.get("memo"), Some(&Node::text("hello")));
+    assert_eq!(node.get("txID"), None);
+
+    let text = node.to_text();
+    log::info!("{}", text);
+    assert!(text.starts_with("type: avm.ExportTx\nnetworkID: 1\n"));
+    assert!(text.contains(&format!("blockchainID: {} (X)\n", x)));
+    assert!(text.contains("  [0]:\n    utxo: "));
+    assert!(text.contains("    amount: 2.5 AVAX\n"));
+    let x_addr = ctx.address("X", &addr);
+    let p_addr = ctx.address("P", &addr);
+    assert!(x_addr.starts_with("X-avax1"));
+    assert!(text.contains(&format!("        [0]: {}\n", x_addr)));
+    // exported to the P-chain
+    assert!(text.contains(&p_addr));
+    assert!(text.ends_with("fee: 0.001 AVAX\n"));
+
+    let json = node.to_json();
+    assert_eq!(json["outputs"][0]["amount"], "1.499 AVAX");
+    assert_eq!(json["inputs"][0]["sigIndices"][0], 0);
+    assert_eq!(json["exportedOutputs"][0]["owners"]["addresses"][0], p_addr);
+
+    assert_eq!(ctx.amount(&usdc, 1_500_000), "1.5 USDC");
+    assert_eq!(
+        ctx.amount(&ids::Id::empty(), 7),
+        format!("7 (asset {})", ids::Id::empty())
+    );
+    assert_eq!(memo_text(&[0, 1]), "0x0001");
+    assert_eq!(
+        time(1_700_000_000),
+        Node::text("2023-11-14T22:13:20Z (1700000000)")
+    );
+
+    let tx = platformvm::txs::add_validator::Tx {
+        validator: platformvm::txs::Validator {
+            weight: 2_000 * units::AVAX,
+            ..Default::default()
+        },
+        shares: 20_000,
+        ..Default::default()
+    };
+    let node = tx.render(&ctx);
+    assert_eq!(node.get("stake"), Some(&Node::text("2000 AVAX")));
+    assert_eq!(node.get("delegationFee"), Some(&Node::text("2%")));
+}
@@ -0,0 +1,511 @@
+//! Address index of the unspent outputs, built from the accepted
+//! transactions of a chain: the core of a lightweight personal indexer
+//! that answers "what does this address own" without the node's
+//! "getUTXOs" API. Apply every accepted transaction once, in the
+//! acceptance order, and save the index to resume from the last one.
+//!
+//! Only the base transaction moves the UTXOs of the chain. The imported
+//! inputs and the exported outputs go through the shared memory, and the
+//! staked outputs come back with the reward transaction, so none of
+//! them are indexed here.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    io::{self, Error, ErrorKind},
+};
+
+use crate::{avm, ids, platformvm, txs};
+
+/// Transaction whose base transaction spends and produces the UTXOs.
+pub trait Indexable {
+    fn tx_id(&self) -> ids::Id;
+    fn base_tx(&self) -> &txs::Tx;
+}
+
+impl<T: Indexable + ?Sized> Indexable for &T {
+    fn tx_id(&self) -> ids::Id {
+        (**self).tx_id()
+    }
+
+    fn base_tx(&self) -> &txs::Tx {
+        (**self).base_tx()
+    }
+}
+
+impl Indexable for txs::Tx {
+    fn tx_id(&self) -> ids::Id {
+        self.metadata
+            .as_ref()
+            .map(|m| m.id)
+            .unwrap_or_else(ids::Id::empty)
+    }
+
+    fn base_tx(&self) -> &txs::Tx {
+        self
+    }
+}
+
+macro_rules! impl_indexable {
+    ($($t:ty),+) => {
+        $(
+            impl Indexable for $t {
+                fn tx_id(&self) -> ids::Id {
+                    self.tx_id()
+                }
+
+                fn base_tx(&self) -> &txs::Tx {
+                    &self.base_tx
+                }
+            }
+        )+
+    };
+}
+
+impl_indexable!(
+    avm::txs::Tx,
+    avm::txs::import::Tx,
+    avm::txs::export::Tx,
+    platformvm::txs::import::Tx,
+    platformvm::txs::export::Tx,
+    platformvm::txs::add_validator::Tx,
+    platformvm::txs::add_subnet_validator::Tx,
+    platformvm::txs::create_subnet::Tx,
+    platformvm::txs::create_chain::Tx
+);
+
+/// (transaction ID, output index) of the UTXO.
+type UtxoKey = (ids::Id, u32);
+
+/// UTXOs that a transaction moved in the index.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct Changes {
+    pub produced: Vec<txs::utxo::Id>,
+    pub spent: Vec<txs::utxo::Id>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AddressIndex {
+    /// Indexes only these addresses if not empty.
+    tracked: BTreeSet<ids::short::Id>,
+    utxos: HashMap<ids::short::Id, BTreeMap<UtxoKey, txs::utxo::Utxo>>,
+    owners: HashMap<UtxoKey, Vec<ids::short::Id>>,
+    /// Addresses changed since the last save.
+    dirty: BTreeSet<ids::short::Id>,
+    last_tx_id: Option<ids::Id>,
+}
+
+impl AddressIndex {
+    /// Creates the index of every address.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the index to the addresses (e.g., the keys of a wallet),
+    /// which keeps it small on a busy chain. Set it before applying.
+    #[must_use]
+    pub fn track(mut self, addresses: &[ids::short::Id]) -> Self {
+        self.tracked.extend(addresses.iter().cloned());
+        self
+    }
+
+    fn is_tracked(&self, addr: &ids::short::Id) -> bool {
+        self.tracked.is_empty() || self.tracked.contains(addr)
+    }
+
+    /// Returns the last applied transaction, to resume the indexing from.
+    pub fn last_tx_id(&self) -> Option<ids::Id> {
+        self.last_tx_id
+    }
+
+    /// Applies the accepted transaction: removes the UTXOs that its inputs
+    /// spend, and adds its outputs under each of their owners.
+    pub fn apply<T: Indexable + ?Sized>(&mut self, tx: &T) -> io::Result<Changes> {
+        let tx_id = tx.tx_id();
+        if tx_id.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "transaction has no ID (not signed or parsed)",
+            ));
+        }
+        let base_tx = tx.base_tx();
+        let mut changes = Changes::default();
+
+        for input in base_tx.transferable_inputs.iter().flatten() {
+            let key = (input.utxo_id.tx_id, input.utxo_id.output_index);
+            let owners = match self.owners.remove(&key) {
+                Some(owners) => owners,
+                None => continue,
+            };
+            for addr in owners {
+                if let Some(utxos) = self.utxos.get_mut(&addr) {
+                    utxos.remove(&key);
+                    if utxos.is_empty() {
+                        self.utxos.remove(&addr);
+                    }
+                }
+                self.dirty.insert(addr);
+            }
+            changes.spent.push(input.utxo_id.clone());
+        }
+
+        for (i, output) in base_tx.transferable_outputs.iter().flatten().enumerate() {
+            let transfer_output = match (&output.transfer_output, &output.stakeable_lock_out) {
+                (Some(out), _) => out,
+                (None, Some(lock_out)) => &lock_out.transfer_output,
+                (None, None) => continue,
+            };
+            let owners: Vec<ids::short::Id> = transfer_output
+                .output_owners
+                .addresses
+                .iter()
+                .filter(|a| self.is_tracked(a))
+                .cloned()
+                .collect();
+            if owners.is_empty() {
+                continue;
+            }
+
+            let utxo = txs::utxo::Utxo {
+                utxo_id: txs::utxo::Id::new(tx_id.as_ref(), i as u32, false)?,
+                asset_id: output.asset_id,
+                transfer_output: output.transfer_output.clone(),
+                stakeable_lock_out: output.stakeable_lock_out.clone(),
+            };
+            let key = (tx_id, i as u32);
+            for addr in owners.iter() {
+                self.utxos
+                    .entry(addr.clone())
+                    .or_default()
+                    .insert(key, utxo.clone());
+                self.dirty.insert(addr.clone());
+            }
+            self.owners.insert(key, owners);
+            changes.produced.push(utxo.utxo_id);
+        }
+
+        self.last_tx_id = Some(tx_id);
+        Ok(changes)
+    }
+
+    /// Applies the accepted transactions in order, and stops at the first
+    /// one that fails to apply.
+    pub fn apply_all<I>(&mut self, txs: I) -> io::Result<Vec<Changes>>
+    where
+        I: IntoIterator,
+        I::Item: Indexable,
+    {
+        txs.into_iter().map(|tx| self.apply(&tx)).collect()
+    }
+
+    /// Applies the accepted transactions of the stream (e.g., parsed from
+    /// the containers of "client::index") until it ends, and returns the
+    /// number of applied transactions. Stops at the first item that fails
+    /// to parse or to apply, so the index resumes from "last_tx_id".
+    #[cfg(feature = "futures")]
+    pub async fn apply_stream<S, T>(&mut self, stream: S) -> io::Result<usize>
+    where
+        S: futures::Stream<Item = io::Result<T>>,
+        T: Indexable,
+    {
+        use futures::StreamExt;
+
+        futures::pin_mut!(stream);
+        let mut applied = 0;
+        while let Some(tx) = stream.next().await {
+            self.apply(&tx?)?;
+            applied += 1;
+        }
+        Ok(applied)
+    }
+
+    /// Returns the addresses that own any UTXO.
+    pub fn addresses(&self) -> Vec<ids::short::Id> {
+        let mut addrs: Vec<ids::short::Id> = self.utxos.keys().cloned().collect();
+        addrs.sort();
+        addrs
+    }
+
+    /// Returns the UTXOs of the address, in the (transaction ID, output index) order.
+    pub fn utxos(&self, addr: &ids::short::Id) -> Vec<txs::utxo::Utxo> {
+        self.utxos
+            .get(addr)
+            .map(|utxos| utxos.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the total amount of the asset that the address owns, locked or not.
+    pub fn balance(&self, addr: &ids::short::Id, asset_id: &ids::Id) -> u64 {
+        self.utxos
+            .get(addr)
+            .into_iter()
+            .flat_map(|utxos| utxos.values())
+            .filter(|u| u.asset_id == *asset_id)
+            .map(|u| match (&u.transfer_output, &u.stakeable_lock_out) {
+                (Some(out), _) => out.amount,
+                (None, Some(lock_out)) => lock_out.transfer_output.amount,
+                (None, None) => 0,
+            })
+            .fold(0_u64, |total, amount| total.saturating_add(amount))
+    }
+}
+
+#[cfg(feature = "subnet_vm")]
+const ADDRESSES_KEY: &[u8] = b"address_index/addresses";
+#[cfg(feature = "subnet_vm")]
+const LAST_TX_KEY: &[u8] = b"address_index/last_tx";
+#[cfg(feature = "subnet_vm")]
+const UTXOS_PREFIX: &[u8] = b"address_index/utxos/";
+
+#[cfg(feature = "subnet_vm")]
+fn utxos_key(addr: &ids::short::Id) -> Vec<u8> {
+    [UTXOS_PREFIX, addr.as_ref()].concat()
+}
+
+#[cfg(feature = "subnet_vm")]
+impl AddressIndex {
+    /// Writes the addresses changed since the last save to the database.
+    /// Each address keeps its packed UTXOs under its own key, so a save
+    /// after a few transactions only writes the few addresses they touched.
+    /// The UTXOs are written before the address list that refers to them,
+    /// the emptied addresses are deleted after it, and the last transaction
+    /// goes last, so an interrupted save still loads and is re-applied from
+    /// the previously saved transaction.
+    pub async fn save<D>(&mut self, db: &mut D) -> io::Result<()>
+    where
+        D: crate::subnet::rpc::database::KeyValueReaderWriterDeleter + Send + Sync + ?Sized,
+    {
+        for addr in self.dirty.iter() {
+            if let Some(utxos) = self.utxos.get(addr) {
+                let packed = utxos
+                    .values()
+                    .map(|u| Ok(u.pack(crate::codec::VERSION)?.take_bytes().to_vec()))
+                    .collect::<io::Result<Vec<Vec<u8>>>>()?;
+                let packer = crate::packer::Packer::new((1 << 31) - 1, 0);
+                packer.pack_2d_bytes_with_header(packed)?;
+                db.put(&utxos_key(addr), &packer.take_bytes()).await?;
+            }
+        }
+
+        let packer = crate::packer::Packer::new((1 << 31) - 1, 0);
+        packer.pack_2d_bytes_with_header(
+            self.addresses()
+                .iter()
+                .map(|a| a.as_ref().to_vec())
+                .collect(),
+        )?;
+        db.put(ADDRESSES_KEY, &packer.take_bytes()).await?;
+
+        for addr in self.dirty.iter() {
+            if !self.utxos.contains_key(addr) {
+                db.delete(&utxos_key(addr)).await?;
+            }
+        }
+        if let Some(tx_id) = &self.last_tx_id {
+            db.put(LAST_TX_KEY, tx_id.as_ref()).await?;
+        }
+
+        log::debug!("saved {} changed addresses", self.dirty.len());
+        self.dirty.clear();
+        Ok(())
+    }
+
+    /// Loads the index saved in the database, or an empty index if none.
+    /// The tracked addresses are not saved, so set them again with "track".
+    pub async fn load<D>(db: &D) -> io::Result<Self>
+    where
+        D: crate::subnet::rpc::database::KeyValueReaderWriterDeleter + Send + Sync + ?Sized,
+    {
+        let mut index = Self::new();
+        if !db.has(ADDRESSES_KEY).await? {
+            return Ok(index);
+        }
+
+        let b = db.get(ADDRESSES_KEY).await?;
+        let addrs = crate::packer::Packer::load_bytes_for_unpack(b.len(), &b)
+            .unpack_2d_bytes_with_header()?;
+        for addr in addrs {
+            if addr.len() != ids::short::LEN {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("invalid address length {} in the index", addr.len()),
+                ));
+            }
+            let addr = ids::short::Id::from_slice(&addr);
+
+            let b = db.get(&utxos_key(&addr)).await?;
+            let packed = crate::packer::Packer::load_bytes_for_unpack(b.len(), &b)
+                .unpack_2d_bytes_with_header()?;
+            let mut utxos = BTreeMap::new();
+            for d in packed {
+                let utxo = txs::utxo::Utxo::unpack(&d)?;
+                let key = (utxo.utxo_id.tx_id, utxo.utxo_id.output_index);
+                index.owners.entry(key).or_default().push(addr.clone());
+                utxos.insert(key, utxo);
+            }
+            index.utxos.insert(addr, utxos);
+        }
+
+        if db.has(LAST_TX_KEY).await? {
+            index.last_tx_id = Some(ids::Id::from_slice(&db.get(LAST_TX_KEY).await?));
+        }
+        Ok(index)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::index::test_address_index --exact --show-output
+#[test]
+fn test_address_index() {
+    use crate::key;
+
+    let alice = ids::short::Id::from_slice(&[1; 20]);
+    let bob = ids::short::Id::from_slice(&[2; 20]);
+    let carol = ids::short::Id::from_slice(&[3; 20]);
+    let avax = ids::Id::from_slice(&[0xaa; 32]);
+    let other = ids::Id::from_slice(&[0xbb; 32]);
+
+    let output = |asset_id: ids::Id, amount: u64, addresses: Vec<ids::short::Id>| {
+        txs::transferable::Output {
+            asset_id,
+            transfer_output: Some(key::secp256k1::txs::transfer::Output {
+                amount,
+                output_owners: key::secp256k1::txs::OutputOwners {
+                    locktime: 0,
+                    threshold: 1,
+                    addresses,
+                },
+            }),
+            ..Default::default()
+        }
+    };
+    let input = |tx_id: ids::Id, output_index: u32| txs::transferable::Input {
+        utxo_id: txs::utxo::Id::new(tx_id.as_ref(), output_index, false).unwrap(),
+        asset_id: avax,
+        ..Default::default()
+    };
+    let tx = |id: u8, ins, outs| txs::Tx {
+        metadata: Some(txs::Metadata {
+            id: ids::Id::from_slice(&[id; 32]),
+            ..Default::default()
+        }),
+        transferable_inputs: Some(ins),
+        transferable_outputs: Some(outs),
+        ..Default::default()
+    };
+
+    let mut index = AddressIndex::new();
+    assert!(index.apply(&txs::Tx::default()).is_err());
+
+    // alice gets two outputs, one shared with bob, and carol gets another asset
+    let tx1 = tx(
+        1,
+        vec![],
+        vec![
+            output(avax, 100, vec![alice.clone()]),
+            output(avax, 50, vec![alice.clone(), bob.clone()]),
+            output(other, 7, vec![carol.clone()]),
+        ],
+    );
+    let changes = index.apply(&tx1).unwrap();
+    assert_eq!(changes.produced.len(), 3);
+    assert_eq!(index.balance(&alice, &avax), 150);
+    assert_eq!(index.balance(&bob, &avax), 50);
+    assert_eq!(index.balance(&carol, &other), 7);
+    assert_eq!(index.balance(&carol, &avax), 0);
+    assert_eq!(
+        index.addresses(),
+        vec![alice.clone(), bob.clone(), carol.clone()]
+    );
+    assert_eq!(index.utxos(&alice)[1].utxo_id.output_index, 1);
+
+    // spending the shared output removes it from both owners
+    let tx2 = tx(
+        2,
+        vec![input(tx1.tx_id(), 1)],
+        vec![output(avax, 49, vec![bob.clone()])],
+    );
+    let changes = index.apply(&tx2).unwrap();
+    assert_eq!(changes.spent.len(), 1);
+    assert_eq!(index.balance(&alice, &avax), 100);
+    assert_eq!(index.balance(&bob, &avax), 49);
+    assert_eq!(index.utxos(&bob)[0].utxo_id.tx_id, tx2.tx_id());
+    assert_eq!(index.last_tx_id(), Some(tx2.tx_id()));
+
+    // unknown inputs are ignored, and an emptied address is dropped
+    let tx3 = tx(
+        3,
+        vec![input(tx1.tx_id(), 0), input(tx1.tx_id(), 9)],
+        vec![],
+    );
+    assert_eq!(index.apply(&tx3).unwrap().spent.len(), 1);
+    assert!(index.utxos(&alice).is_empty());
+    assert_eq!(index.addresses(), vec![bob.clone(), carol.clone()]);
+
+    // a tracked index ignores the other owners
+    let mut tracked = AddressIndex::new().track(std::slice::from_ref(&bob));
+    tracked.apply(&tx1).unwrap();
+    assert_eq!(tracked.addresses(), vec![bob.clone()]);
+    assert_eq!(tracked.utxos(&bob)[0].utxo_id.output_index, 1);
+
+    // applying in bulk matches applying one by one
+    let mut bulk = AddressIndex::new();
+    let changes = bulk.apply_all([&tx1, &tx2, &tx3]).unwrap();
+    assert_eq!(changes.len(), 3);
+    assert_eq!(bulk.addresses(), index.addresses());
+    assert_eq!(bulk.utxos(&bob), index.utxos(&bob));
+    assert!(bulk.apply_all([&txs::Tx::default()]).is_err());
+
+    #[cfg(feature = "futures")]
+    futures::executor::block_on(async {
+        let mut streamed = AddressIndex::new();
+        let txs = vec![Ok(&tx1), Ok(&tx2), Ok(&tx3)];
+        let applied = streamed
+            .apply_stream(futures::stream::iter(txs))
+            .await
+            .unwrap();
+        assert_eq!(applied, 3);
+        assert_eq!(streamed.utxos(&bob), index.utxos(&bob));
+
+        // stops at the failed item, resuming from the last applied
+        let mut streamed = AddressIndex::new();
+        let txs = vec![
+            Ok(&tx1),
+            Err(Error::new(ErrorKind::InvalidData, "bad")),
+            Ok(&tx2),
+        ];
+        assert!(streamed
+            .apply_stream(futures::stream::iter(txs))
+            .await
+            .is_err());
+        assert_eq!(streamed.last_tx_id(), Some(tx1.tx_id()));
+    });
+
+    #[cfg(feature = "subnet_vm")]
+    tokio::runtime::Runtime::new().unwrap().block_on(async {
+        use crate::subnet::rpc::database::memdb;
+
+        let mut db = memdb::Database::new();
+        assert!(AddressIndex::load(db.as_ref())
+            .await
+            .unwrap()
+            .addresses()
+            .is_empty());
+
+        let mut index = AddressIndex::new();
+        index.apply(&tx1).unwrap();
+        index.save(db.as_mut()).await.unwrap();
+        index.apply(&tx2).unwrap();
+        index.apply(&tx3).unwrap();
+        index.save(db.as_mut()).await.unwrap();
+
+        let mut loaded = AddressIndex::load(db.as_ref()).await.unwrap();
+        assert_eq!(loaded.addresses(), index.addresses());
+        assert_eq!(loaded.utxos(&bob), index.utxos(&bob));
+        assert_eq!(loaded.last_tx_id(), Some(tx3.tx_id()));
+        assert!(!db.has(&utxos_key(&alice)).await.unwrap());
+
+        // the loaded index keeps applying where the saved one stopped
+        let tx4 = tx(4, vec![input(tx2.tx_id(), 0)], vec![]);
+        loaded.apply(&tx4).unwrap();
+        assert_eq!(loaded.addresses(), vec![carol.clone()]);
+    });
+}
@@ -0,0 +1,124 @@
+//! Transaction limits that avalanchego enforces on issuance.
+//!
+//! The node rejects a transaction over these limits with a generic
+//! mempool or codec error, so the wallet checks them before issuing.
+//! The errors convert into "io::Error" with the kind "InvalidInput",
+//! and the typed error can be recovered with "io::Error::get_ref".
+
+use std::io;
+
+use crate::txs;
+use thiserror::Error;
+
+/// Largest signed transaction that the mempool accepts.
+/// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/platformvm/txs/mempool/mempool.go "MaxTxSize"
+/// ref. https://github.com/ava-labs/avalanchego/blob/v1.9.4/vms/avm/txs/mempool/mempool.go "maxTxSize"
+pub const MAX_TX_SIZE: usize = 64 * 1024;
+
+/// Largest memo in the base transaction.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#MaxMemoSize
+pub const MAX_MEMO_SIZE: usize = 256;
+
+/// Largest number of elements in a slice that the codec unmarshals,
+/// which bounds the inputs, outputs, and credentials.
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/codec/linearcodec#DefaultMaxSliceLength
+pub const MAX_SLICE_LEN: usize = 256 * 1024;
+
+#[derive(Clone, Debug, Eq, PartialEq, Error)]
+pub enum Error {
+    #[error("transaction of {size} bytes exceeds the maximum of {max} bytes")]
+    TxTooLarge { size: usize, max: usize },
+    #[error("memo of {size} bytes exceeds the maximum of {max} bytes")]
+    MemoTooLarge { size: usize, max: usize },
+    #[error("{count} inputs exceed the maximum of {max}")]
+    TooManyInputs { count: usize, max: usize },
+    #[error("{count} outputs exceed the maximum of {max}")]
+    TooManyOutputs { count: usize, max: usize },
+}
+
+impl From<Error> for io::Error {
+    fn from(e: Error) -> Self {
+        io::Error::new(io::ErrorKind::InvalidInput, e)
+    }
+}
+
+/// Checks the size of the signed transaction bytes.
+pub fn check_tx_size(size: usize) -> Result<(), Error> {
+    if size > MAX_TX_SIZE {
+        return Err(Error::TxTooLarge {
+            size,
+            max: MAX_TX_SIZE,
+        });
+    }
+    Ok(())
+}
+
+pub fn check_memo(memo: &[u8]) -> Result<(), Error> {
+    if memo.len() > MAX_MEMO_SIZE {
+        return Err(Error::MemoTooLarge {
+            size: memo.len(),
+            max: MAX_MEMO_SIZE,
+        });
+    }
+    Ok(())
+}
+
+/// Checks the memo and the number of inputs and outputs of the base transaction.
+pub fn check_base_tx(tx: &txs::Tx) -> Result<(), Error> {
+    if let Some(memo) = &tx.memo {
+        check_memo(memo)?;
+    }
+    let ins = tx.transferable_inputs.as_ref().map_or(0, |v| v.len());
+    if ins > MAX_SLICE_LEN {
+        return Err(Error::TooManyInputs {
+            count: ins,
+            max: MAX_SLICE_LEN,
+        });
+    }
+    let outs = tx.transferable_outputs.as_ref().map_or(0, |v| v.len());
+    if outs > MAX_SLICE_LEN {
+        return Err(Error::TooManyOutputs {
+            count: outs,
+            max: MAX_SLICE_LEN,
+        });
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::limits::test_limits --exact --show-output
+#[test]
+fn test_limits() {
+    assert!(check_tx_size(MAX_TX_SIZE).is_ok());
+    assert_eq!(
+        check_tx_size(MAX_TX_SIZE + 1),
+        Err(Error::TxTooLarge {
+            size: MAX_TX_SIZE + 1,
+            max: MAX_TX_SIZE
+        })
+    );
+
+    let mut tx = txs::Tx {
+        memo: Some(vec![0; MAX_MEMO_SIZE]),
+        ..Default::default()
+    };
+    assert!(check_base_tx(&tx).is_ok());
+    tx.memo = Some(vec![0; MAX_MEMO_SIZE + 1]);
+    assert_eq!(
+        check_base_tx(&tx),
+        Err(Error::MemoTooLarge {
+            size: MAX_MEMO_SIZE + 1,
+            max: MAX_MEMO_SIZE
+        })
+    );
+
+    let e: io::Error = Error::MemoTooLarge {
+        size: 300,
+        max: 256,
+    }
+    .into();
+    assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+    assert!(matches!(
+        e.get_ref().and_then(|e| e.downcast_ref::<Error>()),
+        Some(Error::MemoTooLarge { size: 300, .. })
+    ));
+}
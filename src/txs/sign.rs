@@ -0,0 +1,98 @@
+//! Signing of the transactions with the secp256k1 keys.
+//!
+//! The credentials sign the SHA256 digest of the unsigned transaction bytes,
+//! and are packed after those bytes to form the signed transaction,
+//! whose SHA256 digest is the transaction ID.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm/txs#Tx.Sign
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/avm/txs#Tx.SignSECP256K1Fx
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    codec, ids,
+    key::{self, secp256k1::txs::Credential},
+    txs::{self, verify::Verifiable},
+};
+use ring::digest::{digest, SHA256};
+
+/// Transaction that is signed by packing its credentials
+/// after the unsigned transaction bytes.
+pub trait Signable: Verifiable {
+    /// Returns the embedded base transaction, whose metadata
+    /// is updated with the signing.
+    fn base_tx_mut(&mut self) -> &mut txs::Tx;
+
+    /// Replaces the credentials, including the subnet auth one (if any) last.
+    fn set_credentials(&mut self, creds: Vec<Credential>);
+
+    /// Packs the credentials after the unsigned transaction marshaled
+    /// with the codec version, and updates the transaction with the credentials
+    /// and the metadata (ID, unsigned and signed bytes).
+    /// ref. "avalanchego/vms/components/avax.BaseTx.Metadata.Initialize"
+    fn set_signed(&mut self, codec_version: u16, creds: Vec<Credential>) -> io::Result<()> {
+        let packer = self.pack_unsigned(codec_version)?;
+
+        // "avalanchego" marshals the whole struct again for signed bytes
+        // even when the underlying "unsigned_tx" is already once marshaled
+        // reuse the underlying packer and just marshal the credentials next
+        let tx_bytes_with_no_signature = packer.take_bytes();
+        packer.set_bytes(&tx_bytes_with_no_signature);
+
+        // pack the number of credentials, then each "secp256k1fx.Credential"
+        // with its type ID as it's not embedded
+        let cred_type_id =
+            codec::REGISTRY.type_id(Self::VM, codec_version, &Credential::type_name())?;
+        packer.pack_u32(creds.len() as u32)?;
+        for cred in creds.iter() {
+            packer.pack_u32(cred_type_id)?;
+            packer.pack_u32(cred.signatures.len() as u32)?;
+            for sig in cred.signatures.iter() {
+                packer.pack_bytes(sig)?;
+            }
+        }
+        let tx_bytes_with_signatures = packer.take_bytes();
+        let tx_id = digest(&SHA256, &tx_bytes_with_signatures);
+
+        self.base_tx_mut().metadata = Some(txs::Metadata {
+            id: ids::Id::from_slice(tx_id.as_ref()),
+            tx_bytes_with_no_signature: tx_bytes_with_no_signature.to_vec(),
+            tx_bytes_with_signatures: tx_bytes_with_signatures.to_vec(),
+        });
+        self.set_credentials(creds);
+
+        Ok(())
+    }
+}
+
+/// Signs the transaction marshaled with the codec version, which must be
+/// registered in "codec::REGISTRY". Each group of the signers (in case of multi-sig)
+/// signs the digest of the unsigned transaction into one credential.
+/// TODO: support ledger signing
+pub async fn sign_with_codec_version<S, T>(
+    tx: &mut S,
+    codec_version: u16,
+    signers: Vec<Vec<T>>,
+) -> io::Result<()>
+where
+    S: Signable,
+    T: key::secp256k1::SignOnly,
+{
+    // fails before packing and signing a transaction that the node would reject
+    txs::limits::check_base_tx(tx.base_tx_mut())?;
+    let tx_bytes_hash = tx.tx_digest(codec_version)?;
+
+    let mut creds = Vec::with_capacity(signers.len());
+    for keys in signers.iter() {
+        let mut sigs: Vec<Vec<u8>> = Vec::new();
+        for k in keys.iter() {
+            let sig = k
+                .sign_digest(&tx_bytes_hash)
+                .await
+                .map_err(|e| Error::new(ErrorKind::Other, format!("failed sign_digest {}", e)))?;
+            sigs.push(Vec::from(sig));
+        }
+        creds.push(Credential::new(sigs));
+    }
+
+    tx.set_signed(codec_version, creds)
+}
@@ -1,6 +1,9 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    io::{self, Error, ErrorKind},
+};
 
-use crate::{ids, key, platformvm, txs};
+use crate::{codec, ids, key, packer, platformvm, txs};
 use serde::{Deserialize, Serialize};
 
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
@@ -44,6 +47,118 @@ impl Output {
             stakeable_lock_out: None,
         }
     }
+
+    /// Returns the type ID of the underlying output in the default codec version.
+    /// Only the P-chain registers "platformvm.StakeableLockOut",
+    /// and both chains share the "secp256k1fx" type IDs.
+    fn type_id(&self) -> u32 {
+        let type_name = if self.transfer_output.is_some() {
+            key::secp256k1::txs::transfer::Output::type_name()
+        } else {
+            platformvm::txs::StakeableLockOut::type_name()
+        };
+        codec::REGISTRY
+            .type_id(codec::Vm::P, codec::VERSION, &type_name)
+            .expect("unexpected unregistered TransferableOutput type")
+    }
+
+    /// Packs the output of the transaction, with the type ID
+    /// looked up in the codec version of the VM.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
+    pub fn pack(
+        &self,
+        packer: &packer::Packer,
+        vm: codec::Vm,
+        codec_version: u16,
+    ) -> io::Result<()> {
+        // "TransferableOutput.Asset" is struct and serialize:"true"
+        // but embedded inline in the struct "TransferableOutput"
+        // so no need to encode type ID
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
+        packer.pack_bytes(self.asset_id.as_ref())?;
+
+        // fx_id is serialize:"false" thus skipping serialization
+
+        // decide the type
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableOutput
+        if self.transfer_output.is_none() && self.stakeable_lock_out.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "unexpected Nones in TransferableOutput transfer_output and stakeable_lock_out",
+            ));
+        }
+        let type_name_transferable_out = {
+            if self.transfer_output.is_some() {
+                key::secp256k1::txs::transfer::Output::type_name()
+            } else {
+                platformvm::txs::StakeableLockOut::type_name()
+            }
+        };
+        // marshal type ID for "key::secp256k1::txs::transfer::Output" or "platformvm::txs::StakeableLockOut"
+        packer.pack_u32(codec::REGISTRY.type_id(
+            vm,
+            codec_version,
+            &type_name_transferable_out,
+        )?)?;
+
+        if self.transfer_output.is_some() {
+            // "key::secp256k1::txs::transfer::Output"
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+            let transfer_output = self.transfer_output.clone().unwrap();
+
+            // marshal "secp256k1fx.TransferOutput.Amt" field
+            packer.pack_u64(transfer_output.amount)?;
+
+            // "secp256k1fx.TransferOutput.OutputOwners" is struct and serialize:"true"
+            // but embedded inline in the struct "TransferOutput"
+            // so no need to encode type ID
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
+            packer.pack_u64(transfer_output.output_owners.locktime)?;
+            packer.pack_u32(transfer_output.output_owners.threshold)?;
+            packer.pack_u32(transfer_output.output_owners.addresses.len() as u32)?;
+            for addr in transfer_output.output_owners.addresses.iter() {
+                packer.pack_bytes(addr.as_ref())?;
+            }
+        } else {
+            // "platformvm::txs::StakeableLockOut"
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
+            let stakeable_lock_out = self.stakeable_lock_out.clone().unwrap();
+
+            // marshal "platformvm::txs::StakeableLockOut.locktime" field
+            packer.pack_u64(stakeable_lock_out.locktime)?;
+
+            // "platformvm.StakeableLockOut.TransferOutput" is struct and serialize:"true"
+            // but embedded inline in the struct "StakeableLockOut"
+            // so no need to encode type ID
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#OutputOwners
+            //
+            // marshal "secp256k1fx.TransferOutput.Amt" field
+            packer.pack_u64(stakeable_lock_out.transfer_output.amount)?;
+            packer.pack_u64(stakeable_lock_out.transfer_output.output_owners.locktime)?;
+            packer.pack_u32(stakeable_lock_out.transfer_output.output_owners.threshold)?;
+            packer.pack_u32(
+                stakeable_lock_out
+                    .transfer_output
+                    .output_owners
+                    .addresses
+                    .len() as u32,
+            )?;
+            for addr in stakeable_lock_out
+                .transfer_output
+                .output_owners
+                .addresses
+                .iter()
+            {
+                packer.pack_bytes(addr.as_ref())?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableOutputs
@@ -64,23 +179,9 @@ impl Ord for Output {
             return Ordering::Equal;
         }
 
-        // unlike "avalanchego", we want ordering without "codec" dependency and marshal-ing
+        // unlike "avalanchego", we want ordering without marshal-ing
         // just check type ID header
-        let type_id_self = {
-            if self.transfer_output.is_some() {
-                key::secp256k1::txs::transfer::Output::type_id()
-            } else {
-                platformvm::txs::StakeableLockOut::type_id()
-            }
-        };
-        let type_id_other = {
-            if other.transfer_output.is_some() {
-                key::secp256k1::txs::transfer::Output::type_id()
-            } else {
-                platformvm::txs::StakeableLockOut::type_id()
-            }
-        };
-        let type_id_ord = type_id_self.cmp(&type_id_other);
+        let type_id_ord = self.type_id().cmp(&other.type_id());
         if type_id_ord != Ordering::Equal {
             // no need to compare further
             return type_id_ord;
@@ -88,25 +189,15 @@ impl Ord for Output {
 
         // both instances have the same type!!!
         // just use the ordering of underlying types
-        match type_id_self {
-            7 => {
-                // "key::secp256k1::txs::transfer::Output"
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
-                let transfer_output_self = self.transfer_output.clone().unwrap();
-                let transfer_output_other = other.transfer_output.clone().unwrap();
-                transfer_output_self.cmp(&transfer_output_other)
-            }
-            22 => {
-                // "platformvm::txs::StakeableLockOut"
-                // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
-                let stakeable_lock_out_self = self.stakeable_lock_out.clone().unwrap();
-                let stakeable_lock_out_other = other.stakeable_lock_out.clone().unwrap();
-                stakeable_lock_out_self.cmp(&stakeable_lock_out_other)
-            }
-            _ => {
-                // should never happen
-                panic!("unexpected type ID {} for TransferableOutput", type_id_self);
+        match (&self.transfer_output, &other.transfer_output) {
+            // "key::secp256k1::txs::transfer::Output"
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferOutput
+            (Some(transfer_output_self), Some(transfer_output_other)) => {
+                transfer_output_self.cmp(transfer_output_other)
             }
+            // "platformvm::txs::StakeableLockOut"
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockOut
+            _ => self.stakeable_lock_out.cmp(&other.stakeable_lock_out),
         }
     }
 }
@@ -441,6 +532,103 @@ impl Input {
             stakeable_lock_in: None,
         }
     }
+
+    /// Packs the input of the transaction, with the type ID
+    /// looked up in the codec version of the VM.
+    /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
+    pub fn pack(
+        &self,
+        packer: &packer::Packer,
+        vm: codec::Vm,
+        codec_version: u16,
+    ) -> io::Result<()> {
+        // "TransferableInput.UTXOID" is struct and serialize:"true"
+        // but embedded inline in the struct "TransferableInput"
+        // so no need to encode type ID
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#UTXOID
+        packer.pack_bytes(self.utxo_id.tx_id.as_ref())?;
+        packer.pack_u32(self.utxo_id.output_index)?;
+
+        // "TransferableInput.Asset" is struct and serialize:"true"
+        // but embedded inline in the struct "TransferableInput"
+        // so no need to encode type ID
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#Asset
+        packer.pack_bytes(self.asset_id.as_ref())?;
+
+        // fx_id is serialize:"false" thus skipping serialization
+
+        // decide the type
+        // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#TransferableInput
+        if self.transfer_input.is_none() && self.stakeable_lock_in.is_none() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "unexpected Nones in TransferableInput transfer_input and stakeable_lock_in",
+            ));
+        }
+        let type_name_transferable_in = {
+            if self.transfer_input.is_some() {
+                key::secp256k1::txs::transfer::Input::type_name()
+            } else {
+                platformvm::txs::StakeableLockIn::type_name()
+            }
+        };
+        // marshal type ID for "key::secp256k1::txs::transfer::Input" or "platformvm::txs::StakeableLockIn"
+        packer.pack_u32(codec::REGISTRY.type_id(
+            vm,
+            codec_version,
+            &type_name_transferable_in,
+        )?)?;
+
+        if self.transfer_input.is_some() {
+            // "key::secp256k1::txs::transfer::Input"
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
+            let transfer_input = self.transfer_input.clone().unwrap();
+
+            // marshal "secp256k1fx.TransferInput.Amt" field
+            packer.pack_u64(transfer_input.amount)?;
+
+            // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
+            // but embedded inline in the struct "TransferInput"
+            // so no need to encode type ID
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
+            packer.pack_u32(transfer_input.sig_indices.len() as u32)?;
+            for idx in transfer_input.sig_indices.iter() {
+                packer.pack_u32(*idx)?;
+            }
+        } else {
+            // "platformvm::txs::StakeableLockIn"
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
+            let stakeable_lock_in = self.stakeable_lock_in.clone().unwrap();
+
+            // marshal "platformvm::txs::StakeableLockIn.locktime" field
+            packer.pack_u64(stakeable_lock_in.locktime)?;
+
+            // "platformvm.StakeableLockIn.TransferableIn" is struct and serialize:"true"
+            // but embedded inline in the struct "StakeableLockIn"
+            // so no need to encode type ID
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/platformvm#StakeableLockIn
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
+            //
+            // marshal "secp256k1fx.TransferInput.Amt" field
+            packer.pack_u64(stakeable_lock_in.transfer_input.amount)?;
+            //
+            // "secp256k1fx.TransferInput.Input" is struct and serialize:"true"
+            // but embedded inline in the struct "TransferInput"
+            // so no need to encode type ID
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#TransferInput
+            // ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Input
+            packer.pack_u32(stakeable_lock_in.transfer_input.sig_indices.len() as u32)?;
+            for idx in stakeable_lock_in.transfer_input.sig_indices.iter() {
+                packer.pack_u32(*idx)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/components/avax#SortTransferableInputs
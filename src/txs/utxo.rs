@@ -190,6 +190,8 @@ impl Utxo {
     }
 
     /// Packes the Utxo.
+    /// Only the P-chain codec registers "platformvm.StakeableLockOut",
+    /// and both chains share the "secp256k1fx" type IDs.
     pub fn pack(&self, codec_version: u16) -> io::Result<packer::Packer> {
         // ref. "avalanchego/codec.manager.Marshal", "vms/avm.newCustomCodecs"
         // ref. "math.MaxInt32" and "constants.DefaultByteSliceCap" in Go
@@ -210,7 +212,11 @@ impl Utxo {
         packer.pack_bytes(self.asset_id.as_ref())?;
 
         if let Some(out) = &self.transfer_output {
-            packer.pack_u32(key::secp256k1::txs::transfer::Output::type_id())?;
+            packer.pack_u32(codec::REGISTRY.type_id(
+                codec::Vm::P,
+                codec_version,
+                &key::secp256k1::txs::transfer::Output::type_name(),
+            )?)?;
             packer.pack_u64(out.amount)?;
 
             packer.pack_u64(out.output_owners.locktime)?;
@@ -221,10 +227,18 @@ impl Utxo {
                 packer.pack_bytes(addr.as_ref())?;
             }
         } else if let Some(lock_out) = &self.stakeable_lock_out {
-            packer.pack_u32(platformvm::txs::StakeableLockOut::type_id())?;
+            packer.pack_u32(codec::REGISTRY.type_id(
+                codec::Vm::P,
+                codec_version,
+                &platformvm::txs::StakeableLockOut::type_name(),
+            )?)?;
             packer.pack_u64(lock_out.locktime)?;
 
-            packer.pack_u32(key::secp256k1::txs::transfer::Output::type_id())?;
+            packer.pack_u32(codec::REGISTRY.type_id(
+                codec::Vm::P,
+                codec_version,
+                &key::secp256k1::txs::transfer::Output::type_name(),
+            )?)?;
             packer.pack_u64(lock_out.transfer_output.amount)?;
 
             packer.pack_u64(lock_out.transfer_output.output_owners.locktime)?;
@@ -245,7 +259,16 @@ impl Utxo {
     pub fn unpack(d: &[u8]) -> io::Result<Self> {
         let packer = packer::Packer::load_bytes_for_unpack(d.len() + 1024, d);
 
-        let _codec_version = packer.unpack_u16()?;
+        // UTXOs are shared by the X-chain and the P-chain codecs
+        let codec_version = packer.unpack_u16()?;
+        if !codec::REGISTRY.is_supported(codec::Vm::X, codec_version)
+            && !codec::REGISTRY.is_supported(codec::Vm::P, codec_version)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("unknown codec version {}", codec_version),
+            ));
+        }
 
         // must unpack in the order of struct
         let tx_id_bytes = packer.unpack_bytes(ids::LEN)?;
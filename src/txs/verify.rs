@@ -0,0 +1,361 @@
+//! Offline verification of the credentials of the signed transactions.
+//!
+//! Each credential must carry the signatures of the owners of the UTXO
+//! that its input spends, at the signature indices of the input, over
+//! the SHA256 digest of the unsigned transaction bytes. Checking them
+//! against the fetched UTXOs lets auditors validate historical
+//! transactions without trusting that the node accepted them.
+//! ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/vms/secp256k1fx#Fx.VerifyCredentials
+
+use std::io::{self, Error, ErrorKind};
+
+use crate::{
+    codec,
+    key::secp256k1::{
+        public_key,
+        txs::{Credential, Input, OutputOwners},
+    },
+    packer,
+    txs::{transferable, utxo},
+};
+use ring::digest::{digest, SHA256};
+
+/// Transaction whose credentials can be verified offline.
+///
+/// The digest is recomputed by packing the unsigned transaction with the
+/// codec, so a transaction decoded from its bytes (or JSON) verifies
+/// without the metadata that only the signing sets.
+pub trait Verifiable {
+    /// VM whose codec marshals the transaction.
+    const VM: codec::Vm;
+
+    /// Packs the unsigned transaction with the codec version, which must be
+    /// registered in "codec::REGISTRY". Returns the packer itself so that
+    /// the signing can pack the credentials next.
+    fn pack_unsigned(&self, codec_version: u16) -> io::Result<packer::Packer>;
+
+    /// Returns the inputs in the order of their credentials.
+    fn signed_inputs(&self) -> Vec<transferable::Input>;
+
+    /// Returns the credentials, including the subnet auth one (if any) last.
+    fn credentials(&self) -> Vec<Credential>;
+
+    /// Returns the subnet auth input, which the last credential signs.
+    fn subnet_auth(&self) -> Option<&Input> {
+        None
+    }
+
+    /// Returns the SHA256 digest of the unsigned transaction bytes
+    /// that the credentials sign.
+    fn tx_digest(&self, codec_version: u16) -> io::Result<Vec<u8>> {
+        let packer = self.pack_unsigned(codec_version)?;
+        Ok(digest(&SHA256, &packer.take_bytes()).as_ref().to_vec())
+    }
+
+    /// Verifies the signatures of the credentials against the owners
+    /// of the spent UTXOs (e.g., from "platform.getUTXOs"), as the node would.
+    /// Fails for the transactions with the subnet auth, which need
+    /// "verify_credentials_with_subnet_owners".
+    fn verify_credentials(&self, utxos: &[utxo::Utxo]) -> io::Result<()> {
+        verify(self, utxos, None)
+    }
+
+    /// Verifies the credentials of the inputs, and the last credential
+    /// against the subnet owners (e.g., from "platform.getSubnets")
+    /// at the subnet auth signature indices.
+    fn verify_credentials_with_subnet_owners(
+        &self,
+        utxos: &[utxo::Utxo],
+        subnet_owners: &OutputOwners,
+    ) -> io::Result<()> {
+        verify(self, utxos, Some(subnet_owners))
+    }
+}
+
+fn verify<T: Verifiable + ?Sized>(
+    tx: &T,
+    utxos: &[utxo::Utxo],
+    subnet_owners: Option<&OutputOwners>,
+) -> io::Result<()> {
+    let ins = tx.signed_inputs();
+    let creds = tx.credentials();
+    let subnet_auth = tx.subnet_auth();
+    check_credentials_len(ins.len() + subnet_auth.map_or(0, |_| 1), creds.len())?;
+
+    let tx_digest = tx.tx_digest(codec::VERSION)?;
+    verify_inputs(&tx_digest, &ins, utxos, &creds)?;
+
+    match (subnet_auth, subnet_owners) {
+        (None, _) => Ok(()),
+        (Some(_), None) => Err(Error::new(
+            ErrorKind::InvalidInput,
+            "subnet auth credential needs the subnet owners",
+        )),
+        (Some(auth), Some(owners)) => {
+            verify_credential(&tx_digest, owners, &auth.sig_indices, &creds[ins.len()])
+                .map_err(|e| Error::new(e.kind(), format!("subnet auth: {}", e)))
+        }
+    }
+}
+
+/// Verifies that the credential signs the digest by the owners
+/// at the signature indices, meeting the threshold exactly.
+pub fn verify_credential(
+    tx_digest: &[u8],
+    owners: &OutputOwners,
+    sig_indices: &[u32],
+    cred: &Credential,
+) -> io::Result<()> {
+    if sig_indices.len() != cred.signatures.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "{} signature indices but {} signatures in the credential",
+                sig_indices.len(),
+                cred.signatures.len()
+            ),
+        ));
+    }
+    if !cmp_manager::is_sorted_and_unique(sig_indices) {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("signature indices {:?} not sorted and unique", sig_indices),
+        ));
+    }
+    if sig_indices.len() != owners.threshold as usize {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "{} signatures but the threshold is {}",
+                sig_indices.len(),
+                owners.threshold
+            ),
+        ));
+    }
+
+    for (idx, sig) in sig_indices.iter().zip(cred.signatures.iter()) {
+        let owner = owners.addresses.get(*idx as usize).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "signature index {} out of bounds ({} owners)",
+                    idx,
+                    owners.addresses.len()
+                ),
+            )
+        })?;
+        let signer = public_key::Key::from_signature(tx_digest, sig)?.to_short_id()?;
+        if signer != *owner {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "signature index {} signed by {} instead of the owner {}",
+                    idx, signer, owner
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Verifies the credential of each input, in order, against the owners
+/// of the UTXO that the input spends. The UTXOs may be given in any order
+/// and include the ones that the transaction does not spend.
+pub fn verify_inputs(
+    tx_digest: &[u8],
+    ins: &[transferable::Input],
+    utxos: &[utxo::Utxo],
+    creds: &[Credential],
+) -> io::Result<()> {
+    if ins.len() > creds.len() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("{} inputs but {} credentials", ins.len(), creds.len()),
+        ));
+    }
+
+    for (i, (input, cred)) in ins.iter().zip(creds.iter()).enumerate() {
+        let u = utxos
+            .iter()
+            .find(|u| {
+                u.utxo_id.tx_id == input.utxo_id.tx_id
+                    && u.utxo_id.output_index == input.utxo_id.output_index
+            })
+            .ok_or_else(|| {
+                Error::new(
+                    ErrorKind::NotFound,
+                    format!(
+                        "input {} spends unknown UTXO {}:{}",
+                        i, input.utxo_id.tx_id, input.utxo_id.output_index
+                    ),
+                )
+            })?;
+        if u.asset_id != input.asset_id {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "input {} asset {} does not match the UTXO asset {}",
+                    i, input.asset_id, u.asset_id
+                ),
+            ));
+        }
+
+        let out = match (&u.transfer_output, &u.stakeable_lock_out) {
+            (Some(out), _) => out,
+            (None, Some(lock_out)) => &lock_out.transfer_output,
+            (None, None) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("UTXO of input {} has no output", i),
+                ))
+            }
+        };
+        let transfer_in = match (&input.transfer_input, &input.stakeable_lock_in) {
+            (Some(transfer_in), _) => transfer_in,
+            (None, Some(lock_in)) => &lock_in.transfer_input,
+            (None, None) => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    format!("input {} has no transfer input", i),
+                ))
+            }
+        };
+        if transfer_in.amount != out.amount {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "input {} amount {} does not match the UTXO amount {}",
+                    i, transfer_in.amount, out.amount
+                ),
+            ));
+        }
+
+        verify_credential(
+            tx_digest,
+            &out.output_owners,
+            &transfer_in.sig_indices,
+            cred,
+        )
+        .map_err(|e| Error::new(e.kind(), format!("input {}: {}", i, e)))?;
+    }
+    Ok(())
+}
+
+/// Checks that there are as many credentials as the signed inputs,
+/// since the credentials without an input are never verified.
+pub fn check_credentials_len(signed: usize, creds: usize) -> io::Result<()> {
+    if signed != creds {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!("{} signed inputs but {} credentials", signed, creds),
+        ));
+    }
+    Ok(())
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- txs::verify::test_verify_credentials --exact --show-output
+#[test]
+fn test_verify_credentials() {
+    use crate::{avm, ids, key, txs};
+
+    let k1 = key::secp256k1::private_key::Key::generate().unwrap();
+    let k2 = key::secp256k1::private_key::Key::generate().unwrap();
+    let owners = OutputOwners::new(0, 1, &[k1.to_public_key().to_short_id().unwrap()]);
+    let asset_id = ids::Id::sha256(b"AVAX");
+
+    let utxo_id = utxo::Id {
+        tx_id: ids::Id::sha256(b"prev"),
+        output_index: 1,
+        ..utxo::Id::default()
+    };
+    let utxos = vec![utxo::Utxo {
+        utxo_id: utxo_id.clone(),
+        asset_id,
+        transfer_output: Some(key::secp256k1::txs::transfer::Output::new(
+            1000,
+            owners.clone(),
+        )),
+        ..utxo::Utxo::default()
+    }];
+    let input = transferable::Input {
+        utxo_id,
+        asset_id,
+        transfer_input: Some(key::secp256k1::txs::transfer::Input {
+            amount: 1000,
+            sig_indices: vec![0],
+        }),
+        ..transferable::Input::default()
+    };
+
+    let mut tx = avm::txs::Tx::new(txs::Tx {
+        network_id: 1,
+        transferable_inputs: Some(vec![input]),
+        ..txs::Tx::default()
+    });
+    assert!(tx.verify_credentials(&utxos).is_err());
+
+    tokio_test::block_on(tx.sign(vec![vec![k1.clone()]])).unwrap();
+    tx.verify_credentials(&utxos).unwrap();
+
+    // decoded without the metadata, which only the signing sets
+    let b = serde_json::to_vec(&tx).unwrap();
+    let decoded: avm::txs::Tx = serde_json::from_slice(&b).unwrap();
+    assert!(decoded.base_tx.metadata.is_none());
+    assert_eq!(
+        decoded.tx_digest(codec::VERSION).unwrap(),
+        digest(
+            &SHA256,
+            &tx.base_tx
+                .metadata
+                .as_ref()
+                .unwrap()
+                .tx_bytes_with_no_signature
+        )
+        .as_ref()
+    );
+    decoded.verify_credentials(&utxos).unwrap();
+
+    // unknown UTXO
+    let e = tx.verify_credentials(&[]).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::NotFound);
+
+    // signed by the wrong key
+    let mut forged = tx.clone();
+    tokio_test::block_on(forged.sign(vec![vec![k2]])).unwrap();
+    assert!(forged.verify_credentials(&utxos).is_err());
+
+    // amount mismatch
+    let mut other = utxos.clone();
+    other[0].transfer_output.as_mut().unwrap().amount = 1;
+    assert!(tx.verify_credentials(&other).is_err());
+
+    // missing credential
+    let mut stripped = tx.clone();
+    stripped.fx_creds.clear();
+    assert!(stripped.verify_credentials(&utxos).is_err());
+
+    // 2-of-3 multisig
+    let k3 = key::secp256k1::private_key::Key::generate().unwrap();
+    let multisig = OutputOwners::new(
+        0,
+        2,
+        &[
+            k1.to_public_key().to_short_id().unwrap(),
+            k3.to_public_key().to_short_id().unwrap(),
+            ids::short::Id::empty(),
+        ],
+    )
+    .sorted();
+    let keychain = key::secp256k1::keychain::Keychain::new(vec![k1, k3]);
+    let (sig_indices, keys) = keychain.match_threshold(&multisig, 0).unwrap();
+    let digest = ids::Id::sha256(b"unsigned tx bytes");
+    let cred = Credential::new(
+        keys.iter()
+            .map(|k| k.sign_digest(digest.as_ref()).unwrap().to_bytes().to_vec())
+            .collect(),
+    );
+    verify_credential(digest.as_ref(), &multisig, &sig_indices, &cred).unwrap();
+    assert!(verify_credential(digest.as_ref(), &multisig, &sig_indices[..1], &cred).is_err());
+    let other = ids::Id::sha256(b"other tx bytes");
+    assert!(verify_credential(other.as_ref(), &multisig, &sig_indices, &cred).is_err());
+}
@@ -1,3 +1,10 @@
+use std::{
+    fmt,
+    io::{self, Error, ErrorKind},
+};
+
+use primitive_types::U256;
+
 pub const NANO_AVAX: u64 = 1;
 pub const MICRO_AVAX: u64 = 1000 * NANO_AVAX;
 pub const MILLI_AVAX: u64 = 1000 * MICRO_AVAX;
@@ -23,3 +30,243 @@ pub fn convert_navax_for_x_and_p(n: u64) -> u64 {
 pub fn convert_navax_for_evm(n: u64) -> u64 {
     n / AVAX_EVM_CHAIN
 }
+
+/// Formats the amount in the smallest unit with the decimals of the token,
+/// trimming the trailing zeros of the fraction.
+/// e.g., "1500000000000000000" with 18 decimals is "1.5".
+pub fn format_units(amount: U256, decimals: u8) -> String {
+    // 10^78 overflows U256
+    let decimals = decimals.min(77) as usize;
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    let base = U256::exp10(decimals);
+    let (whole, frac) = amount.div_mod(base);
+    let frac = format!("{:0>width$}", frac.to_string(), width = decimals);
+    let frac = frac.trim_end_matches('0');
+    if frac.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, frac)
+    }
+}
+
+/// Wei per nano-AVAX, the C-chain (18 decimals) to X/P-chain (9 decimals) unit.
+pub const WEI_PER_NANO_AVAX: u64 = 1_000_000_000;
+
+fn overflow(op: &str, a: impl fmt::Display, b: impl fmt::Display) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("{} {} {} overflows", a, op, b),
+    )
+}
+
+/// Amount in nano-AVAX, the unit of the X and P-chain, whose arithmetic
+/// fails on overflow rather than wrapping (e.g., summing many UTXOs).
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NanoAvax(pub u64);
+
+impl NanoAvax {
+    pub const ZERO: NanoAvax = NanoAvax(0);
+
+    /// Returns the amount of the whole AVAX.
+    pub fn from_avax(avax: u64) -> io::Result<Self> {
+        avax.checked_mul(AVAX)
+            .map(Self)
+            .ok_or_else(|| overflow("AVAX *", avax, AVAX))
+    }
+
+    pub fn as_u64(&self) -> u64 {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Self) -> io::Result<Self> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .ok_or_else(|| overflow("+", self, other))
+    }
+
+    /// Fails if the other amount is greater.
+    pub fn checked_sub(self, other: Self) -> io::Result<Self> {
+        self.0.checked_sub(other.0).map(Self).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} - {} underflows", self, other),
+            )
+        })
+    }
+
+    pub fn checked_mul(self, n: u64) -> io::Result<Self> {
+        self.0
+            .checked_mul(n)
+            .map(Self)
+            .ok_or_else(|| overflow("*", self, n))
+    }
+
+    /// Sums the amounts, failing on overflow.
+    pub fn checked_sum(amounts: impl IntoIterator<Item = u64>) -> io::Result<Self> {
+        amounts
+            .into_iter()
+            .try_fold(Self::ZERO, |total, amount| total.checked_add(Self(amount)))
+    }
+
+    /// Converts to the C-chain unit, which never overflows.
+    pub fn to_wei(&self) -> Wei {
+        Wei(U256::from(self.0) * U256::from(WEI_PER_NANO_AVAX))
+    }
+}
+
+impl From<u64> for NanoAvax {
+    fn from(v: u64) -> Self {
+        Self(v)
+    }
+}
+
+impl From<NanoAvax> for u64 {
+    fn from(v: NanoAvax) -> Self {
+        v.0
+    }
+}
+
+/// Formats in AVAX (e.g., "1.5 AVAX").
+impl fmt::Display for NanoAvax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} AVAX", format_units(U256::from(self.0), 9))
+    }
+}
+
+/// Amount in wei, the unit of the C-chain and the subnet-evm chains.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Wei(pub U256);
+
+impl Wei {
+    pub const ZERO: Wei = Wei(U256::zero());
+
+    pub fn as_u256(&self) -> U256 {
+        self.0
+    }
+
+    pub fn checked_add(self, other: Self) -> io::Result<Self> {
+        self.0
+            .checked_add(other.0)
+            .map(Self)
+            .ok_or_else(|| overflow("+", self, other))
+    }
+
+    /// Fails if the other amount is greater.
+    pub fn checked_sub(self, other: Self) -> io::Result<Self> {
+        self.0.checked_sub(other.0).map(Self).ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} - {} underflows", self, other),
+            )
+        })
+    }
+
+    pub fn checked_mul(self, n: U256) -> io::Result<Self> {
+        self.0
+            .checked_mul(n)
+            .map(Self)
+            .ok_or_else(|| overflow("*", self, n))
+    }
+
+    /// Converts to nano-AVAX, failing if the amount has a fraction of
+    /// nano-AVAX (which the X and P-chain cannot represent) or exceeds u64.
+    /// ref. "coreth/plugin/evm.(*UnsignedExportTx).SemanticVerify"
+    pub fn to_nano_avax(&self) -> io::Result<NanoAvax> {
+        let (n, rem) = self.to_nano_avax_floor()?;
+        if !rem.0.is_zero() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} is not a whole nano-AVAX amount", self),
+            ));
+        }
+        Ok(n)
+    }
+
+    /// Converts to nano-AVAX rounding down, and returns the remaining wei.
+    pub fn to_nano_avax_floor(&self) -> io::Result<(NanoAvax, Wei)> {
+        let (n, rem) = self.0.div_mod(U256::from(WEI_PER_NANO_AVAX));
+        if n > U256::from(u64::MAX) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                format!("{} exceeds the nano-AVAX range", self),
+            ));
+        }
+        Ok((NanoAvax(n.as_u64()), Wei(rem)))
+    }
+}
+
+impl From<U256> for Wei {
+    fn from(v: U256) -> Self {
+        Self(v)
+    }
+}
+
+impl From<NanoAvax> for Wei {
+    fn from(v: NanoAvax) -> Self {
+        v.to_wei()
+    }
+}
+
+impl fmt::Display for Wei {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} wei", self.0)
+    }
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- units::test_format_units --exact --show-output
+#[test]
+fn test_format_units() {
+    assert_eq!(format_units(U256::from(AVAX_EVM_CHAIN), 18), "1");
+    assert_eq!(
+        format_units(U256::from(1_500_000_000_000_000_000_u64), 18),
+        "1.5"
+    );
+    assert_eq!(format_units(U256::from(1_u64), 18), "0.000000000000000001");
+    assert_eq!(format_units(U256::from(12345_u64), 2), "123.45");
+    assert_eq!(format_units(U256::from(7_u64), 0), "7");
+    assert_eq!(format_units(U256::zero(), 6), "0");
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- units::test_amounts --exact --show-output
+#[test]
+fn test_amounts() {
+    let a = NanoAvax::from_avax(2).unwrap();
+    assert_eq!(a.as_u64(), 2 * AVAX);
+    assert_eq!(NanoAvax(1_500_000_000).to_string(), "1.5 AVAX");
+    assert!(NanoAvax::from_avax(u64::MAX / AVAX + 1).is_err());
+
+    assert_eq!(a.checked_add(NanoAvax(1)).unwrap(), NanoAvax(2 * AVAX + 1));
+    assert!(NanoAvax(u64::MAX).checked_add(NanoAvax(1)).is_err());
+    assert!(NanoAvax(1).checked_sub(a).is_err());
+    assert_eq!(a.checked_sub(NanoAvax(AVAX)).unwrap(), NanoAvax(AVAX));
+    assert!(a.checked_mul(u64::MAX).is_err());
+
+    assert_eq!(NanoAvax::checked_sum([1, 2, 3]).unwrap(), NanoAvax(6));
+    assert_eq!(NanoAvax::checked_sum([]).unwrap(), NanoAvax::ZERO);
+    let err = NanoAvax::checked_sum([u64::MAX, 1]).unwrap_err();
+    assert!(err.to_string().contains("overflows"));
+
+    // u64::MAX nano-AVAX in wei exceeds u64, but not U256
+    let w = NanoAvax(u64::MAX).to_wei();
+    assert_eq!(w.0, U256::from(u64::MAX) * U256::from(WEI_PER_NANO_AVAX));
+    assert_eq!(w.to_nano_avax().unwrap(), NanoAvax(u64::MAX));
+    assert!(w
+        .checked_add(Wei(U256::from(WEI_PER_NANO_AVAX)))
+        .unwrap()
+        .to_nano_avax()
+        .is_err());
+    assert!(Wei(U256::MAX).checked_add(Wei(U256::one())).is_err());
+    assert!(Wei::ZERO.checked_sub(Wei(U256::one())).is_err());
+
+    // sub-nano-AVAX fraction
+    let w = Wei(U256::from(AVAX_EVM_CHAIN + 1));
+    assert!(w.to_nano_avax().is_err());
+    assert_eq!(
+        w.to_nano_avax_floor().unwrap(),
+        (NanoAvax(AVAX), Wei(U256::one()))
+    );
+    assert_eq!(Wei::from(NanoAvax(AVAX)).0, U256::from(AVAX_EVM_CHAIN));
+}
@@ -1,7 +1,10 @@
 use std::{
     clone::Clone,
     cmp::Ordering,
+    fmt,
     hash::{Hash, Hasher},
+    io::{self, Error, ErrorKind},
+    str::FromStr,
 };
 
 #[derive(Clone, Debug, Eq)]
@@ -56,6 +59,52 @@ impl ApplicationVersion {
     }
 }
 
+/// e.g., "avalanche/1.9.3".
+impl fmt::Display for ApplicationVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}.{}.{}",
+            self.app, self.major, self.minor, self.patch
+        )
+    }
+}
+
+/// Parses the "[app]/[major].[minor].[patch]" version (e.g., "avalanche/1.9.3"
+/// of "info.getNodeVersion"), where the patch may have a suffix (e.g., "-rc.1").
+/// ref. https://pkg.go.dev/github.com/ava-labs/avalanchego/version#Parser
+impl FromStr for ApplicationVersion {
+    type Err = io::Error;
+
+    fn from_str(s: &str) -> io::Result<Self> {
+        let invalid = || {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("invalid application version '{}'", s),
+            )
+        };
+
+        let (app, semver) = s.split_once('/').ok_or_else(invalid)?;
+        let semver = semver.trim_start_matches('v');
+        let semver = semver.split(['-', '+']).next().unwrap_or(semver);
+        let parts: Vec<&str> = semver.split('.').collect();
+        if app.is_empty() || parts.len() != 3 {
+            return Err(invalid());
+        }
+
+        let mut nums = [0_u32; 3];
+        for (n, p) in nums.iter_mut().zip(parts) {
+            *n = p.parse().map_err(|_| invalid())?;
+        }
+        Ok(Self {
+            app: app.to_string(),
+            major: nums[0],
+            minor: nums[1],
+            patch: nums[2],
+        })
+    }
+}
+
 /// RUST_LOG=debug cargo test --package avalanche-types --lib -- version::test_version --exact --show-output
 #[test]
 fn test_version() {
@@ -89,3 +138,19 @@ fn test_version() {
     assert!(v1.before(&v3) && v2.before(&v3));
     assert!(!v1.before(&v4) && !v2.before(&v4) && !v3.before(&v4));
 }
+
+/// RUST_LOG=debug cargo test --package avalanche-types --lib -- version::test_parse --exact --show-output
+#[test]
+fn test_parse() {
+    let v: ApplicationVersion = "avalanche/1.9.3".parse().unwrap();
+    assert_eq!(v.app, "avalanche");
+    assert_eq!((v.major, v.minor, v.patch), (1, 9, 3));
+    assert_eq!(v.to_string(), "avalanche/1.9.3");
+
+    let v: ApplicationVersion = "avalanche/v1.9.4-rc.1".parse().unwrap();
+    assert_eq!((v.major, v.minor, v.patch), (1, 9, 4));
+
+    for s in ["1.9.3", "avalanche/1.9", "avalanche/1.x.3", "/1.9.3", ""] {
+        assert!(s.parse::<ApplicationVersion>().is_err(), "{}", s);
+    }
+}
@@ -1,2 +1,2 @@
-#[cfg(any(test, feature = "subnet"))]
+#[cfg(any(test, feature = "subnet_vm"))]
 mod rpcchainvm;
@@ -0,0 +1,422 @@
+//! Runs the key-value chain VM of "examples/kvvm" behind the plugin server,
+//! and drives it over gRPC the way avalanchego does.
+
+#[path = "../../examples/kvvm/vm/mod.rs"]
+mod vm;
+
+use std::{io, time::Duration};
+
+use avalanche_types::{
+    choices::status::Status,
+    ids,
+    proto::{
+        grpcutil::default_server,
+        pb::{
+            appsender::app_sender_server::AppSenderServer,
+            google::protobuf::Empty,
+            messenger::{
+                messenger_server::{Messenger, MessengerServer},
+                NotifyRequest, NotifyResponse,
+            },
+            rpcdb::database_server::DatabaseServer,
+            vm::{self as pb_vm, vm_client::VmClient},
+        },
+    },
+    subnet::rpc::{
+        common::{appsender, message::Message},
+        database::{memdb::Database as MemDb, rpcdb::server::Server as RpcDb},
+        http::{client::Client as HttpClient, Handler},
+        plugin,
+        snow::State,
+        utils,
+        vm::server::Server,
+    },
+};
+use jsonrpc_core::Response as JsonResp;
+use prost::bytes::Bytes;
+use tokio::{
+    sync::{broadcast, mpsc},
+    time::timeout,
+};
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Channel;
+
+use crate::rpcchainvm::common::generate_http_request;
+
+/// Forwards the engine notifications of the VM to the test.
+struct TestMessenger(mpsc::UnboundedSender<u32>);
+
+#[tonic::async_trait]
+impl Messenger for TestMessenger {
+    async fn notify(
+        &self,
+        req: tonic::Request<NotifyRequest>,
+    ) -> Result<tonic::Response<NotifyResponse>, tonic::Status> {
+        let _ = self.0.send(req.into_inner().message);
+        Ok(tonic::Response::new(NotifyResponse {}))
+    }
+}
+
+/// Forwards the gossip of the VM to the test.
+#[derive(Clone)]
+struct TestAppSender(mpsc::UnboundedSender<Vec<u8>>);
+
+#[tonic::async_trait]
+impl appsender::AppSender for TestAppSender {
+    async fn send_app_request(
+        &self,
+        _node_ids: ids::node::Set,
+        _request_id: u32,
+        _request: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    async fn send_app_response(
+        &self,
+        _node_id: ids::node::Id,
+        _request_id: u32,
+        _response: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    async fn send_app_gossip(&self, msg: Vec<u8>) -> io::Result<()> {
+        let _ = self.0.send(msg);
+        Ok(())
+    }
+    async fn send_app_gossip_specific(
+        &self,
+        _node_ids: ids::node::Set,
+        _msg: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    async fn send_cross_chain_app_request(
+        &self,
+        _chain_id: ids::Id,
+        _request_id: u32,
+        _app_request_bytes: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+    async fn send_cross_chain_app_response(
+        &self,
+        _chain_id: ids::Id,
+        _request_id: u32,
+        _app_response_bytes: Vec<u8>,
+    ) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+async fn call(
+    client: &mut Box<dyn Handler + Send + Sync>,
+    addr: &str,
+    method: &str,
+    params: &[&str],
+) -> Result<serde_json::Value, String> {
+    let req = generate_http_request(method, &format!("http://{}", addr), params);
+    let resp = client.serve_http_simple(req).await.unwrap();
+    assert!(resp.status().is_success());
+
+    let json_str = std::str::from_utf8(resp.body()).unwrap();
+    match JsonResp::from_json(json_str).unwrap() {
+        JsonResp::Single(jsonrpc_core::Output::Success(s)) => Ok(s.result),
+        JsonResp::Single(jsonrpc_core::Output::Failure(f)) => Err(f.error.message),
+        JsonResp::Batch(_) => panic!("unexpected batch response"),
+    }
+}
+
+/// Builds, verifies and accepts the next block, returning its ID.
+async fn build_and_accept(client: &mut VmClient<Channel>) -> ids::Id {
+    let built = client
+        .build_block(pb_vm::BuildBlockRequest {
+            p_chain_height: None,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    client
+        .block_verify(pb_vm::BlockVerifyRequest {
+            bytes: built.bytes.clone(),
+            p_chain_height: None,
+        })
+        .await
+        .unwrap();
+    client
+        .set_preference(pb_vm::SetPreferenceRequest {
+            id: built.id.clone(),
+        })
+        .await
+        .unwrap();
+    client
+        .block_accept(pb_vm::BlockAcceptRequest {
+            id: built.id.clone(),
+        })
+        .await
+        .unwrap();
+    ids::Id::from_slice(&built.id)
+}
+
+/// RUST_LOG=debug cargo test --package avalanche-types --test integration --features subnet_vm -- rpcchainvm::kvvm::test_kvvm --exact --show-output
+#[tokio::test]
+async fn test_kvvm() {
+    let _ = env_logger::builder().is_test(true).try_init();
+    let (stop_tx, _) = broadcast::channel::<()>(1);
+
+    // database served by the node
+    let db_addr = utils::new_socket_addr();
+    utils::grpc::Server::new(db_addr, stop_tx.subscribe())
+        .serve(DatabaseServer::new(RpcDb::new(MemDb::new())))
+        .unwrap();
+
+    // engine messenger and app sender served by the node
+    let (notify_tx, mut notify_rx) = mpsc::unbounded_channel();
+    let (gossip_tx, mut gossip_rx) = mpsc::unbounded_channel();
+    let runtime_listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let runtime_addr = runtime_listener.local_addr().unwrap();
+    tokio::spawn(
+        default_server()
+            .add_service(MessengerServer::new(TestMessenger(notify_tx)))
+            .add_service(AppSenderServer::new(appsender::server::Server::new(
+                Box::new(TestAppSender(gossip_tx)),
+            )))
+            .serve_with_incoming(TcpListenerStream::new(runtime_listener)),
+    );
+
+    let vm_addr = utils::new_socket_addr();
+    let vm_server = Server::new(Box::new(vm::Vm::new()), stop_tx.clone());
+    tokio::spawn(plugin::serve_with_address(
+        vm_server,
+        vm_addr,
+        stop_tx.subscribe(),
+    ));
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let mut client = VmClient::connect(format!("http://{}", vm_addr))
+        .await
+        .unwrap();
+
+    let genesis = vm::Genesis {
+        timestamp: 1,
+        entries: [("hello".to_string(), "world".to_string())].into(),
+    };
+    let resp = client
+        .initialize(pb_vm::InitializeRequest {
+            network_id: 1337,
+            subnet_id: Bytes::from(ids::Id::sha256(b"subnet").to_vec()),
+            chain_id: Bytes::from(ids::Id::sha256(b"chain").to_vec()),
+            node_id: Bytes::from(ids::node::Id::from_slice(&[1; 20]).to_vec()),
+            x_chain_id: Bytes::from(ids::Id::sha256(b"x").to_vec()),
+            avax_asset_id: Bytes::from(ids::Id::sha256(b"avax").to_vec()),
+            genesis_bytes: Bytes::from(serde_json::to_vec(&genesis).unwrap()),
+            upgrade_bytes: Bytes::new(),
+            config_bytes: Bytes::new(),
+            db_servers: vec![pb_vm::VersionedDbServer {
+                version: "v1.4.5".to_string(),
+                server_addr: db_addr.to_string(),
+            }],
+            server_addr: runtime_addr.to_string(),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(resp.height, 0);
+    let genesis_id = ids::Id::from_slice(&resp.last_accepted_id);
+
+    let resp = client
+        .set_state(pb_vm::SetStateRequest {
+            state: State::NormalOp as u32,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(ids::Id::from_slice(&resp.last_accepted_id), genesis_id);
+
+    let version = client.version(Empty {}).await.unwrap().into_inner();
+    assert_eq!(version.version, vm::VERSION);
+
+    // nothing to build yet
+    assert!(client
+        .build_block(pb_vm::BuildBlockRequest {
+            p_chain_height: None,
+        })
+        .await
+        .is_err());
+
+    let handlers = client
+        .create_handlers(Empty {})
+        .await
+        .unwrap()
+        .into_inner()
+        .handlers;
+    assert_eq!(handlers.len(), 1);
+    assert_eq!(handlers[0].prefix, "/rpc");
+    let rpc_addr = handlers[0].server_addr.clone();
+    let mut rpc = HttpClient::new(
+        Channel::builder(format!("http://{}", rpc_addr).parse().unwrap())
+            .connect()
+            .await
+            .unwrap(),
+    );
+
+    // genesis entries
+    let v = call(&mut rpc, &rpc_addr, "kvvm.get", &["hello"])
+        .await
+        .unwrap();
+    assert_eq!(v, serde_json::json!("world"));
+    let v = call(&mut rpc, &rpc_addr, "kvvm.get", &["foo"])
+        .await
+        .unwrap();
+    assert!(v.is_null());
+
+    // issued put notifies the engine and is gossiped
+    call(&mut rpc, &rpc_addr, "kvvm.put", &["foo", "bar"])
+        .await
+        .unwrap();
+    let msg = timeout(Duration::from_secs(5), notify_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(msg, Message::PendingTxs as u32);
+    let gossip = timeout(Duration::from_secs(5), gossip_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        vm::tx::decode_gossip(&gossip).unwrap(),
+        vec![vm::tx::Tx::new("foo", "bar")]
+    );
+
+    // value is only set once the block is accepted
+    let v = call(&mut rpc, &rpc_addr, "kvvm.get", &["foo"])
+        .await
+        .unwrap();
+    assert!(v.is_null());
+    let blk_id = build_and_accept(&mut client).await;
+    let v = call(&mut rpc, &rpc_addr, "kvvm.get", &["foo"])
+        .await
+        .unwrap();
+    assert_eq!(v, serde_json::json!("bar"));
+
+    let v = call(&mut rpc, &rpc_addr, "kvvm.lastAccepted", &[])
+        .await
+        .unwrap();
+    assert_eq!(v["id"], serde_json::json!(blk_id.to_string()));
+    assert_eq!(v["height"], serde_json::json!(1));
+    assert_eq!(v["parentId"], serde_json::json!(genesis_id.to_string()));
+
+    let blk = client
+        .get_block(pb_vm::GetBlockRequest {
+            id: Bytes::from(blk_id.to_vec()),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(blk.err, 0);
+    assert_eq!(blk.height, 1);
+    assert_eq!(blk.status, Status::Accepted.to_u32());
+    assert_eq!(ids::Id::from_slice(&blk.parent_id), genesis_id);
+
+    // accepted put cannot be issued again
+    let e = call(&mut rpc, &rpc_addr, "kvvm.put", &["foo", "bar"])
+        .await
+        .unwrap_err();
+    assert!(e.contains("already accepted"));
+
+    // gossiped put is built without being gossiped back
+    client
+        .app_gossip(pb_vm::AppGossipMsg {
+            node_id: Bytes::from(ids::node::Id::from_slice(&[2; 20]).to_vec()),
+            msg: Bytes::from(vm::tx::encode_gossip(&[vm::tx::Tx::new("baz", "qux")]).unwrap()),
+        })
+        .await
+        .unwrap();
+    let msg = timeout(Duration::from_secs(5), notify_rx.recv())
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(msg, Message::PendingTxs as u32);
+    build_and_accept(&mut client).await;
+    let v = call(&mut rpc, &rpc_addr, "kvvm.get", &["baz"])
+        .await
+        .unwrap();
+    assert_eq!(v, serde_json::json!("qux"));
+    assert!(gossip_rx.try_recv().is_err());
+
+    // rejected block leaves the state unchanged
+    call(&mut rpc, &rpc_addr, "kvvm.put", &["foo", "rejected"])
+        .await
+        .unwrap();
+    let built = client
+        .build_block(pb_vm::BuildBlockRequest {
+            p_chain_height: None,
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(built.height, 3);
+    client
+        .block_verify(pb_vm::BlockVerifyRequest {
+            bytes: built.bytes.clone(),
+            p_chain_height: None,
+        })
+        .await
+        .unwrap();
+    client
+        .block_reject(pb_vm::BlockRejectRequest {
+            id: built.id.clone(),
+        })
+        .await
+        .unwrap();
+    let v = call(&mut rpc, &rpc_addr, "kvvm.get", &["foo"])
+        .await
+        .unwrap();
+    assert_eq!(v, serde_json::json!("bar"));
+    let blk = client
+        .get_block(pb_vm::GetBlockRequest { id: built.id })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(blk.status, Status::Rejected.to_u32());
+
+    // invalid blocks
+    assert!(client
+        .parse_block(pb_vm::ParseBlockRequest {
+            bytes: Bytes::from_static(b"not a block"),
+        })
+        .await
+        .is_err());
+    let skipped = vm::block::Block::new(
+        genesis_id,
+        5,
+        2,
+        vec![vm::tx::Tx::new("a", "b")],
+        Status::Processing,
+    )
+    .unwrap();
+    assert!(client
+        .block_verify(pb_vm::BlockVerifyRequest {
+            bytes: Bytes::from(skipped.bytes().to_vec()),
+            p_chain_height: None,
+        })
+        .await
+        .is_err());
+
+    // unknown block
+    let blk = client
+        .get_block(pb_vm::GetBlockRequest {
+            id: Bytes::from(ids::Id::sha256(b"unknown").to_vec()),
+        })
+        .await
+        .unwrap()
+        .into_inner();
+    assert_ne!(blk.err, 0);
+
+    let health = client.health(Empty {}).await.unwrap().into_inner();
+    let health: serde_json::Value = serde_json::from_slice(&health.details).unwrap();
+    assert_eq!(health["height"], serde_json::json!(2));
+
+    client.shutdown(Empty {}).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(client.version(Empty {}).await.is_err());
+}
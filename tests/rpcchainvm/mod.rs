@@ -1,5 +1,6 @@
 mod common;
 mod database;
+mod kvvm;
 mod shutdown;
 
 use crate::rpcchainvm::common::*;